@@ -28,7 +28,7 @@
 
 use crate::{
     aztec::shared_test_methods::{stripSpace, toBitArray, toBooleanArray},
-    common::BitMatrix,
+    common::{cpp_essentials::StructuredAppendInfo, BitMatrix},
     Point,
 };
 
@@ -72,6 +72,55 @@ fn test_high_level_decode() {
     );
 }
 
+#[test]
+fn test_structured_append() {
+    // M/L  U/L   ' '   'I'   'D'   ' '   'B'   'D'   'H'   'I'
+    let bits = "XXX.X XXX.X ....X .X.X. ..X.X ....X ...XX ..X.X .X..X .X.X.";
+    let (text, sai) = decode_structured_append(bits);
+    assert_eq!("HI", text);
+    assert_eq!((1, 4, "ID"), (sai.index, sai.count, sai.id.as_str()));
+
+    // M/L  U/L   'B'   'D'   'H'   'I'
+    let bits = "XXX.X XXX.X ...XX ..X.X .X..X .X.X.";
+    let (text, sai) = decode_structured_append(bits);
+    assert_eq!("HI", text);
+    assert_eq!((1, 4, ""), (sai.index, sai.count, sai.id.as_str()));
+
+    // a count of 1 makes no sense, the count is unknown
+    // M/L  U/L   'C'   'A'   'H'   'I'
+    let bits = "XXX.X XXX.X ..X.. ...X. .X..X .X.X.";
+    let (text, sai) = decode_structured_append(bits);
+    assert_eq!("HI", text);
+    assert_eq!((2, 0), (sai.index, sai.count));
+
+    // no header
+    // 'B'  'D'   'H'   'I'   'H'   'I'
+    let bits = "...XX ..X.X .X..X .X.X. .X..X .X.X.";
+    let (text, sai) = decode_structured_append(bits);
+    assert_eq!("BDHIHI", text);
+    assert_eq!(-1, sai.index);
+
+    // a header which does not parse leaves the data as it is
+    // M/L  U/L   ' '   'I'   'D'
+    let bits = "XXX.X XXX.X ....X .X.X. ..X.X";
+    let (text, sai) = decode_structured_append(bits);
+    assert_eq!(" ID", text);
+    assert_eq!(-1, sai.index);
+
+    // M/L  U/L   D/L   '1'  '2'
+    let bits = "XXX.X XXX.X XXXX. ..XX .X..";
+    let (text, sai) = decode_structured_append(bits);
+    assert_eq!("12", text);
+    assert_eq!(-1, sai.index);
+}
+
+fn decode_structured_append(b: &str) -> (String, StructuredAppendInfo) {
+    let bits = toBooleanArray(&toBitArray(&stripSpace(b)));
+    let mut text = decoder::highLevelDecode(&bits).expect("highLevelDecode Failed");
+    let sai = decoder::get_structured_append(&bits, &mut text);
+    (text, sai)
+}
+
 fn test_high_level_decode_string(expectedString: &str, b: &str) {
     let bits = toBitArray(&stripSpace(b));
     assert_eq!(
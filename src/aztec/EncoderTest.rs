@@ -23,11 +23,12 @@ use crate::{
         encoder::HighLevelEncoder,
         shared_test_methods::{stripSpace, toBitArray, toBooleanArray},
     },
-    common::CharacterSet,
-    BarcodeFormat, EncodeHintType, EncodeHintValue, Point,
+    common::{bit_matrix_test_case, CharacterSet},
+    BarcodeFormat, EncodeHintType, EncodeHintValue, Point, RXingResultMetadataType,
+    RXingResultMetadataValue, Reader,
 };
 
-use super::{encoder::aztec_encoder, AztecWriter};
+use super::{encoder::aztec_encoder, AztecReader, AztecWriter};
 
 use crate::Writer;
 
@@ -641,6 +642,57 @@ fn testBorderCompact4Case() {
     assert_eq!(4, aztecCode.getLayers());
 }
 
+#[test]
+fn testEncodeDecodeRune() {
+    for value in [0u8, 1, 25, 127, 128, 255] {
+        let aztec = aztec_encoder::encode_rune(value).expect("should encode");
+        assert!(aztec.isCompact());
+        assert_eq!(0, aztec.getLayers());
+        assert_eq!(11, aztec.getSize());
+        let matrix = aztec.getMatrix();
+        assert_eq!(11, matrix.getWidth());
+
+        let r = AztecDetectorRXingResult::new_rune(matrix.clone(), NO_POINTS, value);
+        let res = decoder::decode(&r).expect("should decode");
+        assert_eq!(format!("{value:03}"), res.getText());
+    }
+}
+
+#[test]
+fn testAztecWriterRune() {
+    let hints = HashMap::from([(EncodeHintType::AZTEC_RUNE, EncodeHintValue::AztecRune(true))]);
+    for value in [0u8, 42, 200, 255] {
+        let matrix = AztecWriter
+            .encode_with_hints(&value.to_string(), &BarcodeFormat::AZTEC, 60, 60, &hints)
+            .expect("should encode");
+        assert_eq!(60, matrix.getWidth());
+
+        let mut image = bit_matrix_test_case::matrix_to_binary_bitmap(&matrix);
+        let result = AztecReader.decode(&mut image).expect("should decode");
+        assert_eq!(format!("{value:03}"), result.getText());
+        assert_eq!(
+            Some(&RXingResultMetadataValue::AztecRuneValue(value as i32)),
+            result
+                .getRXingResultMetadata()
+                .get(&RXingResultMetadataType::AZTEC_RUNE_VALUE)
+        );
+        assert_eq!(
+            Some(&RXingResultMetadataValue::SymbologyIdentifier(
+                "]zC".to_owned()
+            )),
+            result
+                .getRXingResultMetadata()
+                .get(&RXingResultMetadataType::SYMBOLOGY_IDENTIFIER)
+        );
+    }
+
+    for invalid in ["256", "-1", "abc", ""] {
+        assert!(AztecWriter
+            .encode_with_hints(invalid, &BarcodeFormat::AZTEC, 0, 0, &hints)
+            .is_err());
+    }
+}
+
 // Helper routines
 
 fn testEncode(data: &str, compact: bool, layers: u32, expected: &str) {
@@ -37,6 +37,7 @@ pub struct AztecDetectorRXingResult {
     compact: bool,
     nbDatablocks: u32,
     nbLayers: u32,
+    runeValue: Option<u8>,
 }
 
 impl DetectorRXingResult for AztecDetectorRXingResult {
@@ -63,6 +64,22 @@ impl AztecDetectorRXingResult {
             compact,
             nbDatablocks,
             nbLayers,
+            runeValue: None,
+        }
+    }
+
+    /**
+     * Creates the result for an Aztec Rune, a compact symbol without data layers that carries
+     * a single value in its mode message.
+     */
+    pub fn new_rune(bits: BitMatrix, points: [Point; 4], runeValue: u8) -> Self {
+        Self {
+            bits,
+            points,
+            compact: true,
+            nbDatablocks: 0,
+            nbLayers: 0,
+            runeValue: Some(runeValue),
         }
     }
 
@@ -77,4 +94,11 @@ impl AztecDetectorRXingResult {
     pub fn isCompact(&self) -> bool {
         self.compact
     }
+
+    /**
+     * @return the value 0 to 255 if this is an Aztec Rune
+     */
+    pub fn getRuneValue(&self) -> Option<u8> {
+        self.runeValue
+    }
 }
@@ -120,13 +120,41 @@ impl Reader for AztecReader {
                 RXingResultMetadataValue::ErrorCorrectionLevel(ecLevel.to_owned()),
             );
         }
-        result.putMetadata(
-            RXingResultMetadataType::SYMBOLOGY_IDENTIFIER,
-            RXingResultMetadataValue::SymbologyIdentifier(format!(
-                "]z{}",
-                decoderRXingResult.getSymbologyModifier()
-            )),
-        );
+        let structuredAppend = decoderRXingResult.getStructuredAppend();
+        if structuredAppend.index >= 0 {
+            result.putMetadata(
+                RXingResultMetadataType::STRUCTURED_APPEND_SEQUENCE,
+                RXingResultMetadataValue::StructuredAppendSequence(structuredAppend.index),
+            );
+            result.putMetadata(
+                RXingResultMetadataType::STRUCTURED_APPEND_COUNT,
+                RXingResultMetadataValue::StructuredAppendCount(structuredAppend.count.max(0)),
+            );
+            if !structuredAppend.id.is_empty() {
+                result.putMetadata(
+                    RXingResultMetadataType::STRUCTURED_APPEND_ID,
+                    RXingResultMetadataValue::StructuredAppendId(structuredAppend.id.clone()),
+                );
+            }
+        }
+        if let Some(runeValue) = detectorRXingResult.getRuneValue() {
+            result.putMetadata(
+                RXingResultMetadataType::AZTEC_RUNE_VALUE,
+                RXingResultMetadataValue::AztecRuneValue(runeValue as i32),
+            );
+            result.putMetadata(
+                RXingResultMetadataType::SYMBOLOGY_IDENTIFIER,
+                RXingResultMetadataValue::SymbologyIdentifier("]zC".to_owned()),
+            );
+        } else {
+            result.putMetadata(
+                RXingResultMetadataType::SYMBOLOGY_IDENTIFIER,
+                RXingResultMetadataValue::SymbologyIdentifier(format!(
+                    "]z{}",
+                    decoderRXingResult.getSymbologyModifier()
+                )),
+            );
+        }
 
         Ok(result)
     }
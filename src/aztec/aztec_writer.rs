@@ -17,7 +17,7 @@
 use std::collections::HashMap;
 
 use crate::{
-    common::{BitMatrix, CharacterSet, Result},
+    common::{splitIntoSegments, BitMatrix, CharacterSet, Result},
     exceptions::Exceptions,
     BarcodeFormat, EncodeHintType, EncodeHintValue, Writer,
 };
@@ -50,7 +50,7 @@ impl Writer for AztecWriter {
         hints: &std::collections::HashMap<crate::EncodeHintType, crate::EncodeHintValue>,
     ) -> Result<crate::common::BitMatrix> {
         let mut charset = None; // Do not add any ECI code by default
-        let mut ecc_percent = aztec_encoder::DEFAULT_EC_PERCENT;
+        let ecc_percent = Self::ecc_percent(hints);
         let mut layers = aztec_encoder::DEFAULT_AZTEC_LAYERS;
         if let Some(EncodeHintValue::CharacterSet(cset_name)) =
             hints.get(&EncodeHintType::CHARACTER_SET)
@@ -59,16 +59,14 @@ impl Writer for AztecWriter {
                 charset = CharacterSet::get_character_set_by_name(cset_name);
             }
         }
-        if let Some(EncodeHintValue::ErrorCorrection(ecc_level)) =
-            hints.get(&EncodeHintType::ERROR_CORRECTION)
-        {
-            ecc_percent = ecc_level.parse().unwrap_or(23);
-        }
         if let Some(EncodeHintValue::AztecLayers(az_layers)) =
             hints.get(&EncodeHintType::AZTEC_LAYERS)
         {
             layers = *az_layers;
         }
+        if let Some(EncodeHintValue::AztecRune(true)) = hints.get(&EncodeHintType::AZTEC_RUNE) {
+            return encode_rune(contents, *format, width as u32, height as u32);
+        }
         encode(
             contents,
             *format,
@@ -81,6 +79,107 @@ impl Writer for AztecWriter {
     }
 }
 
+impl AztecWriter {
+    /**
+     * @return the minimal percentage of error check words given by
+     *  {@link EncodeHintType#ERROR_CORRECTION}, or the default if it isn't given or isn't a number
+     */
+    fn ecc_percent(hints: &crate::EncodingHintDictionary) -> u32 {
+        match hints.get(&EncodeHintType::ERROR_CORRECTION) {
+            Some(EncodeHintValue::ErrorCorrection(ecc_level)) => ecc_level
+                .parse()
+                .unwrap_or(aztec_encoder::DEFAULT_EC_PERCENT),
+            _ => aztec_encoder::DEFAULT_EC_PERCENT,
+        }
+    }
+
+    /**
+     * Splits the contents across as few Aztec symbols as the hints allow, up to 26 or
+     * {@link EncodeHintType#STRUCTURED_APPEND_MAX_SYMBOLS}. The size of the symbols can be
+     * fixed with {@link EncodeHintType#AZTEC_LAYERS}.
+     *
+     * Each symbol starts with a structured append header holding its position, the number of
+     * symbols and the message id of {@link EncodeHintType#STRUCTURED_APPEND_ID}, if given.
+     * Contents that fit into a single symbol are encoded without header.
+     *
+     * @return one bit matrix per symbol, in sequence order
+     */
+    pub fn encode_structured_append(
+        &self,
+        contents: &str,
+        width: i32,
+        height: i32,
+        hints: &crate::EncodingHintDictionary,
+    ) -> Result<Vec<BitMatrix>> {
+        if let Ok(single) =
+            self.encode_with_hints(contents, &BarcodeFormat::AZTEC, width, height, hints)
+        {
+            return Ok(vec![single]);
+        }
+
+        let mut charset = CharacterSet::ISO8859_1;
+        if let Some(EncodeHintValue::CharacterSet(cset_name)) =
+            hints.get(&EncodeHintType::CHARACTER_SET)
+        {
+            charset = CharacterSet::get_character_set_by_name(cset_name).ok_or_else(|| {
+                Exceptions::illegal_argument_with(format!("unsupported character set {cset_name}"))
+            })?;
+        }
+        let ecc_percent = Self::ecc_percent(hints);
+        let mut layers = aztec_encoder::DEFAULT_AZTEC_LAYERS;
+        if let Some(EncodeHintValue::AztecLayers(az_layers)) =
+            hints.get(&EncodeHintType::AZTEC_LAYERS)
+        {
+            layers = *az_layers;
+        }
+        let mut max_symbols = 26;
+        if let Some(EncodeHintValue::StructuredAppendMaxSymbols(max)) =
+            hints.get(&EncodeHintType::STRUCTURED_APPEND_MAX_SYMBOLS)
+        {
+            max_symbols = (*max).min(26);
+        }
+        let id = match hints.get(&EncodeHintType::STRUCTURED_APPEND_ID) {
+            Some(EncodeHintValue::StructuredAppendId(id)) => id.as_str(),
+            _ => "",
+        };
+
+        let encode_segment = |segment: &str, index: usize, count: usize| {
+            let bytes = charset.encode(segment)?;
+            if count == 1 {
+                return aztec_encoder::encode_bytes_with_charset(
+                    &bytes,
+                    ecc_percent,
+                    layers,
+                    charset,
+                );
+            }
+            aztec_encoder::encode_structured_append(
+                &bytes,
+                ecc_percent,
+                layers,
+                charset,
+                index as u32,
+                count as u32,
+                id,
+            )
+        };
+        let segments = splitIntoSegments(contents, max_symbols as usize, |segment| {
+            encode_segment(segment, 0, 26).is_ok()
+        })?;
+        segments
+            .iter()
+            .enumerate()
+            .map(|(index, segment)| {
+                renderRXingResult(
+                    &encode_segment(segment, index, segments.len())?,
+                    width as u32,
+                    height as u32,
+                )
+            })
+            .collect()
+    }
+}
+
 fn encode(
     contents: &str,
     format: BarcodeFormat,
@@ -104,6 +203,25 @@ fn encode(
     renderRXingResult(&aztec, width, height)
 }
 
+fn encode_rune(
+    contents: &str,
+    format: BarcodeFormat,
+    width: u32,
+    height: u32,
+) -> Result<BitMatrix> {
+    if format != BarcodeFormat::AZTEC {
+        return Err(Exceptions::illegal_argument_with(format!(
+            "can only encode AZTEC, but got {format:?}"
+        )));
+    }
+    let value = contents.parse::<u8>().map_err(|_| {
+        Exceptions::illegal_argument_with(format!(
+            "an Aztec Rune encodes a value from 0 to 255, but got '{contents}'"
+        ))
+    })?;
+    renderRXingResult(&aztec_encoder::encode_rune(value)?, width, height)
+}
+
 fn renderRXingResult(code: &AztecCode, width: u32, height: u32) -> Result<BitMatrix> {
     let input = code.getMatrix();
 
@@ -16,6 +16,7 @@
 
 use crate::{
     common::{
+        cpp_essentials::StructuredAppendInfo,
         reedsolomon::{
             get_predefined_genericgf, GenericGFRef, PredefinedGenericGF, ReedSolomonDecoder,
         },
@@ -74,28 +75,93 @@ const DIGIT_TABLE: [&str; 16] = [
 //   private AztecDetectorRXingResult ddata;
 
 pub fn decode(detectorRXingResult: &AztecDetectorRXingResult) -> Result<DecoderRXingResult> {
+    if let Some(runeValue) = detectorRXingResult.getRuneValue() {
+        return Ok(decodeRune(runeValue));
+    }
     //let mut detectorRXingResult = detectorRXingResult.clone();
     let matrix = detectorRXingResult.getBits();
     let rawbits = extract_bits(detectorRXingResult, matrix);
     let corrected_bits = correct_bits(detectorRXingResult, &rawbits)?;
     let raw_bytes = convertBoolArrayToByteArray(&corrected_bits.correct_bits);
-    let result = get_encoded_data(&corrected_bits.correct_bits);
+    let mut result = get_encoded_data(&corrected_bits.correct_bits)?;
+    let structured_append = get_structured_append(&corrected_bits.correct_bits, &mut result);
     let mut decoder_rxing_result = DecoderRXingResult::new(
         raw_bytes,
-        result?,
+        result,
         Vec::new(),
         format!("{}%", corrected_bits.ec_level),
     );
     decoder_rxing_result.setNumBits(corrected_bits.correct_bits.len());
+    decoder_rxing_result.setStructuredAppend(structured_append);
 
     Ok(decoder_rxing_result)
 }
 
+/**
+ * An Aztec Rune carries no data layers, its value is reported as three decimal digits
+ * as the specification asks for.
+ */
+fn decodeRune(runeValue: u8) -> DecoderRXingResult {
+    let mut decoder_rxing_result = DecoderRXingResult::new(
+        vec![runeValue],
+        format!("{runeValue:03}"),
+        Vec::new(),
+        String::new(),
+    );
+    decoder_rxing_result.setNumBits(8);
+    decoder_rxing_result
+}
+
 /// This method is used for testing the high-level encoder
 pub fn highLevelDecode(correctedBits: &[bool]) -> Result<String> {
     get_encoded_data(correctedBits)
 }
 
+/**
+ * Reads the structured append header, ISO/IEC 24778:2008 8.3, and removes it from the decoded
+ * text. The header is flagged by a latch to Mixed and straight back to Upper at the very start
+ * of the data. An optional message id between spaces follows, then the position and the count
+ * of the symbol as the letters 'A' to 'Z'.
+ *
+ * @return the structured append info, with an index of -1 if there is no header, or if it does
+ * not parse and the data is to be read as it is
+ */
+pub(super) fn get_structured_append(
+    corrected_bits: &[bool],
+    text: &mut String,
+) -> StructuredAppendInfo {
+    let mut sai = StructuredAppendInfo::default();
+    // M/L in Upper, then U/L in Mixed
+    if corrected_bits.len() <= 20
+        || read_code(corrected_bits, 0, 5) != 29
+        || read_code(corrected_bits, 5, 5) != 29
+    {
+        return sai;
+    }
+
+    let mut pos = 0;
+    if let Some(rest) = text.strip_prefix(' ') {
+        let Some(end) = rest.find(' ') else {
+            return StructuredAppendInfo::default();
+        };
+        sai.id = rest[..end].to_owned();
+        pos = end + 2;
+    }
+    let letters = text[pos..].chars().take(2).collect::<Vec<_>>();
+    if letters.len() != 2 || !letters.iter().all(|c| c.is_ascii_uppercase()) {
+        return StructuredAppendInfo::default();
+    }
+    sai.index = (letters[0] as u8 - b'A') as i32;
+    sai.count = (letters[1] as u8 - b'A') as i32 + 1;
+    if sai.count == 1 || sai.count <= sai.index {
+        // If info doesn't make sense, choose to mark count as unknown
+        sai.count = 0;
+    }
+    text.replace_range(..pos + 2, "");
+
+    sai
+}
+
 /**
  * Gets the string encoded in the aztec code bits
  *
@@ -33,6 +33,9 @@ const EXPECTED_CORNER_BITS: [u32; 4] = [
     0x707, // 03407 .XX X.. ... XXX
 ];
 
+// Aztec Runes invert every other bit of the 28 bit mode message, starting with the first one
+const RUNE_MODE_MESSAGE_MASK: u64 = 0xAAAAAAA;
+
 /**
  * Encapsulates logic that can detect an Aztec Code in an image, even if the Aztec Code
  * is rotated or skewed, or partially obscured.
@@ -48,6 +51,7 @@ pub struct Detector<'a> {
     nb_data_blocks: u32,
     nb_center_layers: u32,
     shift: u32,
+    rune_value: Option<u8>,
 }
 
 impl<'a> Detector<'_> {
@@ -59,6 +63,7 @@ impl<'a> Detector<'_> {
             nb_data_blocks: 0,
             nb_center_layers: 0,
             shift: 0,
+            rune_value: None,
         }
     }
 
@@ -102,6 +107,12 @@ impl<'a> Detector<'_> {
         // 5. Get the corners of the matrix.
         let corners = self.get_matrix_corner_points(&bulls_eye_corners);
 
+        if let Some(rune_value) = self.rune_value {
+            return Ok(AztecDetectorRXingResult::new_rune(
+                bits, corners, rune_value,
+            ));
+        }
+
         Ok(AztecDetectorRXingResult::new(
             bits,
             corners,
@@ -158,7 +169,24 @@ impl<'a> Detector<'_> {
 
         // Corrects parameter data using RS.  Returns just the data portion
         // without the error correction.
-        let corrected_data = Self::get_corrected_parameter_data(parameter_data, self.compact)?;
+        self.rune_value = None;
+        let corrected_data = match Self::get_corrected_parameter_data(parameter_data, self.compact)
+        {
+            Ok(corrected_data) => corrected_data,
+            Err(err) if self.compact => {
+                // An Aztec Rune has no data layers, its mode message holds a single byte
+                let rune_value = Self::get_corrected_parameter_data(
+                    parameter_data ^ RUNE_MODE_MESSAGE_MASK,
+                    true,
+                )
+                .map_err(|_| err)?;
+                self.rune_value = Some(rune_value as u8);
+                self.nb_layers = 0;
+                self.nb_data_blocks = 0;
+                return Ok(());
+            }
+            Err(err) => return Err(err),
+        };
 
         if self.compact {
             // 8 bits:  2 bits layers and 6 bits data blocks
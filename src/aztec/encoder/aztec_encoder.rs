@@ -155,6 +155,66 @@ pub fn encode_bytes_with_charset(
     // High-level encode
     let bits = HighLevelEncoder::with_charset(data.into(), charset).encode()?;
 
+    encode_bits(&bits, min_eccpercent, user_specified_layers)
+}
+
+/**
+ * Encodes the given binary content as one symbol of a structured append sequence. The symbol
+ * starts with a header of the optional message id, its position and the number of symbols.
+ *
+ * @param data input data string
+ * @param minECCPercent minimal percentage of error check words (According to ISO/IEC 24778:2008,
+ *                      a minimum of 23% + 3 words is recommended)
+ * @param userSpecifiedLayers if non-zero, a user-specified value for the number of layers
+ * @param charset character set to mark using ECI, none is inserted for ISO/IEC 8859-1
+ * @param index the 0-based position of the symbol in the sequence
+ * @param count the number of symbols in the sequence, 2 to 26
+ * @param id the message id of upper case letters, may be empty
+ * @return Aztec symbol matrix with metadata
+ */
+pub fn encode_structured_append(
+    data: &[u8],
+    min_eccpercent: u32,
+    user_specified_layers: i32,
+    charset: CharacterSet,
+    index: u32,
+    count: u32,
+    id: &str,
+) -> Result<AztecCode> {
+    if !(2..=26).contains(&count) || index >= count {
+        return Err(Exceptions::illegal_argument_with(format!(
+            "invalid structured append position {index} of {count}"
+        )));
+    }
+    if !id.bytes().all(|c| c.is_ascii_uppercase()) {
+        return Err(Exceptions::illegal_argument_with(format!(
+            "structured append id '{id}' must be upper case letters"
+        )));
+    }
+
+    // M/L, U/L, then the header in upper case, where space is 1 and A to Z are 2 to 27
+    let mut bits = BitArray::new();
+    bits.appendBits(29, 5)?;
+    bits.appendBits(29, 5)?;
+    if !id.is_empty() {
+        bits.appendBits(1, 5)?;
+        for c in id.bytes() {
+            bits.appendBits((c - b'A') as u32 + 2, 5)?;
+        }
+        bits.appendBits(1, 5)?;
+    }
+    bits.appendBits(index + 2, 5)?;
+    bits.appendBits(count + 1, 5)?;
+    bits.appendBitArray(HighLevelEncoder::with_charset(data.into(), charset).encode()?);
+
+    encode_bits(&bits, min_eccpercent, user_specified_layers)
+}
+
+fn encode_bits(
+    bits: &BitArray,
+    min_eccpercent: u32,
+    user_specified_layers: i32,
+) -> Result<AztecCode> {
     // stuff bits and choose symbol size
     let ecc_bits = bits.get_size() as u32 * min_eccpercent / 100 + 11;
     let total_size_bits = bits.get_size() as u32 + ecc_bits;
@@ -180,7 +240,7 @@ pub fn encode_bytes_with_charset(
         total_bits_in_layer_var = total_bits_in_layer(layers, compact);
         word_size = WORD_SIZE[layers as usize];
         let usable_bits_in_layers = total_bits_in_layer_var - (total_bits_in_layer_var % word_size);
-        stuffed_bits = stuffBits(&bits, word_size as usize)?;
+        stuffed_bits = stuffBits(bits, word_size as usize)?;
         if stuffed_bits.get_size() as u32 + ecc_bits > usable_bits_in_layers {
             return Err(Exceptions::illegal_argument_with(
                 "Data to large for user specified layer",
@@ -217,7 +277,7 @@ pub fn encode_bytes_with_charset(
             // wordSize has changed
             if stuffed_bits.get_size() == 0 || word_size != WORD_SIZE[layers as usize] {
                 word_size = WORD_SIZE[layers as usize];
-                stuffed_bits = stuffBits(&bits, word_size as usize)?;
+                stuffed_bits = stuffBits(bits, word_size as usize)?;
             }
             let usable_bits_in_layers =
                 total_bits_in_layer_var - (total_bits_in_layer_var % word_size);
@@ -348,6 +408,29 @@ pub fn encode_bytes_with_charset(
     Ok(aztec)
 }
 
+/**
+ * Encodes the given value as an Aztec Rune, a compact symbol of 11x11 modules without data layers
+ *
+ * @param value the value to encode
+ * @return Aztec symbol matrix with metadata
+ */
+pub fn encode_rune(value: u8) -> Result<AztecCode> {
+    let mut mode_message = BitArray::new();
+    mode_message.appendBits(value as u32, 8)?;
+    let mut mode_message = generateCheckWords(&mode_message, 28, 4)?;
+    // runes invert every other bit of the mode message, starting with the first one
+    for i in (0..28).step_by(2) {
+        mode_message.flip(i);
+    }
+
+    let matrixSize = 11;
+    let mut matrix = BitMatrix::with_single_dimension(matrixSize)?;
+    drawModeMessage(&mut matrix, true, matrixSize, mode_message);
+    drawBullsEye(&mut matrix, matrixSize / 2, 5);
+
+    Ok(AztecCode::new(true, matrixSize, 0, 0, matrix))
+}
+
 fn drawBullsEye(matrix: &mut BitMatrix, center: u32, size: u32) {
     let mut i = 0;
     while i < size {
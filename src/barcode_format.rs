@@ -29,12 +29,27 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 pub enum BarcodeFormat {
+    /** Australia Post 4-state postal format. */
+    AUSTRALIA_POST,
+
     /** Aztec 2D barcode format. */
     AZTEC,
 
     /** CODABAR 1D format. */
     CODABAR,
 
+    /** Codablock F stacked format, rows of Code 128 characters. */
+    CODABLOCK_F,
+
+    /** Code 11 (USD-8) 1D format. */
+    CODE_11,
+
+    /** Code 16K stacked format, rows of Code 128 characters. */
+    CODE_16K,
+
+    /** Code 32 (Italian Pharmacode) 1D format, carried by Code 39. */
+    CODE_32,
+
     /** Code 39 1D format. */
     CODE_39,
 
@@ -44,35 +59,83 @@ pub enum BarcodeFormat {
     /** Code 128 1D format. */
     CODE_128,
 
+    /** Datalogic 2 of 5 (China Post) 1D format. */
+    DATALOGIC_2_OF_5,
+
     /** Data Matrix 2D barcode format. */
     DATA_MATRIX,
 
+    /** DotCode 2D barcode format. */
+    DOT_CODE,
+
     /** EAN-8 1D format. */
     EAN_8,
 
     /** EAN-13 1D format. */
     EAN_13,
 
+    /** Han Xin Code 2D barcode format. */
+    HAN_XIN,
+
+    /** IATA 2 of 5 (airline baggage) 1D format. */
+    IATA_2_OF_5,
+
+    /** Industrial (Standard) 2 of 5 1D format. */
+    INDUSTRIAL_2_OF_5,
+
     /** ITF (Interleaved Two of Five) 1D format. */
     ITF,
 
+    /** KIX (Dutch Klantenindex) 4-state postal format. */
+    KIX,
+
+    /** Matrix 2 of 5 1D format. */
+    MATRIX_2_OF_5,
+
     /** MaxiCode 2D barcode format. */
     MAXICODE,
 
+    /** MicroPDF417 format. */
+    MICRO_PDF_417,
+
+    /** MSI Plessey 1D format. */
+    MSI,
+
     /** PDF417 format. */
     PDF_417,
 
+    /** Laetus Pharmacode 1D format. */
+    PHARMACODE,
+
+    /** USPS PLANET 2-state postal format. */
+    PLANET,
+
+    /** USPS POSTNET 2-state postal format. */
+    POSTNET,
+
     /** QR Code 2D barcode format. */
     QR_CODE,
 
     MICRO_QR_CODE,
 
+    /** Rectangular Micro QR Code (rMQR) 2D barcode format. */
+    RECTANGULAR_MICRO_QR_CODE,
+
+    /** Royal Mail 4-State Customer Code (RM4SCC) postal format. */
+    RM4SCC,
+
     /** RSS 14 */
     RSS_14,
 
     /** RSS EXPANDED */
     RSS_EXPANDED,
 
+    /** RSS LIMITED (GS1 DataBar Limited) */
+    RSS_LIMITED,
+
+    /** Telepen 1D format. */
+    TELEPEN,
+
     /** UPC-A 1D format. */
     UPC_A,
 
@@ -82,6 +145,9 @@ pub enum BarcodeFormat {
     /** UPC/EAN extension format. Not a stand-alone format. */
     UPC_EAN_EXTENSION,
 
+    /** USPS Intelligent Mail 4-state postal format. */
+    USPS_INTELLIGENT_MAIL,
+
     ///
     UNSUPORTED_FORMAT,
 }
@@ -92,24 +158,46 @@ impl Display for BarcodeFormat {
             f,
             "{}",
             match self {
+                BarcodeFormat::AUSTRALIA_POST => "australia post",
                 BarcodeFormat::AZTEC => "aztec",
                 BarcodeFormat::CODABAR => "codabar",
+                BarcodeFormat::CODABLOCK_F => "codablock f",
+                BarcodeFormat::CODE_11 => "code 11",
+                BarcodeFormat::CODE_16K => "code 16k",
+                BarcodeFormat::CODE_32 => "code 32",
                 BarcodeFormat::CODE_39 => "code 39",
                 BarcodeFormat::CODE_93 => "code 93",
                 BarcodeFormat::CODE_128 => "code 128",
+                BarcodeFormat::DATALOGIC_2_OF_5 => "datalogic 2 of 5",
                 BarcodeFormat::DATA_MATRIX => "datamatrix",
+                BarcodeFormat::DOT_CODE => "dotcode",
                 BarcodeFormat::EAN_8 => "ean 8",
                 BarcodeFormat::EAN_13 => "ean 13",
+                BarcodeFormat::HAN_XIN => "han xin",
+                BarcodeFormat::IATA_2_OF_5 => "iata 2 of 5",
+                BarcodeFormat::INDUSTRIAL_2_OF_5 => "industrial 2 of 5",
                 BarcodeFormat::ITF => "itf",
+                BarcodeFormat::KIX => "kix",
+                BarcodeFormat::MATRIX_2_OF_5 => "matrix 2 of 5",
                 BarcodeFormat::MAXICODE => "maxicode",
+                BarcodeFormat::MICRO_PDF_417 => "micro pdf 417",
+                BarcodeFormat::MSI => "msi",
                 BarcodeFormat::PDF_417 => "pdf 417",
+                BarcodeFormat::PHARMACODE => "pharmacode",
+                BarcodeFormat::PLANET => "planet",
+                BarcodeFormat::POSTNET => "postnet",
                 BarcodeFormat::QR_CODE => "qrcode",
                 BarcodeFormat::MICRO_QR_CODE => "mqr",
+                BarcodeFormat::RECTANGULAR_MICRO_QR_CODE => "rmqr",
+                BarcodeFormat::RM4SCC => "rm4scc",
                 BarcodeFormat::RSS_14 => "rss 14",
                 BarcodeFormat::RSS_EXPANDED => "rss expanded",
+                BarcodeFormat::RSS_LIMITED => "rss limited",
+                BarcodeFormat::TELEPEN => "telepen",
                 BarcodeFormat::UPC_A => "upc a",
                 BarcodeFormat::UPC_E => "upc e",
                 BarcodeFormat::UPC_EAN_EXTENSION => "upc/ean extension",
+                BarcodeFormat::USPS_INTELLIGENT_MAIL => "usps intelligent mail",
                 _ => "unsuported",
             }
         )
@@ -125,8 +213,15 @@ impl From<String> for BarcodeFormat {
 impl From<&str> for BarcodeFormat {
     fn from(value: &str) -> Self {
         match value.to_lowercase().as_str() {
+            "australia post" | "australia_post" | "auspost" => BarcodeFormat::AUSTRALIA_POST,
             "aztec" | "aztec code" | "aztec_code" => BarcodeFormat::AZTEC,
             "codabar" | "coda" | "coda_bar" | "cod_a_bar" | "cod_a" => BarcodeFormat::CODABAR,
+            "codablock f" | "codablock_f" | "codablockf" | "codablock" => {
+                BarcodeFormat::CODABLOCK_F
+            }
+            "code 11" | "code_11" | "code11" | "usd-8" | "usd 8" => BarcodeFormat::CODE_11,
+            "code 16k" | "code_16k" | "code16k" => BarcodeFormat::CODE_16K,
+            "code 32" | "code_32" | "code32" | "italian pharmacode" => BarcodeFormat::CODE_32,
             "code 39" | "code_39" | "code39" | "alpha39" | "code_3_of_9" | "uss_39" | "usd-3" => {
                 BarcodeFormat::CODE_39
             }
@@ -134,25 +229,56 @@ impl From<&str> for BarcodeFormat {
             "code 128" | "code_129" | "code128" | "iso/ied 15417:2007" | "iso/_15417:2007" => {
                 BarcodeFormat::CODE_128
             }
+            "datalogic 2 of 5" | "datalogic_2_of_5" | "china post" => {
+                BarcodeFormat::DATALOGIC_2_OF_5
+            }
             "datamatrix" | "data matrix" | "data_matrix" => BarcodeFormat::DATA_MATRIX,
+            "dotcode" | "dot code" | "dot_code" => BarcodeFormat::DOT_CODE,
             "ean 8" | "ean_8" | "ean8" => BarcodeFormat::EAN_8,
             "ean 13" | "ean_13" | "ean13" => BarcodeFormat::EAN_13,
+            "han xin" | "han_xin" | "hanxin" | "chinese sensible" => BarcodeFormat::HAN_XIN,
+            "iata 2 of 5" | "iata_2_of_5" | "iata" => BarcodeFormat::IATA_2_OF_5,
+            "industrial 2 of 5" | "industrial_2_of_5" | "standard 2 of 5" | "standard_2_of_5" => {
+                BarcodeFormat::INDUSTRIAL_2_OF_5
+            }
             "itf" | "itf_code" | "itf14" | "itf 14" | "itf_14" | "interleaved 2 of 5" => {
                 BarcodeFormat::ITF
             }
+            "kix" | "kix code" | "kix_code" => BarcodeFormat::KIX,
+            "matrix 2 of 5" | "matrix_2_of_5" => BarcodeFormat::MATRIX_2_OF_5,
             "maxicode" | "maxi_code" => BarcodeFormat::MAXICODE,
+            "micro pdf 417" | "micro_pdf_417" | "micropdf417" | "micropdf" | "iso 24728"
+            | "iso_24728" => BarcodeFormat::MICRO_PDF_417,
+            "msi" | "msi plessey" | "msi_plessey" | "modified plessey" => BarcodeFormat::MSI,
             "pdf 417" | "pdf_417" | "pdf417" | "iso 15438" | "iso_15438" => BarcodeFormat::PDF_417,
+            "pharmacode" | "pharma" | "laetus pharmacode" => BarcodeFormat::PHARMACODE,
+            "planet" | "usps planet" | "usps_planet" => BarcodeFormat::PLANET,
+            "postnet" | "usps postnet" | "usps_postnet" => BarcodeFormat::POSTNET,
             "qrcode" | "qr_code" | "qr code" => BarcodeFormat::QR_CODE,
             "mqr" | "microqr" | "micro_qr" | "micro_qrcode" | "micro_qr_code" | "mqr_code" => {
                 BarcodeFormat::MICRO_QR_CODE
             }
+            "rmqr"
+            | "rmqr_code"
+            | "rectangular_micro_qr"
+            | "rectangular micro qr code"
+            | "rectangular_micro_qr_code" => BarcodeFormat::RECTANGULAR_MICRO_QR_CODE,
+            "rm4scc" | "royal mail" | "royal_mail" | "royal mail 4-state"
+            | "royal_mail_4_state" => BarcodeFormat::RM4SCC,
             "rss 14" | "rss_14" | "rss14" | "gs1 databar" | "gs1 databar coupon"
             | "gs1_databar_coupon" => BarcodeFormat::RSS_14,
             "rss expanded" | "expanded rss" | "rss_expanded" => BarcodeFormat::RSS_EXPANDED,
+            "rss limited" | "rss_limited" | "gs1 databar limited" | "gs1_databar_limited" => {
+                BarcodeFormat::RSS_LIMITED
+            }
+            "telepen" | "telepen ascii" | "telepen numeric" => BarcodeFormat::TELEPEN,
             "upc a" | "upc_a" | "upca" => BarcodeFormat::UPC_A,
             "upc e" | "upc_e" | "upce" => BarcodeFormat::UPC_E,
             "upc ean extension" | "upc extension" | "ean extension" | "upc/ean extension"
             | "upc_ean_extension" => BarcodeFormat::UPC_EAN_EXTENSION,
+            "usps intelligent mail" | "usps_intelligent_mail" | "intelligent mail" | "imb" => {
+                BarcodeFormat::USPS_INTELLIGENT_MAIL
+            }
             _ => BarcodeFormat::UNSUPORTED_FORMAT,
         }
     }
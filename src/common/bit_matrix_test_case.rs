@@ -27,9 +27,9 @@
 //  */
 // public final class BitMatrixTestCase extends Assert {
 
-use crate::point_f;
+use crate::{point_f, BinaryBitmap, Luma8LuminanceSource};
 
-use super::BitMatrix;
+use super::{BitMatrix, HybridBinarizer};
 
 static BIT_MATRIX_POINTS: [u32; 6] = [1, 2, 2, 0, 3, 1];
 
@@ -354,6 +354,23 @@ pub fn matrix_to_string(result: &BitMatrix) -> String {
     builder
 }
 
+/**
+ * Renders the matrix as an image to read, one pixel per module, the set modules black.
+ */
+pub fn matrix_to_binary_bitmap(
+    matrix: &BitMatrix,
+) -> BinaryBitmap<HybridBinarizer<Luma8LuminanceSource>> {
+    let luma = (0..matrix.getHeight())
+        .flat_map(|y| (0..matrix.getWidth()).map(move |x| (x, y)))
+        .map(|(x, y)| if matrix.get(x, y) { 0 } else { u8::MAX })
+        .collect();
+    BinaryBitmap::new(HybridBinarizer::new(Luma8LuminanceSource::new(
+        luma,
+        matrix.getWidth(),
+        matrix.getHeight(),
+    )))
+}
+
 fn test_XOR(dataMatrix: &BitMatrix, flipMatrix: &BitMatrix, expectedMatrix: &BitMatrix) {
     let mut matrix = dataMatrix.clone();
     matrix.xor(flipMatrix).expect("must set");
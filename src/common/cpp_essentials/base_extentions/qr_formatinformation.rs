@@ -1,7 +1,11 @@
 use crate::qrcode::decoder::{
-    ErrorCorrectionLevel, FormatInformation, FORMAT_INFO_DECODE_LOOKUP, FORMAT_INFO_MASK_QR,
+    ErrorCorrectionLevel, FormatInformation, FORMAT_INFO_DECODE_LOOKUP, FORMAT_INFO_MASKS_RMQR,
+    FORMAT_INFO_MASK_QR, FORMAT_INFO_MASK_QR_MODEL1, FORMAT_INFO_POLY_RMQR,
 };
 
+/// Model 1 format information read against the Model 2 lookup table
+const FORMAT_INFO_MASK_MODEL1_OVER_QR: u32 = FORMAT_INFO_MASK_QR ^ FORMAT_INFO_MASK_QR_MODEL1;
+
 pub const FORMAT_INFO_DECODE_LOOKUP_MICRO: [[u32; 2]; 32] = [
     [0x4445, 0x00],
     [0x4172, 0x01],
@@ -43,6 +47,26 @@ impl FormatInformation {
      * @param formatInfoBits2 second copy of same info; both are checked at the same time to establish best match
      */
     pub fn DecodeQR(formatInfoBits1: u32, formatInfoBits2: u32) -> Self {
+        // Some QR codes apparently do not apply the XOR mask. Try without and with additional masking.
+        Self::DecodeQRWithMasks(formatInfoBits1, formatInfoBits2, &[0, FORMAT_INFO_MASK_QR])
+    }
+
+    /**
+     * Decodes the format information of a QR Code Model 1 symbol. It is tried separately from
+     * {@link #DecodeQR}, as its codes are only 5 bits apart from the ones of Model 2.
+     *
+     * @param formatInfoBits1 format info indicator, with mask still applied
+     * @param formatInfoBits2 second copy of same info
+     */
+    pub fn DecodeQRModel1(formatInfoBits1: u32, formatInfoBits2: u32) -> Self {
+        Self::DecodeQRWithMasks(
+            formatInfoBits1,
+            formatInfoBits2,
+            &[FORMAT_INFO_MASK_MODEL1_OVER_QR],
+        )
+    }
+
+    fn DecodeQRWithMasks(formatInfoBits1: u32, formatInfoBits2: u32, masks: &[u32]) -> Self {
         // maks out the 'Dark Module' for mirrored and non-mirrored case (see Figure 25 in ISO/IEC 18004:2015)
         let mirroredFormatInfoBits2 = Self::MirrorBits(
             ((formatInfoBits2 >> 1) & 0b111111110000000) | (formatInfoBits2 & 0b1111111),
@@ -50,7 +74,7 @@ impl FormatInformation {
         let formatInfoBits2 =
             ((formatInfoBits2 >> 1) & 0b111111100000000) | (formatInfoBits2 & 0b11111111);
         let mut fi = Self::FindBestFormatInfo(
-            FORMAT_INFO_MASK_QR,
+            masks,
             FORMAT_INFO_DECODE_LOOKUP,
             &[
                 formatInfoBits1,
@@ -72,7 +96,7 @@ impl FormatInformation {
     pub fn DecodeMQR(formatInfoBits: u32) -> Self {
         // We don't use the additional masking (with 0x4445) to work around potentially non complying MicroQRCode encoders
         let mut fi = Self::FindBestFormatInfo(
-            0,
+            &[0],
             FORMAT_INFO_DECODE_LOOKUP_MICRO,
             &[formatInfoBits, Self::MirrorBits(formatInfoBits)],
         );
@@ -89,16 +113,64 @@ impl FormatInformation {
         fi
     }
 
+    /**
+     * @param formatInfoBits1 format info next to the finder pattern, with mask still applied
+     * @param formatInfoBits2 format info next to the finder sub pattern, with mask still applied
+     */
+    pub fn DecodeRMQR(formatInfoBits1: u32, formatInfoBits2: u32) -> Self {
+        let mut fi = FormatInformation::default();
+
+        // a missing second copy is passed as 0
+        let copies = if formatInfoBits2 != 0 { 2 } else { 1 };
+        for data in 0..64 {
+            let pattern = Self::EncodeRMQR(data);
+            for (bitsIndex, bits) in [formatInfoBits1, formatInfoBits2]
+                .into_iter()
+                .take(copies)
+                .enumerate()
+            {
+                let hammingDist = (bits ^ FORMAT_INFO_MASKS_RMQR[bitsIndex] ^ pattern).count_ones();
+                if hammingDist < fi.hammingDistance {
+                    fi.index = data as u8;
+                    fi.hammingDistance = hammingDist;
+                    fi.bitsIndex = bitsIndex as u8;
+                }
+            }
+        }
+
+        // bit 5 selects M or H, the remaining bits are the version
+        fi.error_correction_level =
+            ErrorCorrectionLevel::ECLevelFromBits(((fi.index >> 5) & 1) << 1, false);
+        fi.data_mask = 4;
+        fi.microVersion = (fi.index & 0x1F) as u32 + 1;
+
+        fi
+    }
+
+    /**
+     * @param data the 6 bits of rMQR format data
+     * @return the unmasked 18 bit format information for these bits
+     */
+    pub fn EncodeRMQR(data: u32) -> u32 {
+        let mut value = data << 12;
+        while value >= 1 << 12 {
+            value ^= FORMAT_INFO_POLY_RMQR << (31 - value.leading_zeros() - 12);
+        }
+        (data << 12) | value
+    }
+
     #[inline(always)]
     pub fn MirrorBits(bits: u32) -> u32 {
         (bits.reverse_bits()) >> 17
     }
 
-    pub fn FindBestFormatInfo(mask: u32, lookup: [[u32; 2]; 32], bits: &[u32]) -> Self {
+    /**
+     * @param masks the additional masks to try on top of the one the lookup table is masked with
+     */
+    pub fn FindBestFormatInfo(masks: &[u32], lookup: [[u32; 2]; 32], bits: &[u32]) -> Self {
         let mut fi = FormatInformation::default();
 
-        // Some QR codes apparently do not apply the XOR mask. Try without and with additional masking.
-        for mask in [0, mask] {
+        for &mask in masks {
             // for (auto mask : {0, mask})
             for (bitsIndex, bit_set) in bits.iter().enumerate() {
                 // for (int bitsIndex = 0; bitsIndex < Size(bits); ++bitsIndex)
@@ -111,6 +183,7 @@ impl FormatInformation {
                         fi.index = index as u8;
                         fi.hammingDistance = hammingDist;
                         fi.bitsIndex = bitsIndex as u8;
+                        fi.mask = mask;
                     }
                 }
             }
@@ -119,6 +192,13 @@ impl FormatInformation {
         fi
     }
 
+    /**
+     * @return whether the format information of a QR Code is masked like that of Model 1 symbols
+     */
+    pub fn isModel1(&self) -> bool {
+        self.mask == FORMAT_INFO_MASK_MODEL1_OVER_QR
+    }
+
     // Hamming distance of the 32 masked codes is 7, by construction, so <= 3 bits differing means we found a match
     pub fn isValid(&self) -> bool {
         self.hammingDistance <= 3
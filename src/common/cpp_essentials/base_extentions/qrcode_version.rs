@@ -1,5 +1,8 @@
 use crate::common::Result;
-use crate::qrcode::decoder::{Version, VersionRef, MICRO_VERSIONS, VERSIONS, VERSION_DECODE_INFO};
+use crate::qrcode::decoder::{
+    Version, VersionRef, MICRO_VERSIONS, MODEL1_VERSIONS, RMQR_SIZES, RMQR_VERSIONS, VERSIONS,
+    VERSION_DECODE_INFO,
+};
 use crate::Exceptions;
 
 // const Version* Version::AllMicroVersions()
@@ -92,4 +95,51 @@ impl Version {
     pub const fn isMicroQRCode(&self) -> bool {
         self.is_micro
     }
+
+    pub const fn isRMQRCode(&self) -> bool {
+        self.is_rmqr
+    }
+
+    pub fn FromNumberRMQR(versionNumber: u32) -> Result<VersionRef> {
+        if !(1..=32).contains(&versionNumber) {
+            return Err(Exceptions::ILLEGAL_ARGUMENT);
+        }
+
+        Ok(&RMQR_VERSIONS[versionNumber as usize - 1])
+    }
+
+    pub fn FromDimensionsRMQR(width: u32, height: u32) -> Result<VersionRef> {
+        let index = RMQR_SIZES
+            .iter()
+            .position(|&size| size == (width, height))
+            .ok_or(Exceptions::ILLEGAL_ARGUMENT)?;
+        Self::FromNumberRMQR(index as u32 + 1)
+    }
+
+    /// Returns the (width, height) of an rMQR symbol in modules
+    pub fn DimensionOfVersionRMQR(version: u32) -> (u32, u32) {
+        RMQR_SIZES[version as usize - 1]
+    }
+
+    pub const fn isModel1(&self) -> bool {
+        self.is_model1
+    }
+
+    pub fn FromNumberModel1(versionNumber: u32) -> Result<VersionRef> {
+        if !(1..=14).contains(&versionNumber) {
+            return Err(Exceptions::ILLEGAL_ARGUMENT);
+        }
+
+        Ok(&MODEL1_VERSIONS[versionNumber as usize - 1])
+    }
+
+    /// Model 1 symbols carry no version information, their size alone tells the version
+    pub fn FromDimensionModel1(dimension: u32) -> Result<VersionRef> {
+        if dimension % Self::DimensionStep(false) != 1 || dimension < Self::DimensionOffset(false) {
+            return Err(Exceptions::ILLEGAL_ARGUMENT);
+        }
+        Self::FromNumberModel1(
+            (dimension - Self::DimensionOffset(false)) / Self::DimensionStep(false),
+        )
+    }
 }
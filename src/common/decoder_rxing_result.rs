@@ -20,6 +20,8 @@
 
 use std::{any::Any, rc::Rc};
 
+use super::cpp_essentials::StructuredAppendInfo;
+
 /**
  * <p>Encapsulates the result of decoding a matrix of bits. This typically
  * applies to 2D barcode formats. For now it contains the raw bytes obtained,
@@ -38,6 +40,7 @@ pub struct DecoderRXingResult {
     other: Option<Rc<dyn Any>>,
     structuredAppendParity: i32,
     structuredAppendSequenceNumber: i32,
+    structuredAppend: StructuredAppendInfo,
     symbologyModifier: u32,
     contentType: String,
     isMirrored: bool,
@@ -128,6 +131,7 @@ impl DecoderRXingResult {
             other: None,
             structuredAppendParity: saParity,
             structuredAppendSequenceNumber: saSequence,
+            structuredAppend: StructuredAppendInfo::default(),
             symbologyModifier,
             contentType,
             isMirrored,
@@ -223,6 +227,18 @@ impl DecoderRXingResult {
         self.structuredAppendSequenceNumber
     }
 
+    /**
+     * @return the position, count and file id of the symbol in a structured append sequence, for
+     *  formats other than QR Code. The index is -1 if the symbol is not part of one.
+     */
+    pub fn getStructuredAppend(&self) -> &StructuredAppendInfo {
+        &self.structuredAppend
+    }
+
+    pub fn setStructuredAppend(&mut self, structuredAppend: StructuredAppendInfo) {
+        self.structuredAppend = structuredAppend;
+    }
+
     pub fn getSymbologyModifier(&self) -> u32 {
         self.symbologyModifier
     }
@@ -112,6 +112,9 @@ pub use eci::*;
 mod quad;
 pub use quad::*;
 
+mod segment_splitter;
+pub(crate) use segment_splitter::*;
+
 pub mod cpp_essentials;
 
 #[cfg(feature = "otsu_level")]
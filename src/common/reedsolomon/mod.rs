@@ -30,6 +30,7 @@ static AZTEC_DATA_6: Lazy<GenericGF> = Lazy::new(|| GenericGF::new(0x43, 64, 1))
 static AZTEC_PARAM: Lazy<GenericGF> = Lazy::new(|| GenericGF::new(0x13, 16, 1)); // x^4 + x + 1
 static QR_CODE_FIELD_256: Lazy<GenericGF> = Lazy::new(|| GenericGF::new(0x011D, 256, 0)); // x^8 + x^4 + x^3 + x^2 + 1
 static DATA_MATRIX_FIELD_256: Lazy<GenericGF> = Lazy::new(|| GenericGF::new(0x012D, 256, 1)); // x^8 + x^5 + x^3 + x^2 + 1
+static HAN_XIN_FIELD_256: Lazy<GenericGF> = Lazy::new(|| GenericGF::new(0x0163, 256, 1)); // x^8 + x^6 + x^5 + x + 1
 
 // pub const AZTEC_DATA_12: GenericGF = GenericGF::new(0x1069, 4096, 1); // x^12 + x^6 + x^5 + x^3 + 1
 // pub const AZTEC_DATA_10: GenericGF = GenericGF::new(0x409, 1024, 1); // x^10 + x^3 + 1
@@ -49,6 +50,7 @@ pub enum PredefinedGenericGF {
     DataMatrixField256,
     AztecData8,
     MaxicodeField64,
+    HanXinField256,
     // PDF417,
 }
 
@@ -63,6 +65,7 @@ pub fn get_predefined_genericgf(request: PredefinedGenericGF) -> GenericGFRef {
         PredefinedGenericGF::DataMatrixField256 | PredefinedGenericGF::AztecData8 => {
             &DATA_MATRIX_FIELD_256
         } // x^8 + x^5 + x^3 + x^2 + 1
+        PredefinedGenericGF::HanXinField256 => &HAN_XIN_FIELD_256, // x^8 + x^6 + x^5 + x + 1
         // PredefinedGenericGF::PDF417 => &PDF_417_FIELD,
     }
 }
@@ -0,0 +1,57 @@
+use crate::Exceptions;
+
+use super::Result;
+
+/**
+ * Splits a message into as few segments as possible for structured append encoding, each being
+ * the longest run of characters that still fits into a symbol.
+ *
+ * @param contents the message to split
+ * @param maxSegments the maximum number of segments allowed
+ * @param fits tells whether a segment fits into one symbol
+ * @return the segments in order
+ */
+pub(crate) fn splitIntoSegments<F: FnMut(&str) -> bool>(
+    contents: &str,
+    maxSegments: usize,
+    mut fits: F,
+) -> Result<Vec<String>> {
+    let chars = contents.chars().collect::<Vec<_>>();
+    if chars.is_empty() {
+        return Err(Exceptions::illegal_argument_with("found empty contents"));
+    }
+
+    let mut segments = Vec::new();
+    let mut start = 0;
+    while start < chars.len() {
+        if segments.len() == maxSegments {
+            return Err(Exceptions::writer_with(format!(
+                "message does not fit into {maxSegments} symbols"
+            )));
+        }
+        let mut fitsUntil = |end: usize| fits(&chars[start..end].iter().collect::<String>());
+        let end = if fitsUntil(chars.len()) {
+            chars.len()
+        } else {
+            // binary search for the longest segment that still fits
+            let (mut low, mut high) = (start, chars.len());
+            while high - low > 1 {
+                let mid = (low + high) / 2;
+                if fitsUntil(mid) {
+                    low = mid;
+                } else {
+                    high = mid;
+                }
+            }
+            if low == start {
+                return Err(Exceptions::writer_with(
+                    "unable to fit a structured append segment into a symbol",
+                ));
+            }
+            low
+        };
+        segments.push(chars[start..end].iter().collect());
+        start = end;
+    }
+    Ok(segments)
+}
@@ -131,6 +131,23 @@ impl Reader for DataMatrixReader {
                 RXingResultMetadataValue::ErrorCorrectionLevel(ecLevel.to_string()),
             );
         }
+        let structuredAppend = decoderRXingResult.getStructuredAppend();
+        if structuredAppend.index >= 0 {
+            result.putMetadata(
+                RXingResultMetadataType::STRUCTURED_APPEND_SEQUENCE,
+                RXingResultMetadataValue::StructuredAppendSequence(structuredAppend.index),
+            );
+            result.putMetadata(
+                RXingResultMetadataType::STRUCTURED_APPEND_COUNT,
+                RXingResultMetadataValue::StructuredAppendCount(structuredAppend.count.max(0)),
+            );
+            if !structuredAppend.id.is_empty() {
+                result.putMetadata(
+                    RXingResultMetadataType::STRUCTURED_APPEND_ID,
+                    RXingResultMetadataValue::StructuredAppendId(structuredAppend.id.clone()),
+                );
+            }
+        }
         let other_meta = decoderRXingResult.getOther();
         if let Some(other) = other_meta {
             if let Some(dcr) = other.downcast_ref::<String>() {
@@ -15,10 +15,10 @@
  * limitations under the License.
  */
 
-use std::collections::HashMap;
+use std::{collections::HashMap, rc::Rc};
 
 use crate::{
-    common::{BitMatrix, CharacterSet, Result},
+    common::{splitIntoSegments, BitMatrix, CharacterSet, Result},
     qrcode::encoder::ByteMatrix,
     BarcodeFormat, EncodeHintType, EncodeHintValue, Exceptions, Writer,
 };
@@ -74,6 +74,92 @@ impl Writer for DataMatrixWriter {
             )));
         }
 
+        Self::encodeSymbol(contents, width, height, hints, None)
+    }
+}
+
+impl DataMatrixWriter {
+    /**
+     * Splits the contents across as few Data Matrix symbols as the hints allow, up to 16 or
+     * {@link EncodeHintType#STRUCTURED_APPEND_MAX_SYMBOLS}. The size of the symbols can be
+     * limited with {@link EncodeHintType#MAX_SIZE} and
+     * {@link EncodeHintType#DATA_MATRIX_SHAPE}.
+     *
+     * Each symbol starts with a structured append header holding its position, the number of
+     * symbols and the file id from {@link EncodeHintType#STRUCTURED_APPEND_ID}, or one derived
+     * from the contents. Contents that fit into a single symbol are encoded without header.
+     * {@link EncodeHintType#DATA_MATRIX_COMPACT} is not supported with a header.
+     *
+     * @return one bit matrix per symbol, in sequence order
+     */
+    pub fn encode_structured_append(
+        &self,
+        contents: &str,
+        width: i32,
+        height: i32,
+        hints: &crate::EncodingHintDictionary,
+    ) -> Result<Vec<BitMatrix>> {
+        if let Ok(single) =
+            self.encode_with_hints(contents, &BarcodeFormat::DATA_MATRIX, width, height, hints)
+        {
+            return Ok(vec![single]);
+        }
+
+        let maxSymbols = if let Some(EncodeHintValue::StructuredAppendMaxSymbols(max)) =
+            hints.get(&EncodeHintType::STRUCTURED_APPEND_MAX_SYMBOLS)
+        {
+            (*max).min(16)
+        } else {
+            16
+        };
+        let (fileId1, fileId2) = if let Some(EncodeHintValue::StructuredAppendId(id)) =
+            hints.get(&EncodeHintType::STRUCTURED_APPEND_ID)
+        {
+            let id = id
+                .parse::<u32>()
+                .map_err(|_| Exceptions::illegal_argument_with(format!("invalid file id {id}")))?;
+            let (fileId1, fileId2) = (id >> 8, id & 0xFF);
+            if !(1..=254).contains(&fileId1) || !(1..=254).contains(&fileId2) {
+                return Err(Exceptions::illegal_argument_with(format!(
+                    "invalid file id {id}, both bytes must be 1 to 254"
+                )));
+            }
+            (fileId1 as u8, fileId2 as u8)
+        } else {
+            let hash = contents
+                .chars()
+                .fold(0u32, |hash, c| hash.wrapping_mul(31).wrapping_add(c as u32));
+            ((hash % 254) as u8 + 1, ((hash / 254) % 254) as u8 + 1)
+        };
+
+        // ISO 16022:2006, 5.6.2: position in the upper and 17 - count in the lower 4 bits
+        let header =
+            |index: usize, count: usize| [((index << 4) | (17 - count)) as u8, fileId1, fileId2];
+        let segments = splitIntoSegments(contents, maxSymbols as usize, |segment| {
+            Self::encodeSymbol(segment, width, height, hints, Some(header(0, 16))).is_ok()
+        })?;
+        segments
+            .iter()
+            .enumerate()
+            .map(|(index, segment)| {
+                Self::encodeSymbol(
+                    segment,
+                    width,
+                    height,
+                    hints,
+                    (segments.len() > 1).then(|| header(index, segments.len())),
+                )
+            })
+            .collect()
+    }
+
+    fn encodeSymbol(
+        contents: &str,
+        width: i32,
+        height: i32,
+        hints: &crate::EncodingHintDictionary,
+        structuredAppend: Option<[u8; 3]>,
+    ) -> Result<BitMatrix> {
         // Try to get force shape & min / max size
         let mut shape = &SymbolShapeHint::FORCE_NONE;
         let mut minSize = None;
@@ -89,13 +175,22 @@ impl Writer for DataMatrixWriter {
             if let Some(EncodeHintValue::MinSize(rq)) = requestedMinSize {
                 minSize = Some(*rq);
             }
-            // @SuppressWarnings("deprecation")
             let requestedMaxSize = hints.get(&EncodeHintType::MAX_SIZE);
-            if let Some(EncodeHintValue::MinSize(rq)) = requestedMaxSize {
+            if let Some(EncodeHintValue::MaxSize(rq)) = requestedMaxSize {
                 maxSize = Some(*rq);
             }
         }
 
+        let allowDMRE = matches!(
+            hints.get(&EncodeHintType::DATA_MATRIX_DMRE),
+            Some(EncodeHintValue::DataMatrixDmre(true))
+        );
+        let symbol_lookup = if allowDMRE {
+            SymbolInfoLookup::with_dmre()
+        } else {
+            SymbolInfoLookup::new()
+        };
+
         //1. step: Data encodation
         let encoded;
 
@@ -106,7 +201,7 @@ impl Writer for DataMatrixWriter {
         } else {
             false
         };
-        if hasCompactionHint {
+        if hasCompactionHint && structuredAppend.is_none() {
             let hasGS1FormatHint = if let Some(EncodeHintValue::Gs1Format(res)) =
                 hints.get(&EncodeHintType::GS1_FORMAT)
             {
@@ -127,7 +222,7 @@ impl Writer for DataMatrixWriter {
                 //encoding::label::encoding_from_whatwg_label(char_set_name);
                 // charset = Charset.forName(hints.get(EncodeHintType.CHARACTER_SET).toString());
             }
-            encoded = minimal_encoder::encodeHighLevelWithDetails(
+            encoded = minimal_encoder::encodeHighLevelWithDetailsAndDMRE(
                 contents,
                 charset,
                 if hasGS1FormatHint {
@@ -136,6 +231,7 @@ impl Writer for DataMatrixWriter {
                     None
                 },
                 *shape,
+                allowDMRE,
             )?;
         } else {
             let hasForceC40Hint = if let Some(EncodeHintValue::ForceC40(hint)) =
@@ -145,16 +241,17 @@ impl Writer for DataMatrixWriter {
             } else {
                 false
             };
-            encoded = high_level_encoder::encodeHighLevelWithDimensionForceC40(
+            encoded = high_level_encoder::encodeHighLevelWithStructuredAppend(
                 contents,
                 *shape,
                 minSize,
                 maxSize,
                 hasForceC40Hint,
+                Some(Rc::new(symbol_lookup)),
+                structuredAppend,
             )?;
         }
 
-        let symbol_lookup = SymbolInfoLookup::new();
         let Some(symbolInfo) = symbol_lookup.lookup_with_codewords_shape_size_fail(
             encoded.chars().count() as u32,
             *shape,
@@ -180,9 +277,7 @@ impl Writer for DataMatrixWriter {
         //4. step: low-level encoding
         Self::encodeLowLevel(&placement, symbolInfo, width as u32, height as u32)
     }
-}
 
-impl DataMatrixWriter {
     /**
      * Encode the given symbol info to a bit matrix.
      *
@@ -313,8 +408,10 @@ mod tests {
     use std::collections::HashMap;
 
     use crate::{
-        datamatrix::{encoder::SymbolShapeHint, DataMatrixWriter},
-        BarcodeFormat, EncodeHintType, EncodeHintValue, Writer,
+        common::bit_matrix_test_case,
+        datamatrix::{encoder::SymbolShapeHint, DataMatrixReader, DataMatrixWriter},
+        BarcodeFormat, DecodeHintType, DecodeHintValue, Dimension, EncodeHintType, EncodeHintValue,
+        Reader, Writer,
     };
 
     #[test]
@@ -381,4 +478,148 @@ mod tests {
         assert!(tooSmall < matrix.getWidth() as i32);
         assert!(tooSmall < matrix.getHeight() as i32);
     }
+
+    #[test]
+    fn testMaxSize() {
+        let mut hints = HashMap::new();
+        hints.insert(
+            EncodeHintType::MAX_SIZE,
+            EncodeHintValue::MaxSize(Dimension::new(16, 16)),
+        );
+        let writer = DataMatrixWriter;
+        let matrix = writer
+            .encode_with_hints("Hello Me", &BarcodeFormat::DATA_MATRIX, 0, 0, &hints)
+            .expect("must encode");
+        assert!(matrix.getWidth() <= 16 && matrix.getHeight() <= 16);
+        // too long for a 16x16 symbol
+        assert!(writer
+            .encode_with_hints(
+                "http://www.google.com/",
+                &BarcodeFormat::DATA_MATRIX,
+                0,
+                0,
+                &hints
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn testDMRE() {
+        // the sizes chosen for rectangles as the data grows. 8x80 and 8x120 are never chosen, as
+        // 16x36 and 16x48 of ISO/IEC 16022 hold as many codewords.
+        let expectedSizes = [
+            (18, 8),
+            (32, 8),
+            (26, 12),
+            (48, 8),
+            (36, 12),
+            (64, 8),
+            (36, 16),
+            (96, 8),
+            (64, 12),
+            (36, 20),
+            (48, 16),
+            (44, 20),
+            (64, 16),
+            (144, 8),
+            (88, 12),
+            (40, 26),
+            (48, 22),
+            (48, 24),
+            (64, 20),
+            (48, 26),
+            (64, 24),
+            (64, 26),
+        ];
+        let writer = DataMatrixWriter;
+        let mut sizes = Vec::new();
+        for length in (2..=236).step_by(2) {
+            // pairs of digits take one codeword each
+            let contents = "0123456789".repeat(24)[..length].to_owned();
+            let mut hints = HashMap::new();
+            hints.insert(
+                EncodeHintType::DATA_MATRIX_SHAPE,
+                EncodeHintValue::DataMatrixShape(SymbolShapeHint::FORCE_RECTANGLE),
+            );
+            hints.insert(
+                EncodeHintType::DATA_MATRIX_DMRE,
+                EncodeHintValue::DataMatrixDmre(true),
+            );
+            let matrix = writer
+                .encode_with_hints(&contents, &BarcodeFormat::DATA_MATRIX, 0, 0, &hints)
+                .expect("must encode");
+            let size = (matrix.getWidth(), matrix.getHeight());
+            if sizes.last() != Some(&size) {
+                sizes.push(size);
+            }
+
+            // render with a quiet zone and let the detector find it
+            let matrix = writer
+                .encode_with_hints(
+                    &contents,
+                    &BarcodeFormat::DATA_MATRIX,
+                    size.0 as i32 * 3 + 12,
+                    size.1 as i32 * 3 + 12,
+                    &hints,
+                )
+                .expect("must encode");
+            let mut image = bit_matrix_test_case::matrix_to_binary_bitmap(&matrix);
+            let result = DataMatrixReader
+                .decode_with_hints(
+                    &mut image,
+                    &HashMap::from([(
+                        DecodeHintType::TRY_HARDER,
+                        DecodeHintValue::TryHarder(true),
+                    )]),
+                )
+                .expect("must decode");
+            assert_eq!(contents, result.getText(), "{size:?}");
+        }
+        assert_eq!(expectedSizes.as_slice(), sizes.as_slice());
+    }
+
+    #[test]
+    fn testDMREIsOptIn() {
+        // 36 digits take 18 codewords
+        let contents = "123456789012345678901234567890123456";
+        let mut hints = HashMap::new();
+        hints.insert(
+            EncodeHintType::DATA_MATRIX_SHAPE,
+            EncodeHintValue::DataMatrixShape(SymbolShapeHint::FORCE_RECTANGLE),
+        );
+        let writer = DataMatrixWriter;
+        let matrix = writer
+            .encode_with_hints(contents, &BarcodeFormat::DATA_MATRIX, 0, 0, &hints)
+            .expect("must encode");
+        assert_eq!((36, 12), (matrix.getWidth(), matrix.getHeight()));
+
+        hints.insert(
+            EncodeHintType::DATA_MATRIX_DMRE,
+            EncodeHintValue::DataMatrixDmre(true),
+        );
+        let matrix = writer
+            .encode_with_hints(contents, &BarcodeFormat::DATA_MATRIX, 0, 0, &hints)
+            .expect("must encode");
+        assert_eq!((48, 8), (matrix.getWidth(), matrix.getHeight()));
+
+        // the minimal encoder pads to the DMRE capacities as well
+        hints.insert(
+            EncodeHintType::DATA_MATRIX_COMPACT,
+            EncodeHintValue::DataMatrixCompact(true),
+        );
+        let matrix = writer
+            .encode_with_hints(contents, &BarcodeFormat::DATA_MATRIX, 0, 0, &hints)
+            .expect("must encode");
+        assert_eq!((48, 8), (matrix.getWidth(), matrix.getHeight()));
+
+        // square symbols are never DMRE
+        hints.insert(
+            EncodeHintType::DATA_MATRIX_SHAPE,
+            EncodeHintValue::DataMatrixShape(SymbolShapeHint::FORCE_SQUARE),
+        );
+        let matrix = writer
+            .encode_with_hints(contents, &BarcodeFormat::DATA_MATRIX, 0, 0, &hints)
+            .expect("must encode");
+        assert_eq!((18, 18), (matrix.getWidth(), matrix.getHeight()));
+    }
 }
@@ -15,7 +15,10 @@
  */
 
 use crate::{
-    common::{BitSource, CharacterSet, DecoderRXingResult, ECIStringBuilder, Eci, Result},
+    common::{
+        cpp_essentials::StructuredAppendInfo, BitSource, CharacterSet, DecoderRXingResult,
+        ECIStringBuilder, Eci, Result,
+    },
     Exceptions,
 };
 
@@ -120,6 +123,7 @@ pub fn decode(bytes: &[u8], is_flipped: bool) -> Result<DecoderRXingResult> {
     let mut isECIencoded = false;
     let mut known_eci = true;
     let mut is_gs1 = false;
+    let mut sai = StructuredAppendInfo::default();
     loop {
         match mode {
             Mode::ASCII_ENCODE => {
@@ -129,6 +133,7 @@ pub fn decode(bytes: &[u8], is_flipped: bool) -> Result<DecoderRXingResult> {
                     &mut resultTrailer,
                     &mut fnc1Positions,
                     &mut is_gs1,
+                    &mut sai,
                 )?
             }
             Mode::C40_ENCODE => {
@@ -203,6 +208,8 @@ pub fn decode(bytes: &[u8], is_flipped: bool) -> Result<DecoderRXingResult> {
         result.setIsMirrored(is_flipped);
     }
 
+    result.setStructuredAppend(sai);
+
     Ok(result)
 }
 
@@ -215,11 +222,11 @@ fn decodeAsciiSegment(
     resultTrailer: &mut String,
     fnc1positions: &mut Vec<usize>,
     is_gs1: &mut bool,
+    sai: &mut StructuredAppendInfo,
 ) -> Result<Mode> {
     let mut upperShift = false;
     let mut firstFNC1Position = 1;
     let mut firstCodeword = true;
-    let mut sai = StructuredAppendInfo::default();
     loop {
         let mut oneByte = bits.readBits(8)?;
         match oneByte {
@@ -280,7 +287,7 @@ fn decodeAsciiSegment(
                         "structured append tag must be first code word",
                     ));
                 }
-                parse_structured_append(bits, &mut sai)?;
+                parse_structured_append(bits, sai)?;
                 firstFNC1Position = 5;
             }
             234 =>
@@ -780,21 +787,6 @@ fn parse_structured_append(bits: &mut BitSource, sai: &mut StructuredAppendInfo)
     sai.id = ((fileId1 << 8) | fileId2).to_string();
     Ok(())
 }
-struct StructuredAppendInfo {
-    index: i32, //= -1;
-    count: i32, // = -1;
-    id: String,
-}
-
-impl Default for StructuredAppendInfo {
-    fn default() -> Self {
-        Self {
-            index: -1,
-            count: -1,
-            id: Default::default(),
-        }
-    }
-}
 
 /**
  * See ISO 16022:2006, Annex B, B.2
@@ -845,6 +837,28 @@ mod tests {
         assert_eq!("00019899", decodedString);
     }
 
+    #[test]
+    fn testStructuredAppend() {
+        // third of four symbols with file id 1/2, followed by "AB"
+        let bytes = [233, (2 << 4) | (17 - 4), 1, 2, (b'A' + 1), (b'B' + 1)];
+        let result = decoded_bit_stream_parser::decode(&bytes, false).expect("decode");
+        assert_eq!("AB", result.getText());
+        let sai = result.getStructuredAppend();
+        assert_eq!(2, sai.index);
+        assert_eq!(4, sai.count);
+        assert_eq!("258", sai.id);
+
+        // a total of 17 symbols is not permitted, the count is unknown
+        let bytes = [233, (1 << 4), 1, 2, (b'A' + 1)];
+        let result = decoded_bit_stream_parser::decode(&bytes, false).expect("decode");
+        assert_eq!(1, result.getStructuredAppend().index);
+        assert_eq!(0, result.getStructuredAppend().count);
+
+        let bytes = [(b'A' + 1)];
+        let result = decoded_bit_stream_parser::decode(&bytes, false).expect("decode");
+        assert_eq!(-1, result.getStructuredAppend().index);
+    }
+
     // TODO(bbrown): Add test cases for each encoding type
     // TODO(bbrown): Add test cases for switching encoding types
 }
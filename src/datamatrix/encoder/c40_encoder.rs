@@ -63,7 +63,7 @@ impl C40Encoder {
             let unwritten = (buffer.chars().count() / 3) * 2;
 
             let curCodewordCount = context.getCodewordCount() + unwritten;
-            context.updateSymbolInfoWithLength(curCodewordCount);
+            context.updateSymbolInfoWithLength(curCodewordCount)?;
             let available = context
                 .getSymbolInfo()
                 .ok_or(Exceptions::ILLEGAL_STATE)?
@@ -138,7 +138,7 @@ impl C40Encoder {
             let unwritten = (buffer.chars().count() / 3) * 2;
 
             let curCodewordCount = context.getCodewordCount() + unwritten + 1; // +1 for the latch to C40
-            context.updateSymbolInfoWithLength(curCodewordCount);
+            context.updateSymbolInfoWithLength(curCodewordCount)?;
             let available = context
                 .getSymbolInfo()
                 .ok_or(Exceptions::ILLEGAL_STATE)?
@@ -200,7 +200,7 @@ impl C40Encoder {
         let rest = buffer.chars().count() % 3;
 
         let curCodewordCount = context.getCodewordCount() + unwritten;
-        context.updateSymbolInfoWithLength(curCodewordCount);
+        context.updateSymbolInfoWithLength(curCodewordCount)?;
         let available = context
             .getSymbolInfo()
             .ok_or(Exceptions::ILLEGAL_STATE)?
@@ -160,6 +160,10 @@ impl DefaultPlacement {
             col += self.numcols as isize;
             row += 4 - ((self.numcols + 4) % 8) as isize;
         }
+        // needed for the DMRE sizes, see the decoder's BitMatrixParser
+        if row >= self.numrows as isize {
+            row -= self.numrows as isize;
+        }
         // Note the conversion:
         let mut v = self
             .codewords
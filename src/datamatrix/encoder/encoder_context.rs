@@ -151,30 +151,26 @@ impl<'a> EncoderContext<'_> {
         &self.symbolInfo
     }
 
-    pub fn updateSymbolInfo(&mut self) {
-        self.updateSymbolInfoWithLength(self.getCodewordCount());
+    pub fn updateSymbolInfo(&mut self) -> Result<()> {
+        self.updateSymbolInfoWithLength(self.getCodewordCount())
     }
 
     /// Update symbol info with the length
     ///
-    /// This function can panic
-    pub fn updateSymbolInfoWithLength(&mut self, len: usize) {
+    /// Fails if no symbol within the size constraints holds that many codewords
+    pub fn updateSymbolInfoWithLength(&mut self, len: usize) -> Result<()> {
         if self.symbolInfo.is_none()
             || len > self.symbolInfo.as_ref().unwrap().getDataCapacity() as usize
         {
-            self.symbolInfo = Some(
-                self.symbol_lookup
-                    .lookup_with_codewords_shape_size_fail(
-                        len as u32,
-                        self.shape,
-                        &self.minSize,
-                        &self.maxSize,
-                        true,
-                    )
-                    .unwrap()
-                    .unwrap(),
-            );
+            self.symbolInfo = self.symbol_lookup.lookup_with_codewords_shape_size_fail(
+                len as u32,
+                self.shape,
+                &self.minSize,
+                &self.maxSize,
+                true,
+            )?;
         }
+        Ok(())
     }
 
     pub fn resetSymbolInfo(&mut self) {
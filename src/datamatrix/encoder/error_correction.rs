@@ -29,12 +29,16 @@ use once_cell::sync::Lazy;
  * Lookup table which factors to use for which number of error correction codewords.
  * See FACTORS.
  */
-const FACTOR_SETS: [u32; 16] = [5, 7, 10, 11, 12, 14, 18, 20, 24, 28, 36, 42, 48, 56, 62, 68];
+const FACTOR_SETS: [u32; 25] = [
+    5, 7, 10, 11, 12, 14, 18, 20, 24, 28, 36, 42, 48, 56, 62, 68,
+    // additional sizes of the rectangular extension (DMRE), ISO/IEC 21471
+    15, 22, 27, 32, 34, 38, 41, 46, 50,
+];
 
 /**
  * Precomputed polynomial factors for ECC 200.
  */
-static FACTORS: Lazy<[Vec<u32>; 16]> = Lazy::new(|| {
+static FACTORS: Lazy<[Vec<u32>; 25]> = Lazy::new(|| {
     [
         vec![228, 48, 15, 111, 62],
         vec![23, 68, 144, 134, 240, 92, 254],
@@ -91,6 +95,45 @@ static FACTORS: Lazy<[Vec<u32>; 16]> = Lazy::new(|| {
             202, 167, 179, 25, 220, 232, 96, 210, 231, 136, 223, 239, 181, 241, 59, 52, 172, 25,
             49, 232, 211, 189, 64, 54, 108, 153, 132, 63, 96, 103, 82, 186,
         ],
+        vec![
+            116, 88, 33, 148, 201, 88, 153, 176, 234, 145, 35, 159, 139, 223, 93,
+        ],
+        vec![
+            75, 249, 76, 201, 95, 241, 5, 123, 71, 94, 27, 132, 1, 152, 38, 10, 125, 217, 209, 3,
+            188, 236,
+        ],
+        vec![
+            215, 183, 130, 96, 182, 147, 97, 74, 143, 205, 47, 229, 29, 123, 55, 65, 244, 246, 210,
+            108, 72, 134, 233, 246, 161, 180, 232,
+        ],
+        vec![
+            227, 184, 159, 130, 228, 199, 107, 95, 236, 21, 61, 58, 209, 120, 2, 38, 211, 202, 103,
+            19, 104, 145, 2, 249, 174, 144, 95, 55, 234, 163, 129, 104,
+        ],
+        vec![
+            190, 197, 231, 218, 210, 172, 71, 234, 203, 95, 42, 103, 237, 116, 90, 95, 143, 6, 189,
+            114, 107, 62, 154, 62, 152, 237, 138, 89, 14, 67, 189, 179, 4, 139,
+        ],
+        vec![
+            109, 30, 101, 141, 239, 222, 166, 90, 102, 168, 237, 74, 233, 143, 123, 203, 41, 172,
+            103, 139, 211, 55, 183, 83, 215, 122, 223, 170, 134, 80, 128, 220, 169, 166, 241, 165,
+            181, 235,
+        ],
+        vec![
+            35, 21, 123, 106, 43, 2, 142, 6, 222, 194, 48, 53, 99, 216, 193, 172, 136, 101, 120,
+            156, 250, 137, 144, 84, 165, 90, 211, 208, 132, 133, 15, 224, 244, 79, 71, 81, 38, 68,
+            249, 220, 149,
+        ],
+        vec![
+            195, 29, 197, 200, 113, 135, 114, 178, 160, 232, 186, 35, 129, 111, 51, 47, 61, 11, 29,
+            135, 171, 83, 221, 33, 206, 130, 127, 47, 77, 21, 43, 72, 138, 173, 118, 144, 98, 40,
+            178, 141, 62, 114, 235, 74, 62, 78,
+        ],
+        vec![
+            1, 166, 254, 165, 43, 167, 102, 150, 123, 245, 74, 222, 223, 36, 230, 112, 16, 9, 142,
+            14, 241, 251, 114, 65, 156, 205, 14, 80, 76, 243, 241, 8, 185, 25, 28, 112, 231, 79,
+            153, 16, 74, 156, 183, 212, 218, 167, 91, 162, 54, 74,
+        ],
     ]
 });
 
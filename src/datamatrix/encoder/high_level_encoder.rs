@@ -49,7 +49,7 @@ pub const LATCH_TO_BASE256: u8 = 231;
 /**
  * Structured Append Codeword
  */
-//private static final char STRUCTURED_APPEND = 233;
+const STRUCTURED_APPEND: u8 = 233;
 /**
  * Reader Programming
  */
@@ -185,6 +185,26 @@ pub fn encodeHighLevelWithDimensionForceC40WithSymbolInfoLookup(
     maxSize: Option<Dimension>,
     forceC40: bool,
     symbol_lookup: Option<Rc<SymbolInfoLookup>>,
+) -> Result<String> {
+    encodeHighLevelWithStructuredAppend(msg, shape, minSize, maxSize, forceC40, symbol_lookup, None)
+}
+
+/**
+ * Performs message encoding of a DataMatrix message, starting with a structured append header
+ * (ISO 16022:2006, 5.6) if given.
+ *
+ * @param structuredAppend the symbol sequence indicator and the two file identification
+ *                         codewords, written right after the structured append codeword
+ * @return the encoded message (the char values range from 0 to 255)
+ */
+pub fn encodeHighLevelWithStructuredAppend(
+    msg: &str,
+    shape: SymbolShapeHint,
+    minSize: Option<Dimension>,
+    maxSize: Option<Dimension>,
+    forceC40: bool,
+    symbol_lookup: Option<Rc<SymbolInfoLookup>>,
+    structuredAppend: Option<[u8; 3]>,
 ) -> Result<String> {
     //the codewords 0..255 are encoded as Unicode characters
     let c40Encoder = Rc::new(C40Encoder::new());
@@ -206,6 +226,13 @@ pub fn encodeHighLevelWithDimensionForceC40WithSymbolInfoLookup(
     context.setSymbolShape(shape);
     context.setSizeConstraints(minSize, maxSize);
 
+    if let Some(structuredAppend) = structuredAppend {
+        context.writeCodeword(STRUCTURED_APPEND);
+        for codeword in structuredAppend {
+            context.writeCodeword(codeword);
+        }
+    }
+
     if msg.starts_with(MACRO_05_HEADER) && msg.ends_with(MACRO_TRAILER) {
         context.writeCodeword(MACRO_05);
         context.setSkipAtEnd(2);
@@ -232,7 +259,7 @@ pub fn encodeHighLevelWithDimensionForceC40WithSymbolInfoLookup(
         }
     }
     let len = context.getCodewordCount();
-    context.updateSymbolInfo();
+    context.updateSymbolInfo()?;
     let capacity = context
         .getSymbolInfo()
         .ok_or(Exceptions::ILLEGAL_STATE)?
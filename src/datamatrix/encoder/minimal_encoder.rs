@@ -21,7 +21,7 @@ use crate::{
     Exceptions,
 };
 
-use super::{high_level_encoder, SymbolShapeHint};
+use super::{high_level_encoder, SymbolInfoLookup, SymbolShapeHint};
 
 const ISO_8859_1_ENCODER: CharacterSet = CharacterSet::ISO8859_1;
 
@@ -154,6 +154,27 @@ pub fn encodeHighLevelWithDetails(
     priorityCharset: Option<CharacterSet>,
     fnc1: Option<char>,
     shape: SymbolShapeHint,
+) -> Result<String> {
+    encodeHighLevelWithDetailsAndDMRE(msg, priorityCharset, fnc1, shape, false)
+}
+
+/**
+ * Performs message encoding of a DataMatrix message
+ *
+ * @param msg the message
+ * @param priorityCharset The preferred {@link Charset} or null for a minimal representation.
+ * @param fnc1 denotes the character in the input that represents the FNC1 character or -1 if this is not a GS1
+ *   bar code.
+ * @param shape requested shape.
+ * @param allowDMRE whether the padding may target the rectangular extension (DMRE) symbol sizes.
+ * @return the encoded message (the char values range from 0 to 255)
+ */
+pub fn encodeHighLevelWithDetailsAndDMRE(
+    msg: &str,
+    priorityCharset: Option<CharacterSet>,
+    fnc1: Option<char>,
+    shape: SymbolShapeHint,
+    allowDMRE: bool,
 ) -> Result<String> {
     let mut msg = msg;
     let mut macroId = 0;
@@ -171,7 +192,14 @@ pub fn encodeHighLevelWithDetails(
         msg = &msg[high_level_encoder::MACRO_06_HEADER.chars().count()..(msg.chars().count() - 2)];
     }
     Ok(ISO_8859_1_ENCODER
-        .decode(&encode(msg, priorityCharset, fnc1, shape, macroId)?)
+        .decode(&encode(
+            msg,
+            priorityCharset,
+            fnc1,
+            shape,
+            macroId,
+            allowDMRE,
+        )?)
         .expect("should decode"))
     // return new String(encode(msg, priorityCharset, fnc1, shape, macroId), StandardCharsets.ISO_8859_1);
 }
@@ -188,6 +216,7 @@ pub fn encodeHighLevelWithDetails(
  *   bar code. If the value is not -1 then a FNC1 is also prepended.
  * @param shape requested shape.
  * @param macroId Prepends the specified macro function in case that a value of 5 or 6 is specified.
+ * @param allowDMRE whether the rectangular extension (DMRE) symbol sizes may be used.
  * @return An array of bytes representing the codewords of a minimal encoding.
  */
 fn encode(
@@ -196,16 +225,11 @@ fn encode(
     fnc1: Option<char>,
     shape: SymbolShapeHint,
     macroId: i32,
+    allowDMRE: bool,
 ) -> Result<Vec<u8>> {
-    Ok(encodeMinimally(Rc::new(Input::new(
-        input,
-        priorityCharset,
-        fnc1,
-        shape,
-        macroId,
-    )))?
-    .getBytes()
-    .to_vec())
+    let mut input = Input::new(input, priorityCharset, fnc1, shape, macroId);
+    input.allowDMRE = allowDMRE;
+    Ok(encodeMinimally(Rc::new(input))?.getBytes().to_vec())
 }
 
 fn addEdge(edges: &mut [Vec<Option<Rc<Edge>>>], edge: Rc<Edge>) -> Result<()> {
@@ -919,6 +943,17 @@ impl Edge {
      * number of codewords.
      **/
     pub fn getMinSymbolSize(&self, minimum: u32) -> u32 {
+        if self.input.allowDMRE {
+            let lookup = SymbolInfoLookup::with_dmre();
+            for shape in [self.input.getShapeHint(), SymbolShapeHint::FORCE_NONE] {
+                if let Ok(Some(symbol)) = lookup
+                    .lookup_with_codewords_shape_size_fail(minimum, shape, &None, &None, false)
+                {
+                    return symbol.getDataCapacity();
+                }
+            }
+            return ALL_CODEWORD_CAPACITIES[ALL_CODEWORD_CAPACITIES.len() - 1];
+        }
         match self.input.getShapeHint() {
             SymbolShapeHint::FORCE_SQUARE => {
                 for capacity in SQUARE_CODEWORD_CAPACITIES {
@@ -1387,6 +1422,7 @@ impl RXingResult {
 struct Input {
     shape: SymbolShapeHint,
     macroId: i32,
+    allowDMRE: bool,
     internal: MinimalECIInput,
 }
 
@@ -1407,6 +1443,7 @@ impl Input {
         Self {
             shape,
             macroId,
+            allowDMRE: false,
             internal: MinimalECIInput::new(stringToEncode, priorityCharset, v),
         }
     }
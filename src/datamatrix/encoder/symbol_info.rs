@@ -22,7 +22,39 @@ use crate::{Dimension, Exceptions};
 use super::SymbolShapeHint;
 use once_cell::sync::Lazy;
 
-pub(super) static PROD_SYMBOLS: Lazy<Vec<SymbolInfo>> = Lazy::new(|| {
+pub(super) static PROD_SYMBOLS: Lazy<Vec<SymbolInfo>> = Lazy::new(buildProdSymbols);
+
+/**
+ * The ECC 200 symbols together with the rectangular extension (DMRE) sizes of ISO/IEC 21471,
+ * ordered by data capacity. Where capacities are equal, the ISO/IEC 16022 symbol comes first.
+ */
+pub(super) static DMRE_SYMBOLS: Lazy<Vec<SymbolInfo>> = Lazy::new(|| {
+    let mut symbols = buildProdSymbols();
+    symbols.extend([
+        SymbolInfo::new_dmre(18, 15, 22, 6, 2),   // 8x48
+        SymbolInfo::new_dmre(24, 18, 14, 6, 4),   // 8x64
+        SymbolInfo::new_dmre(32, 22, 18, 6, 4),   // 8x80
+        SymbolInfo::new_dmre(38, 28, 22, 6, 4),   // 8x96
+        SymbolInfo::new_dmre(49, 32, 18, 6, 6),   // 8x120
+        SymbolInfo::new_dmre(63, 36, 22, 6, 6),   // 8x144
+        SymbolInfo::new_dmre(43, 27, 14, 10, 4),  // 12x64
+        SymbolInfo::new_dmre(64, 36, 20, 10, 4),  // 12x88
+        SymbolInfo::new_dmre(62, 36, 14, 14, 4),  // 16x64
+        SymbolInfo::new_dmre(44, 28, 16, 18, 2),  // 20x36
+        SymbolInfo::new_dmre(56, 34, 20, 18, 2),  // 20x44
+        SymbolInfo::new_dmre(84, 42, 14, 18, 4),  // 20x64
+        SymbolInfo::new_dmre(72, 38, 22, 20, 2),  // 22x48
+        SymbolInfo::new_dmre(80, 41, 22, 22, 2),  // 24x48
+        SymbolInfo::new_dmre(108, 46, 14, 22, 4), // 24x64
+        SymbolInfo::new_dmre(70, 38, 18, 24, 2),  // 26x40
+        SymbolInfo::new_dmre(90, 42, 22, 24, 2),  // 26x48
+        SymbolInfo::new_dmre(118, 50, 14, 24, 4), // 26x64
+    ]);
+    symbols.sort_by_key(|symbol| symbol.dataCapacity);
+    symbols
+});
+
+fn buildProdSymbols() -> Vec<SymbolInfo> {
     vec![
         SymbolInfo::new(false, 3, 5, 8, 8, 1),
         SymbolInfo::new(false, 5, 7, 10, 10, 1),
@@ -55,7 +87,7 @@ pub(super) static PROD_SYMBOLS: Lazy<Vec<SymbolInfo>> = Lazy::new(|| {
         SymbolInfo::with_details(false, 1304, 496, 20, 20, 36, 163, 62),
         SymbolInfo::new_symbol_info_144(),
     ]
-});
+}
 
 /**
  * Symbol info table for DataMatrix.
@@ -72,6 +104,7 @@ pub struct SymbolInfo {
     rsBlockData: i32,
     rsBlockError: u32,
     isSymbolInfo144: bool,
+    isDMRE: bool,
 }
 impl SymbolInfo {
     pub fn new(
@@ -115,6 +148,7 @@ impl SymbolInfo {
             rsBlockData,
             rsBlockError,
             isSymbolInfo144: false,
+            isDMRE: false,
         }
     }
     pub fn new_symbol_info_144() -> Self {
@@ -123,7 +157,37 @@ impl SymbolInfo {
         new_symbol
     }
 
+    /**
+     * Creates a DMRE symbol (ISO/IEC 21471). These always have a single row of data regions,
+     * so {@code dataRegions} is the number of data regions side by side.
+     */
+    pub fn new_dmre(
+        dataCapacity: u32,
+        errorCodewords: u32,
+        matrixWidth: u32,
+        matrixHeight: u32,
+        dataRegions: u32,
+    ) -> Self {
+        let mut new_symbol = Self::new(
+            true,
+            dataCapacity,
+            errorCodewords,
+            matrixWidth,
+            matrixHeight,
+            dataRegions,
+        );
+        new_symbol.isDMRE = true;
+        new_symbol
+    }
+
+    pub fn isDMRE(&self) -> bool {
+        self.isDMRE
+    }
+
     fn getHorizontalDataRegions(&self) -> Result<u32> {
+        if self.isDMRE {
+            return Ok(self.dataRegions);
+        }
         match self.dataRegions {
             1 => Ok(1),
             2 | 4 => Ok(2),
@@ -136,6 +200,9 @@ impl SymbolInfo {
     }
 
     fn getVerticalDataRegions(&self) -> Result<u32> {
+        if self.isDMRE {
+            return Ok(1);
+        }
         match self.dataRegions {
             1 | 2 => Ok(1),
             4 => Ok(2),
@@ -228,6 +295,13 @@ impl<'a> SymbolInfoLookup<'a> {
     pub const fn new() -> Self {
         Self(None)
     }
+
+    /**
+     * Creates a lookup that also considers the rectangular extension (DMRE) sizes of ISO/IEC 21471.
+     */
+    pub fn with_dmre() -> SymbolInfoLookup<'static> {
+        SymbolInfoLookup(Some(&DMRE_SYMBOLS))
+    }
     /**
      * Overrides the symbol info set used by this class. Used for testing purposes.
      *
@@ -472,4 +546,52 @@ mod tests {
             .expect("return");
         assert!(info.is_none());
     }
+
+    #[test]
+    fn testDMRESymbolInfo() {
+        let lookup = SymbolInfoLookup::with_dmre();
+
+        // smaller symbols are unaffected
+        let info = lookup.lookup(3).expect("returns").expect("exists");
+        assert_eq!(10, info.getSymbolWidth().expect("returns"));
+        assert_eq!(10, info.getSymbolHeight().expect("returns"));
+
+        let info = lookup
+            .lookup_with_shape(17, SymbolShapeHint::FORCE_RECTANGLE)
+            .expect("returns")
+            .expect("exists");
+        assert!(info.isDMRE());
+        assert_eq!(15, info.getErrorCodewords());
+        assert_eq!(48, info.getSymbolWidth().expect("returns"));
+        assert_eq!(8, info.getSymbolHeight().expect("returns"));
+        assert_eq!(44, info.getSymbolDataWidth().expect("returns"));
+        assert_eq!(6, info.getSymbolDataHeight().expect("returns"));
+
+        // without DMRE this needs a 12x36 symbol
+        let info = LOOKUP
+            .lookup_with_shape(17, SymbolShapeHint::FORCE_RECTANGLE)
+            .expect("returns")
+            .expect("exists");
+        assert!(!info.isDMRE());
+        assert_eq!(36, info.getSymbolWidth().expect("returns"));
+        assert_eq!(12, info.getSymbolHeight().expect("returns"));
+
+        // equal capacities prefer the ISO/IEC 16022 symbol
+        let info = lookup
+            .lookup_with_shape(49, SymbolShapeHint::FORCE_RECTANGLE)
+            .expect("returns")
+            .expect("exists");
+        assert_eq!(48, info.getSymbolWidth().expect("returns"));
+        assert_eq!(16, info.getSymbolHeight().expect("returns"));
+
+        let info = lookup
+            .lookup_with_shape(50, SymbolShapeHint::FORCE_RECTANGLE)
+            .expect("returns")
+            .expect("exists");
+        assert_eq!(44, info.getSymbolWidth().expect("returns"));
+        assert_eq!(20, info.getSymbolHeight().expect("returns"));
+        assert!(lookup
+            .lookup_with_shape(119, SymbolShapeHint::FORCE_RECTANGLE)
+            .is_err());
+    }
 }
@@ -109,6 +109,47 @@ pub enum DecodeHintType {
      */
     ALSO_INVERTED,
 
+    /**
+     * Specifies the check digit scheme of MSI Plessey symbols (type
+     * {@link crate::oned::MSICheckDigit}). The check digits are verified and removed
+     * from the result, defaults to a single modulo 10 check digit.
+     */
+    MSI_CHECK_DIGIT,
+
+    /**
+     * Specifies the number of Code 11 check digits, C and K, to verify and remove (type
+     * {@link Integer}, 0 to 2). By default C alone is expected after up to 10 data characters,
+     * and both C and K after longer data.
+     */
+    CODE_11_CHECK_DIGITS,
+
+    /**
+     * Assume Code 39 codes may be Code 32 (Italian Pharmacode), carrying a 9 digit AIC number in
+     * base 32. Symbols with a valid AIC check digit are reported as Code 32, their text being the
+     * number prefixed with 'A' (type {@link Boolean}). Also set by asking for Code 32 but not
+     * Code 39 with {@link #POSSIBLE_FORMATS}.
+     */
+    ASSUME_CODE_32,
+
+    /**
+     * Specifies whether Telepen symbols carry double density numeric data, two digits per
+     * character, instead of ASCII (type {@link Boolean}).
+     */
+    TELEPEN_NUMERIC,
+
+    /**
+     * Assume ITF codes are ITF-14 shipping container codes: 14 digits, the last one a GS1 modulo
+     * 10 check digit, which is verified. The result is the GTIN-14 of the container
+     * (type {@link Boolean}).
+     */
+    ASSUME_ITF_14,
+
+    /**
+     * Specifies whether Industrial, IATA, Matrix and Datalogic 2 of 5 symbols end with a modulo 10
+     * check digit, which is then verified and removed (type {@link Boolean}).
+     */
+    TWO_OF_FIVE_CHECK_DIGIT,
+
     /**
      * Specifies that the codes are expected to be in conformance with the specification
      * ISO/IEC 18004 regading the interpretation of character encoding. Values encoded in BYTE mode
@@ -219,6 +260,47 @@ pub enum DecodeHintValue {
      */
     AlsoInverted(bool),
 
+    /**
+     * Specifies the check digit scheme of MSI Plessey symbols (type
+     * {@link crate::oned::MSICheckDigit}). The check digits are verified and removed
+     * from the result, defaults to a single modulo 10 check digit.
+     */
+    MsiCheckDigit(crate::oned::MSICheckDigit),
+
+    /**
+     * Specifies the number of Code 11 check digits, C and K, to verify and remove (type
+     * {@link Integer}, 0 to 2). By default C alone is expected after up to 10 data characters,
+     * and both C and K after longer data.
+     */
+    Code11CheckDigits(u32),
+
+    /**
+     * Assume Code 39 codes may be Code 32 (Italian Pharmacode), carrying a 9 digit AIC number in
+     * base 32. Symbols with a valid AIC check digit are reported as Code 32, their text being the
+     * number prefixed with 'A' (type {@link Boolean}). Also set by asking for Code 32 but not
+     * Code 39 with {@link #POSSIBLE_FORMATS}.
+     */
+    AssumeCode32(bool),
+
+    /**
+     * Specifies whether Telepen symbols carry double density numeric data, two digits per
+     * character, instead of ASCII (type {@link Boolean}).
+     */
+    TelepenNumeric(bool),
+
+    /**
+     * Assume ITF codes are ITF-14 shipping container codes: 14 digits, the last one a GS1 modulo
+     * 10 check digit, which is verified. The result is the GTIN-14 of the container
+     * (type {@link Boolean}).
+     */
+    AssumeItf14(bool),
+
+    /**
+     * Specifies whether Industrial, IATA, Matrix and Datalogic 2 of 5 symbols end with a modulo 10
+     * check digit, which is then verified and removed (type {@link Boolean}).
+     */
+    TwoOfFiveCheckDigit(bool),
+
     /**
      * Specifies that the codes are expected to be in conformance with the specification
      * ISO/IEC 18004 regading the interpretation of character encoding. Values encoded in BYTE mode
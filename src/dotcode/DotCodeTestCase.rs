@@ -0,0 +1,256 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    common::{bit_matrix_test_case, HybridBinarizer},
+    dotcode::{
+        decoder::dot_code_decoder,
+        dot_code_common::{
+            applyMask, getCodeword, getDataCapacity, getDotPositions, isValidSize, removeMask,
+            DOT_PATTERNS, LATCH_AB, LATCH_C, LATCH_C_A, LATCH_C_B, LATCH_C_BINARY, SHIFT_AB,
+            UPPER_SHIFT,
+        },
+        encoder::{dot_code_encoder, encodeHighLevel},
+        DotCodeReader, DotCodeWriter,
+    },
+    BarcodeFormat, BinaryBitmap, DecodeHintType, DecodeHintValue, EncodeHintType, EncodeHintValue,
+    Luma8LuminanceSource, MultiFormatReader, MultiUseMultiFormatReader, RXingResultMetadataType,
+    RXingResultMetadataValue, Reader, Writer,
+};
+
+#[test]
+fn testDotPatterns() {
+    assert_eq!(113, DOT_PATTERNS.iter().collect::<HashSet<_>>().len());
+    for (value, pattern) in DOT_PATTERNS.iter().enumerate() {
+        assert_eq!(5, pattern.count_ones());
+        assert_eq!(Some(value as u32), getCodeword(*pattern));
+    }
+    // the first and last entries of the table of the specification
+    assert_eq!(0x155, DOT_PATTERNS[0]);
+    assert_eq!(0x0AB, DOT_PATTERNS[1]);
+    assert_eq!(0x1CC, DOT_PATTERNS[112]);
+    assert_eq!(Some(0), getCodeword(0x155));
+    assert_eq!(None, getCodeword(0x1F0));
+    assert_eq!(None, getCodeword(0x155 ^ 1));
+}
+
+#[test]
+fn testSizes() {
+    assert!(isValidSize(16, 11));
+    assert!(!isValidSize(16, 12));
+    assert!(!isValidSize(4, 7));
+    assert_eq!(None, getDataCapacity(6, 5));
+    // 88 dots: two bits of mask indicator, 4 data and 5 error correction codewords
+    assert_eq!(Some(5), getDataCapacity(16, 11));
+    let positions = getDotPositions(16, 11);
+    assert_eq!(88, positions.len());
+    assert_eq!(88, positions.iter().collect::<HashSet<_>>().len());
+    assert!(positions.iter().all(|(x, y)| (x + y) % 2 == 0));
+}
+
+#[test]
+fn testDotPositions() {
+    // an odd number of rows is folded in row by row from the bottom, the corners last
+    let positions = getDotPositions(16, 11);
+    assert_eq!(&[(2, 10), (4, 10), (6, 10)], &positions[..3]);
+    assert_eq!((1, 9), positions[6]);
+    assert_eq!(
+        &[(14, 0), (14, 10), (15, 1), (15, 9), (0, 0), (0, 10)],
+        &positions[82..]
+    );
+
+    // an even number of rows column by column from the left
+    let positions = getDotPositions(11, 16);
+    assert_eq!(88, positions.len());
+    assert_eq!(&[(0, 2), (0, 4), (0, 6)], &positions[..3]);
+    assert_eq!((1, 1), positions[6]);
+    assert_eq!(
+        &[(10, 14), (0, 14), (9, 15), (1, 15), (10, 0), (0, 0)],
+        &positions[82..]
+    );
+}
+
+#[test]
+fn testMasks() {
+    let original = (0..40).map(|i| i * 7 % 113).collect::<Vec<_>>();
+    for mask in 0..4 {
+        let mut codewords = original.clone();
+        applyMask(&mut codewords, mask);
+        assert_eq!(original[0], codewords[0]);
+        assert_eq!(mask == 0, codewords == original);
+        removeMask(&mut codewords, mask);
+        assert_eq!(original, codewords);
+    }
+}
+
+#[test]
+fn testHighLevelEncoder() {
+    assert_eq!(vec![12, 34, 56], encode("123456"));
+    assert_eq!(vec![LATCH_C_B, 33, 34, 65, 66], encode("ABab"));
+    assert_eq!(vec![LATCH_C_A, 33, 77, 34], encode("A\rB"));
+    assert_eq!(vec![LATCH_C_B, 65, SHIFT_AB, 77, 66], encode("a\rb"));
+    assert_eq!(
+        vec![LATCH_C_B, 65, LATCH_AB, 77, 74, SHIFT_AB, 66],
+        encode("a\r\nb")
+    );
+    assert_eq!(
+        vec![LATCH_C_B, 33, 34, LATCH_C, 12, 34, LATCH_C_B, 17],
+        encode("AB12341")
+    );
+    assert_eq!(vec![LATCH_C_B, 67, UPPER_SHIFT, 73], encode("c\u{e9}"));
+    // five bytes in six codewords, then two more bytes in three
+    let codewords = encode("\u{e0}\u{e1}\u{e2}\u{e3}\u{e4}\u{e5}\u{e6}");
+    assert_eq!(LATCH_C_BINARY, codewords[0]);
+    assert_eq!(1 + 6 + 3, codewords.len());
+    assert!(codewords[1..].iter().all(|codeword| *codeword < 103));
+}
+
+#[test]
+fn testEncodeDecode() {
+    doTestRoundTrip("1234567890", None);
+    doTestRoundTrip("123456789", None);
+    doTestRoundTrip("DotCode", None);
+    doTestRoundTrip("DotCode 2024-06-01 LOT A123456 exp. 12/26", None);
+    doTestRoundTrip("\u{1}\u{1d}\u{1e}\u{4}tab\there", None);
+    doTestRoundTrip(
+        "caf\u{e9} cr\u{e8}me br\u{fb}l\u{e9}e \u{c0}\u{c1}\u{c2}\u{c3}\u{c4}",
+        None,
+    );
+    doTestRoundTrip("Ελληνικά", None);
+    doTestRoundTrip("Ελληνικά", Some("ISO-8859-7"));
+    doTestRoundTrip("日本語テキスト", Some("Shift_JIS"));
+    doTestRoundTrip(&"0123456789ABCDEFabcdef".repeat(20), None);
+}
+
+#[test]
+fn testErrorCorrection() {
+    let contents = "DotCode error correction 0123456789";
+    let matrix = dot_code_encoder::encode_with_hints(contents, &HashMap::new()).expect("encode");
+
+    let mut damaged = matrix.clone();
+    let positions = getDotPositions(matrix.getWidth(), matrix.getHeight());
+    for (x, y) in positions.iter().skip(20).step_by(23).take(3) {
+        damaged.flip_coords(*x, *y);
+    }
+    let result = dot_code_decoder::decode(&damaged).expect("must decode");
+    assert_eq!(contents, result.getText());
+    assert!(result.getErrorsCorrected() > 0);
+
+    for (x, y) in positions.iter().skip(20).take(100) {
+        damaged.flip_coords(*x, *y);
+    }
+    assert!(dot_code_decoder::decode(&damaged).is_err());
+}
+
+#[test]
+fn testDataTooBig() {
+    assert!(DotCodeWriter
+        .encode(&"x".repeat(20000), &BarcodeFormat::DOT_CODE, 0, 0)
+        .is_err());
+    assert!(DotCodeWriter
+        .encode("12345", &BarcodeFormat::QR_CODE, 0, 0)
+        .is_err());
+}
+
+#[test]
+fn testWriterReader() {
+    let contents = "DotCode 01234567 Tobacco & Beverage";
+    let mut matrix = DotCodeWriter
+        .encode(contents, &BarcodeFormat::DOT_CODE, 300, 200)
+        .expect("must encode");
+    for _ in 0..4 {
+        let result = DotCodeReader
+            .decode(&mut bit_matrix_test_case::matrix_to_binary_bitmap(&matrix))
+            .expect("must decode");
+        assert_eq!(contents, result.getText());
+        assert_eq!(&BarcodeFormat::DOT_CODE, result.getBarcodeFormat());
+        assert_eq!(4, result.getRXingResultPoints().len());
+        assert_eq!(
+            Some(&RXingResultMetadataValue::SymbologyIdentifier(
+                "]J0".to_owned()
+            )),
+            result
+                .getRXingResultMetadata()
+                .get(&RXingResultMetadataType::SYMBOLOGY_IDENTIFIER)
+        );
+        matrix.rotate90();
+    }
+
+    let hints = HashMap::from([(
+        DecodeHintType::POSSIBLE_FORMATS,
+        DecodeHintValue::PossibleFormats(HashSet::from([BarcodeFormat::DOT_CODE])),
+    )]);
+    let result = MultiFormatReader::default()
+        .decode_with_hints(
+            &mut bit_matrix_test_case::matrix_to_binary_bitmap(&matrix),
+            &hints,
+        )
+        .expect("must decode");
+    assert_eq!(contents, result.getText());
+    let result = MultiUseMultiFormatReader::default()
+        .decode_with_hints(
+            &mut bit_matrix_test_case::matrix_to_binary_bitmap(&matrix),
+            &hints,
+        )
+        .expect("must decode");
+    assert_eq!(contents, result.getText());
+
+    // DotCode has no finder pattern, it is only looked for when POSSIBLE_FORMATS names it
+    let mut image = bit_matrix_test_case::matrix_to_binary_bitmap(&matrix);
+    assert!(MultiFormatReader::default().decode(&mut image).is_err());
+    assert!(MultiUseMultiFormatReader::default()
+        .decode(&mut image)
+        .is_err());
+}
+
+#[test]
+fn testRotatedRoundDots() {
+    // round dots, far apart from each other, on a grid turned by 20 degrees
+    let contents = "Inkjet printed DotCode";
+    let code = dot_code_encoder::encode_with_hints(contents, &HashMap::new()).expect("encode");
+    let (size, pitch, radius) = (400usize, 9.0f32, 3.0f32);
+    let (sin, cos) = 20f32.to_radians().sin_cos();
+    let center = (code.getWidth() as f32 / 2.0, code.getHeight() as f32 / 2.0);
+    let mut luma = vec![u8::MAX; size * size];
+    for (x, y) in getDotPositions(code.getWidth(), code.getHeight()) {
+        if !code.get(x, y) {
+            continue;
+        }
+        let gridX = (x as f32 + 0.5 - center.0) * pitch;
+        let gridY = (y as f32 + 0.5 - center.1) * pitch;
+        let imageX = size as f32 / 2.0 + gridX * cos - gridY * sin;
+        let imageY = size as f32 / 2.0 + gridX * sin + gridY * cos;
+        for py in (imageY - radius) as usize..=(imageY + radius) as usize {
+            for px in (imageX - radius) as usize..=(imageX + radius) as usize {
+                let (dx, dy) = (px as f32 + 0.5 - imageX, py as f32 + 0.5 - imageY);
+                if dx * dx + dy * dy <= radius * radius {
+                    luma[py * size + px] = 0;
+                }
+            }
+        }
+    }
+    let mut image = BinaryBitmap::new(HybridBinarizer::new(Luma8LuminanceSource::new(
+        luma,
+        size as u32,
+        size as u32,
+    )));
+    let result = DotCodeReader.decode(&mut image).expect("must decode");
+    assert_eq!(contents, result.getText());
+}
+
+fn encode(contents: &str) -> Vec<u32> {
+    encodeHighLevel(contents, None).expect("must encode")
+}
+
+fn doTestRoundTrip(contents: &str, characterSet: Option<&str>) {
+    let mut hints = HashMap::new();
+    if let Some(characterSet) = characterSet {
+        hints.insert(
+            EncodeHintType::CHARACTER_SET,
+            EncodeHintValue::CharacterSet(characterSet.to_owned()),
+        );
+    }
+    let matrix = dot_code_encoder::encode_with_hints(contents, &hints).expect("must encode");
+    assert!(isValidSize(matrix.getWidth(), matrix.getHeight()));
+    let result = dot_code_decoder::decode(&matrix).expect("must decode");
+    assert_eq!(contents, result.getText());
+}
@@ -0,0 +1,171 @@
+use crate::{
+    common::{CharacterSet, DecoderRXingResult, ECIStringBuilder, Eci, Result},
+    dotcode::dot_code_common::{
+        BINARY_BASE, BINARY_GROUP_SIZE, ECI, ECI_SINGLE_CODEWORD_LIMIT, LATCH_AB, LATCH_BINARY,
+        LATCH_BINARY_A, LATCH_BINARY_B, LATCH_BINARY_C, LATCH_C, LATCH_C_A, LATCH_C_B,
+        LATCH_C_BINARY, NUMBER_OF_CODEWORDS, PAD, SHIFT_AB, UPPER_SHIFT,
+    },
+    Exceptions,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CodeSet {
+    A,
+    B,
+    C,
+    Binary,
+}
+
+impl CodeSet {
+    /** The other one of Code Sets A and B */
+    fn other(&self) -> Self {
+        if *self == CodeSet::A {
+            CodeSet::B
+        } else {
+            CodeSet::A
+        }
+    }
+}
+
+/**
+ * <p>DotCodes encode text in one of several code sets, and can switch between them within one
+ * DotCode. This class decodes the data codewords back into text.</p>
+ *
+ * <p>Code Set C holds pairs of digits, Code Set A upper case letters and control characters, Code
+ * Set B lower and upper case letters. An upper shift adds 128 to the next character. Binary mode
+ * compacts groups of five bytes into six base 103 codewords. Decoding starts in Code Set C, and
+ * characters are ISO-8859-1 unless an ECI says otherwise.</p>
+ */
+pub fn decode(codewords: &[u32]) -> Result<DecoderRXingResult> {
+    let mut result = ECIStringBuilder::with_capacity(codewords.len() * 2);
+    let mut byteSegments = Vec::new();
+    let mut codeSet = CodeSet::C;
+    let mut shiftSet = None;
+    let mut upperShift = false;
+    // the base 103 digits of the binary group being read, and the bytes of the binary segment
+    let mut group = Vec::with_capacity(BINARY_GROUP_SIZE + 1);
+    let mut segment = Vec::new();
+
+    let mut i = 0;
+    while i < codewords.len() {
+        let codeword = codewords[i];
+        i += 1;
+
+        if codeSet == CodeSet::Binary {
+            if codeword < BINARY_BASE {
+                group.push(codeword);
+                if group.len() == BINARY_GROUP_SIZE + 1 {
+                    decodeBinaryGroup(&group, &mut segment)?;
+                    group.clear();
+                }
+                continue;
+            }
+            decodeBinaryGroup(&group, &mut segment)?;
+            group.clear();
+            if !segment.is_empty() {
+                result.append_bytes(&segment);
+                byteSegments.push(std::mem::take(&mut segment));
+            }
+            match codeword {
+                LATCH_BINARY_A => codeSet = CodeSet::A,
+                LATCH_BINARY_B => codeSet = CodeSet::B,
+                LATCH_BINARY_C => codeSet = CodeSet::C,
+                PAD => {}
+                ECI => {
+                    let eci = parseECIValue(codewords, &mut i)?;
+                    result.switch_encoding(CharacterSet::from(eci), true);
+                }
+                _ => return Err(Exceptions::FORMAT),
+            }
+            continue;
+        }
+
+        match codeword {
+            PAD => {}
+            ECI => {
+                let eci = parseECIValue(codewords, &mut i)?;
+                result.switch_encoding(CharacterSet::from(eci), true);
+            }
+            _ if codeSet == CodeSet::C => match codeword {
+                0..=99 => result.append_string(&format!("{codeword:02}")),
+                LATCH_C_BINARY => codeSet = CodeSet::Binary,
+                LATCH_C_A => codeSet = CodeSet::A,
+                LATCH_C_B => codeSet = CodeSet::B,
+                _ => return Err(Exceptions::FORMAT),
+            },
+            0..=95 => {
+                let value = match shiftSet.take().unwrap_or(codeSet) {
+                    CodeSet::A if codeword < 64 => codeword + 32,
+                    CodeSet::A => codeword - 64,
+                    _ => codeword + 32,
+                };
+                let value = if upperShift { value + 128 } else { value };
+                upperShift = false;
+                result.append_byte(value as u8);
+            }
+            UPPER_SHIFT => upperShift = true,
+            SHIFT_AB => shiftSet = Some(codeSet.other()),
+            LATCH_AB => codeSet = codeSet.other(),
+            LATCH_C => codeSet = CodeSet::C,
+            LATCH_BINARY => codeSet = CodeSet::Binary,
+            _ => return Err(Exceptions::FORMAT),
+        }
+    }
+
+    if codeSet == CodeSet::Binary {
+        decodeBinaryGroup(&group, &mut segment)?;
+        if !segment.is_empty() {
+            result.append_bytes(&segment);
+            byteSegments.push(segment);
+        }
+    }
+
+    Ok(DecoderRXingResult::with_symbology(
+        codewords.iter().map(|codeword| *codeword as u8).collect(),
+        result.build_result().to_string(),
+        byteSegments,
+        String::new(),
+        0,
+    ))
+}
+
+/**
+ * Turns the base 103 digits of a binary group into one byte less than there are digits, up to
+ * five bytes for a full group.
+ */
+fn decodeBinaryGroup(group: &[u32], bytes: &mut Vec<u8>) -> Result<()> {
+    if group.is_empty() {
+        return Ok(());
+    }
+    if group.len() == 1 {
+        return Err(Exceptions::FORMAT);
+    }
+    let value = group.iter().fold(0u64, |value, digit| {
+        value * BINARY_BASE as u64 + *digit as u64
+    });
+    let count = group.len() - 1;
+    if value >> (8 * count) != 0 {
+        return Err(Exceptions::FORMAT);
+    }
+    bytes.extend((0..count).rev().map(|i| (value >> (8 * i)) as u8));
+    Ok(())
+}
+
+/**
+ * Reads the ECI value after an ECI codeword: the small ones take a single codeword, the others
+ * two.
+ */
+fn parseECIValue(codewords: &[u32], i: &mut usize) -> Result<Eci> {
+    let first = *codewords.get(*i).ok_or(Exceptions::FORMAT)?;
+    *i += 1;
+    if first < ECI_SINGLE_CODEWORD_LIMIT {
+        return Ok(Eci::from(first));
+    }
+    let second = *codewords.get(*i).ok_or(Exceptions::FORMAT)?;
+    *i += 1;
+    Ok(Eci::from(
+        ECI_SINGLE_CODEWORD_LIMIT
+            + (first - ECI_SINGLE_CODEWORD_LIMIT) * NUMBER_OF_CODEWORDS
+            + second,
+    ))
+}
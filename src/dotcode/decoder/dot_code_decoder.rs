@@ -0,0 +1,109 @@
+use crate::{
+    common::{BitMatrix, DecoderRXingResult, Result},
+    dotcode::dot_code_common::{
+        getBlockPositions, getCodeword, getDataCapacity, getDotPositions,
+        getErrorCorrectionCodewords, getNumberOfBlocks, removeMask, BITS_PER_CODEWORD,
+        DOT_CODE_FIELD, MASK_INDICATOR_BITS, NUM_MASKS,
+    },
+    pdf417::decoder::ec::error_correction,
+    Exceptions,
+};
+
+use super::decoded_bit_stream_parser;
+
+/**
+ * <p>The main class which implements DotCode decoding -- as opposed to locating and extracting
+ * the DotCode from an image.</p>
+ *
+ * <p>Decodes a DotCode represented as a {@link BitMatrix} of its dot positions, in its upright
+ * orientation. A 1 or "true" is taken to mean a dot. The size of the matrix gives the number of
+ * codewords.</p>
+ *
+ * @param bits booleans representing the dots of the DotCode
+ * @return text and bytes encoded within the DotCode
+ * @throws FormatException if the DotCode cannot be decoded
+ * @throws ChecksumException if error correction fails
+ */
+pub fn decode(bits: &BitMatrix) -> Result<DecoderRXingResult> {
+    let width = bits.getWidth();
+    let height = bits.getHeight();
+    let dataCodewords = getDataCapacity(width, height).ok_or(Exceptions::FORMAT)?;
+    let totalCodewords = dataCodewords + getErrorCorrectionCodewords(dataCodewords);
+
+    let mut dots = getDotPositions(width, height)
+        .into_iter()
+        .map(|(x, y)| bits.get(x, y));
+    let mut readBits = |count: u32| {
+        dots.by_ref()
+            .take(count as usize)
+            .fold(0u16, |value, dot| (value << 1) | dot as u16)
+    };
+
+    // The mask indicator is short, the other codewords are dot patterns. A pattern which is not
+    // one of a codeword is an erasure.
+    let mut codewords = Vec::with_capacity(totalCodewords as usize);
+    let mut erasures = Vec::new();
+    codewords.push(readBits(MASK_INDICATOR_BITS) as u32);
+    for position in 1..totalCodewords {
+        codewords.push(getCodeword(readBits(BITS_PER_CODEWORD)).unwrap_or_else(|| {
+            erasures.push(position as usize);
+            0
+        }));
+    }
+
+    let errorsCorrected = correctErrors(&mut codewords, dataCodewords, &erasures)?;
+
+    let mask = codewords[0];
+    if mask >= NUM_MASKS {
+        return Err(Exceptions::FORMAT);
+    }
+    let mut data = codewords[..dataCodewords as usize].to_vec();
+    removeMask(&mut data, mask);
+
+    let mut result = decoded_bit_stream_parser::decode(&data[1..])?;
+    result.setErrorsCorrected(errorsCorrected);
+    result.setErasures(erasures.len());
+    Ok(result)
+}
+
+/**
+ * <p>Given data and error-correction codewords received, possibly corrupted by errors, attempts to
+ * correct the errors in-place, block by block.</p>
+ *
+ * @return the number of errors corrected
+ * @throws ChecksumException if error correction fails
+ */
+fn correctErrors(codewords: &mut [u32], dataCodewords: u32, erasures: &[usize]) -> Result<usize> {
+    let totalCodewords = codewords.len() as u32;
+    let mut errorsCorrected = 0;
+    for block in 0..getNumberOfBlocks(totalCodewords) {
+        let (positions, blockDataCodewords) =
+            getBlockPositions(dataCodewords, totalCodewords, block);
+        let mut received = positions
+            .iter()
+            .map(|position| codewords[*position])
+            .collect::<Vec<_>>();
+        let mut blockErasures = positions
+            .iter()
+            .enumerate()
+            .filter(|(_, position)| erasures.contains(position))
+            .map(|(i, _)| i as u32)
+            .collect::<Vec<_>>();
+        // the erasures are corrected as errors, so at most half as many as there are error
+        // correction codewords can be
+        let numECCodewords = (positions.len() - blockDataCodewords) as u32;
+        if blockErasures.len() as u32 > numECCodewords / 2 {
+            return Err(Exceptions::CHECKSUM);
+        }
+        errorsCorrected += error_correction::decode_with_field(
+            &DOT_CODE_FIELD,
+            &mut received,
+            numECCodewords,
+            &mut blockErasures,
+        )?;
+        for (position, codeword) in positions.iter().zip(received) {
+            codewords[*position] = codeword;
+        }
+    }
+    Ok(errorsCorrected)
+}
@@ -0,0 +1,2 @@
+pub mod decoded_bit_stream_parser;
+pub mod dot_code_decoder;
@@ -0,0 +1,263 @@
+use std::f32::consts::{FRAC_PI_4, PI, SQRT_2};
+
+use crate::{
+    common::{BitMatrix, DetectorRXingResult, Result},
+    point_f, Exceptions, Point,
+};
+
+use super::dot_code_common::isValidSize;
+
+/** The fewest dots a symbol is made of */
+const MIN_DOTS: usize = 10;
+/** Images with more spots than this are not searched */
+const MAX_SPOTS: usize = 100_000;
+/** Spots this many times larger or smaller than the typical one are not dots */
+const MAX_SIZE_RATIO: u32 = 4;
+
+#[derive(Debug)]
+pub struct DotCodeDetectorRXingResult {
+    bits: BitMatrix,
+    points: Vec<Point>,
+}
+
+impl DetectorRXingResult for DotCodeDetectorRXingResult {
+    fn getBits(&self) -> &BitMatrix {
+        &self.bits
+    }
+
+    fn getPoints(&self) -> &[Point] {
+        &self.points
+    }
+}
+
+/**
+ * The centre of a dark spot of the image, and how many pixels it covers.
+ */
+#[derive(Debug, Clone, Copy)]
+struct Dot {
+    center: Point,
+    size: u32,
+}
+
+/**
+ * <p>Detects a DotCode in an image. DotCode has no finder pattern, and its dots need not touch
+ * one another, so the dots are found one by one as dark spots of similar size.</p>
+ *
+ * <p>The nearest neighbours of the dots lie diagonally on the grid, which gives its orientation and
+ * the distance between two positions. The dots are then snapped to the grid, whose extent is the
+ * size of the symbol. The grid is returned as it lies in the image: it is up to the decoder to try
+ * each of its four rotations.</p>
+ *
+ * @return {@link DotCodeDetectorRXingResult} encapsulating results of detecting a DotCode
+ * @throws NotFoundException if no DotCode can be found
+ */
+pub fn detect(image: &BitMatrix) -> Result<DotCodeDetectorRXingResult> {
+    let dots = findDots(image)?;
+    if dots.len() < MIN_DOTS {
+        return Err(Exceptions::NOT_FOUND);
+    }
+
+    let neighbours = findNearestNeighbours(&dots);
+    let mut distances = neighbours
+        .iter()
+        .map(|neighbour| neighbour.length())
+        .collect::<Vec<_>>();
+    distances.sort_by(f32::total_cmp);
+    let typicalDistance = distances[distances.len() / 2];
+    if typicalDistance <= 0.0 {
+        return Err(Exceptions::NOT_FOUND);
+    }
+    let pitch = typicalDistance / SQRT_2;
+
+    // The diagonals repeat every quarter turn, so average the angles taken four times
+    let (sin, cos) = neighbours
+        .iter()
+        .filter(|neighbour| (neighbour.length() - typicalDistance).abs() < typicalDistance / 4.0)
+        .fold((0.0, 0.0), |(sin, cos), neighbour| {
+            let angle = 4.0 * neighbour.y.atan2(neighbour.x);
+            (sin + angle.sin(), cos + angle.cos())
+        });
+    let rotation = sin.atan2(cos) / 4.0 - FRAC_PI_4;
+    let (sin, cos) = rotation.sin_cos();
+
+    // The positions of the dots in units of the grid, along its axes
+    let gridPositions = dots
+        .iter()
+        .map(|dot| {
+            point_f(
+                (dot.center.x * cos + dot.center.y * sin) / pitch,
+                (dot.center.y * cos - dot.center.x * sin) / pitch,
+            )
+        })
+        .collect::<Vec<_>>();
+    let phaseX = getPhase(gridPositions.iter().map(|position| position.x));
+    let phaseY = getPhase(gridPositions.iter().map(|position| position.y));
+    let cells = gridPositions
+        .iter()
+        .map(|position| {
+            (
+                (position.x - phaseX).round() as i32,
+                (position.y - phaseY).round() as i32,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let left = cells
+        .iter()
+        .map(|cell| cell.0)
+        .min()
+        .ok_or(Exceptions::NOT_FOUND)?;
+    let right = cells
+        .iter()
+        .map(|cell| cell.0)
+        .max()
+        .ok_or(Exceptions::NOT_FOUND)?;
+    let top = cells
+        .iter()
+        .map(|cell| cell.1)
+        .min()
+        .ok_or(Exceptions::NOT_FOUND)?;
+    let bottom = cells
+        .iter()
+        .map(|cell| cell.1)
+        .max()
+        .ok_or(Exceptions::NOT_FOUND)?;
+    let width = (right - left + 1) as u32;
+    let height = (bottom - top + 1) as u32;
+    if !isValidSize(width, height) {
+        return Err(Exceptions::NOT_FOUND);
+    }
+
+    let mut bits = BitMatrix::new(width, height)?;
+    for (x, y) in cells {
+        bits.set((x - left) as u32, (y - top) as u32);
+    }
+
+    let toImage = |x: i32, y: i32| {
+        let gridX = (x as f32 + phaseX) * pitch;
+        let gridY = (y as f32 + phaseY) * pitch;
+        point_f(gridX * cos - gridY * sin, gridX * sin + gridY * cos)
+    };
+    Ok(DotCodeDetectorRXingResult {
+        bits,
+        points: vec![
+            toImage(left, top),
+            toImage(right, top),
+            toImage(right, bottom),
+            toImage(left, bottom),
+        ],
+    })
+}
+
+/**
+ * Collects the dark spots of the image, each one the pixels connected horizontally or vertically,
+ * and keeps those of about the typical size.
+ */
+fn findDots(image: &BitMatrix) -> Result<Vec<Dot>> {
+    let width = image.getWidth();
+    let height = image.getHeight();
+    let mut visited = vec![false; (width * height) as usize];
+    let mut dots = Vec::new();
+    let mut stack = Vec::new();
+    for y in 0..height {
+        for x in 0..width {
+            if visited[(y * width + x) as usize] || !image.get(x, y) {
+                continue;
+            }
+            if dots.len() >= MAX_SPOTS {
+                return Err(Exceptions::NOT_FOUND);
+            }
+            visited[(y * width + x) as usize] = true;
+            stack.push((x, y));
+            let (mut sumX, mut sumY, mut size) = (0u64, 0u64, 0u32);
+            while let Some((px, py)) = stack.pop() {
+                sumX += px as u64;
+                sumY += py as u64;
+                size += 1;
+                let neighbours = [
+                    (px.wrapping_sub(1), py),
+                    (px + 1, py),
+                    (px, py.wrapping_sub(1)),
+                    (px, py + 1),
+                ];
+                for (nx, ny) in neighbours {
+                    if nx < width
+                        && ny < height
+                        && !visited[(ny * width + nx) as usize]
+                        && image.get(nx, ny)
+                    {
+                        visited[(ny * width + nx) as usize] = true;
+                        stack.push((nx, ny));
+                    }
+                }
+            }
+            dots.push(Dot {
+                center: point_f(
+                    sumX as f32 / size as f32 + 0.5,
+                    sumY as f32 / size as f32 + 0.5,
+                ),
+                size,
+            });
+        }
+    }
+
+    let mut sizes = dots.iter().map(|dot| dot.size).collect::<Vec<_>>();
+    sizes.sort_unstable();
+    let Some(typicalSize) = sizes.get(sizes.len() / 2).copied() else {
+        return Ok(dots);
+    };
+    dots.retain(|dot| {
+        dot.size * MAX_SIZE_RATIO >= typicalSize && dot.size <= typicalSize * MAX_SIZE_RATIO
+    });
+    Ok(dots)
+}
+
+/**
+ * @return for every dot, the offset to the dot nearest to it
+ */
+fn findNearestNeighbours(dots: &[Dot]) -> Vec<Point> {
+    let mut sorted = dots.iter().map(|dot| dot.center).collect::<Vec<_>>();
+    sorted.sort_by(|a, b| a.x.total_cmp(&b.x));
+    (0..sorted.len())
+        .map(|i| {
+            let center = sorted[i];
+            let mut best = (Point::default(), f32::MAX);
+            // walk away from the dot on both sides, until the dots are too far apart in x alone
+            closestWithin(center, sorted[i + 1..].iter(), &mut best);
+            closestWithin(center, sorted[..i].iter().rev(), &mut best);
+            best.0
+        })
+        .collect()
+}
+
+/**
+ * Updates the closest offset and its length with the dots in order of their x coordinate, as long
+ * as they can be closer.
+ */
+fn closestWithin<'a>(
+    center: Point,
+    others: impl Iterator<Item = &'a Point>,
+    best: &mut (Point, f32),
+) {
+    for other in others {
+        if (other.x - center.x).abs() >= best.1 {
+            break;
+        }
+        let distance = Point::distance(*other, center);
+        if distance < best.1 {
+            *best = (*other - center, distance);
+        }
+    }
+}
+
+/**
+ * @return the offset, as a fraction of a position, of the grid the values lie on, averaged on the
+ * circle so that it does not matter which whole position each value is closest to
+ */
+fn getPhase(values: impl Iterator<Item = f32>) -> f32 {
+    let (sin, cos) = values.fold((0.0f32, 0.0f32), |(sin, cos), value| {
+        let angle = 2.0 * PI * value;
+        (sin + angle.sin(), cos + angle.cos())
+    });
+    sin.atan2(cos) / (2.0 * PI)
+}
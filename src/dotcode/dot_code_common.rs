@@ -0,0 +1,240 @@
+use once_cell::sync::Lazy;
+
+use crate::pdf417::decoder::ec::ModulusGF;
+
+/** The number of codeword values, and the size of the error correction field */
+pub const NUMBER_OF_CODEWORDS: u32 = 113;
+
+/** GF(113), with 3 as its generator */
+pub static DOT_CODE_FIELD: Lazy<ModulusGF> = Lazy::new(|| ModulusGF::new(NUMBER_OF_CODEWORDS, 3));
+
+/** The longest Reed-Solomon block, data and error correction codewords together */
+pub const MAX_BLOCK_LENGTH: u32 = NUMBER_OF_CODEWORDS - 1;
+
+/** Each codeword is drawn as nine dot positions */
+pub const BITS_PER_CODEWORD: u32 = 9;
+/** The mask indicator in front of the codewords takes two dot positions */
+pub const MASK_INDICATOR_BITS: u32 = 2;
+/** The number of masks */
+pub const NUM_MASKS: u32 = 4;
+/**
+ * The steps of the masks: a mask adds nothing to the first data codeword, and the step more to
+ * every next one than to the one before
+ */
+pub const MASK_STEPS: [u32; NUM_MASKS as usize] = [0, 3, 7, 17];
+
+/** The smallest number of rows or columns of a symbol */
+pub const MIN_SIZE: u32 = 5;
+/** The largest number of rows or columns of a symbol */
+pub const MAX_SIZE: u32 = 200;
+
+// Codewords shared by Code Sets A and B
+pub const UPPER_SHIFT: u32 = 96;
+pub const SHIFT_AB: u32 = 97;
+pub const LATCH_AB: u32 = 98;
+pub const LATCH_C: u32 = 99;
+pub const LATCH_BINARY: u32 = 100;
+// Codewords of Code Set C, after the 100 digit pairs
+pub const LATCH_C_BINARY: u32 = 100;
+pub const LATCH_C_A: u32 = 101;
+pub const LATCH_C_B: u32 = 102;
+// Codewords of binary mode, after the base 103 digits
+pub const BINARY_BASE: u32 = 103;
+pub const LATCH_BINARY_A: u32 = 103;
+pub const LATCH_BINARY_B: u32 = 104;
+pub const LATCH_BINARY_C: u32 = 105;
+// Codewords of every code set
+pub const PAD: u32 = 106;
+pub const ECI: u32 = 108;
+
+/** ECI values below this take one codeword, the others two */
+pub const ECI_SINGLE_CODEWORD_LIMIT: u32 = 40;
+/** A group of five bytes is compacted into six base 103 digits in binary mode */
+pub const BINARY_GROUP_SIZE: usize = 5;
+
+/**
+ * The nine dot patterns of the codewords, by value, as tabled in the AIM DotCode specification.
+ * Of the 126 patterns of five dots, the ones with five dots in a row and the ones starting or
+ * ending with four are left out.
+ */
+pub const DOT_PATTERNS: [u16; NUMBER_OF_CODEWORDS as usize] = [
+    0x155, 0x0AB, 0x0AD, 0x0B5, 0x0D5, 0x156, 0x15A, 0x16A, 0x1AA, 0x0AE, 0x0B6, 0x0BA, 0x0D6,
+    0x0DA, 0x0EA, 0x12B, 0x12D, 0x135, 0x14B, 0x14D, 0x153, 0x159, 0x165, 0x169, 0x195, 0x1A5,
+    0x1A9, 0x057, 0x05B, 0x05D, 0x06B, 0x06D, 0x075, 0x097, 0x09B, 0x09D, 0x0A7, 0x0B3, 0x0B9,
+    0x0CB, 0x0CD, 0x0D3, 0x0D9, 0x0E5, 0x0E9, 0x12E, 0x136, 0x13A, 0x14E, 0x15C, 0x166, 0x16C,
+    0x172, 0x174, 0x196, 0x19A, 0x1A6, 0x1AC, 0x1B2, 0x1B4, 0x1CA, 0x1D2, 0x1D4, 0x05E, 0x06E,
+    0x076, 0x07A, 0x09E, 0x0BC, 0x0CE, 0x0DC, 0x0E6, 0x0EC, 0x0F2, 0x0F4, 0x117, 0x11B, 0x11D,
+    0x127, 0x133, 0x139, 0x147, 0x163, 0x171, 0x18B, 0x18D, 0x193, 0x199, 0x1A3, 0x1B1, 0x1C5,
+    0x1C9, 0x1D1, 0x02F, 0x037, 0x03B, 0x03D, 0x04F, 0x067, 0x073, 0x079, 0x08F, 0x0C7, 0x0E3,
+    0x0F1, 0x11E, 0x13C, 0x178, 0x18E, 0x19C, 0x1B8, 0x1C6, 0x1CC,
+];
+
+/** The value of every nine dot pattern which is the one of a codeword */
+static CODEWORD_VALUES: Lazy<[Option<u8>; 1 << BITS_PER_CODEWORD]> = Lazy::new(|| {
+    let mut values = [None; 1 << BITS_PER_CODEWORD];
+    for (value, pattern) in DOT_PATTERNS.iter().enumerate() {
+        values[*pattern as usize] = Some(value as u8);
+    }
+    values
+});
+
+/**
+ * @param pattern nine dots, the first one in the most significant bit
+ * @return the value of the codeword drawn as the pattern, if it is one
+ */
+pub fn getCodeword(pattern: u16) -> Option<u32> {
+    CODEWORD_VALUES
+        .get(pattern as usize)
+        .copied()
+        .flatten()
+        .map(u32::from)
+}
+
+/**
+ * <p>The mask indicator is protected too, but left out of the count: there are three error
+ * correction codewords, and one more for every two other data codewords.</p>
+ *
+ * @param dataCodewords the number of data codewords, the mask indicator included
+ * @return the number of error correction codewords protecting them
+ */
+pub fn getErrorCorrectionCodewords(dataCodewords: u32) -> u32 {
+    3 + dataCodewords.saturating_sub(1) / 2
+}
+
+/**
+ * @return the number of Reed-Solomon blocks the codewords are interleaved into, every one of them
+ * short enough for the field
+ */
+pub fn getNumberOfBlocks(totalCodewords: u32) -> u32 {
+    totalCodewords.div_ceil(MAX_BLOCK_LENGTH)
+}
+
+/**
+ * <p>The codewords are interleaved into the blocks: block i takes codewords i, i + n, i + 2n and
+ * so on, n being the number of blocks. The data codewords of a block come first.</p>
+ *
+ * @return the positions of the codewords of the block, and how many of them hold data
+ */
+pub fn getBlockPositions(
+    dataCodewords: u32,
+    totalCodewords: u32,
+    block: u32,
+) -> (Vec<usize>, usize) {
+    let step = getNumberOfBlocks(totalCodewords);
+    let positions = (block..totalCodewords)
+        .step_by(step as usize)
+        .map(|position| position as usize)
+        .collect();
+    let blockDataCodewords = (dataCodewords.saturating_sub(block)).div_ceil(step) as usize;
+    (positions, blockDataCodewords)
+}
+
+/**
+ * <p>Every other position of the grid holds a dot, the ones where the sum of column and row is
+ * even. As one of the width and height is even and the other odd, that is exactly half of them.</p>
+ *
+ * @return whether a symbol of the size exists
+ */
+pub fn isValidSize(width: u32, height: u32) -> bool {
+    (MIN_SIZE..=MAX_SIZE).contains(&width)
+        && (MIN_SIZE..=MAX_SIZE).contains(&height)
+        && !(width + height).is_multiple_of(2)
+}
+
+/**
+ * @return the number of data codewords, the mask indicator included, a symbol of the size holds,
+ * if it holds any
+ */
+pub fn getDataCapacity(width: u32, height: u32) -> Option<u32> {
+    if !isValidSize(width, height) {
+        return None;
+    }
+    let dots = width * height / 2;
+    // the mask indicator takes two dots instead of a codeword
+    let fits = |dataCodewords: u32| {
+        MASK_INDICATOR_BITS
+            + BITS_PER_CODEWORD * (dataCodewords - 1 + getErrorCorrectionCodewords(dataCodewords))
+            <= dots
+    };
+    let mut dataCodewords = 1;
+    while fits(dataCodewords + 1) {
+        dataCodewords += 1;
+    }
+    (dataCodewords > 1).then_some(dataCodewords)
+}
+
+/**
+ * <p>The bits are folded into the symbol: row by row from the bottom row up if the symbol has an
+ * odd number of rows, else column by column from the left. Both ways, the six dot positions at
+ * the corners are left out at first, and take the last bits instead.</p>
+ *
+ * @return the dot positions of a symbol, in the order the bits are placed
+ */
+pub fn getDotPositions(width: u32, height: u32) -> Vec<(u32, u32)> {
+    let corners = getCorners(width, height);
+    let isDot = |(x, y): &(u32, u32)| (x + y).is_multiple_of(2) && !corners.contains(&(*x, *y));
+    let mut positions = if height % 2 == 1 {
+        (0..height)
+            .rev()
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .filter(isDot)
+            .collect::<Vec<_>>()
+    } else {
+        (0..width)
+            .flat_map(|x| (0..height).map(move |y| (x, y)))
+            .filter(isDot)
+            .collect::<Vec<_>>()
+    };
+    positions.extend_from_slice(&corners);
+    positions
+}
+
+/**
+ * @return the dot positions at the corners of a symbol, in the order the last bits are placed
+ */
+fn getCorners(width: u32, height: u32) -> [(u32, u32); 6] {
+    if height % 2 == 1 {
+        [
+            (width - 2, 0),
+            (width - 2, height - 1),
+            (width - 1, 1),
+            (width - 1, height - 2),
+            (0, 0),
+            (0, height - 1),
+        ]
+    } else {
+        [
+            (width - 1, height - 2),
+            (0, height - 2),
+            (width - 2, height - 1),
+            (1, height - 1),
+            (width - 1, 0),
+            (0, 0),
+        ]
+    }
+}
+
+/**
+ * Adds the mask to the data codewords, the mask indicator in front of them left as it is. The
+ * first data codeword is left as it is too.
+ */
+pub fn applyMask(codewords: &mut [u32], mask: u32) {
+    let step = MASK_STEPS[mask as usize];
+    let mut weight = 0;
+    for codeword in codewords.iter_mut().skip(1) {
+        *codeword = (*codeword + weight) % NUMBER_OF_CODEWORDS;
+        weight = (weight + step) % NUMBER_OF_CODEWORDS;
+    }
+}
+
+/**
+ * Reverts {@link #applyMask(int[], int)}.
+ */
+pub fn removeMask(codewords: &mut [u32], mask: u32) {
+    let step = MASK_STEPS[mask as usize];
+    let mut weight = 0;
+    for codeword in codewords.iter_mut().skip(1) {
+        *codeword = (*codeword + NUMBER_OF_CODEWORDS - weight) % NUMBER_OF_CODEWORDS;
+        weight = (weight + step) % NUMBER_OF_CODEWORDS;
+    }
+}
@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+
+use crate::{
+    common::{DetectorRXingResult, Result},
+    BarcodeFormat, Binarizer, BinaryBitmap, DecodingHintDictionary, Exceptions, RXingResult,
+    RXingResultMetadataType, RXingResultMetadataValue, Reader,
+};
+
+use super::{decoder::dot_code_decoder, detector};
+
+/**
+ * This implementation can detect and decode DotCodes in an image.
+ */
+#[derive(Default)]
+pub struct DotCodeReader;
+
+impl Reader for DotCodeReader {
+    /**
+     * Locates and decodes a DotCode in an image.
+     *
+     * @return a String representing the content encoded by the DotCode
+     * @throws NotFoundException if a DotCode cannot be found
+     * @throws FormatException if a DotCode cannot be decoded
+     * @throws ChecksumException if error correction fails
+     */
+    fn decode<B: Binarizer>(&mut self, image: &mut BinaryBitmap<B>) -> Result<RXingResult> {
+        self.decode_with_hints(image, &HashMap::new())
+    }
+
+    fn decode_with_hints<B: Binarizer>(
+        &mut self,
+        image: &mut BinaryBitmap<B>,
+        _hints: &DecodingHintDictionary,
+    ) -> Result<RXingResult> {
+        let detectorRXingResult = detector::detect(image.get_black_matrix())?;
+
+        // DotCode has no finder pattern telling which way is up, so try every rotation
+        let mut bits = detectorRXingResult.getBits().clone();
+        let mut decoded = Err(Exceptions::NOT_FOUND);
+        for _ in 0..4 {
+            decoded = dot_code_decoder::decode(&bits);
+            if decoded.is_ok() {
+                break;
+            }
+            bits.rotate90();
+        }
+        let decoderRXingResult = decoded?;
+
+        let mut result = RXingResult::new(
+            decoderRXingResult.getText(),
+            decoderRXingResult.getRawBytes().clone(),
+            detectorRXingResult.getPoints().to_vec(),
+            BarcodeFormat::DOT_CODE,
+        );
+
+        let byteSegments = decoderRXingResult.getByteSegments();
+        if !byteSegments.is_empty() {
+            result.putMetadata(
+                RXingResultMetadataType::BYTE_SEGMENTS,
+                RXingResultMetadataValue::ByteSegments(byteSegments.clone()),
+            );
+        }
+        result.putMetadata(
+            RXingResultMetadataType::SYMBOLOGY_IDENTIFIER,
+            RXingResultMetadataValue::SymbologyIdentifier(format!(
+                "]J{}",
+                decoderRXingResult.getSymbologyModifier()
+            )),
+        );
+
+        Ok(result)
+    }
+
+    fn reset(&mut self) {
+        // do nothing
+    }
+}
@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+
+use crate::{
+    common::{BitMatrix, Result},
+    BarcodeFormat, EncodeHintType, EncodeHintValue, EncodingHintDictionary, Exceptions, Writer,
+};
+
+use super::encoder::dot_code_encoder;
+
+const QUIET_ZONE_SIZE: u32 = 3;
+
+/**
+ * <p>Renders a DotCode as a {@link BitMatrix}.</p>
+ *
+ * <p>The dots are drawn a little smaller than their positions, so that diagonal neighbours do not
+ * touch once there are a few pixels per position. {@link EncodeHintType#MARGIN} sets the quiet
+ * zone, three positions by default.</p>
+ */
+#[derive(Default)]
+pub struct DotCodeWriter;
+
+impl Writer for DotCodeWriter {
+    fn encode(
+        &self,
+        contents: &str,
+        format: &BarcodeFormat,
+        width: i32,
+        height: i32,
+    ) -> Result<BitMatrix> {
+        self.encode_with_hints(contents, format, width, height, &HashMap::new())
+    }
+
+    fn encode_with_hints(
+        &self,
+        contents: &str,
+        format: &BarcodeFormat,
+        width: i32,
+        height: i32,
+        hints: &EncodingHintDictionary,
+    ) -> Result<BitMatrix> {
+        if contents.is_empty() {
+            return Err(Exceptions::illegal_argument_with("Found empty contents"));
+        }
+
+        if format != &BarcodeFormat::DOT_CODE {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "Can only encode DOT_CODE, but got {format:?}"
+            )));
+        }
+
+        if width < 0 || height < 0 {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "Requested dimensions can't be negative: {width}x{height}"
+            )));
+        }
+
+        let quietZone =
+            if let Some(EncodeHintValue::Margin(margin)) = hints.get(&EncodeHintType::MARGIN) {
+                margin
+                    .parse::<u32>()
+                    .map_err(|e| Exceptions::parse_with(format!("could not parse {margin}: {e}")))?
+            } else {
+                QUIET_ZONE_SIZE
+            };
+
+        let code = dot_code_encoder::encode_with_hints(contents, hints)?;
+        render(&code, width as u32, height as u32, quietZone)
+    }
+}
+
+/**
+ * Scales the symbol up to the requested size by a whole number of pixels per dot position, and
+ * centres it with at least the quiet zone around it.
+ */
+fn render(code: &BitMatrix, width: u32, height: u32, quietZone: u32) -> Result<BitMatrix> {
+    let inputWidth = code.getWidth();
+    let inputHeight = code.getHeight();
+    let fullWidth = inputWidth + 2 * quietZone;
+    let fullHeight = inputHeight + 2 * quietZone;
+    let outputWidth = width.max(fullWidth);
+    let outputHeight = height.max(fullHeight);
+    let multiple = (outputWidth / fullWidth).min(outputHeight / fullHeight);
+    let leftPadding = (outputWidth - inputWidth * multiple) / 2;
+    let topPadding = (outputHeight - inputHeight * multiple) / 2;
+    // leave a gap of a quarter of a position around each dot
+    let dotSize = multiple - multiple / 4;
+    let dotOffset = (multiple - dotSize) / 2;
+
+    let mut output = BitMatrix::new(outputWidth, outputHeight)?;
+    for y in 0..inputHeight {
+        for x in 0..inputWidth {
+            if code.get(x, y) {
+                output.setRegion(
+                    leftPadding + x * multiple + dotOffset,
+                    topPadding + y * multiple + dotOffset,
+                    dotSize,
+                    dotSize,
+                )?;
+            }
+        }
+    }
+    Ok(output)
+}
@@ -0,0 +1,212 @@
+use crate::{
+    common::{BitMatrix, CharacterSet, Result},
+    dotcode::dot_code_common::{
+        applyMask, getBlockPositions, getDataCapacity, getDotPositions,
+        getErrorCorrectionCodewords, getNumberOfBlocks, BITS_PER_CODEWORD, DOT_CODE_FIELD,
+        DOT_PATTERNS, MASK_INDICATOR_BITS, MAX_SIZE, MIN_SIZE, NUM_MASKS, PAD,
+    },
+    EncodeHintType, EncodeHintValue, EncodingHintDictionary, Exceptions,
+};
+
+use super::high_level_encoder;
+
+/** Penalty for each edge of the symbol without a dot, which would make its size unreadable */
+const EMPTY_EDGE_PENALTY: u32 = 1000;
+/** Penalty for each dot without a diagonal neighbour */
+const LONE_DOT_PENALTY: u32 = 1;
+
+/**
+ * <p>Encodes the contents into a DotCode symbol, one module per dot position, without quiet
+ * zone.</p>
+ *
+ * <p>The symbol is about one and a half times as wide as it is high, the smallest one which holds
+ * the contents. The rest of it is filled with pad codewords. {@link EncodeHintType#CHARACTER_SET}
+ * selects the character set of characters outside of ASCII.</p>
+ *
+ * <p>Every mask is tried and the one with the fewest lone dots is taken. If all of them leave an
+ * edge of the symbol empty, a dot is forced onto that edge, for error correction to revert.</p>
+ */
+pub fn encode_with_hints(contents: &str, hints: &EncodingHintDictionary) -> Result<BitMatrix> {
+    let encoding = if let Some(EncodeHintValue::CharacterSet(name)) =
+        hints.get(&EncodeHintType::CHARACTER_SET)
+    {
+        Some(CharacterSet::get_character_set_by_name(name).ok_or(Exceptions::WRITER)?)
+    } else {
+        None
+    };
+
+    let mut data = high_level_encoder::encodeHighLevel(contents, encoding)?;
+    // the mask indicator comes in front of the data
+    let (width, height) = chooseSize(data.len() as u32 + 1)?;
+    let dataCodewords = getDataCapacity(width, height).ok_or(Exceptions::ILLEGAL_STATE)?;
+    data.resize(dataCodewords as usize - 1, PAD);
+
+    let mut best = None;
+    for mask in 0..NUM_MASKS {
+        let mut codewords = Vec::with_capacity(dataCodewords as usize);
+        codewords.push(mask);
+        codewords.extend_from_slice(&data);
+        applyMask(&mut codewords, mask);
+        addErrorCorrection(&mut codewords);
+        let matrix = placeDots(&codewords, width, height)?;
+        let penalty = calculateMaskPenalty(&matrix);
+        if best
+            .as_ref()
+            .is_none_or(|(bestPenalty, _)| penalty < *bestPenalty)
+        {
+            best = Some((penalty, matrix));
+        }
+    }
+    let (_, mut matrix) = best.ok_or(Exceptions::ILLEGAL_STATE)?;
+    forceEdgeDots(&mut matrix);
+    Ok(matrix)
+}
+
+/**
+ * @return the width and height of the smallest symbol about one and a half times as wide as it is
+ * high, which holds the data codewords
+ */
+fn chooseSize(dataCodewords: u32) -> Result<(u32, u32)> {
+    for height in MIN_SIZE..=MAX_SIZE {
+        let mut width = (height * 3 / 2).min(MAX_SIZE);
+        if (width + height).is_multiple_of(2) {
+            width -= 1;
+        }
+        if getDataCapacity(width, height).is_some_and(|capacity| capacity >= dataCodewords) {
+            return Ok((width, height));
+        }
+    }
+    Err(Exceptions::writer_with("data too big"))
+}
+
+/**
+ * Appends the error correction codewords, interleaving the codewords into blocks no longer than
+ * the field allows.
+ */
+fn addErrorCorrection(codewords: &mut Vec<u32>) {
+    let dataCodewords = codewords.len() as u32;
+    let totalCodewords = dataCodewords + getErrorCorrectionCodewords(dataCodewords);
+    codewords.resize(totalCodewords as usize, 0);
+    for block in 0..getNumberOfBlocks(totalCodewords) {
+        let (positions, blockDataCodewords) =
+            getBlockPositions(dataCodewords, totalCodewords, block);
+        let data = positions[..blockDataCodewords]
+            .iter()
+            .map(|position| codewords[*position])
+            .collect::<Vec<_>>();
+        let ecCodewords = generateErrorCorrection(&data, positions.len() - blockDataCodewords);
+        for (position, codeword) in positions[blockDataCodewords..].iter().zip(ecCodewords) {
+            codewords[*position] = codeword;
+        }
+    }
+}
+
+/**
+ * <p>Divides the data by the generator polynomial, whose roots are 3^1 to 3^n. The negated
+ * remainder makes the whole block a multiple of it.</p>
+ */
+fn generateErrorCorrection(data: &[u32], numECCodewords: usize) -> Vec<u32> {
+    let field = &DOT_CODE_FIELD;
+    // the coefficients of the generator, highest degree first
+    let mut generator = vec![1];
+    for i in 1..=numECCodewords {
+        let root = field.exp(i as u32);
+        generator.push(0);
+        for j in (1..generator.len()).rev() {
+            generator[j] = field.subtract(generator[j], field.multiply(root, generator[j - 1]));
+        }
+    }
+
+    let mut remainder = vec![0; numECCodewords];
+    for codeword in data {
+        let factor = field.add(*codeword, remainder[0]);
+        remainder.rotate_left(1);
+        remainder[numECCodewords - 1] = 0;
+        for (term, coefficient) in remainder.iter_mut().zip(&generator[1..]) {
+            *term = field.subtract(*term, field.multiply(factor, *coefficient));
+        }
+    }
+    remainder
+        .iter()
+        .map(|term| field.subtract(0, *term))
+        .collect()
+}
+
+/**
+ * Places the two bits of the mask indicator and the dot patterns of the other codewords onto the
+ * dot positions, in the order they are folded in. The positions left over hold dots.
+ */
+fn placeDots(codewords: &[u32], width: u32, height: u32) -> Result<BitMatrix> {
+    let bits = (0..MASK_INDICATOR_BITS)
+        .rev()
+        .map(|i| codewords[0] & (1 << i) != 0)
+        .chain(codewords[1..].iter().flat_map(|codeword| {
+            let pattern = DOT_PATTERNS[*codeword as usize];
+            (0..BITS_PER_CODEWORD)
+                .rev()
+                .map(move |i| pattern & (1 << i) != 0)
+        }))
+        .chain(std::iter::repeat(true));
+
+    let mut matrix = BitMatrix::new(width, height)?;
+    for ((x, y), dot) in getDotPositions(width, height).into_iter().zip(bits) {
+        if dot {
+            matrix.set(x, y);
+        }
+    }
+    Ok(matrix)
+}
+
+/**
+ * <p>Scores a masked symbol, the lower the better. An edge without a dot is penalized heavily, as
+ * the size of the symbol could not be told, a dot without neighbours a little, as it is easily
+ * lost in printing.</p>
+ */
+fn calculateMaskPenalty(matrix: &BitMatrix) -> u32 {
+    let width = matrix.getWidth();
+    let height = matrix.getHeight();
+    let emptyEdges = [
+        (0..width).all(|x| !matrix.get(x, 0)),
+        (0..width).all(|x| !matrix.get(x, height - 1)),
+        (0..height).all(|y| !matrix.get(0, y)),
+        (0..height).all(|y| !matrix.get(width - 1, y)),
+    ]
+    .iter()
+    .filter(|empty| **empty)
+    .count() as u32;
+
+    let isDot =
+        |x: i32, y: i32| x >= 0 && y >= 0 && matrix.try_get(x as u32, y as u32) == Some(true);
+    let loneDots = getDotPositions(width, height)
+        .into_iter()
+        .map(|(x, y)| (x as i32, y as i32))
+        .filter(|(x, y)| {
+            isDot(*x, *y)
+                && [(-1, -1), (1, -1), (-1, 1), (1, 1)]
+                    .iter()
+                    .all(|(dx, dy)| !isDot(x + dx, y + dy))
+        })
+        .count() as u32;
+
+    EMPTY_EDGE_PENALTY * emptyEdges + LONE_DOT_PENALTY * loneDots
+}
+
+/**
+ * Sets the first dot position of every edge without a dot.
+ */
+fn forceEdgeDots(matrix: &mut BitMatrix) {
+    let width = matrix.getWidth();
+    let height = matrix.getHeight();
+    if (0..width).all(|x| !matrix.get(x, 0)) {
+        matrix.set(0, 0);
+    }
+    if (0..width).all(|x| !matrix.get(x, height - 1)) {
+        matrix.set((height - 1) % 2, height - 1);
+    }
+    if (0..height).all(|y| !matrix.get(0, y)) {
+        matrix.set(0, 0);
+    }
+    if (0..height).all(|y| !matrix.get(width - 1, y)) {
+        matrix.set(width - 1, (width - 1) % 2);
+    }
+}
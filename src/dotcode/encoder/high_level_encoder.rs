@@ -0,0 +1,172 @@
+use crate::{
+    common::{CharacterSet, Eci, Result},
+    dotcode::dot_code_common::{
+        BINARY_BASE, BINARY_GROUP_SIZE, ECI, ECI_SINGLE_CODEWORD_LIMIT, LATCH_AB, LATCH_BINARY,
+        LATCH_BINARY_A, LATCH_BINARY_B, LATCH_BINARY_C, LATCH_C, LATCH_C_A, LATCH_C_B,
+        LATCH_C_BINARY, NUMBER_OF_CODEWORDS, SHIFT_AB, UPPER_SHIFT,
+    },
+    Exceptions,
+};
+
+/** Runs of digits at least this long are encoded in Code Set C */
+const MIN_DIGITS_RUN: usize = 4;
+/** Runs of bytes above 127 at least this long are encoded in binary mode */
+const MIN_BINARY_RUN: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CodeSet {
+    A,
+    B,
+    C,
+    Binary,
+}
+
+/**
+ * <p>Converts the contents into data codewords, starting in Code Set C.</p>
+ *
+ * <p>The contents are encoded in ISO-8859-1, or in UTF-8 if they cannot be, unless a character
+ * set is given. Any other character set than ISO-8859-1 is announced by an ECI.</p>
+ *
+ * <p>Runs of digits are encoded as pairs in Code Set C, and long runs of bytes above 127 in
+ * binary mode. Every other character goes into Code Set A or B, with a shift for a single
+ * character of the other one and an upper shift for the bytes above 127.</p>
+ */
+pub fn encodeHighLevel(contents: &str, encoding: Option<CharacterSet>) -> Result<Vec<u32>> {
+    let (bytes, eci) = match encoding {
+        Some(encoding) => (
+            encoding.encode(contents)?,
+            (encoding != CharacterSet::ISO8859_1).then(|| Eci::from(encoding)),
+        ),
+        None => match CharacterSet::ISO8859_1.encode(contents) {
+            Ok(bytes) => (bytes, None),
+            Err(_) => (CharacterSet::UTF8.encode(contents)?, Some(Eci::UTF8)),
+        },
+    };
+
+    let mut codewords = Vec::with_capacity(bytes.len());
+    if let Some(eci) = eci {
+        appendECI(&mut codewords, eci as u32)?;
+    }
+
+    let mut codeSet = CodeSet::C;
+    let mut i = 0;
+    while i < bytes.len() {
+        let digits = bytes[i..]
+            .iter()
+            .take_while(|byte| byte.is_ascii_digit())
+            .count();
+        if digits >= MIN_DIGITS_RUN || (digits >= 2 && digits == bytes.len() - i) {
+            latch(&mut codewords, &mut codeSet, CodeSet::C);
+            let pairs = digits / 2;
+            for pair in bytes[i..i + 2 * pairs].chunks(2) {
+                codewords.push(((pair[0] - b'0') * 10 + pair[1] - b'0') as u32);
+            }
+            i += 2 * pairs;
+            continue;
+        }
+
+        let high = bytes[i..].iter().take_while(|byte| **byte >= 128).count();
+        if high >= MIN_BINARY_RUN {
+            latch(&mut codewords, &mut codeSet, CodeSet::Binary);
+            appendBinary(&mut codewords, &bytes[i..i + high]);
+            i += high;
+            continue;
+        }
+
+        let byte = bytes[i];
+        let needed = getNeededCodeSet(byte);
+        let mut current = codeSet;
+        if matches!(codeSet, CodeSet::C | CodeSet::Binary) {
+            // the next character may tell which of Code Sets A and B to latch to
+            let target = needed
+                .or_else(|| bytes.get(i + 1).and_then(|next| getNeededCodeSet(*next)))
+                .unwrap_or(CodeSet::B);
+            latch(&mut codewords, &mut codeSet, target);
+            current = codeSet;
+        } else if let Some(needed) = needed.filter(|needed| *needed != codeSet) {
+            // latch if the next character needs the other code set as well, shift otherwise
+            if bytes.get(i + 1).and_then(|next| getNeededCodeSet(*next)) == Some(needed) {
+                latch(&mut codewords, &mut codeSet, needed);
+            } else {
+                codewords.push(SHIFT_AB);
+            }
+            current = needed;
+        }
+        if byte >= 128 {
+            codewords.push(UPPER_SHIFT);
+        }
+        let base = (byte & 0x7F) as u32;
+        codewords.push(match current {
+            CodeSet::A if base < 32 => base + 64,
+            _ => base - 32,
+        });
+        i += 1;
+    }
+    Ok(codewords)
+}
+
+/**
+ * @return the one of Code Sets A and B which can encode the byte, if only one of them can
+ */
+fn getNeededCodeSet(byte: u8) -> Option<CodeSet> {
+    match byte & 0x7F {
+        0..=31 => Some(CodeSet::A),
+        96..=127 => Some(CodeSet::B),
+        _ => None,
+    }
+}
+
+fn latch(codewords: &mut Vec<u32>, codeSet: &mut CodeSet, target: CodeSet) {
+    if *codeSet == target {
+        return;
+    }
+    codewords.push(match (*codeSet, target) {
+        (CodeSet::C, CodeSet::A) => LATCH_C_A,
+        (CodeSet::C, CodeSet::B) => LATCH_C_B,
+        (CodeSet::C, _) => LATCH_C_BINARY,
+        (CodeSet::Binary, CodeSet::A) => LATCH_BINARY_A,
+        (CodeSet::Binary, CodeSet::B) => LATCH_BINARY_B,
+        (CodeSet::Binary, _) => LATCH_BINARY_C,
+        (_, CodeSet::C) => LATCH_C,
+        (_, CodeSet::Binary) => LATCH_BINARY,
+        _ => LATCH_AB,
+    });
+    *codeSet = target;
+}
+
+/**
+ * Compacts every group of five bytes into six base 103 digits, and a shorter last group into one
+ * digit more than it has bytes.
+ */
+fn appendBinary(codewords: &mut Vec<u32>, bytes: &[u8]) {
+    for group in bytes.chunks(BINARY_GROUP_SIZE) {
+        let mut value = group
+            .iter()
+            .fold(0u64, |value, byte| (value << 8) | *byte as u64);
+        let start = codewords.len();
+        for _ in 0..=group.len() {
+            codewords.push((value % BINARY_BASE as u64) as u32);
+            value /= BINARY_BASE as u64;
+        }
+        codewords[start..].reverse();
+    }
+}
+
+fn appendECI(codewords: &mut Vec<u32>, value: u32) -> Result<()> {
+    codewords.push(ECI);
+    if value < ECI_SINGLE_CODEWORD_LIMIT {
+        codewords.push(value);
+        return Ok(());
+    }
+    let value = value - ECI_SINGLE_CODEWORD_LIMIT;
+    let first = ECI_SINGLE_CODEWORD_LIMIT + value / NUMBER_OF_CODEWORDS;
+    if first >= NUMBER_OF_CODEWORDS {
+        return Err(Exceptions::writer_with(format!(
+            "ECI {} can not be encoded",
+            value + ECI_SINGLE_CODEWORD_LIMIT
+        )));
+    }
+    codewords.push(first);
+    codewords.push(value % NUMBER_OF_CODEWORDS);
+    Ok(())
+}
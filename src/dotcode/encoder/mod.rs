@@ -0,0 +1,3 @@
+pub mod dot_code_encoder;
+mod high_level_encoder;
+pub use high_level_encoder::*;
@@ -0,0 +1,12 @@
+pub mod decoder;
+pub mod detector;
+pub mod dot_code_common;
+mod dot_code_reader;
+mod dot_code_writer;
+pub mod encoder;
+
+pub use dot_code_reader::*;
+pub use dot_code_writer::*;
+
+#[cfg(test)]
+mod DotCodeTestCase;
@@ -18,7 +18,10 @@
 
 #![allow(deprecated)]
 
-use crate::{pdf417::encoder::Dimensions, Dimension};
+use crate::{
+    pdf417::encoder::{Dimensions, MacroPDF417},
+    Dimension,
+};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -68,6 +71,14 @@ pub enum EncodeHintType {
      */
     DATA_MATRIX_COMPACT,
 
+    /**
+     * Specifies whether the rectangular extension sizes (DMRE) of ISO/IEC 21471, such as 8x48 or 24x64,
+     * may be chosen for Data Matrix (type {@link Boolean}, or "true" or "false" {@link String} value).
+     * Defaults to false, in which case only the ISO/IEC 16022 sizes are used. 8x80 and 8x120 are
+     * never chosen, as 16x36 and 16x48 hold as many codewords and are tried first.
+     */
+    DATA_MATRIX_DMRE,
+
     /**
      * Specifies a minimum barcode size (type {@link Dimension}). Only applicable to Data Matrix now.
      *
@@ -79,10 +90,9 @@ pub enum EncodeHintType {
 
     /**
      * Specifies a maximum barcode size (type {@link Dimension}). Only applicable to Data Matrix now.
-     *
-     * @deprecated without replacement
+     * It also limits the symbols a message is split across by
+     * {@link crate::datamatrix::DataMatrixWriter#encode_structured_append}.
      */
-    #[deprecated]
     MAX_SIZE,
 
     /**
@@ -121,6 +131,14 @@ pub enum EncodeHintType {
      */
     PDF417_AUTO_ECI,
 
+    /**
+     * Specifies a Macro PDF417 control block to add to a PDF417 symbol (type
+     * {@link crate::pdf417::encoder::MacroPDF417}). When splitting contents with
+     * {@link crate::pdf417::PDF417Writer#encode_macro}, its segment index, segment count
+     * and last segment marker are filled in for each symbol.
+     */
+    PDF417_MACRO,
+
     /**
      * Specifies the required number of layers for an Aztec code.
      * A negative number (-1, -2, -3, -4) specifies a compact Aztec code.
@@ -130,16 +148,35 @@ pub enum EncodeHintType {
      */
     AZTEC_LAYERS,
 
+    /**
+     * Specifies whether to encode an Aztec Rune instead of an Aztec code (type {@link Boolean}, or "true"
+     * or "false" {@link String} value). The contents must then be a decimal value from 0 to 255.
+     */
+    AZTEC_RUNE,
+
     /**
      * Specifies the exact version of QR code to be encoded.
      * (Type {@link Integer}, or {@link String} representation of the integer value).
      */
     QR_VERSION,
 
+    /**
+     * Specifies the exact version of Micro QR code to be encoded, 1 to 4 for M1 to M4.
+     * (Type {@link Integer}, or {@link String} representation of the integer value).
+     */
+    MICRO_QR_VERSION,
+
+    /**
+     * Specifies the exact version of rMQR code to be encoded, either 1 to 32 or the size name
+     * such as "R11x43" (height x width in modules).
+     * (Type {@link String}).
+     */
+    RMQR_VERSION,
+
     /**
      * Specifies the QR code mask pattern to be used. Allowed values are
      * 0..QRCode.NUM_MASK_PATTERNS-1. By default the code will automatically select
-     * the optimal mask pattern.
+     * the optimal mask pattern. Micro QR codes only have the mask patterns 0..3.
      * * (Type {@link Integer}, or {@link String} representation of the integer value).
      */
     QR_MASK_PATTERN,
@@ -180,6 +217,107 @@ pub enum EncodeHintType {
      * exclusive.
      */
     CODE128_COMPACT,
+
+    /**
+     * Specifies the MaxiCode mode to encode (type {@link Integer}, 2 to 6, default 4).
+     * Modes 2 and 3 expect a structured carrier message of the form
+     * `postcode GS country GS service class GS message`, mode 2 is used for numeric postcodes
+     * of up to 9 digits and mode 3 for alphanumeric postcodes of up to 6 characters.
+     */
+    MAXICODE_MODE,
+
+    /**
+     * Specifies the RSS-14 (GS1 DataBar) layout to encode (type
+     * {@link crate::oned::rss::RSS14Variant}), defaults to omnidirectional.
+     */
+    RSS_14_VARIANT,
+
+    /**
+     * Specifies the number of segments per row of a stacked RSS Expanded (GS1 DataBar Expanded)
+     * symbol (type {@link Integer}, an even number from 2 to 22). Without it the symbol is
+     * encoded on a single row.
+     */
+    RSS_EXPANDED_SEGMENTS,
+
+    /**
+     * Specifies the maximum number of symbols a message may be split across by the structured
+     * append encoding of the QR Code, Data Matrix and Aztec writers (type {@link Integer}).
+     * Defaults to the maximum of the format, 16 for QR Code and Data Matrix and 26 for Aztec.
+     */
+    STRUCTURED_APPEND_MAX_SYMBOLS,
+
+    /**
+     * Specifies the file id the structured append encoding of the Data Matrix and Aztec writers
+     * marks all symbols of a message with (type {@link String}). For Data Matrix it is the
+     * number fileId1 * 256 + fileId2 as reported by the reader, both bytes being 1 to 254,
+     * for Aztec a message id of upper case letters. QR Code symbols carry the parity of the
+     * message instead.
+     */
+    STRUCTURED_APPEND_ID,
+
+    /**
+     * Specifies a 2 or 5 digit add-on to append to EAN-13, UPC-A and UPC-E symbols (type
+     * {@link String}). The add-on may also follow the main digits in the contents, separated
+     * by '+' or a space.
+     */
+    UPC_EAN_EXTENSION,
+
+    /**
+     * Specifies the check digit scheme of MSI Plessey symbols (type
+     * {@link crate::oned::MSICheckDigit}), defaults to a single modulo 10 check digit.
+     */
+    MSI_CHECK_DIGIT,
+
+    /**
+     * Specifies the number of Code 11 check digits, C and K, to append (type {@link Integer},
+     * 0 to 2). Defaults to C alone for up to 10 characters and both C and K for longer contents.
+     */
+    CODE_11_CHECK_DIGITS,
+
+    /**
+     * Specifies whether to encode a two-track Laetus Pharmacode, whose bars fill the top track,
+     * the bottom track or both, instead of the one-track code of narrow and wide bars (type
+     * {@link Boolean}).
+     */
+    PHARMACODE_TWO_TRACK,
+
+    /**
+     * Specifies whether to encode Telepen contents as double density numeric data, two digits per
+     * character, instead of ASCII (type {@link Boolean}).
+     */
+    TELEPEN_NUMERIC,
+
+    /**
+     * Specifies whether to encode ITF-14: 13 digits, to which the GS1 modulo 10 check digit is
+     * appended, or 14 digits including a valid check digit. Bearer bars default to a box
+     * (type {@link Boolean}).
+     */
+    ITF_14,
+
+    /**
+     * Specifies the bearer bars drawn around ITF symbols (type
+     * {@link crate::oned::ITFBearerBars}). Defaults to none, or to a box for ITF-14.
+     */
+    ITF_BEARER_BARS,
+
+    /**
+     * Specifies the exact version of Han Xin Code to be encoded, 1 to 84.
+     * (Type {@link Integer}, or {@link String} representation of the integer value).
+     */
+    HAN_XIN_VERSION,
+
+    /**
+     * Specifies whether to append a modulo 10 check digit to Industrial, IATA, Matrix and
+     * Datalogic 2 of 5 symbols (type {@link Boolean}).
+     */
+    TWO_OF_FIVE_CHECK_DIGIT,
+
+    /**
+     * Specifies the model of QR Code to be encoded, 1 for the original QR Code or 2, the default.
+     * Model 1 symbols come in versions 1 to 14 and know neither ECI nor GS1 formatted data.
+     * (Type {@link Integer}, or {@link String} representation of the integer value).
+     */
+    QR_MODEL,
 }
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -221,6 +359,14 @@ pub enum EncodeHintValue {
      */
     DataMatrixCompact(bool),
 
+    /**
+     * Specifies whether the rectangular extension sizes (DMRE) of ISO/IEC 21471, such as 8x48 or 24x64,
+     * may be chosen for Data Matrix (type {@link Boolean}, or "true" or "false" {@link String} value).
+     * Defaults to false, in which case only the ISO/IEC 16022 sizes are used. 8x80 and 8x120 are
+     * never chosen, as 16x36 and 16x48 hold as many codewords and are tried first.
+     */
+    DataMatrixDmre(bool),
+
     /**
      * Specifies a minimum barcode size (type {@link Dimension}). Only applicable to Data Matrix now.
      *
@@ -232,10 +378,9 @@ pub enum EncodeHintValue {
 
     /**
      * Specifies a maximum barcode size (type {@link Dimension}). Only applicable to Data Matrix now.
-     *
-     * @deprecated without replacement
+     * It also limits the symbols a message is split across by
+     * {@link crate::datamatrix::DataMatrixWriter#encode_structured_append}.
      */
-    #[deprecated]
     MaxSize(Dimension),
 
     /**
@@ -274,6 +419,14 @@ pub enum EncodeHintValue {
      */
     Pdf417AutoEci(String),
 
+    /**
+     * Specifies a Macro PDF417 control block to add to a PDF417 symbol (type
+     * {@link crate::pdf417::encoder::MacroPDF417}). When splitting contents with
+     * {@link crate::pdf417::PDF417Writer#encode_macro}, its segment index, segment count
+     * and last segment marker are filled in for each symbol.
+     */
+    Pdf417Macro(MacroPDF417),
+
     /**
      * Specifies the required number of layers for an Aztec code.
      * A negative number (-1, -2, -3, -4) specifies a compact Aztec code.
@@ -283,16 +436,35 @@ pub enum EncodeHintValue {
      */
     AztecLayers(i32),
 
+    /**
+     * Specifies whether to encode an Aztec Rune instead of an Aztec code (type {@link Boolean}, or "true"
+     * or "false" {@link String} value). The contents must then be a decimal value from 0 to 255.
+     */
+    AztecRune(bool),
+
     /**
      * Specifies the exact version of QR code to be encoded.
      * (Type {@link Integer}, or {@link String} representation of the integer value).
      */
     QrVersion(String),
 
+    /**
+     * Specifies the exact version of Micro QR code to be encoded, 1 to 4 for M1 to M4.
+     * (Type {@link Integer}, or {@link String} representation of the integer value).
+     */
+    MicroQrVersion(String),
+
+    /**
+     * Specifies the exact version of rMQR code to be encoded, either 1 to 32 or the size name
+     * such as "R11x43" (height x width in modules).
+     * (Type {@link String}).
+     */
+    RmqrVersion(String),
+
     /**
      * Specifies the QR code mask pattern to be used. Allowed values are
      * 0..QRCode.NUM_MASK_PATTERNS-1. By default the code will automatically select
-     * the optimal mask pattern.
+     * the optimal mask pattern. Micro QR codes only have the mask patterns 0..3.
      * * (Type {@link Integer}, or {@link String} representation of the integer value).
      */
     QrMaskPattern(String),
@@ -333,4 +505,105 @@ pub enum EncodeHintValue {
      * exclusive.
      */
     Code128Compact(bool),
+
+    /**
+     * Specifies the MaxiCode mode to encode (type {@link Integer}, 2 to 6, default 4).
+     * Modes 2 and 3 expect a structured carrier message of the form
+     * `postcode GS country GS service class GS message`, mode 2 is used for numeric postcodes
+     * of up to 9 digits and mode 3 for alphanumeric postcodes of up to 6 characters.
+     */
+    MaxicodeMode(u8),
+
+    /**
+     * Specifies the RSS-14 (GS1 DataBar) layout to encode (type
+     * {@link crate::oned::rss::RSS14Variant}), defaults to omnidirectional.
+     */
+    Rss14Variant(crate::oned::rss::RSS14Variant),
+
+    /**
+     * Specifies the number of segments per row of a stacked RSS Expanded (GS1 DataBar Expanded)
+     * symbol (type {@link Integer}, an even number from 2 to 22). Without it the symbol is
+     * encoded on a single row.
+     */
+    RssExpandedSegments(u32),
+
+    /**
+     * Specifies the maximum number of symbols a message may be split across by the structured
+     * append encoding of the QR Code, Data Matrix and Aztec writers (type {@link Integer}).
+     * Defaults to the maximum of the format, 16 for QR Code and Data Matrix and 26 for Aztec.
+     */
+    StructuredAppendMaxSymbols(u32),
+
+    /**
+     * Specifies the file id the structured append encoding of the Data Matrix and Aztec writers
+     * marks all symbols of a message with (type {@link String}). For Data Matrix it is the
+     * number fileId1 * 256 + fileId2 as reported by the reader, both bytes being 1 to 254,
+     * for Aztec a message id of upper case letters. QR Code symbols carry the parity of the
+     * message instead.
+     */
+    StructuredAppendId(String),
+
+    /**
+     * Specifies a 2 or 5 digit add-on to append to EAN-13, UPC-A and UPC-E symbols (type
+     * {@link String}). The add-on may also follow the main digits in the contents, separated
+     * by '+' or a space.
+     */
+    UpcEanExtension(String),
+
+    /**
+     * Specifies the check digit scheme of MSI Plessey symbols (type
+     * {@link crate::oned::MSICheckDigit}), defaults to a single modulo 10 check digit.
+     */
+    MsiCheckDigit(crate::oned::MSICheckDigit),
+
+    /**
+     * Specifies the number of Code 11 check digits, C and K, to append (type {@link Integer},
+     * 0 to 2). Defaults to C alone for up to 10 characters and both C and K for longer contents.
+     */
+    Code11CheckDigits(u32),
+
+    /**
+     * Specifies whether to encode a two-track Laetus Pharmacode, whose bars fill the top track,
+     * the bottom track or both, instead of the one-track code of narrow and wide bars (type
+     * {@link Boolean}).
+     */
+    PharmacodeTwoTrack(bool),
+
+    /**
+     * Specifies whether to encode Telepen contents as double density numeric data, two digits per
+     * character, instead of ASCII (type {@link Boolean}).
+     */
+    TelepenNumeric(bool),
+
+    /**
+     * Specifies whether to encode ITF-14: 13 digits, to which the GS1 modulo 10 check digit is
+     * appended, or 14 digits including a valid check digit. Bearer bars default to a box
+     * (type {@link Boolean}).
+     */
+    Itf14(bool),
+
+    /**
+     * Specifies the bearer bars drawn around ITF symbols (type
+     * {@link crate::oned::ITFBearerBars}). Defaults to none, or to a box for ITF-14.
+     */
+    ItfBearerBars(crate::oned::ITFBearerBars),
+
+    /**
+     * Specifies the exact version of Han Xin Code to be encoded, 1 to 84.
+     * (Type {@link Integer}, or {@link String} representation of the integer value).
+     */
+    HanXinVersion(String),
+
+    /**
+     * Specifies whether to append a modulo 10 check digit to Industrial, IATA, Matrix and
+     * Datalogic 2 of 5 symbols (type {@link Boolean}).
+     */
+    TwoOfFiveCheckDigit(bool),
+
+    /**
+     * Specifies the model of QR Code to be encoded, 1 for the original QR Code or 2, the default.
+     * Model 1 symbols come in versions 1 to 14 and know neither ECI nor GS1 formatted data.
+     * (Type {@link Integer}, or {@link String} representation of the integer value).
+     */
+    QrModel(String),
 }
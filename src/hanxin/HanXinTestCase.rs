@@ -0,0 +1,334 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    common::{bit_matrix_test_case, BitMatrix},
+    hanxin::{
+        decoder::{
+            han_xin_decoder, ErrorCorrectionLevel, FunctionInformation, Version, DATA_MODULE,
+        },
+        encoder::han_xin_encoder,
+        HanXinReader, HanXinWriter,
+    },
+    BarcodeFormat, DecodeHintType, DecodeHintValue, EncodeHintType, EncodeHintValue, Exceptions,
+    MultiFormatReader, MultiUseMultiFormatReader, RXingResultMetadataType,
+    RXingResultMetadataValue, Reader, Writer,
+};
+
+#[test]
+fn testVersionCapacities() {
+    for (versionNumber, totalCodewords) in [(1, 25), (3, 50), (4, 54), (10, 155), (84, 3886)] {
+        let version = Version::getVersionForNumber(versionNumber).expect("version");
+        assert_eq!(21 + 2 * versionNumber, version.getDimensionForVersion());
+        assert_eq!(totalCodewords, version.getTotalCodewords());
+    }
+    for versionNumber in 1..=84 {
+        let version = Version::getVersionForNumber(versionNumber).expect("version");
+        let dataModules = version
+            .buildFunctionGrid()
+            .iter()
+            .filter(|module| **module == DATA_MODULE)
+            .count() as u32;
+        assert_eq!(dataModules / 8, version.getTotalCodewords());
+        for ecLevel in [
+            ErrorCorrectionLevel::L1,
+            ErrorCorrectionLevel::L2,
+            ErrorCorrectionLevel::L3,
+            ErrorCorrectionLevel::L4,
+        ] {
+            let blocks = version.getECBlocksForLevel(ecLevel);
+            assert_eq!(
+                version.getTotalCodewords(),
+                blocks
+                    .iter()
+                    .map(|block| block.getDataCodewords() + block.getECCodewords())
+                    .sum::<u32>()
+            );
+            assert!(blocks.iter().all(|block| block.getECCodewords() <= 32));
+        }
+    }
+    assert!(Version::getVersionForNumber(85).is_err());
+    assert!(Version::getVersionForDimension(24).is_err());
+}
+
+#[test]
+fn testECBlocks() {
+    let version = Version::getVersionForNumber(1).expect("version");
+    let blocks = version.getECBlocksForLevel(ErrorCorrectionLevel::L4);
+    assert_eq!(1, blocks.len());
+    assert_eq!(9, blocks[0].getDataCodewords());
+    assert_eq!(16, blocks[0].getECCodewords());
+
+    // entries of Table D.1
+    for (versionNumber, ecLevel, expected) in [
+        (5, ErrorCorrectionLevel::L4, vec![(14, 20), (15, 20)]),
+        (6, ErrorCorrectionLevel::L3, vec![(24, 20), (22, 18)]),
+        (
+            7,
+            ErrorCorrectionLevel::L4,
+            vec![(14, 20), (14, 20), (12, 20)],
+        ),
+        (10, ErrorCorrectionLevel::L2, vec![(52, 22), (57, 24)]),
+        (
+            15,
+            ErrorCorrectionLevel::L3,
+            vec![(33, 28), (33, 28), (33, 28), (36, 30)],
+        ),
+    ] {
+        let version = Version::getVersionForNumber(versionNumber).expect("version");
+        assert_eq!(
+            expected,
+            version
+                .getECBlocksForLevel(ecLevel)
+                .iter()
+                .map(|block| (block.getDataCodewords(), block.getECCodewords()))
+                .collect::<Vec<_>>(),
+            "version {versionNumber}"
+        );
+    }
+}
+
+#[test]
+fn testErrorCorrectionLevel() {
+    assert_eq!(ErrorCorrectionLevel::L1, "L1".parse().expect("parse"));
+    assert_eq!(ErrorCorrectionLevel::L4, "4".parse().expect("parse"));
+    assert!("L5".parse::<ErrorCorrectionLevel>().is_err());
+    assert_eq!("L3", ErrorCorrectionLevel::L3.to_string());
+}
+
+#[test]
+fn testFunctionInformation() {
+    let version = Version::getVersionForNumber(7).expect("version");
+    let information = FunctionInformation::new(version, ErrorCorrectionLevel::L3, 2);
+    let bits = information.encode().expect("encode");
+    let dimension = version.getDimensionForVersion();
+    let positions = FunctionInformation::bitPositions(dimension);
+
+    let mut matrix = BitMatrix::with_single_dimension(dimension).expect("matrix");
+    for copy in &positions {
+        for ((x, y), bit) in copy.iter().zip(bits) {
+            if bit {
+                matrix.set(*x, *y);
+            }
+        }
+    }
+    assert_eq!(
+        information,
+        FunctionInformation::read(&matrix).expect("read")
+    );
+
+    // two errors in the first copy are corrected
+    for (x, y) in positions[0].iter().step_by(4).take(2) {
+        matrix.flip_coords(*x, *y);
+    }
+    assert_eq!(
+        information,
+        FunctionInformation::read(&matrix).expect("read")
+    );
+
+    // the second copy is used when the first one is broken beyond repair
+    for (x, y) in &positions[0][0..28] {
+        matrix.flip_coords(*x, *y);
+    }
+    assert_eq!(
+        information,
+        FunctionInformation::read(&matrix).expect("read")
+    );
+}
+
+#[test]
+fn testEncodeDecode() {
+    doTestRoundTrip("1234567890", "L1", None);
+    doTestRoundTrip("12345678", "L1", None);
+    doTestRoundTrip("HanXin Code, text mode: {1+1=2}", "L2", None);
+    doTestRoundTrip("汉信码", "L3", None);
+    doTestRoundTrip("汉信码 Han Xin 12345 榕 ㄅ 〇", "L4", None);
+    // a GB 18030 four byte character
+    doTestRoundTrip("\u{20AD}\u{1F600}", "L1", None);
+    doTestRoundTrip("\u{1}\u{1c}\u{1f}\u{7f}", "L1", None);
+    doTestRoundTrip(
+        "caf\u{e9} cr\u{e8}me br\u{fb}l\u{e9}e",
+        "L2",
+        Some("ISO-8859-1"),
+    );
+    doTestRoundTrip("Ελληνικά", "L1", Some("UTF-8"));
+}
+
+#[test]
+fn testVersions() {
+    for versionNumber in (1..=84).step_by(7).chain([84]) {
+        for ecLevel in ["L1", "L4"] {
+            let version = Version::getVersionForNumber(versionNumber).expect("version");
+            let level = ecLevel.parse::<ErrorCorrectionLevel>().expect("level");
+            // fill the symbol, in text mode
+            let length = (version.getDataCodewords(level) as usize * 8 - 4 - 6) / 6;
+            let contents = "HanXin".repeat(length / 6 + 1)[..length].to_owned();
+            let hints = HashMap::from([
+                (
+                    EncodeHintType::ERROR_CORRECTION,
+                    EncodeHintValue::ErrorCorrection(ecLevel.to_owned()),
+                ),
+                (
+                    EncodeHintType::HAN_XIN_VERSION,
+                    EncodeHintValue::HanXinVersion(versionNumber.to_string()),
+                ),
+            ]);
+            let matrix =
+                han_xin_encoder::encode_with_hints(&contents, level, &hints).expect("must encode");
+            assert_eq!(version.getDimensionForVersion(), matrix.getWidth());
+            let result = han_xin_decoder::decode(&matrix).expect("must decode");
+            assert_eq!(contents, result.getText());
+            assert_eq!(ecLevel, result.getECLevel());
+        }
+    }
+}
+
+#[test]
+fn testDataTooBig() {
+    let hints = HashMap::from([(
+        EncodeHintType::HAN_XIN_VERSION,
+        EncodeHintValue::HanXinVersion("1".to_owned()),
+    )]);
+    assert!(HanXinWriter
+        .encode_with_hints(
+            "123456789012345678901234567890123456789012345678901234567890",
+            &BarcodeFormat::HAN_XIN,
+            0,
+            0,
+            &hints
+        )
+        .is_err());
+    assert!(HanXinWriter
+        .encode(&"9".repeat(8000), &BarcodeFormat::HAN_XIN, 0, 0)
+        .is_err());
+}
+
+#[test]
+fn testErrorCorrection() {
+    let contents = "Han Xin Code error correction 汉信码";
+    let matrix =
+        han_xin_encoder::encode_with_hints(contents, ErrorCorrectionLevel::L4, &HashMap::new())
+            .expect("must encode");
+    let dimension = matrix.getWidth();
+    let grid = Version::getVersionForDimension(dimension)
+        .expect("version")
+        .buildFunctionGrid();
+
+    let mut damaged = matrix.clone();
+    // a few damaged codewords, in the middle rows of the symbol
+    for y in dimension / 2..dimension / 2 + 2 {
+        for x in 10..dimension - 10 {
+            if grid[(y * dimension + x) as usize] == DATA_MODULE {
+                damaged.flip_coords(x, y);
+            }
+        }
+    }
+    let result = han_xin_decoder::decode(&damaged).expect("must decode");
+    assert_eq!(contents, result.getText());
+    assert!(result.getErrorsCorrected() > 0);
+
+    // too much damage is detected
+    for y in 10..dimension - 10 {
+        for x in 10..dimension - 10 {
+            if grid[(y * dimension + x) as usize] == DATA_MODULE {
+                damaged.flip_coords(x, y);
+            }
+        }
+    }
+    assert!(matches!(
+        han_xin_decoder::decode(&damaged),
+        Err(Exceptions::ReedSolomonException(_) | Exceptions::ChecksumException(_))
+    ));
+}
+
+#[test]
+fn testPureBarcode() {
+    let matrix = HanXinWriter
+        .encode("Han Xin 汉信码", &BarcodeFormat::HAN_XIN, 200, 200)
+        .expect("must encode");
+    let hints = HashMap::from([(
+        DecodeHintType::PURE_BARCODE,
+        DecodeHintValue::PureBarcode(true),
+    )]);
+    let result = HanXinReader
+        .decode_with_hints(
+            &mut bit_matrix_test_case::matrix_to_binary_bitmap(&matrix),
+            &hints,
+        )
+        .expect("must decode");
+    assert_eq!("Han Xin 汉信码", result.getText());
+    assert_eq!(&BarcodeFormat::HAN_XIN, result.getBarcodeFormat());
+    assert_eq!(
+        Some(&RXingResultMetadataValue::SymbologyIdentifier(
+            "]h0".to_owned()
+        )),
+        result
+            .getRXingResultMetadata()
+            .get(&RXingResultMetadataType::SYMBOLOGY_IDENTIFIER)
+    );
+}
+
+#[test]
+fn testDetect() {
+    let contents = "Detected Han Xin Code 汉信码 0123456789";
+    let hints = HashMap::from([(
+        EncodeHintType::ERROR_CORRECTION,
+        EncodeHintValue::ErrorCorrection("L2".to_owned()),
+    )]);
+    let mut matrix = HanXinWriter
+        .encode_with_hints(contents, &BarcodeFormat::HAN_XIN, 300, 300, &hints)
+        .expect("must encode");
+    for _ in 0..4 {
+        let result = HanXinReader
+            .decode(&mut bit_matrix_test_case::matrix_to_binary_bitmap(&matrix))
+            .expect("must decode");
+        assert_eq!(contents, result.getText());
+        assert_eq!(4, result.getRXingResultPoints().len());
+        matrix.rotate90();
+    }
+
+    let hints = HashMap::from([(
+        DecodeHintType::POSSIBLE_FORMATS,
+        DecodeHintValue::PossibleFormats(HashSet::from([BarcodeFormat::HAN_XIN])),
+    )]);
+    let result = MultiFormatReader::default()
+        .decode_with_hints(
+            &mut bit_matrix_test_case::matrix_to_binary_bitmap(&matrix),
+            &hints,
+        )
+        .expect("must decode");
+    assert_eq!(contents, result.getText());
+    assert_eq!(&BarcodeFormat::HAN_XIN, result.getBarcodeFormat());
+    let result = MultiUseMultiFormatReader::default()
+        .decode_with_hints(
+            &mut bit_matrix_test_case::matrix_to_binary_bitmap(&matrix),
+            &hints,
+        )
+        .expect("must decode");
+    assert_eq!(contents, result.getText());
+
+    // Han Xin Code is only looked for when POSSIBLE_FORMATS names it
+    let mut image = bit_matrix_test_case::matrix_to_binary_bitmap(&matrix);
+    assert!(MultiFormatReader::default().decode(&mut image).is_err());
+    assert!(MultiUseMultiFormatReader::default()
+        .decode(&mut image)
+        .is_err());
+}
+
+fn doTestRoundTrip(contents: &str, ecLevel: &str, characterSet: Option<&str>) {
+    let mut hints = HashMap::from([(
+        EncodeHintType::ERROR_CORRECTION,
+        EncodeHintValue::ErrorCorrection(ecLevel.to_owned()),
+    )]);
+    if let Some(characterSet) = characterSet {
+        hints.insert(
+            EncodeHintType::CHARACTER_SET,
+            EncodeHintValue::CharacterSet(characterSet.to_owned()),
+        );
+    }
+    let matrix =
+        han_xin_encoder::encode_with_hints(contents, ecLevel.parse().expect("level"), &hints)
+            .expect("must encode");
+    let result = han_xin_decoder::decode(&matrix).expect("must decode");
+    assert_eq!(contents, result.getText());
+    assert_eq!(ecLevel, result.getECLevel());
+}
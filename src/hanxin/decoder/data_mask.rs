@@ -0,0 +1,18 @@
+/**
+ * <p>See ISO/IEC 20830:2021, 5.8. Whether the module at the given position is inverted by one of
+ * the four data mask patterns. Pattern 0 leaves the symbol as it is.</p>
+ *
+ * @param x column of the module, from 0
+ * @param y row of the module, from 0
+ */
+pub fn isMasked(dataMask: u8, x: u32, y: u32) -> bool {
+    // the patterns count rows and columns from 1
+    let i = y + 1;
+    let j = x + 1;
+    match dataMask {
+        1 => (i + j).is_multiple_of(2),
+        2 => ((i + j) % 3 + j % 3).is_multiple_of(2),
+        3 => (i % j + j % i + i % 3 + j % 3).is_multiple_of(2),
+        _ => false,
+    }
+}
@@ -0,0 +1,217 @@
+use crate::{
+    common::{BitSource, CharacterSet, DecoderRXingResult, ECIStringBuilder, Eci, Result},
+    Exceptions,
+};
+
+use super::{ErrorCorrectionLevel, Mode};
+
+/** The numeric values ending a segment, where they cannot be data */
+const NUMERIC_TERMINATOR: u32 = 1021;
+const TEXT_SHIFT: u32 = 62;
+const TEXT_TERMINATOR: u32 = 63;
+const REGION_TERMINATOR: u32 = 4095;
+const DOUBLE_BYTE_TERMINATOR: u32 = 32767;
+
+/**
+ * <p>Han Xin Codes can encode text as bits in one of several modes, and can use multiple modes
+ * in one Han Xin Code. This class decodes the bits back into text.</p>
+ *
+ * <p>See ISO/IEC 20830:2021, 6.4. Byte data and the Chinese modes default to GB 18030.</p>
+ */
+pub fn decode(bytes: &[u8], ecLevel: ErrorCorrectionLevel) -> Result<DecoderRXingResult> {
+    let mut bits = BitSource::new(bytes.to_owned());
+    let mut result = ECIStringBuilder::with_capacity(50);
+    result.switch_encoding(CharacterSet::GB18030, false);
+    let mut byteSegments = Vec::new();
+    let mut currentEci = Eci::GB18030;
+
+    while bits.available() >= 4 {
+        let mode = Mode::forBits(bits.readBits(4)? as u8)?;
+        match mode {
+            Mode::TERMINATOR => break,
+            Mode::NUMERIC => decodeNumericSegment(&mut bits, &mut result)?,
+            Mode::TEXT => decodeTextSegment(&mut bits, &mut result)?,
+            Mode::BINARY => {
+                let count = bits.readBits(13)? as usize;
+                if count * 8 > bits.available() {
+                    return Err(Exceptions::FORMAT);
+                }
+                let segment = (0..count)
+                    .map(|_| bits.readBits(8).map(|byte| byte as u8))
+                    .collect::<Result<Vec<_>>>()?;
+                result.append_bytes(&segment);
+                byteSegments.push(segment);
+            }
+            Mode::ECI => {
+                currentEci = parseECIValue(&mut bits)?;
+                result.switch_encoding(CharacterSet::from(currentEci), true);
+            }
+            _ => {
+                // the Chinese modes always encode GB 18030 characters
+                if currentEci != Eci::GB18030 {
+                    result.switch_encoding(CharacterSet::GB18030, true);
+                }
+                decodeChineseSegment(&mut bits, &mut result, mode)?;
+                if currentEci != Eci::GB18030 {
+                    result.switch_encoding(CharacterSet::from(currentEci), true);
+                }
+            }
+        }
+    }
+
+    Ok(DecoderRXingResult::with_symbology(
+        bytes.to_vec(),
+        result.build_result().to_string(),
+        byteSegments,
+        ecLevel.to_string(),
+        0,
+    ))
+}
+
+/**
+ * Reads groups of three digits up to the terminator, which tells how many digits the last
+ * group holds.
+ */
+fn decodeNumericSegment(bits: &mut BitSource, result: &mut ECIStringBuilder) -> Result<()> {
+    let mut groups = Vec::new();
+    let lastDigits = loop {
+        let value = bits.readBits(10)?;
+        if value >= NUMERIC_TERMINATOR {
+            break (value - NUMERIC_TERMINATOR + 1) as usize;
+        }
+        if value > 999 {
+            return Err(Exceptions::FORMAT);
+        }
+        groups.push(value);
+    };
+    let Some(last) = groups.pop() else {
+        return Err(Exceptions::FORMAT);
+    };
+    if last >= 10u32.pow(lastDigits as u32) {
+        return Err(Exceptions::FORMAT);
+    }
+    for group in groups {
+        result.append_string(&format!("{group:03}"));
+    }
+    result.append_string(&format!("{last:0lastDigits$}"));
+    Ok(())
+}
+
+/**
+ * Reads six bit values up to the terminator, starting in the first alphabet of digits and
+ * letters. The shift value toggles between it and the second alphabet, which holds the other
+ * ASCII characters.
+ */
+fn decodeTextSegment(bits: &mut BitSource, result: &mut ECIStringBuilder) -> Result<()> {
+    let mut firstAlphabet = true;
+    loop {
+        let value = bits.readBits(6)?;
+        match value {
+            TEXT_TERMINATOR => return Ok(()),
+            TEXT_SHIFT => firstAlphabet = !firstAlphabet,
+            _ => result.append_byte(if firstAlphabet {
+                match value {
+                    0..=9 => b'0' + value as u8,
+                    10..=35 => b'A' + (value - 10) as u8,
+                    _ => b'a' + (value - 36) as u8,
+                }
+            } else {
+                match value {
+                    0..=27 => value as u8,
+                    28..=43 => (value + 4) as u8,
+                    44..=50 => (value + 14) as u8,
+                    51..=56 => (value + 40) as u8,
+                    _ => (value + 66) as u8,
+                }
+            }),
+        }
+    }
+}
+
+/**
+ * Reads the GB 18030 characters of one of the Chinese modes. A four byte segment holds a single
+ * character, the others run up to their terminator.
+ */
+fn decodeChineseSegment(
+    bits: &mut BitSource,
+    result: &mut ECIStringBuilder,
+    mode: Mode,
+) -> Result<()> {
+    if mode == Mode::FOUR_BYTE {
+        let value = bits.readBits(21)?;
+        let first = value / 0x3138;
+        let second = value % 0x3138 / 0x4EC;
+        let third = value % 0x4EC / 10;
+        if first > 0x7D || second > 9 {
+            return Err(Exceptions::FORMAT);
+        }
+        result.append_bytes(&[
+            (first + 0x81) as u8,
+            (second + 0x30) as u8,
+            (third + 0x81) as u8,
+            (value % 10 + 0x30) as u8,
+        ]);
+        return Ok(());
+    }
+
+    loop {
+        let character = match mode {
+            Mode::DOUBLE_BYTE => {
+                let value = bits.readBits(15)?;
+                if value == DOUBLE_BYTE_TERMINATOR {
+                    return Ok(());
+                }
+                let first = value / 0xBE;
+                let second = value % 0xBE;
+                if first > 0x7D {
+                    return Err(Exceptions::FORMAT);
+                }
+                // the second byte skips 0x7F
+                let second = if second < 0x3F {
+                    second + 0x40
+                } else {
+                    second + 0x41
+                };
+                [first + 0x81, second]
+            }
+            _ => {
+                let value = bits.readBits(12)?;
+                if value == REGION_TERMINATOR {
+                    return Ok(());
+                }
+                if mode == Mode::REGION_TWO {
+                    if value >= 32 * 94 {
+                        return Err(Exceptions::FORMAT);
+                    }
+                    [value / 94 + 0xD8, value % 94 + 0xA1]
+                } else if value < 0xEB0 {
+                    [value / 94 + 0xB0, value % 94 + 0xA1]
+                } else if value < 0xFCA {
+                    [(value - 0xEB0) / 94 + 0xA1, (value - 0xEB0) % 94 + 0xA1]
+                } else if value < 0xFCA + 32 {
+                    [0xA8, value - 0xFCA + 0xA1]
+                } else {
+                    return Err(Exceptions::FORMAT);
+                }
+            }
+        };
+        result.append_bytes(&character.map(|byte| byte as u8));
+    }
+}
+
+fn parseECIValue(bits: &mut BitSource) -> Result<Eci> {
+    let firstByte = bits.readBits(8)?;
+    let value = if (firstByte & 0x80) == 0 {
+        // just one byte
+        firstByte & 0x7F
+    } else if (firstByte & 0xC0) == 0x80 {
+        // two bytes
+        ((firstByte & 0x3F) << 8) | bits.readBits(8)?
+    } else if (firstByte & 0xE0) == 0xC0 {
+        // three bytes
+        ((firstByte & 0x1F) << 16) | bits.readBits(16)?
+    } else {
+        return Err(Exceptions::FORMAT);
+    };
+    Ok(Eci::from(value))
+}
@@ -0,0 +1,78 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use crate::common::Result;
+use crate::Exceptions;
+
+/**
+ * <p>See ISO/IEC 20830:2021, 5.5. The four error correction levels of Han Xin Code, recovering
+ * roughly 8%, 15%, 23% and 30% of the codewords.</p>
+ */
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum ErrorCorrectionLevel {
+    L1,
+    L2,
+    L3,
+    L4,
+}
+
+impl ErrorCorrectionLevel {
+    /**
+     * @param bits the two bits of the function information encoding the level
+     */
+    pub fn forBits(bits: u8) -> Result<Self> {
+        match bits {
+            0 => Ok(Self::L1),
+            1 => Ok(Self::L2),
+            2 => Ok(Self::L3),
+            3 => Ok(Self::L4),
+            _ => Err(Exceptions::illegal_argument_with(format!(
+                "{bits} is not a valid bit selection"
+            ))),
+        }
+    }
+
+    pub fn getBits(&self) -> u8 {
+        match self {
+            Self::L1 => 0,
+            Self::L2 => 1,
+            Self::L3 => 2,
+            Self::L4 => 3,
+        }
+    }
+}
+
+impl FromStr for ErrorCorrectionLevel {
+    type Err = Exceptions;
+
+    /**
+     * Parses "L1" to "L4", or the number of the level, 1 to 4.
+     */
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let level = s.trim();
+        let number = level
+            .strip_prefix('L')
+            .or_else(|| level.strip_prefix('l'))
+            .unwrap_or(level);
+        match number {
+            "1" => Ok(Self::L1),
+            "2" => Ok(Self::L2),
+            "3" => Ok(Self::L3),
+            "4" => Ok(Self::L4),
+            _ => Err(Exceptions::illegal_argument_with(format!(
+                "could not parse {s} into an ec level"
+            ))),
+        }
+    }
+}
+
+impl Display for ErrorCorrectionLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::L1 => "L1",
+            Self::L2 => "L2",
+            Self::L3 => "L3",
+            Self::L4 => "L4",
+        })
+    }
+}
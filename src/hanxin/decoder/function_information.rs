@@ -0,0 +1,140 @@
+use crate::{
+    common::{
+        reedsolomon::{
+            get_predefined_genericgf, PredefinedGenericGF, ReedSolomonDecoder, ReedSolomonEncoder,
+        },
+        BitMatrix, Result,
+    },
+    Exceptions,
+};
+
+use super::{ErrorCorrectionLevel, Version, VersionRef};
+
+/** The number of modules holding one copy of the function information */
+pub const FUNCTION_INFORMATION_BITS: usize = 34;
+
+/** The function information is three nibbles of data and four of error correction */
+const DATA_NIBBLES: usize = 3;
+const EC_NIBBLES: usize = 4;
+
+/**
+ * <p>See ISO/IEC 20830:2021, 5.6. Encapsulates the version, error correction level and data mask
+ * of a symbol, which are encoded twice around the finder patterns, protected by a Reed-Solomon
+ * code over GF(16).</p>
+ */
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct FunctionInformation {
+    version: VersionRef,
+    ecLevel: ErrorCorrectionLevel,
+    dataMask: u8,
+}
+
+impl FunctionInformation {
+    pub fn new(version: VersionRef, ecLevel: ErrorCorrectionLevel, dataMask: u8) -> Self {
+        Self {
+            version,
+            ecLevel,
+            dataMask,
+        }
+    }
+
+    pub fn getVersion(&self) -> VersionRef {
+        self.version
+    }
+
+    pub fn getErrorCorrectionLevel(&self) -> ErrorCorrectionLevel {
+        self.ecLevel
+    }
+
+    pub fn getDataMask(&self) -> u8 {
+        self.dataMask
+    }
+
+    /**
+     * @return the 34 bits of the function information: the version plus 20 in eight bits, the
+     * error correction level and the data mask in two bits each, four error correction nibbles
+     * and six alternating padding bits
+     */
+    pub fn encode(&self) -> Result<[bool; FUNCTION_INFORMATION_BITS]> {
+        let versionIndicator = self.version.getVersionNumber() as i32 + 20;
+        let mut nibbles = vec![
+            versionIndicator >> 4,
+            versionIndicator & 0x0F,
+            (i32::from(self.ecLevel.getBits()) << 2) | i32::from(self.dataMask),
+        ];
+        nibbles.resize(DATA_NIBBLES + EC_NIBBLES, 0);
+        ReedSolomonEncoder::new(get_predefined_genericgf(PredefinedGenericGF::AztecParam))?
+            .encode(&mut nibbles, EC_NIBBLES)?;
+
+        let mut bits = [false; FUNCTION_INFORMATION_BITS];
+        for (i, bit) in bits.iter_mut().enumerate() {
+            *bit = if i < 4 * nibbles.len() {
+                nibbles[i / 4] & (0x08 >> (i % 4)) != 0
+            } else {
+                i % 2 == 1
+            };
+        }
+        Ok(bits)
+    }
+
+    /**
+     * Reads the function information from the first copy, or if that cannot be corrected, from
+     * the second one.
+     *
+     * @param bits the symbol, whose dimension must already be known
+     */
+    pub fn read(bits: &BitMatrix) -> Result<Self> {
+        let dimension = bits.getHeight();
+        if dimension != bits.getWidth() {
+            return Err(Exceptions::FORMAT);
+        }
+        let expected = Version::getVersionForDimension(dimension)?;
+        for copy in Self::bitPositions(dimension) {
+            let mut nibbles = vec![0; DATA_NIBBLES + EC_NIBBLES];
+            for (i, (x, y)) in copy.iter().take(4 * nibbles.len()).enumerate() {
+                if bits.get(*x, *y) {
+                    nibbles[i / 4] |= 0x08 >> (i % 4);
+                }
+            }
+            if ReedSolomonDecoder::new(get_predefined_genericgf(PredefinedGenericGF::AztecParam))
+                .decode(&mut nibbles, EC_NIBBLES as i32)
+                .is_err()
+            {
+                continue;
+            }
+            let versionNumber = ((nibbles[0] << 4) | nibbles[1]) as u32;
+            if versionNumber != expected.getVersionNumber() + 20 {
+                continue;
+            }
+            return Ok(Self::new(
+                expected,
+                ErrorCorrectionLevel::forBits((nibbles[2] >> 2) as u8)?,
+                (nibbles[2] & 0x03) as u8,
+            ));
+        }
+        Err(Exceptions::FORMAT)
+    }
+
+    /**
+     * <p>The function information runs along the outside of the separators of the top left and
+     * top right finder patterns, its copy along those of the bottom right and bottom left ones.</p>
+     *
+     * @return the x and y coordinates of every bit of both copies
+     */
+    pub fn bitPositions(dimension: u32) -> [[(u32, u32); FUNCTION_INFORMATION_BITS]; 2] {
+        let size = dimension;
+        let mut positions = [[(0, 0); FUNCTION_INFORMATION_BITS]; 2];
+        for i in 0..9 {
+            let bit = i as usize;
+            positions[0][bit] = (i, 8);
+            positions[1][bit] = (size - 1 - i, size - 9);
+            positions[0][bit + 8] = (8, 8 - i);
+            positions[1][bit + 8] = (size - 9, size - 9 + i);
+            positions[0][bit + 17] = (size - 9, i);
+            positions[1][bit + 17] = (8, size - 1 - i);
+            positions[0][bit + 25] = (size - 9 + i, 8);
+            positions[1][bit + 25] = (8 - i, size - 9);
+        }
+        positions
+    }
+}
@@ -0,0 +1,92 @@
+use once_cell::sync::Lazy;
+
+use crate::common::{
+    reedsolomon::{get_predefined_genericgf, PredefinedGenericGF, ReedSolomonDecoder},
+    BitMatrix, DecoderRXingResult, Result,
+};
+
+use super::{data_mask, decoded_bit_stream_parser, FunctionInformation, DATA_MODULE};
+
+/** The codewords are interleaved by reading every 13th one */
+pub const INTERLEAVING_STEP: usize = 13;
+
+static RS_DECODER: Lazy<ReedSolomonDecoder> = Lazy::new(|| {
+    ReedSolomonDecoder::new(get_predefined_genericgf(
+        PredefinedGenericGF::HanXinField256,
+    ))
+});
+
+/**
+ * <p>The main class which implements Han Xin Code decoding -- as opposed to locating and
+ * extracting the Han Xin Code from an image.</p>
+ *
+ * <p>Decodes a Han Xin Code represented as a {@link BitMatrix}. A 1 or "true" is taken to mean a
+ * black module.</p>
+ *
+ * @param bits booleans representing white/black Han Xin Code modules
+ * @return text and bytes encoded within the Han Xin Code
+ * @throws FormatException if the Han Xin Code cannot be decoded
+ * @throws ChecksumException if error correction fails
+ */
+pub fn decode(bits: &BitMatrix) -> Result<DecoderRXingResult> {
+    let functionInformation = FunctionInformation::read(bits)?;
+    let version = functionInformation.getVersion();
+    let ecLevel = functionInformation.getErrorCorrectionLevel();
+    let dataMask = functionInformation.getDataMask();
+
+    // Read the codewords row by row from the modules which are not part of a function pattern
+    let dimension = version.getDimensionForVersion();
+    let grid = version.buildFunctionGrid();
+    let totalCodewords = version.getTotalCodewords() as usize;
+    let mut interleaved = vec![0u8; totalCodewords];
+    let mut bitIndex = 0;
+    for y in 0..dimension {
+        for x in 0..dimension {
+            if grid[(y * dimension + x) as usize] != DATA_MODULE {
+                continue;
+            }
+            if bitIndex / 8 < totalCodewords
+                && bits.get(x, y) != data_mask::isMasked(dataMask, x, y)
+            {
+                interleaved[bitIndex / 8] |= 0x80 >> (bitIndex % 8);
+            }
+            bitIndex += 1;
+        }
+    }
+
+    let codewords = deinterleave(&interleaved);
+
+    // Separate into data blocks and correct each of them
+    let mut resultBytes = Vec::with_capacity(version.getDataCodewords(ecLevel) as usize);
+    let mut errorsCorrected = 0;
+    let mut offset = 0;
+    for block in version.getECBlocksForLevel(ecLevel) {
+        let dataCodewords = block.getDataCodewords() as usize;
+        let blockLength = dataCodewords + block.getECCodewords() as usize;
+        let mut codewordsInts = codewords[offset..offset + blockLength]
+            .iter()
+            .map(|codeword| *codeword as i32)
+            .collect::<Vec<_>>();
+        errorsCorrected += RS_DECODER.decode(&mut codewordsInts, block.getECCodewords() as i32)?;
+        resultBytes.extend(codewordsInts[..dataCodewords].iter().map(|c| *c as u8));
+        offset += blockLength;
+    }
+
+    let mut result = decoded_bit_stream_parser::decode(&resultBytes, ecLevel)?;
+    result.setErrorsCorrected(errorsCorrected);
+    Ok(result)
+}
+
+/**
+ * The symbol holds the codewords of all blocks one after another, taking every 13th one, then
+ * starting over from the second, and so on.
+ */
+fn deinterleave(interleaved: &[u8]) -> Vec<u8> {
+    let mut codewords = vec![0; interleaved.len()];
+    let positions = (0..INTERLEAVING_STEP)
+        .flat_map(|start| (start..interleaved.len()).step_by(INTERLEAVING_STEP));
+    for (codeword, position) in interleaved.iter().zip(positions) {
+        codewords[position] = *codeword;
+    }
+    codewords
+}
@@ -0,0 +1,13 @@
+mod data_mask;
+pub mod decoded_bit_stream_parser;
+mod error_correction_level;
+mod function_information;
+pub mod han_xin_decoder;
+mod mode;
+mod version;
+
+pub use data_mask::*;
+pub use error_correction_level::*;
+pub use function_information::*;
+pub use mode::*;
+pub use version::*;
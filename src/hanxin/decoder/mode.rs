@@ -0,0 +1,61 @@
+use crate::{common::Result, Exceptions};
+
+/**
+ * <p>See ISO/IEC 20830:2021, 6.4. The data modes of Han Xin Code, each introduced by a four bit
+ * mode indicator.</p>
+ */
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Mode {
+    TERMINATOR,
+    /** Groups of three digits in ten bits */
+    NUMERIC,
+    /** Two alphabets of ASCII characters in six bits each */
+    TEXT,
+    /** Bytes in the current character set, preceded by their count */
+    BINARY,
+    /** GB 18030 double byte characters of the first region, chiefly level 1 Hanzi */
+    REGION_ONE,
+    /** GB 18030 double byte characters of the second region, chiefly level 2 Hanzi */
+    REGION_TWO,
+    /** Any GB 18030 double byte character */
+    DOUBLE_BYTE,
+    /** A single GB 18030 four byte character */
+    FOUR_BYTE,
+    ECI,
+}
+
+impl Mode {
+    /**
+     * @param bits four bits encoding a Han Xin Code data mode
+     * @return Mode encoded by these bits
+     * @throws FormatException if bits do not correspond to a known mode
+     */
+    pub fn forBits(bits: u8) -> Result<Self> {
+        match bits {
+            0x0 => Ok(Self::TERMINATOR),
+            0x1 => Ok(Self::NUMERIC),
+            0x2 => Ok(Self::TEXT),
+            0x3 => Ok(Self::BINARY),
+            0x4 => Ok(Self::REGION_ONE),
+            0x5 => Ok(Self::REGION_TWO),
+            0x6 => Ok(Self::DOUBLE_BYTE),
+            0x7 => Ok(Self::FOUR_BYTE),
+            0x8 => Ok(Self::ECI),
+            _ => Err(Exceptions::FORMAT),
+        }
+    }
+
+    pub fn getBits(&self) -> u8 {
+        match self {
+            Self::TERMINATOR => 0x0,
+            Self::NUMERIC => 0x1,
+            Self::TEXT => 0x2,
+            Self::BINARY => 0x3,
+            Self::REGION_ONE => 0x4,
+            Self::REGION_TWO => 0x5,
+            Self::DOUBLE_BYTE => 0x6,
+            Self::FOUR_BYTE => 0x7,
+            Self::ECI => 0x8,
+        }
+    }
+}
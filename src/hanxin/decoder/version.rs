@@ -0,0 +1,527 @@
+use std::fmt;
+
+use once_cell::sync::Lazy;
+
+use crate::{common::Result, Exceptions};
+
+use super::ErrorCorrectionLevel;
+
+pub type VersionRef = &'static Version;
+
+pub static VERSIONS: Lazy<Vec<Version>> = Lazy::new(Version::buildVersions);
+
+/** A module which carries data */
+pub const DATA_MODULE: u8 = 0;
+/** A light module of a function pattern */
+pub const LIGHT_MODULE: u8 = 1;
+/** A dark module of a function pattern */
+pub const DARK_MODULE: u8 = 2;
+
+/** The most error correction codewords a block can have */
+const MAX_EC_CODEWORDS_PER_BLOCK: u32 = 32;
+
+/**
+ * Element i holds the number of data codewords of version i + 1 at the levels L1 to L4.
+ */
+const DATA_CODEWORDS: [[u32; 4]; 84] = [
+    [21, 17, 13, 9],
+    [31, 25, 19, 15],
+    [42, 34, 26, 20],
+    [46, 38, 30, 22],
+    [57, 49, 37, 29],
+    [70, 58, 46, 34],
+    [84, 70, 54, 40],
+    [99, 81, 63, 47],
+    [114, 96, 74, 54],
+    [131, 109, 83, 61],
+    [135, 113, 87, 63],
+    [153, 127, 97, 71],
+    [171, 143, 109, 79],
+    [189, 157, 121, 87],
+    [209, 175, 135, 97],
+    [229, 191, 147, 105],
+    [251, 209, 161, 115],
+    [273, 227, 175, 125],
+    [297, 247, 191, 137],
+    [321, 267, 205, 147],
+    [345, 287, 221, 157],
+    [354, 296, 228, 166],
+    [381, 317, 245, 175],
+    [407, 339, 261, 187],
+    [436, 362, 280, 200],
+    [464, 388, 298, 210],
+    [493, 411, 317, 225],
+    [523, 435, 337, 237],
+    [554, 462, 358, 248],
+    [586, 490, 376, 262],
+    [619, 517, 397, 275],
+    [634, 528, 408, 283],
+    [666, 556, 428, 300],
+    [702, 586, 452, 312],
+    [738, 614, 474, 328],
+    [774, 646, 498, 344],
+    [812, 676, 522, 358],
+    [849, 707, 545, 375],
+    [888, 740, 572, 392],
+    [929, 773, 597, 407],
+    [946, 788, 608, 418],
+    [987, 823, 635, 433],
+    [1028, 856, 660, 450],
+    [1071, 893, 689, 467],
+    [1115, 929, 717, 485],
+    [1160, 966, 746, 504],
+    [1204, 1004, 774, 521],
+    [1251, 1043, 805, 539],
+    [1271, 1059, 817, 545],
+    [1317, 1099, 847, 565],
+    [1368, 1140, 880, 586],
+    [1416, 1180, 911, 608],
+    [1465, 1221, 942, 628],
+    [1517, 1263, 975, 650],
+    [1569, 1307, 1009, 674],
+    [1621, 1351, 1041, 695],
+    [1674, 1394, 1076, 716],
+    [1697, 1415, 1091, 729],
+    [1752, 1460, 1126, 752],
+    [1807, 1505, 1161, 774],
+    [1864, 1552, 1198, 798],
+    [1920, 1600, 1234, 822],
+    [1979, 1649, 1271, 845],
+    [2036, 1697, 1309, 868],
+    [2097, 1748, 1348, 894],
+    [2123, 1770, 1366, 904],
+    [2185, 1820, 1404, 926],
+    [2246, 1871, 1443, 951],
+    [2310, 1925, 1485, 974],
+    [2373, 1976, 1524, 1000],
+    [2436, 2030, 1566, 1026],
+    [2503, 2084, 1607, 1052],
+    [2567, 2139, 1650, 1080],
+    [2634, 2195, 1693, 1108],
+    [2664, 2219, 1710, 1119],
+    [2732, 2276, 1756, 1147],
+    [2800, 2334, 1800, 1176],
+    [2870, 2391, 1844, 1208],
+    [2940, 2450, 1890, 1240],
+    [3011, 2509, 1935, 1270],
+    [3083, 2569, 1983, 1300],
+    [3156, 2630, 2030, 1330],
+    [3190, 2658, 2050, 1345],
+    [3264, 2720, 2098, 1378],
+];
+
+/**
+ * Element i holds the error correction blocks of version i + 1 at the levels L1 to L4, as listed
+ * in ISO/IEC 20830:2021 Table D.1: two groups of blocks, each given as the number of blocks, and
+ * the data and error correction codewords of every block. Entries left all zero are not listed
+ * here, like the versions past these.
+ */
+const TABLE_D1_BLOCKS: [[[u32; 6]; 4]; 15] = [
+    [
+        [1, 21, 4, 0, 0, 0],
+        [1, 17, 8, 0, 0, 0],
+        [1, 13, 12, 0, 0, 0],
+        [1, 9, 16, 0, 0, 0],
+    ],
+    [
+        [1, 31, 6, 0, 0, 0],
+        [1, 25, 12, 0, 0, 0],
+        [1, 19, 18, 0, 0, 0],
+        [1, 15, 22, 0, 0, 0],
+    ],
+    [
+        [1, 42, 8, 0, 0, 0],
+        [1, 34, 16, 0, 0, 0],
+        [1, 26, 24, 0, 0, 0],
+        [1, 20, 30, 0, 0, 0],
+    ],
+    [
+        [1, 46, 8, 0, 0, 0],
+        [1, 38, 16, 0, 0, 0],
+        [1, 30, 24, 0, 0, 0],
+        [1, 22, 32, 0, 0, 0],
+    ],
+    [
+        [1, 57, 12, 0, 0, 0],
+        [1, 49, 20, 0, 0, 0],
+        [1, 37, 32, 0, 0, 0],
+        [1, 14, 20, 1, 15, 20],
+    ],
+    [
+        [1, 70, 14, 0, 0, 0],
+        [1, 58, 26, 0, 0, 0],
+        [1, 24, 20, 1, 22, 18],
+        [1, 16, 24, 1, 18, 26],
+    ],
+    [
+        [1, 84, 16, 0, 0, 0],
+        [1, 70, 30, 0, 0, 0],
+        [1, 26, 22, 1, 28, 24],
+        [2, 14, 20, 1, 12, 20],
+    ],
+    [
+        [1, 99, 18, 0, 0, 0],
+        [1, 40, 18, 1, 41, 18],
+        [1, 31, 26, 1, 32, 28],
+        [0, 0, 0, 0, 0, 0],
+    ],
+    [
+        [1, 114, 22, 0, 0, 0],
+        [2, 48, 20, 0, 0, 0],
+        [2, 24, 20, 1, 26, 22],
+        [2, 18, 28, 1, 18, 26],
+    ],
+    [
+        [1, 131, 24, 0, 0, 0],
+        [1, 52, 22, 1, 57, 24],
+        [2, 27, 24, 1, 29, 24],
+        [2, 21, 32, 1, 19, 30],
+    ],
+    [
+        [1, 135, 26, 0, 0, 0],
+        [1, 56, 24, 1, 57, 24],
+        [2, 28, 24, 1, 31, 26],
+        [0, 0, 0, 0, 0, 0],
+    ],
+    [
+        [1, 153, 28, 0, 0, 0],
+        [1, 62, 26, 1, 65, 28],
+        [2, 32, 28, 1, 33, 28],
+        [0, 0, 0, 0, 0, 0],
+    ],
+    [
+        [1, 86, 16, 1, 85, 16],
+        [1, 71, 30, 1, 72, 30],
+        [2, 37, 32, 1, 35, 30],
+        [0, 0, 0, 0, 0, 0],
+    ],
+    [
+        [1, 94, 18, 1, 95, 18],
+        [2, 51, 22, 1, 55, 24],
+        [3, 30, 26, 1, 31, 26],
+        [0, 0, 0, 0, 0, 0],
+    ],
+    [
+        [1, 104, 20, 1, 105, 20],
+        [2, 57, 24, 1, 61, 26],
+        [3, 33, 28, 1, 36, 30],
+        [0, 0, 0, 0, 0, 0],
+    ],
+];
+
+/**
+ * Element i holds the spacing of the alignment patterns of version i + 1, in modules. Versions 1
+ * to 3 have no alignment patterns.
+ */
+const ALIGNMENT_SPACING: [u32; 84] = [
+    0, 0, 0, 14, 16, 16, 17, 18, 19, 20, 14, 15, 16, 16, 17, 17, 18, 19, 20, 20, 21, 16, 17, 17,
+    18, 18, 19, 19, 20, 20, 21, 17, 17, 18, 18, 19, 19, 19, 20, 20, 17, 17, 18, 18, 18, 19, 19, 19,
+    17, 17, 18, 18, 18, 18, 19, 19, 19, 17, 17, 18, 18, 18, 18, 19, 19, 17, 17, 17, 18, 18, 18, 18,
+    19, 19, 17, 17, 17, 18, 18, 18, 18, 18, 17, 17,
+];
+
+/**
+ * Element i holds the width of the last, irregular, alignment pattern block of version i + 1.
+ */
+const ALIGNMENT_REMAINDER: [u32; 84] = [
+    0, 0, 0, 15, 15, 17, 18, 19, 20, 21, 15, 15, 15, 17, 17, 19, 19, 19, 19, 21, 21, 17, 16, 18,
+    17, 19, 18, 20, 19, 21, 20, 17, 19, 17, 19, 17, 19, 21, 19, 21, 18, 20, 17, 19, 21, 18, 20, 22,
+    17, 19, 15, 17, 19, 21, 17, 19, 21, 18, 20, 15, 17, 19, 21, 16, 18, 17, 19, 21, 15, 17, 19, 21,
+    15, 17, 18, 20, 22, 15, 17, 19, 21, 23, 17, 19,
+];
+
+/** The rows of the finder pattern in the top left corner, most significant bit on the left */
+const FINDER_TOP_LEFT: [u8; 7] = [0x7F, 0x40, 0x5F, 0x50, 0x57, 0x57, 0x57];
+/** The finder pattern in the top right and bottom left corners, the top left one mirrored */
+const FINDER_MIRRORED: [u8; 7] = [0x7F, 0x01, 0x7D, 0x05, 0x75, 0x75, 0x75];
+/** The finder pattern in the bottom right corner, the top left one turned by 180 degrees */
+const FINDER_BOTTOM_RIGHT: [u8; 7] = [0x75, 0x75, 0x75, 0x05, 0x7D, 0x01, 0x7F];
+
+/**
+ * <p>See ISO/IEC 20830:2021, 5.3. A Han Xin Code version, 1 to 84, which determines the size of
+ * the symbol, 23 to 189 modules, its alignment patterns and its error correction blocks.</p>
+ *
+ * <p>The blocks are those of Table D.1 where {@link #TABLE_D1_BLOCKS} lists them. Other symbols
+ * are split evenly into blocks of at most 32 error correction codewords, with the longer data
+ * blocks last.</p>
+ */
+#[derive(Debug, PartialEq, Eq)]
+pub struct Version {
+    versionNumber: u32,
+    totalCodewords: u32,
+    ecBlocks: [Vec<ECBlock>; 4],
+}
+
+impl Version {
+    fn new(versionNumber: u32) -> Self {
+        let mut version = Self {
+            versionNumber,
+            totalCodewords: 0,
+            ecBlocks: Default::default(),
+        };
+        let dataModules = version
+            .buildFunctionGrid()
+            .iter()
+            .filter(|module| **module == DATA_MODULE)
+            .count() as u32;
+        version.totalCodewords = dataModules / 8;
+        let tableD1 = TABLE_D1_BLOCKS.get(versionNumber as usize - 1);
+        version.ecBlocks = std::array::from_fn(|level| match tableD1.map(|levels| levels[level]) {
+            Some(groups) if groups[0] > 0 => Self::expandBlocks(&groups),
+            _ => Self::splitIntoBlocks(
+                version.totalCodewords,
+                DATA_CODEWORDS[versionNumber as usize - 1][level],
+            ),
+        });
+        version
+    }
+
+    /**
+     * @param groups the groups of blocks of a Table D.1 entry
+     * @return the blocks of the groups, in order
+     */
+    fn expandBlocks(groups: &[u32; 6]) -> Vec<ECBlock> {
+        groups
+            .chunks(3)
+            .flat_map(|group| {
+                std::iter::repeat_n(
+                    ECBlock {
+                        dataCodewords: group[1],
+                        ecCodewords: group[2],
+                    },
+                    group[0] as usize,
+                )
+            })
+            .collect()
+    }
+
+    fn splitIntoBlocks(totalCodewords: u32, dataCodewords: u32) -> Vec<ECBlock> {
+        // blocks have an even number of error correction codewords
+        let dataCodewords = dataCodewords - (totalCodewords - dataCodewords) % 2;
+        let ecCodewords = totalCodewords - dataCodewords;
+        let count = ecCodewords.div_ceil(MAX_EC_CODEWORDS_PER_BLOCK);
+        let ecPairs = ecCodewords / 2;
+        (0..count)
+            .map(|i| ECBlock {
+                dataCodewords: dataCodewords / count
+                    + u32::from(i >= count - dataCodewords % count),
+                ecCodewords: 2 * (ecPairs / count + u32::from(i < ecPairs % count)),
+            })
+            .collect()
+    }
+
+    pub fn getVersionNumber(&self) -> u32 {
+        self.versionNumber
+    }
+
+    pub fn getTotalCodewords(&self) -> u32 {
+        self.totalCodewords
+    }
+
+    pub fn getDimensionForVersion(&self) -> u32 {
+        21 + 2 * self.versionNumber
+    }
+
+    pub fn getECBlocksForLevel(&self, ecLevel: ErrorCorrectionLevel) -> &[ECBlock] {
+        &self.ecBlocks[ecLevel.getBits() as usize]
+    }
+
+    pub fn getDataCodewords(&self, ecLevel: ErrorCorrectionLevel) -> u32 {
+        self.getECBlocksForLevel(ecLevel)
+            .iter()
+            .map(ECBlock::getDataCodewords)
+            .sum()
+    }
+
+    pub fn getVersionForNumber(versionNumber: u32) -> Result<VersionRef> {
+        if !(1..=84).contains(&versionNumber) {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "version {versionNumber} out of bounds"
+            )));
+        }
+        Ok(&VERSIONS[versionNumber as usize - 1])
+    }
+
+    /**
+     * @param dimension the width and height of the symbol, in modules
+     */
+    pub fn getVersionForDimension(dimension: u32) -> Result<VersionRef> {
+        if dimension < 23 || dimension.is_multiple_of(2) {
+            return Err(Exceptions::FORMAT);
+        }
+        Self::getVersionForNumber((dimension - 21) / 2).map_err(|_| Exceptions::FORMAT)
+    }
+
+    /**
+     * <p>Builds the map of the function patterns of the symbol: the finder patterns with their
+     * separators, the reserved function information region and, from version 4 on, the
+     * alignment patterns.</p>
+     *
+     * @return one of {@link #DATA_MODULE}, {@link #LIGHT_MODULE} and {@link #DARK_MODULE} per
+     * module, row by row
+     */
+    pub fn buildFunctionGrid(&self) -> Vec<u8> {
+        let size = self.getDimensionForVersion() as usize;
+        let mut grid = vec![DATA_MODULE; size * size];
+
+        let mut placeFinder = |x: usize, y: usize, rows: &[u8; 7]| {
+            for (yp, row) in rows.iter().enumerate() {
+                for xp in 0..7 {
+                    grid[(y + yp) * size + x + xp] = if row & (0x40 >> xp) != 0 {
+                        DARK_MODULE
+                    } else {
+                        LIGHT_MODULE
+                    };
+                }
+            }
+        };
+        placeFinder(0, 0, &FINDER_TOP_LEFT);
+        placeFinder(size - 7, 0, &FINDER_MIRRORED);
+        placeFinder(0, size - 7, &FINDER_MIRRORED);
+        placeFinder(size - 7, size - 7, &FINDER_BOTTOM_RIGHT);
+
+        // the separators around the finder patterns, then the function information around those
+        for (width, reach) in [(8, 7), (9, 8)] {
+            for i in 0..width {
+                for (x, y) in [
+                    (i, reach),
+                    (reach, i),
+                    (size - 1 - i, reach),
+                    (size - 1 - reach, i),
+                    (i, size - 1 - reach),
+                    (reach, size - 1 - i),
+                    (size - 1 - i, size - 1 - reach),
+                    (size - 1 - reach, size - 1 - i),
+                ] {
+                    grid[y * size + x] = LIGHT_MODULE;
+                }
+            }
+        }
+
+        if self.versionNumber > 3 {
+            Self::placeAlignmentPatterns(&mut grid, size, self.versionNumber as usize);
+        }
+
+        grid
+    }
+
+    /**
+     * Places the assistant alignment patterns along the edges, and the staircase of alignment
+     * patterns which divides the symbol into blocks.
+     */
+    fn placeAlignmentPatterns(grid: &mut [u8], size: usize, versionNumber: usize) {
+        let spacing = ALIGNMENT_SPACING[versionNumber - 1] as usize;
+        let remainder = ALIGNMENT_REMAINDER[versionNumber - 1] as usize;
+        let count = (size - remainder) / spacing;
+        let blockWidth = |index: usize| {
+            if index < count {
+                spacing
+            } else {
+                remainder - 1
+            }
+        };
+
+        // only modules not already taken by other patterns are placed
+        let mut plot = |x: isize, y: isize, module: u8| {
+            if (0..size as isize).contains(&x) && (0..size as isize).contains(&y) {
+                let cell = &mut grid[y as usize * size + x as usize];
+                if *cell == DATA_MODULE {
+                    *cell = module;
+                }
+            }
+        };
+
+        // the assistant patterns along the left and right edges come first, then those along the
+        // top and bottom edges, counted from the right
+        let mut sides = Vec::new();
+        let mut topAndBottom = Vec::new();
+        let mut position = 0;
+        let mut index = 0;
+        while position < size {
+            let isOdd = index % 2 == 1;
+            if isOdd != (count % 2 == 1) {
+                sides.push((0, position));
+                topAndBottom.push((size - 1 - position, size - 1));
+            }
+            if isOdd {
+                sides.push((size - 1, position));
+                topAndBottom.push((size - 1 - position, 0));
+            }
+            position += blockWidth(index);
+            index += 1;
+        }
+        for (x, y) in sides.into_iter().chain(topAndBottom) {
+            for dy in -1..=1 {
+                for dx in -1..=1 {
+                    let module = if dx == 0 && dy == 0 {
+                        DARK_MODULE
+                    } else {
+                        LIGHT_MODULE
+                    };
+                    plot(x as isize + dx, y as isize + dy, module);
+                }
+            }
+        }
+
+        // the blocks are placed like a checkerboard from the top right corner
+        let mut y = 0;
+        let mut row = 0;
+        while y < size {
+            let height = blockWidth(row);
+            let mut x = size - 1;
+            let mut column = 0;
+            loop {
+                let width = blockWidth(column);
+                if (row + column) % 2 == 0 && !(y == 0 && x == size - 1) {
+                    let (x, y) = (x as isize, y as isize);
+                    plot(x, y, DARK_MODULE);
+                    plot(x - 1, y + 1, LIGHT_MODULE);
+                    for i in 1..=width as isize {
+                        plot(x - i, y, DARK_MODULE);
+                        plot(x - i - 1, y + 1, LIGHT_MODULE);
+                    }
+                    for i in 1..height as isize {
+                        plot(x, y + i, DARK_MODULE);
+                        plot(x - 1, y + i + 1, LIGHT_MODULE);
+                    }
+                }
+                column += 1;
+                if x < width {
+                    break;
+                }
+                x -= width;
+            }
+            y += height;
+            row += 1;
+        }
+    }
+
+    fn buildVersions() -> Vec<Version> {
+        (1..=84).map(Version::new).collect()
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.versionNumber)
+    }
+}
+
+/**
+ * <p>A Reed-Solomon block of a Han Xin Code symbol.</p>
+ */
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ECBlock {
+    dataCodewords: u32,
+    ecCodewords: u32,
+}
+
+impl ECBlock {
+    pub fn getDataCodewords(&self) -> u32 {
+        self.dataCodewords
+    }
+
+    pub fn getECCodewords(&self) -> u32 {
+        self.ecCodewords
+    }
+}
@@ -0,0 +1,257 @@
+use crate::{
+    common::{
+        BitMatrix, DefaultGridSampler, DetectorRXingResult, GridSampler, PerspectiveTransform,
+        Quadrilateral, Result, SamplerControl,
+    },
+    point_f, Exceptions, Point,
+};
+
+use super::decoder::{FunctionInformation, Version};
+
+/** A finder pattern is seven modules across its centre block */
+const FINDER_MODULES: f32 = 7.0;
+/** Each finder pattern needs to be seen on at least this many rows */
+const MIN_CONFIRMATIONS: u32 = 2;
+
+#[derive(Debug)]
+pub struct HanXinDetectorRXingResult {
+    bits: BitMatrix,
+    points: Vec<Point>,
+}
+
+impl DetectorRXingResult for HanXinDetectorRXingResult {
+    fn getBits(&self) -> &BitMatrix {
+        &self.bits
+    }
+
+    fn getPoints(&self) -> &[Point] {
+        &self.points
+    }
+}
+
+/**
+ * The centre of the 3x3 block of a finder pattern candidate.
+ */
+#[derive(Debug, Clone, Copy)]
+struct FinderPattern {
+    center: Point,
+    moduleSize: f32,
+    count: u32,
+}
+
+/**
+ * <p>Detects a Han Xin Code in an image by its four finder patterns. Every one of them has a
+ * solid 3x3 block in a corner, which is crossed by a 1:1:1:1:3 pattern of dark and light
+ * modules both horizontally and vertically.</p>
+ *
+ * <p>The finder patterns are ordered around their centre, and each of the four rotations is tried
+ * until the function information of the sampled symbol can be read.</p>
+ *
+ * @return {@link HanXinDetectorRXingResult} encapsulating results of detecting a Han Xin Code
+ * @throws NotFoundException if no Han Xin Code can be found
+ */
+pub fn detect(image: &BitMatrix) -> Result<HanXinDetectorRXingResult> {
+    let mut patterns = findFinderPatterns(image);
+    patterns.retain(|pattern| pattern.count >= MIN_CONFIRMATIONS);
+    if patterns.len() < 4 {
+        return Err(Exceptions::NOT_FOUND);
+    }
+    patterns.sort_by_key(|pattern| std::cmp::Reverse(pattern.count));
+    patterns.truncate(4);
+
+    // clockwise, as the y axis points down
+    let centroid = patterns
+        .iter()
+        .fold(point_f(0.0, 0.0), |sum, pattern| sum + pattern.center / 4.0);
+    patterns.sort_by(|a, b| {
+        let angle = |p: &FinderPattern| (p.center.y - centroid.y).atan2(p.center.x - centroid.x);
+        angle(a).total_cmp(&angle(b))
+    });
+    let moduleSize = patterns
+        .iter()
+        .map(|pattern| pattern.moduleSize)
+        .sum::<f32>()
+        / patterns.len() as f32;
+
+    for rotation in 0..4 {
+        let [topLeft, topRight, bottomRight, bottomLeft] =
+            [0, 1, 2, 3].map(|i| patterns[(rotation + i) % 4].center);
+        // the blocks of the top left and top right finder patterns are dimension - 11 apart,
+        // like those of the top right and bottom right ones
+        let estimate = ((Point::distance(topLeft, topRight)
+            + Point::distance(topRight, bottomRight))
+            / (2.0 * moduleSize)
+            + 11.0)
+            .round() as i32;
+        let estimate = estimate | 1;
+        for dimension in [estimate, estimate - 2, estimate + 2] {
+            let Ok(dimension) = u32::try_from(dimension) else {
+                continue;
+            };
+            if Version::getVersionForDimension(dimension).is_err() {
+                continue;
+            }
+            let Ok(bits) = sampleGrid(
+                image,
+                [topLeft, topRight, bottomRight, bottomLeft],
+                dimension,
+            ) else {
+                continue;
+            };
+            if FunctionInformation::read(&bits).is_ok() {
+                return Ok(HanXinDetectorRXingResult {
+                    bits,
+                    points: vec![topLeft, topRight, bottomRight, bottomLeft],
+                });
+            }
+        }
+    }
+
+    Err(Exceptions::NOT_FOUND)
+}
+
+/**
+ * Samples the symbol, mapping the centres of the finder pattern blocks onto the image.
+ */
+fn sampleGrid(image: &BitMatrix, centers: [Point; 4], dimension: u32) -> Result<BitMatrix> {
+    let size = dimension as f32;
+    let dst = Quadrilateral::new(
+        point_f(5.5, 5.5),
+        point_f(size - 5.5, 5.5),
+        point_f(size - 5.5, size - 5.5),
+        point_f(1.5, size - 1.5),
+    );
+    let [topLeft, topRight, bottomRight, bottomLeft] = centers;
+    let src = Quadrilateral::new(topLeft, topRight, bottomRight, bottomLeft);
+    let transform = PerspectiveTransform::quadrilateralToQuadrilateral(dst, src)?;
+    let (bits, _) = DefaultGridSampler.sample_grid(
+        image,
+        dimension,
+        dimension,
+        &[SamplerControl::new(dimension, dimension, transform)],
+    )?;
+    Ok(bits)
+}
+
+/**
+ * Scans every row for runs in a 1:1:1:1:3 or 3:1:1:1:1 ratio, and confirms their block
+ * vertically.
+ */
+fn findFinderPatterns(image: &BitMatrix) -> Vec<FinderPattern> {
+    let mut patterns: Vec<FinderPattern> = Vec::new();
+    for y in 0..image.getHeight() as i32 {
+        let mut x = 0;
+        while x < image.getWidth() as i32 {
+            if !image.get(x as u32, y as u32) {
+                x += 1;
+                continue;
+            }
+            let Some((centerX, _)) = crossCheck(image, x, y, true) else {
+                x += 1;
+                continue;
+            };
+            let found = crossCheck(image, centerX as i32, y, false).and_then(|(centerY, _)| {
+                crossCheck(image, centerX as i32, centerY as i32, true).and_then(
+                    |(centerX, horizontalSize)| {
+                        crossCheck(image, centerX as i32, centerY as i32, false).map(
+                            |(centerY, verticalSize)| {
+                                (centerX, centerY, (horizontalSize + verticalSize) / 2.0)
+                            },
+                        )
+                    },
+                )
+            });
+            if let Some((centerX, centerY, moduleSize)) = found {
+                let center = point_f(centerX, centerY);
+                if let Some(pattern) = patterns.iter_mut().find(|pattern| {
+                    Point::distance(pattern.center, center) < 2.0 * pattern.moduleSize
+                }) {
+                    let count = pattern.count as f32;
+                    pattern.center = (pattern.center * count + center) / (count + 1.0);
+                    pattern.moduleSize = (pattern.moduleSize * count + moduleSize) / (count + 1.0);
+                    pattern.count += 1;
+                } else {
+                    patterns.push(FinderPattern {
+                        center,
+                        moduleSize,
+                        count: 1,
+                    });
+                }
+            }
+            // skip the rest of the dark run
+            while x < image.getWidth() as i32 && image.get(x as u32, y as u32) {
+                x += 1;
+            }
+        }
+    }
+    patterns
+}
+
+/**
+ * <p>Checks whether the dark run through the given position is the block of a finder pattern,
+ * followed by four narrow runs on either side.</p>
+ *
+ * @return the centre of the block along the line, and the module size
+ */
+fn crossCheck(image: &BitMatrix, x: i32, y: i32, horizontal: bool) -> Option<(f32, f32)> {
+    let isDark = |position: i32| {
+        let (px, py) = if horizontal {
+            (position, y)
+        } else {
+            (x, position)
+        };
+        px >= 0 && py >= 0 && image.try_get(px as u32, py as u32) == Some(true)
+    };
+    let isInside = |position: i32| {
+        let limit = if horizontal {
+            image.getWidth()
+        } else {
+            image.getHeight()
+        };
+        position >= 0 && (position as u32) < limit
+    };
+    let position = if horizontal { x } else { y };
+    if !isDark(position) {
+        return None;
+    }
+
+    let mut start = position;
+    while isDark(start - 1) {
+        start -= 1;
+    }
+    let mut end = position + 1;
+    while isDark(end) {
+        end += 1;
+    }
+    let block = (end - start) as f32;
+
+    // the lengths of the four runs next to the block, starting with a light one
+    let runs = |from: i32, step: i32| {
+        let mut lengths = [0.0; 4];
+        let mut current = from;
+        for (i, length) in lengths.iter_mut().enumerate() {
+            let dark = i % 2 == 1;
+            while isInside(current) && isDark(current) == dark {
+                *length += 1.0;
+                current += step;
+            }
+            if *length == 0.0 {
+                return None;
+            }
+        }
+        Some(lengths)
+    };
+
+    let center = (start + end) as f32 / 2.0;
+    for lengths in [runs(start - 1, -1), runs(end, 1)].into_iter().flatten() {
+        let total = block + lengths.iter().sum::<f32>();
+        let moduleSize = total / FINDER_MODULES;
+        let narrowMatch = lengths
+            .iter()
+            .all(|length| (length - moduleSize).abs() < moduleSize * 0.5);
+        if narrowMatch && (block - 3.0 * moduleSize).abs() < moduleSize {
+            return Some((center, moduleSize));
+        }
+    }
+    None
+}
@@ -0,0 +1,233 @@
+use crate::{
+    common::{
+        reedsolomon::{get_predefined_genericgf, PredefinedGenericGF, ReedSolomonEncoder},
+        BitArray, BitMatrix, CharacterSet, Result,
+    },
+    hanxin::decoder::{
+        han_xin_decoder::INTERLEAVING_STEP, isMasked, ErrorCorrectionLevel, FunctionInformation,
+        Version, VersionRef, DARK_MODULE, DATA_MODULE, VERSIONS,
+    },
+    EncodeHintType, EncodeHintValue, EncodingHintDictionary, Exceptions,
+};
+
+use super::high_level_encoder;
+
+/** The number of data mask patterns */
+pub const NUM_MASK_PATTERNS: u8 = 4;
+
+/** Penalty for a pattern that could be mistaken for a finder pattern */
+const FINDER_LIKE_PENALTY: u32 = 50;
+/** Penalty for each module a run of the same color is longer than three */
+const LONG_RUN_PENALTY: u32 = 4;
+
+/**
+ * <p>Encodes the contents into a Han Xin Code symbol, one module per bit, without quiet zone.</p>
+ *
+ * <p>The smallest version that holds the contents at the error correction level is used, unless
+ * {@link EncodeHintType#HAN_XIN_VERSION} asks for a specific one. {@link
+ * EncodeHintType#CHARACTER_SET} selects the character set of characters outside of ASCII.</p>
+ */
+pub fn encode_with_hints(
+    contents: &str,
+    ecLevel: ErrorCorrectionLevel,
+    hints: &EncodingHintDictionary,
+) -> Result<BitMatrix> {
+    let encoding = if let Some(EncodeHintValue::CharacterSet(name)) =
+        hints.get(&EncodeHintType::CHARACTER_SET)
+    {
+        Some(CharacterSet::get_character_set_by_name(name).ok_or(Exceptions::WRITER)?)
+    } else {
+        None
+    };
+
+    let mut bits = high_level_encoder::encodeHighLevel(contents, encoding)?;
+    let neededCodewords = bits.get_size().div_ceil(8) as u32;
+
+    let version = if let Some(EncodeHintValue::HanXinVersion(number)) =
+        hints.get(&EncodeHintType::HAN_XIN_VERSION)
+    {
+        let number = number
+            .parse::<u32>()
+            .map_err(|e| Exceptions::parse_with(format!("could not parse {number}: {e}")))?;
+        let version = Version::getVersionForNumber(number)?;
+        if version.getDataCodewords(ecLevel) < neededCodewords {
+            return Err(Exceptions::writer_with(format!(
+                "data too big for requested version {number}"
+            )));
+        }
+        version
+    } else {
+        VERSIONS
+            .iter()
+            .find(|version| version.getDataCodewords(ecLevel) >= neededCodewords)
+            .ok_or(Exceptions::writer_with("data too big"))?
+    };
+
+    let dataCodewords = version.getDataCodewords(ecLevel) as usize;
+    terminateBits(&mut bits, dataCodewords)?;
+    let mut data = vec![0u8; dataCodewords];
+    bits.toBytes(0, &mut data, 0, dataCodewords);
+
+    let codewords = interleave(&addErrorCorrection(&data, version, ecLevel)?);
+
+    let mut best = None;
+    for dataMask in 0..NUM_MASK_PATTERNS {
+        let matrix = buildMatrix(
+            &codewords,
+            &FunctionInformation::new(version, ecLevel, dataMask),
+        )?;
+        let penalty = calculateMaskPenalty(&matrix);
+        if best
+            .as_ref()
+            .is_none_or(|(bestPenalty, _)| penalty < *bestPenalty)
+        {
+            best = Some((penalty, matrix));
+        }
+    }
+    best.map(|(_, matrix)| matrix)
+        .ok_or(Exceptions::ILLEGAL_STATE)
+}
+
+/**
+ * Pads the bit stream with zeros up to the capacity of the version, which read as the terminator
+ * mode and empty codewords.
+ */
+fn terminateBits(bits: &mut BitArray, dataCodewords: usize) -> Result<()> {
+    let capacity = dataCodewords * 8;
+    if bits.get_size() > capacity {
+        return Err(Exceptions::writer_with(
+            "data bits cannot fit in the Han Xin Code",
+        ));
+    }
+    while bits.get_size() < capacity {
+        bits.appendBit(false);
+    }
+    Ok(())
+}
+
+/**
+ * Splits the data into the blocks of the version and appends the error correction codewords to
+ * each of them.
+ */
+fn addErrorCorrection(
+    data: &[u8],
+    version: VersionRef,
+    ecLevel: ErrorCorrectionLevel,
+) -> Result<Vec<u8>> {
+    let mut encoder = ReedSolomonEncoder::new(get_predefined_genericgf(
+        PredefinedGenericGF::HanXinField256,
+    ))?;
+    let mut codewords = Vec::with_capacity(version.getTotalCodewords() as usize);
+    let mut offset = 0;
+    for block in version.getECBlocksForLevel(ecLevel) {
+        let dataCodewords = block.getDataCodewords() as usize;
+        let ecCodewords = block.getECCodewords() as usize;
+        let mut toEncode = data[offset..offset + dataCodewords]
+            .iter()
+            .map(|codeword| *codeword as i32)
+            .collect::<Vec<_>>();
+        toEncode.resize(dataCodewords + ecCodewords, 0);
+        encoder.encode(&mut toEncode, ecCodewords)?;
+        codewords.extend(toEncode.iter().map(|codeword| *codeword as u8));
+        offset += dataCodewords;
+    }
+    Ok(codewords)
+}
+
+/**
+ * Takes every 13th codeword, starting over from the second one and so on.
+ */
+fn interleave(codewords: &[u8]) -> Vec<u8> {
+    (0..INTERLEAVING_STEP)
+        .flat_map(|start| codewords.iter().skip(start).step_by(INTERLEAVING_STEP))
+        .copied()
+        .collect()
+}
+
+/**
+ * Draws the function patterns and the function information, and places the codewords row by row
+ * into the remaining modules, masked with the data mask.
+ */
+fn buildMatrix(codewords: &[u8], functionInformation: &FunctionInformation) -> Result<BitMatrix> {
+    let version = functionInformation.getVersion();
+    let dimension = version.getDimensionForVersion();
+    let grid = version.buildFunctionGrid();
+    let dataMask = functionInformation.getDataMask();
+
+    let mut matrix = BitMatrix::with_single_dimension(dimension)?;
+    let mut bitIndex = 0;
+    for y in 0..dimension {
+        for x in 0..dimension {
+            let module = grid[(y * dimension + x) as usize];
+            let dark = if module == DATA_MODULE {
+                // the modules left over after the last codeword are zero
+                let bit = codewords
+                    .get(bitIndex / 8)
+                    .is_some_and(|codeword| codeword & (0x80 >> (bitIndex % 8)) != 0);
+                bitIndex += 1;
+                bit != isMasked(dataMask, x, y)
+            } else {
+                module == DARK_MODULE
+            };
+            if dark {
+                matrix.set(x, y);
+            }
+        }
+    }
+
+    let bits = functionInformation.encode()?;
+    for copy in FunctionInformation::bitPositions(dimension) {
+        for ((x, y), bit) in copy.into_iter().zip(bits) {
+            if bit {
+                matrix.set(x, y);
+            }
+        }
+    }
+    Ok(matrix)
+}
+
+/**
+ * <p>Scores a masked symbol, the lower the better: patterns in rows and columns that look like the
+ * 1:1:1:1:3 finder patterns are penalized heavily, long runs of the same color a little.</p>
+ */
+fn calculateMaskPenalty(matrix: &BitMatrix) -> u32 {
+    let dimension = matrix.getWidth();
+    let mut penalty = 0;
+    for isHorizontal in [true, false] {
+        for i in 0..dimension {
+            let mut runs = Vec::new();
+            let mut last = None;
+            for j in 0..dimension {
+                let dark = if isHorizontal {
+                    matrix.get(j, i)
+                } else {
+                    matrix.get(i, j)
+                };
+                if last == Some(dark) {
+                    if let Some((_, length)) = runs.last_mut() {
+                        *length += 1;
+                    }
+                } else {
+                    runs.push((dark, 1));
+                    last = Some(dark);
+                }
+            }
+            penalty += runs
+                .iter()
+                .filter(|(_, length)| *length > 3)
+                .map(|(_, length)| LONG_RUN_PENALTY * (length - 3))
+                .sum::<u32>();
+            penalty += runs
+                .windows(5)
+                .filter(|window| {
+                    let [a, b, c, d, e] = [0, 1, 2, 3, 4].map(|k| window[k].1);
+                    window[0].0
+                        && ((a == b && b == c && c == d && e == 3 * a)
+                            || (a == 3 * b && b == c && c == d && d == e))
+                })
+                .count() as u32
+                * FINDER_LIKE_PENALTY;
+        }
+    }
+    penalty
+}
@@ -0,0 +1,368 @@
+use crate::{
+    common::{BitArray, CharacterSet, Eci, Result},
+    hanxin::decoder::Mode,
+    Exceptions,
+};
+
+/**
+ * The states of the mode segmentation. Text mode has two alphabets, which are switched within a
+ * segment.
+ */
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum State {
+    Numeric,
+    TextOne,
+    TextTwo,
+    Binary,
+    RegionOne,
+    RegionTwo,
+    DoubleByte,
+    FourByte,
+}
+
+const STATES: [State; 8] = [
+    State::Numeric,
+    State::TextOne,
+    State::TextTwo,
+    State::Binary,
+    State::RegionOne,
+    State::RegionTwo,
+    State::DoubleByte,
+    State::FourByte,
+];
+
+impl State {
+    fn mode(&self) -> Mode {
+        match self {
+            State::Numeric => Mode::NUMERIC,
+            State::TextOne | State::TextTwo => Mode::TEXT,
+            State::Binary => Mode::BINARY,
+            State::RegionOne => Mode::REGION_ONE,
+            State::RegionTwo => Mode::REGION_TWO,
+            State::DoubleByte => Mode::DOUBLE_BYTE,
+            State::FourByte => Mode::FOUR_BYTE,
+        }
+    }
+
+    /**
+     * @return the bits of the mode indicator and of the terminator or count of a new segment,
+     * in sixths of a bit
+     */
+    fn segmentCost(&self) -> u32 {
+        6 * match self {
+            State::Numeric => 4 + 10,
+            State::TextOne => 4 + 6,
+            // a shift right after the mode indicator
+            State::TextTwo => 4 + 6 + 6,
+            State::Binary => 4 + 13,
+            State::RegionOne | State::RegionTwo => 4 + 12,
+            State::DoubleByte => 4 + 15,
+            // every character is a segment of its own, counted with the character
+            State::FourByte => 0,
+        }
+    }
+
+    /**
+     * @return the bits the character takes in this state, in sixths of a bit, or None if the
+     * state cannot encode it
+     */
+    fn characterCost(&self, character: &Character) -> Option<u32> {
+        let bits = match self {
+            State::Numeric => return character.is_digit().then_some(20),
+            State::TextOne => textOneValue(character.value?).map(|_| 6),
+            State::TextTwo => textTwoValue(character.value?).map(|_| 6),
+            State::Binary => Some(8 * character.bytes.len() as u32),
+            State::RegionOne => regionOneValue(&character.gb18030?).map(|_| 12),
+            State::RegionTwo => regionTwoValue(&character.gb18030?).map(|_| 12),
+            State::DoubleByte => doubleByteValue(&character.gb18030?).map(|_| 15),
+            State::FourByte => character.fourByte.map(|_| 4 + 21),
+        };
+        bits.map(|bits| 6 * bits)
+    }
+}
+
+/**
+ * A character of the contents, with the forms the modes can encode it in.
+ */
+struct Character {
+    /** The ASCII value, if it is ASCII */
+    value: Option<u8>,
+    /** The bytes in the character set of binary mode */
+    bytes: Vec<u8>,
+    /** The GB 18030 double byte form, if the Chinese modes can be used */
+    gb18030: Option<[u8; 2]>,
+    /** The GB 18030 four byte form, if the Chinese modes can be used */
+    fourByte: Option<[u8; 4]>,
+}
+
+impl Character {
+    fn is_digit(&self) -> bool {
+        self.value.is_some_and(|value| value.is_ascii_digit())
+    }
+}
+
+/**
+ * <p>Encodes the contents into the data bits of a Han Xin Code, choosing the modes which give the
+ * shortest bit stream.</p>
+ *
+ * <p>Without a character set, or with GB 18030, Chinese characters are encoded in the Chinese
+ * modes and other characters as GB 18030 bytes. Any other character set is announced with an ECI
+ * and the characters outside of ASCII are encoded as its bytes in binary mode.</p>
+ */
+pub fn encodeHighLevel(contents: &str, encoding: Option<CharacterSet>) -> Result<BitArray> {
+    let encoding = encoding.unwrap_or(CharacterSet::GB18030);
+    let chinese = encoding == CharacterSet::GB18030;
+
+    let characters = contents
+        .chars()
+        .map(|c| {
+            let bytes = encoding.encode(&c.to_string()).map_err(|_| {
+                Exceptions::illegal_argument_with(format!(
+                    "character {c} can not be encoded in {}",
+                    encoding.get_charset_name()
+                ))
+            })?;
+            Ok(Character {
+                value: c.is_ascii().then_some(c as u8),
+                gb18030: chinese.then(|| bytes.as_slice().try_into().ok()).flatten(),
+                fourByte: chinese
+                    .then(|| bytes.as_slice().try_into().ok())
+                    .flatten()
+                    .filter(|bytes: &[u8; 4]| fourByteValue(bytes).is_some()),
+                bytes,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let states = chooseStates(&characters)?;
+
+    let mut bits = BitArray::new();
+    if !chinese {
+        appendECI(&mut bits, Eci::from(encoding) as u32)?;
+    }
+    let mut start = 0;
+    while start < characters.len() {
+        let state = states[start];
+        let mode = state.mode();
+        let mut end = start + 1;
+        if state != State::FourByte {
+            while end < characters.len() && states[end].mode() == mode {
+                end += 1;
+            }
+        }
+        appendSegment(
+            &mut bits,
+            mode,
+            &characters[start..end],
+            &states[start..end],
+        )?;
+        start = end;
+    }
+    Ok(bits)
+}
+
+/**
+ * Finds the cheapest state for every character by dynamic programming over the states after
+ * each character.
+ */
+fn chooseStates(characters: &[Character]) -> Result<Vec<State>> {
+    // the cost of the cheapest encoding ending in each state, and the state before it
+    let mut costs: Vec<Option<u32>> = vec![None; STATES.len()];
+    let mut previous = Vec::with_capacity(characters.len());
+    for (i, character) in characters.iter().enumerate() {
+        let mut next = vec![None; STATES.len()];
+        let mut from = vec![0; STATES.len()];
+        for (t, state) in STATES.iter().enumerate() {
+            let Some(characterCost) = state.characterCost(character) else {
+                continue;
+            };
+            for (s, cost) in costs.iter().enumerate() {
+                let cost = match cost {
+                    Some(cost) => *cost + transitionCost(STATES[s], *state),
+                    None if i == 0 && s == 0 => state.segmentCost(),
+                    None => continue,
+                };
+                if next[t].is_none_or(|best| cost + characterCost < best) {
+                    next[t] = Some(cost + characterCost);
+                    from[t] = s;
+                }
+            }
+        }
+        costs = next;
+        previous.push(from);
+    }
+
+    let Some(mut state) = (0..STATES.len())
+        .filter(|s| costs[*s].is_some())
+        .min_by_key(|s| costs[*s])
+    else {
+        return Ok(Vec::new());
+    };
+    let mut states = vec![State::Numeric; characters.len()];
+    for i in (0..characters.len()).rev() {
+        states[i] = STATES[state];
+        state = previous[i][state];
+    }
+    Ok(states)
+}
+
+fn transitionCost(from: State, to: State) -> u32 {
+    match (from, to) {
+        (State::TextOne, State::TextTwo) | (State::TextTwo, State::TextOne) => 6 * 6,
+        _ if from == to && to != State::FourByte => 0,
+        _ => to.segmentCost(),
+    }
+}
+
+fn appendSegment(
+    bits: &mut BitArray,
+    mode: Mode,
+    characters: &[Character],
+    states: &[State],
+) -> Result<()> {
+    bits.appendBits(mode.getBits() as u32, 4)?;
+    match mode {
+        Mode::NUMERIC => {
+            let digits = characters
+                .iter()
+                .map(|c| (c.value.unwrap_or_default() - b'0') as u32)
+                .collect::<Vec<_>>();
+            for group in digits.chunks(3) {
+                bits.appendBits(group.iter().fold(0, |value, d| value * 10 + d), 10)?;
+            }
+            // the terminator tells the number of digits in the last group
+            let lastDigits = (digits.len() - 1) % 3 + 1;
+            bits.appendBits(1020 + lastDigits as u32, 10)?;
+        }
+        Mode::TEXT => {
+            let mut firstAlphabet = true;
+            for (character, state) in characters.iter().zip(states) {
+                if (*state == State::TextOne) != firstAlphabet {
+                    bits.appendBits(62, 6)?;
+                    firstAlphabet = !firstAlphabet;
+                }
+                let value = character.value.ok_or(Exceptions::ILLEGAL_STATE)?;
+                let value = if firstAlphabet {
+                    textOneValue(value)
+                } else {
+                    textTwoValue(value)
+                };
+                bits.appendBits(value.ok_or(Exceptions::ILLEGAL_STATE)?, 6)?;
+            }
+            bits.appendBits(63, 6)?;
+        }
+        Mode::BINARY => {
+            let bytes = characters
+                .iter()
+                .flat_map(|c| c.bytes.iter().copied())
+                .collect::<Vec<_>>();
+            if bytes.len() >= 1 << 13 {
+                return Err(Exceptions::WRITER);
+            }
+            bits.appendBits(bytes.len() as u32, 13)?;
+            for byte in bytes {
+                bits.appendBits(byte as u32, 8)?;
+            }
+        }
+        Mode::FOUR_BYTE => {
+            for character in characters {
+                let value = character
+                    .fourByte
+                    .as_ref()
+                    .and_then(fourByteValue)
+                    .ok_or(Exceptions::ILLEGAL_STATE)?;
+                bits.appendBits(value, 21)?;
+            }
+        }
+        _ => {
+            let (numBits, terminator) = if mode == Mode::DOUBLE_BYTE {
+                (15, 32767)
+            } else {
+                (12, 4095)
+            };
+            for character in characters {
+                let value = character
+                    .gb18030
+                    .as_ref()
+                    .and_then(|bytes| match mode {
+                        Mode::REGION_ONE => regionOneValue(bytes),
+                        Mode::REGION_TWO => regionTwoValue(bytes),
+                        _ => doubleByteValue(bytes),
+                    })
+                    .ok_or(Exceptions::ILLEGAL_STATE)?;
+                bits.appendBits(value, numBits)?;
+            }
+            bits.appendBits(terminator, numBits)?;
+        }
+    }
+    Ok(())
+}
+
+fn appendECI(bits: &mut BitArray, value: u32) -> Result<()> {
+    bits.appendBits(Mode::ECI.getBits() as u32, 4)?;
+    if value <= 127 {
+        bits.appendBits(value, 8)
+    } else if value <= 16383 {
+        bits.appendBits(0x8000 | value, 16)
+    } else {
+        bits.appendBits(0xC00000 | value, 24)
+    }
+}
+
+fn textOneValue(value: u8) -> Option<u32> {
+    match value {
+        b'0'..=b'9' => Some((value - b'0') as u32),
+        b'A'..=b'Z' => Some((value - b'A') as u32 + 10),
+        b'a'..=b'z' => Some((value - b'a') as u32 + 36),
+        _ => None,
+    }
+}
+
+fn textTwoValue(value: u8) -> Option<u32> {
+    let value = value as u32;
+    match value {
+        0..=27 => Some(value),
+        32..=47 => Some(value - 4),
+        58..=64 => Some(value - 14),
+        91..=96 => Some(value - 40),
+        123..=127 => Some(value - 66),
+        _ => None,
+    }
+}
+
+fn regionOneValue(bytes: &[u8; 2]) -> Option<u32> {
+    let [first, second] = bytes.map(|byte| byte as u32);
+    match (first, second) {
+        (0xB0..=0xD7, 0xA1..=0xFE) => Some(94 * (first - 0xB0) + second - 0xA1),
+        (0xA1..=0xA3, 0xA1..=0xFE) => Some(94 * (first - 0xA1) + second - 0xA1 + 0xEB0),
+        (0xA8, 0xA1..=0xC0) => Some(second - 0xA1 + 0xFCA),
+        _ => None,
+    }
+}
+
+fn regionTwoValue(bytes: &[u8; 2]) -> Option<u32> {
+    let [first, second] = bytes.map(|byte| byte as u32);
+    match (first, second) {
+        (0xD8..=0xF7, 0xA1..=0xFE) => Some(94 * (first - 0xD8) + second - 0xA1),
+        _ => None,
+    }
+}
+
+fn doubleByteValue(bytes: &[u8; 2]) -> Option<u32> {
+    let [first, second] = bytes.map(|byte| byte as u32);
+    match (first, second) {
+        (0x81..=0xFE, 0x40..=0x7E) => Some(0xBE * (first - 0x81) + second - 0x40),
+        (0x81..=0xFE, 0x80..=0xFE) => Some(0xBE * (first - 0x81) + second - 0x41),
+        _ => None,
+    }
+}
+
+fn fourByteValue(bytes: &[u8; 4]) -> Option<u32> {
+    let [first, second, third, fourth] = bytes.map(|byte| byte as u32);
+    match (first, second, third, fourth) {
+        (0x81..=0xFE, 0x30..=0x39, 0x81..=0xFE, 0x30..=0x39) => Some(
+            0x3138 * (first - 0x81) + 0x4EC * (second - 0x30) + 10 * (third - 0x81) + fourth - 0x30,
+        ),
+        _ => None,
+    }
+}
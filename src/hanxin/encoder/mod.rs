@@ -0,0 +1,4 @@
+pub mod han_xin_encoder;
+mod high_level_encoder;
+
+pub use high_level_encoder::*;
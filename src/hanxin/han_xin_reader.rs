@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+
+use crate::{
+    common::{BitMatrix, DetectorRXingResult, Result},
+    BarcodeFormat, Binarizer, BinaryBitmap, DecodeHintType, DecodeHintValue,
+    DecodingHintDictionary, Exceptions, RXingResult, RXingResultMetadataType,
+    RXingResultMetadataValue, Reader,
+};
+
+use super::{decoder::han_xin_decoder, detector};
+
+/**
+ * This implementation can detect and decode Han Xin Codes in an image.
+ */
+#[derive(Default)]
+pub struct HanXinReader;
+
+impl Reader for HanXinReader {
+    /**
+     * Locates and decodes a Han Xin Code in an image.
+     *
+     * @return a String representing the content encoded by the Han Xin Code
+     * @throws NotFoundException if a Han Xin Code cannot be found
+     * @throws FormatException if a Han Xin Code cannot be decoded
+     * @throws ChecksumException if error correction fails
+     */
+    fn decode<B: Binarizer>(&mut self, image: &mut BinaryBitmap<B>) -> Result<RXingResult> {
+        self.decode_with_hints(image, &HashMap::new())
+    }
+
+    fn decode_with_hints<B: Binarizer>(
+        &mut self,
+        image: &mut BinaryBitmap<B>,
+        hints: &DecodingHintDictionary,
+    ) -> Result<RXingResult> {
+        let (decoderRXingResult, points) = if matches!(
+            hints.get(&DecodeHintType::PURE_BARCODE),
+            Some(DecodeHintValue::PureBarcode(true))
+        ) {
+            let bits = Self::extractPureBits(image.get_black_matrix())?;
+            (han_xin_decoder::decode(&bits)?, Vec::new())
+        } else {
+            let detectorRXingResult = detector::detect(image.get_black_matrix())?;
+            (
+                han_xin_decoder::decode(detectorRXingResult.getBits())?,
+                detectorRXingResult.getPoints().to_vec(),
+            )
+        };
+
+        let mut result = RXingResult::new(
+            decoderRXingResult.getText(),
+            decoderRXingResult.getRawBytes().clone(),
+            points,
+            BarcodeFormat::HAN_XIN,
+        );
+
+        let byteSegments = decoderRXingResult.getByteSegments();
+        if !byteSegments.is_empty() {
+            result.putMetadata(
+                RXingResultMetadataType::BYTE_SEGMENTS,
+                RXingResultMetadataValue::ByteSegments(byteSegments.clone()),
+            );
+        }
+        result.putMetadata(
+            RXingResultMetadataType::ERROR_CORRECTION_LEVEL,
+            RXingResultMetadataValue::ErrorCorrectionLevel(
+                decoderRXingResult.getECLevel().to_owned(),
+            ),
+        );
+        result.putMetadata(
+            RXingResultMetadataType::SYMBOLOGY_IDENTIFIER,
+            RXingResultMetadataValue::SymbologyIdentifier(format!(
+                "]h{}",
+                decoderRXingResult.getSymbologyModifier()
+            )),
+        );
+
+        Ok(result)
+    }
+
+    fn reset(&mut self) {
+        // do nothing
+    }
+}
+
+impl HanXinReader {
+    /**
+     * This method detects a code in a "pure" image -- that is, pure monochrome image
+     * which contains only an unrotated, unskewed, image of a code, with some white border
+     * around it. The module size is taken from the top row of the top left finder pattern,
+     * which is seven modules wide.
+     */
+    fn extractPureBits(image: &BitMatrix) -> Result<BitMatrix> {
+        let [left, top, width, height] =
+            image.getEnclosingRectangle().ok_or(Exceptions::NOT_FOUND)?;
+        if width != height {
+            return Err(Exceptions::NOT_FOUND);
+        }
+
+        let mut finderWidth = 0;
+        while finderWidth < width && image.get(left + finderWidth, top) {
+            finderWidth += 1;
+        }
+        let moduleSize = finderWidth as f32 / 7.0;
+        if moduleSize < 1.0 {
+            return Err(Exceptions::NOT_FOUND);
+        }
+        let dimension = (width as f32 / moduleSize).round() as u32;
+        if dimension < 23 || dimension.is_multiple_of(2) {
+            return Err(Exceptions::NOT_FOUND);
+        }
+
+        // Now just read off the bits, from the centre of each module
+        let mut bits = BitMatrix::with_single_dimension(dimension)?;
+        for y in 0..dimension {
+            let iy = top + ((y as f32 + 0.5) * moduleSize) as u32;
+            for x in 0..dimension {
+                let ix = left + ((x as f32 + 0.5) * moduleSize) as u32;
+                if image.get(ix.min(left + width - 1), iy.min(top + height - 1)) {
+                    bits.set(x, y);
+                }
+            }
+        }
+        Ok(bits)
+    }
+}
@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+
+use crate::{
+    common::{BitMatrix, Result},
+    BarcodeFormat, EncodeHintType, EncodeHintValue, EncodingHintDictionary, Exceptions, Writer,
+};
+
+use super::{decoder::ErrorCorrectionLevel, encoder::han_xin_encoder};
+
+const QUIET_ZONE_SIZE: u32 = 3;
+
+/**
+ * <p>Renders a Han Xin Code as a {@link BitMatrix}.</p>
+ *
+ * <p>The error correction level is set with {@link EncodeHintType#ERROR_CORRECTION}, "L1" to
+ * "L4" or 1 to 4, and defaults to L1. {@link EncodeHintType#HAN_XIN_VERSION} selects a version,
+ * {@link EncodeHintType#MARGIN} the quiet zone, three modules by default.</p>
+ */
+#[derive(Default)]
+pub struct HanXinWriter;
+
+impl Writer for HanXinWriter {
+    fn encode(
+        &self,
+        contents: &str,
+        format: &BarcodeFormat,
+        width: i32,
+        height: i32,
+    ) -> Result<BitMatrix> {
+        self.encode_with_hints(contents, format, width, height, &HashMap::new())
+    }
+
+    fn encode_with_hints(
+        &self,
+        contents: &str,
+        format: &BarcodeFormat,
+        width: i32,
+        height: i32,
+        hints: &EncodingHintDictionary,
+    ) -> Result<BitMatrix> {
+        if contents.is_empty() {
+            return Err(Exceptions::illegal_argument_with("Found empty contents"));
+        }
+
+        if format != &BarcodeFormat::HAN_XIN {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "Can only encode HAN_XIN, but got {format:?}"
+            )));
+        }
+
+        if width < 0 || height < 0 {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "Requested dimensions can't be negative: {width}x{height}"
+            )));
+        }
+
+        let ecLevel = if let Some(EncodeHintValue::ErrorCorrection(ecLevel)) =
+            hints.get(&EncodeHintType::ERROR_CORRECTION)
+        {
+            ecLevel.parse()?
+        } else {
+            ErrorCorrectionLevel::L1
+        };
+
+        let quietZone =
+            if let Some(EncodeHintValue::Margin(margin)) = hints.get(&EncodeHintType::MARGIN) {
+                margin
+                    .parse::<u32>()
+                    .map_err(|e| Exceptions::parse_with(format!("could not parse {margin}: {e}")))?
+            } else {
+                QUIET_ZONE_SIZE
+            };
+
+        let code = han_xin_encoder::encode_with_hints(contents, ecLevel, hints)?;
+        render(&code, width as u32, height as u32, quietZone)
+    }
+}
+
+/**
+ * Scales the symbol up to the requested size by a whole number of pixels per module, and centres
+ * it with at least the quiet zone around it.
+ */
+fn render(code: &BitMatrix, width: u32, height: u32, quietZone: u32) -> Result<BitMatrix> {
+    let inputSize = code.getWidth();
+    let fullSize = inputSize + 2 * quietZone;
+    let outputWidth = width.max(fullSize);
+    let outputHeight = height.max(fullSize);
+    let multiple = (outputWidth / fullSize).min(outputHeight / fullSize);
+    let leftPadding = (outputWidth - inputSize * multiple) / 2;
+    let topPadding = (outputHeight - inputSize * multiple) / 2;
+
+    let mut output = BitMatrix::new(outputWidth, outputHeight)?;
+    for y in 0..inputSize {
+        for x in 0..inputSize {
+            if code.get(x, y) {
+                output.setRegion(
+                    leftPadding + x * multiple,
+                    topPadding + y * multiple,
+                    multiple,
+                    multiple,
+                )?;
+            }
+        }
+    }
+    Ok(output)
+}
@@ -0,0 +1,11 @@
+pub mod decoder;
+pub mod detector;
+pub mod encoder;
+mod han_xin_reader;
+mod han_xin_writer;
+
+pub use han_xin_reader::*;
+pub use han_xin_writer::*;
+
+#[cfg(test)]
+mod HanXinTestCase;
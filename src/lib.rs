@@ -4,7 +4,9 @@
 pub mod aztec;
 pub mod client;
 pub mod common;
+pub mod dotcode;
 mod exceptions;
+pub mod hanxin;
 pub mod maxicode;
 pub mod qrcode;
 
@@ -86,6 +88,7 @@ pub mod datamatrix;
 pub mod multi;
 pub mod oned;
 pub mod pdf417;
+pub mod postal;
 
 mod multi_format_writer;
 pub use multi_format_writer::*;
@@ -46,14 +46,14 @@ const PAD: &str = "\u{FFFC}";
 const FS: &str = "\u{001C}";
 const GS: &str = "\u{001D}";
 const RS: &str = "\u{001E}";
-const COUNTRY_BYTES: [u8; 10] = [53, 54, 43, 44, 45, 46, 47, 48, 37, 38];
-const SERVICE_CLASS_BYTES: [u8; 10] = [55, 56, 57, 58, 59, 60, 49, 50, 51, 52];
-const POSTCODE_2_LENGTH_BYTES: [u8; 6] = [39, 40, 41, 42, 31, 32];
-const POSTCODE_2_BYTES: [u8; 30] = [
+pub(crate) const COUNTRY_BYTES: [u8; 10] = [53, 54, 43, 44, 45, 46, 47, 48, 37, 38];
+pub(crate) const SERVICE_CLASS_BYTES: [u8; 10] = [55, 56, 57, 58, 59, 60, 49, 50, 51, 52];
+pub(crate) const POSTCODE_2_LENGTH_BYTES: [u8; 6] = [39, 40, 41, 42, 31, 32];
+pub(crate) const POSTCODE_2_BYTES: [u8; 30] = [
     33, 34, 35, 36, 25, 26, 27, 28, 29, 30, 19, 20, 21, 22, 23, 24, 13, 14, 15, 16, 17, 18, 7, 8,
     9, 10, 11, 12, 1, 2,
 ];
-const POSTCODE_3_BYTES: [[u8; 6]; 6] = [
+pub(crate) const POSTCODE_3_BYTES: [[u8; 6]; 6] = [
     [39, 40, 41, 42, 31, 32],
     [33, 34, 35, 36, 25, 26],
     [27, 28, 29, 30, 19, 20],
@@ -62,7 +62,7 @@ const POSTCODE_3_BYTES: [[u8; 6]; 6] = [
     [9, 10, 11, 12, 1, 2],
 ];
 
-static SETS: Lazy<[String; 5]> = Lazy::new(|| {
+pub(crate) static SETS: Lazy<[String; 5]> = Lazy::new(|| {
     [
     format!("\rABCDEFGHIJKLMNOPQRSTUVWXYZ{ECI}{FS}{GS}{RS}{NS} {PAD}\"#$%&'()*+,-./0123456789:{SHIFTB}{SHIFTC}{SHIFTD}{SHIFTE}{LATCHB}"           ),
     format!("`abcdefghijklmnopqrstuvwxyz{ECI}{FS}{GS}{RS}{NS}{{{PAD}}}~\u{007F};<=>?[\\]^_ ,./:@!|{PAD}{TWOSHIFTA}{THREESHIFTA}{PAD}{SHIFTA}{SHIFTC}{SHIFTD}{SHIFTE}{LATCHA}"               ),
@@ -109,7 +109,7 @@ pub fn decode(bytes: &[u8], mode: u8) -> Result<DecoderRXingResult> {
                 result.insert_str(0, &format!("{postcode}{GS}{country}{GS}{service}{GS}"));
             }
         }
-        4 => {
+        4 | 6 => {
             result.push_str(&getMessage(bytes, 1, 93));
         }
         5 => {
@@ -162,10 +162,9 @@ fn getPostCode2(bytes: &[u8]) -> u32 {
 
 fn getPostCode3(bytes: &[u8]) -> String {
     let mut sb = String::with_capacity(POSTCODE_3_BYTES.len());
-    let mut graphemes = SETS[0].graphemes(true);
     for p3bytes in &POSTCODE_3_BYTES {
         // for (byte[] p3bytes : POSTCODE_3_BYTES) {
-        if let Some(c) = graphemes.nth(getInt(bytes, p3bytes) as usize) {
+        if let Some(c) = SETS[0].graphemes(true).nth(getInt(bytes, p3bytes) as usize) {
             sb.push_str(c);
         }
     }
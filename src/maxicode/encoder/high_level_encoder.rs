@@ -0,0 +1,254 @@
+use std::collections::HashMap;
+
+use once_cell::sync::Lazy;
+
+use crate::{common::Result, maxicode::decoder::decoded_bit_stream_parser::SETS, Exceptions};
+
+/*
+ * Converts text into MaxiCode codewords using the five code sets (A-E) understood by
+ * the decoder, switching sets with shifts and latches and compressing runs of nine or
+ * more digits with the numeric shift.
+ */
+
+const SET_A: usize = 0;
+const SET_B: usize = 1;
+const SET_C: usize = 2;
+const SET_D: usize = 3;
+const SET_E: usize = 4;
+
+const PAD: u8 = 33;
+const NS: u8 = 31;
+const SHIFT_A: u8 = 59;
+const SHIFT_B: u8 = 59;
+const TWO_SHIFT_A: u8 = 56;
+const THREE_SHIFT_A: u8 = 57;
+/// Latch to set A from sets C, D and E
+const LATCH_A: u8 = 58;
+/// Latch to set A from set B
+const LATCH_A_FROM_B: u8 = 63;
+const LATCH_B: u8 = 63;
+
+/// Shortest digit run worth compressing with the numeric shift
+const NS_DIGITS: usize = 9;
+
+/// Every character that can be encoded, mapped to the sets (and values in those sets) that hold it.
+static CHAR_VALUES: Lazy<HashMap<char, Vec<(usize, u8)>>> = Lazy::new(|| {
+    let mut values: HashMap<char, Vec<(usize, u8)>> = HashMap::new();
+    for (set, chars) in SETS.iter().enumerate() {
+        for (value, c) in chars.chars().enumerate() {
+            // The private use characters are the decoder's markers for shifts, latches, PAD, ECI and NS
+            if ('\u{FFF0}'..='\u{FFFC}').contains(&c) {
+                continue;
+            }
+            values.entry(c).or_default().push((set, value as u8));
+        }
+    }
+    values
+});
+
+/**
+ * Encodes `msg` into exactly `capacity` codewords, padding if required.
+ *
+ * @param msg the message to encode
+ * @param capacity the number of codewords available to the message
+ * @return the message codewords
+ */
+pub fn encode_message(msg: &str, capacity: usize) -> Result<Vec<u8>> {
+    let chars: Vec<char> = msg.chars().collect();
+    let mut codewords = Vec::with_capacity(capacity);
+    let mut set = SET_A;
+
+    let mut i = 0;
+    while i < chars.len() {
+        if digit_run(&chars, i) >= NS_DIGITS {
+            let value: u32 = chars[i..i + NS_DIGITS]
+                .iter()
+                .collect::<String>()
+                .parse()
+                .map_err(|_| Exceptions::ILLEGAL_STATE)?;
+            codewords.push(NS);
+            for shift in [24, 18, 12, 6, 0] {
+                codewords.push(((value >> shift) & 0x3F) as u8);
+            }
+            i += NS_DIGITS;
+            continue;
+        }
+
+        let c = chars[i];
+        if let Some(value) = value_in_set(c, set) {
+            codewords.push(value);
+            i += 1;
+            continue;
+        }
+
+        let target = choose_set(&chars, i)?;
+        let run = run_length(&chars, i, target);
+        match (set, target) {
+            (SET_A, SET_B) if run == 1 => codewords.push(SHIFT_B),
+            (SET_A, SET_B) => {
+                codewords.push(LATCH_B);
+                set = SET_B;
+                continue;
+            }
+            (SET_B, SET_A) if run <= 3 => {
+                codewords.push(match run {
+                    1 => SHIFT_A,
+                    2 => TWO_SHIFT_A,
+                    _ => THREE_SHIFT_A,
+                });
+                for c in &chars[i..i + run] {
+                    codewords.push(value_in_set(*c, SET_A).ok_or(Exceptions::ILLEGAL_STATE)?);
+                }
+                i += run;
+                continue;
+            }
+            (SET_B, SET_A) => {
+                codewords.push(LATCH_A_FROM_B);
+                set = SET_A;
+                continue;
+            }
+            (_, SET_A) => {
+                codewords.push(LATCH_A);
+                set = SET_A;
+                continue;
+            }
+            (_, SET_B) => {
+                codewords.push(LATCH_B);
+                set = SET_B;
+                continue;
+            }
+            (_, _) => {
+                // Shift C, D and E share their value in every set, as does the lock which
+                // follows a shift to turn it into a latch.
+                let shift = 60 + (target - SET_C) as u8;
+                codewords.push(shift);
+                if run > 1 {
+                    codewords.push(shift);
+                    set = target;
+                    continue;
+                }
+            }
+        }
+        // single shifted character
+        codewords.push(value_in_set(c, target).ok_or(Exceptions::ILLEGAL_STATE)?);
+        i += 1;
+    }
+
+    if codewords.len() > capacity {
+        return Err(Exceptions::illegal_argument_with(format!(
+            "message requires {} codewords, but only {capacity} are available",
+            codewords.len()
+        )));
+    }
+
+    if codewords.len() < capacity && matches!(set, SET_C | SET_D | SET_E) {
+        // PAD is only available in sets A and B
+        codewords.push(LATCH_A);
+    }
+    codewords.resize(capacity, PAD);
+
+    Ok(codewords)
+}
+
+/**
+ * Returns the value of a character in set A, used for the postcode of a mode 3 symbol.
+ */
+pub fn set_a_value(c: char) -> Option<u8> {
+    value_in_set(c, SET_A)
+}
+
+fn value_in_set(c: char, set: usize) -> Option<u8> {
+    CHAR_VALUES
+        .get(&c)?
+        .iter()
+        .find(|(s, _)| *s == set)
+        .map(|(_, v)| *v)
+}
+
+fn digit_run(chars: &[char], start: usize) -> usize {
+    chars[start..]
+        .iter()
+        .take_while(|c| c.is_ascii_digit())
+        .count()
+}
+
+fn run_length(chars: &[char], start: usize, set: usize) -> usize {
+    let mut run = 0;
+    while start + run < chars.len()
+        && (run == 0 || digit_run(chars, start + run) < NS_DIGITS)
+        && value_in_set(chars[start + run], set).is_some()
+    {
+        run += 1;
+    }
+    run
+}
+
+fn choose_set(chars: &[char], position: usize) -> Result<usize> {
+    let c = chars[position];
+    let Some(candidates) = CHAR_VALUES.get(&c) else {
+        return Err(Exceptions::illegal_argument_with(format!(
+            "character '{}' (U+{:04X}) cannot be encoded in MaxiCode",
+            c.escape_default(),
+            c as u32
+        )));
+    };
+    let mut best = candidates[0].0;
+    let mut best_run = 0;
+    for (set, _) in candidates {
+        let run = run_length(chars, position, *set);
+        if run > best_run {
+            best = *set;
+            best_run = run;
+        }
+    }
+    Ok(best)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::maxicode::decoder::decoded_bit_stream_parser;
+
+    use super::encode_message;
+
+    fn round_trip(msg: &str) {
+        let mut bytes = vec![4];
+        bytes.extend(encode_message(msg, 93).expect("must encode"));
+        let result = decoded_bit_stream_parser::decode(&bytes, 4).expect("must decode");
+        assert_eq!(msg, result.getText());
+    }
+
+    #[test]
+    fn set_a_only() {
+        round_trip("HELLO WORLD 12345");
+    }
+
+    #[test]
+    fn shifts_and_latches() {
+        round_trip("Hello World");
+        round_trip("aBc");
+        round_trip("abcDEFghiJKLMnop");
+        round_trip("ab12cd");
+        round_trip(
+            "\u{00E0}b\u{00C0}c\u{0001}\u{00E9}\u{00E8}\u{00EA}\u{00C9}\u{00C8}\u{0002}\u{0003}Z",
+        );
+    }
+
+    #[test]
+    fn numeric_compression() {
+        round_trip("123456789");
+        round_trip("ab0123456789012345678cd");
+        let codewords = encode_message("123456789", 10).expect("must encode");
+        assert_eq!(&codewords[0..6], &[31, 7, 22, 60, 52, 21]);
+    }
+
+    #[test]
+    fn pads_after_latch() {
+        round_trip("\u{00E0}\u{00E1}\u{00E2}");
+    }
+
+    #[test]
+    fn too_long() {
+        assert!(encode_message(&"a".repeat(94), 93).is_err());
+        assert!(encode_message("\u{4E2D}", 93).is_err());
+    }
+}
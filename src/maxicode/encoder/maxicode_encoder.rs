@@ -0,0 +1,303 @@
+use crate::{
+    common::{
+        reedsolomon::{get_predefined_genericgf, PredefinedGenericGF, ReedSolomonEncoder},
+        BitMatrix, Result,
+    },
+    maxicode::{
+        decoder::{
+            decoded_bit_stream_parser::{
+                COUNTRY_BYTES, POSTCODE_2_BYTES, POSTCODE_2_LENGTH_BYTES, POSTCODE_3_BYTES,
+                SERVICE_CLASS_BYTES,
+            },
+            BITNR,
+        },
+        MaxiCodeReader,
+    },
+    Exceptions,
+};
+
+use super::high_level_encoder;
+
+/*
+ * Generates the module grid of a MaxiCode symbol.
+ *
+ * The returned matrix is the same 30x33 grid that `maxicode::decoder::decode` reads: odd rows
+ * are offset by half a module to the right, and the area of the bullseye is left empty.
+ */
+
+pub const DEFAULT_MODE: u8 = 4;
+
+const MODE_BITS: [u8; 4] = [3, 4, 5, 6];
+const PRIMARY_CODEWORDS: usize = 10;
+const PRIMARY_EC_CODEWORDS: usize = 10;
+const SEC_DATA_CODEWORDS: usize = 84;
+const EEC_DATA_CODEWORDS: usize = 68;
+const TOTAL_CODEWORDS: usize = 144;
+const MAX_MODE_2_POSTCODE_LENGTH: usize = 9;
+const MAX_MODE_3_POSTCODE_LENGTH: usize = 6;
+const MESSAGE_HEADER: &str = "[)>\u{001E}01\u{001D}";
+const GS: char = '\u{001D}';
+
+/**
+ * Encodes the given contents as a MaxiCode symbol.
+ *
+ * Modes 2 and 3 expect a structured carrier message, formatted the same way the decoder reports
+ * it: `postcode GS country GS service GS message`, optionally preceded by the
+ * `[)>RS01GSyy` header. Mode 2 is used for numeric postcodes of up to 9 digits, mode 3 otherwise.
+ * Modes 4, 5 and 6 encode the contents as a standard, enhanced error correction or reader
+ * programming message respectively.
+ *
+ * @param contents the data to encode
+ * @param mode the MaxiCode mode (2-6)
+ * @return the 30x33 module grid of the symbol
+ */
+pub fn encode(contents: &str, mode: u8) -> Result<BitMatrix> {
+    let data = match mode {
+        2 | 3 => encode_structured_carrier(contents, mode)?,
+        4 | 6 => encode_plain(contents, mode, SEC_DATA_CODEWORDS)?,
+        5 => encode_plain(contents, mode, EEC_DATA_CODEWORDS)?,
+        _ => {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "unsupported MaxiCode mode {mode}, expected 2-6"
+            )))
+        }
+    };
+
+    let codewords = add_error_correction(&data)?;
+    place_codewords(&codewords)
+}
+
+/**
+ * Builds the data codewords for modes 4, 5 and 6, where the message starts in the primary message
+ * right after the mode and continues into the secondary message.
+ */
+fn encode_plain(contents: &str, mode: u8, secondary_codewords: usize) -> Result<Vec<u8>> {
+    let mut data = vec![mode];
+    data.extend(high_level_encoder::encode_message(
+        contents,
+        PRIMARY_CODEWORDS - 1 + secondary_codewords,
+    )?);
+    Ok(data)
+}
+
+/**
+ * Builds the data codewords for modes 2 and 3, where the primary message holds the postcode,
+ * country code and service class.
+ */
+fn encode_structured_carrier(contents: &str, mode: u8) -> Result<Vec<u8>> {
+    let (header, fields) = if contents.starts_with(MESSAGE_HEADER)
+        && contents.chars().count() >= MESSAGE_HEADER.len() + 2
+    {
+        let split = contents
+            .char_indices()
+            .nth(MESSAGE_HEADER.len() + 2)
+            .map(|(i, _)| i)
+            .unwrap_or(contents.len());
+        contents.split_at(split)
+    } else {
+        ("", contents)
+    };
+
+    let mut parts = fields.splitn(4, GS);
+    let (Some(postcode), Some(country), Some(service)) = (parts.next(), parts.next(), parts.next())
+    else {
+        return Err(Exceptions::illegal_argument_with(
+            "structured carrier message must start with postcode, country and service class separated by GS",
+        ));
+    };
+    let message = format!("{header}{}", parts.next().unwrap_or_default());
+
+    let country = parse_three_digits(country, "country code")?;
+    let service = parse_three_digits(service, "service class")?;
+
+    let numeric_postcode = !postcode.is_empty()
+        && postcode.len() <= MAX_MODE_2_POSTCODE_LENGTH
+        && postcode.chars().all(|c| c.is_ascii_digit());
+
+    let mut primary = [0u8; PRIMARY_CODEWORDS];
+    if mode == 2 && numeric_postcode {
+        set_int(&mut primary, &MODE_BITS, 2);
+        set_int(
+            &mut primary,
+            &POSTCODE_2_BYTES,
+            postcode.parse().map_err(|_| Exceptions::ILLEGAL_STATE)?,
+        );
+        set_int(
+            &mut primary,
+            &POSTCODE_2_LENGTH_BYTES,
+            postcode.len() as u32,
+        );
+    } else {
+        if postcode.chars().count() > MAX_MODE_3_POSTCODE_LENGTH {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "postcode '{postcode}' is longer than {MAX_MODE_3_POSTCODE_LENGTH} characters"
+            )));
+        }
+        set_int(&mut primary, &MODE_BITS, 3);
+        let mut postcode_chars = postcode.chars();
+        for bits in &POSTCODE_3_BYTES {
+            let c = postcode_chars.next().unwrap_or(' ');
+            let value = high_level_encoder::set_a_value(c).ok_or_else(|| {
+                Exceptions::illegal_argument_with(format!(
+                    "postcode character '{c}' is not in code set A"
+                ))
+            })?;
+            set_int(&mut primary, bits, value as u32);
+        }
+    }
+    set_int(&mut primary, &COUNTRY_BYTES, country);
+    set_int(&mut primary, &SERVICE_CLASS_BYTES, service);
+
+    let mut data = primary.to_vec();
+    data.extend(high_level_encoder::encode_message(
+        &message,
+        SEC_DATA_CODEWORDS,
+    )?);
+    Ok(data)
+}
+
+fn parse_three_digits(value: &str, name: &str) -> Result<u32> {
+    if value.is_empty() || value.len() > 3 || !value.chars().all(|c| c.is_ascii_digit()) {
+        return Err(Exceptions::illegal_argument_with(format!(
+            "{name} must be 1 to 3 digits, found '{value}'"
+        )));
+    }
+    value.parse().map_err(|_| Exceptions::ILLEGAL_STATE)
+}
+
+/**
+ * Writes `value` into the (1-based, most significant first) bit positions listed in `bits`,
+ * the inverse of the decoder's `getInt`.
+ */
+fn set_int(bytes: &mut [u8], bits: &[u8], value: u32) {
+    for (i, bit) in bits.iter().enumerate() {
+        if value & (1 << (bits.len() - i - 1)) != 0 {
+            let bit = bit - 1;
+            bytes[bit as usize / 6] |= 1 << (5 - (bit % 6));
+        }
+    }
+}
+
+/**
+ * Interleaves the data codewords with their Reed-Solomon error correction codewords. The primary
+ * message is protected as a whole, the secondary message separately for even and odd codewords.
+ */
+fn add_error_correction(data: &[u8]) -> Result<[u8; TOTAL_CODEWORDS]> {
+    let mut codewords = [0u8; TOTAL_CODEWORDS];
+    let mut rs = ReedSolomonEncoder::new(get_predefined_genericgf(
+        PredefinedGenericGF::MaxicodeField64,
+    ))?;
+
+    let mut primary = data[..PRIMARY_CODEWORDS]
+        .iter()
+        .map(|cw| *cw as i32)
+        .collect::<Vec<_>>();
+    primary.resize(PRIMARY_CODEWORDS + PRIMARY_EC_CODEWORDS, 0);
+    rs.encode(&mut primary, PRIMARY_EC_CODEWORDS)?;
+    for (cw, value) in codewords.iter_mut().zip(primary) {
+        *cw = value as u8;
+    }
+
+    let secondary = &data[PRIMARY_CODEWORDS..];
+    let start = PRIMARY_CODEWORDS + PRIMARY_EC_CODEWORDS;
+    let ec_codewords = TOTAL_CODEWORDS - start - secondary.len();
+    codewords[start..start + secondary.len()].copy_from_slice(secondary);
+    for parity in 0..2 {
+        let mut block = secondary
+            .iter()
+            .skip(parity)
+            .step_by(2)
+            .map(|cw| *cw as i32)
+            .collect::<Vec<_>>();
+        let block_data = block.len();
+        block.resize(block_data + ec_codewords / 2, 0);
+        rs.encode(&mut block, ec_codewords / 2)?;
+        for (i, value) in block[block_data..].iter().enumerate() {
+            codewords[start + secondary.len() + 2 * i + parity] = *value as u8;
+        }
+    }
+
+    Ok(codewords)
+}
+
+fn place_codewords(codewords: &[u8; TOTAL_CODEWORDS]) -> Result<BitMatrix> {
+    let mut matrix = BitMatrix::new(MaxiCodeReader::MATRIX_WIDTH, MaxiCodeReader::MATRIX_HEIGHT)?;
+    for (y, row) in BITNR.iter().enumerate() {
+        for (x, bit) in row.iter().enumerate() {
+            let dark = match *bit {
+                // orientation modules
+                -2 => true,
+                // fixed light modules and the bullseye
+                -1 | -3 => false,
+                bit => codewords[bit as usize / 6] & (1 << (5 - (bit % 6))) != 0,
+            };
+            if dark {
+                matrix.set(x as u32, y as u32);
+            }
+        }
+    }
+    Ok(matrix)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::maxicode::decoder;
+
+    use super::encode;
+
+    fn round_trip(contents: &str, mode: u8, expected: &str) {
+        let bits = encode(contents, mode).expect("must encode");
+        let result = decoder::decode(&bits).expect("must decode");
+        assert_eq!(expected, result.getText());
+        assert_eq!(mode.to_string(), result.getECLevel());
+    }
+
+    #[test]
+    fn mode_2() {
+        let contents = "152382802\u{001D}840\u{001D}001\u{001D}1Z00004951\u{001D}UPSN\u{001D}06X610\u{001D}159\u{001D}1234567\u{001D}1/1\u{001D}\u{001D}Y\u{001D}634 ALPHA DR\u{001D}PITTSBURGH\u{001D}PA\u{001E}\u{0004}";
+        round_trip(contents, 2, contents);
+    }
+
+    #[test]
+    fn mode_2_with_header() {
+        let contents = "[)>\u{001E}01\u{001D}96123450000\u{001D}222\u{001D}111\u{001D}MODE6";
+        round_trip(contents, 2, contents);
+    }
+
+    #[test]
+    fn mode_3() {
+        let contents = "B1050 \u{001D}056\u{001D}999\u{001D}Some Message";
+        round_trip(contents, 3, contents);
+        // alphanumeric postcodes switch to mode 3
+        let result =
+            decoder::decode(&encode(contents, 2).expect("must encode")).expect("must decode");
+        assert_eq!(contents, result.getText());
+        assert_eq!("3", result.getECLevel());
+        // short postcodes are padded with spaces
+        round_trip(
+            "B1050\u{001D}056\u{001D}999\u{001D}",
+            3,
+            "B1050 \u{001D}056\u{001D}999\u{001D}",
+        );
+    }
+
+    #[test]
+    fn modes_4_to_6() {
+        round_trip(
+            "Standard message, 123456789 and more",
+            4,
+            "Standard message, 123456789 and more",
+        );
+        round_trip("Enhanced error correction", 5, "Enhanced error correction");
+        round_trip("Reader programming", 6, "Reader programming");
+    }
+
+    #[test]
+    fn invalid_input() {
+        assert!(encode("hello", 1).is_err());
+        assert!(encode("hello", 2).is_err());
+        assert!(encode("123\u{001D}1234\u{001D}001\u{001D}", 2).is_err());
+        assert!(encode("ABCDEFG\u{001D}840\u{001D}001\u{001D}", 3).is_err());
+        assert!(encode(&"x".repeat(78), 5).is_err());
+    }
+}
@@ -0,0 +1,4 @@
+mod high_level_encoder;
+pub mod maxicode_encoder;
+
+pub use high_level_encoder::*;
@@ -0,0 +1,219 @@
+use std::collections::HashMap;
+
+use crate::{
+    common::{BitMatrix, Result},
+    BarcodeFormat, EncodeHintType, EncodeHintValue, Exceptions, Writer,
+};
+
+use super::{encoder::maxicode_encoder, MaxiCodeReader};
+
+/// Vertical distance between the centers of two rows of hexagons, in module widths
+const ROW_PITCH: f32 = 0.866_025_4;
+/// Distance from the center of a hexagon to its top and bottom vertices, in module widths
+const HEXAGON_RADIUS: f32 = 0.577_350_3;
+/// Center of the bullseye in module widths, between the modules the grid leaves empty for it
+const BULLSEYE_CENTER: (f32, f32) = (14.5, HEXAGON_RADIUS + 16.0 * ROW_PITCH);
+/// Outer radii of the alternating dark and light rings of the bullseye, in module widths
+const BULLSEYE_RADII: [f32; 6] = [4.41, 3.65, 2.89, 2.12, 1.35, 0.58];
+/// Smallest module width, in pixels, at which the hexagons and the bullseye are recognizable
+const MIN_MODULE_WIDTH: u32 = 4;
+
+/**
+ * Renders a MaxiCode symbol as a {@link BitMatrix}, drawing hexagonal modules on alternately
+ * offset rows around the bullseye.
+ *
+ * The mode is selected with {@link EncodeHintType#MAXICODE_MODE} and defaults to mode 4.
+ */
+#[derive(Default)]
+pub struct MaxiCodeWriter;
+
+impl Writer for MaxiCodeWriter {
+    fn encode(
+        &self,
+        contents: &str,
+        format: &BarcodeFormat,
+        width: i32,
+        height: i32,
+    ) -> Result<BitMatrix> {
+        self.encode_with_hints(contents, format, width, height, &HashMap::new())
+    }
+
+    fn encode_with_hints(
+        &self,
+        contents: &str,
+        format: &BarcodeFormat,
+        width: i32,
+        height: i32,
+        hints: &crate::EncodingHintDictionary,
+    ) -> Result<BitMatrix> {
+        if contents.is_empty() {
+            return Err(Exceptions::illegal_argument_with("Found empty contents"));
+        }
+
+        if format != &BarcodeFormat::MAXICODE {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "Can only encode MAXICODE, but got {format:?}"
+            )));
+        }
+
+        if width < 0 || height < 0 {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "Requested dimensions can't be negative: {width}x{height}"
+            )));
+        }
+
+        let mode = if let Some(EncodeHintValue::MaxicodeMode(mode)) =
+            hints.get(&EncodeHintType::MAXICODE_MODE)
+        {
+            *mode
+        } else {
+            maxicode_encoder::DEFAULT_MODE
+        };
+
+        let code = maxicode_encoder::encode(contents, mode)?;
+        render(&code, width as u32, height as u32)
+    }
+}
+
+fn render(code: &BitMatrix, width: u32, height: u32) -> Result<BitMatrix> {
+    let symbol_width = MaxiCodeReader::MATRIX_WIDTH as f32;
+    let symbol_height =
+        (MaxiCodeReader::MATRIX_HEIGHT - 1) as f32 * ROW_PITCH + 2.0 * HEXAGON_RADIUS;
+
+    let module_width = ((width as f32 / symbol_width).min(height as f32 / symbol_height) as u32)
+        .max(MIN_MODULE_WIDTH);
+    let scale = module_width as f32;
+
+    let output_width = width.max((symbol_width * scale).ceil() as u32);
+    let output_height = height.max((symbol_height * scale).ceil() as u32);
+    let left = (output_width as f32 - symbol_width * scale) / 2.0;
+    let top = (output_height as f32 - symbol_height * scale) / 2.0;
+
+    let mut output = BitMatrix::new(output_width, output_height)?;
+
+    // Module centers, in module widths from the top left of the symbol
+    let center_of = |x: u32, y: u32| {
+        (
+            x as f32 + 0.5 + 0.5 * (y & 0x01) as f32,
+            HEXAGON_RADIUS + y as f32 * ROW_PITCH,
+        )
+    };
+
+    for y in 0..code.getHeight() {
+        for x in 0..code.getWidth() {
+            if !code.get(x, y) {
+                continue;
+            }
+            let (cx, cy) = center_of(x, y);
+            let min_x = (left + (cx - 0.5) * scale).floor().max(0.0) as u32;
+            let max_x = ((left + (cx + 0.5) * scale).ceil() as u32).min(output_width);
+            let min_y = (top + (cy - HEXAGON_RADIUS) * scale).floor().max(0.0) as u32;
+            let max_y = ((top + (cy + HEXAGON_RADIUS) * scale).ceil() as u32).min(output_height);
+            for py in min_y..max_y {
+                for px in min_x..max_x {
+                    let dx = ((px as f32 + 0.5 - left) / scale - cx).abs();
+                    let dy = ((py as f32 + 0.5 - top) / scale - cy).abs();
+                    // pointy topped hexagon with flat sides half a module from the center
+                    if dx <= 0.5 && dy <= HEXAGON_RADIUS - dx * HEXAGON_RADIUS {
+                        output.set(px, py);
+                    }
+                }
+            }
+        }
+    }
+
+    let (bx, by) = BULLSEYE_CENTER;
+    let outer = BULLSEYE_RADII[0];
+    let min_x = (left + (bx - outer) * scale).floor() as u32;
+    let max_x = (left + (bx + outer) * scale).ceil() as u32;
+    let min_y = (top + (by - outer) * scale).floor() as u32;
+    let max_y = (top + (by + outer) * scale).ceil() as u32;
+    for py in min_y..max_y {
+        for px in min_x..max_x {
+            let dx = (px as f32 + 0.5 - left) / scale - bx;
+            let dy = (py as f32 + 0.5 - top) / scale - by;
+            let distance = (dx * dx + dy * dy).sqrt();
+            // dark rings are the ones starting at an even index of BULLSEYE_RADII
+            let ring = BULLSEYE_RADII
+                .iter()
+                .rposition(|radius| distance <= *radius);
+            if matches!(ring, Some(r) if r % 2 == 0) {
+                output.set(px, py);
+            }
+        }
+    }
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::{
+        common::{bit_matrix_test_case, BitMatrix},
+        maxicode::{MaxiCodeReader, MaxiCodeWriter},
+        BarcodeFormat, DecodeHintType, DecodeHintValue, EncodeHintType, EncodeHintValue, Reader,
+        Writer,
+    };
+
+    fn read(matrix: &BitMatrix, try_harder: bool) -> String {
+        let mut image = bit_matrix_test_case::matrix_to_binary_bitmap(matrix);
+        let mut hints = HashMap::new();
+        hints.insert(
+            DecodeHintType::TRY_HARDER,
+            DecodeHintValue::TryHarder(try_harder),
+        );
+        MaxiCodeReader::default()
+            .decode_with_hints(&mut image, &hints)
+            .expect("must decode")
+            .getText()
+            .to_owned()
+    }
+
+    fn encode(contents: &str, mode: u8, size: i32) -> BitMatrix {
+        let mut hints = HashMap::new();
+        hints.insert(
+            EncodeHintType::MAXICODE_MODE,
+            EncodeHintValue::MaxicodeMode(mode),
+        );
+        MaxiCodeWriter
+            .encode_with_hints(contents, &BarcodeFormat::MAXICODE, size, size, &hints)
+            .expect("must encode")
+    }
+
+    #[test]
+    fn round_trip_pure() {
+        for (contents, mode) in [
+            ("123456789\u{001D}840\u{001D}001\u{001D}Shipping label", 2),
+            ("AB12CD\u{001D}826\u{001D}002\u{001D}Shipping label", 3),
+            ("The quick brown fox jumps over the lazy dog", 4),
+            ("Enhanced error correction", 5),
+            ("Reader programming", 6),
+        ] {
+            assert_eq!(contents, read(&encode(contents, mode, 300), false));
+        }
+    }
+
+    #[test]
+    fn round_trip_detected() {
+        let contents = "[)>\u{001E}01\u{001D}96123450000\u{001D}222\u{001D}111\u{001D}Detected";
+        assert_eq!(contents, read(&encode(contents, 2, 300), true));
+    }
+
+    #[test]
+    fn default_mode() {
+        let matrix = MaxiCodeWriter
+            .encode("Hello, World!", &BarcodeFormat::MAXICODE, 0, 0)
+            .expect("must encode");
+        assert_eq!(120, matrix.getWidth());
+        assert_eq!("Hello, World!", read(&matrix, false));
+    }
+
+    #[test]
+    fn wrong_format() {
+        assert!(MaxiCodeWriter
+            .encode("Hello", &BarcodeFormat::QR_CODE, 100, 100)
+            .is_err());
+    }
+}
@@ -8,6 +8,12 @@ pub use by_quadrant_reader::*;
 mod generic_multiple_barcode_reader;
 pub use generic_multiple_barcode_reader::*;
 
+mod structured_append;
+pub use structured_append::*;
+
 #[cfg(test)]
 #[cfg(feature = "image")]
 mod multi_test_case;
+
+#[cfg(test)]
+mod structured_append_test_case;
@@ -0,0 +1,348 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    common::CharacterSet, BarcodeFormat, RXingResult, RXingResultMetadataType,
+    RXingResultMetadataValue,
+};
+
+/**
+ * <p>Puts messages that were split across several symbols with structured append back
+ * together.</p>
+ *
+ * <p>Results may come from any number of images or video frames, in any order. They are grouped
+ * into messages by barcode format and file id, segments seen before are ignored. QR Code,
+ * Data Matrix, Aztec, PDF417 and MicroPDF417 sequences are understood.</p>
+ *
+ * <p>QR Code results of {@link crate::qrcode::QRCodeReader} carry the raw sequence indicator as
+ * structured append sequence and the parity as file id. All other results carry the 0-based
+ * position, the symbol count and the file id, except for (Micro)PDF417, which reports them in
+ * its {@link crate::pdf417::PDF417RXingResultMetadata}.</p>
+ */
+#[derive(Default)]
+pub struct StructuredAppendReassembler {
+    messages: Vec<StructuredAppendMessage>,
+}
+
+impl StructuredAppendReassembler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /**
+     * Adds a result to the message it is a segment of.
+     *
+     * @param result a decoded result
+     * @return false if the result is not part of a structured append sequence
+     */
+    pub fn add(&mut self, result: RXingResult) -> bool {
+        let Some(segment) = getSegment(&result) else {
+            return false;
+        };
+
+        let position = self
+            .messages
+            .iter()
+            .position(|message| {
+                message.format == segment.format && message.fileId == segment.fileId
+            })
+            .unwrap_or_else(|| {
+                self.messages.push(StructuredAppendMessage {
+                    format: segment.format,
+                    fileId: segment.fileId.clone(),
+                    count: None,
+                    segments: BTreeMap::new(),
+                });
+                self.messages.len() - 1
+            });
+        let message = &mut self.messages[position];
+        if message.count.is_none() {
+            message.count = segment.count;
+        }
+        message.segments.entry(segment.index).or_insert(result);
+        true
+    }
+
+    /**
+     * Adds all results, see {@link #add}.
+     *
+     * @return the results that are not part of a structured append sequence
+     */
+    pub fn addAll<I: IntoIterator<Item = RXingResult>>(&mut self, results: I) -> Vec<RXingResult> {
+        let mut others = Vec::new();
+        for result in results {
+            if getSegment(&result).is_some() {
+                self.add(result);
+            } else {
+                others.push(result);
+            }
+        }
+        others
+    }
+
+    /**
+     * @return all messages segments have been seen of, complete or not, in the order the first
+     *  segment of each was added
+     */
+    pub fn getMessages(&self) -> &[StructuredAppendMessage] {
+        &self.messages
+    }
+
+    /**
+     * Removes the messages of which all segments have been seen.
+     *
+     * @return the complete messages
+     */
+    pub fn takeCompleteMessages(&mut self) -> Vec<StructuredAppendMessage> {
+        let (complete, incomplete) = std::mem::take(&mut self.messages)
+            .into_iter()
+            .partition(StructuredAppendMessage::isComplete);
+        self.messages = incomplete;
+        complete
+    }
+}
+
+/**
+ * The segments seen so far of a message split with structured append.
+ */
+pub struct StructuredAppendMessage {
+    format: BarcodeFormat,
+    fileId: String,
+    count: Option<usize>,
+    segments: BTreeMap<usize, RXingResult>,
+}
+
+impl StructuredAppendMessage {
+    pub fn getBarcodeFormat(&self) -> &BarcodeFormat {
+        &self.format
+    }
+
+    /**
+     * @return the file id shared by the segments, the parity for QR Code. It is empty if the
+     *  symbols don't carry one.
+     */
+    pub fn getFileId(&self) -> &str {
+        &self.fileId
+    }
+
+    /**
+     * @return the number of segments of the message, if any segment told
+     */
+    pub fn getSegmentCount(&self) -> Option<usize> {
+        self.count
+    }
+
+    /**
+     * @return the segments seen so far, ordered by position
+     */
+    pub fn getSegments(&self) -> Vec<&RXingResult> {
+        self.segments.values().collect()
+    }
+
+    /**
+     * @return the 0-based positions of the segments not seen yet. While the number of segments is
+     *  unknown only the gaps before the last segment seen are reported.
+     */
+    pub fn getMissingSegments(&self) -> Vec<usize> {
+        let end = self.count.unwrap_or_else(|| {
+            self.segments
+                .keys()
+                .next_back()
+                .map_or(0, |index| index + 1)
+        });
+        (0..end)
+            .filter(|index| !self.segments.contains_key(index))
+            .collect()
+    }
+
+    pub fn isComplete(&self) -> bool {
+        self.count.is_some() && self.getMissingSegments().is_empty()
+    }
+
+    /**
+     * @return the text of the whole message, once complete
+     */
+    pub fn getText(&self) -> Option<String> {
+        self.completeSegments()
+            .map(|segments| segments.map(RXingResult::getText).collect())
+    }
+
+    /**
+     * <p>Each segment adds its raw bytes if they read as its text. Otherwise they hold the mode
+     *  indicators and the structured append header of the symbol, and the segment adds its byte
+     *  segments if they read as all of its text. Failing that it adds its text, which may mix
+     *  byte segments with other modes, in the character set its byte segments read in. Without
+     *  byte segments that is ISO-8859-1, the character set symbols are read in by default, or
+     *  UTF-8 if the text has other characters.</p>
+     *
+     * @return the payload of the whole message, once complete
+     */
+    pub fn getBytes(&self) -> Option<Vec<u8>> {
+        self.completeSegments()
+            .map(|segments| segments.flat_map(getPayload).collect())
+    }
+
+    /**
+     * @return a result holding the whole message, once complete. Its raw bytes are the payload,
+     *  and the byte segments of all segments are joined into one.
+     */
+    pub fn getRXingResult(&self) -> Option<RXingResult> {
+        let text = self.getText()?;
+        let rawBytes = self.getBytes()?;
+        let byteSegment = self
+            .completeSegments()?
+            .filter_map(|segment| {
+                match segment
+                    .getRXingResultMetadata()
+                    .get(&RXingResultMetadataType::BYTE_SEGMENTS)
+                {
+                    Some(RXingResultMetadataValue::ByteSegments(byteSegments)) => {
+                        Some(byteSegments.concat())
+                    }
+                    _ => None,
+                }
+            })
+            .flatten()
+            .collect::<Vec<_>>();
+
+        let mut result = RXingResult::new(&text, rawBytes, Vec::new(), self.format);
+        if !byteSegment.is_empty() {
+            result.putMetadata(
+                RXingResultMetadataType::BYTE_SEGMENTS,
+                RXingResultMetadataValue::ByteSegments(vec![byteSegment]),
+            );
+        }
+        Some(result)
+    }
+
+    fn completeSegments(&self) -> Option<impl Iterator<Item = &RXingResult>> {
+        let count = self.count?;
+        if !self.isComplete() {
+            return None;
+        }
+        Some(self.segments.range(..count).map(|(_, segment)| segment))
+    }
+}
+
+/**
+ * The character sets byte segments are tried in, as the decoders guess them without ECI.
+ */
+const BYTE_SEGMENT_CHARACTER_SETS: [CharacterSet; 3] = [
+    CharacterSet::UTF8,
+    CharacterSet::Shift_JIS,
+    CharacterSet::ISO8859_1,
+];
+
+/**
+ * @return the bytes the text of a segment was read from, see
+ *  {@link StructuredAppendMessage#getBytes}
+ */
+fn getPayload(segment: &RXingResult) -> Vec<u8> {
+    let text = segment.getText();
+    let rawBytes = segment.getRawBytes();
+    let readsAsText = |bytes: &[u8]| {
+        [CharacterSet::UTF8, CharacterSet::ISO8859_1]
+            .iter()
+            .any(|characterSet| characterSet.decode(bytes).is_ok_and(|read| read == text))
+    };
+    if !rawBytes.is_empty() && readsAsText(rawBytes) {
+        return rawBytes.clone();
+    }
+    if let Some(RXingResultMetadataValue::ByteSegments(byteSegments)) = segment
+        .getRXingResultMetadata()
+        .get(&RXingResultMetadataType::BYTE_SEGMENTS)
+    {
+        // the character set each byte segment reads as a part of the text in
+        let characterSet = BYTE_SEGMENT_CHARACTER_SETS.iter().find(|characterSet| {
+            !byteSegments.is_empty()
+                && byteSegments.iter().all(|byteSegment| {
+                    characterSet
+                        .decode(byteSegment)
+                        .is_ok_and(|read| text.contains(read.as_str()))
+                })
+        });
+        if let Some(characterSet) = characterSet {
+            let bytes = byteSegments.concat();
+            if characterSet.decode(&bytes).is_ok_and(|read| read == text) {
+                return bytes;
+            }
+            if let Ok(encoded) = characterSet.encode(text) {
+                return encoded;
+            }
+        }
+    }
+    CharacterSet::ISO8859_1
+        .encode(text)
+        .unwrap_or_else(|_| text.as_bytes().to_vec())
+}
+
+struct Segment {
+    format: BarcodeFormat,
+    fileId: String,
+    index: usize,
+    count: Option<usize>,
+}
+
+/**
+ * @return where the result sits in a structured append sequence, if it is part of one
+ */
+fn getSegment(result: &RXingResult) -> Option<Segment> {
+    let metadata = result.getRXingResultMetadata();
+    let format = *result.getBarcodeFormat();
+
+    if let Some(RXingResultMetadataValue::Pdf417ExtraMetadata(pdf417)) =
+        metadata.get(&RXingResultMetadataType::PDF417_EXTRA_METADATA)
+    {
+        let index = pdf417.getSegmentIndex();
+        let count = if pdf417.getSegmentCount() > 0 {
+            Some(pdf417.getSegmentCount() as usize)
+        } else if pdf417.isLastSegment() {
+            Some(index + 1)
+        } else {
+            None
+        };
+        return Some(Segment {
+            format,
+            fileId: pdf417.getFileId().to_owned(),
+            index,
+            count,
+        });
+    }
+
+    let Some(RXingResultMetadataValue::StructuredAppendSequence(sequence)) =
+        metadata.get(&RXingResultMetadataType::STRUCTURED_APPEND_SEQUENCE)
+    else {
+        return None;
+    };
+    if *sequence < 0 {
+        return None;
+    }
+
+    if let Some(RXingResultMetadataValue::StructuredAppendCount(count)) =
+        metadata.get(&RXingResultMetadataType::STRUCTURED_APPEND_COUNT)
+    {
+        let fileId = match metadata.get(&RXingResultMetadataType::STRUCTURED_APPEND_ID) {
+            Some(RXingResultMetadataValue::StructuredAppendId(id)) => id.clone(),
+            _ => String::new(),
+        };
+        Some(Segment {
+            format,
+            fileId,
+            index: *sequence as usize,
+            count: (*count > 0).then_some(*count as usize),
+        })
+    } else if format == BarcodeFormat::QR_CODE {
+        // the symbol sequence indicator holds the position and the count - 1 in 4 bits each
+        let fileId = match metadata.get(&RXingResultMetadataType::STRUCTURED_APPEND_PARITY) {
+            Some(RXingResultMetadataValue::StructuredAppendParity(parity)) => parity.to_string(),
+            _ => String::new(),
+        };
+        Some(Segment {
+            format,
+            fileId,
+            index: (*sequence >> 4) as usize,
+            count: Some((*sequence & 0x0F) as usize + 1),
+        })
+    } else {
+        None
+    }
+}
@@ -0,0 +1,551 @@
+/**
+ * Tests {@link StructuredAppendReassembler}.
+ */
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+use crate::{
+    aztec::AztecWriter,
+    common::{BitMatrix, HybridBinarizer},
+    datamatrix::{encoder::SymbolShapeHint, DataMatrixWriter},
+    pdf417::{encoder::MacroPDF417, PDF417RXingResultMetadata},
+    qrcode::QRCodeWriter,
+    BarcodeFormat, BinaryBitmap, DecodeHintType, DecodeHintValue, Dimension, EncodeHintType,
+    EncodeHintValue, EncodingHintDictionary, Luma8LuminanceSource, MultiFormatReader,
+    MultiFormatWriter, RXingResult, RXingResultMetadataType, RXingResultMetadataValue, Reader,
+    Writer,
+};
+
+use super::StructuredAppendReassembler;
+
+fn result(text: &str, format: BarcodeFormat, metadata: &[RXingResultMetadataValue]) -> RXingResult {
+    let mut result = RXingResult::new(text, text.as_bytes().to_vec(), Vec::new(), format);
+    for value in metadata {
+        let key = match value {
+            RXingResultMetadataValue::StructuredAppendSequence(_) => {
+                RXingResultMetadataType::STRUCTURED_APPEND_SEQUENCE
+            }
+            RXingResultMetadataValue::StructuredAppendParity(_) => {
+                RXingResultMetadataType::STRUCTURED_APPEND_PARITY
+            }
+            RXingResultMetadataValue::StructuredAppendCount(_) => {
+                RXingResultMetadataType::STRUCTURED_APPEND_COUNT
+            }
+            RXingResultMetadataValue::StructuredAppendId(_) => {
+                RXingResultMetadataType::STRUCTURED_APPEND_ID
+            }
+            RXingResultMetadataValue::Pdf417ExtraMetadata(_) => {
+                RXingResultMetadataType::PDF417_EXTRA_METADATA
+            }
+            _ => RXingResultMetadataType::OTHER,
+        };
+        result.putMetadata(key, value.clone());
+    }
+    result
+}
+
+fn qrCode(text: &str, index: i32, count: i32, parity: i32) -> RXingResult {
+    result(
+        text,
+        BarcodeFormat::QR_CODE,
+        &[
+            RXingResultMetadataValue::StructuredAppendSequence((index << 4) | (count - 1)),
+            RXingResultMetadataValue::StructuredAppendParity(parity),
+        ],
+    )
+}
+
+fn segment(text: &str, format: BarcodeFormat, index: i32, count: i32, id: &str) -> RXingResult {
+    result(
+        text,
+        format,
+        &[
+            RXingResultMetadataValue::StructuredAppendSequence(index),
+            RXingResultMetadataValue::StructuredAppendCount(count),
+            RXingResultMetadataValue::StructuredAppendId(id.to_owned()),
+        ],
+    )
+}
+
+fn pdf417(text: &str, index: usize, fileId: &str, lastSegment: bool) -> RXingResult {
+    let mut metadata = PDF417RXingResultMetadata::default();
+    metadata.setSegmentIndex(index);
+    metadata.setFileId(fileId.to_owned());
+    metadata.setLastSegment(lastSegment);
+    result(
+        text,
+        BarcodeFormat::PDF_417,
+        &[RXingResultMetadataValue::Pdf417ExtraMetadata(Rc::new(
+            metadata,
+        ))],
+    )
+}
+
+#[test]
+fn testQRCode() {
+    let mut reassembler = StructuredAppendReassembler::new();
+    assert!(!reassembler.add(RXingResult::new(
+        "not a segment",
+        Vec::new(),
+        Vec::new(),
+        BarcodeFormat::QR_CODE
+    )));
+    assert!(reassembler.getMessages().is_empty());
+
+    assert!(reassembler.add(qrCode("Hello, ", 0, 3, 0x42)));
+    assert!(reassembler.add(qrCode("!", 2, 3, 0x42)));
+    let message = &reassembler.getMessages()[0];
+    assert_eq!(&BarcodeFormat::QR_CODE, message.getBarcodeFormat());
+    assert_eq!("66", message.getFileId());
+    assert_eq!(Some(3), message.getSegmentCount());
+    assert_eq!(vec![1], message.getMissingSegments());
+    assert!(!message.isComplete());
+    assert_eq!(None, message.getText());
+    assert!(reassembler.takeCompleteMessages().is_empty());
+
+    // segments read again from another frame don't count twice
+    assert!(reassembler.add(qrCode("Hello, ", 0, 3, 0x42)));
+    assert!(reassembler.add(qrCode("World", 1, 3, 0x42)));
+    let message = &reassembler.getMessages()[0];
+    assert!(message.isComplete());
+    assert_eq!(3, message.getSegments().len());
+    assert_eq!(Some("Hello, World!".to_owned()), message.getText());
+    assert_eq!(Some(b"Hello, World!".to_vec()), message.getBytes());
+
+    let complete = reassembler.takeCompleteMessages();
+    assert_eq!(1, complete.len());
+    assert!(reassembler.getMessages().is_empty());
+    let merged = complete[0].getRXingResult().expect("complete");
+    assert_eq!("Hello, World!", merged.getText());
+    assert_eq!(&BarcodeFormat::QR_CODE, merged.getBarcodeFormat());
+}
+
+#[test]
+fn testGroupsByFormatAndFileId() {
+    let mut reassembler = StructuredAppendReassembler::new();
+    let others = reassembler.addAll([
+        segment("b", BarcodeFormat::DATA_MATRIX, 1, 2, "258"),
+        segment("2", BarcodeFormat::DATA_MATRIX, 1, 2, "259"),
+        RXingResult::new("other", Vec::new(), Vec::new(), BarcodeFormat::DATA_MATRIX),
+        segment("B", BarcodeFormat::AZTEC, 1, 2, "258"),
+        segment("a", BarcodeFormat::DATA_MATRIX, 0, 2, "258"),
+        segment("A", BarcodeFormat::AZTEC, 0, 2, "258"),
+    ]);
+    assert_eq!(1, others.len());
+    assert_eq!("other", others[0].getText());
+
+    let messages = reassembler.getMessages();
+    assert_eq!(3, messages.len());
+    assert_eq!(Some("ab".to_owned()), messages[0].getText());
+    assert_eq!(vec![0], messages[1].getMissingSegments());
+    assert_eq!(&BarcodeFormat::AZTEC, messages[2].getBarcodeFormat());
+    assert_eq!(Some("AB".to_owned()), messages[2].getText());
+
+    let complete = reassembler.takeCompleteMessages();
+    assert_eq!(2, complete.len());
+    assert_eq!(1, reassembler.getMessages().len());
+    assert_eq!("259", reassembler.getMessages()[0].getFileId());
+}
+
+#[test]
+fn testUnknownCount() {
+    let mut reassembler = StructuredAppendReassembler::new();
+
+    // a count of 0 means the symbol doesn't tell
+    reassembler.add(segment("1", BarcodeFormat::AZTEC, 0, 0, ""));
+    reassembler.add(segment("3", BarcodeFormat::AZTEC, 2, 0, ""));
+    let message = &reassembler.getMessages()[0];
+    assert_eq!(None, message.getSegmentCount());
+    assert_eq!(vec![1], message.getMissingSegments());
+    reassembler.add(segment("2", BarcodeFormat::AZTEC, 1, 0, ""));
+    assert!(reassembler.getMessages()[0].getMissingSegments().is_empty());
+    assert!(!reassembler.getMessages()[0].isComplete());
+
+    // the last PDF417 segment tells the count
+    reassembler.add(pdf417("Macro ", 0, "000001", false));
+    reassembler.add(pdf417("PDF417", 2, "000001", true));
+    let message = &reassembler.getMessages()[1];
+    assert_eq!(Some(3), message.getSegmentCount());
+    assert_eq!(vec![1], message.getMissingSegments());
+    reassembler.add(pdf417("in ", 1, "000001", false));
+    let complete = reassembler.takeCompleteMessages();
+    assert_eq!(1, complete.len());
+    assert_eq!(Some("Macro in PDF417".to_owned()), complete[0].getText());
+}
+
+#[test]
+fn testDecodedPDF417() {
+    let segments = ["Structured ", "append ", "across formats"];
+    let mut reassembler = StructuredAppendReassembler::new();
+    for format in [BarcodeFormat::PDF_417, BarcodeFormat::MICRO_PDF_417] {
+        // add the segments in reverse
+        for (index, contents) in segments.iter().enumerate().rev() {
+            let mut macroPDF417 = MacroPDF417::new(index as u32, "000042");
+            macroPDF417.setSegmentCount(Some(segments.len() as u32));
+            let hints = HashMap::from([(
+                EncodeHintType::PDF417_MACRO,
+                EncodeHintValue::Pdf417Macro(macroPDF417),
+            )]);
+            let matrix = MultiFormatWriter
+                .encode_with_hints(contents, &format, 0, 0, &hints)
+                .expect("encode");
+
+            let luma = (0..matrix.getHeight() * 2)
+                .flat_map(|y| (0..matrix.getWidth() * 2).map(move |x| (x, y)))
+                .map(|(x, y)| if matrix.get(x / 2, y / 2) { 0 } else { u8::MAX })
+                .collect();
+            let mut image = BinaryBitmap::new(HybridBinarizer::new(Luma8LuminanceSource::new(
+                luma,
+                matrix.getWidth() * 2,
+                matrix.getHeight() * 2,
+            )));
+            let hints = HashMap::from([(
+                DecodeHintType::POSSIBLE_FORMATS,
+                DecodeHintValue::PossibleFormats(HashSet::from([format])),
+            )]);
+            let result = MultiFormatReader::default()
+                .decode_with_hints(&mut image, &hints)
+                .expect("decode");
+            assert!(reassembler.add(result));
+        }
+    }
+
+    let complete = reassembler.takeCompleteMessages();
+    assert_eq!(2, complete.len());
+    assert_eq!(&BarcodeFormat::PDF_417, complete[0].getBarcodeFormat());
+    assert_eq!(
+        &BarcodeFormat::MICRO_PDF_417,
+        complete[1].getBarcodeFormat()
+    );
+    for message in complete {
+        assert_eq!("000042", message.getFileId());
+        assert_eq!(Some(segments.concat()), message.getText());
+    }
+}
+
+/**
+ * Decodes a symbol with a quiet zone of 4 modules, scaled by 3.
+ */
+fn decode(matrix: &BitMatrix, format: BarcodeFormat) -> RXingResult {
+    let (width, height) = ((matrix.getWidth() + 8) * 3, (matrix.getHeight() + 8) * 3);
+    let luma = (0..height)
+        .flat_map(|y| (0..width).map(move |x| (x / 3, y / 3)))
+        .map(|(x, y)| {
+            let set = (4..matrix.getWidth() + 4).contains(&x)
+                && (4..matrix.getHeight() + 4).contains(&y)
+                && matrix.get(x - 4, y - 4);
+            if set {
+                0
+            } else {
+                u8::MAX
+            }
+        })
+        .collect();
+    let mut image = BinaryBitmap::new(HybridBinarizer::new(Luma8LuminanceSource::new(
+        luma, width, height,
+    )));
+    let hints = HashMap::from([(
+        DecodeHintType::POSSIBLE_FORMATS,
+        DecodeHintValue::PossibleFormats(HashSet::from([format])),
+    )]);
+    MultiFormatReader::default()
+        .decode_with_hints(&mut image, &hints)
+        .expect("decode")
+}
+
+fn reassemble(symbols: &[BitMatrix], format: BarcodeFormat) -> super::StructuredAppendMessage {
+    let mut reassembler = StructuredAppendReassembler::new();
+    // read the symbols in reverse
+    for symbol in symbols.iter().rev() {
+        assert!(reassembler.add(decode(symbol, format)));
+    }
+    let mut complete = reassembler.takeCompleteMessages();
+    assert_eq!(1, complete.len());
+    complete.remove(0)
+}
+
+const MESSAGE: &str = "Structured append splits a message that is too long for one symbol \
+                       across up to 16 symbols, or 26 for Aztec.";
+
+#[test]
+fn testEncodedQRCode() {
+    let writer = QRCodeWriter;
+    let mut hints: EncodingHintDictionary = HashMap::from([(
+        EncodeHintType::QR_VERSION,
+        EncodeHintValue::QrVersion("2".to_owned()),
+    )]);
+    let symbols = writer
+        .encode_structured_append(MESSAGE, 0, 0, &hints)
+        .expect("encode");
+    assert!(symbols.len() > 1);
+    let message = reassemble(&symbols, BarcodeFormat::QR_CODE);
+    assert_eq!(Some(symbols.len()), message.getSegmentCount());
+    let parity = MESSAGE.bytes().fold(0, |parity, b| parity ^ b);
+    assert_eq!(parity.to_string(), message.getFileId());
+    assert_eq!(Some(MESSAGE.to_owned()), message.getText());
+    let result = message.getRXingResult().expect("complete");
+    assert_eq!(MESSAGE.as_bytes(), result.getRawBytes());
+
+    // text beyond ASCII comes back as the same bytes
+    let contents = "Grüße aus Köln, über 20 °C und Sonnenschein — структурированное добавление";
+    let symbols = writer
+        .encode_structured_append(contents, 0, 0, &hints)
+        .expect("encode");
+    assert!(symbols.len() > 1);
+    let message = reassemble(&symbols, BarcodeFormat::QR_CODE);
+    assert_eq!(Some(contents.as_bytes().to_vec()), message.getBytes());
+    // every symbol keeps to the encoding the parity was taken over, the raw bytes of the
+    // results being the bytes read
+    let rawBytes = message
+        .getSegments()
+        .iter()
+        .flat_map(|segment| segment.getRawBytes().clone())
+        .collect::<Vec<_>>();
+    assert_eq!(contents.as_bytes(), rawBytes);
+
+    // too few symbols allowed
+    hints.insert(
+        EncodeHintType::STRUCTURED_APPEND_MAX_SYMBOLS,
+        EncodeHintValue::StructuredAppendMaxSymbols(symbols.len() as u32 - 1),
+    );
+    assert!(writer
+        .encode_structured_append(MESSAGE, 0, 0, &hints)
+        .is_err());
+
+    // short messages need no header
+    let symbols = writer
+        .encode_structured_append("short", 0, 0, &hints)
+        .expect("encode");
+    assert_eq!(1, symbols.len());
+    let result = decode(&symbols[0], BarcodeFormat::QR_CODE);
+    assert_eq!("short", result.getText());
+    let metadata = result.getRXingResultMetadata();
+    assert_eq!(
+        Some(&RXingResultMetadataValue::StructuredAppendSequence(-1)),
+        metadata.get(&RXingResultMetadataType::STRUCTURED_APPEND_SEQUENCE)
+    );
+    assert_eq!(
+        Some(&RXingResultMetadataValue::StructuredAppendParity(-1)),
+        metadata.get(&RXingResultMetadataType::STRUCTURED_APPEND_PARITY)
+    );
+    assert!(!metadata.contains_key(&RXingResultMetadataType::STRUCTURED_APPEND_COUNT));
+    assert!(!metadata.contains_key(&RXingResultMetadataType::STRUCTURED_APPEND_ID));
+}
+
+#[test]
+fn testEncodedQRCodeMetadata() {
+    let hints: EncodingHintDictionary = HashMap::from([(
+        EncodeHintType::QR_VERSION,
+        EncodeHintValue::QrVersion("2".to_owned()),
+    )]);
+    let symbols = QRCodeWriter
+        .encode_structured_append(MESSAGE, 0, 0, &hints)
+        .expect("encode");
+    let count = symbols.len() as i32;
+    let parity = MESSAGE.bytes().fold(0, |parity, b| parity ^ b);
+    let result = decode(&symbols[1], BarcodeFormat::QR_CODE);
+    let metadata = result.getRXingResultMetadata();
+
+    // the keys results carried before, with their values unchanged
+    assert_eq!(
+        Some(&RXingResultMetadataValue::StructuredAppendSequence(1)),
+        metadata.get(&RXingResultMetadataType::STRUCTURED_APPEND_SEQUENCE)
+    );
+    assert_eq!(
+        Some(&RXingResultMetadataValue::StructuredAppendParity(count)),
+        metadata.get(&RXingResultMetadataType::STRUCTURED_APPEND_PARITY)
+    );
+    // and the ones added for reassembly
+    assert_eq!(
+        Some(&RXingResultMetadataValue::StructuredAppendCount(count)),
+        metadata.get(&RXingResultMetadataType::STRUCTURED_APPEND_COUNT)
+    );
+    assert_eq!(
+        Some(&RXingResultMetadataValue::StructuredAppendId(
+            parity.to_string()
+        )),
+        metadata.get(&RXingResultMetadataType::STRUCTURED_APPEND_ID)
+    );
+}
+
+#[test]
+fn testEncodedDataMatrix() {
+    let writer = DataMatrixWriter;
+    let mut hints: EncodingHintDictionary = HashMap::from([
+        (
+            EncodeHintType::DATA_MATRIX_SHAPE,
+            EncodeHintValue::DataMatrixShape(SymbolShapeHint::FORCE_SQUARE),
+        ),
+        (
+            EncodeHintType::MAX_SIZE,
+            EncodeHintValue::MaxSize(Dimension::new(20, 20)),
+        ),
+    ]);
+    let symbols = writer
+        .encode_structured_append(MESSAGE, 0, 0, &hints)
+        .expect("encode");
+    assert!(symbols.len() > 1);
+    assert!(symbols.iter().all(|symbol| symbol.getWidth() <= 20));
+    let message = reassemble(&symbols, BarcodeFormat::DATA_MATRIX);
+    assert_eq!(Some(symbols.len()), message.getSegmentCount());
+    assert_eq!(Some(MESSAGE.to_owned()), message.getText());
+    let result = message.getRXingResult().expect("complete");
+    assert_eq!(MESSAGE.as_bytes(), result.getRawBytes());
+
+    hints.insert(
+        EncodeHintType::STRUCTURED_APPEND_ID,
+        EncodeHintValue::StructuredAppendId("258".to_owned()),
+    );
+    let symbols = writer
+        .encode_structured_append(MESSAGE, 0, 0, &hints)
+        .expect("encode");
+    let message = reassemble(&symbols, BarcodeFormat::DATA_MATRIX);
+    assert_eq!("258", message.getFileId());
+    assert_eq!(Some(MESSAGE.to_owned()), message.getText());
+
+    // both bytes of the file id must be 1 to 254
+    hints.insert(
+        EncodeHintType::STRUCTURED_APPEND_ID,
+        EncodeHintValue::StructuredAppendId("255".to_owned()),
+    );
+    assert!(writer
+        .encode_structured_append(MESSAGE, 0, 0, &hints)
+        .is_err());
+}
+
+#[test]
+fn testEncodedAztec() {
+    let writer = AztecWriter;
+    let mut hints: EncodingHintDictionary = HashMap::from([
+        (
+            EncodeHintType::AZTEC_LAYERS,
+            EncodeHintValue::AztecLayers(-2),
+        ),
+        (
+            EncodeHintType::STRUCTURED_APPEND_ID,
+            EncodeHintValue::StructuredAppendId("MSG".to_owned()),
+        ),
+    ]);
+    let symbols = writer
+        .encode_structured_append(MESSAGE, 0, 0, &hints)
+        .expect("encode");
+    assert!(symbols.len() > 1);
+    let message = reassemble(&symbols, BarcodeFormat::AZTEC);
+    assert_eq!("MSG", message.getFileId());
+    assert_eq!(Some(symbols.len()), message.getSegmentCount());
+    assert_eq!(Some(MESSAGE.to_owned()), message.getText());
+    let result = message.getRXingResult().expect("complete");
+    assert_eq!(MESSAGE.as_bytes(), result.getRawBytes());
+
+    hints.insert(
+        EncodeHintType::STRUCTURED_APPEND_ID,
+        EncodeHintValue::StructuredAppendId("lower".to_owned()),
+    );
+    assert!(writer
+        .encode_structured_append(MESSAGE, 0, 0, &hints)
+        .is_err());
+}
+
+#[test]
+fn testBinaryPayload() {
+    // Shift_JIS bytes read as Japanese text, followed by binary data read as ISO-8859-1, the
+    // raw bytes being codewords
+    let binary = |text: &str, index: i32, byteSegments: Vec<Vec<u8>>| {
+        let mut binary = RXingResult::new(text, vec![0x31, 0x40], Vec::new(), BarcodeFormat::AZTEC);
+        binary.putAllMetadata(
+            segment("", BarcodeFormat::AZTEC, index, 2, "1")
+                .getRXingResultMetadata()
+                .clone(),
+        );
+        if !byteSegments.is_empty() {
+            binary.putMetadata(
+                RXingResultMetadataType::BYTE_SEGMENTS,
+                RXingResultMetadataValue::ByteSegments(byteSegments),
+            );
+        }
+        binary
+    };
+    let mut reassembler = StructuredAppendReassembler::new();
+    reassembler.add(binary("グ", 0, vec![vec![0x83, 0x4F]]));
+    reassembler.add(binary("\u{FF}\u{00}\u{80}", 1, Vec::new()));
+    let message = &reassembler.getMessages()[0];
+    assert_eq!(Some(vec![0x83, 0x4F, 0xFF, 0x00, 0x80]), message.getBytes());
+
+    // bytes that are no UTF-8 come back as they were written
+    let payload = (0..200u32)
+        .map(|i| (i * 37 % 256) as u8)
+        .collect::<Vec<_>>();
+    assert!(String::from_utf8(payload.clone()).is_err());
+    let contents = payload.iter().map(|b| *b as char).collect::<String>();
+
+    let hints: EncodingHintDictionary = HashMap::from([(
+        EncodeHintType::QR_VERSION,
+        EncodeHintValue::QrVersion("3".to_owned()),
+    )]);
+    let symbols = QRCodeWriter
+        .encode_structured_append(&contents, 0, 0, &hints)
+        .expect("encode");
+    assert!(symbols.len() > 1);
+    let message = reassemble(&symbols, BarcodeFormat::QR_CODE);
+    assert_eq!(Some(payload.clone()), message.getBytes());
+    let result = message.getRXingResult().expect("complete");
+    assert_eq!(&payload, result.getRawBytes());
+
+    let hints: EncodingHintDictionary = HashMap::from([(
+        EncodeHintType::AZTEC_LAYERS,
+        EncodeHintValue::AztecLayers(-3),
+    )]);
+    let symbols = AztecWriter
+        .encode_structured_append(&contents, 0, 0, &hints)
+        .expect("encode");
+    assert!(symbols.len() > 1);
+    let message = reassemble(&symbols, BarcodeFormat::AZTEC);
+    assert_eq!(Some(payload), message.getBytes());
+}
+
+#[test]
+fn testMixedModePayload() {
+    // numeric and byte mode in one symbol, the byte segments holding only part of the text
+    let mixed = |text: &str, index: i32, byteSegments: Vec<Vec<u8>>| {
+        let mut mixed =
+            RXingResult::new(text, vec![0x10, 0x20], Vec::new(), BarcodeFormat::QR_CODE);
+        mixed.putAllMetadata(qrCode("", index, 2, 7).getRXingResultMetadata().clone());
+        mixed.putMetadata(
+            RXingResultMetadataType::BYTE_SEGMENTS,
+            RXingResultMetadataValue::ByteSegments(byteSegments),
+        );
+        mixed
+    };
+    let mut reassembler = StructuredAppendReassembler::new();
+    reassembler.add(mixed("0123456789\u{E4}", 0, vec![vec![0xE4]]));
+    reassembler.add(mixed(
+        "\u{3000}42グ",
+        1,
+        vec![vec![0x81, 0x40], vec![0x83, 0x4F]],
+    ));
+    let message = &reassembler.getMessages()[0];
+    assert_eq!(
+        Some(b"0123456789\xE4\x81\x40\x34\x32\x83\x4F".to_vec()),
+        message.getBytes()
+    );
+
+    // a symbol read with numeric, alphanumeric and byte mode
+    let contents = "0123456789012345678901234567890123456789 CAF\u{C9} caf\u{E9}";
+    let hints: EncodingHintDictionary = HashMap::from([(
+        EncodeHintType::QR_COMPACT,
+        EncodeHintValue::QrCompact("true".to_owned()),
+    )]);
+    let symbol = QRCodeWriter
+        .encode_with_hints(contents, &BarcodeFormat::QR_CODE, 0, 0, &hints)
+        .expect("encode");
+    let mut decoded = decode(&symbol, BarcodeFormat::QR_CODE);
+    assert_eq!(contents, decoded.getText());
+    decoded.putAllMetadata(qrCode("", 1, 2, 7).getRXingResultMetadata().clone());
+    let mut reassembler = StructuredAppendReassembler::new();
+    reassembler.add(qrCode("head ", 0, 2, 7));
+    reassembler.add(decoded);
+    let message = &reassembler.getMessages()[0];
+    let mut payload = b"head ".to_vec();
+    payload.extend(contents.chars().map(|c| c as u8));
+    assert_eq!(Some(payload), message.getBytes());
+}
@@ -19,10 +19,17 @@ use std::collections::{HashMap, HashSet};
 use crate::common::Result;
 use crate::qrcode::cpp_port::QrReader;
 use crate::{
-    aztec::AztecReader, datamatrix::DataMatrixReader, maxicode::MaxiCodeReader,
-    oned::MultiFormatOneDReader, pdf417::PDF417Reader, qrcode::QRCodeReader, BarcodeFormat,
-    Binarizer, BinaryBitmap, DecodeHintType, DecodeHintValue, DecodingHintDictionary, Exceptions,
-    RXingResult, Reader,
+    aztec::AztecReader,
+    datamatrix::DataMatrixReader,
+    dotcode::DotCodeReader,
+    hanxin::HanXinReader,
+    maxicode::MaxiCodeReader,
+    oned::{CodablockFReader, Code16KReader, MultiFormatOneDReader},
+    pdf417::{MicroPDF417Reader, PDF417Reader},
+    postal::{FourStateReader, TwoStateReader},
+    qrcode::QRCodeReader,
+    BarcodeFormat, Binarizer, BinaryBitmap, DecodeHintType, DecodeHintValue,
+    DecodingHintDictionary, Exceptions, RXingResult, Reader,
 };
 
 /**
@@ -161,11 +168,48 @@ impl MultiFormatReader {
                 || self.possible_formats.contains(&BarcodeFormat::EAN_8)
                 || self.possible_formats.contains(&BarcodeFormat::CODABAR)
                 || self.possible_formats.contains(&BarcodeFormat::CODE_39)
+                || self.possible_formats.contains(&BarcodeFormat::CODE_32)
                 || self.possible_formats.contains(&BarcodeFormat::CODE_93)
+                || self.possible_formats.contains(&BarcodeFormat::CODE_11)
                 || self.possible_formats.contains(&BarcodeFormat::CODE_128)
                 || self.possible_formats.contains(&BarcodeFormat::ITF)
+                || self
+                    .possible_formats
+                    .contains(&BarcodeFormat::INDUSTRIAL_2_OF_5)
+                || self.possible_formats.contains(&BarcodeFormat::IATA_2_OF_5)
+                || self
+                    .possible_formats
+                    .contains(&BarcodeFormat::MATRIX_2_OF_5)
+                || self
+                    .possible_formats
+                    .contains(&BarcodeFormat::DATALOGIC_2_OF_5)
+                || self.possible_formats.contains(&BarcodeFormat::MSI)
+                || self.possible_formats.contains(&BarcodeFormat::PHARMACODE)
+                || self.possible_formats.contains(&BarcodeFormat::TELEPEN)
                 || self.possible_formats.contains(&BarcodeFormat::RSS_14)
-                || self.possible_formats.contains(&BarcodeFormat::RSS_EXPANDED);
+                || self.possible_formats.contains(&BarcodeFormat::RSS_EXPANDED)
+                || self.possible_formats.contains(&BarcodeFormat::RSS_LIMITED);
+            let four_state = self
+                .possible_formats
+                .contains(&BarcodeFormat::AUSTRALIA_POST)
+                || self.possible_formats.contains(&BarcodeFormat::KIX)
+                || self.possible_formats.contains(&BarcodeFormat::RM4SCC)
+                || self
+                    .possible_formats
+                    .contains(&BarcodeFormat::USPS_INTELLIGENT_MAIL);
+            let two_state = self.possible_formats.contains(&BarcodeFormat::PLANET)
+                || self.possible_formats.contains(&BarcodeFormat::POSTNET);
+            // stacked formats come first, their rows would read as Code 128 otherwise
+            if self.possible_formats.contains(&BarcodeFormat::CODABLOCK_F) {
+                if let Ok(res) = CodablockFReader::default().decode_with_hints(image, &self.hints) {
+                    return Ok(res);
+                }
+            }
+            if self.possible_formats.contains(&BarcodeFormat::CODE_16K) {
+                if let Ok(res) = Code16KReader::default().decode_with_hints(image, &self.hints) {
+                    return Ok(res);
+                }
+            }
             if one_d && !self.try_harder {
                 if let Ok(res) = self.one_d_reader.decode_with_hints(image, &self.hints) {
                     return Ok(res);
@@ -181,21 +225,38 @@ impl MultiFormatReader {
                             QRCodeReader.decode_with_hints(image, &self.hints)
                         }
                     }
-                    BarcodeFormat::MICRO_QR_CODE => QrReader.decode_with_hints(image, &self.hints),
+                    BarcodeFormat::MICRO_QR_CODE | BarcodeFormat::RECTANGULAR_MICRO_QR_CODE => {
+                        QrReader.decode_with_hints(image, &self.hints)
+                    }
                     BarcodeFormat::DATA_MATRIX => {
                         DataMatrixReader.decode_with_hints(image, &self.hints)
                     }
                     BarcodeFormat::AZTEC => AztecReader.decode_with_hints(image, &self.hints),
                     BarcodeFormat::PDF_417 => PDF417Reader.decode_with_hints(image, &self.hints),
+                    BarcodeFormat::MICRO_PDF_417 => {
+                        MicroPDF417Reader.decode_with_hints(image, &self.hints)
+                    }
                     BarcodeFormat::MAXICODE => {
                         MaxiCodeReader::default().decode_with_hints(image, &self.hints)
                     }
+                    BarcodeFormat::HAN_XIN => HanXinReader.decode_with_hints(image, &self.hints),
+                    BarcodeFormat::DOT_CODE => DotCodeReader.decode_with_hints(image, &self.hints),
                     _ => Err(Exceptions::UNSUPPORTED_OPERATION),
                 };
                 if res.is_ok() {
                     return res;
                 }
             }
+            if four_state {
+                if let Ok(res) = FourStateReader.decode_with_hints(image, &self.hints) {
+                    return Ok(res);
+                }
+            }
+            if two_state {
+                if let Ok(res) = TwoStateReader.decode_with_hints(image, &self.hints) {
+                    return Ok(res);
+                }
+            }
             if one_d && self.try_harder {
                 if let Ok(res) = self.one_d_reader.decode_with_hints(image, &self.hints) {
                     return Ok(res);
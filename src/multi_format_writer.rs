@@ -20,11 +20,17 @@ use crate::{
     aztec::AztecWriter,
     common::Result,
     datamatrix::DataMatrixWriter,
+    dotcode::DotCodeWriter,
+    hanxin::HanXinWriter,
+    maxicode::MaxiCodeWriter,
     oned::{
-        CodaBarWriter, Code128Writer, Code39Writer, Code93Writer, EAN13Writer, EAN8Writer,
-        ITFWriter, UPCAWriter, UPCEWriter,
+        rss::{expanded::RSSExpandedWriter, RSS14Writer, RSSLimitedWriter},
+        CodaBarWriter, CodablockFWriter, Code11Writer, Code128Writer, Code16KWriter, Code32Writer,
+        Code39Writer, Code93Writer, EAN13Writer, EAN8Writer, ITFWriter, MSIWriter,
+        PharmacodeWriter, TelepenWriter, TwoOfFiveWriter, UPCAWriter, UPCEWriter,
     },
-    pdf417::PDF417Writer,
+    pdf417::{MicroPDF417Writer, PDF417Writer},
+    postal::{FourStateWriter, TwoStateWriter},
     qrcode::QRCodeWriter,
     BarcodeFormat, Exceptions, Writer,
 };
@@ -62,15 +68,40 @@ impl Writer for MultiFormatWriter {
             BarcodeFormat::UPC_E => Box::<UPCEWriter>::default(),
             BarcodeFormat::EAN_13 => Box::<EAN13Writer>::default(),
             BarcodeFormat::UPC_A => Box::<UPCAWriter>::default(),
-            BarcodeFormat::QR_CODE => Box::<QRCodeWriter>::default(),
+            BarcodeFormat::QR_CODE
+            | BarcodeFormat::MICRO_QR_CODE
+            | BarcodeFormat::RECTANGULAR_MICRO_QR_CODE => Box::<QRCodeWriter>::default(),
             BarcodeFormat::CODE_39 => Box::<Code39Writer>::default(),
+            BarcodeFormat::CODE_32 => Box::<Code32Writer>::default(),
             BarcodeFormat::CODE_93 => Box::<Code93Writer>::default(),
+            BarcodeFormat::CODE_11 => Box::<Code11Writer>::default(),
+            BarcodeFormat::CODE_16K => Box::<Code16KWriter>::default(),
             BarcodeFormat::CODE_128 => Box::<Code128Writer>::default(),
             BarcodeFormat::ITF => Box::<ITFWriter>::default(),
+            BarcodeFormat::INDUSTRIAL_2_OF_5
+            | BarcodeFormat::IATA_2_OF_5
+            | BarcodeFormat::MATRIX_2_OF_5
+            | BarcodeFormat::DATALOGIC_2_OF_5 => Box::new(TwoOfFiveWriter::new(*format)),
+            BarcodeFormat::MSI => Box::<MSIWriter>::default(),
+            BarcodeFormat::PHARMACODE => Box::<PharmacodeWriter>::default(),
+            BarcodeFormat::TELEPEN => Box::<TelepenWriter>::default(),
             BarcodeFormat::PDF_417 => Box::<PDF417Writer>::default(),
+            BarcodeFormat::MICRO_PDF_417 => Box::<MicroPDF417Writer>::default(),
             BarcodeFormat::CODABAR => Box::<CodaBarWriter>::default(),
+            BarcodeFormat::CODABLOCK_F => Box::<CodablockFWriter>::default(),
             BarcodeFormat::DATA_MATRIX => Box::<DataMatrixWriter>::default(),
             BarcodeFormat::AZTEC => Box::<AztecWriter>::default(),
+            BarcodeFormat::MAXICODE => Box::<MaxiCodeWriter>::default(),
+            BarcodeFormat::HAN_XIN => Box::<HanXinWriter>::default(),
+            BarcodeFormat::DOT_CODE => Box::<DotCodeWriter>::default(),
+            BarcodeFormat::RSS_14 => Box::<RSS14Writer>::default(),
+            BarcodeFormat::RSS_EXPANDED => Box::<RSSExpandedWriter>::default(),
+            BarcodeFormat::RSS_LIMITED => Box::<RSSLimitedWriter>::default(),
+            BarcodeFormat::AUSTRALIA_POST
+            | BarcodeFormat::KIX
+            | BarcodeFormat::RM4SCC
+            | BarcodeFormat::USPS_INTELLIGENT_MAIL => Box::<FourStateWriter>::default(),
+            BarcodeFormat::PLANET | BarcodeFormat::POSTNET => Box::<TwoStateWriter>::default(),
             _ => {
                 return Err(Exceptions::illegal_argument_with(format!(
                     "No encoder available for format {format:?}"
@@ -19,10 +19,17 @@ use std::collections::{HashMap, HashSet};
 use crate::common::Result;
 use crate::qrcode::cpp_port::QrReader;
 use crate::{
-    aztec::AztecReader, datamatrix::DataMatrixReader, maxicode::MaxiCodeReader,
-    oned::MultiFormatOneDReader, pdf417::PDF417Reader, qrcode::QRCodeReader, BarcodeFormat,
-    Binarizer, BinaryBitmap, DecodeHintType, DecodeHintValue, DecodingHintDictionary, Exceptions,
-    RXingResult, Reader,
+    aztec::AztecReader,
+    datamatrix::DataMatrixReader,
+    dotcode::DotCodeReader,
+    hanxin::HanXinReader,
+    maxicode::MaxiCodeReader,
+    oned::{CodablockFReader, Code16KReader, MultiFormatOneDReader},
+    pdf417::{MicroPDF417Reader, PDF417Reader},
+    postal::{FourStateReader, TwoStateReader},
+    qrcode::QRCodeReader,
+    BarcodeFormat, Binarizer, BinaryBitmap, DecodeHintType, DecodeHintValue,
+    DecodingHintDictionary, Exceptions, RXingResult, Reader,
 };
 
 /**
@@ -39,12 +46,19 @@ pub struct MultiUseMultiFormatReader {
     possible_formats: HashSet<BarcodeFormat>,
     try_harder: bool,
     one_d_reader: MultiFormatOneDReader,
+    codablock_f_reader: CodablockFReader,
+    code_16k_reader: Code16KReader,
     qr_code_reader: QRCodeReader,
     data_matrix_reader: DataMatrixReader,
     aztec_reader: AztecReader,
     pdf417_reader: PDF417Reader,
+    micro_pdf417_reader: MicroPDF417Reader,
     maxicode_reader: MaxiCodeReader,
+    han_xin_reader: HanXinReader,
+    dot_code_reader: DotCodeReader,
     cpp_qrcode_reader: QrReader,
+    four_state_reader: FourStateReader,
+    two_state_reader: TwoStateReader,
 }
 
 impl Reader for MultiUseMultiFormatReader {
@@ -81,12 +95,19 @@ impl Reader for MultiUseMultiFormatReader {
 
     fn reset(&mut self) {
         self.one_d_reader.reset();
+        self.codablock_f_reader.reset();
+        self.code_16k_reader.reset();
         self.qr_code_reader.reset();
         self.data_matrix_reader.reset();
         self.aztec_reader.reset();
         self.pdf417_reader.reset();
+        self.micro_pdf417_reader.reset();
         self.maxicode_reader.reset();
+        self.han_xin_reader.reset();
+        self.dot_code_reader.reset();
         self.cpp_qrcode_reader.reset();
+        self.four_state_reader.reset();
+        self.two_state_reader.reset();
     }
 }
 
@@ -172,11 +193,51 @@ impl MultiUseMultiFormatReader {
                 || self.possible_formats.contains(&BarcodeFormat::EAN_8)
                 || self.possible_formats.contains(&BarcodeFormat::CODABAR)
                 || self.possible_formats.contains(&BarcodeFormat::CODE_39)
+                || self.possible_formats.contains(&BarcodeFormat::CODE_32)
                 || self.possible_formats.contains(&BarcodeFormat::CODE_93)
+                || self.possible_formats.contains(&BarcodeFormat::CODE_11)
                 || self.possible_formats.contains(&BarcodeFormat::CODE_128)
                 || self.possible_formats.contains(&BarcodeFormat::ITF)
+                || self
+                    .possible_formats
+                    .contains(&BarcodeFormat::INDUSTRIAL_2_OF_5)
+                || self.possible_formats.contains(&BarcodeFormat::IATA_2_OF_5)
+                || self
+                    .possible_formats
+                    .contains(&BarcodeFormat::MATRIX_2_OF_5)
+                || self
+                    .possible_formats
+                    .contains(&BarcodeFormat::DATALOGIC_2_OF_5)
+                || self.possible_formats.contains(&BarcodeFormat::MSI)
+                || self.possible_formats.contains(&BarcodeFormat::PHARMACODE)
+                || self.possible_formats.contains(&BarcodeFormat::TELEPEN)
                 || self.possible_formats.contains(&BarcodeFormat::RSS_14)
-                || self.possible_formats.contains(&BarcodeFormat::RSS_EXPANDED);
+                || self.possible_formats.contains(&BarcodeFormat::RSS_EXPANDED)
+                || self.possible_formats.contains(&BarcodeFormat::RSS_LIMITED);
+            let four_state = self
+                .possible_formats
+                .contains(&BarcodeFormat::AUSTRALIA_POST)
+                || self.possible_formats.contains(&BarcodeFormat::KIX)
+                || self.possible_formats.contains(&BarcodeFormat::RM4SCC)
+                || self
+                    .possible_formats
+                    .contains(&BarcodeFormat::USPS_INTELLIGENT_MAIL);
+            let two_state = self.possible_formats.contains(&BarcodeFormat::PLANET)
+                || self.possible_formats.contains(&BarcodeFormat::POSTNET);
+            // stacked formats come first, their rows would read as Code 128 otherwise
+            if self.possible_formats.contains(&BarcodeFormat::CODABLOCK_F) {
+                if let Ok(res) = self
+                    .codablock_f_reader
+                    .decode_with_hints(image, &self.hints)
+                {
+                    return Ok(res);
+                }
+            }
+            if self.possible_formats.contains(&BarcodeFormat::CODE_16K) {
+                if let Ok(res) = self.code_16k_reader.decode_with_hints(image, &self.hints) {
+                    return Ok(res);
+                }
+            }
             if one_d && !self.try_harder {
                 if let Ok(res) = self.one_d_reader.decode_with_hints(image, &self.hints) {
                     return Ok(res);
@@ -192,7 +253,7 @@ impl MultiUseMultiFormatReader {
                             self.qr_code_reader.decode_with_hints(image, &self.hints)
                         }
                     }
-                    BarcodeFormat::MICRO_QR_CODE => {
+                    BarcodeFormat::MICRO_QR_CODE | BarcodeFormat::RECTANGULAR_MICRO_QR_CODE => {
                         self.cpp_qrcode_reader.decode_with_hints(image, &self.hints)
                     }
                     BarcodeFormat::DATA_MATRIX => self
@@ -202,15 +263,34 @@ impl MultiUseMultiFormatReader {
                     BarcodeFormat::PDF_417 => {
                         self.pdf417_reader.decode_with_hints(image, &self.hints)
                     }
+                    BarcodeFormat::MICRO_PDF_417 => self
+                        .micro_pdf417_reader
+                        .decode_with_hints(image, &self.hints),
                     BarcodeFormat::MAXICODE => {
                         self.maxicode_reader.decode_with_hints(image, &self.hints)
                     }
+                    BarcodeFormat::HAN_XIN => {
+                        self.han_xin_reader.decode_with_hints(image, &self.hints)
+                    }
+                    BarcodeFormat::DOT_CODE => {
+                        self.dot_code_reader.decode_with_hints(image, &self.hints)
+                    }
                     _ => Err(Exceptions::UNSUPPORTED_OPERATION),
                 };
                 if res.is_ok() {
                     return res;
                 }
             }
+            if four_state {
+                if let Ok(res) = self.four_state_reader.decode_with_hints(image, &self.hints) {
+                    return Ok(res);
+                }
+            }
+            if two_state {
+                if let Ok(res) = self.two_state_reader.decode_with_hints(image, &self.hints) {
+                    return Ok(res);
+                }
+            }
             if one_d && self.try_harder {
                 if let Ok(res) = self.one_d_reader.decode_with_hints(image, &self.hints) {
                     return Ok(res);
@@ -0,0 +1,174 @@
+use std::collections::HashMap;
+
+use crate::{
+    common::{BitArray, Result},
+    BarcodeFormat, Binarizer, BinaryBitmap, DecodingHintDictionary, Exceptions, RXingResult,
+    RXingResultMetadataType, RXingResultMetadataValue, Reader,
+};
+
+use super::{
+    stacked_code_128_support::{getStackedResultPoints, CodeSetDecoder, StackedRow},
+    Code128Reader, OneDReader,
+};
+
+pub(super) const CODE_START_A: u8 = 103;
+pub(super) const CODE_STOP: u8 = 106;
+
+/// A symbol has 2 to 44 rows of 4 to 62 data characters each
+pub(super) const MIN_ROWS: u32 = 2;
+pub(super) const MAX_ROWS: u32 = 44;
+pub(super) const MIN_COLUMNS: u32 = 4;
+pub(super) const MAX_COLUMNS: u32 = 62;
+
+/// The row indicator of the first row is the number of rows less 2, the one of every other row
+/// its index plus 42
+pub(super) const FIRST_ROW_INDICATOR_OFFSET: u32 = MIN_ROWS;
+pub(super) const ROW_INDICATOR_OFFSET: u32 = MAX_ROWS - MIN_ROWS;
+
+/// The check characters of the whole text are modulo 86
+const CHECK_CHARACTER_MODULUS: u32 = 86;
+
+/**
+ * <p>Decodes Codablock F barcodes: rows of Code 128 characters stacked on top of each other. Each
+ * row starts with Start Code A, a row indicator and the code set its data starts in, and ends
+ * with its own check character. The last row also holds two check characters of the whole
+ * text.</p>
+ *
+ * <p>The rows are kept across calls to {@link #decode_row}, which only returns a result once it
+ * has found every row of a symbol. Codablock F is only read when asked for with
+ * {@link DecodeHintType#POSSIBLE_FORMATS}, as its rows would read as Code 128 otherwise.</p>
+ */
+#[derive(Default)]
+pub struct CodablockFReader {
+    // The rows found so far, by their index in the symbol
+    rows: HashMap<u32, StackedRow>,
+}
+
+impl OneDReader for CodablockFReader {
+    fn decode_row(
+        &mut self,
+        rowNumber: u32,
+        row: &BitArray,
+        _hints: &DecodingHintDictionary,
+    ) -> Result<RXingResult> {
+        let (codewords, left, right) = Code128Reader.decodeCodewords(row)?;
+        if codewords[0] != CODE_START_A || codewords.len() < 3 + MIN_COLUMNS as usize {
+            return Err(Exceptions::NOT_FOUND);
+        }
+        let indicator = codewords[1] as u32;
+        let index = if indicator < ROW_INDICATOR_OFFSET + 1 {
+            0
+        } else {
+            indicator - ROW_INDICATOR_OFFSET
+        };
+        if index >= MAX_ROWS {
+            return Err(Exceptions::FORMAT);
+        }
+        self.rows.insert(
+            index,
+            StackedRow {
+                codewords,
+                left,
+                right,
+                rowNumber,
+            },
+        );
+
+        let result = self.combineRows()?;
+        self.rows.clear();
+        Ok(result)
+    }
+}
+
+impl Reader for CodablockFReader {
+    fn decode<B: Binarizer>(&mut self, image: &mut BinaryBitmap<B>) -> Result<RXingResult> {
+        self.decode_with_hints(image, &HashMap::new())
+    }
+
+    fn decode_with_hints<B: Binarizer>(
+        &mut self,
+        image: &mut BinaryBitmap<B>,
+        hints: &DecodingHintDictionary,
+    ) -> Result<RXingResult> {
+        self.reset();
+        let result = self._do_decode_stacked(image, hints, false).or_else(|_| {
+            self.reset();
+            self._do_decode_stacked(image, hints, true)
+        });
+        self.reset();
+        result
+    }
+
+    fn reset(&mut self) {
+        self.rows.clear();
+    }
+}
+
+impl CodablockFReader {
+    /**
+     * @return the symbol, once the first row and all the rows it announces have been found, all
+     * of the same length
+     * @throws NotFoundException if rows are still missing
+     * @throws ChecksumException if the check characters of the text do not match
+     */
+    fn combineRows(&self) -> Result<RXingResult> {
+        let first = self.rows.get(&0).ok_or(Exceptions::NOT_FOUND)?;
+        let rowCount = first.codewords[1] as u32 + FIRST_ROW_INDICATOR_OFFSET;
+        let rows = (0..rowCount)
+            .map(|index| self.rows.get(&index))
+            .collect::<Option<Vec<_>>>()
+            .ok_or(Exceptions::NOT_FOUND)?;
+        let length = first.codewords.len();
+        if rows.iter().any(|row| row.codewords.len() != length) {
+            return Err(Exceptions::NOT_FOUND);
+        }
+
+        let mut decoder = CodeSetDecoder::new(0);
+        for (i, row) in rows.iter().enumerate() {
+            decoder.setCodeSet(row.codewords[2])?;
+            let end = if i + 1 == rows.len() {
+                length - 2
+            } else {
+                length
+            };
+            for code in &row.codewords[3..end] {
+                decoder.decode(*code)?;
+            }
+        }
+        let text = decoder.getText();
+        let last = rows[rows.len() - 1];
+        if getCheckCharacters(text) != (last.codewords[length - 2], last.codewords[length - 1]) {
+            return Err(Exceptions::CHECKSUM);
+        }
+
+        let mut resultObject = RXingResult::new(
+            text,
+            rows.iter()
+                .flat_map(|row| row.codewords.iter().copied())
+                .collect(),
+            getStackedResultPoints(first, last),
+            BarcodeFormat::CODABLOCK_F,
+        );
+        resultObject.putMetadata(
+            RXingResultMetadataType::SYMBOLOGY_IDENTIFIER,
+            RXingResultMetadataValue::SymbologyIdentifier(
+                if decoder.isFNC1First() { "]O5" } else { "]O4" }.to_owned(),
+            ),
+        );
+        Ok(resultObject)
+    }
+}
+
+/**
+ * @return the two check characters of the text, sums of its characters weighted by their
+ * position counted from one and from zero
+ */
+pub(super) fn getCheckCharacters(text: &str) -> (u8, u8) {
+    let (k1, k2) = text.chars().enumerate().fold((0, 0), |(k1, k2), (i, c)| {
+        (
+            (k1 + (i as u32 + 1) * c as u32) % CHECK_CHARACTER_MODULUS,
+            (k2 + i as u32 * c as u32) % CHECK_CHARACTER_MODULUS,
+        )
+    });
+    (k1 as u8, k2 as u8)
+}
@@ -0,0 +1,234 @@
+use std::collections::HashMap;
+
+use crate::{
+    common::{BitMatrix, Result},
+    BarcodeFormat, EncodeHintType, EncodeHintValue, Exceptions, Writer,
+};
+
+use super::{
+    codablock_f_reader::{
+        getCheckCharacters, CODE_START_A, CODE_STOP, FIRST_ROW_INDICATOR_OFFSET, MAX_COLUMNS,
+        MAX_ROWS, MIN_COLUMNS, ROW_INDICATOR_OFFSET,
+    },
+    code_128_reader::CODE_PATTERNS,
+    stacked_code_128_support::{getDecodedText, renderStackedRows, CodeSetEncoder},
+    OneDimensionalCodeWriter,
+};
+
+/// Every row is ten modules high
+const ROW_HEIGHT: u32 = 10;
+
+/**
+ * <p>This object renders a Codablock F as a {@link BitMatrix}: the text is encoded in Code 128
+ * code sets like {@link Code128Writer} does, and broken into rows of as many data characters each
+ * as it takes for the symbol to be about as high as it is wide.</p>
+ */
+#[derive(Default)]
+pub struct CodablockFWriter;
+
+impl Writer for CodablockFWriter {
+    fn encode(
+        &self,
+        contents: &str,
+        format: &BarcodeFormat,
+        width: i32,
+        height: i32,
+    ) -> Result<BitMatrix> {
+        self.encode_with_hints(contents, format, width, height, &HashMap::new())
+    }
+
+    fn encode_with_hints(
+        &self,
+        contents: &str,
+        format: &BarcodeFormat,
+        width: i32,
+        height: i32,
+        hints: &crate::EncodingHintDictionary,
+    ) -> Result<BitMatrix> {
+        if contents.is_empty() {
+            return Err(Exceptions::illegal_argument_with("Found empty contents"));
+        }
+
+        if format != &BarcodeFormat::CODABLOCK_F {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "Can only encode CODABLOCK_F, but got {format:?}"
+            )));
+        }
+
+        if width < 0 || height < 0 {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "Negative size is not allowed. Input: {width}x{height}"
+            )));
+        }
+
+        let mut sidesMargin = self.getDefaultMargin();
+        if let Some(EncodeHintValue::Margin(margin)) = hints.get(&EncodeHintType::MARGIN) {
+            sidesMargin = margin.parse::<u32>().map_err(|e| {
+                Exceptions::illegal_argument_with(format!("couldnt parse {margin}: {e}"))
+            })?;
+        }
+
+        let rows = Self::encodeRows(contents)?
+            .iter()
+            .map(|codewords| Self::encodeRow(codewords))
+            .collect::<Vec<_>>();
+        renderStackedRows(&rows, ROW_HEIGHT, width, height, sidesMargin)
+    }
+}
+
+impl OneDimensionalCodeWriter for CodablockFWriter {
+    /**
+     * @return the rows of the symbol one after the other
+     */
+    fn encode_oned(&self, contents: &str) -> Result<Vec<bool>> {
+        Ok(Self::encodeRows(contents)?
+            .iter()
+            .flat_map(|codewords| Self::encodeRow(codewords))
+            .collect())
+    }
+
+    fn getSupportedWriteFormats(&self) -> Option<Vec<BarcodeFormat>> {
+        Some(vec![BarcodeFormat::CODABLOCK_F])
+    }
+}
+
+impl CodablockFWriter {
+    /**
+     * <p>Tries ever more columns until the rows are no higher than they are wide.</p>
+     *
+     * @return the codewords of every row, from the start code up to the row check character
+     */
+    pub(super) fn encodeRows(contents: &str) -> Result<Vec<Vec<u8>>> {
+        for columns in MIN_COLUMNS..=MAX_COLUMNS {
+            let rows = Self::encodeWithColumns(contents, columns)?;
+            let rowCount = rows.len() as u32;
+            // a row is 11 modules per character wide, start, stop and three more characters
+            // included
+            if rowCount <= MAX_ROWS
+                && (rowCount * (ROW_HEIGHT + 1) <= 11 * (columns + 5) || columns == MAX_COLUMNS)
+            {
+                return Ok(rows);
+            }
+        }
+        Err(Exceptions::illegal_argument_with(format!(
+            "Contents too long for a Codablock F: {} characters",
+            contents.chars().count()
+        )))
+    }
+
+    /**
+     * <p>Every row starts with its row indicator and the code set its data starts in. The last
+     * row ends with the two check characters of the whole text, and every row with its own check
+     * character.</p>
+     */
+    fn encodeWithColumns(contents: &str, columns: u32) -> Result<Vec<Vec<u8>>> {
+        let mut encoder = CodeSetEncoder::new(contents)?;
+        let mut rows = Vec::new();
+        loop {
+            let mut data = Vec::with_capacity(columns as usize);
+            let codeSet = if encoder.isDone() {
+                encoder.pad()
+            } else {
+                encoder.startCodeSet()? as u8
+            };
+            while data.len() < columns as usize {
+                match encoder.next()? {
+                    Some(codeword) => data.push(codeword),
+                    None => break,
+                }
+            }
+            // the check characters need two columns of the last row, which is never the first
+            let isLast = encoder.isDone() && !rows.is_empty() && data.len() + 2 <= columns as usize;
+            let dataColumns = if isLast { columns - 2 } else { columns };
+            while data.len() < dataColumns as usize {
+                data.push(encoder.pad());
+            }
+            rows.push((codeSet, data));
+            if isLast {
+                break;
+            }
+            if rows.len() as u32 > MAX_ROWS {
+                // too long for this number of columns
+                break;
+            }
+        }
+
+        let (k1, k2) = getCheckCharacters(&getDecodedText(contents));
+        if let Some((_, data)) = rows.last_mut() {
+            data.extend([k1, k2]);
+        }
+
+        let rowCount = rows.len() as u32;
+        Ok(rows
+            .into_iter()
+            .enumerate()
+            .map(|(i, (codeSet, data))| {
+                let indicator = if i == 0 {
+                    rowCount.saturating_sub(FIRST_ROW_INDICATOR_OFFSET)
+                } else {
+                    i as u32 + ROW_INDICATOR_OFFSET
+                };
+                let mut codewords = vec![CODE_START_A, indicator as u8, codeSet];
+                codewords.extend(data);
+                let checkSum = codewords
+                    .iter()
+                    .enumerate()
+                    .map(|(i, codeword)| i.max(1) * *codeword as usize)
+                    .sum::<usize>();
+                codewords.push((checkSum % 103) as u8);
+                codewords
+            })
+            .collect())
+    }
+
+    fn encodeRow(codewords: &[u8]) -> Vec<bool> {
+        let patterns = codewords
+            .iter()
+            .map(|codeword| &CODE_PATTERNS[*codeword as usize])
+            .chain([&CODE_PATTERNS[CODE_STOP as usize]])
+            .collect::<Vec<_>>();
+        let mut result = vec![false; patterns.iter().flat_map(|p| p.iter()).sum::<u32>() as usize];
+        let mut pos = 0;
+        for pattern in patterns {
+            pos += Self::appendPattern(&mut result, pos, pattern, true) as usize;
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod CodablockFWriterTestCase {
+    use crate::{
+        oned::{codablock_f_reader::CODE_START_A, CodablockFWriter},
+        BarcodeFormat, Writer,
+    };
+
+    #[test]
+    fn testEncodeRows() {
+        // Code Set B from the start, one row of data padded with latches, the check characters in
+        // a second row
+        let rows = CodablockFWriter::encodeRows("AIM").expect("encode");
+        assert_eq!(2, rows.len());
+        assert_eq!(
+            vec![CODE_START_A, 0, 100, 33, 41, 45, 99],
+            rows[0][..7].to_vec()
+        );
+        assert_eq!(vec![CODE_START_A, 43, 100, 99, 100], rows[1][..5].to_vec());
+        assert!(rows.iter().all(|row| row.len() == rows[0].len()));
+
+        let rows =
+            CodablockFWriter::encodeRows(&"Codablock F 0123456789 ".repeat(8)).expect("encode");
+        assert!(rows.len() > 2);
+        assert_eq!(rows.len() as u8 - 2, rows[0][1]);
+        for (i, row) in rows.iter().enumerate().skip(1) {
+            assert_eq!(i as u8 + 42, row[1]);
+        }
+
+        assert!(CodablockFWriter
+            .encode("caf\u{e9}", &BarcodeFormat::CODABLOCK_F, 0, 0)
+            .is_err());
+        assert!(CodablockFWriter
+            .encode(&"x".repeat(3000), &BarcodeFormat::CODABLOCK_F, 0, 0)
+            .is_err());
+    }
+}
@@ -0,0 +1,321 @@
+use rxing_one_d_proc_derive::OneDReader;
+
+use crate::{
+    common::{BitArray, Result},
+    point_f, BarcodeFormat, DecodeHintValue, Exceptions, RXingResult,
+};
+
+use super::{one_d_reader, OneDReader};
+
+/**
+ * <p>Decodes Code 11 (USD-8) barcodes.</p>
+ *
+ * <p>{@link DecodeHintType#CODE_11_CHECK_DIGITS} tells how many check digits, C and K, to verify
+ * and remove. By default C alone is expected after up to 10 data characters, and both C and K
+ * after longer data. Like MSI Plessey, Code 11 is only read when asked for with
+ * {@link DecodeHintType#POSSIBLE_FORMATS}.</p>
+ *
+ * @see Code93Reader
+ */
+#[derive(OneDReader)]
+pub struct Code11Reader {
+    decodeRowRXingResult: String,
+    counters: [u32; 5],
+}
+
+impl Default for Code11Reader {
+    fn default() -> Self {
+        Self {
+            decodeRowRXingResult: String::with_capacity(20),
+            counters: [0; 5],
+        }
+    }
+}
+
+impl OneDReader for Code11Reader {
+    fn decode_row(
+        &mut self,
+        rowNumber: u32,
+        row: &crate::common::BitArray,
+        hints: &crate::DecodingHintDictionary,
+    ) -> Result<crate::RXingResult> {
+        let start = self.findAsteriskPattern(row)?;
+        // Read off white space
+        let mut nextStart = row.getNextSet(start[1]);
+        let end = row.get_size();
+
+        let mut theCounters = self.counters;
+        theCounters.fill(0);
+        self.decodeRowRXingResult.truncate(0);
+
+        let mut decodedChar;
+        let mut lastStart;
+        loop {
+            one_d_reader::record_pattern(row, nextStart, &mut theCounters)?;
+            let pattern = Self::toPattern(&theCounters).ok_or(Exceptions::NOT_FOUND)?;
+            decodedChar = Self::patternToChar(pattern)?;
+            self.decodeRowRXingResult.push(decodedChar);
+            lastStart = nextStart;
+
+            for counter in theCounters {
+                nextStart += counter as usize;
+            }
+            // Read off white space
+            nextStart = row.getNextSet(nextStart);
+
+            if decodedChar == '*' {
+                break;
+            }
+        }
+        self.decodeRowRXingResult
+            .truncate(self.decodeRowRXingResult.len() - 1); // remove asterisk
+
+        let lastPatternSize: u32 = theCounters.iter().sum();
+
+        // If 50% of last pattern size, following last pattern, is not whitespace, fail
+        // (but if it's whitespace to the very end of the image, that's OK)
+        let whiteSpaceAfterEnd = nextStart - lastStart - lastPatternSize as usize;
+        if nextStart != end && (whiteSpaceAfterEnd * 2) < lastPatternSize as usize {
+            return Err(Exceptions::NOT_FOUND);
+        }
+
+        let length = self.decodeRowRXingResult.len();
+        let checkDigits = if let Some(DecodeHintValue::Code11CheckDigits(checkDigits)) =
+            hints.get(&DecodeHintType::CODE_11_CHECK_DIGITS)
+        {
+            *checkDigits as usize
+        } else if length > 11 {
+            2
+        } else {
+            1
+        };
+        if checkDigits > 2 || length <= checkDigits {
+            // false positive -- need at least one data character
+            return Err(Exceptions::NOT_FOUND);
+        }
+
+        Self::checkChecksums(&self.decodeRowRXingResult, checkDigits)?;
+        // Remove checksum digits
+        self.decodeRowRXingResult.truncate(length - checkDigits);
+
+        let left: f32 = (start[1] + start[0]) as f32 / 2.0;
+        let right: f32 = lastStart as f32 + lastPatternSize as f32 / 2.0;
+
+        Ok(RXingResult::new(
+            &self.decodeRowRXingResult,
+            Vec::new(),
+            vec![
+                point_f(left, rowNumber as f32),
+                point_f(right, rowNumber as f32),
+            ],
+            BarcodeFormat::CODE_11,
+        ))
+    }
+}
+
+impl Code11Reader {
+    pub const ALPHABET_STRING: &str = "0123456789-*";
+
+    /**
+     * These represent the encodings of characters, as patterns of wide and narrow bars and spaces.
+     * The 5 least-significant bits of each int correspond to the pattern of wide and narrow,
+     * starting with a bar.
+     */
+    pub const CHARACTER_ENCODINGS: [u32; 12] = [
+        0x01, 0x11, 0x09, 0x18, 0x05, 0x14, 0x0C, 0x03, 0x12, 0x10, // 0-9
+        0x04, 0x06, // -, *
+    ];
+    pub const ASTERISK_ENCODING: u32 = Self::CHARACTER_ENCODINGS[11];
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn findAsteriskPattern(&mut self, row: &BitArray) -> Result<[usize; 2]> {
+        let width = row.get_size();
+        let rowOffset = row.getNextSet(0);
+
+        self.counters.fill(0);
+        let mut theCounters = self.counters;
+        let mut patternStart = rowOffset;
+        let mut isWhite = false;
+        let patternLength = theCounters.len();
+
+        let mut counterPosition = 0;
+        for i in rowOffset..width {
+            if row.get(i) != isWhite {
+                theCounters[counterPosition] += 1;
+            } else {
+                if counterPosition == patternLength - 1 {
+                    if Self::toPattern(&theCounters) == Some(Self::ASTERISK_ENCODING) {
+                        return Ok([patternStart, i]);
+                    }
+                    patternStart += (theCounters[0] + theCounters[1]) as usize;
+
+                    theCounters.copy_within(2..(counterPosition - 1 + 2), 0);
+                    theCounters[counterPosition - 1] = 0;
+                    theCounters[counterPosition] = 0;
+                    counterPosition -= 1;
+                } else {
+                    counterPosition += 1;
+                }
+                theCounters[counterPosition] = 1;
+                isWhite = !isWhite;
+            }
+        }
+        Err(Exceptions::NOT_FOUND)
+    }
+
+    /**
+     * Every character has one or two wide elements and at least three narrow ones, so the
+     * widest element is wide and the narrowest one is narrow.
+     */
+    fn toPattern(counters: &[u32; 5]) -> Option<u32> {
+        let min = *counters.iter().min()?;
+        let max = *counters.iter().max()?;
+        // wide elements are about twice as wide as narrow ones
+        if min == 0 || max * 2 < min * 3 || max > min * 4 {
+            return None;
+        }
+        let threshold = min + max;
+        let mut pattern = 0;
+        let mut wideCount = 0;
+        for counter in counters {
+            pattern <<= 1;
+            if counter * 2 > threshold {
+                pattern |= 1;
+                wideCount += 1;
+            }
+        }
+        (wideCount <= 2).then_some(pattern)
+    }
+
+    fn patternToChar(pattern: u32) -> Result<char> {
+        Self::CHARACTER_ENCODINGS
+            .iter()
+            .position(|encoding| *encoding == pattern)
+            .map(|i| Self::ALPHABET_STRING.as_bytes()[i] as char)
+            .ok_or(Exceptions::NOT_FOUND)
+    }
+
+    fn checkChecksums(result: &str, checkDigits: usize) -> Result<()> {
+        let length = result.len();
+        if checkDigits >= 1 {
+            Self::checkOneChecksum(result, length - checkDigits, 10)?;
+        }
+        if checkDigits == 2 {
+            Self::checkOneChecksum(result, length - 1, 9)?;
+        }
+        Ok(())
+    }
+
+    fn checkOneChecksum(result: &str, checkPosition: usize, weightMax: u32) -> Result<()> {
+        let expected = Self::computeChecksum(&result[..checkPosition], weightMax);
+        if result.as_bytes()[checkPosition] as char != expected {
+            Err(Exceptions::CHECKSUM)
+        } else {
+            Ok(())
+        }
+    }
+
+    /**
+     * The weights of the C check digit run from 1 to 10, those of the K check digit from 1 to 9,
+     * both starting again at 1 after the maximum, from the rightmost character.
+     *
+     * @return the check character
+     */
+    pub(crate) fn computeChecksum(contents: &str, weightMax: u32) -> char {
+        let total: u32 = contents
+            .bytes()
+            .rev()
+            .enumerate()
+            .map(|(i, c)| {
+                let value = Self::ALPHABET_STRING
+                    .bytes()
+                    .position(|a| a == c)
+                    .unwrap_or_default() as u32;
+                value * (i as u32 % weightMax + 1)
+            })
+            .sum();
+        Self::ALPHABET_STRING.as_bytes()[(total % 11) as usize] as char
+    }
+}
+
+#[cfg(test)]
+mod Code11ReaderTestCase {
+    use std::collections::HashMap;
+
+    use crate::{
+        common::BitMatrix,
+        oned::{Code11Writer, OneDReader},
+        BarcodeFormat, DecodeHintType, DecodeHintValue, DecodingHintDictionary, Exceptions, Writer,
+    };
+
+    use super::Code11Reader;
+
+    // 123-4552 with the check digits C = 5 and K = 2
+    const ENCODED_C_K: &str =
+        "0000010110010110101101001011011001010101101010110110110110101101101010010110101100100000";
+    // 123-455 with the check digit C = 5
+    const ENCODED_C: &str =
+        "00000101100101101011010010110110010101011010101101101101101011011010101100100000";
+
+    #[test]
+    fn testDecode() {
+        assert_eq!(
+            "123-45",
+            doTest(ENCODED_C, HashMap::new()).expect("must decode")
+        );
+        assert_eq!(
+            "123-455",
+            doTest(ENCODED_C, checkDigits(0)).expect("must decode")
+        );
+        assert_eq!(
+            "123-45",
+            doTest(ENCODED_C_K, checkDigits(2)).expect("must decode")
+        );
+        assert_eq!(
+            "123-455",
+            doTest(ENCODED_C_K, checkDigits(1)).expect("must decode")
+        );
+
+        // longer contents carry both check digits by default
+        let matrix = Code11Writer
+            .encode("0123456789-", &BarcodeFormat::CODE_11, 0, 0)
+            .expect("must encode");
+        let result = Code11Reader::new()
+            .decode_row(0, &matrix.getRow(0), &HashMap::new())
+            .expect("must decode");
+        assert_eq!("0123456789-", result.getText());
+    }
+
+    #[test]
+    fn testChecksum() {
+        // 123-45 with the wrong check digit C = 6
+        let encoded =
+            "00000101100101101011010010110110010101011010101101101101101010011010101100100000";
+        assert!(matches!(
+            doTest(encoded, HashMap::new()),
+            Err(Exceptions::ChecksumException(_))
+        ));
+        assert_eq!(
+            "123-456",
+            doTest(encoded, checkDigits(0)).expect("must decode")
+        );
+    }
+
+    fn checkDigits(checkDigits: u32) -> DecodingHintDictionary {
+        HashMap::from([(
+            DecodeHintType::CODE_11_CHECK_DIGITS,
+            DecodeHintValue::Code11CheckDigits(checkDigits),
+        )])
+    }
+
+    fn doTest(encoded: &str, hints: DecodingHintDictionary) -> crate::common::Result<String> {
+        let matrix = BitMatrix::parse_strings(encoded, "1", "0").expect("must parse");
+        let row = matrix.getRow(0);
+        Code11Reader::new()
+            .decode_row(0, &row, &hints)
+            .map(|result| result.getText().to_owned())
+    }
+}
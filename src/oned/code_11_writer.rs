@@ -0,0 +1,149 @@
+use rxing_one_d_proc_derive::OneDWriter;
+
+use crate::common::Result;
+use crate::BarcodeFormat;
+
+use super::{Code11Reader, OneDimensionalCodeWriter};
+
+/**
+ * This object renders a Code 11 code as a {@link BitMatrix}. The check digits C and K are
+ * appended as told by {@link EncodeHintType#CODE_11_CHECK_DIGITS}, by default C alone for up to
+ * 10 characters and both C and K for longer contents.
+ */
+#[derive(OneDWriter, Default)]
+pub struct Code11Writer;
+
+impl OneDimensionalCodeWriter for Code11Writer {
+    fn encode_oned(&self, contents: &str) -> Result<Vec<bool>> {
+        self.encode_oned_with_hints(contents, &HashMap::new())
+    }
+
+    /**
+     * @param contents barcode contents to encode, digits and '-'
+     * @param hints the number of check digits to append
+     * @return a {@code boolean[]} of horizontal pixels (false = white, true = black)
+     */
+    fn encode_oned_with_hints(
+        &self,
+        contents: &str,
+        hints: &crate::EncodingHintDictionary,
+    ) -> Result<Vec<bool>> {
+        if let Some(c) = contents.chars().find(|c| !c.is_ascii_digit() && *c != '-') {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "Requested contents contains a not encodable character: '{c}'"
+            )));
+        }
+
+        let checkDigits = if let Some(EncodeHintValue::Code11CheckDigits(checkDigits)) =
+            hints.get(&EncodeHintType::CODE_11_CHECK_DIGITS)
+        {
+            *checkDigits
+        } else if contents.len() > 10 {
+            2
+        } else {
+            1
+        };
+        if checkDigits > 2 {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "Code 11 has up to 2 check digits, but {checkDigits} were requested"
+            )));
+        }
+
+        let mut contents = contents.to_owned();
+        //add the check digits, C and then K
+        for weightMax in [10, 9].into_iter().take(checkDigits as usize) {
+            contents.push(Code11Reader::computeChecksum(&contents, weightMax));
+        }
+
+        let mut result = Vec::new();
+
+        //start character (*)
+        Self::appendPattern(&mut result, Code11Reader::ASTERISK_ENCODING);
+
+        for c in contents.bytes() {
+            let Some(indexInString) = Code11Reader::ALPHABET_STRING.bytes().position(|a| a == c)
+            else {
+                panic!("alphabet")
+            };
+            //narrow space between characters
+            result.push(false);
+            Self::appendPattern(
+                &mut result,
+                Code11Reader::CHARACTER_ENCODINGS[indexInString],
+            );
+        }
+
+        //end character (*)
+        result.push(false);
+        Self::appendPattern(&mut result, Code11Reader::ASTERISK_ENCODING);
+
+        Ok(result)
+    }
+
+    fn getSupportedWriteFormats(&self) -> Option<Vec<crate::BarcodeFormat>> {
+        Some(vec![BarcodeFormat::CODE_11])
+    }
+}
+
+impl Code11Writer {
+    /**
+     * Appends the 5 elements of a character, starting with a bar. Wide elements are two modules
+     * wide, narrow ones one module.
+     */
+    fn appendPattern(target: &mut Vec<bool>, pattern: u32) {
+        for i in 0..5 {
+            let width = if pattern & (1 << (4 - i)) != 0 { 2 } else { 1 };
+            target.resize(target.len() + width, i % 2 == 0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod Code11WriterTestCase {
+    use std::collections::HashMap;
+
+    use crate::{
+        common::bit_matrix_test_case, oned::Code11Writer, BarcodeFormat, EncodeHintType,
+        EncodeHintValue, Writer,
+    };
+
+    #[test]
+    fn testEncode() {
+        doTest(
+            "123-45",
+            None,
+            "00000101100101101011010010110110010101011010101101101101101011011010101100100000",
+        );
+        doTest(
+            "123-45",
+            Some(2),
+            "0000010110010110101101001011011001010101101010110110110110101101101010010110101100100000",
+        );
+        doTest(
+            "0123456789-",
+            None,
+            "00000101100101010110110101101001011011001010101101101101101010011010101001101101001011010101011010101011010011010101100100000",
+        );
+    }
+
+    #[test]
+    fn testBadContents() {
+        assert!(Code11Writer
+            .encode("12A", &BarcodeFormat::CODE_11, 0, 0)
+            .is_err());
+    }
+
+    fn doTest(input: &str, checkDigits: Option<u32>, expected: &str) {
+        let mut hints = HashMap::new();
+        if let Some(checkDigits) = checkDigits {
+            hints.insert(
+                EncodeHintType::CODE_11_CHECK_DIGITS,
+                EncodeHintValue::Code11CheckDigits(checkDigits),
+            );
+        }
+        let result = Code11Writer
+            .encode_with_hints(input, &BarcodeFormat::CODE_11, 0, 0, &hints)
+            .expect("encode");
+        assert_eq!(expected, bit_matrix_test_case::matrix_to_string(&result));
+    }
+}
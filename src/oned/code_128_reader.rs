@@ -47,6 +47,8 @@ impl OneDReader for Code128Reader {
 
         let mut rawCodes: Vec<u8> = Vec::with_capacity(20); //new ArrayList<>(20);
         rawCodes.push(startCode);
+        // the code set each code was read in
+        let mut rawCodeSets: Vec<u8> = Vec::with_capacity(20);
 
         let mut codeSet = match startCode {
             // switch (startCode) {
@@ -84,6 +86,7 @@ impl OneDReader for Code128Reader {
             code = self.decodeCode(row, &mut counters, nextStart)?;
 
             rawCodes.push(code);
+            rawCodeSets.push(codeSet);
 
             // Remember whether the last code was printable or not (excluding CODE_STOP)
             if code != CODE_STOP {
@@ -349,10 +352,79 @@ impl OneDReader for Code128Reader {
             RXingResultMetadataValue::SymbologyIdentifier(format!("]C{symbologyModifier}")),
         );
 
+        // A code set character right before the check character is the linkage flag of a
+        // GS1-128 symbol with a CC-A, CC-B or CC-C component (ISO/IEC 24723)
+        if symbologyModifier == 1 && rawCodeSets.len() >= 3 {
+            let linkage = rawCodeSets.len() - 3;
+            let code = rawCodes[linkage + 1];
+            if (CODE_CODE_C..=CODE_CODE_A).contains(&code) && code != rawCodeSets[linkage] {
+                resultObject.putMetadata(
+                    RXingResultMetadataType::IS_GS1_COMPOSITE,
+                    RXingResultMetadataValue::IsGs1Composite(true),
+                );
+            }
+        }
+
         Ok(resultObject)
     }
 }
 impl Code128Reader {
+    /**
+     * <p>Reads the codewords of a row up to its stop code and checks the row's check character,
+     * without interpreting them. Stacked symbologies such as Codablock F are made of rows like
+     * these.</p>
+     *
+     * @return the codewords, start code first and without the check character, and where the row
+     * begins and ends
+     */
+    pub(super) fn decodeCodewords(&self, row: &BitArray) -> Result<(Vec<u8>, f32, f32)> {
+        let startPatternInfo = self.findStartPattern(row)?;
+        let mut codewords = vec![startPatternInfo[2] as u8];
+        let mut counters = [0_u32; 6];
+        let mut lastStart;
+        let mut nextStart = startPatternInfo[1];
+
+        loop {
+            let code = self.decodeCode(row, &mut counters, nextStart)?;
+            lastStart = nextStart;
+            nextStart += counters.iter().sum::<u32>() as usize;
+            match code {
+                CODE_START_A | CODE_START_B | CODE_START_C => return Err(Exceptions::FORMAT),
+                CODE_STOP => break,
+                _ => codewords.push(code),
+            }
+        }
+        let lastPatternSize = nextStart - lastStart;
+
+        // As in decode_row, skip the last bar of the stop code, then check for whitespace
+        nextStart = row.getNextUnset(nextStart);
+        if !row.isRange(
+            nextStart,
+            row.get_size().min(nextStart + (nextStart - lastStart) / 2),
+            false,
+        )? {
+            return Err(Exceptions::NOT_FOUND);
+        }
+
+        if codewords.len() < 2 {
+            return Err(Exceptions::NOT_FOUND);
+        }
+        let checkCode = codewords.pop().ok_or(Exceptions::NOT_FOUND)?;
+        // the start code and the first codeword both weigh one
+        let checksumTotal = codewords
+            .iter()
+            .enumerate()
+            .map(|(i, code)| i.max(1) * *code as usize)
+            .sum::<usize>();
+        if (checksumTotal % 103) as u8 != checkCode {
+            return Err(Exceptions::CHECKSUM);
+        }
+
+        let left = (startPatternInfo[1] + startPatternInfo[0]) as f32 / 2.0;
+        let right = lastStart as f32 + lastPatternSize as f32 / 2.0;
+        Ok((codewords, left, right))
+    }
+
     fn findStartPattern(&self, row: &BitArray) -> Result<[usize; 3]> {
         let width = row.get_size();
         let rowOffset = row.getNextSet(0);
@@ -409,7 +481,12 @@ impl Code128Reader {
         Err(Exceptions::NOT_FOUND)
     }
 
-    fn decodeCode(&self, row: &BitArray, counters: &mut [u32; 6], rowOffset: usize) -> Result<u8> {
+    pub(super) fn decodeCode(
+        &self,
+        row: &BitArray,
+        counters: &mut [u32; 6],
+        rowOffset: usize,
+    ) -> Result<u8> {
         one_d_reader::record_pattern(row, rowOffset, counters)?;
         let mut bestVariance = MAX_AVG_VARIANCE; // worst variance we'll accept
         let mut bestMatch = -1_isize;
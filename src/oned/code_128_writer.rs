@@ -24,18 +24,18 @@ use super::{code_128_reader, OneDimensionalCodeWriter};
 const CODE_START_A: usize = 103;
 const CODE_START_B: usize = 104;
 const CODE_START_C: usize = 105;
-const CODE_CODE_A: usize = 101;
-const CODE_CODE_B: usize = 100;
-const CODE_CODE_C: usize = 99;
+pub(super) const CODE_CODE_A: usize = 101;
+pub(super) const CODE_CODE_B: usize = 100;
+pub(super) const CODE_CODE_C: usize = 99;
 const CODE_STOP: usize = 106;
 
 // Dummy characters used to specify control characters in input
-const ESCAPE_FNC_1: char = '\u{00f1}';
+pub(super) const ESCAPE_FNC_1: char = '\u{00f1}';
 const ESCAPE_FNC_2: char = '\u{00f2}';
 const ESCAPE_FNC_3: char = '\u{00f3}';
 const ESCAPE_FNC_4: char = '\u{00f4}';
 
-const CODE_FNC_1: usize = 102; // Code A, Code B, Code C
+pub(super) const CODE_FNC_1: usize = 102; // Code A, Code B, Code C
 const CODE_FNC_2: usize = 97; // Code A, Code B
 const CODE_FNC_3: usize = 96; // Code A, Code B
 const CODE_FNC_4_A: usize = 101; // Code A
@@ -365,7 +365,7 @@ fn findCType(value: &str, start: usize) -> Option<CType> {
     Some(CType::TwoDigits)
 }
 
-fn chooseCode(value: &str, start: usize, oldCode: usize) -> Option<usize> {
+pub(super) fn chooseCode(value: &str, start: usize, oldCode: usize) -> Option<usize> {
     let mut lookahead = findCType(value, start)?;
     if lookahead == CType::OneDigit {
         if oldCode == CODE_CODE_A {
@@ -0,0 +1,260 @@
+use std::collections::HashMap;
+
+use crate::{
+    common::{BitArray, Result},
+    BarcodeFormat, Binarizer, BinaryBitmap, DecodingHintDictionary, Exceptions, RXingResult,
+    RXingResultMetadataType, RXingResultMetadataValue, Reader,
+};
+
+use super::{
+    code_128_writer::{CODE_CODE_A, CODE_CODE_B, CODE_CODE_C},
+    one_d_reader,
+    stacked_code_128_support::{getStackedResultPoints, CodeSetDecoder, StackedRow},
+    Code128Reader, OneDReader,
+};
+
+const MAX_AVG_VARIANCE: f32 = 0.25;
+const MAX_INDIVIDUAL_VARIANCE: f32 = 0.7;
+
+/// A symbol has 2 to 16 rows of 5 characters each
+pub(super) const MIN_ROWS: u32 = 2;
+pub(super) const MAX_ROWS: u32 = 16;
+pub(super) const CHARACTERS_PER_ROW: usize = 5;
+
+/// The first character of the first row holds both the number of rows and the mode
+pub(super) const NUM_MODES: u32 = 7;
+/// The code sets the text starts in, by mode
+pub(super) const MODE_CODE_SETS: [usize; 3] = [CODE_CODE_A, CODE_CODE_B, CODE_CODE_C];
+/// Fills up the last row in front of the check characters
+pub(super) const CODE_PAD: u8 = 103;
+/// The check characters are modulo 107, the number of character values
+pub(super) const CHECK_CHARACTER_MODULUS: u32 = 107;
+
+/// The bars and spaces of the start and stop patterns, each seven modules wide
+pub(super) const START_STOP_PATTERNS: [[u32; 4]; 8] = [
+    [3, 2, 1, 1],
+    [2, 2, 2, 1],
+    [2, 1, 2, 2],
+    [1, 4, 1, 1],
+    [1, 1, 3, 2],
+    [1, 2, 3, 1],
+    [1, 1, 1, 4],
+    [3, 1, 1, 2],
+];
+/// The start and stop patterns of every row, which together tell the rows apart
+pub(super) const START_VALUES: [usize; MAX_ROWS as usize] =
+    [0, 1, 2, 3, 4, 5, 6, 7, 0, 1, 2, 3, 4, 5, 6, 7];
+pub(super) const STOP_VALUES: [usize; MAX_ROWS as usize] =
+    [0, 1, 2, 3, 4, 5, 6, 7, 4, 5, 6, 7, 0, 1, 2, 3];
+
+/**
+ * <p>Decodes Code 16K barcodes: rows of five Code 128 characters stacked on top of each other,
+ * between a start and a stop pattern which tell the row apart from the others, and ended by a
+ * single module bar. The first character holds the number of rows and the code set the text
+ * starts in, the last two are check characters of the whole symbol.</p>
+ *
+ * <p>The rows are kept across calls to {@link #decode_row}, which only returns a result once it
+ * has found every row of a symbol. Code 16K is only read when asked for with
+ * {@link DecodeHintType#POSSIBLE_FORMATS}.</p>
+ */
+#[derive(Default)]
+pub struct Code16KReader {
+    // The rows found so far, by their index in the symbol
+    rows: HashMap<u32, StackedRow>,
+}
+
+impl OneDReader for Code16KReader {
+    fn decode_row(
+        &mut self,
+        rowNumber: u32,
+        row: &BitArray,
+        _hints: &DecodingHintDictionary,
+    ) -> Result<RXingResult> {
+        let mut start = row.getNextSet(0);
+        while start < row.get_size() {
+            if let Some((index, codewords, left, right)) = Self::decodeRowFrom(row, start) {
+                self.rows.insert(
+                    index,
+                    StackedRow {
+                        codewords,
+                        left,
+                        right,
+                        rowNumber,
+                    },
+                );
+                let result = self.combineRows()?;
+                self.rows.clear();
+                return Ok(result);
+            }
+            start = row.getNextSet(row.getNextUnset(start));
+        }
+        Err(Exceptions::NOT_FOUND)
+    }
+}
+
+impl Reader for Code16KReader {
+    fn decode<B: Binarizer>(&mut self, image: &mut BinaryBitmap<B>) -> Result<RXingResult> {
+        self.decode_with_hints(image, &HashMap::new())
+    }
+
+    fn decode_with_hints<B: Binarizer>(
+        &mut self,
+        image: &mut BinaryBitmap<B>,
+        hints: &DecodingHintDictionary,
+    ) -> Result<RXingResult> {
+        self.reset();
+        let result = self._do_decode_stacked(image, hints, false).or_else(|_| {
+            self.reset();
+            self._do_decode_stacked(image, hints, true)
+        });
+        self.reset();
+        result
+    }
+
+    fn reset(&mut self) {
+        self.rows.clear();
+    }
+}
+
+impl Code16KReader {
+    /**
+     * Reads a row whose start pattern begins at the given bar: the start pattern, five characters,
+     * the stop pattern and the closing bar, with white space on both sides.
+     *
+     * @return the index of the row in the symbol, its characters and where it begins and ends
+     */
+    fn decodeRowFrom(row: &BitArray, start: usize) -> Option<(u32, Vec<u8>, f32, f32)> {
+        let mut counters = [0_u32; 4];
+        one_d_reader::record_pattern(row, start, &mut counters).ok()?;
+        let startValue = Self::matchStartStopPattern(&counters)?;
+        let patternWidth = counters.iter().sum::<u32>() as usize;
+        // Look for whitespace before the start pattern, half as wide as the start pattern
+        if !row
+            .isRange(start.saturating_sub(patternWidth / 2), start, false)
+            .ok()?
+        {
+            return None;
+        }
+
+        let mut position = start + patternWidth;
+        let mut characterCounters = [0_u32; 6];
+        let mut codewords = Vec::with_capacity(CHARACTERS_PER_ROW);
+        for _ in 0..CHARACTERS_PER_ROW {
+            codewords.push(
+                Code128Reader
+                    .decodeCode(row, &mut characterCounters, position)
+                    .ok()?,
+            );
+            position += characterCounters.iter().sum::<u32>() as usize;
+        }
+
+        one_d_reader::record_pattern(row, position, &mut counters).ok()?;
+        let stopValue = Self::matchStartStopPattern(&counters)?;
+        position += counters.iter().sum::<u32>() as usize;
+
+        // the closing bar is one module wide, a seventh of the stop pattern
+        let end = row.getNextUnset(position);
+        let moduleWidth = counters.iter().sum::<u32>() as f32 / 7.0;
+        if end == position || (end - position) as f32 > 2.0 * moduleWidth {
+            return None;
+        }
+        if !row
+            .isRange(end, row.get_size().min(end + patternWidth / 2), false)
+            .ok()?
+        {
+            return None;
+        }
+
+        let index = (0..MAX_ROWS as usize)
+            .find(|i| START_VALUES[*i] == startValue && STOP_VALUES[*i] == stopValue)?;
+        Some((index as u32, codewords, start as f32, end as f32))
+    }
+
+    fn matchStartStopPattern(counters: &[u32; 4]) -> Option<usize> {
+        let mut bestVariance = MAX_AVG_VARIANCE;
+        let mut bestMatch = None;
+        for (value, pattern) in START_STOP_PATTERNS.iter().enumerate() {
+            let variance =
+                one_d_reader::pattern_match_variance(counters, pattern, MAX_INDIVIDUAL_VARIANCE);
+            if variance < bestVariance {
+                bestVariance = variance;
+                bestMatch = Some(value);
+            }
+        }
+        bestMatch
+    }
+
+    /**
+     * @return the symbol, once the first row and all the rows it announces have been found
+     * @throws NotFoundException if rows are still missing
+     * @throws ChecksumException if the check characters do not match
+     */
+    fn combineRows(&self) -> Result<RXingResult> {
+        let first = self.rows.get(&0).ok_or(Exceptions::NOT_FOUND)?;
+        let rowCount = first.codewords[0] as u32 / NUM_MODES + MIN_ROWS;
+        let mode = first.codewords[0] as u32 % NUM_MODES;
+        if rowCount > MAX_ROWS {
+            return Err(Exceptions::FORMAT);
+        }
+        let rows = (0..rowCount)
+            .map(|index| self.rows.get(&index))
+            .collect::<Option<Vec<_>>>()
+            .ok_or(Exceptions::NOT_FOUND)?;
+        let codewords = rows
+            .iter()
+            .flat_map(|row| row.codewords.iter().copied())
+            .collect::<Vec<_>>();
+
+        let dataLength = codewords.len() - 2;
+        if getCheckCharacters(&codewords[..dataLength])
+            != (codewords[dataLength], codewords[dataLength + 1])
+        {
+            return Err(Exceptions::CHECKSUM);
+        }
+
+        // Code Sets A, B and C are modes 0, 1 and 2, the other modes are not supported
+        let mut decoder = CodeSetDecoder::new(0);
+        let codeSet = MODE_CODE_SETS
+            .get(mode as usize)
+            .ok_or(Exceptions::UNSUPPORTED_OPERATION)?;
+        decoder.setCodeSet(*codeSet as u8)?;
+        for code in codewords[1..dataLength]
+            .iter()
+            .filter(|code| **code != CODE_PAD)
+        {
+            decoder.decode(*code)?;
+        }
+
+        let mut resultObject = RXingResult::new(
+            decoder.getText(),
+            codewords,
+            getStackedResultPoints(first, rows[rows.len() - 1]),
+            BarcodeFormat::CODE_16K,
+        );
+        resultObject.putMetadata(
+            RXingResultMetadataType::SYMBOLOGY_IDENTIFIER,
+            RXingResultMetadataValue::SymbologyIdentifier(
+                if decoder.isFNC1First() { "]K1" } else { "]K0" }.to_owned(),
+            ),
+        );
+        Ok(resultObject)
+    }
+}
+
+/**
+ * @return the two check characters of the codewords: sums of the codewords weighted by their
+ * position counted from two, and of the codewords and the first check character weighted by their
+ * position counted from one
+ */
+pub(super) fn getCheckCharacters(codewords: &[u8]) -> (u8, u8) {
+    let weightedSum = |firstWeight: u32| {
+        codewords
+            .iter()
+            .zip(firstWeight..)
+            .map(|(codeword, weight)| weight * *codeword as u32)
+            .sum::<u32>()
+    };
+    let c1 = weightedSum(2) % CHECK_CHARACTER_MODULUS;
+    let c2 = (weightedSum(1) + (codewords.len() as u32 + 1) * c1) % CHECK_CHARACTER_MODULUS;
+    (c1 as u8, c2 as u8)
+}
@@ -0,0 +1,188 @@
+use std::collections::HashMap;
+
+use crate::{
+    common::{BitMatrix, Result},
+    BarcodeFormat, EncodeHintType, EncodeHintValue, Exceptions, Writer,
+};
+
+use super::{
+    code_128_reader::CODE_PATTERNS,
+    code_16k_reader::{
+        getCheckCharacters, CHARACTERS_PER_ROW, CODE_PAD, MAX_ROWS, MIN_ROWS, MODE_CODE_SETS,
+        NUM_MODES, START_STOP_PATTERNS, START_VALUES, STOP_VALUES,
+    },
+    stacked_code_128_support::{renderStackedRows, CodeSetEncoder},
+    OneDimensionalCodeWriter,
+};
+
+/// Every row is eight modules high
+const ROW_HEIGHT: u32 = 8;
+
+/**
+ * <p>This object renders a Code 16K as a {@link BitMatrix}: the text is encoded in Code 128 code
+ * sets like {@link Code128Writer} does, in as few rows of five characters as it fits in.</p>
+ */
+#[derive(Default)]
+pub struct Code16KWriter;
+
+impl Writer for Code16KWriter {
+    fn encode(
+        &self,
+        contents: &str,
+        format: &BarcodeFormat,
+        width: i32,
+        height: i32,
+    ) -> Result<BitMatrix> {
+        self.encode_with_hints(contents, format, width, height, &HashMap::new())
+    }
+
+    fn encode_with_hints(
+        &self,
+        contents: &str,
+        format: &BarcodeFormat,
+        width: i32,
+        height: i32,
+        hints: &crate::EncodingHintDictionary,
+    ) -> Result<BitMatrix> {
+        if contents.is_empty() {
+            return Err(Exceptions::illegal_argument_with("Found empty contents"));
+        }
+
+        if format != &BarcodeFormat::CODE_16K {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "Can only encode CODE_16K, but got {format:?}"
+            )));
+        }
+
+        if width < 0 || height < 0 {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "Negative size is not allowed. Input: {width}x{height}"
+            )));
+        }
+
+        let mut sidesMargin = self.getDefaultMargin();
+        if let Some(EncodeHintValue::Margin(margin)) = hints.get(&EncodeHintType::MARGIN) {
+            sidesMargin = margin.parse::<u32>().map_err(|e| {
+                Exceptions::illegal_argument_with(format!("couldnt parse {margin}: {e}"))
+            })?;
+        }
+
+        let rows = Self::encodeRows(contents)?;
+        renderStackedRows(&rows, ROW_HEIGHT, width, height, sidesMargin)
+    }
+}
+
+impl OneDimensionalCodeWriter for Code16KWriter {
+    /**
+     * @return the rows of the symbol one after the other
+     */
+    fn encode_oned(&self, contents: &str) -> Result<Vec<bool>> {
+        Ok(Self::encodeRows(contents)?.concat())
+    }
+
+    fn getSupportedWriteFormats(&self) -> Option<Vec<BarcodeFormat>> {
+        Some(vec![BarcodeFormat::CODE_16K])
+    }
+}
+
+impl Code16KWriter {
+    /**
+     * @return the characters of the symbol, five for every row
+     */
+    pub(super) fn encodeCodewords(contents: &str) -> Result<Vec<u8>> {
+        let mut encoder = CodeSetEncoder::new(contents)?;
+        let codeSet = encoder.startCodeSet()?;
+        let mode = MODE_CODE_SETS
+            .iter()
+            .position(|modeCodeSet| *modeCodeSet == codeSet)
+            .ok_or(Exceptions::ILLEGAL_STATE)?;
+
+        let mut data = Vec::new();
+        while let Some(codeword) = encoder.next()? {
+            data.push(codeword);
+        }
+
+        // the first character and the two check characters come on top of the data
+        let rowCount = (data.len() + 3)
+            .div_ceil(CHARACTERS_PER_ROW)
+            .max(MIN_ROWS as usize) as u32;
+        if rowCount > MAX_ROWS {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "Contents too long for a Code 16K: {} characters",
+                contents.chars().count()
+            )));
+        }
+
+        let mut codewords = vec![(NUM_MODES * (rowCount - MIN_ROWS)) as u8 + mode as u8];
+        codewords.extend(data);
+        codewords.resize(rowCount as usize * CHARACTERS_PER_ROW - 2, CODE_PAD);
+        let (c1, c2) = getCheckCharacters(&codewords);
+        codewords.extend([c1, c2]);
+        Ok(codewords)
+    }
+
+    /**
+     * <p>Every row is the start pattern, five characters, the stop pattern and a one module wide
+     * bar.</p>
+     */
+    fn encodeRows(contents: &str) -> Result<Vec<Vec<bool>>> {
+        Ok(Self::encodeCodewords(contents)?
+            .chunks(CHARACTERS_PER_ROW)
+            .enumerate()
+            .map(|(i, characters)| {
+                let mut patterns = vec![&START_STOP_PATTERNS[START_VALUES[i]][..]];
+                // only the first six bars and spaces of the Code 128 stop pattern, character 106
+                patterns.extend(
+                    characters
+                        .iter()
+                        .map(|character| &CODE_PATTERNS[*character as usize][..6]),
+                );
+                patterns.push(&START_STOP_PATTERNS[STOP_VALUES[i]]);
+                patterns.push(&[1]);
+
+                let mut row =
+                    vec![false; patterns.iter().flat_map(|p| p.iter()).sum::<u32>() as usize];
+                let mut pos = 0;
+                for pattern in patterns {
+                    pos += Self::appendPattern(&mut row, pos, pattern, true) as usize;
+                }
+                row
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod Code16KWriterTestCase {
+    use crate::{oned::Code16KWriter, BarcodeFormat, Writer};
+
+    #[test]
+    fn testEncodeCodewords() {
+        // two rows in Code Set B, padded in front of the check characters
+        let codewords = Code16KWriter::encodeCodewords("AIM").expect("encode");
+        assert_eq!(
+            vec![1, 33, 41, 45, 103, 103, 103, 103],
+            codewords[..8].to_vec()
+        );
+        assert_eq!(10, codewords.len());
+
+        // three rows in Code Set C
+        let codewords = Code16KWriter::encodeCodewords("0123456789012345").expect("encode");
+        assert_eq!(
+            vec![7 + 2, 1, 23, 45, 67, 89, 1, 23, 45],
+            codewords[..9].to_vec()
+        );
+        assert_eq!(15, codewords.len());
+
+        let matrix = Code16KWriter
+            .encode("AIM", &BarcodeFormat::CODE_16K, 0, 0)
+            .expect("encode");
+        // 70 modules wide with the margin, two rows of 8 modules and 3 separators high
+        assert_eq!(80, matrix.getWidth());
+        assert_eq!(19, matrix.getHeight());
+
+        assert!(Code16KWriter
+            .encode(&"x".repeat(80), &BarcodeFormat::CODE_16K, 0, 0)
+            .is_err());
+    }
+}
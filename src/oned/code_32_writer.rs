@@ -0,0 +1,82 @@
+use rxing_one_d_proc_derive::OneDWriter;
+
+use crate::common::Result;
+use crate::BarcodeFormat;
+
+use super::{Code39Reader, Code39Writer, OneDimensionalCodeWriter};
+
+/**
+ * This object renders a Code 32 (Italian Pharmacode) as a {@link BitMatrix}: a Code 39 symbol
+ * carrying the 9 digit AIC number, check digit included, in base 32.
+ */
+#[derive(OneDWriter, Default)]
+pub struct Code32Writer;
+
+impl OneDimensionalCodeWriter for Code32Writer {
+    /**
+     * @param contents the AIC number, 8 digits or 9 digits with the check digit, optionally
+     * prefixed with 'A'
+     * @return a {@code boolean[]} of horizontal pixels (false = white, true = black)
+     */
+    fn encode_oned(&self, contents: &str) -> Result<Vec<bool>> {
+        let digits = contents.strip_prefix('A').unwrap_or(contents);
+        if !(8..=9).contains(&digits.len()) || !digits.bytes().all(|c| c.is_ascii_digit()) {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "Requested contents should be 8 or 9 digits, optionally prefixed with 'A', but got {contents}"
+            )));
+        }
+        let checkDigit = Code39Reader::code32CheckDigit(&digits[..8]);
+        if digits.len() == 9 && digits.as_bytes()[8] as char != checkDigit {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "Contents do not pass checksum, the check digit should be {checkDigit}"
+            )));
+        }
+
+        let mut value = format!("{}{checkDigit}", &digits[..8])
+            .parse::<u32>()
+            .map_err(|e| Exceptions::illegal_argument_with(format!("{e}")))?;
+        let mut encoded = [b'0'; 6];
+        for c in encoded.iter_mut().rev() {
+            *c = Code39Reader::CODE_32_ALPHABET.as_bytes()[value as usize % 32];
+            value /= 32;
+        }
+
+        Code39Writer.encode_oned(std::str::from_utf8(&encoded).map_err(|_| Exceptions::PARSE)?)
+    }
+
+    fn getSupportedWriteFormats(&self) -> Option<Vec<crate::BarcodeFormat>> {
+        Some(vec![BarcodeFormat::CODE_32])
+    }
+}
+
+#[cfg(test)]
+mod Code32WriterTestCase {
+    use crate::{
+        common::bit_matrix_test_case,
+        oned::{Code32Writer, Code39Writer},
+        BarcodeFormat, Writer,
+    };
+
+    #[test]
+    fn testEncode() {
+        // 01234567 has the check digit 6, 012345676 is 0CSSBD in base 32
+        let expected = Code39Writer
+            .encode("0CSSBD", &BarcodeFormat::CODE_39, 0, 0)
+            .expect("encode");
+        for contents in ["01234567", "012345676", "A012345676"] {
+            let result = Code32Writer
+                .encode(contents, &BarcodeFormat::CODE_32, 0, 0)
+                .expect("encode");
+            assert_eq!(
+                bit_matrix_test_case::matrix_to_string(&expected),
+                bit_matrix_test_case::matrix_to_string(&result)
+            );
+        }
+
+        for contents in ["012345675", "A0123456", "B01234567", "0123456A"] {
+            assert!(Code32Writer
+                .encode(contents, &BarcodeFormat::CODE_32, 0, 0)
+                .is_err());
+        }
+    }
+}
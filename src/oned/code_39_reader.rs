@@ -17,13 +17,16 @@
 use rxing_one_d_proc_derive::OneDReader;
 
 use crate::common::{BitArray, Result};
-use crate::{point_f, BarcodeFormat, Exceptions, RXingResult};
+use crate::{point_f, BarcodeFormat, DecodeHintValue, Exceptions, RXingResult};
 
 use super::{one_d_reader, OneDReader};
 
 /**
  * <p>Decodes Code 39 barcodes. Supports "Full ASCII Code 39" if USE_CODE_39_EXTENDED_MODE is set.</p>
  *
+ * <p>Symbols carrying a valid Code 32 (Italian Pharmacode) AIC number are reported as
+ * {@link BarcodeFormat#CODE_32} if {@link DecodeHintType#ASSUME_CODE_32} is set.</p>
+ *
  * @author Sean Owen
  * @see Code93Reader
  */
@@ -44,7 +47,7 @@ impl OneDReader for Code39Reader {
         &mut self,
         rowNumber: u32,
         row: &crate::common::BitArray,
-        _hints: &DecodingHintDictionary,
+        hints: &DecodingHintDictionary,
     ) -> Result<crate::RXingResult> {
         let mut counters = [0_u32; 9];
         self.decodeRowRXingResult.clear();
@@ -88,7 +91,13 @@ impl OneDReader for Code39Reader {
             return Err(Exceptions::NOT_FOUND);
         }
 
-        if self.usingCheckDigit {
+        let code32 = if Self::isCode32Mode(hints) {
+            Self::decodeCode32(&self.decodeRowRXingResult)
+        } else {
+            None
+        };
+
+        if self.usingCheckDigit && code32.is_none() {
             let max = self.decodeRowRXingResult.chars().count() - 1;
             let mut total = 0;
             for i in 0..max {
@@ -121,7 +130,9 @@ impl OneDReader for Code39Reader {
             return Err(Exceptions::NOT_FOUND);
         }
 
-        let resultString = if self.extendedMode {
+        let resultString = if let Some(code32) = &code32 {
+            code32.clone()
+        } else if self.extendedMode {
             Self::decodeExtended(&self.decodeRowRXingResult)?
         } else {
             self.decodeRowRXingResult.clone()
@@ -137,7 +148,11 @@ impl OneDReader for Code39Reader {
                 point_f(left, rowNumber as f32),
                 point_f(right, rowNumber as f32),
             ],
-            BarcodeFormat::CODE_39,
+            if code32.is_some() {
+                BarcodeFormat::CODE_32
+            } else {
+                BarcodeFormat::CODE_39
+            },
         );
 
         resultObject.putMetadata(
@@ -166,6 +181,9 @@ impl Code39Reader {
 
     pub const ASTERISK_ENCODING: u32 = 0x094;
 
+    /** The base 32 digits of Code 32, which leaves out the vowels */
+    pub const CODE_32_ALPHABET: &str = "0123456789BCDFGHJKLMNPQRSTUVWXYZ";
+
     /**
      * Creates a reader that assumes all encoded data is data, and does not treat the final
      * character as a check digit. It will not decoded "extended Code 39" sequences.
@@ -204,6 +222,60 @@ impl Code39Reader {
         }
     }
 
+    /**
+     * Code 32 is read when asked for with {@link DecodeHintType#ASSUME_CODE_32}, or with
+     * {@link DecodeHintType#POSSIBLE_FORMATS} if these don't ask for Code 39 as well.
+     */
+    fn isCode32Mode(hints: &DecodingHintDictionary) -> bool {
+        matches!(
+            hints.get(&DecodeHintType::ASSUME_CODE_32),
+            Some(DecodeHintValue::AssumeCode32(true))
+        ) || matches!(
+            hints.get(&DecodeHintType::POSSIBLE_FORMATS),
+            Some(DecodeHintValue::PossibleFormats(formats))
+                if formats.contains(&BarcodeFormat::CODE_32)
+                    && !formats.contains(&BarcodeFormat::CODE_39)
+        )
+    }
+
+    /**
+     * Interprets the text of a Code 39 symbol as Code 32 (Italian Pharmacode): 6 base 32 digits
+     * of the 9 digit AIC number, whose last digit is a check digit.
+     *
+     * @return the AIC number prefixed with 'A', or None if the text isn't valid Code 32
+     */
+    pub(crate) fn decodeCode32(text: &str) -> Option<String> {
+        if text.len() != 6 {
+            return None;
+        }
+        let mut value = 0_u32;
+        for c in text.chars() {
+            value = value * 32 + Self::CODE_32_ALPHABET.find(c)? as u32;
+        }
+        if value > 999_999_999 {
+            return None;
+        }
+        let digits = format!("{value:09}");
+        (Self::code32CheckDigit(&digits[..8]) == digits.as_bytes()[8] as char)
+            .then(|| format!("A{digits}"))
+    }
+
+    /**
+     * The check digit of the first 8 digits of an AIC number: digits in even positions are
+     * doubled, and the digits of all the products summed modulo 10.
+     */
+    pub(crate) fn code32CheckDigit(digits: &str) -> char {
+        let sum: u32 = digits
+            .bytes()
+            .enumerate()
+            .map(|(i, c)| {
+                let digit = (c - b'0') as u32 * if i % 2 == 1 { 2 } else { 1 };
+                digit / 10 + digit % 10
+            })
+            .sum();
+        (b'0' + (sum % 10) as u8) as char
+    }
+
     fn findAsteriskPattern(row: &BitArray, counters: &mut [u32]) -> Result<Vec<u32>> {
         let width = row.get_size();
         let rowOffset = row.getNextSet(0);
@@ -435,3 +507,101 @@ mod code_39_extended_mode_test_case {
         assert_eq!(expectedRXingResult, result.getText());
     }
 }
+
+#[cfg(test)]
+mod code_32_test_case {
+    use std::collections::{HashMap, HashSet};
+
+    use crate::{
+        oned::{Code32Writer, Code39Reader, Code39Writer, MultiFormatOneDReader, OneDReader},
+        BarcodeFormat, DecodeHintType, DecodeHintValue, Writer,
+    };
+
+    #[test]
+    fn testDecodeCode32() {
+        let hints = HashMap::from([(
+            DecodeHintType::ASSUME_CODE_32,
+            DecodeHintValue::AssumeCode32(true),
+        )]);
+
+        let matrix = Code32Writer
+            .encode("01234567", &BarcodeFormat::CODE_32, 0, 0)
+            .expect("encode");
+        let result = Code39Reader::new()
+            .decode_row(0, &matrix.getRow(0), &hints)
+            .expect("decode row");
+        assert_eq!("A012345676", result.getText());
+        assert_eq!(&BarcodeFormat::CODE_32, result.getBarcodeFormat());
+
+        // without the hint the base 32 digits are returned
+        let result = Code39Reader::new()
+            .decode_row(0, &matrix.getRow(0), &HashMap::new())
+            .expect("decode row");
+        assert_eq!("0CSSBD", result.getText());
+        assert_eq!(&BarcodeFormat::CODE_39, result.getBarcodeFormat());
+
+        // 012345677 fails the check digit, and vowels aren't base 32 digits
+        for contents in ["0CSSBF", "0CSSBA", "0CSSB"] {
+            let matrix = Code39Writer
+                .encode(contents, &BarcodeFormat::CODE_39, 0, 0)
+                .expect("encode");
+            let result = Code39Reader::new()
+                .decode_row(0, &matrix.getRow(0), &hints)
+                .expect("decode row");
+            assert_eq!(contents, result.getText());
+            assert_eq!(&BarcodeFormat::CODE_39, result.getBarcodeFormat());
+        }
+    }
+
+    #[test]
+    fn testPossibleFormats() {
+        // a plain Code 39 symbol that happens to be valid Code 32
+        let matrix = Code39Writer
+            .encode("0CSSBD", &BarcodeFormat::CODE_39, 0, 0)
+            .expect("encode");
+        let decode = |formats: &[BarcodeFormat], assumeCode32: bool| {
+            let mut hints = HashMap::from([(
+                DecodeHintType::POSSIBLE_FORMATS,
+                DecodeHintValue::PossibleFormats(HashSet::from_iter(formats.iter().copied())),
+            )]);
+            if assumeCode32 {
+                hints.insert(
+                    DecodeHintType::ASSUME_CODE_32,
+                    DecodeHintValue::AssumeCode32(true),
+                );
+            }
+            let result = MultiFormatOneDReader::new(&hints)
+                .decode_row(0, &matrix.getRow(0), &hints)
+                .expect("decode row");
+            (result.getText().to_owned(), *result.getBarcodeFormat())
+        };
+
+        let both = [BarcodeFormat::CODE_39, BarcodeFormat::CODE_32];
+        assert_eq!(
+            ("0CSSBD".to_owned(), BarcodeFormat::CODE_39),
+            decode(&both, false)
+        );
+        assert_eq!(
+            ("A012345676".to_owned(), BarcodeFormat::CODE_32),
+            decode(&both, true)
+        );
+        assert_eq!(
+            ("A012345676".to_owned(), BarcodeFormat::CODE_32),
+            decode(&[BarcodeFormat::CODE_32], false)
+        );
+        assert_eq!(
+            ("0CSSBD".to_owned(), BarcodeFormat::CODE_39),
+            decode(&[BarcodeFormat::CODE_39], false)
+        );
+    }
+
+    #[test]
+    fn testCheckDigit() {
+        assert_eq!('6', Code39Reader::code32CheckDigit("01234567"));
+        assert_eq!(
+            Some("A012345676".to_owned()),
+            Code39Reader::decodeCode32("0CSSBD")
+        );
+        assert_eq!(None, Code39Reader::decodeCode32("ZZZZZZ"));
+    }
+}
@@ -0,0 +1,253 @@
+use crate::{
+    common::BitMatrix,
+    pdf417::{decoder::micro_pdf_417_decoder, MicroPDF417Reader, PDF417Reader},
+    BarcodeFormat, RXingResult, RXingResultMetadataType, RXingResultMetadataValue,
+};
+
+/// Blank pixels kept around the 2D component when it is cut out of the image
+const QUIET_ZONE: u32 = 10;
+/// How far the 2D component may stick out beyond the linear one, in modules: its quiet zone
+const OVERHANG: f32 = 10.0;
+
+/// The GS1 linear symbologies that can be the linear component of a GS1 Composite symbol
+const LINEAR_COMPONENT_FORMATS: [BarcodeFormat; 8] = [
+    BarcodeFormat::EAN_8,
+    BarcodeFormat::EAN_13,
+    BarcodeFormat::UPC_A,
+    BarcodeFormat::UPC_E,
+    BarcodeFormat::CODE_128,
+    BarcodeFormat::RSS_14,
+    BarcodeFormat::RSS_LIMITED,
+    BarcodeFormat::RSS_EXPANDED,
+];
+
+/**
+ * @return true if the result is the linear component of a GS1 Composite symbol, as told by its
+ *  linkage flag
+ */
+pub(crate) fn isLinearComponent(result: &RXingResult) -> bool {
+    LINEAR_COMPONENT_FORMATS.contains(result.getBarcodeFormat())
+        && result
+            .getRXingResultMetadata()
+            .get(&RXingResultMetadataType::IS_GS1_COMPOSITE)
+            == Some(&RXingResultMetadataValue::IsGs1Composite(true))
+}
+
+/**
+ * <p>Reads the 2D component of a GS1 Composite symbol (ISO/IEC 24723), printed above the linear
+ * component.</p>
+ *
+ * <p>The component is expected right above the rows of the linear component, separated by at
+ * most a separator pattern and a small gap. It is decoded as CC-A, CC-B (MicroPDF417) or CC-C
+ * (PDF417).</p>
+ *
+ * @param image the binarized image
+ * @param rowNumber the row the linear component was read in
+ * @param upsideDown true if the linear component was read in the reversed row, so that the 2D
+ *  component is below it. Its points are then those of the reversed row.
+ * @param linear the result of the linear component
+ * @return the element strings of the 2D component, if there is one
+ */
+pub(crate) fn decodeComposite(
+    image: &BitMatrix,
+    rowNumber: u32,
+    upsideDown: bool,
+    linear: &RXingResult,
+) -> Option<String> {
+    if rowNumber >= image.getHeight() {
+        return None;
+    }
+    let image = Oriented { image, upsideDown };
+    let rowNumber = if upsideDown {
+        image.getHeight() - 1 - rowNumber
+    } else {
+        rowNumber
+    };
+    let (left, top, right, bottom) = locateComponent(&image, rowNumber, linear)?;
+
+    // the separator pattern of GS1 DataBar symbols sits right below the 2D component
+    let mut separatorTop = bottom - 1;
+    while separatorTop > top && rowsMatch(&image, left, right, separatorTop - 1, bottom - 1) {
+        separatorTop -= 1;
+    }
+    [bottom, separatorTop]
+        .into_iter()
+        .filter(|bottom| bottom - top >= 4)
+        .find_map(|bottom| decodeComponent(&cutOut(&image, left, top, right, bottom)?))
+}
+
+/**
+ * The binarized image the way up the linear component was read in. Only the pixels that are
+ * looked at are turned, rather than the whole image.
+ */
+struct Oriented<'a> {
+    image: &'a BitMatrix,
+    upsideDown: bool,
+}
+
+impl Oriented<'_> {
+    fn getWidth(&self) -> u32 {
+        self.image.getWidth()
+    }
+
+    fn getHeight(&self) -> u32 {
+        self.image.getHeight()
+    }
+
+    fn get(&self, x: u32, y: u32) -> bool {
+        if self.upsideDown {
+            self.image
+                .get(self.getWidth() - 1 - x, self.getHeight() - 1 - y)
+        } else {
+            self.image.get(x, y)
+        }
+    }
+}
+
+/**
+ * Finds the rows right above the linear component that are not blank, up to the first blank
+ * row. Only the columns of the linear component and its quiet zones are looked at.
+ *
+ * @return the left, top, right and bottom edge of the area, right and bottom exclusive
+ */
+fn locateComponent(
+    image: &Oriented,
+    rowNumber: u32,
+    linear: &RXingResult,
+) -> Option<(u32, u32, u32, u32)> {
+    let (left, right, moduleWidth) = linearExtent(linear)?;
+    if right < left + 8.0 || rowNumber >= image.getHeight() {
+        return None;
+    }
+    let overhang = OVERHANG * moduleWidth;
+    let areaLeft = (left - overhang).max(0.0) as u32;
+    let areaRight = ((right + overhang) as u32).min(image.getWidth());
+    let (symbolLeft, symbolRight) = (left.max(0.0) as u32, (right as u32).min(image.getWidth()));
+
+    // go up to the top of the linear component
+    let mut y = rowNumber;
+    while y > 0 && rowsMatch(image, symbolLeft, symbolRight, y - 1, rowNumber) {
+        y -= 1;
+    }
+    let linearTop = y;
+
+    // at most a separator pattern and a small gap lie between both components
+    let maxGap = (2.0 * moduleWidth).max(2.0) as u32;
+    let isBlank = |y: u32| (areaLeft..areaRight).all(|x| !image.get(x, y));
+    while y > 0 && isBlank(y - 1) {
+        y -= 1;
+        if linearTop - y > maxGap {
+            return None;
+        }
+    }
+    let bottom = y;
+    while y > 0 && !isBlank(y - 1) {
+        y -= 1;
+    }
+    if bottom - y < 4 {
+        return None;
+    }
+    Some((areaLeft, y, areaRight, bottom))
+}
+
+/**
+ * Estimates where the linear component starts and ends from its result points.
+ *
+ * @return the left and right edge and the module width, in pixels
+ */
+fn linearExtent(linear: &RXingResult) -> Option<(f32, f32, f32)> {
+    let points = linear.getPoints();
+    let minX = points.iter().map(|point| point.x).reduce(f32::min)?;
+    let maxX = points.iter().map(|point| point.x).reduce(f32::max)?;
+    let (moduleWidth, outside) = match linear.getBarcodeFormat() {
+        BarcodeFormat::CODE_128 => {
+            // the points are the middles of the start and the stop pattern, and every code after
+            // the start code is 11 modules wide
+            let codes = linear.getRawBytes().len().checked_sub(1)?.max(1);
+            ((maxX - minX) / (11 * codes) as f32, 6.5)
+        }
+        // the points are the middles of the start and the end guard
+        BarcodeFormat::EAN_8 => ((maxX - minX) / 64.0, 1.5),
+        BarcodeFormat::EAN_13 | BarcodeFormat::UPC_A => ((maxX - minX) / 92.0, 1.5),
+        BarcodeFormat::UPC_E => ((maxX - minX) / 46.5, 3.0),
+        // the points are the edges of the symbol after the space of the left guard
+        BarcodeFormat::RSS_LIMITED => ((maxX - minX) / 73.0, 1.0),
+        format => {
+            // the first two points are the edges of the first finder pattern, 15 modules wide,
+            // which comes after the left guard and an outside or check character
+            let finderWidth = (points.get(1)?.x - points.first()?.x).abs();
+            let outside = if format == &BarcodeFormat::RSS_14 {
+                18.0
+            } else {
+                19.0
+            };
+            (finderWidth / 15.0, outside)
+        }
+    };
+    if moduleWidth <= 0.0 {
+        return None;
+    }
+    Some((
+        minX - outside * moduleWidth,
+        maxX + outside * moduleWidth,
+        moduleWidth,
+    ))
+}
+
+/**
+ * @return true if at most an eighth of the pixels between left and right differ in both rows
+ */
+fn rowsMatch(image: &Oriented, left: u32, right: u32, y1: u32, y2: u32) -> bool {
+    let differences = (left..right)
+        .filter(|x| image.get(*x, y1) != image.get(*x, y2))
+        .count() as u32;
+    differences * 8 <= right - left
+}
+
+/**
+ * @return the area of the image, surrounded by a quiet zone
+ */
+fn cutOut(image: &Oriented, left: u32, top: u32, right: u32, bottom: u32) -> Option<BitMatrix> {
+    let mut bits =
+        BitMatrix::new(right - left + 2 * QUIET_ZONE, bottom - top + 2 * QUIET_ZONE).ok()?;
+    for y in top..bottom {
+        for x in left..right {
+            if image.get(x, y) {
+                bits.set(x - left + QUIET_ZONE, y - top + QUIET_ZONE);
+            }
+        }
+    }
+    Some(bits)
+}
+
+/**
+ * Decodes the 2D component, trying CC-B, CC-A and CC-C in turn.
+ *
+ * @return the element strings of the component
+ */
+fn decodeComponent(bits: &BitMatrix) -> Option<String> {
+    let isGS1 = |result: &RXingResult| {
+        matches!(
+            result
+                .getRXingResultMetadata()
+                .get(&RXingResultMetadataType::CONTENT_TYPE),
+            Some(RXingResultMetadataValue::ContentType(contentType)) if contentType == "GS1"
+        )
+    };
+
+    if let Ok(result) = MicroPDF417Reader::decodeMatrix(bits, 0) {
+        if isGS1(&result) {
+            return Some(result.getText().to_owned());
+        }
+    }
+    if let Some([left, top, width, height]) = bits.getEnclosingRectangle() {
+        if let Ok(result) = micro_pdf_417_decoder::decodeCompositeA(bits, left, top, width, height)
+        {
+            return Some(result.getText().to_owned());
+        }
+    }
+    PDF417Reader::decodeBitMatrix(bits)
+        .ok()
+        .filter(isGS1)
+        .map(|result| result.getText().to_owned())
+}
@@ -0,0 +1,459 @@
+/**
+ * Tests reading GS1 Composite symbols, a linear component with a CC-A, CC-B or CC-C component
+ * printed above it.
+ */
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    common::{bit_matrix_test_case, BitMatrix},
+    oned::{
+        code_128_reader::CODE_PATTERNS,
+        rss::{expanded::RSSExpandedWriter, RSS14Writer},
+    },
+    pdf417::{
+        encoder::{pdf_417_error_correction, BarcodeMatrix, MicroPDF417, PDF417},
+        micro_pdf_417_common::{self, ROW_HEIGHT},
+    },
+    BarcodeFormat, DecodeHintType, DecodeHintValue, EncodeHintType, EncodeHintValue,
+    MultiFormatReader, MultiFormatWriter, RXingResult, RXingResultMetadataType,
+    RXingResultMetadataValue, Reader, Writer,
+};
+
+/// Pixels per module of the test images
+const SCALE: u32 = 3;
+/// Quiet zone around the symbol, in modules
+const QUIET_ZONE: u32 = 10;
+const PDF417_ROW_HEIGHT: usize = 3;
+
+fn encodeLinear(contents: &str, format: BarcodeFormat) -> BitMatrix {
+    let hints = HashMap::from([(
+        EncodeHintType::MARGIN,
+        EncodeHintValue::Margin("0".to_owned()),
+    )]);
+    MultiFormatWriter
+        .encode_with_hints(contents, &format, 0, 33, &hints)
+        .expect("must encode")
+}
+
+/**
+ * Draws the GS1-128 linear component of a symbol with a CC-C component: the data in code set C
+ * followed by a CODE B character, the linkage flag.
+ */
+fn encodeLinkedGS1128(digits: &str) -> BitMatrix {
+    // START C, FNC1
+    let mut codewords = vec![105, 102];
+    codewords.extend(
+        digits
+            .as_bytes()
+            .chunks(2)
+            .map(|pair| ((pair[0] - b'0') * 10 + pair[1] - b'0') as usize),
+    );
+    codewords.push(100);
+    let checksum = codewords
+        .iter()
+        .enumerate()
+        .map(|(i, codeword)| i.max(1) * codeword)
+        .sum::<usize>()
+        % 103;
+    codewords.extend([checksum, 106]);
+
+    let widths = codewords
+        .iter()
+        .flat_map(|codeword| CODE_PATTERNS[*codeword].iter().copied())
+        .collect::<Vec<_>>();
+    let mut matrix = BitMatrix::new(widths.iter().sum(), 33).unwrap();
+    let mut x = 0;
+    for (i, width) in widths.iter().enumerate() {
+        if i % 2 == 0 {
+            matrix.setRegion(x, 0, *width, 33).unwrap();
+        }
+        x += width;
+    }
+    matrix
+}
+
+/**
+ * Encodes digits and FNC1s ('F') in the numeric encodation of the general purpose data field.
+ */
+fn numeric(data: &str) -> String {
+    let value = |c: char| c.to_digit(10).unwrap_or(10);
+    let chars = data.chars().collect::<Vec<_>>();
+    chars
+        .chunks(2)
+        .map(|pair| {
+            let second = pair.get(1).copied().map_or(10, value);
+            format!("{:07b}", 11 * value(pair[0]) + second + 8)
+        })
+        .collect()
+}
+
+/**
+ * Pads a bit stream ending in numeric encodation to the given size.
+ */
+fn pad(bits: &str, size: usize) -> String {
+    bits.chars()
+        .chain("0000".chars())
+        .chain("00100".chars().cycle())
+        .take(size)
+        .collect()
+}
+
+fn codewordString(codewords: &[u32]) -> String {
+    codewords
+        .iter()
+        .filter_map(|codeword| char::from_u32(*codeword))
+        .collect()
+}
+
+/**
+ * @return the rows of modules, top down, each drawn rowHeight times
+ */
+fn moduleRows(matrix: &BarcodeMatrix, rowHeight: usize) -> Vec<Vec<u8>> {
+    let mut rows = matrix.getScaledMatrix(1, rowHeight);
+    rows.reverse();
+    rows
+}
+
+fn encodeCompositeA(bits: &str, columns: u32, rows: u32) -> Vec<Vec<u8>> {
+    let variant = micro_pdf_417_common::getCompositeAVariant(columns, rows).unwrap();
+    let groups = (0..variant.getDataCapacity() as usize)
+        .collect::<Vec<_>>()
+        .chunks(7)
+        .map(<[usize]>::len)
+        .collect::<Vec<_>>();
+    let bits = pad(bits, groups.iter().map(|group| 10 * group - 1).sum());
+
+    let mut codewords = Vec::new();
+    let mut position = 0;
+    for group in groups {
+        let size = 10 * group - 1;
+        let mut value = u128::from_str_radix(&bits[position..position + size], 2).unwrap();
+        position += size;
+        let start = codewords.len();
+        for _ in 0..group {
+            codewords.insert(start, (value % 928) as u32);
+            value /= 928;
+        }
+    }
+    let data = codewordString(&codewords);
+    let ec = pdf_417_error_correction::generateErrorCorrectionCodewords(
+        &data,
+        variant.getErrorCorrectionCodewords(),
+    )
+    .unwrap();
+
+    let fullCodewords = data
+        .chars()
+        .chain(ec.chars())
+        .map(|c| c as u32)
+        .collect::<Vec<_>>();
+    let mut matrix = BarcodeMatrix::with_row_width(rows as usize, variant.getRowWidth() as usize);
+    MicroPDF417::encodeLowLevel(&fullCodewords, variant, &mut matrix).unwrap();
+    let mut rows = moduleRows(&matrix, ROW_HEIGHT as usize);
+    if columns == 3 {
+        // unlike MicroPDF417, there is no left row address pattern
+        for row in &mut rows {
+            row.drain(..micro_pdf_417_common::MODULES_IN_RAP as usize);
+        }
+    }
+    rows
+}
+
+/**
+ * @return the codewords of the bit stream of a CC-B or CC-C component, which is byte compacted
+ *  after the linkage codeword
+ */
+fn linkedCodewords(bits: &str) -> Vec<u32> {
+    let bytes = bits
+        .as_bytes()
+        .chunks(8)
+        .map(|byte| u8::from_str_radix(std::str::from_utf8(byte).unwrap(), 2).unwrap())
+        .collect::<Vec<_>>();
+    let mut codewords = vec![920, if bytes.len() % 6 == 0 { 924 } else { 901 }];
+    let groups = bytes.chunks_exact(6);
+    let rest = groups.remainder();
+    for group in groups {
+        let mut value = group
+            .iter()
+            .fold(0u64, |value, byte| value << 8 | *byte as u64);
+        let start = codewords.len();
+        for _ in 0..5 {
+            codewords.insert(start, (value % 900) as u32);
+            value /= 900;
+        }
+    }
+    codewords.extend(rest.iter().map(|byte| *byte as u32));
+    codewords
+}
+
+fn encodeCompositeB(bits: &str, columns: u32, rows: u32) -> Vec<Vec<u8>> {
+    let variant = micro_pdf_417_common::getVariant(columns, rows).unwrap();
+    let mut codewords = linkedCodewords(bits);
+    codewords.resize(variant.getDataCapacity() as usize, 900);
+    let data = codewordString(&codewords);
+    let ec = pdf_417_error_correction::generateErrorCorrectionCodewords(
+        &data,
+        variant.getErrorCorrectionCodewords(),
+    )
+    .unwrap();
+
+    let fullCodewords = data
+        .chars()
+        .chain(ec.chars())
+        .map(|c| c as u32)
+        .collect::<Vec<_>>();
+    let mut matrix = BarcodeMatrix::with_row_width(rows as usize, variant.getRowWidth() as usize);
+    MicroPDF417::encodeLowLevel(&fullCodewords, variant, &mut matrix).unwrap();
+    moduleRows(&matrix, ROW_HEIGHT as usize)
+}
+
+fn encodeCompositeC(
+    bits: &str,
+    columns: u32,
+    rows: u32,
+    errorCorrectionLevel: u32,
+) -> Vec<Vec<u8>> {
+    let ecCount = 2 << errorCorrectionLevel;
+    let dataCount = (columns * rows - ecCount) as usize;
+    let mut codewords = vec![dataCount as u32];
+    codewords.extend(linkedCodewords(bits));
+    codewords.resize(dataCount, 900);
+    let data = codewordString(&codewords);
+    let ec =
+        pdf_417_error_correction::generateErrorCorrection(&data, errorCorrectionLevel).unwrap();
+
+    let mut matrix = BarcodeMatrix::new(rows as usize, columns as usize, false);
+    PDF417::new()
+        .encodeLowLevel(
+            &format!("{data}{ec}"),
+            columns,
+            rows,
+            errorCorrectionLevel,
+            &mut matrix,
+        )
+        .unwrap();
+    moduleRows(&matrix, PDF417_ROW_HEIGHT)
+}
+
+/**
+ * Puts the 2D component above the linear one, followed by a gap or the separator pattern of
+ * GS1 DataBar symbols.
+ *
+ * @param offset the position of the 2D component relative to the linear one, in modules
+ */
+fn compose(component: &[Vec<u8>], offset: u32, linear: &BitMatrix, separator: bool) -> BitMatrix {
+    let componentWidth = component[0].len() as u32;
+    let componentHeight = component.len() as u32;
+    let width = linear.getWidth().max(offset + componentWidth) + 2 * QUIET_ZONE;
+    let linearTop = QUIET_ZONE + componentHeight + if separator { 1 } else { 2 };
+    let height = linearTop + linear.getHeight() + QUIET_ZONE;
+    let mut modules = BitMatrix::new(width, height).unwrap();
+
+    for (y, row) in component.iter().enumerate() {
+        for (x, module) in row.iter().enumerate() {
+            if *module == 1 {
+                modules.set(QUIET_ZONE + offset + x as u32, QUIET_ZONE + y as u32);
+            }
+        }
+    }
+    if separator {
+        let [left, _, symbolWidth, _] = linear.getEnclosingRectangle().unwrap();
+        for x in left..left + symbolWidth {
+            if !linear.get(x, 0) {
+                modules.set(QUIET_ZONE + x, linearTop - 1);
+            }
+        }
+    }
+    for y in 0..linear.getHeight() {
+        for x in 0..linear.getWidth() {
+            if linear.get(x, y) {
+                modules.set(QUIET_ZONE + x, linearTop + y);
+            }
+        }
+    }
+
+    let mut image = BitMatrix::new(width * SCALE, height * SCALE).unwrap();
+    for y in 0..height {
+        for x in 0..width {
+            if modules.get(x, y) {
+                image.setRegion(x * SCALE, y * SCALE, SCALE, SCALE).unwrap();
+            }
+        }
+    }
+    image
+}
+
+fn read(image: &BitMatrix, format: BarcodeFormat) -> RXingResult {
+    let mut bitmap = bit_matrix_test_case::matrix_to_binary_bitmap(image);
+    let hints = HashMap::from([(
+        DecodeHintType::POSSIBLE_FORMATS,
+        DecodeHintValue::PossibleFormats(HashSet::from([format])),
+    )]);
+    let result = MultiFormatReader::default()
+        .decode_with_hints(&mut bitmap, &hints)
+        .expect("must decode");
+    assert_eq!(&format, result.getBarcodeFormat());
+    result
+}
+
+fn isComposite(result: &RXingResult) -> bool {
+    result
+        .getRXingResultMetadata()
+        .get(&RXingResultMetadataType::IS_GS1_COMPOSITE)
+        == Some(&RXingResultMetadataValue::IsGs1Composite(true))
+}
+
+fn compositeComponent(result: &RXingResult) -> Option<&str> {
+    match result
+        .getRXingResultMetadata()
+        .get(&RXingResultMetadataType::GS1_COMPOSITE_COMPONENT)
+    {
+        Some(RXingResultMetadataValue::Gs1CompositeComponent(component)) => Some(component),
+        _ => None,
+    }
+}
+
+#[test]
+fn testCompositeA() {
+    let linear = RSS14Writer::encodeLinked("0950110153000", 33).unwrap();
+    // encodation method 10 with the date of AI 17 and a batch number
+    let date = format!("{:016b}", 26 * 384 + (12 - 1) * 32 + 31);
+    let bits = format!("10{date}1{}", numeric("4567"));
+
+    for (columns, rows) in [(4, 4), (3, 5), (2, 7)] {
+        let component = encodeCompositeA(&bits, columns, rows);
+        let result = read(
+            &compose(&component, 2, &linear, true),
+            BarcodeFormat::RSS_14,
+        );
+        assert_eq!("09501101530003", result.getText());
+        assert_eq!(
+            Some("(17)261231(10)4567"),
+            compositeComponent(&result),
+            "{columns}x{rows}"
+        );
+        assert!(isComposite(&result));
+    }
+
+    // without the 2D component only the linear one is read, which still tells it is linked
+    let result = read(
+        &compose(&[vec![0]], 0, &linear, false),
+        BarcodeFormat::RSS_14,
+    );
+    assert_eq!("09501101530003", result.getText());
+    assert!(isComposite(&result));
+    assert_eq!(None, compositeComponent(&result));
+}
+
+#[test]
+fn testUnlinked() {
+    // without the linkage flag, a 2D component above the linear one is not looked for
+    let linear = encodeLinear("0950110153000", BarcodeFormat::RSS_14);
+    let date = format!("{:016b}", 26 * 384 + (12 - 1) * 32 + 31);
+    let component = encodeCompositeA(&format!("10{date}1{}", numeric("4567")), 4, 4);
+    let result = read(
+        &compose(&component, 2, &linear, true),
+        BarcodeFormat::RSS_14,
+    );
+    assert_eq!("09501101530003", result.getText());
+    assert!(!isComposite(&result));
+    assert_eq!(None, compositeComponent(&result));
+}
+
+#[test]
+fn testComponentBesideLinear() {
+    // a symbol next to the linear component, rather than above it, is not taken as its component
+    let linear = RSS14Writer::encodeLinked("0950110153000", 33).unwrap();
+    let date = format!("{:016b}", 26 * 384 + (12 - 1) * 32 + 31);
+    let component = encodeCompositeA(&format!("10{date}1{}", numeric("4567")), 4, 4);
+    let result = read(
+        &compose(&component, linear.getWidth() + 12, &linear, false),
+        BarcodeFormat::RSS_14,
+    );
+    assert_eq!("09501101530003", result.getText());
+    assert_eq!(None, compositeComponent(&result));
+}
+
+#[test]
+fn testCompositeB() {
+    let linear = RSSExpandedWriter::encodeLinked("(01)09501101530003(3103)000123", 33).unwrap();
+    // general purpose encodation of (21)12345678 and (10)42
+    let bits = pad(&format!("0{}", numeric("2112345678F1042")), 64);
+    let component = encodeCompositeB(&bits, 2, 11);
+
+    let result = read(
+        &compose(&component, 1, &linear, true),
+        BarcodeFormat::RSS_EXPANDED,
+    );
+    assert_eq!("(01)09501101530003(3103)000123", result.getText());
+    assert_eq!(Some("(21)12345678(10)42"), compositeComponent(&result));
+    assert!(isComposite(&result));
+}
+
+#[test]
+fn testCompositeC() {
+    let linear = encodeLinkedGS1128("0109501101530003");
+    // encodation method 10 without a date, a batch number and (21)55
+    let bits = pad(&format!("1011{}", numeric("9876F2155")), 48);
+    let component = encodeCompositeC(&bits, 2, 8, 2);
+
+    let result = read(
+        &compose(&component, 10, &linear, false),
+        BarcodeFormat::CODE_128,
+    );
+    assert_eq!("0109501101530003", result.getText());
+    assert_eq!(Some("(10)9876(21)55"), compositeComponent(&result));
+    assert!(isComposite(&result));
+    assert_eq!(
+        Some(&RXingResultMetadataValue::SymbologyIdentifier(
+            "]C1".to_owned()
+        )),
+        result
+            .getRXingResultMetadata()
+            .get(&RXingResultMetadataType::SYMBOLOGY_IDENTIFIER)
+    );
+
+    // a plain GS1-128 symbol carries no linkage flag
+    let linear = encodeLinear("\u{f1}0109501101530003", BarcodeFormat::CODE_128);
+    let result = read(
+        &compose(&component, 10, &linear, false),
+        BarcodeFormat::CODE_128,
+    );
+    assert_eq!("0109501101530003", result.getText());
+    assert!(!isComposite(&result));
+    assert_eq!(None, compositeComponent(&result));
+}
+
+#[test]
+fn testUpsideDown() {
+    let date = format!("{:016b}", 26 * 384 + (12 - 1) * 32 + 31);
+    let rss14 = compose(
+        &encodeCompositeA(&format!("10{date}1{}", numeric("4567")), 4, 4),
+        2,
+        &RSS14Writer::encodeLinked("0950110153000", 33).unwrap(),
+        true,
+    );
+    let expanded = compose(
+        &encodeCompositeB(&pad(&format!("0{}", numeric("2112345678F1042")), 64), 2, 11),
+        1,
+        &RSSExpandedWriter::encodeLinked("(01)09501101530003(3103)000123", 33).unwrap(),
+        true,
+    );
+    let gs1128 = compose(
+        &encodeCompositeC(&pad(&format!("1011{}", numeric("9876F2155")), 48), 2, 8, 2),
+        10,
+        &encodeLinkedGS1128("0109501101530003"),
+        false,
+    );
+    for (mut image, format, expected) in [
+        (rss14, BarcodeFormat::RSS_14, "(17)261231(10)4567"),
+        (expanded, BarcodeFormat::RSS_EXPANDED, "(21)12345678(10)42"),
+        (gs1128, BarcodeFormat::CODE_128, "(10)9876(21)55"),
+    ] {
+        // the 2D component is below the linear one, turned upside down
+        image.rotate180();
+        let result = read(&image, format);
+        assert_eq!(Some(expected), compositeComponent(&result), "{format:?}");
+        assert!(isComposite(&result));
+    }
+}
@@ -117,6 +117,14 @@ impl OneDimensionalCodeWriter for EAN13Writer {
         Ok(result.to_vec())
     }
 
+    fn encode_oned_with_hints(
+        &self,
+        contents: &str,
+        hints: &crate::EncodingHintDictionary,
+    ) -> Result<Vec<bool>> {
+        self.encode_oned_with_extension(contents, hints)
+    }
+
     fn getSupportedWriteFormats(&self) -> Option<Vec<crate::BarcodeFormat>> {
         Some(vec![BarcodeFormat::EAN_13])
     }
@@ -139,7 +147,15 @@ const CODE_WIDTH: usize = 3 + // start guard
  */
 #[cfg(test)]
 mod EAN13WriterTestCase {
-    use crate::{common::bit_matrix_test_case, BarcodeFormat, Writer};
+    use std::collections::{HashMap, HashSet};
+
+    use crate::{
+        common::{bit_matrix_test_case, BitMatrix, HybridBinarizer},
+        oned::UPCAWriter,
+        BarcodeFormat, BinaryBitmap, DecodeHintType, DecodeHintValue, EncodeHintType,
+        EncodeHintValue, Luma8LuminanceSource, MultiFormatReader, RXingResult,
+        RXingResultMetadataType, RXingResultMetadataValue, Reader, Writer,
+    };
 
     use super::EAN13Writer;
 
@@ -173,6 +189,73 @@ mod EAN13WriterTestCase {
         assert_eq!(testStr, bit_matrix_test_case::matrix_to_string(&result));
     }
 
+    fn decode(matrix: &BitMatrix, format: BarcodeFormat) -> RXingResult {
+        let height = 20;
+        let luma = (0..height)
+            .flat_map(|_| 0..matrix.getWidth())
+            .map(|x| if matrix.get(x, 0) { 0 } else { u8::MAX })
+            .collect();
+        let mut image = BinaryBitmap::new(HybridBinarizer::new(Luma8LuminanceSource::new(
+            luma,
+            matrix.getWidth(),
+            height,
+        )));
+        let hints = HashMap::from([(
+            DecodeHintType::POSSIBLE_FORMATS,
+            DecodeHintValue::PossibleFormats(HashSet::from([format])),
+        )]);
+        MultiFormatReader::default()
+            .decode_with_hints(&mut image, &hints)
+            .expect("decode")
+    }
+
+    fn extension(result: &RXingResult) -> Option<&RXingResultMetadataValue> {
+        result
+            .getRXingResultMetadata()
+            .get(&RXingResultMetadataType::UPC_EAN_EXTENSION)
+    }
+
+    #[test]
+    fn testEncodeExtension() {
+        let width = 400;
+        // ISBN with price add-on, given in the contents or as hint
+        let hints = HashMap::from([(
+            EncodeHintType::UPC_EAN_EXTENSION,
+            EncodeHintValue::UpcEanExtension("51299".to_owned()),
+        )]);
+        for matrix in [
+            EAN13Writer.encode("9780201379624+51299", &BarcodeFormat::EAN_13, width, 0),
+            EAN13Writer.encode("9780201379624 51299", &BarcodeFormat::EAN_13, width, 0),
+            EAN13Writer.encode_with_hints("978020137962", &BarcodeFormat::EAN_13, width, 0, &hints),
+        ] {
+            let result = decode(&matrix.expect("encode"), BarcodeFormat::EAN_13);
+            assert_eq!("9780201379624", result.getText());
+            assert_eq!(
+                Some(&RXingResultMetadataValue::UpcEanExtension(
+                    "51299".to_owned()
+                )),
+                extension(&result)
+            );
+        }
+
+        // periodical issue number on UPC-A
+        let matrix = UPCAWriter::default()
+            .encode("03600029145+07", &BarcodeFormat::UPC_A, width, 0)
+            .expect("encode");
+        let result = decode(&matrix, BarcodeFormat::UPC_A);
+        assert_eq!("036000291452", result.getText());
+        assert_eq!(
+            Some(&RXingResultMetadataValue::UpcEanExtension("07".to_owned())),
+            extension(&result)
+        );
+
+        for illegal in ["9780201379624+123", "9780201379624+1a"] {
+            assert!(EAN13Writer
+                .encode(illegal, &BarcodeFormat::EAN_13, 0, 0)
+                .is_err());
+        }
+    }
+
     #[test]
     #[should_panic]
     fn testEncodeIllegalCharacters() {
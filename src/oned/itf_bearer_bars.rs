@@ -0,0 +1,17 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/**
+ * The bearer bars drawn around ITF symbols, which protect them from partial scans when printed
+ * on corrugated board.
+ */
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ITFBearerBars {
+    /** No bearer bars */
+    NONE,
+    /** A box around the symbol and its quiet zones */
+    BOX,
+    /** Bars above and below the symbol and its quiet zones */
+    TOP_AND_BOTTOM,
+}
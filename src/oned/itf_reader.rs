@@ -21,7 +21,7 @@ use crate::{
     point_f, BarcodeFormat, DecodeHintValue, Exceptions, RXingResult,
 };
 
-use super::{one_d_reader, OneDReader};
+use super::{one_d_reader, OneDReader, UPCEANReader, STAND_IN};
 
 const MAX_AVG_VARIANCE: f32 = 0.38;
 const MAX_INDIVIDUAL_VARIANCE: f32 = 0.5;
@@ -84,6 +84,9 @@ const PATTERNS: [[u32; 5]; 20] = [
  * <p>The checksum is optional and is not applied by this Reader. The consumer of the decoded
  * value will have to apply a checksum if required.</p>
  *
+ * <p>With {@link DecodeHintType#ASSUME_ITF_14} only ITF-14 symbols are read: 14 digits whose
+ * GS1 check digit is verified. Their content type is then "GTIN-14".</p>
+ *
  * <p><a href="http://en.wikipedia.org/wiki/Interleaved_2_of_5">http://en.wikipedia.org/wiki/Interleaved_2_of_5</a>
  * is a great reference for Interleaved 2 of 5 information.</p>
  *
@@ -146,6 +149,19 @@ impl OneDReader for ITFReader {
             return Err(Exceptions::FORMAT);
         }
 
+        let itf14 = matches!(
+            hints.get(&DecodeHintType::ASSUME_ITF_14),
+            Some(DecodeHintValue::AssumeItf14(true))
+        );
+        if itf14 {
+            if length != 14 {
+                return Err(Exceptions::FORMAT);
+            }
+            if !STAND_IN.checkStandardUPCEANChecksum(&resultString)? {
+                return Err(Exceptions::CHECKSUM);
+            }
+        }
+
         let mut resultObject = RXingResult::new(
             &resultString,
             Vec::new(), // no natural byte representation for these barcodes
@@ -158,8 +174,16 @@ impl OneDReader for ITFReader {
 
         resultObject.putMetadata(
             RXingResultMetadataType::SYMBOLOGY_IDENTIFIER,
-            RXingResultMetadataValue::SymbologyIdentifier("]I0".to_owned()),
+            RXingResultMetadataValue::SymbologyIdentifier(
+                if itf14 { "]I1" } else { "]I0" }.to_owned(),
+            ),
         );
+        if itf14 {
+            resultObject.putMetadata(
+                RXingResultMetadataType::CONTENT_TYPE,
+                RXingResultMetadataValue::ContentType("GTIN-14".to_owned()),
+            );
+        }
 
         Ok(resultObject)
     }
@@ -406,3 +430,83 @@ impl ITFReader {
         }
     }
 }
+
+#[cfg(test)]
+mod ITFReaderTestCase {
+    use std::collections::{HashMap, HashSet};
+
+    use crate::{
+        common::bit_matrix_test_case,
+        oned::{ITFReader, ITFWriter, OneDReader},
+        BarcodeFormat, DecodeHintType, DecodeHintValue, EncodeHintType, EncodeHintValue,
+        Exceptions, MultiFormatReader, RXingResultMetadataType, RXingResultMetadataValue, Reader,
+        Writer,
+    };
+
+    #[test]
+    fn testDecodeItf14() {
+        let image = ITFWriter
+            .encode_with_hints(
+                "0012345678901",
+                &BarcodeFormat::ITF,
+                400,
+                100,
+                &HashMap::from([(EncodeHintType::ITF_14, EncodeHintValue::Itf14(true))]),
+            )
+            .expect("must encode");
+        let mut bitmap = bit_matrix_test_case::matrix_to_binary_bitmap(&image);
+
+        let hints = HashMap::from([
+            (
+                DecodeHintType::POSSIBLE_FORMATS,
+                DecodeHintValue::PossibleFormats(HashSet::from([BarcodeFormat::ITF])),
+            ),
+            (
+                DecodeHintType::ASSUME_ITF_14,
+                DecodeHintValue::AssumeItf14(true),
+            ),
+        ]);
+        let result = MultiFormatReader::default()
+            .decode_with_hints(&mut bitmap, &hints)
+            .expect("must decode");
+        assert_eq!("00123456789012", result.getText());
+        assert_eq!(
+            Some(&RXingResultMetadataValue::SymbologyIdentifier(
+                "]I1".to_owned()
+            )),
+            result
+                .getRXingResultMetadata()
+                .get(&RXingResultMetadataType::SYMBOLOGY_IDENTIFIER)
+        );
+        assert_eq!(
+            Some(&RXingResultMetadataValue::ContentType("GTIN-14".to_owned())),
+            result
+                .getRXingResultMetadata()
+                .get(&RXingResultMetadataType::CONTENT_TYPE)
+        );
+    }
+
+    #[test]
+    fn testBadItf14() {
+        let hints = HashMap::from([(
+            DecodeHintType::ASSUME_ITF_14,
+            DecodeHintValue::AssumeItf14(true),
+        )]);
+        let decode = |contents| {
+            let matrix = ITFWriter
+                .encode(contents, &BarcodeFormat::ITF, 0, 0)
+                .expect("must encode");
+            ITFReader::default().decode_row(0, &matrix.getRow(0), &hints)
+        };
+
+        assert!(decode("00123456789012").is_ok());
+        assert!(matches!(
+            decode("00123456789013"),
+            Err(Exceptions::ChecksumException(_))
+        ));
+        assert!(matches!(
+            decode("0012345678901234"),
+            Err(Exceptions::FormatException(_))
+        ));
+    }
+}
@@ -14,22 +14,121 @@
  * limitations under the License.
  */
 
-use rxing_one_d_proc_derive::OneDWriter;
+use std::collections::HashMap;
 
-use crate::common::Result;
-use crate::BarcodeFormat;
+use crate::{
+    common::{BitMatrix, Result},
+    BarcodeFormat, EncodeHintType, EncodeHintValue, Exceptions, Writer,
+};
 
-use super::OneDimensionalCodeWriter;
+use super::{ITFBearerBars, OneDimensionalCodeWriter, UPCEANReader, STAND_IN};
 
 /**
  * This object renders a ITF code as a {@link BitMatrix}.
  *
+ * <p>With {@link EncodeHintType#ITF_14} an ITF-14 shipping container code is rendered, its
+ * check digit computed if missing. Bearer bars are drawn as told by
+ * {@link EncodeHintType#ITF_BEARER_BARS}.</p>
+ *
  * @author erik.barbara@gmail.com (Erik Barbara)
  */
-#[derive(OneDWriter, Default)]
+#[derive(Default)]
 pub struct ITFWriter;
 
+impl Writer for ITFWriter {
+    fn encode(
+        &self,
+        contents: &str,
+        format: &BarcodeFormat,
+        width: i32,
+        height: i32,
+    ) -> Result<BitMatrix> {
+        self.encode_with_hints(contents, format, width, height, &HashMap::new())
+    }
+
+    fn encode_with_hints(
+        &self,
+        contents: &str,
+        format: &BarcodeFormat,
+        width: i32,
+        height: i32,
+        hints: &crate::EncodingHintDictionary,
+    ) -> Result<BitMatrix> {
+        if contents.is_empty() {
+            return Err(Exceptions::illegal_argument_with("Found empty contents"));
+        }
+
+        if format != &BarcodeFormat::ITF {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "Can only encode ITF, but got {format:?}"
+            )));
+        }
+
+        if width < 0 || height < 0 {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "Negative size is not allowed. Input: {width}x{height}"
+            )));
+        }
+
+        let mut sidesMargin = self.getDefaultMargin();
+        if let Some(EncodeHintValue::Margin(margin)) = hints.get(&EncodeHintType::MARGIN) {
+            sidesMargin = margin.parse::<u32>().map_err(|e| {
+                Exceptions::illegal_argument_with(format!("couldnt parse {margin}: {e}"))
+            })?;
+        }
+
+        let itf14 = matches!(
+            hints.get(&EncodeHintType::ITF_14),
+            Some(EncodeHintValue::Itf14(true))
+        );
+        let bearerBars = match hints.get(&EncodeHintType::ITF_BEARER_BARS) {
+            Some(EncodeHintValue::ItfBearerBars(bearerBars)) => *bearerBars,
+            _ if itf14 => ITFBearerBars::BOX,
+            _ => ITFBearerBars::NONE,
+        };
+
+        let code = self.encode_oned_with_hints(contents, hints)?;
+        if bearerBars == ITFBearerBars::NONE {
+            Self::renderRXingResult(&code, width, height, sidesMargin)
+        } else {
+            Self::renderBearerBars(&code, bearerBars, width, height, sidesMargin)
+        }
+    }
+}
+
 impl OneDimensionalCodeWriter for ITFWriter {
+    fn encode_oned_with_hints(
+        &self,
+        contents: &str,
+        hints: &crate::EncodingHintDictionary,
+    ) -> Result<Vec<bool>> {
+        if !matches!(
+            hints.get(&EncodeHintType::ITF_14),
+            Some(EncodeHintValue::Itf14(true))
+        ) {
+            return self.encode_oned(contents);
+        }
+
+        Self::checkNumeric(contents)?;
+        match contents.len() {
+            13 => {
+                let check = STAND_IN.getStandardUPCEANChecksum(contents)?;
+                self.encode_oned(&format!("{contents}{check}"))
+            }
+            14 => {
+                if !STAND_IN.checkStandardUPCEANChecksum(contents)? {
+                    return Err(Exceptions::illegal_argument_with(
+                        "Contents do not pass checksum",
+                    ));
+                }
+                self.encode_oned(contents)
+            }
+            length => Err(Exceptions::illegal_argument_with(format!(
+                "Requested contents should be 13 or 14 digits long for ITF-14, but got {length}"
+            ))),
+        }
+    }
+
     fn encode_oned(&self, contents: &str) -> Result<Vec<bool>> {
         let length = contents.chars().count();
         if length % 2 != 0 {
@@ -79,6 +178,53 @@ impl OneDimensionalCodeWriter for ITFWriter {
     }
 }
 
+impl ITFWriter {
+    /**
+     * Renders the code with quiet zones of {@link #BEARER_QUIET_ZONE} modules, enclosed in bearer
+     * bars {@link #BEARER_BAR_WIDTH} modules thick. The bars of the code are at least as high as
+     * the bearer bars are thick.
+     */
+    fn renderBearerBars(
+        code: &[bool],
+        bearerBars: ITFBearerBars,
+        width: i32,
+        height: i32,
+        sidesMargin: u32,
+    ) -> Result<BitMatrix> {
+        let mut padded =
+            Vec::with_capacity(code.len() + 2 * (BEARER_QUIET_ZONE + BEARER_BAR_WIDTH));
+        let verticalBars = bearerBars == ITFBearerBars::BOX;
+        if verticalBars {
+            padded.resize(BEARER_BAR_WIDTH, true);
+        }
+        padded.resize(padded.len() + BEARER_QUIET_ZONE, false);
+        padded.extend_from_slice(code);
+        padded.resize(padded.len() + BEARER_QUIET_ZONE, false);
+        if verticalBars {
+            padded.resize(padded.len() + BEARER_BAR_WIDTH, true);
+        }
+
+        // same scaling as renderRXingResult
+        let fullWidth = padded.len() as u32 + sidesMargin;
+        let outputWidth = (width as u32).max(fullWidth);
+        let multiple = outputWidth / fullWidth;
+        let thickness = BEARER_BAR_WIDTH as u32 * multiple;
+        let outputHeight = (height as u32).max(3 * thickness);
+
+        let mut output = Self::renderRXingResult(&padded, width, outputHeight as i32, sidesMargin)?;
+        let left = (outputWidth - padded.len() as u32 * multiple) / 2;
+        let barsWidth = padded.len() as u32 * multiple;
+        output.setRegion(left, 0, barsWidth, thickness)?;
+        output.setRegion(left, outputHeight - thickness, barsWidth, thickness)?;
+        Ok(output)
+    }
+}
+
+/// Thickness of bearer bars, in modules
+const BEARER_BAR_WIDTH: usize = 5;
+/// Quiet zones between the code and vertical bearer bars, in modules
+const BEARER_QUIET_ZONE: usize = 10;
+
 const START_PATTERN: [usize; 4] = [1, 1, 1, 1];
 const END_PATTERN: [usize; 3] = [3, 1, 1];
 
@@ -105,7 +251,12 @@ const PATTERNS: [[usize; 5]; 10] = [
  */
 #[cfg(test)]
 mod ITFWriterTestCase {
-    use crate::{common::bit_matrix_test_case, BarcodeFormat, Writer};
+    use std::collections::HashMap;
+
+    use crate::{
+        common::bit_matrix_test_case, oned::ITFBearerBars, BarcodeFormat, EncodeHintType,
+        EncodeHintValue, Writer,
+    };
 
     use super::ITFWriter;
 
@@ -133,4 +284,91 @@ mod ITFWriterTestCase {
             .encode("00123456789abc", &BarcodeFormat::ITF, 0, 0)
             .expect("should fail");
     }
+
+    const ENCODED_00123456789012: &str =
+        "0000010101010111000111000101110100010101110001110111010001010001110100011\
+100010101000101011100011101011101000111000101110100010101110001110100000";
+
+    #[test]
+    fn testEncodeItf14() {
+        let hints = HashMap::from([
+            (EncodeHintType::ITF_14, EncodeHintValue::Itf14(true)),
+            (
+                EncodeHintType::ITF_BEARER_BARS,
+                EncodeHintValue::ItfBearerBars(ITFBearerBars::NONE),
+            ),
+        ]);
+        // the check digit of 0012345678901 is 2
+        for contents in ["0012345678901", "00123456789012"] {
+            let result = ITFWriter
+                .encode_with_hints(contents, &BarcodeFormat::ITF, 0, 0, &hints)
+                .expect("encode");
+            assert_eq!(
+                ENCODED_00123456789012,
+                bit_matrix_test_case::matrix_to_string(&result)
+            );
+        }
+
+        for contents in ["00123456789013", "001234567890", "001234567890123"] {
+            assert!(ITFWriter
+                .encode_with_hints(contents, &BarcodeFormat::ITF, 0, 0, &hints)
+                .is_err());
+        }
+    }
+
+    #[test]
+    fn testEncodeBearerBars() {
+        let code = ENCODED_00123456789012.trim_matches('0');
+        let quietZone = "0".repeat(10);
+
+        // ITF-14 defaults to a box
+        let result = ITFWriter
+            .encode_with_hints(
+                "0012345678901",
+                &BarcodeFormat::ITF,
+                0,
+                0,
+                &HashMap::from([(EncodeHintType::ITF_14, EncodeHintValue::Itf14(true))]),
+            )
+            .expect("encode");
+        let row = |y| {
+            (0..result.getWidth())
+                .map(|x| if result.get(x, y) { '1' } else { '0' })
+                .collect::<String>()
+        };
+        assert_eq!(15, result.getHeight());
+        let bars = format!("00000{}00000", "1".repeat(code.len() + 30));
+        for y in [0, 4, 10, 14] {
+            assert_eq!(bars, row(y));
+        }
+        let middle = format!("0000011111{quietZone}{code}{quietZone}1111100000");
+        for y in [5, 7, 9] {
+            assert_eq!(middle, row(y));
+        }
+
+        let result = ITFWriter
+            .encode_with_hints(
+                "00123456789012",
+                &BarcodeFormat::ITF,
+                0,
+                20,
+                &HashMap::from([(
+                    EncodeHintType::ITF_BEARER_BARS,
+                    EncodeHintValue::ItfBearerBars(ITFBearerBars::TOP_AND_BOTTOM),
+                )]),
+            )
+            .expect("encode");
+        let row = |y| {
+            (0..result.getWidth())
+                .map(|x| if result.get(x, y) { '1' } else { '0' })
+                .collect::<String>()
+        };
+        assert_eq!(20, result.getHeight());
+        assert_eq!(format!("00000{}00000", "1".repeat(code.len() + 20)), row(0));
+        assert_eq!(format!("00000{quietZone}{code}{quietZone}00000"), row(10));
+        assert_eq!(
+            format!("00000{}00000", "1".repeat(code.len() + 20)),
+            row(19)
+        );
+    }
 }
@@ -1,6 +1,11 @@
 mod one_d_reader;
 pub mod rss;
 
+mod composite_decoder;
+
+#[cfg(test)]
+mod composite_decoder_test_case;
+
 pub use one_d_reader::*;
 
 mod ean_manufacturer_org_support;
@@ -18,12 +23,41 @@ pub use multi_format_one_d_reader::*;
 mod code_93_reader;
 pub use code_93_reader::*;
 
+mod code_11_reader;
+pub use code_11_reader::*;
+
 mod code_128_reader;
 pub use code_128_reader::*;
 
+mod stacked_code_128_support;
+
+mod codablock_f_reader;
+pub use codablock_f_reader::*;
+
+mod code_16k_reader;
+pub use code_16k_reader::*;
+
+#[cfg(test)]
+mod stacked_code_128_test_case;
+
 mod itf_reader;
 pub use itf_reader::*;
 
+mod two_of_five_reader;
+pub use two_of_five_reader::*;
+
+mod msi_check_digit;
+pub use msi_check_digit::*;
+
+mod msi_reader;
+pub use msi_reader::*;
+
+mod pharmacode_reader;
+pub use pharmacode_reader::*;
+
+mod telepen_reader;
+pub use telepen_reader::*;
+
 mod upc_ean_reader;
 pub use upc_ean_reader::*;
 
@@ -59,15 +93,48 @@ pub use multi_format_upc_ean_reader::*;
 mod code_39_writer;
 pub use code_39_writer::*;
 
+mod code_32_writer;
+pub use code_32_writer::*;
+
 mod code_93_writer;
 pub use code_93_writer::*;
 
+mod code_11_writer;
+pub use code_11_writer::*;
+
 mod itf_writer;
 pub use itf_writer::*;
 
+mod itf_bearer_bars;
+pub use itf_bearer_bars::*;
+
+mod two_of_five_writer;
+pub use two_of_five_writer::*;
+
+#[cfg(test)]
+mod two_of_five_test_case;
+
+mod msi_writer;
+pub use msi_writer::*;
+
+mod pharmacode_writer;
+pub use pharmacode_writer::*;
+
+mod telepen_writer;
+pub use telepen_writer::*;
+
+#[cfg(test)]
+mod msi_test_case;
+
 mod code_128_writer;
 pub use code_128_writer::*;
 
+mod codablock_f_writer;
+pub use codablock_f_writer::*;
+
+mod code_16k_writer;
+pub use code_16k_writer::*;
+
 #[cfg(test)]
 mod code_128_writer_test_tase;
 
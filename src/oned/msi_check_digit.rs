@@ -0,0 +1,113 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{common::Result, Exceptions};
+
+/**
+ * The check digit schemes of MSI Plessey symbols. The check digits follow the data digits and
+ * are encoded like them.
+ */
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MSICheckDigit {
+    /** No check digit */
+    NONE,
+    /** A Luhn (modulo 10) check digit */
+    MOD_10,
+    /** A modulo 11 check digit with the weights 2 to 7, a remainder of 10 is encoded as "10" */
+    MOD_11,
+    /** Two modulo 10 check digits, the second one also covering the first */
+    MOD_10_10,
+    /** A modulo 11 check digit followed by a modulo 10 check digit covering both */
+    MOD_11_10,
+}
+
+impl MSICheckDigit {
+    /**
+     * @param digits the data digits
+     * @return the data digits followed by their check digits
+     */
+    pub fn append(&self, digits: &str) -> String {
+        let mut result = digits.to_owned();
+        match self {
+            MSICheckDigit::NONE => {}
+            MSICheckDigit::MOD_10 => result.push(mod10(&result)),
+            MSICheckDigit::MOD_11 => result.push_str(&mod11(&result)),
+            MSICheckDigit::MOD_10_10 => {
+                result.push(mod10(&result));
+                result.push(mod10(&result));
+            }
+            MSICheckDigit::MOD_11_10 => {
+                result.push_str(&mod11(&result));
+                result.push(mod10(&result));
+            }
+        }
+        result
+    }
+
+    /**
+     * @param digits the decoded digits, including the check digits
+     * @return the data digits
+     * @throws ChecksumException if the check digits don't match the data digits
+     */
+    pub fn strip(&self, digits: &str) -> Result<String> {
+        let stripped = match self {
+            MSICheckDigit::NONE => Some(digits),
+            MSICheckDigit::MOD_10 => stripMod10(digits),
+            MSICheckDigit::MOD_11 => stripMod11(digits),
+            MSICheckDigit::MOD_10_10 => stripMod10(digits).and_then(stripMod10),
+            MSICheckDigit::MOD_11_10 => stripMod10(digits).and_then(stripMod11),
+        };
+        match stripped {
+            Some(data) if !data.is_empty() => Ok(data.to_owned()),
+            _ => Err(Exceptions::CHECKSUM),
+        }
+    }
+}
+
+/**
+ * Luhn check digit: every other digit is doubled, starting with the rightmost one.
+ */
+fn mod10(digits: &str) -> char {
+    let sum: u32 = digits
+        .bytes()
+        .rev()
+        .enumerate()
+        .map(|(i, c)| {
+            let digit = (c - b'0') as u32;
+            if i % 2 == 0 {
+                let doubled = digit * 2;
+                doubled / 10 + doubled % 10
+            } else {
+                digit
+            }
+        })
+        .sum();
+    (b'0' + ((10 - sum % 10) % 10) as u8) as char
+}
+
+/**
+ * IBM modulo 11 check digit: the weights 2 to 7 repeat from the rightmost digit.
+ */
+fn mod11(digits: &str) -> String {
+    let sum: u32 = digits
+        .bytes()
+        .rev()
+        .enumerate()
+        .map(|(i, c)| (c - b'0') as u32 * (i as u32 % 6 + 2))
+        .sum();
+    ((11 - sum % 11) % 11).to_string()
+}
+
+fn stripMod10(digits: &str) -> Option<&str> {
+    let (data, check) = digits.split_at(digits.len().checked_sub(1)?);
+    check.starts_with(mod10(data)).then_some(data)
+}
+
+fn stripMod11(digits: &str) -> Option<&str> {
+    // a check value of 10 takes two digits
+    [1, 2].into_iter().find_map(|checkLength| {
+        let (data, check) = digits.split_at(digits.len().checked_sub(checkLength)?);
+        (mod11(data) == check).then_some(data)
+    })
+}
@@ -0,0 +1,150 @@
+use rxing_one_d_proc_derive::OneDReader;
+
+use crate::{common::Result, point_f, BarcodeFormat, DecodeHintValue, Exceptions, RXingResult};
+
+use super::{one_d_reader, MSICheckDigit, OneDReader};
+
+const MAX_AVG_VARIANCE: f32 = 0.38;
+const MAX_INDIVIDUAL_VARIANCE: f32 = 0.5;
+
+/// Quiet zone required before the start and after the end pattern, in narrow widths
+const QUIET_ZONE: f32 = 5.0;
+
+/// A wide bar and a narrow space
+const START_PATTERN: [u32; 2] = [2, 1];
+/// A narrow bar, a wide space and a narrow bar
+const END_PATTERN: [u32; 3] = [1, 2, 1];
+/// The bar and space of a 0 bit and of a 1 bit
+const BIT_PATTERNS: [[u32; 2]; 2] = [[1, 2], [2, 1]];
+
+/**
+ * <p>Decodes MSI Plessey barcodes. Each digit is made of four bits, most significant first.</p>
+ *
+ * <p>The check digits are verified and removed according to
+ * {@link DecodeHintType#MSI_CHECK_DIGIT}, which defaults to a single Luhn (modulo 10) check
+ * digit. As the symbology is prone to false positives, it is only read when asked for with
+ * {@link DecodeHintType#POSSIBLE_FORMATS}.</p>
+ */
+#[derive(OneDReader)]
+pub struct MSIReader {
+    counters: Vec<u32>,
+}
+
+impl Default for MSIReader {
+    fn default() -> Self {
+        Self {
+            counters: Vec::with_capacity(80),
+        }
+    }
+}
+
+impl OneDReader for MSIReader {
+    fn decode_row(
+        &mut self,
+        rowNumber: u32,
+        row: &crate::common::BitArray,
+        hints: &crate::DecodingHintDictionary,
+    ) -> Result<crate::RXingResult> {
+        let checkDigit = if let Some(DecodeHintValue::MsiCheckDigit(checkDigit)) =
+            hints.get(&DecodeHintType::MSI_CHECK_DIGIT)
+        {
+            *checkDigit
+        } else {
+            MSICheckDigit::MOD_10
+        };
+
+        one_d_reader::record_runs(row, &mut self.counters);
+
+        let mut left = self.counters[0] as usize;
+        for start in (1..self.counters.len()).step_by(2) {
+            if let Some((digits, end)) = self.decodeFrom(start) {
+                if let Ok(resultString) = checkDigit.strip(&digits) {
+                    let right = left + self.counters[start..end].iter().sum::<u32>() as usize;
+                    return Ok(RXingResult::new(
+                        &resultString,
+                        Vec::new(),
+                        vec![
+                            point_f(left as f32, rowNumber as f32),
+                            point_f(right as f32, rowNumber as f32),
+                        ],
+                        BarcodeFormat::MSI,
+                    ));
+                }
+            }
+            left += self.counters[start..(start + 2).min(self.counters.len())]
+                .iter()
+                .sum::<u32>() as usize;
+        }
+
+        Err(Exceptions::NOT_FOUND)
+    }
+}
+
+impl MSIReader {
+    /**
+     * Attempts to decode a symbol whose start pattern begins with the bar at the given index.
+     *
+     * @return the decoded digits, including the check digits, and the index past the end pattern
+     */
+    fn decodeFrom(&self, start: usize) -> Option<(String, usize)> {
+        let counters = &self.counters;
+        let length = counters.len();
+        if start + 2 > length
+            || one_d_reader::pattern_match_variance(
+                &counters[start..start + 2],
+                &START_PATTERN,
+                MAX_INDIVIDUAL_VARIANCE,
+            ) >= MAX_AVG_VARIANCE
+        {
+            return None;
+        }
+        let narrow = (counters[start] + counters[start + 1]) as f32 / 3.0;
+        // the leading white run reaches the edge of the row
+        if start > 1 && (counters[start - 1] as f32) < narrow * QUIET_ZONE {
+            return None;
+        }
+
+        let mut digits = String::new();
+        let mut position = start + 2;
+        loop {
+            if position + 3 <= length
+                && one_d_reader::pattern_match_variance(
+                    &counters[position..position + 3],
+                    &END_PATTERN,
+                    MAX_INDIVIDUAL_VARIANCE,
+                ) < MAX_AVG_VARIANCE
+                && (position + 3 == length || counters[position + 3] as f32 >= narrow * QUIET_ZONE)
+            {
+                return Some((digits, position + 3));
+            }
+            if position + 8 > length {
+                return None;
+            }
+            let mut digit = 0;
+            for bit in counters[position..position + 8].chunks_exact(2) {
+                digit = digit << 1 | Self::decodeBit(bit)?;
+            }
+            if digit > 9 {
+                return None;
+            }
+            digits.push((b'0' + digit) as char);
+            position += 8;
+        }
+    }
+
+    /**
+     * @param counters the widths of the bar and the space of a bit
+     */
+    fn decodeBit(counters: &[u32]) -> Option<u8> {
+        let variances = BIT_PATTERNS.map(|pattern| {
+            one_d_reader::pattern_match_variance(counters, &pattern, MAX_INDIVIDUAL_VARIANCE)
+        });
+        if variances[0] < variances[1] && variances[0] < MAX_AVG_VARIANCE {
+            Some(0)
+        } else if variances[1] < variances[0] && variances[1] < MAX_AVG_VARIANCE {
+            Some(1)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    common::{bit_matrix_test_case, BitMatrix},
+    oned::{MSICheckDigit, MSIReader, MSIWriter, OneDReader},
+    BarcodeFormat, DecodeHintType, DecodeHintValue, EncodeHintType, EncodeHintValue, Exceptions,
+    MultiFormatReader, MultiFormatWriter, Reader, Writer,
+};
+
+const ALL_CHECK_DIGITS: [MSICheckDigit; 5] = [
+    MSICheckDigit::NONE,
+    MSICheckDigit::MOD_10,
+    MSICheckDigit::MOD_11,
+    MSICheckDigit::MOD_10_10,
+    MSICheckDigit::MOD_11_10,
+];
+
+fn encode(contents: &str, checkDigit: MSICheckDigit) -> BitMatrix {
+    let hints = HashMap::from([(
+        EncodeHintType::MSI_CHECK_DIGIT,
+        EncodeHintValue::MsiCheckDigit(checkDigit),
+    )]);
+    MSIWriter
+        .encode_with_hints(contents, &BarcodeFormat::MSI, 0, 0, &hints)
+        .expect("must encode")
+}
+
+fn decodeRow(image: &BitMatrix, checkDigit: MSICheckDigit) -> crate::common::Result<String> {
+    let hints = HashMap::from([(
+        DecodeHintType::MSI_CHECK_DIGIT,
+        DecodeHintValue::MsiCheckDigit(checkDigit),
+    )]);
+    MSIReader::default()
+        .decode_row(0, &image.getRow(0), &hints)
+        .map(|result| result.getText().to_owned())
+}
+
+#[test]
+fn testCheckDigits() {
+    assert_eq!("1234567", MSICheckDigit::NONE.append("1234567"));
+    assert_eq!("12345674", MSICheckDigit::MOD_10.append("1234567"));
+    assert_eq!("123456741", MSICheckDigit::MOD_10_10.append("1234567"));
+    assert_eq!("805234", MSICheckDigit::MOD_10.append("80523"));
+    assert_eq!("8052342", MSICheckDigit::MOD_10_10.append("80523"));
+    assert_eq!("805238", MSICheckDigit::MOD_11.append("80523"));
+    assert_eq!("8052383", MSICheckDigit::MOD_11_10.append("80523"));
+    // a remainder of 10 takes two digits
+    assert_eq!("610", MSICheckDigit::MOD_11.append("6"));
+    assert_eq!("6", MSICheckDigit::MOD_11.strip("610").expect("must strip"));
+
+    for checkDigit in ALL_CHECK_DIGITS {
+        for contents in ["1234567", "6", "80523"] {
+            assert_eq!(
+                contents,
+                checkDigit
+                    .strip(&checkDigit.append(contents))
+                    .expect("must strip")
+            );
+        }
+    }
+
+    assert!(matches!(
+        MSICheckDigit::MOD_10.strip("12345675"),
+        Err(Exceptions::ChecksumException(_))
+    ));
+}
+
+#[test]
+fn testEncode() {
+    let image = encode("1234567", MSICheckDigit::MOD_10);
+    let row = (0..image.getWidth())
+        .map(|x| if image.get(x, 0) { '1' } else { '0' })
+        .collect::<String>();
+    assert_eq!(
+        concat!(
+            "00000",
+            "110",
+            "100100100110", // 1
+            "100100110100", // 2
+            "100100110110", // 3
+            "100110100100", // 4
+            "100110100110", // 5
+            "100110110100", // 6
+            "100110110110", // 7
+            "100110100100", // 4
+            "1001",
+            "00000"
+        ),
+        row
+    );
+
+    assert!(MSIWriter.encode("12A4", &BarcodeFormat::MSI, 0, 0).is_err());
+}
+
+#[test]
+fn testDecode() {
+    for checkDigit in ALL_CHECK_DIGITS {
+        for contents in ["1234567", "6", "80523", "0123456789"] {
+            let image = encode(contents, checkDigit);
+            assert_eq!(
+                contents,
+                decodeRow(&image, checkDigit).expect("must decode"),
+                "{checkDigit:?}"
+            );
+        }
+    }
+
+    // the wrong check digit
+    let image = encode("1234565", MSICheckDigit::NONE);
+    assert!(decodeRow(&image, MSICheckDigit::MOD_10).is_err());
+    assert_eq!(
+        "1234565",
+        decodeRow(&image, MSICheckDigit::NONE).expect("must decode")
+    );
+}
+
+#[test]
+fn testMultiFormat() {
+    let image = MultiFormatWriter
+        .encode("80523", &BarcodeFormat::MSI, 200, 50)
+        .expect("must encode");
+    let mut bitmap = bit_matrix_test_case::matrix_to_binary_bitmap(&image);
+
+    let hints = HashMap::from([(
+        DecodeHintType::POSSIBLE_FORMATS,
+        DecodeHintValue::PossibleFormats(HashSet::from([BarcodeFormat::MSI])),
+    )]);
+    let result = MultiFormatReader::default()
+        .decode_with_hints(&mut bitmap, &hints)
+        .expect("must decode");
+    assert_eq!("80523", result.getText());
+    assert_eq!(&BarcodeFormat::MSI, result.getBarcodeFormat());
+}
@@ -0,0 +1,67 @@
+use rxing_one_d_proc_derive::OneDWriter;
+
+use crate::common::Result;
+use crate::BarcodeFormat;
+
+use super::{MSICheckDigit, OneDimensionalCodeWriter};
+
+/**
+ * This object renders an MSI Plessey code as a {@link BitMatrix}. A Luhn (modulo 10) check
+ * digit is appended unless {@link EncodeHintType#MSI_CHECK_DIGIT} asks for another scheme.
+ */
+#[derive(OneDWriter, Default)]
+pub struct MSIWriter;
+
+impl OneDimensionalCodeWriter for MSIWriter {
+    fn encode_oned(&self, contents: &str) -> Result<Vec<bool>> {
+        self.encode_oned_with_hints(contents, &HashMap::new())
+    }
+
+    /**
+     * @param contents the digits to encode, without check digits
+     * @param hints the {@link EncodeHintType#MSI_CHECK_DIGIT} check digit scheme
+     * @return a {@code boolean[]} of horizontal pixels (false = white, true = black)
+     */
+    fn encode_oned_with_hints(
+        &self,
+        contents: &str,
+        hints: &crate::EncodingHintDictionary,
+    ) -> Result<Vec<bool>> {
+        if !contents.chars().all(|c| c.is_ascii_digit()) {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "Requested contents should only contain digits, but got {contents}"
+            )));
+        }
+        let checkDigit = if let Some(EncodeHintValue::MsiCheckDigit(checkDigit)) =
+            hints.get(&EncodeHintType::MSI_CHECK_DIGIT)
+        {
+            *checkDigit
+        } else {
+            MSICheckDigit::MOD_10
+        };
+        let digits = checkDigit.append(contents);
+
+        let mut result = Vec::with_capacity(3 + digits.len() * 12 + 4);
+        result.extend(START_PATTERN);
+        for digit in digits.bytes().map(|c| c - b'0') {
+            for bit in (0..4).rev() {
+                result.extend(if digit >> bit & 1 != 0 { ONE } else { ZERO });
+            }
+        }
+        result.extend(END_PATTERN);
+
+        Ok(result)
+    }
+
+    fn getSupportedWriteFormats(&self) -> Option<Vec<crate::BarcodeFormat>> {
+        Some(vec![BarcodeFormat::MSI])
+    }
+}
+
+/// A wide bar and a narrow space
+const START_PATTERN: [bool; 3] = [true, true, false];
+/// A narrow bar, a wide space and a narrow bar
+const END_PATTERN: [bool; 4] = [true, false, false, true];
+/// Bits are a wide bar and a narrow space for 1, a narrow bar and a wide space for 0
+const ONE: [bool; 3] = [true, true, false];
+const ZERO: [bool; 3] = [true, false, false];
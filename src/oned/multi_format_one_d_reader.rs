@@ -16,13 +16,19 @@
 
 use super::rss::expanded::RSSExpandedReader;
 use super::rss::RSS14Reader;
+use super::rss::RSSLimitedReader;
 use super::CodaBarReader;
+use super::Code11Reader;
 use super::Code128Reader;
 use super::Code39Reader;
 use super::Code93Reader;
 use super::ITFReader;
+use super::MSIReader;
 use super::MultiFormatUPCEANReader;
 use super::OneDReader;
+use super::PharmacodeReader;
+use super::TelepenReader;
+use super::TwoOfFiveReader;
 use crate::common::Result;
 use crate::DecodeHintValue;
 use crate::Exceptions;
@@ -67,11 +73,16 @@ impl OneDReader for MultiFormatOneDReader {
                     return Ok(res);
                 }
             }
-            if possible_formats.contains(&BarcodeFormat::CODE_39) {
+            if possible_formats.contains(&BarcodeFormat::CODE_39)
+                || possible_formats.contains(&BarcodeFormat::CODE_32)
+            {
                 if let Ok(res) = Code39Reader::with_use_check_digit(*use_code_39_check_digit)
                     .decode_row(row_number, row, hints)
                 {
-                    return Ok(res);
+                    // plain Code 39 is not returned when only Code 32 was asked for
+                    if possible_formats.contains(res.getBarcodeFormat()) {
+                        return Ok(res);
+                    }
                 }
             }
             if possible_formats.contains(&BarcodeFormat::CODE_93) {
@@ -79,6 +90,11 @@ impl OneDReader for MultiFormatOneDReader {
                     return Ok(res);
                 }
             }
+            if possible_formats.contains(&BarcodeFormat::CODE_11) {
+                if let Ok(res) = Code11Reader::default().decode_row(row_number, row, hints) {
+                    return Ok(res);
+                }
+            }
             if possible_formats.contains(&BarcodeFormat::CODE_128) {
                 if let Ok(res) = Code128Reader.decode_row(row_number, row, hints) {
                     return Ok(res);
@@ -89,6 +105,34 @@ impl OneDReader for MultiFormatOneDReader {
                     return Ok(res);
                 }
             }
+            for format in [
+                BarcodeFormat::INDUSTRIAL_2_OF_5,
+                BarcodeFormat::IATA_2_OF_5,
+                BarcodeFormat::MATRIX_2_OF_5,
+                BarcodeFormat::DATALOGIC_2_OF_5,
+            ] {
+                if possible_formats.contains(&format) {
+                    if let Ok(res) = TwoOfFiveReader::new(format).decode_row(row_number, row, hints)
+                    {
+                        return Ok(res);
+                    }
+                }
+            }
+            if possible_formats.contains(&BarcodeFormat::MSI) {
+                if let Ok(res) = MSIReader::default().decode_row(row_number, row, hints) {
+                    return Ok(res);
+                }
+            }
+            if possible_formats.contains(&BarcodeFormat::PHARMACODE) {
+                if let Ok(res) = PharmacodeReader::default().decode_row(row_number, row, hints) {
+                    return Ok(res);
+                }
+            }
+            if possible_formats.contains(&BarcodeFormat::TELEPEN) {
+                if let Ok(res) = TelepenReader::default().decode_row(row_number, row, hints) {
+                    return Ok(res);
+                }
+            }
             if possible_formats.contains(&BarcodeFormat::CODABAR) {
                 if let Ok(res) = CodaBarReader::default().decode_row(row_number, row, hints) {
                     return Ok(res);
@@ -104,6 +148,11 @@ impl OneDReader for MultiFormatOneDReader {
                     return Ok(res);
                 }
             }
+            if possible_formats.contains(&BarcodeFormat::RSS_LIMITED) {
+                if let Ok(res) = RSSLimitedReader::default().decode_row(row_number, row, hints) {
+                    return Ok(res);
+                }
+            }
         } else {
             if let Ok(res) =
                 MultiFormatUPCEANReader::new(internal_hints).decode_row(row_number, row, hints)
@@ -20,6 +20,8 @@ use crate::{
     Exceptions, RXingResult, RXingResultMetadataType, RXingResultMetadataValue, Reader,
 };
 
+use super::composite_decoder;
+
 /**
  * Encapsulates functionality and implementation that is common to all families
  * of one-dimensional barcodes.
@@ -107,6 +109,21 @@ pub trait OneDReader: Reader {
                 let Ok(mut result) = self.decode_row(row_number as u32, &row, &hints) else {
                     continue;
                 };
+                if composite_decoder::isLinearComponent(&result) {
+                    // the linkage flag tells there is a 2D component above the linear one, below
+                    // it in an upside down image
+                    if let Some(component) = composite_decoder::decodeComposite(
+                        image.get_black_matrix(),
+                        row_number as u32,
+                        attempt == 1,
+                        &result,
+                    ) {
+                        result.putMetadata(
+                            RXingResultMetadataType::GS1_COMPOSITE_COMPONENT,
+                            RXingResultMetadataValue::Gs1CompositeComponent(component),
+                        );
+                    }
+                }
                 // We found our barcode
                 if attempt == 1 {
                     // But it was upside down, so note that
@@ -128,6 +145,65 @@ pub trait OneDReader: Reader {
         Err(Exceptions::NOT_FOUND)
     }
 
+    /**
+     * <p>Stacked barcodes, such as Codablock F and Code 16K, are made of several rows which each
+     * look like a one-dimensional barcode of their own. Rather than scanning out from the middle
+     * until a row decodes, every rowStep-th row is scanned from the top down and handed to
+     * decode_row. The reader is expected to keep the rows it has found, and to only return a
+     * result once it has all the rows of a barcode.</p>
+     *
+     * <p>All rows of a pass are read in the same direction, so that a row read backwards by mistake
+     * never takes the place of one read the right way. The reader should forget its rows between
+     * a forwards and a backwards pass.</p>
+     *
+     * @param image The image to decode
+     * @param hints Any hints that were requested
+     * @param reversed Whether to read the rows backwards, for an upside down barcode
+     * @return The contents of the decoded barcode
+     * @throws NotFoundException if no barcode is complete
+     */
+    fn _do_decode_stacked<B: Binarizer>(
+        &mut self,
+        image: &mut BinaryBitmap<B>,
+        hints: &DecodingHintDictionary,
+        reversed: bool,
+    ) -> Result<RXingResult> {
+        let width = image.get_width();
+        let height = image.get_height();
+
+        let try_harder = matches!(
+            hints.get(&DecodeHintType::TRY_HARDER),
+            Some(DecodeHintValue::TryHarder(true))
+        );
+        let row_step = if try_harder { 1 } else { 1.max(height >> 8) };
+
+        for row_number in (0..height).step_by(row_step) {
+            let mut row = if let Ok(res) = image.get_black_row(row_number) {
+                res
+            } else {
+                continue;
+            };
+            // The rows of an upside down barcode read backwards
+            if reversed {
+                row.to_mut().reverse();
+            }
+            if let Ok(mut result) = self.decode_row(row_number as u32, &row, hints) {
+                if reversed {
+                    result.putMetadata(
+                        RXingResultMetadataType::ORIENTATION,
+                        RXingResultMetadataValue::Orientation(180),
+                    );
+                    for point in result.getPointsMut() {
+                        *point = point_f(width as f32 - point.x - 1.0, point.y);
+                    }
+                }
+                return Ok(result);
+            }
+        }
+
+        Err(Exceptions::NOT_FOUND)
+    }
+
     /**
      * <p>Attempts to decode a one-dimensional barcode format given a single row of
      * an image.</p>
@@ -262,3 +338,27 @@ pub fn record_pattern_in_reverse(row: &BitArray, start: usize, counters: &mut [u
 
     Ok(())
 }
+
+/**
+ * Records the size of every run of white and black pixels in a row. The first count is the run
+ * of white pixels the row starts with, which is 0 if it starts on a black pixel, so that bars are
+ * always at odd indices.
+ *
+ * @param row row to count from
+ * @param counters vector to replace the counts of with those of the row
+ */
+pub fn record_runs(row: &BitArray, counters: &mut Vec<u32>) {
+    counters.clear();
+    let mut is_white = true;
+    let mut count = 0;
+    for i in 0..row.get_size() {
+        if row.get(i) != is_white {
+            count += 1;
+        } else {
+            counters.push(count);
+            count = 1;
+            is_white = !is_white;
+        }
+    }
+    counters.push(count);
+}
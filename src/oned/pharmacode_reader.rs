@@ -0,0 +1,169 @@
+use rxing_one_d_proc_derive::OneDReader;
+
+use crate::{common::Result, point_f, BarcodeFormat, Exceptions, RXingResult};
+
+use super::{one_d_reader, OneDReader};
+
+/// Bars of a one-track Pharmacode; at least two are needed to encode 3
+const MIN_BARS: usize = 2;
+const MAX_BARS: usize = 16;
+
+/**
+ * <p>Decodes one-track Laetus Pharmacode, a number from 3 to 131070 made of narrow and wide bars.
+ * Narrow bars are one unit, spaces two units and wide bars three units wide.</p>
+ *
+ * <p>As there are no start and stop patterns, any group of bars with regular spaces looks like
+ * a Pharmacode. It is hence only read when asked for with
+ * {@link DecodeHintType#POSSIBLE_FORMATS}, and the group must be surrounded by white space of
+ * at least twice its spaces.</p>
+ */
+#[derive(OneDReader)]
+pub struct PharmacodeReader {
+    counters: Vec<u32>,
+}
+
+impl Default for PharmacodeReader {
+    fn default() -> Self {
+        Self {
+            counters: Vec::with_capacity(80),
+        }
+    }
+}
+
+impl OneDReader for PharmacodeReader {
+    fn decode_row(
+        &mut self,
+        rowNumber: u32,
+        row: &crate::common::BitArray,
+        _hints: &crate::DecodingHintDictionary,
+    ) -> Result<crate::RXingResult> {
+        one_d_reader::record_runs(row, &mut self.counters);
+
+        let mut left = self.counters[0] as usize;
+        for start in (1..self.counters.len()).step_by(2) {
+            if let Some((value, end)) = self.decodeFrom(start) {
+                let right = left + self.counters[start..end].iter().sum::<u32>() as usize;
+                return Ok(RXingResult::new(
+                    &value.to_string(),
+                    Vec::new(),
+                    vec![
+                        point_f(left as f32, rowNumber as f32),
+                        point_f(right as f32, rowNumber as f32),
+                    ],
+                    BarcodeFormat::PHARMACODE,
+                ));
+            }
+            left += self.counters[start..(start + 2).min(self.counters.len())]
+                .iter()
+                .sum::<u32>() as usize;
+        }
+
+        Err(Exceptions::NOT_FOUND)
+    }
+}
+
+impl PharmacodeReader {
+    /**
+     * Attempts to decode a group of bars beginning with the bar at the given index.
+     *
+     * @return the decoded value and the index past the last bar
+     */
+    fn decodeFrom(&self, start: usize) -> Option<(u32, usize)> {
+        let counters = &self.counters;
+        let firstSpace = *counters.get(start + 1)? as f32;
+        // white space before the first bar
+        if (counters[start - 1] as f32) < 2.0 * firstSpace {
+            return None;
+        }
+
+        // spaces are all two units wide, white space wider than that ends the group
+        let mut end = start + 1;
+        while end + 1 < counters.len()
+            && (counters[end] as f32) < 1.5 * firstSpace
+            && (counters[end] as f32) > firstSpace / 1.5
+        {
+            end += 2;
+        }
+        // white space after the last bar
+        if end >= counters.len() || (counters[end] as f32) < 2.0 * firstSpace {
+            return None;
+        }
+
+        let barCount = (end - start).div_ceil(2);
+        if !(MIN_BARS..=MAX_BARS).contains(&barCount) {
+            return None;
+        }
+        let spaceTotal: u32 = counters[start + 1..end].iter().step_by(2).sum();
+        let unit = spaceTotal as f32 / (2 * (barCount - 1)) as f32;
+
+        // narrow bars weigh 1, wide bars 2, doubling from one bar to the one on its left
+        let mut value = 0;
+        for bar in counters[start..end].iter().step_by(2) {
+            let units = *bar as f32 / unit;
+            let weight = if (0.4..2.0).contains(&units) {
+                1
+            } else if (2.0..4.5).contains(&units) {
+                2
+            } else {
+                return None;
+            };
+            value = value * 2 + weight;
+        }
+        Some((value, end))
+    }
+}
+
+#[cfg(test)]
+mod PharmacodeReaderTestCase {
+    use std::collections::{HashMap, HashSet};
+
+    use crate::{
+        common::{bit_matrix_test_case, BitMatrix},
+        oned::{OneDReader, PharmacodeReader, PharmacodeWriter},
+        BarcodeFormat, DecodeHintType, DecodeHintValue, MultiFormatReader, Reader, Writer,
+    };
+
+    #[test]
+    fn testDecode() {
+        for contents in ["3", "4", "1234", "65535", "131070"] {
+            let matrix = PharmacodeWriter
+                .encode(contents, &BarcodeFormat::PHARMACODE, 0, 0)
+                .expect("must encode");
+            let result = PharmacodeReader::default()
+                .decode_row(0, &matrix.getRow(0), &HashMap::new())
+                .expect("must decode");
+            assert_eq!(contents, result.getText());
+        }
+
+        // no white space around the bars
+        for encoded in ["1001001110", "0111001001"] {
+            let matrix = BitMatrix::parse_strings(encoded, "1", "0").expect("must parse");
+            assert!(PharmacodeReader::default()
+                .decode_row(0, &matrix.getRow(0), &HashMap::new())
+                .is_err());
+        }
+    }
+
+    #[test]
+    fn testPossibleFormats() {
+        let image = PharmacodeWriter
+            .encode("1234", &BarcodeFormat::PHARMACODE, 200, 50)
+            .expect("must encode");
+        let bitmap = || bit_matrix_test_case::matrix_to_binary_bitmap(&image);
+
+        assert!(!matches!(
+            MultiFormatReader::default().decode(&mut bitmap()),
+            Ok(result) if result.getBarcodeFormat() == &BarcodeFormat::PHARMACODE
+        ));
+
+        let hints = HashMap::from([(
+            DecodeHintType::POSSIBLE_FORMATS,
+            DecodeHintValue::PossibleFormats(HashSet::from([BarcodeFormat::PHARMACODE])),
+        )]);
+        let result = MultiFormatReader::default()
+            .decode_with_hints(&mut bitmap(), &hints)
+            .expect("must decode");
+        assert_eq!("1234", result.getText());
+        assert_eq!(&BarcodeFormat::PHARMACODE, result.getBarcodeFormat());
+    }
+}
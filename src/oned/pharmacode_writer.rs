@@ -0,0 +1,232 @@
+use std::collections::HashMap;
+
+use crate::{
+    common::{BitMatrix, Result},
+    BarcodeFormat, EncodeHintType, EncodeHintValue, Exceptions, Writer,
+};
+
+use super::OneDimensionalCodeWriter;
+
+const MAX_ONE_TRACK: u32 = 131070;
+const MAX_TWO_TRACK: u32 = 64570080;
+
+/**
+ * This object renders a Laetus Pharmacode as a {@link BitMatrix}.
+ *
+ * <p>The one-track code is made of narrow and wide bars and encodes 3 to 131070. With
+ * {@link EncodeHintType#PHARMACODE_TWO_TRACK} the two-track code is rendered instead, whose bars
+ * fill the top track, the bottom track or both, and which encodes 4 to 64570080.</p>
+ */
+#[derive(Default)]
+pub struct PharmacodeWriter;
+
+impl Writer for PharmacodeWriter {
+    fn encode(
+        &self,
+        contents: &str,
+        format: &BarcodeFormat,
+        width: i32,
+        height: i32,
+    ) -> Result<BitMatrix> {
+        self.encode_with_hints(contents, format, width, height, &HashMap::new())
+    }
+
+    fn encode_with_hints(
+        &self,
+        contents: &str,
+        format: &BarcodeFormat,
+        width: i32,
+        height: i32,
+        hints: &crate::EncodingHintDictionary,
+    ) -> Result<BitMatrix> {
+        if contents.is_empty() {
+            return Err(Exceptions::illegal_argument_with("Found empty contents"));
+        }
+
+        if format != &BarcodeFormat::PHARMACODE {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "Can only encode PHARMACODE, but got {format:?}"
+            )));
+        }
+
+        if width < 0 || height < 0 {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "Negative size is not allowed. Input: {width}x{height}"
+            )));
+        }
+
+        let mut sidesMargin = self.getDefaultMargin();
+        if let Some(EncodeHintValue::Margin(margin)) = hints.get(&EncodeHintType::MARGIN) {
+            sidesMargin = margin.parse::<u32>().map_err(|e| {
+                Exceptions::illegal_argument_with(format!("couldnt parse {margin}: {e}"))
+            })?;
+        }
+
+        if matches!(
+            hints.get(&EncodeHintType::PHARMACODE_TWO_TRACK),
+            Some(EncodeHintValue::PharmacodeTwoTrack(true))
+        ) {
+            let tracks = Self::encodeTwoTrack(contents)?;
+            Self::renderTwoTrack(&tracks, width, height, sidesMargin)
+        } else {
+            let code = self.encode_oned(contents)?;
+            Self::renderRXingResult(&code, width, height, sidesMargin)
+        }
+    }
+}
+
+impl OneDimensionalCodeWriter for PharmacodeWriter {
+    /**
+     * @param contents the number to encode, 3 to 131070
+     * @return a {@code boolean[]} of horizontal pixels (false = white, true = black)
+     */
+    fn encode_oned(&self, contents: &str) -> Result<Vec<bool>> {
+        let mut value = Self::parseValue(contents, 3, MAX_ONE_TRACK)?;
+
+        // narrow bars weigh 1, wide bars 2, doubling from one bar to the one on its left
+        let mut wide = Vec::new();
+        while value > 0 {
+            let isWide = value.is_multiple_of(2);
+            wide.push(isWide);
+            value = (value - if isWide { 2 } else { 1 }) / 2;
+        }
+
+        let mut result = Vec::new();
+        for (i, wide) in wide.iter().rev().enumerate() {
+            if i > 0 {
+                result.extend([false, false]);
+            }
+            result.extend(if *wide { &[true; 3][..] } else { &[true][..] });
+        }
+        Ok(result)
+    }
+
+    fn getSupportedWriteFormats(&self) -> Option<Vec<BarcodeFormat>> {
+        Some(vec![BarcodeFormat::PHARMACODE])
+    }
+}
+
+impl PharmacodeWriter {
+    fn parseValue(contents: &str, min: u32, max: u32) -> Result<u32> {
+        contents
+            .parse::<u32>()
+            .ok()
+            .filter(|value| {
+                contents.bytes().all(|c| c.is_ascii_digit()) && (min..=max).contains(value)
+            })
+            .ok_or_else(|| {
+                Exceptions::illegal_argument_with(format!(
+                    "Requested contents should be a number from {min} to {max}, but got {contents}"
+                ))
+            })
+    }
+
+    /**
+     * @return whether each bar, from left to right, fills the top and the bottom track
+     */
+    fn encodeTwoTrack(contents: &str) -> Result<Vec<(bool, bool)>> {
+        let mut value = Self::parseValue(contents, 4, MAX_TWO_TRACK)?;
+
+        // bottom bars weigh 1, top bars 2 and full bars 3, tripling from one bar to the one on
+        // its left
+        let mut bars = Vec::new();
+        while value > 0 {
+            let digit = match value % 3 {
+                0 => 3,
+                digit => digit,
+            };
+            bars.push((digit & 2 != 0, digit & 1 != 0));
+            value = (value - digit) / 3;
+        }
+        bars.reverse();
+        Ok(bars)
+    }
+
+    /**
+     * Bars are one module wide and one module apart, each track takes half of the height.
+     */
+    fn renderTwoTrack(
+        bars: &[(bool, bool)],
+        width: i32,
+        height: i32,
+        sidesMargin: u32,
+    ) -> Result<BitMatrix> {
+        let inputWidth = 2 * bars.len() as u32 - 1;
+        let fullWidth = inputWidth + sidesMargin;
+        let outputWidth = (width as u32).max(fullWidth);
+        let outputHeight = (height as u32).max(2);
+
+        let multiple = outputWidth / fullWidth;
+        let leftPadding = (outputWidth - inputWidth * multiple) / 2;
+        let trackHeight = outputHeight / 2;
+
+        let mut output = BitMatrix::new(outputWidth, outputHeight)?;
+        for (i, (top, bottom)) in bars.iter().enumerate() {
+            let left = leftPadding + 2 * i as u32 * multiple;
+            let barTop = if *top { 0 } else { trackHeight };
+            let barBottom = if *bottom { outputHeight } else { trackHeight };
+            output.setRegion(left, barTop, multiple, barBottom - barTop)?;
+        }
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod PharmacodeWriterTestCase {
+    use std::collections::HashMap;
+
+    use crate::{
+        common::bit_matrix_test_case, oned::PharmacodeWriter, BarcodeFormat, EncodeHintType,
+        EncodeHintValue, Writer,
+    };
+
+    #[test]
+    fn testEncode() {
+        doTest("3", "00000100100000");
+        doTest("4", "0000010011100000");
+        doTest("1234", "000001001001110011100100111001001001110011100000");
+        doTest("131070", &format!("00000{}11100000", "11100".repeat(15)));
+
+        for contents in ["2", "131071", "12a", "-5", ""] {
+            assert!(PharmacodeWriter
+                .encode(contents, &BarcodeFormat::PHARMACODE, 0, 0)
+                .is_err());
+        }
+    }
+
+    #[test]
+    fn testEncodeTwoTrack() {
+        let hints = HashMap::from([(
+            EncodeHintType::PHARMACODE_TWO_TRACK,
+            EncodeHintValue::PharmacodeTwoTrack(true),
+        )]);
+        // bottom, bottom, top, full, bottom, full, bottom
+        let result = PharmacodeWriter
+            .encode_with_hints("1234", &BarcodeFormat::PHARMACODE, 0, 0, &hints)
+            .expect("encode");
+        let row = |y| {
+            (0..result.getWidth())
+                .map(|x| if result.get(x, y) { '1' } else { '0' })
+                .collect::<String>()
+        };
+        assert_eq!(2, result.getHeight());
+        assert_eq!("00000000010100010000000", row(0));
+        assert_eq!("00000101000101010100000", row(1));
+
+        assert!(PharmacodeWriter
+            .encode_with_hints("64570080", &BarcodeFormat::PHARMACODE, 0, 0, &hints)
+            .is_ok());
+        for contents in ["3", "64570081"] {
+            assert!(PharmacodeWriter
+                .encode_with_hints(contents, &BarcodeFormat::PHARMACODE, 0, 0, &hints)
+                .is_err());
+        }
+    }
+
+    fn doTest(input: &str, expected: &str) {
+        let result = PharmacodeWriter
+            .encode(input, &BarcodeFormat::PHARMACODE, 0, 0)
+            .expect("encode");
+        assert_eq!(expected, bit_matrix_test_case::matrix_to_string(&result));
+    }
+}
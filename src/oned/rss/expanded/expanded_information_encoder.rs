@@ -0,0 +1,483 @@
+/*
+ * The reverse of the decoders in {@link decoders}: compacts a GS1 element string into the binary
+ * data of an RSS Expanded symbol, choosing the most compact encodation method that applies.
+ */
+
+use crate::{
+    common::{BitArray, Result},
+    Exceptions,
+};
+
+/// Stands for the FNC1 separating a variable length element string from the next one
+const FNC1: char = '\u{001D}';
+
+const GTIN_AI: &str = "01";
+
+const MIN_DATA_CHARACTERS: usize = 3;
+const MAX_DATA_CHARACTERS: usize = 21;
+const BITS_PER_CHARACTER: usize = 12;
+
+/// First two digits of the AIs whose data has a predefined length, these need no FNC1 after them
+const PREDEFINED_LENGTH_AIS: [&str; 23] = [
+    "00", "01", "02", "03", "04", "11", "12", "13", "14", "15", "16", "17", "18", "19", "20", "23",
+    "31", "32", "33", "34", "35", "36", "41",
+];
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Numeric,
+    Alpha,
+    IsoIec646,
+}
+
+/**
+ * Encodes an element string such as `(01)90012345678908(3103)001750` into the bits carried by
+ * the data characters of an RSS Expanded symbol, padded to a whole number of characters.
+ */
+pub fn encode(contents: &str) -> Result<BitArray> {
+    let elements = parseElementStrings(contents)?;
+
+    let mut bits = BitArray::new();
+    // linkage flag, there is never a 2D component
+    bits.appendBit(false);
+
+    let gtin = match elements.first() {
+        Some((ai, gtin)) if ai == GTIN_AI => gtin.as_str(),
+        _ => {
+            // method and variable length symbol field, filled in once the length is known
+            bits.appendBits(0b0000, 4)?;
+            return encodeVariableLength(bits, &elements, 3);
+        }
+    };
+    checkGtin(gtin)?;
+
+    if gtin.starts_with('9') {
+        if let Some(bits) = encodeWeight(&bits, gtin, &elements[1..])? {
+            return Ok(bits);
+        }
+    }
+
+    match &elements[1..] {
+        [(ai, price)] if ai.len() == 4 && (ai.starts_with("392") || ai.starts_with("393")) => {
+            let lastDigit = ai[3..].parse::<u32>().unwrap_or(u32::MAX);
+            if lastDigit < 4 && gtin.starts_with('9') {
+                return encodePrice(bits, gtin, ai.starts_with("393"), lastDigit, price);
+            }
+        }
+        _ => {}
+    }
+
+    bits.appendBit(true);
+    // variable length symbol field, filled in once the length is known
+    bits.appendBits(0b00, 2)?;
+    bits.appendBits(gtin[0..1].parse::<u32>().unwrap_or_default(), 4)?;
+    appendCompressedGtin(&mut bits, gtin)?;
+    encodeVariableLength(bits, &elements[1..], 2)
+}
+
+/**
+ * Splits `(ai)data(ai)data...` into its AIs and their data.
+ */
+fn parseElementStrings(contents: &str) -> Result<Vec<(String, String)>> {
+    let chars: Vec<char> = contents.chars().collect();
+    let isAiAt = |i: usize| {
+        chars[i] == '('
+            && (3..=5).any(|length| {
+                chars.get(i + length) == Some(&')')
+                    && chars[i + 1..i + length].iter().all(char::is_ascii_digit)
+            })
+    };
+
+    let mut elements: Vec<(String, String)> = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        if !isAiAt(i) {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "Expected an AI in parentheses at position {i} of {contents}"
+            )));
+        }
+        let close = i + chars[i..]
+            .iter()
+            .position(|c| *c == ')')
+            .unwrap_or_default();
+        let ai: String = chars[i + 1..close].iter().collect();
+        i = close + 1;
+        let mut data = String::new();
+        while i < chars.len() && !isAiAt(i) {
+            data.push(chars[i]);
+            i += 1;
+        }
+        if data.is_empty() {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "Missing data for AI ({ai})"
+            )));
+        }
+        elements.push((ai, data));
+    }
+
+    if elements.is_empty() {
+        return Err(Exceptions::illegal_argument_with("Found empty contents"));
+    }
+
+    Ok(elements)
+}
+
+fn checkGtin(gtin: &str) -> Result<()> {
+    if gtin.len() != 14 || !gtin.chars().all(|c| c.is_ascii_digit()) {
+        return Err(Exceptions::illegal_argument_with(format!(
+            "GTIN should be 14 digits long, but got {gtin}"
+        )));
+    }
+    let checksum = gtin
+        .bytes()
+        .take(13)
+        .enumerate()
+        .map(|(i, digit)| (digit - b'0') as u32 * if i % 2 == 0 { 3 } else { 1 })
+        .sum::<u32>();
+    if (10 - checksum % 10) % 10 != (gtin.as_bytes()[13] - b'0') as u32 {
+        return Err(Exceptions::illegal_argument_with(format!(
+            "GTIN {gtin} does not pass checksum"
+        )));
+    }
+    Ok(())
+}
+
+/**
+ * Appends digits 2 to 13 of the GTIN in groups of three, its check digit is recalculated when
+ * decoding.
+ */
+fn appendCompressedGtin(bits: &mut BitArray, gtin: &str) -> Result<()> {
+    for i in 0..4 {
+        let group = gtin[1 + 3 * i..4 + 3 * i]
+            .parse::<u32>()
+            .map_err(|_| Exceptions::ILLEGAL_ARGUMENT)?;
+        bits.appendBits(group, 10)?;
+    }
+    Ok(())
+}
+
+/**
+ * The fixed size encodation methods for a GTIN starting with 9 followed by a weight in kilograms
+ * or pounds, and optionally a date. Returns `None` when the element strings don't fit them.
+ */
+fn encodeWeight(
+    header: &BitArray,
+    gtin: &str,
+    elements: &[(String, String)],
+) -> Result<Option<BitArray>> {
+    let weight = |ai: &str, data: &str| -> Option<(String, u32)> {
+        if ai.len() == 4 && data.len() == 6 && data.chars().all(|c| c.is_ascii_digit()) {
+            Some((ai.to_owned(), data.parse().ok()?))
+        } else {
+            None
+        }
+    };
+
+    let (ai, value, date) = match elements {
+        [(ai, data)] => match weight(ai, data) {
+            Some((ai, value)) => (ai, value, None),
+            None => return Ok(None),
+        },
+        [(ai, data), (dateAi, date)] => match weight(ai, data) {
+            Some((ai, value)) => (ai, value, Some((dateAi.as_str(), date.as_str()))),
+            None => return Ok(None),
+        },
+        _ => return Ok(None),
+    };
+
+    let mut bits = header.clone();
+    match (ai.as_str(), date) {
+        ("3103", None) if value <= 32767 => {
+            bits.appendBits(0b0100, 4)?;
+            appendCompressedGtin(&mut bits, gtin)?;
+            bits.appendBits(value, 15)?;
+        }
+        ("3202", None) if value <= 9999 => {
+            bits.appendBits(0b0101, 4)?;
+            appendCompressedGtin(&mut bits, gtin)?;
+            bits.appendBits(value, 15)?;
+        }
+        ("3203", None) if value <= 22767 => {
+            bits.appendBits(0b0101, 4)?;
+            appendCompressedGtin(&mut bits, gtin)?;
+            bits.appendBits(value + 10000, 15)?;
+        }
+        _ if (ai.starts_with("310") || ai.starts_with("320")) && value <= 99999 => {
+            let (dateCode, dateValue) = match date {
+                Some((dateAi, date)) => match (compressDate(date), dateAi) {
+                    (Some(dateValue), "11") => (0, dateValue),
+                    (Some(dateValue), "13") => (1, dateValue),
+                    (Some(dateValue), "15") => (2, dateValue),
+                    (Some(dateValue), "17") => (3, dateValue),
+                    _ => return Ok(None),
+                },
+                // no date
+                None => (0, 38400),
+            };
+            let pounds = u32::from(ai.starts_with("320"));
+            bits.appendBits(0b0111, 4)?;
+            bits.appendBits(2 * dateCode + pounds, 3)?;
+            appendCompressedGtin(&mut bits, gtin)?;
+            bits.appendBits((ai.as_bytes()[3] - b'0') as u32 * 100000 + value, 20)?;
+            bits.appendBits(dateValue, 16)?;
+        }
+        _ => return Ok(None),
+    }
+
+    Ok(Some(bits))
+}
+
+fn compressDate(date: &str) -> Option<u32> {
+    if date.len() != 6 || !date.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let year = date[0..2].parse::<u32>().ok()?;
+    let month = date[2..4].parse::<u32>().ok()?;
+    let day = date[4..6].parse::<u32>().ok()?;
+    if !(1..=12).contains(&month) || day > 31 {
+        return None;
+    }
+    Some(year * 384 + (month - 1) * 32 + day)
+}
+
+/**
+ * The encodation methods for a GTIN starting with 9 followed by a price, either in the local
+ * currency (392x) or with an ISO 4217 currency code (393x).
+ */
+fn encodePrice(
+    header: BitArray,
+    gtin: &str,
+    withCurrency: bool,
+    lastDigit: u32,
+    price: &str,
+) -> Result<BitArray> {
+    let mut bits = header;
+    bits.appendBits(if withCurrency { 0b01101 } else { 0b01100 }, 5)?;
+    bits.appendBits(0b00, 2)?;
+    appendCompressedGtin(&mut bits, gtin)?;
+    bits.appendBits(lastDigit, 2)?;
+    let price = if withCurrency {
+        let currency = price
+            .get(0..3)
+            .filter(|currency| currency.chars().all(|c| c.is_ascii_digit()))
+            .ok_or_else(|| {
+                Exceptions::illegal_argument_with(format!("Invalid currency in {price}"))
+            })?;
+        bits.appendBits(currency.parse().unwrap_or_default(), 10)?;
+        &price[3..]
+    } else {
+        price
+    };
+    let chars: Vec<char> = price.chars().collect();
+    let mode = encodeGeneralPurpose(&mut bits, &chars)?;
+    finish(bits, mode, 6)
+}
+
+/**
+ * Appends the remaining element strings in the general purpose data field and fills in the
+ * variable length symbol field that precedes the data of the method.
+ */
+fn encodeVariableLength(
+    header: BitArray,
+    elements: &[(String, String)],
+    vlsPosition: usize,
+) -> Result<BitArray> {
+    let mut bits = header;
+
+    let mut data = Vec::new();
+    for (i, (ai, value)) in elements.iter().enumerate() {
+        data.extend(ai.chars());
+        data.extend(value.chars());
+        if i + 1 < elements.len() && !PREDEFINED_LENGTH_AIS.contains(&&ai[..2.min(ai.len())]) {
+            data.push(FNC1);
+        }
+    }
+
+    let mode = encodeGeneralPurpose(&mut bits, &data)?;
+    finish(bits, mode, vlsPosition)
+}
+
+/**
+ * Pads the data to a whole number of symbol characters and sets the variable length symbol
+ * field, the parity of the number of symbol characters and whether there are more than 14.
+ */
+fn finish(bits: BitArray, mode: Mode, vlsPosition: usize) -> Result<BitArray> {
+    let dataCharacters = bits
+        .get_size()
+        .div_ceil(BITS_PER_CHARACTER)
+        .max(MIN_DATA_CHARACTERS);
+    if dataCharacters > MAX_DATA_CHARACTERS {
+        return Err(Exceptions::illegal_argument_with(format!(
+            "Contents need {dataCharacters} data characters, more than the {MAX_DATA_CHARACTERS} that fit"
+        )));
+    }
+
+    let mut bits = bits;
+    let padding = if mode == Mode::Numeric { "0000" } else { "" }
+        .chars()
+        .chain("00100".chars().cycle());
+    for bit in padding.take(dataCharacters * BITS_PER_CHARACTER - bits.get_size()) {
+        bits.appendBit(bit == '1');
+    }
+
+    // the symbol characters include the check character
+    let symbolCharacters = dataCharacters + 1;
+    if symbolCharacters % 2 == 1 {
+        bits.set(vlsPosition);
+    }
+    if symbolCharacters > 14 {
+        bits.set(vlsPosition + 1);
+    }
+    Ok(bits)
+}
+
+fn isNumeric(c: char) -> bool {
+    c.is_ascii_digit() || c == FNC1
+}
+
+fn isAlphanumeric(c: char) -> bool {
+    isNumeric(c) || c.is_ascii_uppercase() || matches!(c, '*' | ',' | '-' | '.' | '/')
+}
+
+fn isIsoIec646(c: char) -> bool {
+    isAlphanumeric(c)
+        || c.is_ascii_lowercase()
+        || matches!(
+            c,
+            '!' | '"'
+                | '%'
+                | '&'
+                | '\''
+                | '('
+                | ')'
+                | '+'
+                | ':'
+                | ';'
+                | '<'
+                | '='
+                | '>'
+                | '?'
+                | '_'
+                | ' '
+        )
+}
+
+/**
+ * Numeric runs long enough to be worth latching to numeric encodation for, from an alphanumeric
+ * or ISO/IEC 646 encodation.
+ */
+fn startsNumericRun(data: &[char]) -> bool {
+    let run = data.iter().take_while(|c| isNumeric(**c)).count();
+    run >= 6 || (run == data.len() && run >= 4)
+}
+
+/**
+ * Appends the general purpose data field, returning the encodation mode it ends in.
+ */
+fn encodeGeneralPurpose(bits: &mut BitArray, data: &[char]) -> Result<Mode> {
+    let mut mode = Mode::Numeric;
+    let mut i = 0;
+    while i < data.len() {
+        let c = data[i];
+        if !isIsoIec646(c) {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "Character {c:?} can not be encoded in RSS Expanded"
+            )));
+        }
+        match mode {
+            Mode::Numeric => {
+                let next = data.get(i + 1).copied();
+                let value = |c: char| c.to_digit(10).unwrap_or(10);
+                match next {
+                    Some(next) if isNumeric(c) && isNumeric(next) => {
+                        bits.appendBits(11 * value(c) + value(next) + 8, 7)?;
+                        i += 2;
+                    }
+                    // a single digit at the end is followed by an FNC1
+                    None if c.is_ascii_digit() => {
+                        bits.appendBits(11 * value(c) + 10 + 8, 7)?;
+                        i += 1;
+                    }
+                    _ => {
+                        bits.appendBits(0b0000, 4)?;
+                        mode = Mode::Alpha;
+                    }
+                }
+            }
+            Mode::Alpha => {
+                if startsNumericRun(&data[i..]) {
+                    bits.appendBits(0b000, 3)?;
+                    mode = Mode::Numeric;
+                } else if isAlphanumeric(c) {
+                    appendAlphanumeric(bits, c)?;
+                    i += 1;
+                } else {
+                    bits.appendBits(0b00100, 5)?;
+                    mode = Mode::IsoIec646;
+                }
+            }
+            Mode::IsoIec646 => {
+                let alphanumericRun = data[i..].iter().take_while(|c| isAlphanumeric(**c)).count();
+                if startsNumericRun(&data[i..]) {
+                    bits.appendBits(0b000, 3)?;
+                    mode = Mode::Numeric;
+                } else if alphanumericRun >= 10
+                    || (alphanumericRun == data.len() - i && alphanumericRun >= 6)
+                {
+                    bits.appendBits(0b00100, 5)?;
+                    mode = Mode::Alpha;
+                } else {
+                    appendIsoIec646(bits, c)?;
+                    i += 1;
+                }
+            }
+        }
+    }
+    Ok(mode)
+}
+
+fn appendAlphanumeric(bits: &mut BitArray, c: char) -> Result<()> {
+    match c {
+        FNC1 => bits.appendBits(15, 5),
+        '0'..='9' => bits.appendBits(c as u32 - '0' as u32 + 5, 5),
+        'A'..='Z' => bits.appendBits(c as u32 - 33, 6),
+        '*' => bits.appendBits(58, 6),
+        ',' => bits.appendBits(59, 6),
+        '-' => bits.appendBits(60, 6),
+        '.' => bits.appendBits(61, 6),
+        '/' => bits.appendBits(62, 6),
+        _ => Err(Exceptions::ILLEGAL_ARGUMENT),
+    }
+}
+
+fn appendIsoIec646(bits: &mut BitArray, c: char) -> Result<()> {
+    let value = match c {
+        FNC1 => return bits.appendBits(15, 5),
+        '0'..='9' => return bits.appendBits(c as u32 - '0' as u32 + 5, 5),
+        'A'..='Z' => return bits.appendBits(c as u32 - 1, 7),
+        'a'..='z' => return bits.appendBits(c as u32 - 7, 7),
+        '!' => 232,
+        '"' => 233,
+        '%' => 234,
+        '&' => 235,
+        '\'' => 236,
+        '(' => 237,
+        ')' => 238,
+        '*' => 239,
+        '+' => 240,
+        ',' => 241,
+        '-' => 242,
+        '.' => 243,
+        '/' => 244,
+        ':' => 245,
+        ';' => 246,
+        '<' => 247,
+        '=' => 248,
+        '>' => 249,
+        '?' => 250,
+        '_' => 251,
+        ' ' => 252,
+        _ => return Err(Exceptions::ILLEGAL_ARGUMENT),
+    };
+    bits.appendBits(value, 8)
+}
@@ -15,6 +15,11 @@ pub use expanded_row::*;
 mod rss_expanded_reader;
 pub use rss_expanded_reader::*;
 
+mod expanded_information_encoder;
+
+mod rss_expanded_writer;
+pub use rss_expanded_writer::*;
+
 #[cfg(test)]
 #[cfg(feature = "image")]
 mod rss_expanded_internal_test_case;
@@ -38,3 +43,6 @@ mod rss_expanded_stacked_internal_test_case;
 #[cfg(test)]
 #[cfg(feature = "image")]
 mod test_case_util;
+
+#[cfg(test)]
+mod rss_expanded_writer_test_case;
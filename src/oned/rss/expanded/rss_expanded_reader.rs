@@ -51,7 +51,7 @@ const FINDER_PAT_D: u32 = 3;
 const FINDER_PAT_E: u32 = 4;
 const FINDER_PAT_F: u32 = 5;
 
-static FINDER_PATTERN_SEQUENCES: Lazy<Vec<Vec<u32>>> = Lazy::new(|| {
+pub(super) static FINDER_PATTERN_SEQUENCES: Lazy<Vec<Vec<u32>>> = Lazy::new(|| {
     vec![
         vec![FINDER_PAT_A, FINDER_PAT_A],
         vec![FINDER_PAT_A, FINDER_PAT_B, FINDER_PAT_B],
@@ -239,11 +239,11 @@ impl RSSExpandedReader {
     pub fn new() -> Self {
         Self::default()
     }
-    const SYMBOL_WIDEST: [u32; 5] = [7, 5, 4, 3, 1];
-    const EVEN_TOTAL_SUBSET: [u32; 5] = [4, 20, 52, 104, 204];
-    const GSUM: [u32; 5] = [0, 348, 1388, 2948, 3988];
+    pub(super) const SYMBOL_WIDEST: [u32; 5] = [7, 5, 4, 3, 1];
+    pub(super) const EVEN_TOTAL_SUBSET: [u32; 5] = [4, 20, 52, 104, 204];
+    pub(super) const GSUM: [u32; 5] = [0, 348, 1388, 2948, 3988];
 
-    const FINDER_PATTERNS: [[u32; 4]; 6] = [
+    pub(super) const FINDER_PATTERNS: [[u32; 4]; 6] = [
         [1, 8, 4, 1], // A
         [3, 6, 4, 1], // B
         [3, 4, 6, 1], // C
@@ -252,7 +252,7 @@ impl RSSExpandedReader {
         [2, 2, 9, 1], // F
     ];
 
-    const WEIGHTS: [[u32; 8]; 23] = [
+    pub(super) const WEIGHTS: [[u32; 8]; 23] = [
         [1, 3, 9, 27, 81, 32, 96, 77],
         [20, 60, 180, 118, 143, 7, 21, 63],
         [189, 145, 13, 39, 117, 140, 209, 205],
@@ -560,6 +560,13 @@ impl RSSExpandedReader {
             RXingResultMetadataType::SYMBOLOGY_IDENTIFIER,
             RXingResultMetadataValue::SymbologyIdentifier("]e0".to_owned()),
         );
+        // the first bit is the linkage flag of GS1 Composite symbols
+        if binary.get(0) {
+            result.putMetadata(
+                RXingResultMetadataType::IS_GS1_COMPOSITE,
+                RXingResultMetadataValue::IsGs1Composite(true),
+            );
+        }
 
         Ok(result)
     }
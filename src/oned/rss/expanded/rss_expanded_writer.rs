@@ -0,0 +1,208 @@
+use std::collections::HashMap;
+
+use crate::{
+    common::{BitMatrix, Result},
+    oned::rss::{
+        rss_rows::{self, SymbolRow},
+        rss_utils,
+    },
+    BarcodeFormat, EncodeHintType, EncodeHintValue, Exceptions, Writer,
+};
+
+use super::{
+    expanded_information_encoder, rss_expanded_reader::FINDER_PATTERN_SEQUENCES, RSSExpandedReader,
+};
+
+/// Height of a row of the symbol, in modules
+const ROW_HEIGHT: u32 = 34;
+/// Width of a pair of data characters and the finder pattern between them, in modules
+const PAIR_MODULES: usize = 49;
+/// Position of the first finder pattern of a row, after the guard and the first data character
+const FIRST_FINDER: usize = 2 + 17;
+
+/**
+ * This object renders an RSS Expanded (GS1 DataBar Expanded) code as a {@link BitMatrix}.
+ *
+ * The contents are GS1 element strings with their AIs in parentheses, as returned by
+ * {@link RSSExpandedReader}, for example `(01)90012345678908(3103)001750`. The symbol is stacked
+ * when {@link EncodeHintType#RSS_EXPANDED_SEGMENTS} is given.
+ */
+#[derive(Default)]
+pub struct RSSExpandedWriter;
+
+impl RSSExpandedWriter {
+    /**
+     * Encodes the single row linear component of a GS1 Composite symbol, with the linkage flag set.
+     */
+    #[cfg(test)]
+    pub(crate) fn encodeLinked(contents: &str, height: i32) -> Result<BitMatrix> {
+        rss_rows::render(&encodeRows(contents, None, true)?, 0, height, 0)
+    }
+}
+
+impl Writer for RSSExpandedWriter {
+    fn encode(
+        &self,
+        contents: &str,
+        format: &BarcodeFormat,
+        width: i32,
+        height: i32,
+    ) -> Result<BitMatrix> {
+        self.encode_with_hints(contents, format, width, height, &HashMap::new())
+    }
+
+    fn encode_with_hints(
+        &self,
+        contents: &str,
+        format: &BarcodeFormat,
+        width: i32,
+        height: i32,
+        hints: &crate::EncodingHintDictionary,
+    ) -> Result<BitMatrix> {
+        if contents.is_empty() {
+            return Err(Exceptions::illegal_argument_with("Found empty contents"));
+        }
+
+        if format != &BarcodeFormat::RSS_EXPANDED {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "Can only encode RSS_EXPANDED, but got {format:?}"
+            )));
+        }
+
+        if width < 0 || height < 0 {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "Negative size is not allowed. Input: {width}x{height}"
+            )));
+        }
+
+        let mut sidesMargin = 10;
+        if let Some(EncodeHintValue::Margin(margin)) = hints.get(&EncodeHintType::MARGIN) {
+            sidesMargin = margin.parse::<u32>().map_err(|e| {
+                Exceptions::illegal_argument_with(format!("couldnt parse {margin}: {e}"))
+            })?;
+        }
+
+        let mut segments = None;
+        if let Some(EncodeHintValue::RssExpandedSegments(perRow)) =
+            hints.get(&EncodeHintType::RSS_EXPANDED_SEGMENTS)
+        {
+            if *perRow % 2 != 0 || !(2..=22).contains(perRow) {
+                return Err(Exceptions::illegal_argument_with(format!(
+                    "Segments per row should be an even number from 2 to 22, but got {perRow}"
+                )));
+            }
+            segments = Some(*perRow as usize);
+        }
+
+        let rows = encodeRows(contents, segments, false)?;
+        rss_rows::render(&rows, width, height, sidesMargin)
+    }
+}
+
+/**
+ * Lays out the symbol characters in pairs around their finder patterns, and the pairs in rows of
+ * the requested number of segments.
+ *
+ * @param linked whether the linkage flag to a 2D component is set
+ */
+fn encodeRows(contents: &str, segments: Option<usize>, linked: bool) -> Result<Vec<SymbolRow>> {
+    let mut bits = expanded_information_encoder::encode(contents)?;
+    if linked {
+        bits.set(0);
+    }
+
+    // the first symbol character is the check character
+    let mut characters = vec![[0; 8]];
+    let symbolCharacters = bits.get_size() / 12 + 1;
+    let pairs = symbolCharacters.div_ceil(2);
+    let finderPatterns = &FINDER_PATTERN_SEQUENCES[pairs - 2];
+
+    let mut checksum = 0;
+    for position in 1..symbolCharacters {
+        let value = (0..12).fold(0, |value, bit| {
+            value << 1 | u32::from(bits.get((position - 1) * 12 + bit))
+        });
+        let widths = dataCharacter(value);
+        let pair = position / 2;
+        let weightRow =
+            4 * finderPatterns[pair] as usize + if pair % 2 == 0 { 0 } else { 2 } + position % 2
+                - 1;
+        for (i, width) in widths.iter().enumerate() {
+            checksum += width * RSSExpandedReader::WEIGHTS[weightRow][i];
+        }
+        characters.push(widths);
+    }
+    characters[0] = dataCharacter(211 * (symbolCharacters as u32 - 4) + checksum % 211);
+
+    let pairsPerRow = segments.map_or(pairs, |segments| segments / 2);
+    let mut rows = Vec::new();
+    let mut previousSeparator: Option<Vec<bool>> = None;
+    for (row, rowPairs) in (0..pairs)
+        .collect::<Vec<_>>()
+        .chunks(pairsPerRow)
+        .enumerate()
+    {
+        let mut widths = vec![1, 1];
+        for pair in rowPairs {
+            let finder = RSSExpandedReader::FINDER_PATTERNS[finderPatterns[*pair] as usize];
+            widths.extend(characters[2 * pair]);
+            if pair % 2 == 0 {
+                widths.extend(finder);
+                widths.push(1);
+            } else {
+                widths.push(1);
+                widths.extend(finder.iter().rev());
+            }
+            if let Some(right) = characters.get(2 * pair + 1) {
+                widths.extend(right.iter().rev());
+            }
+        }
+        widths.extend([1, 1]);
+
+        // the colors carry on from the row above, as if the rows were one
+        let mut modules = rss_rows::modules(&widths, rowPairs[0] % 2 == 1);
+        let finders: Vec<usize> = (0..rowPairs.len())
+            .map(|i| FIRST_FINDER + PAIR_MODULES * i)
+            .collect();
+        let mut separator = rss_rows::separator(&modules, &finders);
+        // with an even number of pairs per row every other row is read right to left
+        if pairsPerRow.is_multiple_of(2) && row % 2 == 1 {
+            modules.reverse();
+            separator.reverse();
+        }
+
+        if let Some(previousSeparator) = previousSeparator {
+            let width = previousSeparator.len();
+            rows.push((previousSeparator, 1));
+            rows.push((rss_rows::alternating_row(width), 1));
+            rows.push((separator.clone(), 1));
+        }
+        rows.push((modules, ROW_HEIGHT));
+        previousSeparator = Some(separator);
+    }
+
+    Ok(rows)
+}
+
+/**
+ * Computes the element widths of a data character, odd elements at even indices.
+ */
+fn dataCharacter(value: u32) -> [u32; 8] {
+    let group = RSSExpandedReader::GSUM
+        .iter()
+        .rposition(|gSum| *gSum <= value)
+        .unwrap_or_default();
+    let value = value - RSSExpandedReader::GSUM[group];
+    let tEven = RSSExpandedReader::EVEN_TOTAL_SUBSET[group];
+    let oddWidest = RSSExpandedReader::SYMBOL_WIDEST[group];
+    let oddModules = 12 - 2 * group as u32;
+    let odd = rss_utils::getRSSwidths(value / tEven, oddModules, 4, oddWidest, true);
+    let even = rss_utils::getRSSwidths(value % tEven, 17 - oddModules, 4, 9 - oddWidest, false);
+
+    let mut widths = [0; 8];
+    for i in 0..4 {
+        widths[2 * i] = odd[i];
+        widths[2 * i + 1] = even[i];
+    }
+    widths
+}
@@ -0,0 +1,141 @@
+/**
+ * Tests {@link RSSExpandedWriter}.
+ */
+use std::collections::HashMap;
+
+use crate::{
+    common::{bit_matrix_test_case, BitMatrix},
+    BarcodeFormat, DecodeHintType, DecodeHintValue, EncodeHintType, EncodeHintValue,
+    MultiFormatWriter, Reader, Writer,
+};
+
+use super::{
+    decoders::abstract_expanded_decoder::createDecoder, expanded_information_encoder,
+    RSSExpandedReader, RSSExpandedWriter,
+};
+
+const CONTENTS: [&str; 13] = [
+    "(01)90012345678908(3103)001750",
+    "(01)90012345678908(3202)001750",
+    "(01)90012345678908(3203)001750",
+    "(01)90012345678908(3102)001750(11)100312",
+    "(01)90012345678908(3205)012345(17)100312",
+    "(01)90012345678908(3922)795",
+    "(01)90012345678908(3922)7958888888888888888888888888888888888888888888888888888",
+    "(01)90012345678908(3932)0401234",
+    "(01)98898765432106(3202)012345(15)991231",
+    "(01)00012345678905(10)ABC123",
+    "(10)12A(21)abc-def",
+    "(01)12345678901231(10)A1B2C3D4E5F6G7H8(21)12345",
+    "(240)A+B-C*D/E(8004)HELLO world!(90)12",
+];
+
+fn encode(contents: &str, segments: Option<u32>) -> BitMatrix {
+    let mut hints = HashMap::new();
+    if let Some(segments) = segments {
+        hints.insert(
+            EncodeHintType::RSS_EXPANDED_SEGMENTS,
+            EncodeHintValue::RssExpandedSegments(segments),
+        );
+    }
+    RSSExpandedWriter
+        .encode_with_hints(contents, &BarcodeFormat::RSS_EXPANDED, 0, 0, &hints)
+        .expect("must encode")
+}
+
+fn decode(matrix: &BitMatrix) -> String {
+    let mut image = bit_matrix_test_case::matrix_to_binary_bitmap(matrix);
+    let mut hints = HashMap::new();
+    hints.insert(DecodeHintType::TRY_HARDER, DecodeHintValue::TryHarder(true));
+    RSSExpandedReader::new()
+        .decode_with_hints(&mut image, &hints)
+        .expect("must decode")
+        .getText()
+        .to_owned()
+}
+
+#[test]
+fn testInformationRoundTrip() {
+    for contents in CONTENTS {
+        let bits = expanded_information_encoder::encode(contents).expect("must encode");
+        assert_eq!(0, bits.get_size() % 12);
+        let mut decoder = createDecoder(&bits).expect("must create decoder");
+        assert_eq!(contents, decoder.parseInformation().expect("must parse"));
+    }
+}
+
+#[test]
+fn testCompressedMethods() {
+    // 5 data characters for a GTIN and a 15 bit weight
+    let bits = expanded_information_encoder::encode(CONTENTS[0]).expect("must encode");
+    assert_eq!(60, bits.get_size());
+    // 7 data characters for a GTIN, a 20 bit weight and a date
+    let bits = expanded_information_encoder::encode(CONTENTS[3]).expect("must encode");
+    assert_eq!(84, bits.get_size());
+}
+
+#[test]
+fn testRoundTrip() {
+    for contents in CONTENTS {
+        assert_eq!(contents, decode(&encode(contents, None)));
+    }
+}
+
+#[test]
+fn testStackedRoundTrip() {
+    for segments in [2, 4, 6, 8] {
+        for contents in CONTENTS {
+            assert_eq!(
+                contents,
+                decode(&encode(contents, Some(segments))),
+                "{segments} segments per row"
+            );
+        }
+    }
+}
+
+#[test]
+fn testStackedDimensions() {
+    let linear = encode(CONTENTS[0], None);
+    // 6 symbol characters in 3 pairs
+    assert_eq!(2 + 3 * 49 + 2 + 10, linear.getWidth());
+    assert_eq!(34, linear.getHeight());
+
+    let stacked = encode(CONTENTS[0], Some(2));
+    assert_eq!(2 + 49 + 2 + 10, stacked.getWidth());
+    assert_eq!(3 * 34 + 2 * 3, stacked.getHeight());
+}
+
+#[test]
+fn testMultiFormatWriter() {
+    let matrix = MultiFormatWriter
+        .encode(CONTENTS[9], &BarcodeFormat::RSS_EXPANDED, 0, 0)
+        .expect("must encode");
+    assert_eq!(CONTENTS[9], decode(&matrix));
+}
+
+#[test]
+fn testBadContents() {
+    for contents in [
+        "",
+        "0112345678901231",
+        "(01)12345678901234",
+        "(01)1234567890123",
+        "(10)",
+        "(10)ABC\u{00e9}",
+        "(10)ABCDEFGHIJKLMNOPQRST(21)ABCDEFGHIJKLMNOPQRST(22)ABCDEFGHIJKLMNOPQRST",
+    ] {
+        assert!(RSSExpandedWriter
+            .encode(contents, &BarcodeFormat::RSS_EXPANDED, 0, 0)
+            .is_err());
+    }
+
+    let mut hints = HashMap::new();
+    hints.insert(
+        EncodeHintType::RSS_EXPANDED_SEGMENTS,
+        EncodeHintValue::RssExpandedSegments(3),
+    );
+    assert!(RSSExpandedWriter
+        .encode_with_hints(CONTENTS[0], &BarcodeFormat::RSS_EXPANDED, 0, 0, &hints)
+        .is_err());
+}
@@ -16,3 +16,23 @@ pub use abstract_rss_reader::*;
 
 mod rss_14_reader;
 pub use rss_14_reader::*;
+
+mod rss_rows;
+
+mod rss_14_variant;
+pub use rss_14_variant::*;
+
+mod rss_14_writer;
+pub use rss_14_writer::*;
+
+mod rss_limited_reader;
+pub use rss_limited_reader::*;
+
+mod rss_limited_writer;
+pub use rss_limited_writer::*;
+
+#[cfg(test)]
+mod rss_14_writer_test_case;
+
+#[cfg(test)]
+mod rss_limited_test_case;
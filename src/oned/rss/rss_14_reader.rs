@@ -135,14 +135,14 @@ impl Reader for RSS14Reader {
 }
 
 impl RSS14Reader {
-    const OUTSIDE_EVEN_TOTAL_SUBSET: [u32; 5] = [1, 10, 34, 70, 126];
-    const INSIDE_ODD_TOTAL_SUBSET: [u32; 4] = [4, 20, 48, 81];
-    const OUTSIDE_GSUM: [u32; 5] = [0, 161, 961, 2015, 2715];
-    const INSIDE_GSUM: [u32; 4] = [0, 336, 1036, 1516];
-    const OUTSIDE_ODD_WIDEST: [u32; 5] = [8, 6, 4, 3, 1];
-    const INSIDE_ODD_WIDEST: [u32; 4] = [2, 4, 6, 8];
-
-    const FINDER_PATTERNS: [[u32; 4]; 9] = [
+    pub(super) const OUTSIDE_EVEN_TOTAL_SUBSET: [u32; 5] = [1, 10, 34, 70, 126];
+    pub(super) const INSIDE_ODD_TOTAL_SUBSET: [u32; 4] = [4, 20, 48, 81];
+    pub(super) const OUTSIDE_GSUM: [u32; 5] = [0, 161, 961, 2015, 2715];
+    pub(super) const INSIDE_GSUM: [u32; 4] = [0, 336, 1036, 1516];
+    pub(super) const OUTSIDE_ODD_WIDEST: [u32; 5] = [8, 6, 4, 3, 1];
+    pub(super) const INSIDE_ODD_WIDEST: [u32; 4] = [2, 4, 6, 8];
+
+    pub(super) const FINDER_PATTERNS: [[u32; 4]; 9] = [
         [3, 8, 2, 1],
         [3, 5, 5, 1],
         [3, 3, 7, 1],
@@ -187,6 +187,9 @@ impl RSS14Reader {
 
     fn constructRXingResult(&self, leftPair: &Pair, rightPair: &Pair) -> Option<RXingResult> {
         let symbolValue: u64 = 4537077 * leftPair.getValue() as u64 + rightPair.getValue() as u64;
+        // values beyond 13 digits carry the linkage flag of GS1 Composite symbols
+        let linked = symbolValue >= 10_000_000_000_000;
+        let symbolValue = symbolValue % 10_000_000_000_000;
         let text = symbolValue.to_string();
 
         let mut buffer = String::with_capacity(14);
@@ -222,6 +225,12 @@ impl RSS14Reader {
             RXingResultMetadataType::SYMBOLOGY_IDENTIFIER,
             RXingResultMetadataValue::SymbologyIdentifier("]e0".to_owned()),
         );
+        if linked {
+            result.putMetadata(
+                RXingResultMetadataType::IS_GS1_COMPOSITE,
+                RXingResultMetadataValue::IsGs1Composite(true),
+            );
+        }
 
         Some(result)
     }
@@ -0,0 +1,19 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/**
+ * The layouts an RSS-14 (GS1 DataBar) symbol can be printed in. All of them carry the same
+ * 14 digit GTIN, the stacked ones split the symbol in two rows.
+ */
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RSS14Variant {
+    /** Single row, 33 modules tall */
+    OMNIDIRECTIONAL,
+    /** Single row, 13 modules tall */
+    TRUNCATED,
+    /** Two rows of 5 and 7 modules with a separator */
+    STACKED,
+    /** Two rows of 33 modules with a three row separator */
+    STACKED_OMNIDIRECTIONAL,
+}
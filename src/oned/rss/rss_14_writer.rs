@@ -0,0 +1,274 @@
+use std::collections::HashMap;
+
+use crate::{
+    common::{BitMatrix, Result},
+    BarcodeFormat, EncodeHintType, EncodeHintValue, Exceptions, Writer,
+};
+
+use super::{
+    rss_rows::{self, SymbolRow},
+    rss_utils, RSS14Reader, RSS14Variant,
+};
+
+/// Index of the first element of the right half, where stacked symbols are split
+const RIGHT_HALF_START: usize = 23;
+/// Positions of the left and right finder patterns in the top and bottom rows of stacked symbols
+const TOP_FINDER: usize = 18;
+const BOTTOM_FINDER: usize = 17;
+
+/**
+ * This object renders an RSS-14 (GS1 DataBar) code as a {@link BitMatrix}.
+ *
+ * The layout is selected with {@link EncodeHintType#RSS_14_VARIANT} and defaults to
+ * omnidirectional. The contents are the 13 digits of a GTIN, or 14 with a check digit.
+ *
+ * GS1 DataBar Limited is a symbology of its own, with other characters and check character
+ * patterns, and is written by {@link RSSLimitedWriter}.
+ */
+#[derive(Default)]
+pub struct RSS14Writer;
+
+impl RSS14Writer {
+    /**
+     * Encodes the linear component of an omnidirectional GS1 Composite symbol, with the linkage flag
+     * set.
+     */
+    #[cfg(test)]
+    pub(crate) fn encodeLinked(contents: &str, height: i32) -> Result<BitMatrix> {
+        let widths = encodeWidths(contents, true)?;
+        rss_rows::render(
+            &layout(&widths, RSS14Variant::OMNIDIRECTIONAL),
+            0,
+            height,
+            0,
+        )
+    }
+}
+
+impl Writer for RSS14Writer {
+    fn encode(
+        &self,
+        contents: &str,
+        format: &BarcodeFormat,
+        width: i32,
+        height: i32,
+    ) -> Result<BitMatrix> {
+        self.encode_with_hints(contents, format, width, height, &HashMap::new())
+    }
+
+    fn encode_with_hints(
+        &self,
+        contents: &str,
+        format: &BarcodeFormat,
+        width: i32,
+        height: i32,
+        hints: &crate::EncodingHintDictionary,
+    ) -> Result<BitMatrix> {
+        if contents.is_empty() {
+            return Err(Exceptions::illegal_argument_with("Found empty contents"));
+        }
+
+        if format != &BarcodeFormat::RSS_14 {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "Can only encode RSS_14, but got {format:?}"
+            )));
+        }
+
+        if width < 0 || height < 0 {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "Negative size is not allowed. Input: {width}x{height}"
+            )));
+        }
+
+        let mut sidesMargin = 10;
+        if let Some(EncodeHintValue::Margin(margin)) = hints.get(&EncodeHintType::MARGIN) {
+            sidesMargin = margin.parse::<u32>().map_err(|e| {
+                Exceptions::illegal_argument_with(format!("couldnt parse {margin}: {e}"))
+            })?;
+        }
+
+        let variant = if let Some(EncodeHintValue::Rss14Variant(variant)) =
+            hints.get(&EncodeHintType::RSS_14_VARIANT)
+        {
+            *variant
+        } else {
+            RSS14Variant::OMNIDIRECTIONAL
+        };
+
+        let widths = encodeWidths(contents, false)?;
+        rss_rows::render(&layout(&widths, variant), width, height, sidesMargin)
+    }
+}
+
+/**
+ * Parses the 13 digits of a GTIN, or 14 with a check digit, which must then be correct.
+ *
+ * @return the value of the first 13 digits
+ */
+pub(super) fn parseGtin(contents: &str) -> Result<u64> {
+    let length = contents.chars().count();
+    if !(13..=14).contains(&length) || !contents.chars().all(|c| c.is_ascii_digit()) {
+        return Err(Exceptions::illegal_argument_with(format!(
+            "Requested contents should be 13 or 14 digits long, but got {length}"
+        )));
+    }
+    let digits: Vec<u32> = contents.chars().filter_map(|c| c.to_digit(10)).collect();
+    let checkDigit = (10
+        - digits
+            .iter()
+            .take(13)
+            .enumerate()
+            .map(|(i, digit)| if i % 2 == 0 { 3 * digit } else { *digit })
+            .sum::<u32>()
+            % 10)
+        % 10;
+    if length == 14 && digits[13] != checkDigit {
+        return Err(Exceptions::illegal_argument_with(
+            "Contents do not pass checksum",
+        ));
+    }
+
+    Ok(digits
+        .iter()
+        .take(13)
+        .fold(0u64, |value, digit| value * 10 + *digit as u64))
+}
+
+/**
+ * Computes the 46 element widths of the linear symbol, starting with the space of the left guard.
+ *
+ * @param linked whether the linkage flag to a 2D component is set
+ */
+fn encodeWidths(contents: &str, linked: bool) -> Result<[u32; 46]> {
+    let symbolValue = parseGtin(contents)? + if linked { 10_000_000_000_000 } else { 0 };
+    let leftPair = (symbolValue / 4537077) as u32;
+    let rightPair = (symbolValue % 4537077) as u32;
+
+    let (leftOutside, leftOutsideChecksum) = outsideCharacter(leftPair / 1597);
+    let (leftInside, leftInsideChecksum) = insideCharacter(leftPair % 1597);
+    let (rightOutside, rightOutsideChecksum) = outsideCharacter(rightPair / 1597);
+    let (rightInside, rightInsideChecksum) = insideCharacter(rightPair % 1597);
+
+    let leftChecksum = leftOutsideChecksum + 4 * leftInsideChecksum;
+    let rightChecksum = rightOutsideChecksum + 4 * rightInsideChecksum;
+    // the finder combinations (0, 8) and (8, 0) are not used
+    let mut checkValue = (leftChecksum + 16 * rightChecksum) % 79;
+    if checkValue >= 8 {
+        checkValue += 1;
+    }
+    if checkValue >= 72 {
+        checkValue += 1;
+    }
+    let leftFinder = RSS14Reader::FINDER_PATTERNS[checkValue as usize / 9];
+    let rightFinder = RSS14Reader::FINDER_PATTERNS[checkValue as usize % 9];
+
+    let mut widths = [1; 46];
+    widths[2..10].copy_from_slice(&leftOutside);
+    widths[10..14].copy_from_slice(&leftFinder);
+    widths[15..23].copy_from_slice(&leftInside);
+    widths[15..23].reverse();
+    widths[23..31].copy_from_slice(&rightInside);
+    widths[32..36].copy_from_slice(&rightFinder);
+    widths[32..36].reverse();
+    widths[36..44].copy_from_slice(&rightOutside);
+    widths[36..44].reverse();
+    Ok(widths)
+}
+
+/**
+ * Arranges the linear symbol in the rows of the requested variant.
+ */
+fn layout(widths: &[u32; 46], variant: RSS14Variant) -> Vec<SymbolRow> {
+    match variant {
+        RSS14Variant::OMNIDIRECTIONAL => vec![(rss_rows::modules(widths, false), 33)],
+        RSS14Variant::TRUNCATED => vec![(rss_rows::modules(widths, false), 13)],
+        RSS14Variant::STACKED | RSS14Variant::STACKED_OMNIDIRECTIONAL => {
+            let mut topWidths = widths[..RIGHT_HALF_START].to_vec();
+            topWidths.extend([1, 1]);
+            let mut bottomWidths = vec![1, 1];
+            bottomWidths.extend(&widths[RIGHT_HALF_START..]);
+            let top = rss_rows::modules(&topWidths, false);
+            let bottom = rss_rows::modules(&bottomWidths, true);
+
+            if variant == RSS14Variant::STACKED {
+                let separator = stackedSeparator(&top, &bottom);
+                vec![(top, 5), (separator, 1), (bottom, 7)]
+            } else {
+                let topSeparator = rss_rows::separator(&top, &[TOP_FINDER]);
+                let bottomSeparator = rss_rows::separator(&bottom, &[BOTTOM_FINDER]);
+                let middle = rss_rows::alternating_row(top.len());
+                vec![
+                    (top, 33),
+                    (topSeparator, 1),
+                    (middle, 1),
+                    (bottomSeparator, 1),
+                    (bottom, 33),
+                ]
+            }
+        }
+    }
+}
+
+/**
+ * The separator between the two rows of a stacked symbol complements the modules where both
+ * rows agree, and alternates where they don't.
+ */
+fn stackedSeparator(top: &[bool], bottom: &[bool]) -> Vec<bool> {
+    let mut separator = vec![false; top.len()];
+    for i in 1..top.len() - 4 {
+        separator[i] = if top[i] == bottom[i] {
+            !top[i]
+        } else {
+            !separator[i - 1]
+        };
+    }
+    separator[..4].fill(false);
+    separator
+}
+
+/**
+ * Computes the element widths and the checksum portion of an outside data character.
+ */
+fn outsideCharacter(value: u32) -> ([u32; 8], u32) {
+    let group = RSS14Reader::OUTSIDE_GSUM
+        .iter()
+        .rposition(|gSum| *gSum <= value)
+        .unwrap_or_default();
+    let value = value - RSS14Reader::OUTSIDE_GSUM[group];
+    let tEven = RSS14Reader::OUTSIDE_EVEN_TOTAL_SUBSET[group];
+    let oddWidest = RSS14Reader::OUTSIDE_ODD_WIDEST[group];
+    let oddModules = 12 - 2 * group as u32;
+    let odd = rss_utils::getRSSwidths(value / tEven, oddModules, 4, oddWidest, false);
+    let even = rss_utils::getRSSwidths(value % tEven, 16 - oddModules, 4, 9 - oddWidest, true);
+    interleave(&odd, &even)
+}
+
+/**
+ * Computes the element widths and the checksum portion of an inside data character.
+ */
+fn insideCharacter(value: u32) -> ([u32; 8], u32) {
+    let group = RSS14Reader::INSIDE_GSUM
+        .iter()
+        .rposition(|gSum| *gSum <= value)
+        .unwrap_or_default();
+    let value = value - RSS14Reader::INSIDE_GSUM[group];
+    let tOdd = RSS14Reader::INSIDE_ODD_TOTAL_SUBSET[group];
+    let oddWidest = RSS14Reader::INSIDE_ODD_WIDEST[group];
+    let evenModules = 10 - 2 * group as u32;
+    let odd = rss_utils::getRSSwidths(value % tOdd, 15 - evenModules, 4, oddWidest, true);
+    let even = rss_utils::getRSSwidths(value / tOdd, evenModules, 4, 9 - oddWidest, false);
+    interleave(&odd, &even)
+}
+
+fn interleave(odd: &[u32], even: &[u32]) -> ([u32; 8], u32) {
+    let mut widths = [0; 8];
+    let mut oddChecksumPortion = 0;
+    let mut evenChecksumPortion = 0;
+    for i in (0..4).rev() {
+        widths[2 * i] = odd[i];
+        widths[2 * i + 1] = even[i];
+        oddChecksumPortion = oddChecksumPortion * 9 + odd[i];
+        evenChecksumPortion = evenChecksumPortion * 9 + even[i];
+    }
+    (widths, oddChecksumPortion + 3 * evenChecksumPortion)
+}
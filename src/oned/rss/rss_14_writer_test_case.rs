@@ -0,0 +1,125 @@
+/**
+ * Tests {@link RSS14Writer}.
+ */
+use std::collections::HashMap;
+
+use crate::{
+    common::{bit_matrix_test_case, BitMatrix},
+    BarcodeFormat, DecodeHintType, DecodeHintValue, EncodeHintType, EncodeHintValue, Reader,
+    Writer,
+};
+
+use super::{rss_utils, RSS14Reader, RSS14Variant, RSS14Writer};
+
+fn encode(contents: &str, variant: RSS14Variant) -> BitMatrix {
+    let mut hints = HashMap::new();
+    hints.insert(
+        EncodeHintType::RSS_14_VARIANT,
+        EncodeHintValue::Rss14Variant(variant),
+    );
+    RSS14Writer
+        .encode_with_hints(contents, &BarcodeFormat::RSS_14, 0, 0, &hints)
+        .expect("must encode")
+}
+
+fn decode(matrix: &BitMatrix) -> String {
+    let mut image = bit_matrix_test_case::matrix_to_binary_bitmap(matrix);
+    let mut hints = HashMap::new();
+    hints.insert(DecodeHintType::TRY_HARDER, DecodeHintValue::TryHarder(true));
+    RSS14Reader::new()
+        .decode_with_hints(&mut image, &hints)
+        .expect("must decode")
+        .getText()
+        .to_owned()
+}
+
+fn assertWidthsRoundTrip(count: u32, n: u32, maxWidth: u32, noNarrow: bool) {
+    for value in 0..count {
+        let widths = rss_utils::getRSSwidths(value, n, 4, maxWidth, noNarrow);
+        assert_eq!(n, widths.iter().sum::<u32>());
+        assert_eq!(value, rss_utils::getRSSvalue(&widths, maxWidth, noNarrow));
+    }
+}
+
+#[test]
+fn testRSSwidthsRoundTrip() {
+    for group in 0..5 {
+        let tEven = RSS14Reader::OUTSIDE_EVEN_TOTAL_SUBSET[group];
+        let tOdd = ([161, 961, 2015, 2715, 2841][group] - RSS14Reader::OUTSIDE_GSUM[group]) / tEven;
+        let oddWidest = RSS14Reader::OUTSIDE_ODD_WIDEST[group];
+        let oddModules = 12 - 2 * group as u32;
+        assertWidthsRoundTrip(tOdd, oddModules, oddWidest, false);
+        assertWidthsRoundTrip(tEven, 16 - oddModules, 9 - oddWidest, true);
+    }
+    for group in 0..4 {
+        let tOdd = RSS14Reader::INSIDE_ODD_TOTAL_SUBSET[group];
+        let tEven = ([336, 1036, 1516, 1597][group] - RSS14Reader::INSIDE_GSUM[group]) / tOdd;
+        let oddWidest = RSS14Reader::INSIDE_ODD_WIDEST[group];
+        let evenModules = 10 - 2 * group as u32;
+        assertWidthsRoundTrip(tOdd, 15 - evenModules, oddWidest, true);
+        assertWidthsRoundTrip(tEven, evenModules, 9 - oddWidest, false);
+    }
+}
+
+#[test]
+fn testEncodeOmnidirectional() {
+    let matrix = encode("0950110153001", RSS14Variant::OMNIDIRECTIONAL);
+    assert_eq!(96 + 10, matrix.getWidth());
+    assert_eq!(33, matrix.getHeight());
+    assert_eq!("09501101530010", decode(&matrix));
+}
+
+#[test]
+fn testRoundTrip() {
+    for contents in [
+        "0000000000000",
+        "2001234567890",
+        "0441234567890",
+        "9999999999999",
+        "0012345678901",
+    ] {
+        for variant in [
+            RSS14Variant::OMNIDIRECTIONAL,
+            RSS14Variant::TRUNCATED,
+            RSS14Variant::STACKED,
+            RSS14Variant::STACKED_OMNIDIRECTIONAL,
+        ] {
+            assert_eq!(
+                contents,
+                &decode(&encode(contents, variant))[..13],
+                "{variant:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn testStackedDimensions() {
+    let matrix = RSS14Writer
+        .encode("0001234567890", &BarcodeFormat::RSS_14, 0, 0)
+        .expect("must encode");
+    assert_eq!(33, matrix.getHeight());
+    let stacked = encode("0001234567890", RSS14Variant::STACKED);
+    assert_eq!(50 + 10, stacked.getWidth());
+    assert_eq!(13, stacked.getHeight());
+    let stackedOmnidirectional = encode("0001234567890", RSS14Variant::STACKED_OMNIDIRECTIONAL);
+    assert_eq!(69, stackedOmnidirectional.getHeight());
+}
+
+#[test]
+fn testBadContents() {
+    for contents in [
+        "",
+        "123",
+        "000123456789012",
+        "00012345678906",
+        "000123456789O",
+    ] {
+        assert!(RSS14Writer
+            .encode(contents, &BarcodeFormat::RSS_14, 0, 0)
+            .is_err());
+    }
+    assert!(RSS14Writer
+        .encode("0001234567890", &BarcodeFormat::RSS_EXPANDED, 0, 0)
+        .is_err());
+}
@@ -0,0 +1,302 @@
+use rxing_one_d_proc_derive::OneDReader;
+
+use crate::{
+    common::{BitArray, Result},
+    oned::{one_d_reader, OneDReader},
+    point_f, BarcodeFormat, Exceptions, RXingResult,
+};
+
+use super::rss_utils;
+
+/// Elements from the bar of the left guard to the bar of the right guard
+const SYMBOL_ELEMENTS: usize = 45;
+/// Modules from the bar of the left guard to the bar of the right guard
+const SYMBOL_MODULES: f32 = 73.0;
+pub(super) const DATA_CHARACTER_MODULES: u32 = 26;
+const CHECK_CHARACTER_MODULES: u32 = 18;
+/// Added to the value of symbols whose linkage flag to a 2D component is set
+const LINKAGE_VALUE: u64 = 2015133531096;
+
+/**
+ * Decodes GS1 DataBar Limited (RSS Limited), a single row symbol of two data characters and a
+ * check character holding a GTIN whose first digit is 0 or 1. See ISO/IEC 24724:2011.
+ *
+ * <p>The symbol has no finder pattern, only the 89 check character patterns set it apart. As
+ * this makes it prone to false positives, it is only read when asked for with
+ * {@link DecodeHintType#POSSIBLE_FORMATS}.</p>
+ */
+#[derive(OneDReader)]
+pub struct RSSLimitedReader {
+    counters: Vec<u32>,
+}
+
+impl Default for RSSLimitedReader {
+    fn default() -> Self {
+        Self {
+            counters: Vec::with_capacity(80),
+        }
+    }
+}
+
+impl OneDReader for RSSLimitedReader {
+    fn decode_row(
+        &mut self,
+        rowNumber: u32,
+        row: &BitArray,
+        _hints: &DecodingHintDictionary,
+    ) -> Result<RXingResult> {
+        one_d_reader::record_runs(row, &mut self.counters);
+
+        let counters = &self.counters;
+        let mut left = counters[0] as usize;
+        for start in (1..counters.len()).step_by(2) {
+            let end = start + SYMBOL_ELEMENTS;
+            if end > counters.len() {
+                break;
+            }
+            let width = counters[start..end].iter().sum::<u32>() as usize;
+            let moduleSize = width as f32 / SYMBOL_MODULES;
+            // the space of the left guard and the one after the right guard are light too
+            let quiet = (start == 1 || counters[start - 1] as f32 >= moduleSize)
+                && (end == counters.len() || counters[end] as f32 >= moduleSize);
+            if let Some((gtin, linked)) = quiet
+                .then(|| Self::decodeSymbol(&counters[start..end]))
+                .flatten()
+            {
+                let mut result = RXingResult::new(
+                    &gtin,
+                    Vec::new(),
+                    vec![
+                        point_f(left as f32, rowNumber as f32),
+                        point_f((left + width) as f32, rowNumber as f32),
+                    ],
+                    BarcodeFormat::RSS_LIMITED,
+                );
+                result.putMetadata(
+                    RXingResultMetadataType::SYMBOLOGY_IDENTIFIER,
+                    RXingResultMetadataValue::SymbologyIdentifier("]e0".to_owned()),
+                );
+                if linked {
+                    result.putMetadata(
+                        RXingResultMetadataType::IS_GS1_COMPOSITE,
+                        RXingResultMetadataValue::IsGs1Composite(true),
+                    );
+                }
+                return Ok(result);
+            }
+            left += (counters[start] + counters[start + 1]) as usize;
+        }
+
+        Err(Exceptions::NOT_FOUND)
+    }
+}
+
+impl RSSLimitedReader {
+    pub(super) const GSUM: [u32; 7] = [0, 183064, 820064, 1000776, 1491021, 1979845, 1996939];
+    pub(super) const EVEN_TOTAL_SUBSET: [u32; 7] = [28, 728, 6454, 203, 2408, 1, 16632];
+    pub(super) const ODD_MODULES: [u32; 7] = [17, 13, 9, 15, 11, 19, 7];
+    pub(super) const ODD_WIDEST: [u32; 7] = [6, 5, 3, 5, 4, 8, 1];
+
+    /// Element widths of the check characters, by check value
+    pub(super) const CHECK_CHARACTERS: [[u32; 14]; 89] = [
+        [1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 3, 3, 1, 1],
+        [1, 1, 1, 1, 1, 1, 1, 1, 1, 2, 3, 2, 1, 1],
+        [1, 1, 1, 1, 1, 1, 1, 1, 1, 3, 3, 1, 1, 1],
+        [1, 1, 1, 1, 1, 1, 1, 2, 1, 1, 3, 2, 1, 1],
+        [1, 1, 1, 1, 1, 1, 1, 2, 1, 2, 3, 1, 1, 1],
+        [1, 1, 1, 1, 1, 1, 1, 3, 1, 1, 3, 1, 1, 1],
+        [1, 1, 1, 1, 1, 2, 1, 1, 1, 1, 3, 2, 1, 1],
+        [1, 1, 1, 1, 1, 2, 1, 1, 1, 2, 3, 1, 1, 1],
+        [1, 1, 1, 1, 1, 2, 1, 2, 1, 1, 3, 1, 1, 1],
+        [1, 1, 1, 1, 1, 3, 1, 1, 1, 1, 3, 1, 1, 1],
+        [1, 1, 1, 2, 1, 1, 1, 1, 1, 1, 3, 2, 1, 1],
+        [1, 1, 1, 2, 1, 1, 1, 1, 1, 2, 3, 1, 1, 1],
+        [1, 1, 1, 2, 1, 1, 1, 2, 1, 1, 3, 1, 1, 1],
+        [1, 1, 1, 2, 1, 2, 1, 1, 1, 1, 3, 1, 1, 1],
+        [1, 1, 1, 3, 1, 1, 1, 1, 1, 1, 3, 1, 1, 1],
+        [1, 2, 1, 1, 1, 1, 1, 1, 1, 1, 3, 2, 1, 1],
+        [1, 2, 1, 1, 1, 1, 1, 1, 1, 2, 3, 1, 1, 1],
+        [1, 2, 1, 1, 1, 1, 1, 2, 1, 1, 3, 1, 1, 1],
+        [1, 2, 1, 1, 1, 2, 1, 1, 1, 1, 3, 1, 1, 1],
+        [1, 2, 1, 2, 1, 1, 1, 1, 1, 1, 3, 1, 1, 1],
+        [1, 3, 1, 1, 1, 1, 1, 1, 1, 1, 3, 1, 1, 1],
+        [1, 1, 1, 1, 1, 1, 1, 1, 2, 1, 2, 3, 1, 1],
+        [1, 1, 1, 1, 1, 1, 1, 1, 2, 2, 2, 2, 1, 1],
+        [1, 1, 1, 1, 1, 1, 1, 1, 2, 3, 2, 1, 1, 1],
+        [1, 1, 1, 1, 1, 1, 1, 2, 2, 1, 2, 2, 1, 1],
+        [1, 1, 1, 1, 1, 1, 1, 2, 2, 2, 2, 1, 1, 1],
+        [1, 1, 1, 1, 1, 1, 1, 3, 2, 1, 2, 1, 1, 1],
+        [1, 1, 1, 1, 1, 2, 1, 1, 2, 1, 2, 2, 1, 1],
+        [1, 1, 1, 1, 1, 2, 1, 1, 2, 2, 2, 1, 1, 1],
+        [1, 1, 1, 1, 1, 2, 1, 2, 2, 1, 2, 1, 1, 1],
+        [1, 1, 1, 1, 1, 3, 1, 1, 2, 1, 2, 1, 1, 1],
+        [1, 1, 1, 2, 1, 1, 1, 1, 2, 1, 2, 2, 1, 1],
+        [1, 1, 1, 2, 1, 1, 1, 1, 2, 2, 2, 1, 1, 1],
+        [1, 1, 1, 2, 1, 1, 1, 2, 2, 1, 2, 1, 1, 1],
+        [1, 1, 1, 2, 1, 2, 1, 1, 2, 1, 2, 1, 1, 1],
+        [1, 1, 1, 3, 1, 1, 1, 1, 2, 1, 2, 1, 1, 1],
+        [1, 2, 1, 1, 1, 1, 1, 1, 2, 1, 2, 2, 1, 1],
+        [1, 2, 1, 1, 1, 1, 1, 1, 2, 2, 2, 1, 1, 1],
+        [1, 2, 1, 1, 1, 1, 1, 2, 2, 1, 2, 1, 1, 1],
+        [1, 2, 1, 1, 1, 2, 1, 1, 2, 1, 2, 1, 1, 1],
+        [1, 2, 1, 2, 1, 1, 1, 1, 2, 1, 2, 1, 1, 1],
+        [1, 3, 1, 1, 1, 1, 1, 1, 2, 1, 2, 1, 1, 1],
+        [1, 1, 1, 1, 1, 1, 1, 1, 3, 1, 1, 3, 1, 1],
+        [1, 1, 1, 1, 1, 1, 1, 1, 3, 2, 1, 2, 1, 1],
+        [1, 1, 1, 1, 1, 1, 1, 1, 3, 3, 1, 1, 1, 1],
+        [1, 1, 1, 1, 1, 1, 1, 2, 3, 1, 1, 2, 1, 1],
+        [1, 1, 1, 1, 1, 1, 1, 2, 3, 2, 1, 1, 1, 1],
+        [1, 1, 1, 1, 1, 1, 1, 3, 3, 1, 1, 1, 1, 1],
+        [1, 1, 1, 1, 1, 2, 1, 1, 3, 1, 1, 2, 1, 1],
+        [1, 1, 1, 1, 1, 2, 1, 1, 3, 2, 1, 1, 1, 1],
+        [1, 1, 1, 1, 1, 2, 1, 2, 3, 1, 1, 1, 1, 1],
+        [1, 1, 1, 1, 1, 3, 1, 1, 3, 1, 1, 1, 1, 1],
+        [1, 1, 1, 2, 1, 1, 1, 1, 3, 1, 1, 2, 1, 1],
+        [1, 1, 1, 2, 1, 1, 1, 1, 3, 2, 1, 1, 1, 1],
+        [1, 1, 1, 2, 1, 1, 1, 2, 3, 1, 1, 1, 1, 1],
+        [1, 1, 1, 2, 1, 2, 1, 1, 3, 1, 1, 1, 1, 1],
+        [1, 1, 1, 3, 1, 1, 1, 1, 3, 1, 1, 1, 1, 1],
+        [1, 2, 1, 1, 1, 1, 1, 1, 3, 1, 1, 2, 1, 1],
+        [1, 2, 1, 1, 1, 1, 1, 1, 3, 2, 1, 1, 1, 1],
+        [1, 2, 1, 1, 1, 1, 1, 2, 3, 1, 1, 1, 1, 1],
+        [1, 2, 1, 1, 1, 2, 1, 1, 3, 1, 1, 1, 1, 1],
+        [1, 2, 1, 2, 1, 1, 1, 1, 3, 1, 1, 1, 1, 1],
+        [1, 3, 1, 1, 1, 1, 1, 1, 3, 1, 1, 1, 1, 1],
+        [1, 1, 1, 1, 1, 1, 2, 1, 1, 1, 2, 3, 1, 1],
+        [1, 1, 1, 1, 1, 1, 2, 1, 1, 2, 2, 2, 1, 1],
+        [1, 1, 1, 1, 1, 1, 2, 1, 1, 3, 2, 1, 1, 1],
+        [1, 1, 1, 1, 1, 1, 2, 2, 1, 1, 2, 2, 1, 1],
+        [1, 1, 1, 1, 1, 1, 2, 2, 1, 2, 2, 1, 1, 1],
+        [1, 1, 1, 1, 1, 1, 2, 3, 1, 1, 2, 1, 1, 1],
+        [1, 1, 1, 1, 1, 2, 2, 1, 1, 1, 2, 2, 1, 1],
+        [1, 1, 1, 1, 1, 2, 2, 1, 1, 2, 2, 1, 1, 1],
+        [1, 1, 1, 1, 1, 2, 2, 2, 1, 1, 2, 1, 1, 1],
+        [1, 1, 1, 1, 1, 3, 2, 1, 1, 1, 2, 1, 1, 1],
+        [1, 1, 1, 2, 1, 1, 2, 1, 1, 1, 2, 2, 1, 1],
+        [1, 1, 1, 2, 1, 1, 2, 1, 1, 2, 2, 1, 1, 1],
+        [1, 1, 1, 2, 1, 1, 2, 2, 1, 1, 2, 1, 1, 1],
+        [1, 1, 1, 2, 1, 2, 2, 1, 1, 1, 2, 1, 1, 1],
+        [1, 1, 1, 3, 1, 1, 2, 1, 1, 1, 2, 1, 1, 1],
+        [1, 2, 1, 1, 1, 1, 2, 1, 1, 1, 2, 2, 1, 1],
+        [1, 2, 1, 1, 1, 1, 2, 1, 1, 2, 2, 1, 1, 1],
+        [1, 2, 1, 1, 1, 1, 2, 2, 1, 1, 2, 1, 1, 1],
+        [1, 2, 1, 1, 1, 2, 2, 1, 1, 1, 2, 1, 1, 1],
+        [1, 2, 1, 2, 1, 1, 2, 1, 1, 1, 2, 1, 1, 1],
+        [1, 3, 1, 1, 1, 1, 2, 1, 1, 1, 2, 1, 1, 1],
+        [1, 1, 1, 1, 1, 1, 2, 1, 2, 1, 1, 3, 1, 1],
+        [1, 1, 1, 1, 1, 1, 2, 1, 2, 2, 1, 2, 1, 1],
+        [1, 1, 1, 1, 1, 1, 2, 1, 2, 3, 1, 1, 1, 1],
+        [1, 1, 1, 1, 1, 1, 2, 2, 2, 1, 1, 2, 1, 1],
+        [1, 1, 1, 1, 1, 1, 2, 2, 2, 2, 1, 1, 1, 1],
+    ];
+
+    /**
+     * Computes the check value of the element widths of the two data characters, the weights
+     * being the powers of 3 modulo 89.
+     */
+    pub(super) fn checksum(left: &[u32; 14], right: &[u32; 14]) -> usize {
+        let mut checksum = 0;
+        let mut weight = 1;
+        for width in left.iter().chain(right) {
+            checksum += weight * width;
+            weight = weight * 3 % 89;
+        }
+        (checksum % 89) as usize
+    }
+
+    /**
+     * @param counters widths of the 45 elements from the bar of the left guard to the bar of the
+     *  right guard
+     * @return the GTIN with its check digit and whether the linkage flag is set
+     */
+    fn decodeSymbol(counters: &[u32]) -> Option<(String, bool)> {
+        let moduleSize = counters.iter().sum::<u32>() as f32 / SYMBOL_MODULES;
+        // the guards are made of single modules
+        if [0, 43, 44]
+            .iter()
+            .any(|i| counters[*i] as f32 > 1.5 * moduleSize)
+        {
+            return None;
+        }
+
+        let left = Self::readWidths(&counters[1..15], DATA_CHARACTER_MODULES)?;
+        let check = Self::readWidths(&counters[15..29], CHECK_CHARACTER_MODULES)?;
+        let right = Self::readWidths(&counters[29..43], DATA_CHARACTER_MODULES)?;
+        if Self::CHECK_CHARACTERS.get(Self::checksum(&left, &right)) != Some(&check) {
+            return None;
+        }
+
+        let value = Self::dataCharacterValue(&left)? as u64 * 2013571
+            + Self::dataCharacterValue(&right)? as u64;
+        let linked = value >= LINKAGE_VALUE;
+        let value = if linked { value - LINKAGE_VALUE } else { value };
+        // the first digit is 0 or 1
+        if value >= 2_000_000_000_000 {
+            return None;
+        }
+
+        let mut gtin = format!("{value:013}");
+        let sum = gtin
+            .bytes()
+            .enumerate()
+            .map(|(i, digit)| (digit - b'0') as u32 * if i % 2 == 0 { 3 } else { 1 })
+            .sum::<u32>();
+        gtin.push(char::from(b'0' + ((10 - sum % 10) % 10) as u8));
+        Some((gtin, linked))
+    }
+
+    /**
+     * Rounds the widths of the 14 elements of a character to modules, so that they add up to
+     * the width of the character.
+     */
+    fn readWidths(counters: &[u32], modules: u32) -> Option<[u32; 14]> {
+        let moduleSize = counters.iter().sum::<u32>() as f32 / modules as f32;
+        let mut widths = [0; 14];
+        let mut roundingErrors = [0.0; 14];
+        for (i, counter) in counters.iter().enumerate() {
+            let exact = *counter as f32 / moduleSize;
+            widths[i] = (exact.round() as u32).max(1);
+            roundingErrors[i] = widths[i] as f32 - exact;
+        }
+
+        // take modules from the elements rounded up the most, give them to those rounded down
+        let mut total = widths.iter().sum::<u32>();
+        while total > modules {
+            let i = (0..14)
+                .filter(|i| widths[*i] > 1)
+                .max_by(|a, b| roundingErrors[*a].total_cmp(&roundingErrors[*b]))?;
+            widths[i] -= 1;
+            roundingErrors[i] -= 1.0;
+            total -= 1;
+        }
+        while total < modules {
+            let i = (0..14).min_by(|a, b| roundingErrors[*a].total_cmp(&roundingErrors[*b]))?;
+            widths[i] += 1;
+            roundingErrors[i] += 1.0;
+            total += 1;
+        }
+        Some(widths)
+    }
+
+    /**
+     * @return the value of a data character, if its element widths belong to one
+     */
+    fn dataCharacterValue(widths: &[u32; 14]) -> Option<u32> {
+        let odd: Vec<u32> = widths.iter().step_by(2).copied().collect();
+        let even: Vec<u32> = widths.iter().skip(1).step_by(2).copied().collect();
+        let group = Self::ODD_MODULES
+            .iter()
+            .position(|modules| *modules == odd.iter().sum::<u32>())?;
+        let oddWidest = Self::ODD_WIDEST[group];
+        // the even elements always include a narrow one
+        if odd.iter().any(|width| *width > oddWidest)
+            || even.iter().any(|width| *width > 9 - oddWidest)
+            || !even.contains(&1)
+        {
+            return None;
+        }
+        let oddValue = rss_utils::getRSSvalue(&odd, oddWidest, false);
+        let evenValue = rss_utils::getRSSvalue(&even, 9 - oddWidest, true);
+        Some(Self::GSUM[group] + oddValue * Self::EVEN_TOTAL_SUBSET[group] + evenValue)
+    }
+}
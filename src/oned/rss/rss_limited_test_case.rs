@@ -0,0 +1,147 @@
+/**
+ * Tests {@link RSSLimitedWriter} and {@link RSSLimitedReader}.
+ */
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    common::{bit_matrix_test_case, BitMatrix},
+    BarcodeFormat, DecodeHintType, DecodeHintValue, MultiFormatReader, MultiFormatWriter, Reader,
+    Writer,
+};
+
+use super::{rss_utils, RSSLimitedReader, RSSLimitedWriter};
+
+fn encode(contents: &str) -> BitMatrix {
+    RSSLimitedWriter
+        .encode(contents, &BarcodeFormat::RSS_LIMITED, 0, 0)
+        .expect("must encode")
+}
+
+fn decode(matrix: &BitMatrix) -> String {
+    RSSLimitedReader::default()
+        .decode(&mut bit_matrix_test_case::matrix_to_binary_bitmap(matrix))
+        .expect("must decode")
+        .getText()
+        .to_owned()
+}
+
+fn assertWidthsRoundTrip(count: u32, n: u32, maxWidth: u32, noNarrow: bool) {
+    for value in 0..count {
+        let widths = rss_utils::getRSSwidths(value, n, 7, maxWidth, noNarrow);
+        assert_eq!(n, widths.iter().sum::<u32>());
+        assert!(widths.iter().all(|width| *width <= maxWidth));
+        assert!(!noNarrow || widths.contains(&1));
+        assert_eq!(value, rss_utils::getRSSvalue(&widths, maxWidth, noNarrow));
+    }
+}
+
+#[test]
+fn testRSSwidthsRoundTrip() {
+    for group in 0..7 {
+        let tEven = RSSLimitedReader::EVEN_TOTAL_SUBSET[group];
+        let tOdd = ([183064, 820064, 1000776, 1491021, 1979845, 1996939, 2013571][group]
+            - RSSLimitedReader::GSUM[group])
+            / tEven;
+        let oddWidest = RSSLimitedReader::ODD_WIDEST[group];
+        let oddModules = RSSLimitedReader::ODD_MODULES[group];
+        assertWidthsRoundTrip(tOdd, oddModules, oddWidest, false);
+        assertWidthsRoundTrip(tEven, 26 - oddModules, 9 - oddWidest, true);
+    }
+}
+
+#[test]
+fn testCheckCharacters() {
+    let patterns = RSSLimitedReader::CHECK_CHARACTERS;
+    assert_eq!(89, patterns.iter().collect::<HashSet<_>>().len());
+    for pattern in patterns {
+        assert_eq!(9, pattern.iter().step_by(2).sum::<u32>());
+        assert_eq!(9, pattern.iter().skip(1).step_by(2).sum::<u32>());
+    }
+}
+
+#[test]
+fn testEncode() {
+    let matrix = encode("1501234567890");
+    assert_eq!(74 + 10, matrix.getWidth());
+    assert_eq!(10, matrix.getHeight());
+    // the bar of the left guard follows its space, the symbol ends with the bar of the right guard
+    assert!(!matrix.get(5, 0) && matrix.get(6, 0));
+    assert!(!matrix.get(77, 0) && matrix.get(78, 0) && !matrix.get(79, 0));
+    assert_eq!("15012345678907", decode(&matrix));
+}
+
+#[test]
+fn testRoundTrip() {
+    for contents in [
+        "0000000000000",
+        "0000000000001",
+        "0009876543210",
+        "0441234567890",
+        "0950110153001",
+        "1000000000000",
+        "1234567890123",
+        "1999999999999",
+    ] {
+        let matrix = encode(contents);
+        assert_eq!(contents, &decode(&matrix)[..13]);
+
+        let mut scaled = RSSLimitedWriter
+            .encode(contents, &BarcodeFormat::RSS_LIMITED, 300, 40)
+            .expect("must encode");
+        assert_eq!(contents, &decode(&scaled)[..13]);
+        // upside down
+        scaled.rotate180();
+        let hints = HashMap::from([(DecodeHintType::TRY_HARDER, DecodeHintValue::TryHarder(true))]);
+        let result = RSSLimitedReader::default()
+            .decode_with_hints(
+                &mut bit_matrix_test_case::matrix_to_binary_bitmap(&scaled),
+                &hints,
+            )
+            .expect("must decode");
+        assert_eq!(contents, &result.getText()[..13]);
+    }
+}
+
+#[test]
+fn testMultiFormat() {
+    let matrix = MultiFormatWriter
+        .encode("15012345678907", &BarcodeFormat::RSS_LIMITED, 0, 0)
+        .expect("must encode");
+    let hints = HashMap::from([(
+        DecodeHintType::POSSIBLE_FORMATS,
+        DecodeHintValue::PossibleFormats(HashSet::from([BarcodeFormat::RSS_LIMITED])),
+    )]);
+    let result = MultiFormatReader::default()
+        .decode_with_hints(
+            &mut bit_matrix_test_case::matrix_to_binary_bitmap(&matrix),
+            &hints,
+        )
+        .expect("must decode");
+    assert_eq!("15012345678907", result.getText());
+    assert_eq!(&BarcodeFormat::RSS_LIMITED, result.getBarcodeFormat());
+
+    // without a finder pattern it is only looked for when asked for
+    let mut image = bit_matrix_test_case::matrix_to_binary_bitmap(&matrix);
+    assert!(!MultiFormatReader::default()
+        .decode(&mut image)
+        .is_ok_and(|result| result.getBarcodeFormat() == &BarcodeFormat::RSS_LIMITED));
+}
+
+#[test]
+fn testBadContents() {
+    for contents in [
+        "",
+        "123",
+        "2001234567890",
+        "9501101530001",
+        "15012345678906",
+        "150123456789O",
+    ] {
+        assert!(RSSLimitedWriter
+            .encode(contents, &BarcodeFormat::RSS_LIMITED, 0, 0)
+            .is_err());
+    }
+    assert!(RSSLimitedWriter
+        .encode("1501234567890", &BarcodeFormat::RSS_14, 0, 0)
+        .is_err());
+}
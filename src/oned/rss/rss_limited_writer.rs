@@ -0,0 +1,122 @@
+use std::collections::HashMap;
+
+use crate::{
+    common::{BitMatrix, Result},
+    BarcodeFormat, EncodeHintType, EncodeHintValue, Exceptions, Writer,
+};
+
+use super::{
+    rss_14_writer::parseGtin, rss_limited_reader::DATA_CHARACTER_MODULES, rss_rows, rss_utils,
+    RSSLimitedReader,
+};
+
+/**
+ * This object renders a GS1 DataBar Limited (RSS Limited) code as a {@link BitMatrix}.
+ *
+ * The contents are the 13 digits of a GTIN, or 14 with a check digit, and must start with 0 or
+ * 1. The symbol is a single row 10 modules tall.
+ */
+#[derive(Default)]
+pub struct RSSLimitedWriter;
+
+impl Writer for RSSLimitedWriter {
+    fn encode(
+        &self,
+        contents: &str,
+        format: &BarcodeFormat,
+        width: i32,
+        height: i32,
+    ) -> Result<BitMatrix> {
+        self.encode_with_hints(contents, format, width, height, &HashMap::new())
+    }
+
+    fn encode_with_hints(
+        &self,
+        contents: &str,
+        format: &BarcodeFormat,
+        width: i32,
+        height: i32,
+        hints: &crate::EncodingHintDictionary,
+    ) -> Result<BitMatrix> {
+        if contents.is_empty() {
+            return Err(Exceptions::illegal_argument_with("Found empty contents"));
+        }
+
+        if format != &BarcodeFormat::RSS_LIMITED {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "Can only encode RSS_LIMITED, but got {format:?}"
+            )));
+        }
+
+        if width < 0 || height < 0 {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "Negative size is not allowed. Input: {width}x{height}"
+            )));
+        }
+
+        let mut sidesMargin = 10;
+        if let Some(EncodeHintValue::Margin(margin)) = hints.get(&EncodeHintType::MARGIN) {
+            sidesMargin = margin.parse::<u32>().map_err(|e| {
+                Exceptions::illegal_argument_with(format!("couldnt parse {margin}: {e}"))
+            })?;
+        }
+
+        let widths = encodeWidths(contents)?;
+        rss_rows::render(
+            &[(rss_rows::modules(&widths, false), 10)],
+            width,
+            height,
+            sidesMargin,
+        )
+    }
+}
+
+/**
+ * Computes the 46 element widths of the symbol, starting with the space of the left guard.
+ */
+fn encodeWidths(contents: &str) -> Result<[u32; 46]> {
+    let symbolValue = parseGtin(contents)?;
+    if symbolValue >= 2_000_000_000_000 {
+        return Err(Exceptions::illegal_argument_with(
+            "GS1 DataBar Limited can only encode GTINs starting with 0 or 1",
+        ));
+    }
+
+    let left = dataCharacter((symbolValue / 2013571) as u32);
+    let right = dataCharacter((symbolValue % 2013571) as u32);
+    let check = RSSLimitedReader::CHECK_CHARACTERS[RSSLimitedReader::checksum(&left, &right)];
+
+    let mut widths = [1; 46];
+    widths[2..16].copy_from_slice(&left);
+    widths[16..30].copy_from_slice(&check);
+    widths[30..44].copy_from_slice(&right);
+    Ok(widths)
+}
+
+/**
+ * Computes the element widths of a data character.
+ */
+fn dataCharacter(value: u32) -> [u32; 14] {
+    let group = RSSLimitedReader::GSUM
+        .iter()
+        .rposition(|gSum| *gSum <= value)
+        .unwrap_or_default();
+    let value = value - RSSLimitedReader::GSUM[group];
+    let tEven = RSSLimitedReader::EVEN_TOTAL_SUBSET[group];
+    let oddWidest = RSSLimitedReader::ODD_WIDEST[group];
+    let oddModules = RSSLimitedReader::ODD_MODULES[group];
+    let odd = rss_utils::getRSSwidths(value / tEven, oddModules, 7, oddWidest, false);
+    let even = rss_utils::getRSSwidths(
+        value % tEven,
+        DATA_CHARACTER_MODULES - oddModules,
+        7,
+        9 - oddWidest,
+        true,
+    );
+    let mut widths = [0; 14];
+    for i in 0..7 {
+        widths[2 * i] = odd[i];
+        widths[2 * i + 1] = even[i];
+    }
+    widths
+}
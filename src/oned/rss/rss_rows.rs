@@ -0,0 +1,105 @@
+use crate::common::{BitMatrix, Result};
+
+/** A row of modules together with its height in module widths. */
+pub(super) type SymbolRow = (Vec<bool>, u32);
+
+/** Width in modules of the finder patterns of both RSS-14 and RSS Expanded. */
+pub(super) const FINDER_PATTERN_MODULES: usize = 15;
+
+/**
+ * Expands element widths into modules, alternating between bars and spaces.
+ *
+ * @param widths widths of the elements, in modules
+ * @param dark whether the first element is a bar
+ */
+pub(super) fn modules(widths: &[u32], dark: bool) -> Vec<bool> {
+    let mut modules = Vec::with_capacity(widths.iter().sum::<u32>() as usize);
+    let mut color = dark;
+    for width in widths {
+        modules.extend(std::iter::repeat_n(color, *width as usize));
+        color = !color;
+    }
+    modules
+}
+
+/**
+ * Builds the separator pattern bordering a row of a stacked symbol: the complement of the row
+ * except for the first and last four modules, where light modules over a finder pattern
+ * alternate so the separator doesn't look like a wide bar.
+ *
+ * @param row modules of the row next to the separator
+ * @param finders positions of the finder patterns in the row
+ */
+pub(super) fn separator(row: &[bool], finders: &[usize]) -> Vec<bool> {
+    let mut separator = vec![false; row.len()];
+    for i in 4..row.len().saturating_sub(4) {
+        separator[i] = !row[i];
+    }
+    for finder in finders {
+        for i in *finder..*finder + FINDER_PATTERN_MODULES {
+            if !row[i] && !row[i - 1] && separator[i - 1] {
+                separator[i] = false;
+            }
+        }
+    }
+    separator
+}
+
+/**
+ * The middle row of a three row separator, dark on every other module.
+ */
+pub(super) fn alternating_row(width: usize) -> Vec<bool> {
+    (0..width)
+        .map(|i| i >= 5 && i < width - 4 && i % 2 == 1)
+        .collect()
+}
+
+/**
+ * Renders the rows of a symbol, scaling the modules like
+ * {@link OneDimensionalCodeWriter#renderRXingResult} does. The symbol is at least as tall as the
+ * sum of the row heights, any extra height is shared out between the rows proportionally.
+ */
+pub(super) fn render(
+    rows: &[SymbolRow],
+    width: i32,
+    height: i32,
+    sidesMargin: u32,
+) -> Result<BitMatrix> {
+    let inputWidth = rows
+        .iter()
+        .map(|(row, _)| row.len())
+        .max()
+        .unwrap_or_default();
+    // Add quiet zone on both sides.
+    let fullWidth = inputWidth + sidesMargin as usize;
+    let outputWidth = (width as usize).max(fullWidth);
+
+    let multiple = outputWidth / fullWidth;
+    let leftPadding = (outputWidth - inputWidth * multiple) / 2;
+
+    let inputHeight = rows
+        .iter()
+        .map(|(_, rowHeight)| *rowHeight as usize)
+        .sum::<usize>();
+    let outputHeight = (height as usize).max(inputHeight * multiple);
+
+    let mut output = BitMatrix::new(outputWidth as u32, outputHeight as u32)?;
+
+    let mut inputY = 0;
+    let mut outputY = 0;
+    for (row, rowHeight) in rows {
+        inputY += *rowHeight as usize;
+        let nextOutputY = inputY * outputHeight / inputHeight;
+        for (inputX, _) in row.iter().enumerate().filter(|(_, dark)| **dark) {
+            output.setRegion(
+                (leftPadding + inputX * multiple) as u32,
+                outputY as u32,
+                multiple as u32,
+                (nextOutputY - outputY) as u32,
+            )?;
+        }
+        outputY = nextOutputY;
+    }
+
+    Ok(output)
+}
@@ -86,3 +86,49 @@ fn combins(n: u32, r: u32) -> u32 {
 
     val
 }
+
+/** The inverse of {@link #getRSSvalue}, yields the element widths of the given value. */
+pub fn getRSSwidths(val: u32, n: u32, elements: u32, maxWidth: u32, noNarrow: bool) -> Vec<u32> {
+    let mut widths = vec![0; elements as usize];
+    let mut val = val as i64;
+    let mut n = n;
+    let mut narrowMask = 0;
+    for bar in 0..(elements - 1) {
+        let mut elmWidth = 1;
+        narrowMask |= 1 << bar;
+        let mut subVal;
+        loop {
+            subVal = combins(n - elmWidth - 1, elements - bar - 2) as i64;
+            if noNarrow
+                && (narrowMask == 0)
+                && (n - elmWidth - (elements - bar - 1) >= elements - bar - 1)
+            {
+                subVal -= combins(n - elmWidth - (elements - bar), elements - bar - 2) as i64;
+            }
+            if elements - bar - 1 > 1 {
+                let mut lessVal = 0;
+                let mut mxwElement = n - elmWidth - (elements - bar - 2);
+                while mxwElement > maxWidth {
+                    lessVal += combins(n - elmWidth - mxwElement - 1, elements - bar - 3) as i64;
+
+                    mxwElement -= 1;
+                }
+                subVal -= lessVal * (elements - 1 - bar) as i64;
+            } else if n - elmWidth > maxWidth {
+                subVal -= 1;
+            }
+            val -= subVal;
+            if val < 0 {
+                break;
+            }
+
+            elmWidth += 1;
+            narrowMask &= !(1 << bar);
+        }
+        val += subVal;
+        n -= elmWidth;
+        widths[bar as usize] = elmWidth;
+    }
+    widths[elements as usize - 1] = n;
+    widths
+}
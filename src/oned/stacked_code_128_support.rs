@@ -0,0 +1,307 @@
+use crate::{
+    common::{BitMatrix, Result},
+    point_f, Exceptions, Point,
+};
+
+use super::code_128_writer::{
+    chooseCode, CODE_CODE_A, CODE_CODE_B, CODE_CODE_C, CODE_FNC_1, ESCAPE_FNC_1,
+};
+
+const CODE_FNC_2: u8 = 97;
+const CODE_FNC_3: u8 = 96;
+const CODE_SHIFT: u8 = 98;
+
+/**
+ * <p>Encodes text into the Code 128 codewords of Code Sets A, B and C, switching between them the
+ * way {@link Code128Writer} does. Stacked symbologies made of Code 128 characters, such as
+ * Codablock F and Code 16K, share it.</p>
+ *
+ * <p>Only ASCII can be encoded, and {@code 'ñ'} stands for FNC1.</p>
+ */
+pub(super) struct CodeSetEncoder<'a> {
+    contents: &'a str,
+    length: usize,
+    position: usize,
+    codeSet: usize,
+}
+
+impl<'a> CodeSetEncoder<'a> {
+    pub fn new(contents: &'a str) -> Result<Self> {
+        if let Some(c) = contents
+            .chars()
+            .find(|c| *c as u32 > 127 && *c != ESCAPE_FNC_1)
+        {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "Bad character in input: ASCII value={}",
+                c as u32
+            )));
+        }
+        Ok(Self {
+            contents,
+            length: contents.chars().count(),
+            position: 0,
+            codeSet: 0,
+        })
+    }
+
+    /** @return whether all of the text has been encoded */
+    pub fn isDone(&self) -> bool {
+        self.position >= self.length
+    }
+
+    /**
+     * Chooses the code set to start encoding the rest of the text in, without a latch.
+     *
+     * @return the code set, one of {@code CODE_CODE_A}, {@code CODE_CODE_B} and {@code CODE_CODE_C}
+     */
+    pub fn startCodeSet(&mut self) -> Result<usize> {
+        self.codeSet =
+            chooseCode(self.contents, self.position, 0).ok_or(Exceptions::ILLEGAL_STATE)?;
+        Ok(self.codeSet)
+    }
+
+    /**
+     * @return the next codeword, a latch to another code set or a character, if there is text
+     * left to encode
+     */
+    pub fn next(&mut self) -> Result<Option<u8>> {
+        if self.isDone() {
+            return Ok(None);
+        }
+        let newCodeSet = chooseCode(self.contents, self.position, self.codeSet)
+            .ok_or(Exceptions::ILLEGAL_STATE)?;
+        if newCodeSet != self.codeSet {
+            self.codeSet = newCodeSet;
+            return Ok(Some(newCodeSet as u8));
+        }
+
+        let c = self
+            .contents
+            .chars()
+            .nth(self.position)
+            .ok_or(Exceptions::INDEX_OUT_OF_BOUNDS)?;
+        self.position += 1;
+        let value = if c == ESCAPE_FNC_1 {
+            CODE_FNC_1
+        } else {
+            match self.codeSet {
+                // everything below a space character comes behind the underscore
+                CODE_CODE_A if c < ' ' => c as usize + 64,
+                CODE_CODE_A | CODE_CODE_B => c as usize - ' ' as usize,
+                _ => {
+                    let next = self
+                        .contents
+                        .chars()
+                        .nth(self.position)
+                        .and_then(|c| c.to_digit(10))
+                        .ok_or_else(|| {
+                            Exceptions::illegal_argument_with(
+                                "Bad number of characters for digit only encoding.",
+                            )
+                        })?;
+                    self.position += 1;
+                    c.to_digit(10).ok_or(Exceptions::ILLEGAL_STATE)? as usize * 10 + next as usize
+                }
+            }
+        };
+        Ok(Some(value as u8))
+    }
+
+    /**
+     * @return the codeword which switches to another code set without encoding anything, used to
+     * fill up rows
+     */
+    pub fn pad(&mut self) -> u8 {
+        self.codeSet = if self.codeSet == CODE_CODE_C {
+            CODE_CODE_B
+        } else {
+            CODE_CODE_C
+        };
+        self.codeSet as u8
+    }
+}
+
+/**
+ * <p>Decodes Code 128 codewords of Code Sets A, B and C back into text, with shifts, FNC1 and
+ * FNC4. The codewords of a stacked symbology may be fed to it row by row, the code set being
+ * chosen anew for every row.</p>
+ */
+pub(super) struct CodeSetDecoder {
+    codeSet: u8,
+    shifted: bool,
+    upperShift: bool,
+    fnc1First: bool,
+    result: String,
+}
+
+impl CodeSetDecoder {
+    pub fn new(codeSet: u8) -> Self {
+        Self {
+            codeSet,
+            shifted: false,
+            upperShift: false,
+            fnc1First: false,
+            result: String::new(),
+        }
+    }
+
+    pub fn setCodeSet(&mut self, codeSet: u8) -> Result<()> {
+        match codeSet as usize {
+            CODE_CODE_A | CODE_CODE_B | CODE_CODE_C => {
+                self.codeSet = codeSet;
+                Ok(())
+            }
+            _ => Err(Exceptions::FORMAT),
+        }
+    }
+
+    pub fn decode(&mut self, code: u8) -> Result<()> {
+        let codeSet = if self.shifted {
+            self.shifted = false;
+            if self.codeSet as usize == CODE_CODE_A {
+                CODE_CODE_B
+            } else {
+                CODE_CODE_A
+            }
+        } else {
+            self.codeSet as usize
+        };
+
+        if codeSet == CODE_CODE_C {
+            match code as usize {
+                0..=99 => self.result.push_str(&format!("{code:02}")),
+                CODE_CODE_A | CODE_CODE_B => self.codeSet = code,
+                CODE_FNC_1 => self.appendFNC1(),
+                _ => return Err(Exceptions::FORMAT),
+            }
+            return Ok(());
+        }
+
+        let fnc4 = if codeSet == CODE_CODE_A {
+            CODE_CODE_A
+        } else {
+            CODE_CODE_B
+        };
+        match code {
+            0..=95 => {
+                let c = if codeSet == CODE_CODE_A && code >= 64 {
+                    code - 64
+                } else {
+                    code + b' '
+                };
+                let c = if self.upperShift { c + 128 } else { c };
+                self.upperShift = false;
+                self.result.push(c as char);
+            }
+            CODE_FNC_2 | CODE_FNC_3 => {}
+            CODE_SHIFT => self.shifted = true,
+            _ if code as usize == fnc4 => self.upperShift = true,
+            _ => match code as usize {
+                CODE_CODE_A | CODE_CODE_B | CODE_CODE_C => self.codeSet = code,
+                CODE_FNC_1 => self.appendFNC1(),
+                _ => return Err(Exceptions::FORMAT),
+            },
+        }
+        Ok(())
+    }
+
+    /**
+     * FNC1 in front of the text marks GS1 data, everywhere else it separates fields.
+     */
+    fn appendFNC1(&mut self) {
+        if self.result.is_empty() {
+            self.fnc1First = true;
+        } else {
+            self.result.push(29 as char);
+        }
+    }
+
+    /** @return whether the text started with FNC1 */
+    pub fn isFNC1First(&self) -> bool {
+        self.fnc1First
+    }
+
+    pub fn getText(&self) -> &str {
+        &self.result
+    }
+}
+
+/**
+ * @return the text decoding the contents gives back: FNC1 in front is left out, every other one
+ * becomes a group separator
+ */
+pub(super) fn getDecodedText(contents: &str) -> String {
+    contents
+        .chars()
+        .enumerate()
+        .filter(|(i, c)| !(*i == 0 && *c == ESCAPE_FNC_1))
+        .map(|(_, c)| if c == ESCAPE_FNC_1 { 29 as char } else { c })
+        .collect()
+}
+
+/**
+ * <p>Renders the rows of a stacked symbology, each one the given number of modules high, with a
+ * separator bar of one module above, below and in between them. The rows are scaled horizontally
+ * like {@link OneDimensionalCodeWriter#renderRXingResult}, and fill the height.</p>
+ */
+pub(super) fn renderStackedRows(
+    rows: &[Vec<bool>],
+    rowHeight: u32,
+    width: i32,
+    height: i32,
+    sidesMargin: u32,
+) -> Result<BitMatrix> {
+    let inputWidth = rows.iter().map(Vec::len).max().unwrap_or_default() as u32;
+    let inputHeight = rows.len() as u32 * (rowHeight + 1) + 1;
+    let fullWidth = inputWidth + sidesMargin;
+    let outputWidth = (width as u32).max(fullWidth);
+    let outputHeight = (height as u32).max(inputHeight);
+
+    let multiple = outputWidth / fullWidth;
+    let verticalMultiple = outputHeight / inputHeight;
+    let leftPadding = (outputWidth - inputWidth * multiple) / 2;
+    let topPadding = (outputHeight - inputHeight * verticalMultiple) / 2;
+
+    let mut output = BitMatrix::new(outputWidth, outputHeight)?;
+    for (i, row) in rows.iter().enumerate() {
+        let rowTop = topPadding + (i as u32 * (rowHeight + 1)) * verticalMultiple;
+        output.setRegion(leftPadding, rowTop, inputWidth * multiple, verticalMultiple)?;
+        for (x, _) in row.iter().enumerate().filter(|(_, bar)| **bar) {
+            output.setRegion(
+                leftPadding + x as u32 * multiple,
+                rowTop + verticalMultiple,
+                multiple,
+                rowHeight * verticalMultiple,
+            )?;
+        }
+    }
+    output.setRegion(
+        leftPadding,
+        topPadding + (inputHeight - 1) * verticalMultiple,
+        inputWidth * multiple,
+        verticalMultiple,
+    )?;
+    Ok(output)
+}
+
+/**
+ * A row of a stacked symbology found in an image, kept until all the other rows are found too.
+ */
+pub(super) struct StackedRow {
+    pub codewords: Vec<u8>,
+    pub left: f32,
+    pub right: f32,
+    pub rowNumber: u32,
+}
+
+/**
+ * @return the left and right ends of the first and the last row
+ */
+pub(super) fn getStackedResultPoints(first: &StackedRow, last: &StackedRow) -> Vec<Point> {
+    vec![
+        point_f(first.left, first.rowNumber as f32),
+        point_f(first.right, first.rowNumber as f32),
+        point_f(last.left, last.rowNumber as f32),
+        point_f(last.right, last.rowNumber as f32),
+    ]
+}
@@ -0,0 +1,183 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    common::{bit_matrix_test_case, BitMatrix},
+    oned::{CodablockFReader, Code16KReader, OneDReader},
+    BarcodeFormat, DecodeHintType, DecodeHintValue, Exceptions, MultiFormatReader,
+    MultiFormatWriter, RXingResult, RXingResultMetadataType, RXingResultMetadataValue, Reader,
+    Writer,
+};
+
+fn encode(contents: &str, format: BarcodeFormat) -> BitMatrix {
+    MultiFormatWriter
+        .encode(contents, &format, 0, 0)
+        .expect("must encode")
+}
+
+fn decode(matrix: &BitMatrix, format: BarcodeFormat) -> crate::common::Result<RXingResult> {
+    let mut image = bit_matrix_test_case::matrix_to_binary_bitmap(matrix);
+    match format {
+        BarcodeFormat::CODABLOCK_F => CodablockFReader::default().decode(&mut image),
+        _ => Code16KReader::default().decode(&mut image),
+    }
+}
+
+fn symbologyIdentifier(result: &RXingResult) -> Option<&RXingResultMetadataValue> {
+    result
+        .getRXingResultMetadata()
+        .get(&RXingResultMetadataType::SYMBOLOGY_IDENTIFIER)
+}
+
+#[test]
+fn testCodablockFRoundTrip() {
+    for contents in [
+        "AIM",
+        "Codablock F",
+        "0123456789",
+        "LOT 12345678 EXP 2027-01\r\nqty 25",
+        "\u{1}\u{2}control\u{1f}characters",
+        &"Stacked Code 128 rows, 0123456789. ".repeat(12),
+    ] {
+        let result = decode(
+            &encode(contents, BarcodeFormat::CODABLOCK_F),
+            BarcodeFormat::CODABLOCK_F,
+        )
+        .expect("must decode");
+        assert_eq!(contents, result.getText());
+        assert_eq!(&BarcodeFormat::CODABLOCK_F, result.getBarcodeFormat());
+        assert_eq!(4, result.getRXingResultPoints().len());
+        assert_eq!(
+            Some(&RXingResultMetadataValue::SymbologyIdentifier(
+                "]O4".to_owned()
+            )),
+            symbologyIdentifier(&result)
+        );
+    }
+
+    // FNC1 in front marks GS1 data
+    let result = decode(
+        &encode(
+            "\u{f1}0112345678901231\u{f1}10ABC",
+            BarcodeFormat::CODABLOCK_F,
+        ),
+        BarcodeFormat::CODABLOCK_F,
+    )
+    .expect("must decode");
+    assert_eq!("0112345678901231\u{1d}10ABC", result.getText());
+    assert_eq!(
+        Some(&RXingResultMetadataValue::SymbologyIdentifier(
+            "]O5".to_owned()
+        )),
+        symbologyIdentifier(&result)
+    );
+}
+
+#[test]
+fn testCode16KRoundTrip() {
+    for contents in [
+        "AIM",
+        "Code 16K",
+        "0123456789012345",
+        "\u{1}\u{2}control\u{1f}characters",
+        "Code 16K holds up to 77 characters: 0123456789 abcdefghijklmnopqrst",
+    ] {
+        let result = decode(
+            &encode(contents, BarcodeFormat::CODE_16K),
+            BarcodeFormat::CODE_16K,
+        )
+        .expect("must decode");
+        assert_eq!(contents, result.getText());
+        assert_eq!(&BarcodeFormat::CODE_16K, result.getBarcodeFormat());
+        assert_eq!(
+            Some(&RXingResultMetadataValue::SymbologyIdentifier(
+                "]K0".to_owned()
+            )),
+            symbologyIdentifier(&result)
+        );
+    }
+}
+
+#[test]
+fn testUpsideDown() {
+    for format in [BarcodeFormat::CODABLOCK_F, BarcodeFormat::CODE_16K] {
+        let mut matrix = encode("Upside down 0123456789", format);
+        matrix.rotate180();
+        let result = decode(&matrix, format).expect("must decode");
+        assert_eq!("Upside down 0123456789", result.getText());
+        assert_eq!(
+            Some(&RXingResultMetadataValue::Orientation(180)),
+            result
+                .getRXingResultMetadata()
+                .get(&RXingResultMetadataType::ORIENTATION)
+        );
+    }
+}
+
+#[test]
+fn testRowsAreCombined() {
+    let matrix = MultiFormatWriter
+        .encode(
+            "Rows are combined 0123456789",
+            &BarcodeFormat::CODE_16K,
+            0,
+            0,
+        )
+        .expect("must encode");
+    // the middle of every row, below the separator above it, from the bottom up
+    let rows = (1..matrix.getHeight() - 1)
+        .step_by(9)
+        .map(|y| matrix.getRow(y + 4))
+        .collect::<Vec<_>>();
+    assert!(rows.len() > 2);
+
+    let mut reader = Code16KReader::default();
+    let hints = HashMap::new();
+    for (i, row) in rows.iter().enumerate().rev() {
+        let result = reader.decode_row(i as u32, row, &hints);
+        if i > 0 {
+            assert!(matches!(result, Err(Exceptions::NotFoundException(_))));
+        } else {
+            assert_eq!(
+                "Rows are combined 0123456789",
+                result.expect("must decode").getText()
+            );
+        }
+    }
+}
+
+#[test]
+fn testChecksum() {
+    // a character of the last row, changed to another valid character, upsets the check
+    // characters of the whole symbol without upsetting the row check character
+    let mut codewords = crate::oned::Code16KWriter::encodeCodewords("checksum").expect("encode");
+    let last = codewords.len() - 3;
+    codewords[last] = (codewords[last] + 1) % 103;
+    let (c1, c2) = super::code_16k_reader::getCheckCharacters(&codewords[..codewords.len() - 2]);
+    assert_ne!((c1, c2), (codewords[last + 1], codewords[last + 2]));
+
+    assert_ne!(
+        super::codablock_f_reader::getCheckCharacters("checksum"),
+        super::codablock_f_reader::getCheckCharacters("checksun")
+    );
+}
+
+#[test]
+fn testMultiFormatReader() {
+    for format in [BarcodeFormat::CODABLOCK_F, BarcodeFormat::CODE_16K] {
+        let matrix = MultiFormatWriter
+            .encode("Multi format 42", &format, 400, 200)
+            .expect("must encode");
+        let hints = HashMap::from([(
+            DecodeHintType::POSSIBLE_FORMATS,
+            DecodeHintValue::PossibleFormats(HashSet::from([format, BarcodeFormat::CODE_128])),
+        )]);
+        let result = MultiFormatReader::default()
+            .decode_with_hints(
+                &mut bit_matrix_test_case::matrix_to_binary_bitmap(&matrix),
+                &hints,
+            )
+            .expect("must decode");
+        assert_eq!("Multi format 42", result.getText());
+        assert_eq!(&format, result.getBarcodeFormat());
+    }
+}
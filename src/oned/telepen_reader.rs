@@ -0,0 +1,323 @@
+use once_cell::sync::Lazy;
+use rxing_one_d_proc_derive::OneDReader;
+
+use crate::{common::Result, point_f, BarcodeFormat, DecodeHintValue, Exceptions, RXingResult};
+
+use super::{one_d_reader, OneDReader};
+
+const MAX_AVG_VARIANCE: f32 = 0.38;
+const MAX_INDIVIDUAL_VARIANCE: f32 = 0.5;
+
+/// Every character is 16 modules wide
+const CHARACTER_MODULES: u32 = 16;
+/// Quiet zone required before the start and after the stop character, in modules
+const QUIET_ZONE: f32 = 5.0;
+
+/// The element widths of every character, in modules
+static CHARACTER_ENCODINGS: Lazy<Vec<Vec<u32>>> =
+    Lazy::new(|| (0..128).map(TelepenReader::encodeCharacter).collect());
+
+/**
+ * <p>Decodes Telepen barcodes, which encode full ASCII between a '_' start and a 'z' stop
+ * character, followed by a modulo 127 check character.</p>
+ *
+ * <p>With {@link DecodeHintType#TELEPEN_NUMERIC} the characters are read as double density
+ * numeric data, two digits per character. Like MSI Plessey, Telepen is only read when asked for
+ * with {@link DecodeHintType#POSSIBLE_FORMATS}.</p>
+ */
+#[derive(OneDReader)]
+pub struct TelepenReader {
+    counters: Vec<u32>,
+}
+
+impl Default for TelepenReader {
+    fn default() -> Self {
+        Self {
+            counters: Vec::with_capacity(160),
+        }
+    }
+}
+
+impl OneDReader for TelepenReader {
+    fn decode_row(
+        &mut self,
+        rowNumber: u32,
+        row: &crate::common::BitArray,
+        hints: &crate::DecodingHintDictionary,
+    ) -> Result<crate::RXingResult> {
+        let numeric = matches!(
+            hints.get(&DecodeHintType::TELEPEN_NUMERIC),
+            Some(DecodeHintValue::TelepenNumeric(true))
+        );
+
+        one_d_reader::record_runs(row, &mut self.counters);
+
+        let mut left = self.counters[0] as usize;
+        for start in (1..self.counters.len()).step_by(2) {
+            if let Some((characters, end)) = self.decodeFrom(start) {
+                let right = left + self.counters[start..end].iter().sum::<u32>() as usize;
+                let resultString = Self::checkAndInterpret(&characters, numeric)?;
+
+                let mut resultObject = RXingResult::new(
+                    &resultString,
+                    Vec::new(),
+                    vec![
+                        point_f(left as f32, rowNumber as f32),
+                        point_f(right as f32, rowNumber as f32),
+                    ],
+                    BarcodeFormat::TELEPEN,
+                );
+                resultObject.putMetadata(
+                    RXingResultMetadataType::SYMBOLOGY_IDENTIFIER,
+                    RXingResultMetadataValue::SymbologyIdentifier(
+                        if numeric { "]B1" } else { "]B0" }.to_owned(),
+                    ),
+                );
+                return Ok(resultObject);
+            }
+            left += self.counters[start..(start + 2).min(self.counters.len())]
+                .iter()
+                .sum::<u32>() as usize;
+        }
+
+        Err(Exceptions::NOT_FOUND)
+    }
+}
+
+impl TelepenReader {
+    pub const START_CHARACTER: u8 = b'_';
+    pub const STOP_CHARACTER: u8 = b'z';
+
+    /**
+     * <p>Encodes an ASCII character, with an even parity bit added as the most significant bit,
+     * least significant bit first. A 1 is a narrow bar and a narrow space, 00 a wide bar and a
+     * narrow space. Other zeros are paired up with the next zero: the first is a wide bar, the
+     * second a wide space, and the ones in between narrow spaces and bars.</p>
+     *
+     * @return the widths of the bars and spaces, starting with a bar, in modules
+     */
+    pub(crate) fn encodeCharacter(c: u8) -> Vec<u32> {
+        let byte = if c.count_ones() % 2 == 1 { c | 0x80 } else { c };
+        let bits = (0..8).map(|i| byte >> i & 1 != 0).collect::<Vec<_>>();
+
+        let mut widths = Vec::new();
+        let mut i = 0;
+        while i < 8 {
+            if bits[i] {
+                widths.extend([1, 1]);
+                i += 1;
+            } else if !bits[i + 1] {
+                widths.extend([3, 1]);
+                i += 2;
+            } else {
+                // 0 1...1 0, with an even parity there is always a closing zero
+                widths.push(3);
+                i += 1;
+                while bits[i + 1] {
+                    widths.extend([1, 1]);
+                    i += 1;
+                }
+                widths.push(3);
+                i += 2;
+            }
+        }
+        widths
+    }
+
+    /**
+     * Attempts to decode a symbol whose start character begins with the bar at the given index.
+     *
+     * @return the characters between the start and the stop character, and the index past the
+     * last bar of the stop character
+     */
+    fn decodeFrom(&self, start: usize) -> Option<(Vec<u8>, usize)> {
+        let counters = &self.counters;
+        let startPattern = &CHARACTER_ENCODINGS[Self::START_CHARACTER as usize];
+        let stopPattern = &CHARACTER_ENCODINGS[Self::STOP_CHARACTER as usize];
+
+        let startCounters = counters.get(start..start + startPattern.len())?;
+        if one_d_reader::pattern_match_variance(
+            startCounters,
+            startPattern,
+            MAX_INDIVIDUAL_VARIANCE,
+        ) >= MAX_AVG_VARIANCE
+        {
+            return None;
+        }
+        let mut moduleWidth = startCounters.iter().sum::<u32>() as f32 / CHARACTER_MODULES as f32;
+        // the row may begin with the start character
+        if start > 1 && (counters[start - 1] as f32) < moduleWidth * QUIET_ZONE {
+            return None;
+        }
+
+        let mut characters = Vec::new();
+        let mut position = start + startPattern.len();
+        loop {
+            // the last space of the stop character is part of the quiet zone
+            let stopEnd = position + stopPattern.len() - 1;
+            if stopEnd <= counters.len()
+                && Self::matches(
+                    &counters[position..stopEnd],
+                    &stopPattern[..stopPattern.len() - 1],
+                    moduleWidth,
+                )
+                && (stopEnd == counters.len()
+                    || counters[stopEnd] as f32 >= moduleWidth * QUIET_ZONE)
+            {
+                return Some((characters, stopEnd));
+            }
+
+            let (c, pattern) = CHARACTER_ENCODINGS
+                .iter()
+                .enumerate()
+                .find(|(_, pattern)| {
+                    counters
+                        .get(position..position + pattern.len())
+                        .is_some_and(|elements| Self::matches(elements, pattern, moduleWidth))
+                })?;
+            let width = counters[position..position + pattern.len()]
+                .iter()
+                .sum::<u32>();
+            // follow changes of the module width along the symbol
+            moduleWidth = width as f32 / CHARACTER_MODULES as f32;
+            characters.push(c as u8);
+            position += pattern.len();
+        }
+    }
+
+    /**
+     * @return whether the elements are narrow and wide as the pattern says. Narrow elements are
+     * one module wide, wide ones three modules.
+     */
+    fn matches(elements: &[u32], pattern: &[u32], moduleWidth: f32) -> bool {
+        elements.iter().zip(pattern).all(|(element, width)| {
+            let modules = *element as f32 / moduleWidth;
+            if *width == 1 {
+                (0.4..2.0).contains(&modules)
+            } else {
+                (2.0..4.5).contains(&modules)
+            }
+        })
+    }
+
+    /**
+     * Verifies the check character, the last one, which brings the sum of all characters to a
+     * multiple of 127, and interprets the others.
+     */
+    fn checkAndInterpret(characters: &[u8], numeric: bool) -> Result<String> {
+        let Some((_, data)) = characters.split_last() else {
+            return Err(Exceptions::NOT_FOUND);
+        };
+        if data.is_empty() {
+            return Err(Exceptions::NOT_FOUND);
+        }
+        if characters.iter().map(|c| *c as u32).sum::<u32>() % 127 != 0 {
+            return Err(Exceptions::CHECKSUM);
+        }
+
+        if !numeric {
+            return Ok(data.iter().map(|c| *c as char).collect());
+        }
+        let mut result = String::with_capacity(data.len() * 2);
+        for c in data {
+            match c {
+                27..=126 => result.push_str(&format!("{:02}", c - 27)),
+                // a single digit followed by 'X'
+                17..=26 => {
+                    result.push((b'0' + c - 17) as char);
+                    result.push('X');
+                }
+                _ => return Err(Exceptions::FORMAT),
+            }
+        }
+        Ok(result)
+    }
+
+    /**
+     * @return the check character of the characters
+     */
+    pub(crate) fn checkCharacter(characters: &[u8]) -> u8 {
+        ((127 - characters.iter().map(|c| *c as u32).sum::<u32>() % 127) % 127) as u8
+    }
+}
+
+#[cfg(test)]
+mod TelepenReaderTestCase {
+    use std::collections::{HashMap, HashSet};
+
+    use crate::{
+        common::{bit_matrix_test_case, BitMatrix},
+        oned::{OneDReader, TelepenReader, TelepenWriter},
+        BarcodeFormat, DecodeHintType, DecodeHintValue, EncodeHintType, EncodeHintValue,
+        Exceptions, MultiFormatReader, Reader, Writer,
+    };
+
+    #[test]
+    fn testDecode() {
+        doTest("A", false);
+        doTest("Telepen 123 z_!", false);
+        doTest("\u{0}\u{1}\u{7f}", false);
+        doTest("0123456789", true);
+        doTest("00991X", true);
+    }
+
+    #[test]
+    fn testBadCheckCharacter() {
+        // _ A ? z, the check character should be >
+        let matrix = BitMatrix::parse_strings(
+            "0000010101010101110001011101110111000101010101010111011100010101010100000",
+            "1",
+            "0",
+        )
+        .expect("must parse");
+        assert!(matches!(
+            TelepenReader::default().decode_row(0, &matrix.getRow(0), &HashMap::new()),
+            Err(Exceptions::ChecksumException(_))
+        ));
+    }
+
+    #[test]
+    fn testPossibleFormats() {
+        let image = TelepenWriter
+            .encode("Telepen", &BarcodeFormat::TELEPEN, 300, 50)
+            .expect("must encode");
+        let mut bitmap = bit_matrix_test_case::matrix_to_binary_bitmap(&image);
+
+        let hints = HashMap::from([(
+            DecodeHintType::POSSIBLE_FORMATS,
+            DecodeHintValue::PossibleFormats(HashSet::from([BarcodeFormat::TELEPEN])),
+        )]);
+        let result = MultiFormatReader::default()
+            .decode_with_hints(&mut bitmap, &hints)
+            .expect("must decode");
+        assert_eq!("Telepen", result.getText());
+        assert_eq!(&BarcodeFormat::TELEPEN, result.getBarcodeFormat());
+    }
+
+    fn doTest(contents: &str, numeric: bool) {
+        let matrix = TelepenWriter
+            .encode_with_hints(
+                contents,
+                &BarcodeFormat::TELEPEN,
+                0,
+                0,
+                &HashMap::from([(
+                    EncodeHintType::TELEPEN_NUMERIC,
+                    EncodeHintValue::TelepenNumeric(numeric),
+                )]),
+            )
+            .expect("must encode");
+        let result = TelepenReader::default()
+            .decode_row(
+                0,
+                &matrix.getRow(0),
+                &HashMap::from([(
+                    DecodeHintType::TELEPEN_NUMERIC,
+                    DecodeHintValue::TelepenNumeric(numeric),
+                )]),
+            )
+            .expect("must decode");
+        assert_eq!(contents, result.getText());
+        assert_eq!(&BarcodeFormat::TELEPEN, result.getBarcodeFormat());
+    }
+}
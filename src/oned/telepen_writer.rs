@@ -0,0 +1,145 @@
+use rxing_one_d_proc_derive::OneDWriter;
+
+use crate::common::Result;
+use crate::BarcodeFormat;
+
+use super::{OneDimensionalCodeWriter, TelepenReader};
+
+/**
+ * This object renders a Telepen code as a {@link BitMatrix}. Contents are full ASCII, or with
+ * {@link EncodeHintType#TELEPEN_NUMERIC} pairs of digits encoded in double density. The modulo
+ * 127 check character is always appended.
+ */
+#[derive(OneDWriter, Default)]
+pub struct TelepenWriter;
+
+impl OneDimensionalCodeWriter for TelepenWriter {
+    fn encode_oned(&self, contents: &str) -> Result<Vec<bool>> {
+        self.encode_oned_with_hints(contents, &HashMap::new())
+    }
+
+    /**
+     * @param contents barcode contents to encode, ASCII characters, or an even number of digits
+     * in numeric mode, where the last digit may be replaced by 'X'
+     * @param hints whether to encode in {@link EncodeHintType#TELEPEN_NUMERIC} mode
+     * @return a {@code boolean[]} of horizontal pixels (false = white, true = black)
+     */
+    fn encode_oned_with_hints(
+        &self,
+        contents: &str,
+        hints: &crate::EncodingHintDictionary,
+    ) -> Result<Vec<bool>> {
+        let mut characters = if matches!(
+            hints.get(&EncodeHintType::TELEPEN_NUMERIC),
+            Some(EncodeHintValue::TelepenNumeric(true))
+        ) {
+            Self::encodeNumeric(contents)?
+        } else {
+            if let Some(c) = contents.chars().find(|c| !c.is_ascii()) {
+                return Err(Exceptions::illegal_argument_with(format!(
+                    "Requested contents contains a not encodable character: '{c}'"
+                )));
+            }
+            contents.as_bytes().to_vec()
+        };
+        characters.push(TelepenReader::checkCharacter(&characters));
+
+        let mut result = Vec::new();
+        for c in [TelepenReader::START_CHARACTER]
+            .into_iter()
+            .chain(characters)
+            .chain([TelepenReader::STOP_CHARACTER])
+        {
+            for (i, width) in TelepenReader::encodeCharacter(c).into_iter().enumerate() {
+                result.resize(result.len() + width as usize, i % 2 == 0);
+            }
+        }
+        // the last space of the stop character is part of the quiet zone
+        result.pop();
+
+        Ok(result)
+    }
+
+    fn getSupportedWriteFormats(&self) -> Option<Vec<crate::BarcodeFormat>> {
+        Some(vec![BarcodeFormat::TELEPEN])
+    }
+}
+
+impl TelepenWriter {
+    /**
+     * Pairs of digits 00 to 99 are the characters 27 to 126, a digit followed by 'X' the
+     * characters 17 to 26.
+     */
+    fn encodeNumeric(contents: &str) -> Result<Vec<u8>> {
+        let bytes = contents.as_bytes();
+        if !bytes.len().is_multiple_of(2) {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "Requested contents should have an even number of digits, but got {contents}"
+            )));
+        }
+        bytes
+            .chunks(2)
+            .enumerate()
+            .map(|(i, pair)| match pair {
+                [a @ b'0'..=b'9', b @ b'0'..=b'9'] => Ok(27 + (a - b'0') * 10 + (b - b'0')),
+                [a @ b'0'..=b'9', b'X'] if 2 * i + 2 == bytes.len() => Ok(17 + (a - b'0')),
+                _ => Err(Exceptions::illegal_argument_with(format!(
+                    "Requested contents should only contain digits, with an optional 'X' last, but got {contents}"
+                ))),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod TelepenWriterTestCase {
+    use std::collections::HashMap;
+
+    use crate::{
+        common::bit_matrix_test_case, oned::TelepenWriter, BarcodeFormat, EncodeHintType,
+        EncodeHintValue, Writer,
+    };
+
+    #[test]
+    fn testEncode() {
+        // _ A > z, the check character of "A" is 127 - 65 = 62
+        doTest(
+            "A",
+            false,
+            "0000010101010101110001011101110111000111010101010001011100010101010100000",
+        );
+        // the pairs 12 and 34 are the characters 39 and 61, the check character 27
+        doTest(
+            "1234",
+            true,
+            "00000101010101011100010101011101011101011101010100010101011101000111011100010101010100000",
+        );
+    }
+
+    #[test]
+    fn testBadContents() {
+        let hints = HashMap::from([(
+            EncodeHintType::TELEPEN_NUMERIC,
+            EncodeHintValue::TelepenNumeric(true),
+        )]);
+        for contents in ["123", "12A4", "X1", "1X23"] {
+            assert!(TelepenWriter
+                .encode_with_hints(contents, &BarcodeFormat::TELEPEN, 0, 0, &hints)
+                .is_err());
+        }
+        assert!(TelepenWriter
+            .encode("caf\u{e9}", &BarcodeFormat::TELEPEN, 0, 0)
+            .is_err());
+    }
+
+    fn doTest(input: &str, numeric: bool, expected: &str) {
+        let hints = HashMap::from([(
+            EncodeHintType::TELEPEN_NUMERIC,
+            EncodeHintValue::TelepenNumeric(numeric),
+        )]);
+        let result = TelepenWriter
+            .encode_with_hints(input, &BarcodeFormat::TELEPEN, 0, 0, &hints)
+            .expect("encode");
+        assert_eq!(expected, bit_matrix_test_case::matrix_to_string(&result));
+    }
+}
@@ -0,0 +1,260 @@
+use rxing_one_d_proc_derive::OneDReader;
+
+use crate::{
+    common::{BitArray, Result},
+    point_f, BarcodeFormat, DecodeHintValue, Exceptions, RXingResult,
+};
+
+use super::{OneDReader, UPCEANReader, STAND_IN};
+
+/// Wide elements are three times as wide as narrow ones
+pub(super) const W: u32 = 3;
+pub(super) const N: u32 = 1;
+
+/// Elements from the minimum up to this many narrow elements wide are narrow, wider ones up to
+/// the maximum are wide
+const MIN_NARROW_RATIO: f32 = 0.4;
+const MAX_NARROW_RATIO: f32 = 1.8;
+const MAX_WIDE_RATIO: f32 = 4.5;
+/// The white space before the start and after the stop pattern is wider than any wide element
+const QUIET_ZONE: f32 = MAX_WIDE_RATIO;
+
+/**
+ * The widths of the five elements of every digit, two of them wide.
+ */
+pub(super) const DIGIT_PATTERNS: [[u32; 5]; 10] = [
+    [N, N, W, W, N], // 0
+    [W, N, N, N, W], // 1
+    [N, W, N, N, W], // 2
+    [W, W, N, N, N], // 3
+    [N, N, W, N, W], // 4
+    [W, N, W, N, N], // 5
+    [N, W, W, N, N], // 6
+    [N, N, N, W, W], // 7
+    [W, N, N, W, N], // 8
+    [N, W, N, W, N], // 9
+];
+
+/**
+ * <p>How a variant of 2 of 5 draws its symbols: the bars and spaces of its start and stop
+ * patterns, both beginning with a bar, and whether the digits are drawn in the bars alone,
+ * narrow spaces in between, or in three bars and the two spaces between them.</p>
+ */
+pub(super) struct TwoOfFiveVariant {
+    pub start: &'static [u32],
+    pub stop: &'static [u32],
+    pub barsOnly: bool,
+}
+
+impl TwoOfFiveVariant {
+    /**
+     * @return the variant of the format, if it is one of the 2 of 5 formats other than ITF
+     */
+    pub fn of(format: &BarcodeFormat) -> Option<Self> {
+        match format {
+            BarcodeFormat::INDUSTRIAL_2_OF_5 => Some(Self {
+                start: &[W, N, W, N, N, N],
+                stop: &[W, N, N, N, W],
+                barsOnly: true,
+            }),
+            BarcodeFormat::IATA_2_OF_5 => Some(Self {
+                start: &[N, N, N, N],
+                stop: &[W, N, N],
+                barsOnly: true,
+            }),
+            BarcodeFormat::MATRIX_2_OF_5 => Some(Self {
+                start: &[W, N, N, N, N, N],
+                stop: &[W, N, N, N, N],
+                barsOnly: false,
+            }),
+            BarcodeFormat::DATALOGIC_2_OF_5 => Some(Self {
+                start: &[N, N, N, N],
+                stop: &[W, N, N],
+                barsOnly: false,
+            }),
+            _ => None,
+        }
+    }
+
+    /**
+     * @return the bars and spaces of a digit, including the narrow space after it
+     */
+    pub fn digitElements(&self, digit: usize) -> Vec<u32> {
+        let pattern = DIGIT_PATTERNS[digit];
+        if self.barsOnly {
+            pattern.iter().flat_map(|bar| [*bar, N]).collect()
+        } else {
+            let mut elements = pattern.to_vec();
+            elements.push(N);
+            elements
+        }
+    }
+
+    fn digitLength(&self) -> usize {
+        if self.barsOnly {
+            10
+        } else {
+            6
+        }
+    }
+}
+
+/**
+ * <p>Decodes Industrial (Standard), IATA, Matrix and Datalogic 2 of 5 barcodes, which unlike ITF
+ * draw every digit on its own. Industrial and IATA 2 of 5 draw the digits in five bars, two of
+ * them wide, with narrow spaces in between. Matrix and Datalogic 2 of 5 draw them in three bars
+ * and two spaces, two of the five wide. The variants differ in their start and stop patterns.</p>
+ *
+ * <p>A modulo 10 check digit, weighted like the UPC/EAN one, is verified and removed when
+ * {@link DecodeHintType#TWO_OF_FIVE_CHECK_DIGIT} is set. Like ITF, these variants have no
+ * checksum of their own, so they are only read when asked for with
+ * {@link DecodeHintType#POSSIBLE_FORMATS}.</p>
+ *
+ * @see ITFReader
+ */
+#[derive(OneDReader)]
+pub struct TwoOfFiveReader {
+    format: BarcodeFormat,
+}
+
+impl OneDReader for TwoOfFiveReader {
+    fn decode_row(
+        &mut self,
+        rowNumber: u32,
+        row: &BitArray,
+        hints: &DecodingHintDictionary,
+    ) -> Result<RXingResult> {
+        let variant = TwoOfFiveVariant::of(&self.format).ok_or_else(|| {
+            Exceptions::illegal_argument_with(format!(
+                "Can only decode 2 of 5 variants, but got {:?}",
+                self.format
+            ))
+        })?;
+
+        let (starts, widths) = Self::recordRuns(row);
+        // bars are at the even indices
+        let (mut resultString, left, right) = (0..widths.len())
+            .step_by(2)
+            .find_map(|i| Self::decodeFrom(&variant, &widths, i))
+            .map(|(text, first, last)| (text, starts[first], starts[last] + widths[last]))
+            .ok_or(Exceptions::NOT_FOUND)?;
+
+        if matches!(
+            hints.get(&DecodeHintType::TWO_OF_FIVE_CHECK_DIGIT),
+            Some(DecodeHintValue::TwoOfFiveCheckDigit(true))
+        ) {
+            if resultString.len() < 2 {
+                return Err(Exceptions::NOT_FOUND);
+            }
+            if !STAND_IN.checkStandardUPCEANChecksum(&resultString)? {
+                return Err(Exceptions::CHECKSUM);
+            }
+            resultString.pop();
+        }
+
+        Ok(RXingResult::new(
+            &resultString,
+            Vec::new(),
+            vec![
+                point_f(left as f32, rowNumber as f32),
+                point_f(right as f32, rowNumber as f32),
+            ],
+            self.format,
+        ))
+    }
+}
+
+impl TwoOfFiveReader {
+    /**
+     * @param format one of {@code INDUSTRIAL_2_OF_5}, {@code IATA_2_OF_5}, {@code MATRIX_2_OF_5}
+     * and {@code DATALOGIC_2_OF_5}
+     */
+    pub fn new(format: BarcodeFormat) -> Self {
+        Self { format }
+    }
+
+    /**
+     * @return where every run of bars or of spaces begins and how wide it is, from the first bar
+     */
+    fn recordRuns(row: &BitArray) -> (Vec<usize>, Vec<usize>) {
+        let mut starts = Vec::new();
+        let mut widths = Vec::new();
+        let mut start = row.getNextSet(0);
+        while start < row.get_size() {
+            let end = if row.get(start) {
+                row.getNextUnset(start)
+            } else {
+                row.getNextSet(start)
+            };
+            starts.push(start);
+            widths.push(end - start);
+            start = end;
+        }
+        (starts, widths)
+    }
+
+    /**
+     * Reads a symbol whose start pattern begins at the given bar.
+     *
+     * @return the digits, and the indices of the first and the last bar of the symbol
+     */
+    fn decodeFrom(
+        variant: &TwoOfFiveVariant,
+        widths: &[usize],
+        first: usize,
+    ) -> Option<(String, usize, usize)> {
+        let startWidths = widths.get(first..first + variant.start.len())?;
+        let narrow =
+            startWidths.iter().sum::<usize>() as f32 / variant.start.iter().sum::<u32>() as f32;
+        // Look for whitespace before the start pattern; the first bar may follow the row's edge
+        if first > 0 && (widths[first - 1] as f32) < QUIET_ZONE * narrow {
+            return None;
+        }
+        if Self::toModules(startWidths, narrow)? != variant.start {
+            return None;
+        }
+
+        let mut result = String::new();
+        let mut position = first + variant.start.len();
+        loop {
+            let stopEnd = position + variant.stop.len();
+            if let Some(stopWidths) = widths.get(position..stopEnd) {
+                // the stop pattern is followed by whitespace, up to the end of the row at least
+                if Self::toModules(stopWidths, narrow)? == variant.stop
+                    && !result.is_empty()
+                    && widths
+                        .get(stopEnd)
+                        .is_some_and(|quietZone| *quietZone as f32 >= QUIET_ZONE * narrow)
+                {
+                    return Some((result, first, stopEnd - 1));
+                }
+            }
+
+            let digitWidths = widths.get(position..position + variant.digitLength())?;
+            let elements = Self::toModules(digitWidths, narrow)?;
+            let digit = (0..DIGIT_PATTERNS.len())
+                .find(|digit| variant.digitElements(*digit) == elements)?;
+            result.push(char::from_digit(digit as u32, 10)?);
+            position += variant.digitLength();
+        }
+    }
+
+    /**
+     * @return the widths in narrow elements, one or three, if all of them are either
+     */
+    fn toModules(widths: &[usize], narrow: f32) -> Option<Vec<u32>> {
+        widths
+            .iter()
+            .map(|width| {
+                let ratio = *width as f32 / narrow;
+                if !(MIN_NARROW_RATIO..=MAX_WIDE_RATIO).contains(&ratio) {
+                    None
+                } else if ratio <= MAX_NARROW_RATIO {
+                    Some(N)
+                } else {
+                    Some(W)
+                }
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,153 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    common::{bit_matrix_test_case, BitMatrix},
+    oned::{OneDReader, TwoOfFiveReader, TwoOfFiveWriter},
+    BarcodeFormat, DecodeHintType, DecodeHintValue, EncodeHintType, EncodeHintValue, Exceptions,
+    MultiFormatReader, MultiFormatWriter, Reader, Writer,
+};
+
+const ALL_FORMATS: [BarcodeFormat; 4] = [
+    BarcodeFormat::INDUSTRIAL_2_OF_5,
+    BarcodeFormat::IATA_2_OF_5,
+    BarcodeFormat::MATRIX_2_OF_5,
+    BarcodeFormat::DATALOGIC_2_OF_5,
+];
+
+fn encode(contents: &str, format: BarcodeFormat, checkDigit: bool) -> BitMatrix {
+    let hints = HashMap::from([(
+        EncodeHintType::TWO_OF_FIVE_CHECK_DIGIT,
+        EncodeHintValue::TwoOfFiveCheckDigit(checkDigit),
+    )]);
+    TwoOfFiveWriter::new(format)
+        .encode_with_hints(contents, &format, 0, 0, &hints)
+        .expect("must encode")
+}
+
+fn decodeRow(
+    image: &BitMatrix,
+    format: BarcodeFormat,
+    checkDigit: bool,
+) -> crate::common::Result<String> {
+    let hints = HashMap::from([(
+        DecodeHintType::TWO_OF_FIVE_CHECK_DIGIT,
+        DecodeHintValue::TwoOfFiveCheckDigit(checkDigit),
+    )]);
+    TwoOfFiveReader::new(format)
+        .decode_row(0, &image.getRow(0), &hints)
+        .map(|result| result.getText().to_owned())
+}
+
+fn rowToString(image: &BitMatrix) -> String {
+    (0..image.getWidth())
+        .map(|x| if image.get(x, 0) { '1' } else { '0' })
+        .collect()
+}
+
+#[test]
+fn testEncode() {
+    assert_eq!(
+        concat!(
+            "00000",
+            "1110111010",     // start
+            "11101010101110", // 1
+            "10111010101110", // 2
+            "111010111",      // stop
+            "00000"
+        ),
+        rowToString(&encode("12", BarcodeFormat::INDUSTRIAL_2_OF_5, false))
+    );
+    assert_eq!(
+        concat!(
+            "00000",
+            "11101010",   // start
+            "1110101110", // 1
+            "1000101110", // 2
+            "1110101",    // stop
+            "00000"
+        ),
+        rowToString(&encode("12", BarcodeFormat::MATRIX_2_OF_5, false))
+    );
+    // IATA shares the digits of Industrial, Datalogic those of Matrix
+    assert_eq!(
+        concat!("00000", "1010", "11101010101110", "11101", "00000"),
+        rowToString(&encode("1", BarcodeFormat::IATA_2_OF_5, false))
+    );
+    assert_eq!(
+        concat!("00000", "1010", "1110101110", "11101", "00000"),
+        rowToString(&encode("1", BarcodeFormat::DATALOGIC_2_OF_5, false))
+    );
+
+    // the check digit is weighted like the UPC/EAN one
+    assert_eq!(
+        encode("12345670", BarcodeFormat::MATRIX_2_OF_5, false),
+        encode("1234567", BarcodeFormat::MATRIX_2_OF_5, true)
+    );
+
+    assert!(TwoOfFiveWriter::new(BarcodeFormat::INDUSTRIAL_2_OF_5)
+        .encode("12A4", &BarcodeFormat::INDUSTRIAL_2_OF_5, 0, 0)
+        .is_err());
+    assert!(TwoOfFiveWriter::new(BarcodeFormat::INDUSTRIAL_2_OF_5)
+        .encode("1234", &BarcodeFormat::MATRIX_2_OF_5, 0, 0)
+        .is_err());
+}
+
+#[test]
+fn testDecode() {
+    for format in ALL_FORMATS {
+        for checkDigit in [false, true] {
+            for contents in ["1", "1234567", "0123456789", "80523"] {
+                let image = encode(contents, format, checkDigit);
+                assert_eq!(
+                    contents,
+                    decodeRow(&image, format, checkDigit).expect("must decode"),
+                    "{format:?} {checkDigit}"
+                );
+            }
+        }
+    }
+
+    // the wrong check digit
+    let image = encode("12345675", BarcodeFormat::IATA_2_OF_5, false);
+    assert!(matches!(
+        decodeRow(&image, BarcodeFormat::IATA_2_OF_5, true),
+        Err(Exceptions::ChecksumException(_))
+    ));
+    assert_eq!(
+        "12345675",
+        decodeRow(&image, BarcodeFormat::IATA_2_OF_5, false).expect("must decode")
+    );
+}
+
+#[test]
+fn testVariantsAreTold() {
+    for format in ALL_FORMATS {
+        let image = encode("0123456789", format, false);
+        for other in ALL_FORMATS.iter().filter(|other| **other != format) {
+            assert!(
+                decodeRow(&image, *other, false).is_err(),
+                "{format:?} read as {other:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn testMultiFormat() {
+    for format in ALL_FORMATS {
+        let image = MultiFormatWriter
+            .encode("80523", &format, 200, 50)
+            .expect("must encode");
+        let mut bitmap = bit_matrix_test_case::matrix_to_binary_bitmap(&image);
+
+        let hints = HashMap::from([(
+            DecodeHintType::POSSIBLE_FORMATS,
+            DecodeHintValue::PossibleFormats(HashSet::from(ALL_FORMATS)),
+        )]);
+        let result = MultiFormatReader::default()
+            .decode_with_hints(&mut bitmap, &hints)
+            .expect("must decode");
+        assert_eq!("80523", result.getText());
+        assert_eq!(&format, result.getBarcodeFormat());
+    }
+}
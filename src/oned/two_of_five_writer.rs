@@ -0,0 +1,79 @@
+use rxing_one_d_proc_derive::OneDWriter;
+
+use crate::{common::Result, BarcodeFormat};
+
+use super::{OneDimensionalCodeWriter, TwoOfFiveVariant, UPCEANReader, STAND_IN};
+
+/**
+ * This object renders Industrial (Standard), IATA, Matrix and Datalogic 2 of 5 codes as a
+ * {@link BitMatrix}, the variant told by the format it is made for. With
+ * {@link EncodeHintType#TWO_OF_FIVE_CHECK_DIGIT} a modulo 10 check digit is appended.
+ *
+ * @see ITFWriter
+ */
+#[derive(OneDWriter)]
+pub struct TwoOfFiveWriter {
+    format: BarcodeFormat,
+}
+
+impl OneDimensionalCodeWriter for TwoOfFiveWriter {
+    fn encode_oned(&self, contents: &str) -> Result<Vec<bool>> {
+        self.encode_oned_with_hints(contents, &HashMap::new())
+    }
+
+    /**
+     * @param contents barcode contents to encode, digits only
+     * @param hints whether to append a check digit
+     * @return a {@code boolean[]} of horizontal pixels (false = white, true = black)
+     */
+    fn encode_oned_with_hints(
+        &self,
+        contents: &str,
+        hints: &crate::EncodingHintDictionary,
+    ) -> Result<Vec<bool>> {
+        let variant = TwoOfFiveVariant::of(&self.format).ok_or_else(|| {
+            Exceptions::illegal_argument_with(format!(
+                "Can only encode 2 of 5 variants, but got {:?}",
+                self.format
+            ))
+        })?;
+        if let Some(c) = contents.chars().find(|c| !c.is_ascii_digit()) {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "Requested contents contains a not encodable character: '{c}'"
+            )));
+        }
+
+        let mut contents = contents.to_owned();
+        if matches!(
+            hints.get(&EncodeHintType::TWO_OF_FIVE_CHECK_DIGIT),
+            Some(EncodeHintValue::TwoOfFiveCheckDigit(true))
+        ) {
+            let check = STAND_IN.getStandardUPCEANChecksum(&contents)?;
+            contents.push_str(&check.to_string());
+        }
+
+        let mut elements = variant.start.to_vec();
+        for c in contents.bytes() {
+            elements.extend(variant.digitElements((c - b'0') as usize));
+        }
+        elements.extend(variant.stop);
+
+        let mut result = vec![false; elements.iter().sum::<u32>() as usize];
+        Self::appendPattern(&mut result, 0, &elements, true);
+        Ok(result)
+    }
+
+    fn getSupportedWriteFormats(&self) -> Option<Vec<BarcodeFormat>> {
+        Some(vec![self.format])
+    }
+}
+
+impl TwoOfFiveWriter {
+    /**
+     * @param format one of {@code INDUSTRIAL_2_OF_5}, {@code IATA_2_OF_5}, {@code MATRIX_2_OF_5}
+     * and {@code DATALOGIC_2_OF_5}
+     */
+    pub fn new(format: BarcodeFormat) -> Self {
+        Self { format }
+    }
+}
@@ -118,6 +118,14 @@ impl OneDimensionalCodeWriter for UPCEWriter {
         Ok(result.to_vec())
     }
 
+    fn encode_oned_with_hints(
+        &self,
+        contents: &str,
+        hints: &crate::EncodingHintDictionary,
+    ) -> Result<Vec<bool>> {
+        self.encode_oned_with_extension(contents, hints)
+    }
+
     fn getSupportedWriteFormats(&self) -> Option<Vec<crate::BarcodeFormat>> {
         Some(vec![BarcodeFormat::UPC_E])
     }
@@ -160,6 +168,23 @@ mod UPCEWriterTestCase {
         );
     }
 
+    #[test]
+    fn testEncodeExtension() {
+        let encode = |content| {
+            let result = UPCEWriter
+                .encode(content, &BarcodeFormat::UPC_E, 0, 0)
+                .expect("ok");
+            bit_matrix_test_case::matrix_to_string(&result)
+                .trim_matches('0')
+                .to_owned()
+        };
+        // gap, start guard, 1 and 2 with L parity and the separator between them
+        assert_eq!(
+            encode("05096893") + "000000000" + "1011" + "0011001" + "01" + "0010011",
+            encode("05096893+12")
+        );
+    }
+
     fn doTest(content: &str, encoding: &str) {
         let result = UPCEWriter
             .encode(
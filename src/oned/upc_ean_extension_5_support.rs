@@ -31,7 +31,7 @@ use super::{upc_ean_reader, UPCEANReader, STAND_IN};
 pub struct UPCEANExtension5Support;
 
 impl UPCEANExtension5Support {
-    const CHECK_DIGIT_ENCODINGS: [usize; 10] =
+    pub const CHECK_DIGIT_ENCODINGS: [usize; 10] =
         [0x18, 0x14, 0x12, 0x11, 0x0C, 0x06, 0x03, 0x0A, 0x09, 0x05];
 
     pub fn decodeRow(
@@ -117,7 +117,7 @@ impl UPCEANExtension5Support {
         Ok(rowOffset as u32)
     }
 
-    fn extensionChecksum(s: &str) -> Option<u32> {
+    pub fn extensionChecksum(s: &str) -> Option<u32> {
         let length = s.chars().count();
         let mut sum = 0;
         let mut i = length as isize - 2;
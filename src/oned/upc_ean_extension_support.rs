@@ -28,7 +28,7 @@ pub struct UPCEANExtensionSupport {
 }
 
 impl UPCEANExtensionSupport {
-    const EXTENSION_START_PATTERN: [u32; 3] = [1, 1, 2];
+    pub const EXTENSION_START_PATTERN: [u32; 3] = [1, 1, 2];
 
     pub fn decodeRow(
         &self,
@@ -44,8 +44,9 @@ impl UPCEANExtensionSupport {
         {
             Ok(res_1)
         } else {
+            let [start, end] = extensionStartRange;
             self.twoSupport
-                .decodeRow(rowNumber, row, &Self::EXTENSION_START_PATTERN)
+                .decodeRow(rowNumber, row, &[start as u32, end as u32, 0])
         }
     }
 }
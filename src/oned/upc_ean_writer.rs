@@ -14,7 +14,16 @@
  * limitations under the License.
  */
 
-use super::OneDimensionalCodeWriter;
+use crate::{common::Result, EncodeHintType, EncodeHintValue, Exceptions};
+
+use super::{
+    upc_ean_reader, OneDimensionalCodeWriter, UPCEANExtension5Support, UPCEANExtensionSupport,
+};
+
+/**
+ * Number of light modules between the main symbol and an add-on.
+ */
+pub const EXTENSION_GAP: usize = 9;
 
 /**
  * <p>Encapsulates functionality and implementation that is common to UPC and EAN families
@@ -25,4 +34,93 @@ use super::OneDimensionalCodeWriter;
  */
 pub trait UPCEANWriter: OneDimensionalCodeWriter {
     const DEFAULT_MARGIN: u32 = 9;
+
+    /**
+     * Encodes the contents followed by a 2 or 5 digit add-on, if one is given. The add-on either
+     * follows the main digits after a '+' or a space, or comes from the
+     * {@link EncodeHintType#UPC_EAN_EXTENSION} hint.
+     *
+     * @param contents barcode contents to encode, optionally with add-on
+     * @param hints encoding hints
+     * @return a {@code boolean[]} of horizontal pixels (false = white, true = black)
+     */
+    fn encode_oned_with_extension(
+        &self,
+        contents: &str,
+        hints: &crate::EncodingHintDictionary,
+    ) -> Result<Vec<bool>> {
+        let (contents, extension) = match contents.split_once(['+', ' ']) {
+            Some((contents, extension)) => (contents, Some(extension)),
+            None => (contents, None),
+        };
+        let extension = match hints.get(&EncodeHintType::UPC_EAN_EXTENSION) {
+            Some(EncodeHintValue::UpcEanExtension(hinted)) if extension.is_none() => {
+                Some(hinted.as_str())
+            }
+            _ => extension,
+        };
+
+        let mut code = self.encode_oned(contents)?;
+        if let Some(extension) = extension {
+            code.extend(Self::encodeExtension(extension)?);
+        }
+        Ok(code)
+    }
+
+    /**
+     * Encodes a 2 or 5 digit add-on, starting with the gap that separates it from the main symbol.
+     *
+     * @param contents the digits of the add-on
+     * @return a {@code boolean[]} of horizontal pixels (false = white, true = black)
+     */
+    fn encodeExtension(contents: &str) -> Result<Vec<bool>> {
+        let digits = contents
+            .chars()
+            .map(|c| c.to_digit(10).map(|d| d as usize))
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| {
+                Exceptions::illegal_argument_with("Add-on should only contain digits 0-9")
+            })?;
+        // digits with G parity, the first digit being the most significant bit
+        let parities = match digits.len() {
+            2 => (digits[0] * 10 + digits[1]) % 4,
+            5 => {
+                let checkDigit = UPCEANExtension5Support::extensionChecksum(contents)
+                    .ok_or(Exceptions::ILLEGAL_ARGUMENT)?;
+                UPCEANExtension5Support::CHECK_DIGIT_ENCODINGS[checkDigit as usize]
+            }
+            length => {
+                return Err(Exceptions::illegal_argument_with(format!(
+                    "Add-on should be 2 or 5 digits long, but got {length}"
+                )))
+            }
+        };
+
+        let mut result = vec![false; EXTENSION_GAP + 4 + digits.len() * 9 - 2];
+        let mut pos = EXTENSION_GAP;
+        pos += Self::appendPattern(
+            &mut result,
+            pos,
+            &UPCEANExtensionSupport::EXTENSION_START_PATTERN,
+            true,
+        ) as usize;
+        for (i, digit) in digits.iter().enumerate() {
+            if i > 0 {
+                // separator
+                pos += Self::appendPattern(&mut result, pos, &[1, 1], false) as usize;
+            }
+            let digit = if (parities >> (digits.len() - 1 - i)) & 1 == 1 {
+                digit + 10
+            } else {
+                *digit
+            };
+            pos += Self::appendPattern(
+                &mut result,
+                pos,
+                &upc_ean_reader::L_AND_G_PATTERNS[digit],
+                false,
+            ) as usize;
+        }
+        Ok(result)
+    }
 }
@@ -0,0 +1,111 @@
+use crate::{
+    common::{BitArray, Result},
+    oned::rss::expanded::decoders::{field_parser, DecodedObject, GeneralAppIdDecoder},
+    Exceptions,
+};
+
+/**
+ * <p>Decodes the bit stream of a GS1 Composite 2D component (ISO/IEC 24723, 5.4.1) into a GS1
+ * element string such as `(10)ABC123(21)12345`.</p>
+ *
+ * <p>The bit stream starts with the encodation method. "0" is followed by the general purpose
+ * data field, "10" compresses a leading date (AI 11 or 17) and batch number (AI 10). The "11"
+ * method, which compresses AI 90 data, is not supported.</p>
+ */
+pub fn decode(bits: &BitArray) -> Result<String> {
+    let size = bits.get_size();
+    if size < 1 {
+        return Err(Exceptions::NOT_FOUND);
+    }
+    if !bits.get(0) {
+        return decodeGeneralPurpose(bits, String::new(), 1, false);
+    }
+    if size < 4 || bits.get(1) {
+        return Err(Exceptions::format_with(
+            "unsupported composite encodation method",
+        ));
+    }
+
+    let mut buffer = String::new();
+    let mut position = 2;
+    if bits.get(2) && bits.get(3) {
+        // no date, the batch number follows directly
+        position += 2;
+        return decodeGeneralPurpose(bits, buffer, position, true);
+    }
+
+    if position + 17 > size {
+        return Err(Exceptions::FORMAT);
+    }
+    let date = extractNumericValue(bits, position, 16);
+    position += 16;
+    let ai = if bits.get(position) { "17" } else { "11" };
+    position += 1;
+    let (year, month, day) = (date / 384, date % 384 / 32 + 1, date % 32);
+    if year > 99 || month > 12 {
+        return Err(Exceptions::FORMAT);
+    }
+    buffer.push_str(&format!("({ai}){year:02}{month:02}{day:02}"));
+
+    decodeGeneralPurpose(bits, buffer, position, false)
+}
+
+/**
+ * Decodes the general purpose data field.
+ *
+ * @param buffer the element strings decoded so far
+ * @param position the start of the field
+ * @param batch whether the field starts with the data of AI 10. If not told by the encodation
+ *              method, it is there unless the field starts with FNC1.
+ */
+fn decodeGeneralPurpose(
+    bits: &BitArray,
+    buffer: String,
+    position: usize,
+    batch: bool,
+) -> Result<String> {
+    let mut buffer = buffer;
+    let mut decoder = GeneralAppIdDecoder::new(bits);
+    let mut position = position;
+    let mut remaining = String::new();
+    if batch {
+        remaining.push_str("10");
+    } else if !buffer.is_empty() {
+        let info = decoder.decodeGeneralPurposeField(position, "")?;
+        if info.getNewString().is_empty() && info.getNewPosition() > position {
+            position = info.getNewPosition();
+            if info.isRemaining() {
+                remaining = info.getRemainingValue().to_string();
+            }
+        } else {
+            // decode the field again from scratch, with the AI
+            decoder = GeneralAppIdDecoder::new(bits);
+            remaining.push_str("10");
+        }
+    }
+
+    loop {
+        let info = decoder.decodeGeneralPurposeField(position, &remaining)?;
+        buffer.push_str(&field_parser::parseFieldsInGeneralPurpose(
+            info.getNewString(),
+        )?);
+        remaining = if info.isRemaining() {
+            info.getRemainingValue().to_string()
+        } else {
+            String::new()
+        };
+        if position == info.getNewPosition() {
+            break;
+        }
+        position = info.getNewPosition();
+    }
+
+    if buffer.is_empty() {
+        return Err(Exceptions::FORMAT);
+    }
+    Ok(buffer)
+}
+
+fn extractNumericValue(bits: &BitArray, position: usize, size: usize) -> u32 {
+    (position..position + size).fold(0, |value, i| (value << 1) | bits.get(i) as u32)
+}
@@ -19,11 +19,13 @@ use num::{self, bigint::ToBigUint, BigUint};
 use std::rc::Rc;
 
 use crate::{
-    common::{DecoderRXingResult, ECIStringBuilder, Eci, Result},
+    common::{BitArray, DecoderRXingResult, ECIStringBuilder, Eci, Result},
     pdf417::PDF417RXingResultMetadata,
     Exceptions,
 };
 
+use super::composite_bit_stream_parser;
+
 /**
  * <p>This class contains the methods for decoding the PDF417 codewords.</p>
  *
@@ -52,6 +54,7 @@ const BEGIN_MACRO_PDF417_CONTROL_BLOCK: u32 = 928;
 const BEGIN_MACRO_PDF417_OPTIONAL_FIELD: u32 = 923;
 const MACRO_PDF417_TERMINATOR: u32 = 922;
 const MODE_SHIFT_TO_BYTE_COMPACTION_MODE: u32 = 913;
+const LINKAGE_EANUCC: u32 = 920;
 const MAX_NUMERIC_CODEWORDS: usize = 15;
 
 const MACRO_PDF417_OPTIONAL_FIELD_FILE_NAME: u32 = 0;
@@ -107,6 +110,9 @@ static EXP900: Lazy<Vec<BigUint>> = Lazy::new(|| {
 const NUMBER_OF_SEQUENCE_CODEWORDS: usize = 2;
 
 pub fn decode(codewords: &[u32], ecLevel: &str) -> Result<DecoderRXingResult> {
+    if codewords[0] > 2 && codewords[1] == LINKAGE_EANUCC {
+        return decodeComposite(codewords, ecLevel);
+    }
     let mut result = ECIStringBuilder::with_capacity(codewords.len() * 2);
     let mut codeIndex = textCompaction(codewords, 1, &mut result)?;
     let mut resultMetadata = PDF417RXingResultMetadata::default();
@@ -176,6 +182,34 @@ pub fn decode(codewords: &[u32], ecLevel: &str) -> Result<DecoderRXingResult> {
     Ok(decoderRXingResult)
 }
 
+/**
+ * The 2D component of a GS1 Composite symbol (CC-B or CC-C) starts with the linkage codeword,
+ * followed by its bit stream in byte compaction mode.
+ */
+fn decodeComposite(codewords: &[u32], ecLevel: &str) -> Result<DecoderRXingResult> {
+    let mode = codewords[2];
+    if mode != BYTE_COMPACTION_MODE_LATCH && mode != BYTE_COMPACTION_MODE_LATCH_6 {
+        return Err(Exceptions::FORMAT);
+    }
+    let mut bytes = ECIStringBuilder::with_capacity(codewords.len() * 2);
+    byteCompaction(mode, codewords, 3, &mut bytes)?;
+
+    let mut bits = BitArray::new();
+    for byte in bytes.bytes() {
+        bits.appendBits(*byte as u32, 8)?;
+    }
+
+    let mut decoderRXingResult = DecoderRXingResult::new(
+        bytes.bytes().to_vec(),
+        composite_bit_stream_parser::decode(&bits)?,
+        Vec::new(),
+        ecLevel.to_owned(),
+    );
+    decoderRXingResult.setContentType("GS1".to_owned());
+
+    Ok(decoderRXingResult)
+}
+
 pub fn decodeMacroBlock(
     codewords: &[u32],
     codeIndex: usize,
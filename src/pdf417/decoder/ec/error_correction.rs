@@ -47,7 +47,21 @@ static FLD_INTERIOR: Lazy<ModulusGF> = Lazy::new(|| ModulusGF::new(NUMBER_OF_COD
  * @throws ChecksumException if errors cannot be corrected, maybe because of too many errors
  */
 pub fn decode(received: &mut [u32], numECCodewords: u32, erasures: &mut [u32]) -> Result<usize> {
-    let field: &'static ModulusGF = &FLD_INTERIOR;
+    decode_with_field(&FLD_INTERIOR, received, numECCodewords, erasures)
+}
+
+/**
+ * <p>Like {@link #decode(int[], int, int[])}, but over any other field of prime size whose error
+ * correction codewords are built the same way, such as the GF(113) of DotCode.</p>
+ *
+ * @param field the field the codewords are elements of
+ */
+pub fn decode_with_field(
+    field: &'static ModulusGF,
+    received: &mut [u32],
+    numECCodewords: u32,
+    erasures: &mut [u32],
+) -> Result<usize> {
     let poly = ModulusPoly::new(field, received.to_vec())?;
     let mut S = vec![0u32; numECCodewords as usize];
     let mut error = false;
@@ -0,0 +1,365 @@
+use crate::{
+    common::{BitArray, BitMatrix, DecoderRXingResult, Result},
+    pdf417::{
+        micro_pdf_417_common::{self, MicroPDF417Variant},
+        pdf_417_common,
+    },
+    Exceptions,
+};
+
+use super::{
+    composite_bit_stream_parser, decoded_bit_stream_parser, ec, pdf_417_codeword_decoder,
+    pdf_417_scanning_decoder, BarcodeValue,
+};
+
+/**
+ * Samples and decodes a MicroPDF417 symbol (ISO/IEC 24728).
+ *
+ * The number of columns follows from the width of the symbol. Rows are told apart by the cluster
+ * of their codewords, which changes from one row to the next, so the row address patterns don't
+ * need to be read.
+ *
+ * @param image the binarized image
+ * @param left the left edge of the symbol
+ * @param top the top edge of the symbol
+ * @param width the width of the symbol, from the first bar of the left row address pattern to
+ *              the stop pattern
+ * @param height the height of the symbol
+ * @return the decoded contents, with the number of error correction codewords as EC level
+ */
+pub fn decode(
+    image: &BitMatrix,
+    left: u32,
+    top: u32,
+    width: u32,
+    height: u32,
+) -> Result<DecoderRXingResult> {
+    let mut result = Err(Exceptions::NOT_FOUND);
+    for columns in 1..=4 {
+        result = decodeWithColumns(image, columns, left, top, width, height);
+        if result.is_ok() {
+            break;
+        }
+    }
+    result
+}
+
+fn decodeWithColumns(
+    image: &BitMatrix,
+    columns: u32,
+    left: u32,
+    top: u32,
+    width: u32,
+    height: u32,
+) -> Result<DecoderRXingResult> {
+    let (mut codewords, mut erasures, rows) = readCodewords(
+        image,
+        micro_pdf_417_common::getRowWidth(columns),
+        &micro_pdf_417_common::getCodewordOffsets(columns),
+        left,
+        top,
+        width,
+        height,
+    )?;
+    let variant = micro_pdf_417_common::getVariant(columns, rows).ok_or(Exceptions::NOT_FOUND)?;
+
+    decodeCodewords(&mut codewords, variant, &mut erasures)
+}
+
+/**
+ * Samples and decodes a CC-A composite component (ISO/IEC 24723), the MicroPDF417 based 2D
+ * component of a GS1 Composite symbol with the smallest capacity.
+ *
+ * @param image the binarized image
+ * @param left the left edge of the component
+ * @param top the top edge of the component
+ * @param width the width of the component, from its first bar to the stop pattern
+ * @param height the height of the component
+ * @return the GS1 element string of the component, with the number of error correction
+ *         codewords as EC level
+ */
+pub fn decodeCompositeA(
+    image: &BitMatrix,
+    left: u32,
+    top: u32,
+    width: u32,
+    height: u32,
+) -> Result<DecoderRXingResult> {
+    let mut result = Err(Exceptions::NOT_FOUND);
+    for columns in 2..=4 {
+        result = decodeCompositeAWithColumns(image, columns, left, top, width, height);
+        if result.is_ok() {
+            break;
+        }
+    }
+    result
+}
+
+fn decodeCompositeAWithColumns(
+    image: &BitMatrix,
+    columns: u32,
+    left: u32,
+    top: u32,
+    width: u32,
+    height: u32,
+) -> Result<DecoderRXingResult> {
+    let (mut codewords, mut erasures, rows) = readCodewords(
+        image,
+        micro_pdf_417_common::getCompositeARowWidth(columns),
+        &micro_pdf_417_common::getCompositeACodewordOffsets(columns),
+        left,
+        top,
+        width,
+        height,
+    )?;
+    let variant =
+        micro_pdf_417_common::getCompositeAVariant(columns, rows).ok_or(Exceptions::NOT_FOUND)?;
+
+    let numECCodewords = variant.getErrorCorrectionCodewords();
+    if erasures.len() as u32 > numECCodewords / 2 {
+        return Err(Exceptions::CHECKSUM);
+    }
+    let correctedErrorsCount =
+        ec::error_correction::decode(&mut codewords, numECCodewords, &mut erasures)?;
+
+    // Groups of up to 7 data codewords are base 928 numbers, each holding 10 bits per codeword
+    // less one
+    let mut bits = BitArray::new();
+    for group in codewords[..variant.getDataCapacity() as usize].chunks(7) {
+        let value = group
+            .iter()
+            .fold(0u128, |value, codeword| value * 928 + *codeword as u128);
+        let size = 10 * group.len() - 1;
+        if value >> size != 0 {
+            return Err(Exceptions::FORMAT);
+        }
+        for i in (0..size).rev() {
+            bits.appendBit((value >> i) & 1 == 1);
+        }
+    }
+
+    let mut decoderRXingResult = DecoderRXingResult::new(
+        Vec::new(),
+        composite_bit_stream_parser::decode(&bits)?,
+        Vec::new(),
+        numECCodewords.to_string(),
+    );
+    decoderRXingResult.setContentType("GS1".to_owned());
+    decoderRXingResult.setErrorsCorrected(correctedErrorsCount);
+    decoderRXingResult.setErasures(erasures.len());
+
+    Ok(decoderRXingResult)
+}
+
+/**
+ * Reads the codewords of all rows, telling the rows apart by their clusters.
+ *
+ * @param rowWidth the width of a row in modules
+ * @param offsets the module offset of every codeword in a row
+ * @return the codewords row by row, the positions of those that could not be read and the
+ *         number of rows
+ */
+fn readCodewords(
+    image: &BitMatrix,
+    rowWidth: u32,
+    offsets: &[u32],
+    left: u32,
+    top: u32,
+    width: u32,
+    height: u32,
+) -> Result<(Vec<u32>, Vec<u32>, u32)> {
+    let moduleWidth = width as f32 / rowWidth as f32;
+    if moduleWidth < 1.0 {
+        return Err(Exceptions::NOT_FOUND);
+    }
+    let lines = (top..top + height)
+        .filter_map(|y| readScanLine(image, y, left, moduleWidth, offsets))
+        .collect::<Vec<_>>();
+
+    // Consecutive scan lines reading the same cluster belong to the same row
+    let mut runs: Vec<(u32, usize, usize)> = Vec::new();
+    for (index, (cluster, _)) in lines.iter().enumerate() {
+        match runs.last_mut() {
+            Some((last, _, end)) if last == cluster => *end = index + 1,
+            _ => runs.push((*cluster, index, index + 1)),
+        }
+    }
+    // Drop runs of only a few misread lines, at the boundaries between two rows
+    let maxLines = runs
+        .iter()
+        .map(|(_, start, end)| end - start)
+        .max()
+        .unwrap_or(0);
+    let mut rows: Vec<(u32, Vec<BarcodeValue>)> = Vec::new();
+    for (cluster, start, end) in runs {
+        if (end - start) * 4 <= maxLines {
+            continue;
+        }
+        if rows.last().map(|(last, _)| *last) != Some(cluster) {
+            rows.push((cluster, vec![BarcodeValue::new(); offsets.len()]));
+        }
+        let (_, values) = rows.last_mut().ok_or(Exceptions::ILLEGAL_STATE)?;
+        for (_, codewords) in &lines[start..end] {
+            for (value, codeword) in values.iter_mut().zip(codewords) {
+                if let Some(codeword) = codeword {
+                    value.setValue(*codeword);
+                }
+            }
+        }
+    }
+    if rows.windows(2).any(|pair| pair[1].0 != (pair[0].0 + 3) % 9) {
+        return Err(Exceptions::NOT_FOUND);
+    }
+
+    let mut codewords = Vec::with_capacity(rows.len() * offsets.len());
+    let mut erasures = Vec::new();
+    for value in rows.iter().flat_map(|(_, values)| values) {
+        match value.getValue()[..] {
+            [codeword] => codewords.push(codeword),
+            _ => {
+                erasures.push(codewords.len() as u32);
+                codewords.push(0);
+            }
+        }
+    }
+
+    Ok((codewords, erasures, rows.len() as u32))
+}
+
+/**
+ * Reads the codewords of one scan line.
+ *
+ * @return the cluster of the line and the codewords of that cluster, or None if no codeword
+ *         could be read
+ */
+fn readScanLine(
+    image: &BitMatrix,
+    y: u32,
+    left: u32,
+    moduleWidth: f32,
+    offsets: &[u32],
+) -> Option<(u32, Vec<Option<u32>>)> {
+    let decoded = offsets
+        .iter()
+        .map(|offset| {
+            readCodeword(
+                image,
+                y,
+                left as f32 + *offset as f32 * moduleWidth,
+                moduleWidth,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let mut clusterCounts = [0; 3];
+    for (cluster, _) in decoded.iter().flatten() {
+        clusterCounts[*cluster as usize / 3] += 1;
+    }
+    let (index, count) = clusterCounts
+        .iter()
+        .enumerate()
+        .max_by_key(|(_, count)| **count)?;
+    if *count == 0 {
+        return None;
+    }
+    let cluster = index as u32 * 3;
+    Some((
+        cluster,
+        decoded
+            .into_iter()
+            .map(|codeword| {
+                codeword
+                    .filter(|(c, _)| *c == cluster)
+                    .map(|(_, value)| value)
+            })
+            .collect(),
+    ))
+}
+
+/**
+ * Reads the codeword starting at about the given position.
+ *
+ * @return the cluster and value of the codeword, if it could be read
+ */
+fn readCodeword(image: &BitMatrix, y: u32, start: f32, moduleWidth: f32) -> Option<(u32, u32)> {
+    let codewordWidth = pdf_417_common::MODULES_IN_CODEWORD as f32 * moduleWidth;
+    let end = ((start + codewordWidth + moduleWidth).round() as u32).min(image.getWidth());
+
+    // codewords start with a bar and the preceding pattern ends with a space
+    let mut x = (start - moduleWidth / 2.0).max(0.0).round() as u32;
+    let firstBarLimit = (start + moduleWidth / 2.0).round() as u32;
+    while !image.get(x, y) {
+        x += 1;
+        if x > firstBarLimit || x >= end {
+            return None;
+        }
+    }
+
+    let mut moduleBitCount = [0u32; pdf_417_common::BARS_IN_MODULE as usize];
+    let mut index = 0;
+    let mut black = true;
+    while x < end {
+        if image.get(x, y) != black {
+            index += 1;
+            if index == moduleBitCount.len() {
+                break;
+            }
+            black = !black;
+        }
+        moduleBitCount[index] += 1;
+        x += 1;
+    }
+    if index < moduleBitCount.len() - 1 {
+        return None;
+    }
+    let sum = moduleBitCount.iter().sum::<u32>() as f32;
+    if sum < codewordWidth * 0.75 || sum > codewordWidth * 1.25 {
+        return None;
+    }
+
+    let symbol = pdf_417_codeword_decoder::getDecodedValue(&moduleBitCount);
+    let codeword = pdf_417_common::getCodeword(symbol);
+    if codeword < 0 {
+        return None;
+    }
+    Some((
+        pdf_417_scanning_decoder::getCodewordBucketNumber(symbol),
+        codeword as u32,
+    ))
+}
+
+/**
+ * <p>Corrects the errors in the data and error correction codewords of a symbol and decodes its
+ * data codewords.</p>
+ *
+ * @param codewords data and error correction codewords
+ * @param variant the symbol size, which determines the number of error correction codewords
+ * @param erasures positions of any known erasures
+ */
+pub fn decodeCodewords(
+    codewords: &mut [u32],
+    variant: &MicroPDF417Variant,
+    erasures: &mut [u32],
+) -> Result<DecoderRXingResult> {
+    let numECCodewords = variant.getErrorCorrectionCodewords();
+    if codewords.len() as u32 != variant.getColumns() * variant.getRows() {
+        return Err(Exceptions::FORMAT);
+    }
+    if erasures.len() as u32 > numECCodewords / 2 {
+        return Err(Exceptions::CHECKSUM);
+    }
+    let correctedErrorsCount = ec::error_correction::decode(codewords, numECCodewords, erasures)?;
+
+    // There is no symbol length descriptor, but the bit stream parser expects one
+    let dataCount = variant.getDataCapacity() as usize;
+    let mut dataCodewords = Vec::with_capacity(dataCount + 1);
+    dataCodewords.push(dataCount as u32 + 1);
+    dataCodewords.extend_from_slice(&codewords[..dataCount]);
+
+    let mut decoderRXingResult =
+        decoded_bit_stream_parser::decode(&dataCodewords, &numECCodewords.to_string())?;
+    decoderRXingResult.setErrorsCorrected(correctedErrorsCount);
+    decoderRXingResult.setErasures(erasures.len());
+
+    Ok(decoderRXingResult)
+}
@@ -26,5 +26,9 @@ pub use detection_result::*;
 pub mod decoded_bit_stream_parser;
 pub mod pdf_417_scanning_decoder;
 
+pub mod micro_pdf_417_decoder;
+
+pub mod composite_bit_stream_parser;
+
 #[cfg(test)]
 mod pdf_417_decoder_test_case;
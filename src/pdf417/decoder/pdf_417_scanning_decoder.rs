@@ -932,7 +932,7 @@ fn getBitCountForCodeword(codeword: u32) -> [u32; 8] {
     result
 }
 
-fn getCodewordBucketNumber(codeword: u32) -> u32 {
+pub(super) fn getCodewordBucketNumber(codeword: u32) -> u32 {
     getCodewordBucketNumberArray(&getBitCountForCodeword(codeword))
 }
 
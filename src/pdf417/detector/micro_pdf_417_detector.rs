@@ -0,0 +1,256 @@
+use crate::{
+    common::BitMatrix,
+    pdf417::{
+        decoder::pdf_417_codeword_decoder,
+        micro_pdf_417_common::{self, MODULES_IN_RAP, ROW_HEIGHT, SIDE_RAP_PATTERNS},
+        pdf_417_common::{self, BARS_IN_MODULE, MODULES_IN_CODEWORD},
+    },
+    point, Point,
+};
+
+/// Bars and spaces of a row address pattern
+const ELEMENTS_IN_RAP: usize = 6;
+/// The most scan lines skipped between two looked at for a left row address pattern
+const MAX_ROW_STEP: u32 = 4;
+/// The smallest number of rows of a symbol, each with a row address pattern of its own
+const MIN_ROWS: usize = 4;
+/// How far the width of a symbol may be off the one its module width tells, as a fraction
+const MAX_WIDTH_VARIANCE: f32 = 0.15;
+
+/**
+ * <p>Locates MicroPDF417 symbols by their row address patterns (RAPs). The image may hold other
+ * things next to a symbol, which has to be upright.</p>
+ *
+ * <p>Scan lines a few pixels apart are searched for a left RAP followed by a codeword. The RAP is
+ * followed up and down the image for the height of the symbol, its row address changing from row
+ * to row, which tells it apart from the bars of a linear barcode. The number of columns is told
+ * by where the right RAP and the stop pattern are found.</p>
+ *
+ * @param image the binarized image
+ * @return the top left, top right, bottom left and bottom right corners of every symbol found
+ */
+pub fn detect(image: &BitMatrix) -> Vec<[Point; 4]> {
+    let height = image.getHeight();
+    let rowStep = (height / 64).clamp(1, MAX_ROW_STEP);
+    let mut symbols: Vec<[Point; 4]> = Vec::new();
+
+    for y in (0..height).step_by(rowStep as usize) {
+        let runs = getRuns(image, y);
+        for start in 1..runs.len() {
+            let (left, _, black) = runs[start];
+            if !black {
+                continue;
+            }
+            // a symbol is only looked for once
+            let isKnown = symbols.iter().any(|[topLeft, _, bottomLeft, bottomRight]| {
+                (topLeft.y..bottomLeft.y).contains(&(y as f32))
+                    && (topLeft.x - 1.0..bottomRight.x).contains(&(left as f32))
+            });
+            if isKnown {
+                continue;
+            }
+            let Some(moduleWidth) = findLeftRAP(image, &runs, start, y) else {
+                continue;
+            };
+            symbols.extend(findSymbols(image, left, y, moduleWidth));
+        }
+    }
+    symbols
+}
+
+/**
+ * @return the start, the length and the color of every run of pixels in the scan line
+ */
+fn getRuns(image: &BitMatrix, y: u32) -> Vec<(u32, u32, bool)> {
+    let mut runs: Vec<(u32, u32, bool)> = Vec::new();
+    for x in 0..image.getWidth() {
+        let black = image.get(x, y);
+        match runs.last_mut() {
+            Some((_, length, color)) if *color == black => *length += 1,
+            _ => runs.push((x, 1, black)),
+        }
+    }
+    runs
+}
+
+/**
+ * Checks whether the bar at runs[start] begins a left RAP: six bars and spaces, a quiet zone
+ * before them and a codeword after them.
+ *
+ * @return the module width, if it does
+ */
+fn findLeftRAP(image: &BitMatrix, runs: &[(u32, u32, bool)], start: usize, y: u32) -> Option<f32> {
+    let codewordEnd = start + ELEMENTS_IN_RAP + BARS_IN_MODULE as usize;
+    if codewordEnd > runs.len() {
+        return None;
+    }
+    let rapWidth = runs[start..start + ELEMENTS_IN_RAP]
+        .iter()
+        .map(|(_, length, _)| length)
+        .sum::<u32>();
+    let moduleWidth = rapWidth as f32 / MODULES_IN_RAP as f32;
+    let (_, quietZone, _) = runs[start - 1];
+    if moduleWidth < 1.0 || (quietZone as f32) < moduleWidth * 0.75 {
+        return None;
+    }
+    let (left, _, _) = runs[start];
+    readRAP(image, left as f32, y, moduleWidth)?;
+
+    // the first codeword follows right after the RAP
+    let moduleBitCount = runs[start + ELEMENTS_IN_RAP..codewordEnd]
+        .iter()
+        .map(|(_, length, _)| *length)
+        .collect::<Vec<_>>();
+    let codewordWidth = moduleBitCount.iter().sum::<u32>() as f32;
+    let expected = MODULES_IN_CODEWORD as f32 * moduleWidth;
+    if (codewordWidth - expected).abs() > expected * 0.25 {
+        return None;
+    }
+    let symbol = pdf_417_codeword_decoder::getDecodedValue(&moduleBitCount);
+    (pdf_417_common::getCodeword(symbol) >= 0).then_some(moduleWidth)
+}
+
+/**
+ * Samples the middles of the ten modules starting at left.
+ *
+ * @return the left or right RAP read, if the modules form one
+ */
+fn readRAP(image: &BitMatrix, left: f32, y: u32, moduleWidth: f32) -> Option<u32> {
+    let mut pattern = 0;
+    for module in 0..MODULES_IN_RAP {
+        let x = (left + (module as f32 + 0.5) * moduleWidth) as u32;
+        if x >= image.getWidth() {
+            return None;
+        }
+        pattern = (pattern << 1) | u32::from(image.get(x, y));
+    }
+    SIDE_RAP_PATTERNS.contains(&pattern).then_some(pattern)
+}
+
+/**
+ * Follows the left RAP found in row y up and down the image, and looks for the right edge of the
+ * symbol in the row in the middle.
+ *
+ * @return the corners of the symbol, for every width it may have
+ */
+fn findSymbols(image: &BitMatrix, left: u32, y: u32, moduleWidth: f32) -> Vec<[Point; 4]> {
+    let (top, topLeft, mut raps) = followRAP(image, left, y, moduleWidth, false);
+    let (bottom, bottomLeft, rapsBelow) = followRAP(image, left, y, moduleWidth, true);
+    raps.extend(rapsBelow);
+    raps.sort_unstable();
+    raps.dedup();
+    let height = bottom + 1 - top;
+    if raps.len() < MIN_ROWS
+        || (height as f32) < (MIN_ROWS as u32 * ROW_HEIGHT) as f32 * moduleWidth
+    {
+        return Vec::new();
+    }
+
+    let middle = top + height / 2;
+    let Some(middleLeft) = findLeftEdge(image, left, middle, moduleWidth) else {
+        return Vec::new();
+    };
+    findRightEdges(image, middleLeft, middle, moduleWidth)
+        .into_iter()
+        .map(|right| {
+            let symbolWidth = (right - middleLeft) as f32;
+            [
+                point(topLeft as f32, top as f32),
+                point(topLeft as f32 + symbolWidth, top as f32),
+                point(bottomLeft as f32, (bottom + 1) as f32),
+                point(bottomLeft as f32 + symbolWidth, (bottom + 1) as f32),
+            ]
+        })
+        .collect()
+}
+
+/**
+ * Goes up or down from row y as long as the rows start with a left RAP, allowing for a few rows
+ * that don't read, and for the left edge drifting by a pixel from row to row.
+ *
+ * @return the last row with a RAP, its left edge, and the RAPs read
+ */
+fn followRAP(
+    image: &BitMatrix,
+    left: u32,
+    y: u32,
+    moduleWidth: f32,
+    down: bool,
+) -> (u32, u32, Vec<u32>) {
+    let maxGap = (ROW_HEIGHT as f32 * moduleWidth).ceil() as u32;
+    let (mut last, mut lastLeft) = (y, left);
+    let mut raps = Vec::new();
+    let mut row = y;
+    loop {
+        let next = if down {
+            row.checked_add(1).filter(|next| *next < image.getHeight())
+        } else {
+            row.checked_sub(1)
+        };
+        let Some(next) = next else {
+            break;
+        };
+        if next.abs_diff(last) > maxGap {
+            break;
+        }
+        row = next;
+        let Some(edge) = findLeftEdge(image, lastLeft, row, moduleWidth) else {
+            continue;
+        };
+        if let Some(rap) = readRAP(image, edge as f32, row, moduleWidth) {
+            raps.push(rap);
+            (last, lastLeft) = (row, edge);
+        }
+    }
+    if let Some(rap) = readRAP(image, left as f32, y, moduleWidth) {
+        raps.push(rap);
+    }
+    (last, lastLeft, raps)
+}
+
+/**
+ * @return the first bar of the row within a module of the expected left edge, with a space
+ *  before it
+ */
+fn findLeftEdge(image: &BitMatrix, left: u32, y: u32, moduleWidth: f32) -> Option<u32> {
+    let reach = moduleWidth.ceil() as u32;
+    (left.saturating_sub(reach).max(1)..(left + reach).min(image.getWidth()))
+        .find(|x| image.get(*x, y) && !image.get(x - 1, y))
+}
+
+/**
+ * Looks for the right RAP and the stop pattern where they would end a row of one to four
+ * columns.
+ *
+ * @return the right edge of the stop pattern, exclusive, for every number of columns they are
+ *  found for
+ */
+fn findRightEdges(image: &BitMatrix, left: u32, y: u32, moduleWidth: f32) -> Vec<u32> {
+    let runs = getRuns(image, y);
+    (1..=4)
+        .filter_map(|columns| {
+            let rowWidth = micro_pdf_417_common::getRowWidth(columns) as f32;
+            let expected = left as f32 + rowWidth * moduleWidth;
+            let tolerance = rowWidth * moduleWidth * MAX_WIDTH_VARIANCE;
+            // the stop pattern is a single bar, followed by the quiet zone
+            runs.windows(2)
+                .filter(|pair| pair[0].2 && !pair[1].2)
+                .map(|pair| pair[0].0 + pair[0].1)
+                .filter(|end| (*end as f32 - expected).abs() <= tolerance)
+                .filter(|end| {
+                    // the row width tells the module width more exactly than the left RAP
+                    let moduleWidth = (end - left) as f32 / rowWidth;
+                    let rapLeft = *end as f32
+                        - (MODULES_IN_RAP + micro_pdf_417_common::MODULES_IN_STOP_PATTERN) as f32
+                            * moduleWidth;
+                    readRAP(image, rapLeft, y, moduleWidth).is_some()
+                        && readRAP(image, left as f32, y, moduleWidth).is_some()
+                })
+                .min_by(|a, b| {
+                    (*a as f32 - expected)
+                        .abs()
+                        .total_cmp(&(*b as f32 - expected).abs())
+                })
+        })
+        .collect()
+}
@@ -2,3 +2,5 @@ mod pdf_417_detector_result;
 pub use pdf_417_detector_result::*;
 
 pub mod pdf_417_detector;
+
+pub mod micro_pdf_417_detector;
@@ -52,6 +52,19 @@ impl BarcodeMatrix {
         }
     }
 
+    /**
+     * @param height   the height of the matrix (Rows)
+     * @param rowWidth the width of a row in modules
+     */
+    pub fn with_row_width(height: usize, rowWidth: usize) -> Self {
+        Self {
+            matrix: (0..height).map(|_| BarcodeRow::new(rowWidth)).collect(),
+            currentRow: -1,
+            height,
+            width: rowWidth,
+        }
+    }
+
     pub fn set(&mut self, x: usize, y: usize, value: u8) {
         self.matrix[y].set(x, value);
     }
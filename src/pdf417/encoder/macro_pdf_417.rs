@@ -0,0 +1,121 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/**
+ * Data object holding the fields of a Macro PDF417 control block (see ISO/IEC 15438:2015 Annex H),
+ * which links several PDF417 symbols into one file.
+ *
+ * The file id is given as a string of digits in groups of three, each group being a codeword
+ * value from 000 to 899. This is the same representation that
+ * {@link PDF417RXingResultMetadata#getFileId()} reports when decoding.
+ */
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct MacroPDF417 {
+    segmentIndex: u32,
+    fileId: String,
+    lastSegment: bool,
+    segmentCount: Option<u32>,
+    fileName: Option<String>,
+    timestamp: Option<u64>,
+    sender: Option<String>,
+    addressee: Option<String>,
+    fileSize: Option<u64>,
+    checksum: Option<u16>,
+}
+
+impl MacroPDF417 {
+    pub fn new(segmentIndex: u32, fileId: &str) -> Self {
+        Self {
+            segmentIndex,
+            fileId: fileId.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    pub fn getSegmentIndex(&self) -> u32 {
+        self.segmentIndex
+    }
+
+    pub fn setSegmentIndex(&mut self, segmentIndex: u32) {
+        self.segmentIndex = segmentIndex;
+    }
+
+    pub fn getFileId(&self) -> &str {
+        &self.fileId
+    }
+
+    pub fn setFileId(&mut self, fileId: &str) {
+        self.fileId = fileId.to_owned();
+    }
+
+    pub fn isLastSegment(&self) -> bool {
+        self.lastSegment
+    }
+
+    /**
+     * @param lastSegment whether to add the terminator marking the last segment of the file
+     */
+    pub fn setLastSegment(&mut self, lastSegment: bool) {
+        self.lastSegment = lastSegment;
+    }
+
+    pub fn getSegmentCount(&self) -> Option<u32> {
+        self.segmentCount
+    }
+
+    pub fn setSegmentCount(&mut self, segmentCount: Option<u32>) {
+        self.segmentCount = segmentCount;
+    }
+
+    pub fn getFileName(&self) -> Option<&str> {
+        self.fileName.as_deref()
+    }
+
+    pub fn setFileName(&mut self, fileName: Option<String>) {
+        self.fileName = fileName;
+    }
+
+    /**
+     * @return the time stamp of the file in seconds since the Unix epoch
+     */
+    pub fn getTimestamp(&self) -> Option<u64> {
+        self.timestamp
+    }
+
+    pub fn setTimestamp(&mut self, timestamp: Option<u64>) {
+        self.timestamp = timestamp;
+    }
+
+    pub fn getSender(&self) -> Option<&str> {
+        self.sender.as_deref()
+    }
+
+    pub fn setSender(&mut self, sender: Option<String>) {
+        self.sender = sender;
+    }
+
+    pub fn getAddressee(&self) -> Option<&str> {
+        self.addressee.as_deref()
+    }
+
+    pub fn setAddressee(&mut self, addressee: Option<String>) {
+        self.addressee = addressee;
+    }
+
+    pub fn getFileSize(&self) -> Option<u64> {
+        self.fileSize
+    }
+
+    pub fn setFileSize(&mut self, fileSize: Option<u64>) {
+        self.fileSize = fileSize;
+    }
+
+    pub fn getChecksum(&self) -> Option<u16> {
+        self.checksum
+    }
+
+    pub fn setChecksum(&mut self, checksum: Option<u16>) {
+        self.checksum = checksum;
+    }
+}
@@ -0,0 +1,228 @@
+use crate::common::{CharacterSet, Result};
+use crate::pdf417::micro_pdf_417_common::{
+    self, MicroPDF417Variant, CENTER_RAP_PATTERNS, MICRO_PDF417_VARIANTS, MODULES_IN_RAP,
+    MODULES_IN_STOP_PATTERN, SIDE_RAP_PATTERNS,
+};
+use crate::Exceptions;
+
+use super::{
+    pdf_417_error_correction, pdf_417_high_level_encoder, BarcodeMatrix, Compaction, MacroPDF417,
+    CODEWORD_TABLE, PDF417,
+};
+
+/**
+ * Top-level class for the logic part of the MicroPDF417 implementation (ISO/IEC 24728).
+ *
+ * MicroPDF417 uses the codewords and high-level encoding of PDF417, but has neither start and
+ * stop patterns nor row indicators. Rows are told apart by row address patterns instead, and the
+ * symbol comes in 34 fixed sizes with a fixed number of error correction codewords each.
+ */
+pub struct MicroPDF417 {
+    barcodeMatrix: Option<BarcodeMatrix>,
+    variant: Option<&'static MicroPDF417Variant>,
+    compaction: Compaction,
+    encoding: Option<CharacterSet>,
+    minCols: u32,
+    maxCols: u32,
+    maxRows: u32,
+    minRows: u32,
+    macroPDF417: Option<MacroPDF417>,
+}
+
+impl Default for MicroPDF417 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MicroPDF417 {
+    pub fn new() -> Self {
+        Self {
+            barcodeMatrix: None,
+            variant: None,
+            compaction: Compaction::AUTO,
+            encoding: None,
+            minCols: 1,
+            maxCols: 4,
+            maxRows: 44,
+            minRows: 4,
+            macroPDF417: None,
+        }
+    }
+
+    pub fn getBarcodeMatrix(&self) -> &Option<BarcodeMatrix> {
+        &self.barcodeMatrix
+    }
+
+    /**
+     * @return the symbol size chosen by the last call to generateBarcodeLogic
+     */
+    pub fn getVariant(&self) -> Option<&'static MicroPDF417Variant> {
+        self.variant
+    }
+
+    pub(crate) fn encodeLowLevel(
+        fullCodewords: &[u32],
+        variant: &MicroPDF417Variant,
+        logic: &mut BarcodeMatrix,
+    ) -> Result<()> {
+        let columns = variant.getColumns();
+        let mut codewords = fullCodewords.iter();
+        for y in 0..variant.getRows() {
+            let cluster = (variant.getCluster(y) / 3) as usize;
+            logic.startRow();
+            PDF417::encodeChar(
+                SIDE_RAP_PATTERNS[variant.getLeftRAP(y) as usize - 1],
+                MODULES_IN_RAP,
+                logic.getCurrentRowMut(),
+            );
+            for x in 0..columns {
+                // the center RAP follows the first column of 3 column symbols and the second of 4
+                if columns > 2 && x == columns - 2 {
+                    PDF417::encodeChar(
+                        CENTER_RAP_PATTERNS[variant.getCenterRAP(y) as usize - 1],
+                        MODULES_IN_RAP,
+                        logic.getCurrentRowMut(),
+                    );
+                }
+                let codeword = codewords.next().ok_or(Exceptions::INDEX_OUT_OF_BOUNDS)?;
+                PDF417::encodeChar(
+                    CODEWORD_TABLE[cluster][*codeword as usize],
+                    17,
+                    logic.getCurrentRowMut(),
+                );
+            }
+            PDF417::encodeChar(
+                SIDE_RAP_PATTERNS[variant.getRightRAP(y) as usize - 1],
+                MODULES_IN_RAP,
+                logic.getCurrentRowMut(),
+            );
+            logic
+                .getCurrentRowMut()
+                .addBar(true, MODULES_IN_STOP_PATTERN as usize);
+        }
+        Ok(())
+    }
+
+    /**
+     * @param msg message to encode
+     * @throws WriterException if the contents cannot be encoded in this format
+     */
+    pub fn generateBarcodeLogic(&mut self, msg: &str) -> Result<()> {
+        self.generateBarcodeLogicWithAutoECI(msg, false)
+    }
+
+    /**
+     * @param msg message to encode
+     * @param autoECI automatically insert ECIs if needed
+     * @throws WriterException if the contents cannot be encoded in this format
+     */
+    pub fn generateBarcodeLogicWithAutoECI(&mut self, msg: &str, autoECI: bool) -> Result<()> {
+        //1. step: High-level encoding
+        let highLevel = pdf_417_high_level_encoder::encodeHighLevel(
+            msg,
+            self.compaction,
+            self.encoding,
+            autoECI,
+        )?;
+        let macroBlock = if let Some(macroPDF417) = &self.macroPDF417 {
+            pdf_417_high_level_encoder::encodeMacroBlock(macroPDF417)?
+        } else {
+            String::new()
+        };
+        let sourceCodeWords = (highLevel.chars().count() + macroBlock.chars().count()) as u32;
+
+        let variant = self.determineVariant(sourceCodeWords).ok_or_else(|| {
+            Exceptions::writer_with(format!(
+                "Encoded message contains too many code words, message too big ({} bytes)",
+                msg.chars().count()
+            ))
+        })?;
+
+        //2. step: construct data codewords, there is no symbol length descriptor
+        let pad = variant.getDataCapacity() - sourceCodeWords;
+        let mut dataCodewords = highLevel;
+        for _i in 0..pad {
+            dataCodewords.push(char::from_u32(900).ok_or(Exceptions::PARSE)?);
+        }
+        // the Macro PDF417 control block follows the pad codewords
+        dataCodewords.push_str(&macroBlock);
+
+        //3. step: Error correction
+        let ec = pdf_417_error_correction::generateErrorCorrectionCodewords(
+            &dataCodewords,
+            variant.getErrorCorrectionCodewords(),
+        )?;
+
+        //4. step: low-level encoding
+        let fullCodewords = dataCodewords
+            .chars()
+            .chain(ec.chars())
+            .map(|c| c as u32)
+            .collect::<Vec<_>>();
+        let mut barcode_matrix = BarcodeMatrix::with_row_width(
+            variant.getRows() as usize,
+            variant.getRowWidth() as usize,
+        );
+        Self::encodeLowLevel(&fullCodewords, variant, &mut barcode_matrix)?;
+        self.barcodeMatrix = Some(barcode_matrix);
+        self.variant = Some(variant);
+        Ok(())
+    }
+
+    /**
+     * Determines the smallest symbol within the dimension limits that holds the given number of
+     * codewords.
+     */
+    fn determineVariant(&self, sourceCodeWords: u32) -> Option<&'static MicroPDF417Variant> {
+        MICRO_PDF417_VARIANTS
+            .iter()
+            .filter(|variant| {
+                (self.minCols..=self.maxCols).contains(&variant.getColumns())
+                    && (self.minRows..=self.maxRows).contains(&variant.getRows())
+                    && variant.getDataCapacity() >= sourceCodeWords
+            })
+            .min_by_key(|variant| {
+                (
+                    variant.getRowWidth() * variant.getRows() * micro_pdf_417_common::ROW_HEIGHT,
+                    variant.getColumns(),
+                )
+            })
+    }
+
+    /**
+     * Sets max/min row/col values
+     *
+     * @param maxCols maximum allowed columns
+     * @param minCols minimum allowed columns
+     * @param maxRows maximum allowed rows
+     * @param minRows minimum allowed rows
+     */
+    pub fn setDimensions(&mut self, maxCols: u32, minCols: u32, maxRows: u32, minRows: u32) {
+        self.maxCols = maxCols;
+        self.minCols = minCols;
+        self.maxRows = maxRows;
+        self.minRows = minRows;
+    }
+
+    /**
+     * @param compaction compaction mode to use
+     */
+    pub fn setCompaction(&mut self, compaction: Compaction) {
+        self.compaction = compaction
+    }
+
+    /**
+     * @param encoding sets character encoding to use
+     */
+    pub fn setEncoding(&mut self, encoding: Option<CharacterSet>) {
+        self.encoding = encoding;
+    }
+
+    /**
+     * @param macroPDF417 sets the Macro PDF417 control block to append, or {@code None} for none
+     */
+    pub fn setMacroPDF417(&mut self, macroPDF417: Option<MacroPDF417>) {
+        self.macroPDF417 = macroPDF417;
+    }
+}
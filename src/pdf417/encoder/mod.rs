@@ -10,11 +10,17 @@ pub use barcode_matrix::*;
 mod dimensions;
 pub use dimensions::*;
 
+mod macro_pdf_417;
+pub use macro_pdf_417::*;
+
 pub mod pdf_417_error_correction;
 pub mod pdf_417_high_level_encoder;
 
 mod pdf_417;
 pub use pdf_417::*;
 
+mod micro_pdf_417;
+pub use micro_pdf_417::*;
+
 #[cfg(test)]
 pub mod pdf_417_high_level_encoder_test_adapter;
@@ -23,6 +23,7 @@ use crate::Exceptions;
 
 use super::{
     pdf_417_error_correction, pdf_417_high_level_encoder, BarcodeMatrix, BarcodeRow, Compaction,
+    MacroPDF417,
 };
 
 /**
@@ -37,6 +38,7 @@ pub struct PDF417 {
     maxCols: u32,
     maxRows: u32,
     minRows: u32,
+    macroPDF417: Option<MacroPDF417>,
 }
 
 impl Default for PDF417 {
@@ -60,6 +62,7 @@ impl PDF417 {
             maxCols: 30,
             maxRows: 30,
             minRows: 2,
+            macroPDF417: None,
         }
     }
 
@@ -106,7 +109,7 @@ impl PDF417 {
         }
     }
 
-    fn encodeChar(pattern: u32, len: u32, logic: &mut BarcodeRow) {
+    pub(super) fn encodeChar(pattern: u32, len: u32, logic: &mut BarcodeRow) {
         let mut map = 1 << (len - 1);
         let mut last = (pattern & map) != 0; //Initialize to inverse of first bit
         let mut width = 0;
@@ -125,7 +128,7 @@ impl PDF417 {
         logic.addBar(last, width);
     }
 
-    fn encodeLowLevel(
+    pub(crate) fn encodeLowLevel(
         &self,
         fullCodewords: &str,
         c: u32,
@@ -208,7 +211,12 @@ impl PDF417 {
             self.encoding,
             autoECI,
         )?;
-        let sourceCodeWords = highLevel.chars().count() as u32;
+        let macroBlock = if let Some(macroPDF417) = &self.macroPDF417 {
+            pdf_417_high_level_encoder::encodeMacroBlock(macroPDF417)?
+        } else {
+            String::new()
+        };
+        let sourceCodeWords = (highLevel.chars().count() + macroBlock.chars().count()) as u32;
 
         let dimension = self.determineDimensions(sourceCodeWords, errorCorrectionCodeWords)?;
 
@@ -234,6 +242,8 @@ impl PDF417 {
             sb.push(char::from_u32(900).ok_or(Exceptions::PARSE)?);
             //PAD characters
         }
+        // the Macro PDF417 control block follows the pad codewords
+        sb.push_str(&macroBlock);
         let dataCodewords = sb;
 
         //3. step: Error correction
@@ -348,6 +358,13 @@ impl PDF417 {
     pub fn setEncoding(&mut self, encoding: Option<CharacterSet>) {
         self.encoding = encoding;
     }
+
+    /**
+     * @param macroPDF417 sets the Macro PDF417 control block to append, or {@code None} for none
+     */
+    pub fn setMacroPDF417(&mut self, macroPDF417: Option<MacroPDF417>) {
+        self.macroPDF417 = macroPDF417;
+    }
 }
 
 /**
@@ -362,7 +379,7 @@ const STOP_PATTERN: u32 = 0x3fa29;
 /**
  * The codeword table from the Annex A of ISO/IEC 15438:2001(E).
  */
-const CODEWORD_TABLE: [[u32; 929]; 3] = [
+pub(super) const CODEWORD_TABLE: [[u32; 929]; 3] = [
     [
         0x1d5c0, 0x1eaf0, 0x1f57c, 0x1d4e0, 0x1ea78, 0x1f53e, 0x1a8c0, 0x1d470, 0x1a860, 0x15040,
         0x1a830, 0x15020, 0x1adc0, 0x1d6f0, 0x1eb7c, 0x1ace0, 0x1d678, 0x1eb3e, 0x158c0, 0x1ac70,
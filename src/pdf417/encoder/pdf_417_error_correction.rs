@@ -158,7 +158,49 @@ pub fn getRecommendedMinimumErrorCorrectionLevel(n: u32) -> Result<u32> {
  * @return the String representing the error correction codewords
  */
 pub fn generateErrorCorrection(dataCodewords: &str, errorCorrectionLevel: u32) -> Result<String> {
-    let k = getErrorCorrectionCodewordCount(errorCorrectionLevel)?;
+    getErrorCorrectionCodewordCount(errorCorrectionLevel)?;
+    generateErrorCorrectionWithCoefficients(
+        dataCodewords,
+        &EC_COEFFICIENTS[errorCorrectionLevel as usize],
+    )
+}
+
+/**
+ * Generates an arbitrary number of error correction codewords, as MicroPDF417 symbols use
+ * counts that are not a power of two (see ISO/IEC 24728).
+ *
+ * @param dataCodewords the data codewords
+ * @param k             the number of error correction codewords
+ * @return the String representing the error correction codewords
+ */
+pub fn generateErrorCorrectionCodewords(dataCodewords: &str, k: u32) -> Result<String> {
+    if k < 2 {
+        return Err(Exceptions::illegal_argument_with(
+            "At least two error correction codewords are needed",
+        ));
+    }
+    // the generator polynomial is (x - 3)(x - 3^2)...(x - 3^k)
+    let mut coefficients = vec![1u32];
+    let mut root = 1;
+    for _i in 0..k {
+        root = (root * 3) % 929;
+        let mut product = vec![0u32; coefficients.len() + 1];
+        for (j, coefficient) in coefficients.iter().enumerate() {
+            product[j + 1] = (product[j + 1] + coefficient) % 929;
+            product[j] = (product[j] + 929 - (coefficient * root) % 929) % 929;
+        }
+        coefficients = product;
+    }
+    // drop the leading coefficient, which is always 1
+    coefficients.pop();
+    generateErrorCorrectionWithCoefficients(dataCodewords, &coefficients)
+}
+
+fn generateErrorCorrectionWithCoefficients(
+    dataCodewords: &str,
+    coefficients: &[u32],
+) -> Result<String> {
+    let k = coefficients.len() as u32;
     let mut e = vec![0 as char; k as usize]; //new char[k];
     let sld = dataCodewords.chars().count();
     for i in 0..sld {
@@ -172,12 +214,12 @@ pub fn generateErrorCorrection(dataCodewords: &str, errorCorrectionLevel: u32) -
         let mut t3;
         let mut j = k as usize - 1;
         while j >= 1 {
-            t2 = (t1 * EC_COEFFICIENTS[errorCorrectionLevel as usize][j]) % 929;
+            t2 = (t1 * coefficients[j]) % 929;
             t3 = 929 - t2;
             e[j] = char::from_u32((e[j - 1] as u32 + t3) % 929).ok_or(Exceptions::PARSE)?;
             j -= 1;
         }
-        t2 = (t1 * EC_COEFFICIENTS[errorCorrectionLevel as usize][0]) % 929;
+        t2 = (t1 * coefficients[0]) % 929;
         t3 = 929 - t2;
         e[0] = char::from_u32(t3 % 929).ok_or(Exceptions::PARSE)?;
     }
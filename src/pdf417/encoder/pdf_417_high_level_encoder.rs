@@ -25,7 +25,7 @@ use crate::{
     Exceptions,
 };
 
-use super::Compaction;
+use super::{Compaction, MacroPDF417};
 
 /**
  * PDF417 high-level encoder following the algorithm described in ISO/IEC 15438:2001(E) in
@@ -107,6 +107,34 @@ const ECI_GENERAL_PURPOSE: u32 = 926;
  */
 const ECI_CHARSET: u32 = 927;
 
+/**
+ * begin of a Macro PDF417 control block
+ */
+const BEGIN_MACRO_PDF417_CONTROL_BLOCK: u32 = 928;
+
+/**
+ * begin of an optional field in a Macro PDF417 control block
+ */
+const BEGIN_MACRO_PDF417_OPTIONAL_FIELD: u32 = 923;
+
+/**
+ * terminator of the Macro PDF417 control block of the last segment
+ */
+const MACRO_PDF417_TERMINATOR: u32 = 922;
+
+/**
+ * highest segment index a Macro PDF417 control block can hold
+ */
+pub const MAX_MACRO_PDF417_SEGMENT_INDEX: u32 = 99998;
+
+const MACRO_PDF417_OPTIONAL_FIELD_FILE_NAME: u32 = 0;
+const MACRO_PDF417_OPTIONAL_FIELD_SEGMENT_COUNT: u32 = 1;
+const MACRO_PDF417_OPTIONAL_FIELD_TIME_STAMP: u32 = 2;
+const MACRO_PDF417_OPTIONAL_FIELD_SENDER: u32 = 3;
+const MACRO_PDF417_OPTIONAL_FIELD_ADDRESSEE: u32 = 4;
+const MACRO_PDF417_OPTIONAL_FIELD_FILE_SIZE: u32 = 5;
+const MACRO_PDF417_OPTIONAL_FIELD_CHECKSUM: u32 = 6;
+
 /**
  * Raw code table for text compaction Mixed sub-mode
  */
@@ -352,6 +380,109 @@ pub fn encodeHighLevel(
     Ok(sb)
 }
 
+/**
+ * Encodes a Macro PDF417 control block as described in Annex H of ISO/IEC 15438:2015. The
+ * block has to be placed after the data and pad codewords of the symbol.
+ *
+ * @param macroPDF417 the control block fields
+ * @return the encoded control block (the char values range from 0 to 928)
+ */
+pub fn encodeMacroBlock(macroPDF417: &MacroPDF417) -> Result<String> {
+    let mut sb = String::new();
+    sb.push(char::from_u32(BEGIN_MACRO_PDF417_CONTROL_BLOCK).ok_or(Exceptions::PARSE)?);
+
+    // the segment index is always numerically compacted into two codewords
+    if macroPDF417.getSegmentIndex() > MAX_MACRO_PDF417_SEGMENT_INDEX {
+        return Err(Exceptions::writer_with(format!(
+            "Macro PDF417 segment index must be at most {MAX_MACRO_PDF417_SEGMENT_INDEX}, but was {}",
+            macroPDF417.getSegmentIndex()
+        )));
+    }
+    encodeMacroNumber(u64::from(macroPDF417.getSegmentIndex()), 5, &mut sb)?;
+
+    let fileId = macroPDF417.getFileId();
+    if fileId.is_empty()
+        || !fileId.len().is_multiple_of(3)
+        || !fileId.chars().all(|c| c.is_ascii_digit())
+    {
+        return Err(Exceptions::writer_with(format!(
+            "Macro PDF417 file id must consist of groups of three digits, but was '{fileId}'"
+        )));
+    }
+    for i in (0..fileId.len()).step_by(3) {
+        let codeword = fileId[i..i + 3]
+            .parse::<u32>()
+            .map_err(|e| Exceptions::writer_with(e.to_string()))?;
+        if codeword >= LATCH_TO_TEXT {
+            return Err(Exceptions::writer_with(format!(
+                "Macro PDF417 file id groups must be below 900, but got {codeword}"
+            )));
+        }
+        sb.push(char::from_u32(codeword).ok_or(Exceptions::PARSE)?);
+    }
+
+    if let Some(fileName) = macroPDF417.getFileName() {
+        encodeMacroText(MACRO_PDF417_OPTIONAL_FIELD_FILE_NAME, fileName, &mut sb)?;
+    }
+    if let Some(segmentCount) = macroPDF417.getSegmentCount() {
+        encodeMacroField(MACRO_PDF417_OPTIONAL_FIELD_SEGMENT_COUNT, &mut sb)?;
+        encodeMacroNumber(u64::from(segmentCount), 5, &mut sb)?;
+    }
+    if let Some(timestamp) = macroPDF417.getTimestamp() {
+        encodeMacroField(MACRO_PDF417_OPTIONAL_FIELD_TIME_STAMP, &mut sb)?;
+        encodeMacroNumber(timestamp, 0, &mut sb)?;
+    }
+    if let Some(sender) = macroPDF417.getSender() {
+        encodeMacroText(MACRO_PDF417_OPTIONAL_FIELD_SENDER, sender, &mut sb)?;
+    }
+    if let Some(addressee) = macroPDF417.getAddressee() {
+        encodeMacroText(MACRO_PDF417_OPTIONAL_FIELD_ADDRESSEE, addressee, &mut sb)?;
+    }
+    if let Some(fileSize) = macroPDF417.getFileSize() {
+        encodeMacroField(MACRO_PDF417_OPTIONAL_FIELD_FILE_SIZE, &mut sb)?;
+        encodeMacroNumber(fileSize, 0, &mut sb)?;
+    }
+    if let Some(checksum) = macroPDF417.getChecksum() {
+        encodeMacroField(MACRO_PDF417_OPTIONAL_FIELD_CHECKSUM, &mut sb)?;
+        encodeMacroNumber(u64::from(checksum), 0, &mut sb)?;
+    }
+
+    if macroPDF417.isLastSegment() {
+        sb.push(char::from_u32(MACRO_PDF417_TERMINATOR).ok_or(Exceptions::PARSE)?);
+    }
+
+    Ok(sb)
+}
+
+fn encodeMacroField(field: u32, sb: &mut String) -> Result<()> {
+    sb.push(char::from_u32(BEGIN_MACRO_PDF417_OPTIONAL_FIELD).ok_or(Exceptions::PARSE)?);
+    sb.push(char::from_u32(field).ok_or(Exceptions::PARSE)?);
+    Ok(())
+}
+
+/**
+ * Optional text fields are implicitly text compacted, starting in submode Alpha.
+ */
+fn encodeMacroText(field: u32, value: &str, sb: &mut String) -> Result<()> {
+    if value.is_empty() || !value.chars().all(isText) {
+        return Err(Exceptions::writer_with(format!(
+            "Macro PDF417 text fields must be non-empty and text compactable, but got '{value}'"
+        )));
+    }
+    encodeMacroField(field, sb)?;
+    let input = NoECIInput::new(value.to_owned());
+    encodeText(&input, 0, input.length() as u32, sb, SUBMODE_ALPHA)?;
+    Ok(())
+}
+
+/**
+ * Numeric fields are implicitly numerically compacted.
+ */
+fn encodeMacroNumber(value: u64, width: usize, sb: &mut String) -> Result<()> {
+    let digits = format!("{value:0width$}");
+    encodeNumeric(&NoECIInput::new(digits.clone()), 0, digits.len() as u32, sb)
+}
+
 /**
  * Encode parts of the message using Text Compaction as described in ISO/IEC 15438:2001(E),
  * chapter 4.4.2.
@@ -865,7 +996,10 @@ impl Display for NoECIInput {
 mod PDF417EncoderTestCase {
     use crate::{
         common::CharacterSet,
-        pdf417::encoder::{pdf_417_high_level_encoder::encodeHighLevel, Compaction},
+        pdf417::encoder::{
+            pdf_417_high_level_encoder::{encodeHighLevel, encodeMacroBlock},
+            Compaction, MacroPDF417,
+        },
     };
 
     #[test]
@@ -926,4 +1060,33 @@ mod PDF417EncoderTestCase {
     fn testEncodeEmptyString() {
         encodeHighLevel("", Compaction::AUTO, None, false).expect("encode");
     }
+
+    /**
+     * Encodes the first sample given in ISO/IEC 15438:2015(E) - Annex H.4
+     */
+    #[test]
+    fn testEncodeMacroBlock() {
+        let mut macroPDF417 = MacroPDF417::new(0, "017053");
+        macroPDF417.setSegmentCount(Some(4));
+        macroPDF417.setSender(Some("CEN BE".to_owned()));
+        macroPDF417.setAddressee(Some("ISO CH".to_owned()));
+        let encoded = encodeMacroBlock(&macroPDF417).expect("encode");
+        assert_eq!(
+            vec![
+                928, 111, 100, 17, 53, 923, 1, 111, 104, 923, 3, 64, 416, 34, 923, 4, 258, 446, 67
+            ],
+            encoded.chars().map(|c| c as u32).collect::<Vec<_>>()
+        );
+
+        // the second sample is the last segment
+        macroPDF417.setSegmentIndex(3);
+        macroPDF417.setSender(None);
+        macroPDF417.setAddressee(None);
+        macroPDF417.setLastSegment(true);
+        let encoded = encodeMacroBlock(&macroPDF417).expect("encode");
+        assert_eq!(
+            vec![928, 111, 103, 17, 53, 923, 1, 111, 104, 922],
+            encoded.chars().map(|c| c as u32).collect::<Vec<_>>()
+        );
+    }
 }
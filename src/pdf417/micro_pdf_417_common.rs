@@ -0,0 +1,269 @@
+/**
+ * Symbol sizes and row address patterns of MicroPDF417 (ISO/IEC 24728), shared by the encoder
+ * and the decoder, and of the CC-A composite component built from it (ISO/IEC 24723).
+ */
+use super::pdf_417_common::MODULES_IN_CODEWORD;
+
+/**
+ * Number of modules in a row address pattern (RAP).
+ */
+pub const MODULES_IN_RAP: u32 = 10;
+
+/**
+ * Number of modules in the stop pattern closing every row.
+ */
+pub const MODULES_IN_STOP_PATTERN: u32 = 1;
+
+/**
+ * The minimum height of a row, in modules.
+ */
+pub const ROW_HEIGHT: u32 = 2;
+
+/**
+ * One of the 34 MicroPDF417 symbol sizes, with the number of error correction codewords it
+ * carries and the row address patterns its first row starts with.
+ */
+#[derive(Debug, PartialEq, Eq)]
+pub struct MicroPDF417Variant {
+    columns: u32,
+    rows: u32,
+    errorCorrectionCodewords: u32,
+    leftRAP: u32,
+    centerRAP: u32,
+    rightRAP: u32,
+    cluster: u32,
+}
+
+impl MicroPDF417Variant {
+    const fn new(
+        columns: u32,
+        rows: u32,
+        errorCorrectionCodewords: u32,
+        leftRAP: u32,
+        centerRAP: u32,
+        rightRAP: u32,
+        cluster: u32,
+    ) -> Self {
+        Self {
+            columns,
+            rows,
+            errorCorrectionCodewords,
+            leftRAP,
+            centerRAP,
+            rightRAP,
+            cluster,
+        }
+    }
+
+    /**
+     * @return the number of data columns (1 to 4)
+     */
+    pub fn getColumns(&self) -> u32 {
+        self.columns
+    }
+
+    pub fn getRows(&self) -> u32 {
+        self.rows
+    }
+
+    pub fn getErrorCorrectionCodewords(&self) -> u32 {
+        self.errorCorrectionCodewords
+    }
+
+    /**
+     * @return the number of data codewords (including pad codewords) the symbol holds
+     */
+    pub fn getDataCapacity(&self) -> u32 {
+        self.columns * self.rows - self.errorCorrectionCodewords
+    }
+
+    /**
+     * @return the width of a row in modules
+     */
+    pub fn getRowWidth(&self) -> u32 {
+        getRowWidth(self.columns)
+    }
+
+    /**
+     * @return the row address number (1-52) of the left RAP in the given row
+     */
+    pub fn getLeftRAP(&self, row: u32) -> u32 {
+        (self.leftRAP - 1 + row) % 52 + 1
+    }
+
+    /**
+     * @return the row address number (1-52) of the center RAP in the given row, only present in
+     * symbols with 3 or 4 columns
+     */
+    pub fn getCenterRAP(&self, row: u32) -> u32 {
+        (self.centerRAP - 1 + row) % 52 + 1
+    }
+
+    /**
+     * @return the row address number (1-52) of the right RAP in the given row
+     */
+    pub fn getRightRAP(&self, row: u32) -> u32 {
+        (self.rightRAP - 1 + row) % 52 + 1
+    }
+
+    /**
+     * @return the cluster (0, 3 or 6) the codewords of the given row are taken from
+     */
+    pub fn getCluster(&self, row: u32) -> u32 {
+        (self.cluster + 3 * row) % 9
+    }
+}
+
+/**
+ * All symbol sizes, ordered by number of columns and then rows.
+ */
+pub const MICRO_PDF417_VARIANTS: [MicroPDF417Variant; 34] = [
+    MicroPDF417Variant::new(1, 11, 7, 1, 0, 9, 0),
+    MicroPDF417Variant::new(1, 14, 7, 8, 0, 8, 3),
+    MicroPDF417Variant::new(1, 17, 7, 36, 0, 36, 6),
+    MicroPDF417Variant::new(1, 20, 8, 19, 0, 19, 0),
+    MicroPDF417Variant::new(1, 24, 8, 9, 0, 17, 6),
+    MicroPDF417Variant::new(1, 28, 8, 25, 0, 33, 0),
+    MicroPDF417Variant::new(2, 8, 8, 1, 0, 1, 0),
+    MicroPDF417Variant::new(2, 11, 9, 1, 0, 9, 0),
+    MicroPDF417Variant::new(2, 14, 9, 8, 0, 8, 3),
+    MicroPDF417Variant::new(2, 17, 10, 36, 0, 36, 6),
+    MicroPDF417Variant::new(2, 20, 11, 19, 0, 19, 0),
+    MicroPDF417Variant::new(2, 23, 13, 9, 0, 17, 6),
+    MicroPDF417Variant::new(2, 26, 15, 27, 0, 35, 6),
+    MicroPDF417Variant::new(3, 6, 12, 1, 1, 1, 0),
+    MicroPDF417Variant::new(3, 8, 14, 7, 7, 7, 0),
+    MicroPDF417Variant::new(3, 10, 16, 15, 15, 15, 6),
+    MicroPDF417Variant::new(3, 12, 18, 25, 25, 25, 0),
+    MicroPDF417Variant::new(3, 15, 21, 37, 37, 37, 0),
+    MicroPDF417Variant::new(3, 20, 26, 1, 17, 33, 0),
+    MicroPDF417Variant::new(3, 26, 32, 1, 9, 17, 0),
+    MicroPDF417Variant::new(3, 32, 38, 21, 29, 37, 6),
+    MicroPDF417Variant::new(3, 38, 44, 15, 31, 47, 6),
+    MicroPDF417Variant::new(3, 44, 50, 1, 25, 49, 0),
+    MicroPDF417Variant::new(4, 4, 8, 47, 19, 43, 3),
+    MicroPDF417Variant::new(4, 6, 12, 1, 1, 1, 0),
+    MicroPDF417Variant::new(4, 8, 14, 7, 7, 7, 0),
+    MicroPDF417Variant::new(4, 10, 16, 15, 15, 15, 6),
+    MicroPDF417Variant::new(4, 12, 18, 25, 25, 25, 0),
+    MicroPDF417Variant::new(4, 15, 21, 37, 37, 37, 0),
+    MicroPDF417Variant::new(4, 20, 26, 1, 17, 33, 0),
+    MicroPDF417Variant::new(4, 26, 32, 1, 9, 17, 0),
+    MicroPDF417Variant::new(4, 32, 38, 21, 29, 37, 6),
+    MicroPDF417Variant::new(4, 38, 44, 15, 31, 47, 6),
+    MicroPDF417Variant::new(4, 44, 50, 1, 25, 49, 0),
+];
+
+/**
+ * @return the symbol size with the given number of columns and rows, if there is one
+ */
+pub fn getVariant(columns: u32, rows: u32) -> Option<&'static MicroPDF417Variant> {
+    MICRO_PDF417_VARIANTS
+        .iter()
+        .find(|variant| variant.columns == columns && variant.rows == rows)
+}
+
+/**
+ * @return the width of a row in modules, for the given number of columns
+ */
+pub fn getRowWidth(columns: u32) -> u32 {
+    let raps = if columns > 2 { 3 } else { 2 };
+    raps * MODULES_IN_RAP + columns * MODULES_IN_CODEWORD + MODULES_IN_STOP_PATTERN
+}
+
+/**
+ * @return the module offset of every codeword in a row, for the given number of columns
+ */
+pub fn getCodewordOffsets(columns: u32) -> Vec<u32> {
+    let mut offsets = Vec::with_capacity(columns as usize);
+    let mut offset = MODULES_IN_RAP;
+    for column in 0..columns {
+        // the center RAP follows the first column of 3 column symbols and the second of 4
+        if columns > 2 && column == columns - 2 {
+            offset += MODULES_IN_RAP;
+        }
+        offsets.push(offset);
+        offset += MODULES_IN_CODEWORD;
+    }
+    offsets
+}
+
+/**
+ * All sizes of a CC-A composite component, ordered by number of columns and then rows. Symbols
+ * with 3 columns have no left row address pattern, its row address numbers only determine the
+ * clusters.
+ */
+pub const CC_A_VARIANTS: [MicroPDF417Variant; 17] = [
+    MicroPDF417Variant::new(2, 5, 4, 39, 0, 19, 6),
+    MicroPDF417Variant::new(2, 6, 4, 1, 0, 33, 0),
+    MicroPDF417Variant::new(2, 7, 5, 32, 0, 12, 3),
+    MicroPDF417Variant::new(2, 8, 5, 8, 0, 40, 3),
+    MicroPDF417Variant::new(2, 9, 6, 14, 0, 46, 3),
+    MicroPDF417Variant::new(2, 10, 6, 43, 0, 23, 0),
+    MicroPDF417Variant::new(2, 12, 7, 20, 0, 52, 3),
+    MicroPDF417Variant::new(3, 4, 4, 11, 43, 23, 3),
+    MicroPDF417Variant::new(3, 5, 5, 1, 33, 13, 0),
+    MicroPDF417Variant::new(3, 6, 6, 5, 37, 17, 3),
+    MicroPDF417Variant::new(3, 7, 7, 15, 47, 27, 6),
+    MicroPDF417Variant::new(3, 8, 7, 21, 1, 33, 6),
+    MicroPDF417Variant::new(4, 3, 4, 40, 20, 52, 0),
+    MicroPDF417Variant::new(4, 4, 5, 43, 23, 3, 0),
+    MicroPDF417Variant::new(4, 5, 6, 46, 26, 6, 0),
+    MicroPDF417Variant::new(4, 6, 7, 34, 14, 46, 0),
+    MicroPDF417Variant::new(4, 7, 8, 29, 9, 41, 3),
+];
+
+/**
+ * @return the CC-A size with the given number of columns and rows, if there is one
+ */
+pub fn getCompositeAVariant(columns: u32, rows: u32) -> Option<&'static MicroPDF417Variant> {
+    CC_A_VARIANTS
+        .iter()
+        .find(|variant| variant.columns == columns && variant.rows == rows)
+}
+
+/**
+ * @return the width of a CC-A row in modules, for the given number of columns
+ */
+pub fn getCompositeARowWidth(columns: u32) -> u32 {
+    match columns {
+        3 => 2 * MODULES_IN_RAP + columns * MODULES_IN_CODEWORD + MODULES_IN_STOP_PATTERN,
+        _ => getRowWidth(columns),
+    }
+}
+
+/**
+ * @return the module offset of every codeword in a CC-A row, for the given number of columns
+ */
+pub fn getCompositeACodewordOffsets(columns: u32) -> Vec<u32> {
+    match columns {
+        // the center RAP takes the place of the left one, after the first column
+        3 => vec![
+            0,
+            MODULES_IN_CODEWORD + MODULES_IN_RAP,
+            2 * MODULES_IN_CODEWORD + MODULES_IN_RAP,
+        ],
+        _ => getCodewordOffsets(columns),
+    }
+}
+
+/**
+ * The left and right row address patterns, indexed by row address number - 1. Each pattern has
+ * three bars and three spaces, the most significant bit being the first module.
+ */
+pub const SIDE_RAP_PATTERNS: [u32; 52] = [
+    0x322, 0x3A2, 0x3B2, 0x332, 0x372, 0x37A, 0x33A, 0x3BA, 0x39A, 0x3DA, 0x3CA, 0x38A, 0x30A,
+    0x31A, 0x312, 0x392, 0x3D2, 0x3D6, 0x396, 0x394, 0x314, 0x316, 0x336, 0x326, 0x366, 0x36E,
+    0x36C, 0x34C, 0x34E, 0x35E, 0x35C, 0x358, 0x348, 0x368, 0x328, 0x3A8, 0x3AC, 0x32C, 0x32E,
+    0x3AE, 0x3A6, 0x3B6, 0x3B4, 0x3A4, 0x324, 0x334, 0x374, 0x364, 0x344, 0x346, 0x342, 0x362,
+];
+
+/**
+ * The center row address patterns, indexed by row address number - 1.
+ */
+pub const CENTER_RAP_PATTERNS: [u32; 52] = [
+    0x2CE, 0x24E, 0x26E, 0x22E, 0x226, 0x236, 0x216, 0x212, 0x21A, 0x23A, 0x232, 0x222, 0x262,
+    0x272, 0x27A, 0x2FA, 0x2F2, 0x2F6, 0x276, 0x274, 0x264, 0x266, 0x246, 0x242, 0x2C2, 0x2E2,
+    0x2E6, 0x2E4, 0x2EC, 0x26C, 0x22C, 0x228, 0x268, 0x2E8, 0x2C8, 0x2CC, 0x2C4, 0x2C6, 0x286,
+    0x28E, 0x28C, 0x29C, 0x298, 0x2B8, 0x2B0, 0x290, 0x2D0, 0x250, 0x258, 0x25C, 0x2DC, 0x2DE,
+];
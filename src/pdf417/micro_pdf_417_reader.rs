@@ -0,0 +1,156 @@
+use std::collections::HashMap;
+
+use crate::{
+    common::{BitMatrix, Result},
+    point_f, BarcodeFormat, Binarizer, BinaryBitmap, DecodeHintType, DecodeHintValue,
+    DecodingHintDictionary, Exceptions, Point, RXingResult, RXingResultMetadataType,
+    RXingResultMetadataValue, Reader,
+};
+
+use super::{
+    decoder::micro_pdf_417_decoder, detector::micro_pdf_417_detector, PDF417RXingResultMetadata,
+};
+
+/**
+ * This implementation can detect and decode MicroPDF417 codes in an image.
+ *
+ * Symbols are located by their row address patterns, other things may be in the image too.
+ * Upside down symbols are found as well, and sideways ones with
+ * {@link DecodeHintType#TRY_HARDER}.
+ */
+#[derive(Default)]
+pub struct MicroPDF417Reader;
+
+impl Reader for MicroPDF417Reader {
+    /**
+     * Locates and decodes a MicroPDF417 code in an image.
+     *
+     * @return a String representing the content encoded by the MicroPDF417 code
+     * @throws NotFoundException if a MicroPDF417 code cannot be found,
+     * @throws FormatException if a MicroPDF417 cannot be decoded
+     */
+    fn decode<B: Binarizer>(&mut self, image: &mut BinaryBitmap<B>) -> Result<RXingResult> {
+        self.decode_with_hints(image, &HashMap::new())
+    }
+
+    fn decode_with_hints<B: Binarizer>(
+        &mut self,
+        image: &mut BinaryBitmap<B>,
+        hints: &DecodingHintDictionary,
+    ) -> Result<RXingResult> {
+        let tryHarder = matches!(
+            hints.get(&DecodeHintType::TRY_HARDER),
+            Some(DecodeHintValue::TryHarder(true))
+        );
+        let mut bits = image.get_black_matrix().clone();
+        let (width, height) = (bits.getWidth() as f32, bits.getHeight() as f32);
+        let mut rotation = 0;
+        let mut result = Self::decodeMatrix(&bits, rotation);
+        if result.is_err() {
+            bits.rotate180();
+            rotation = 180;
+            result = Self::decodeMatrix(&bits, rotation);
+        }
+        if result.is_err() && tryHarder {
+            bits.rotate90();
+            rotation = 270;
+            result = Self::decodeMatrix(&bits, rotation);
+            if result.is_err() {
+                bits.rotate180();
+                rotation = 90;
+                result = Self::decodeMatrix(&bits, rotation);
+            }
+        }
+        let mut result = result?;
+
+        // the points were found in the rotated image, map them back onto the original one
+        for point in result.getPointsMut() {
+            *point = match rotation {
+                90 => point_f(width - point.y, point.x),
+                180 => point_f(width - point.x, height - point.y),
+                270 => point_f(point.y, height - point.x),
+                _ => *point,
+            };
+        }
+        Ok(result)
+    }
+}
+
+impl MicroPDF417Reader {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /**
+     * Decodes a MicroPDF417 symbol in an already binarized image, the symbol being rotated by
+     * the given degrees.
+     */
+    pub(crate) fn decodeMatrix(bits: &BitMatrix, rotation: u32) -> Result<RXingResult> {
+        let mut result = Err(Exceptions::NOT_FOUND);
+        for points in micro_pdf_417_detector::detect(bits) {
+            result = Self::decodeSymbol(bits, points, rotation);
+            if result.is_ok() {
+                break;
+            }
+        }
+        result
+    }
+
+    fn decodeSymbol(bits: &BitMatrix, points: [Point; 4], rotation: u32) -> Result<RXingResult> {
+        let [topLeft, _, _, bottomRight] = points;
+        let decoderRXingResult = micro_pdf_417_decoder::decode(
+            bits,
+            topLeft.x as u32,
+            topLeft.y as u32,
+            (bottomRight.x - topLeft.x) as u32,
+            (bottomRight.y - topLeft.y) as u32,
+        )?;
+
+        let mut result = RXingResult::new(
+            decoderRXingResult.getText(),
+            decoderRXingResult.getRawBytes().clone(),
+            points.to_vec(),
+            BarcodeFormat::MICRO_PDF_417,
+        );
+
+        result.putMetadata(
+            RXingResultMetadataType::ERROR_CORRECTION_LEVEL,
+            RXingResultMetadataValue::ErrorCorrectionLevel(
+                decoderRXingResult.getECLevel().to_owned(),
+            ),
+        );
+
+        if let Some(pdf417RXingResultMetadata) = decoderRXingResult.getOther() {
+            if pdf417RXingResultMetadata.is::<PDF417RXingResultMetadata>() {
+                let data = RXingResultMetadataValue::Pdf417ExtraMetadata(
+                    pdf417RXingResultMetadata
+                        .clone()
+                        .downcast::<PDF417RXingResultMetadata>()
+                        .map_err(|_| Exceptions::ILLEGAL_STATE)?,
+                );
+                result.putMetadata(RXingResultMetadataType::PDF417_EXTRA_METADATA, data);
+            }
+        }
+
+        let contentType = decoderRXingResult.getContentType();
+        if !contentType.is_empty() {
+            result.putMetadata(
+                RXingResultMetadataType::CONTENT_TYPE,
+                RXingResultMetadataValue::ContentType(contentType.to_owned()),
+            );
+        }
+
+        result.putMetadata(
+            RXingResultMetadataType::ORIENTATION,
+            RXingResultMetadataValue::Orientation(rotation as i32),
+        );
+        result.putMetadata(
+            RXingResultMetadataType::SYMBOLOGY_IDENTIFIER,
+            RXingResultMetadataValue::SymbologyIdentifier(format!(
+                "]L{}",
+                decoderRXingResult.getSymbologyModifier()
+            )),
+        );
+        Ok(result)
+    }
+}
@@ -0,0 +1,345 @@
+/**
+ * Tests {@link MicroPDF417Writer} and {@link MicroPDF417Reader}.
+ */
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    common::{bit_matrix_test_case, BitMatrix},
+    point, BarcodeFormat, DecodeHintType, DecodeHintValue, DecodingHintDictionary, EncodeHintType,
+    EncodeHintValue, EncodingHintDictionary, MultiFormatReader, MultiFormatWriter,
+    MultiUseMultiFormatReader, Point, RXingResult, RXingResultMetadataType,
+    RXingResultMetadataValue, Reader, Writer,
+};
+
+use super::{
+    encoder::{pdf_417_error_correction, Dimensions, MacroPDF417, MicroPDF417},
+    micro_pdf_417_common::{getVariant, MICRO_PDF417_VARIANTS, ROW_HEIGHT},
+    MicroPDF417Reader,
+};
+
+fn encode(contents: &str, hints: &EncodingHintDictionary) -> BitMatrix {
+    MultiFormatWriter
+        .encode_with_hints(contents, &BarcodeFormat::MICRO_PDF_417, 0, 0, hints)
+        .expect("must encode")
+}
+
+fn read(matrix: &BitMatrix, hints: &DecodingHintDictionary) -> crate::common::Result<RXingResult> {
+    let mut image = bit_matrix_test_case::matrix_to_binary_bitmap(matrix);
+    MicroPDF417Reader.decode_with_hints(&mut image, hints)
+}
+
+fn scale(matrix: &BitMatrix, factor: u32) -> BitMatrix {
+    let mut scaled = BitMatrix::new(matrix.getWidth() * factor, matrix.getHeight() * factor)
+        .expect("must create");
+    for y in 0..matrix.getHeight() {
+        for x in 0..matrix.getWidth() {
+            if matrix.get(x, y) {
+                scaled
+                    .setRegion(x * factor, y * factor, factor, factor)
+                    .unwrap();
+            }
+        }
+    }
+    scaled
+}
+
+fn dimensionHints(columns: u32, rows: u32) -> EncodingHintDictionary {
+    HashMap::from([
+        (
+            EncodeHintType::PDF417_DIMENSIONS,
+            EncodeHintValue::Pdf417Dimensions(Dimensions::new(
+                columns as usize,
+                columns as usize,
+                rows as usize,
+                rows as usize,
+            )),
+        ),
+        (
+            EncodeHintType::MARGIN,
+            EncodeHintValue::Margin("2".to_owned()),
+        ),
+    ])
+}
+
+#[test]
+fn testErrorCorrectionCodewords() {
+    let data = "\u{5}\u{1}\u{2}\u{384}\u{385}";
+    for level in 0..=8 {
+        assert_eq!(
+            pdf_417_error_correction::generateErrorCorrection(data, level).unwrap(),
+            pdf_417_error_correction::generateErrorCorrectionCodewords(data, 2 << level).unwrap()
+        );
+    }
+    assert!(pdf_417_error_correction::generateErrorCorrectionCodewords(data, 1).is_err());
+}
+
+#[test]
+fn testAllSizes() {
+    for variant in &MICRO_PDF417_VARIANTS {
+        let (columns, rows) = (variant.getColumns(), variant.getRows());
+        // find the longest content that still fits
+        let mut encoder = MicroPDF417::new();
+        encoder.setDimensions(columns, columns, rows, rows);
+        let mut length = 1;
+        while encoder
+            .generateBarcodeLogic(&"1234567890".repeat(40)[..length + 1])
+            .is_ok()
+        {
+            length += 1;
+        }
+        let contents = "1234567890".repeat(40)[..length].to_owned();
+
+        let matrix = encode(&contents, &dimensionHints(columns, rows));
+        assert_eq!(
+            (variant.getRowWidth() + 4, rows * ROW_HEIGHT + 4),
+            (matrix.getWidth(), matrix.getHeight()),
+            "{columns}x{rows}"
+        );
+
+        let result = read(&scale(&matrix, 2), &HashMap::new()).expect("must decode");
+        assert_eq!(contents, result.getText(), "{columns}x{rows}");
+        assert_eq!(&BarcodeFormat::MICRO_PDF_417, result.getBarcodeFormat());
+        assert_eq!(
+            Some(&RXingResultMetadataValue::ErrorCorrectionLevel(
+                variant.getErrorCorrectionCodewords().to_string()
+            )),
+            result
+                .getRXingResultMetadata()
+                .get(&RXingResultMetadataType::ERROR_CORRECTION_LEVEL)
+        );
+    }
+}
+
+#[test]
+fn testSmallestSize() {
+    let mut encoder = MicroPDF417::new();
+    for (contents, columns, rows) in [
+        ("1", 4, 4),
+        ("Hello", 4, 4),
+        ("Hello, World!", 4, 6),
+        ("MicroPDF417 is the small sibling of PDF417", 2, 20),
+        ("1234567890123456789012345678901234567890", 2, 14),
+    ] {
+        encoder.generateBarcodeLogic(contents).expect("must encode");
+        assert_eq!(
+            getVariant(columns, rows),
+            encoder.getVariant(),
+            "{contents}"
+        );
+    }
+
+    // the dimensions limit the choice
+    encoder.setDimensions(1, 1, 44, 4);
+    encoder.generateBarcodeLogic("Hello").expect("must encode");
+    assert_eq!(getVariant(1, 11), encoder.getVariant());
+
+    // 4 columns with 44 rows is the largest symbol
+    let mut encoder = MicroPDF417::new();
+    assert!(encoder.generateBarcodeLogic(&"1".repeat(366)).is_ok());
+    assert!(encoder.generateBarcodeLogic(&"1".repeat(400)).is_err());
+    assert!(MultiFormatWriter
+        .encode("1", &BarcodeFormat::PDF_417, 0, 0)
+        .is_ok());
+    assert!(super::MicroPDF417Writer
+        .encode("1", &BarcodeFormat::PDF_417, 0, 0)
+        .is_err());
+}
+
+#[test]
+fn testOrientation() {
+    let contents = "Upside down and sideways";
+    let mut matrix = scale(&encode(contents, &HashMap::new()), 3);
+
+    matrix.rotate180();
+    let result = read(&matrix, &HashMap::new()).expect("must decode");
+    assert_eq!(contents, result.getText());
+    assert_eq!(
+        Some(&RXingResultMetadataValue::Orientation(180)),
+        result
+            .getRXingResultMetadata()
+            .get(&RXingResultMetadataType::ORIENTATION)
+    );
+
+    matrix.rotate90();
+    assert!(read(&matrix, &HashMap::new()).is_err());
+    let tryHarder = HashMap::from([(DecodeHintType::TRY_HARDER, DecodeHintValue::TryHarder(true))]);
+    assert_eq!(contents, read(&matrix, &tryHarder).unwrap().getText());
+}
+
+#[test]
+fn testResultPoints() {
+    // the points are given in the image read, whichever way up the symbol is
+    let contents = "Upside down points";
+    let symbol = scale(&encode(contents, &HashMap::new()), 3);
+    let mut matrix =
+        BitMatrix::new(symbol.getWidth() + 100, symbol.getHeight() + 60).expect("must create");
+    for y in 0..symbol.getHeight() {
+        for x in 0..symbol.getWidth() {
+            if symbol.get(x, y) {
+                matrix.set(x + 25, y + 40);
+            }
+        }
+    }
+    let (width, height) = (matrix.getWidth() as f32, matrix.getHeight() as f32);
+    let upright = read(&matrix, &HashMap::new())
+        .expect("must decode")
+        .getPoints()
+        .to_vec();
+    assert_eq!(4, upright.len());
+    assert!(upright
+        .iter()
+        .all(|point| point.x >= 25.0 && point.y >= 40.0));
+
+    let assertPoints = |expected: Vec<Point>, matrix: &BitMatrix| {
+        let tryHarder =
+            HashMap::from([(DecodeHintType::TRY_HARDER, DecodeHintValue::TryHarder(true))]);
+        let result = read(matrix, &tryHarder).expect("must decode");
+        assert_eq!(contents, result.getText());
+        for (expected, actual) in expected.iter().zip(result.getPoints()) {
+            assert!(
+                (expected.x - actual.x).abs() <= 1.0,
+                "{expected:?} {actual:?}"
+            );
+            assert!(
+                (expected.y - actual.y).abs() <= 1.0,
+                "{expected:?} {actual:?}"
+            );
+        }
+    };
+    matrix.rotate180();
+    assertPoints(
+        upright
+            .iter()
+            .map(|p| point(width - p.x, height - p.y))
+            .collect(),
+        &matrix,
+    );
+    matrix.rotate180();
+    matrix.rotate90();
+    assertPoints(
+        upright.iter().map(|p| point(p.y, width - p.x)).collect(),
+        &matrix,
+    );
+}
+
+#[test]
+fn testErrorCorrection() {
+    let contents = "Damaged symbols can still be read";
+    let matrix = encode(contents, &dimensionHints(3, 12));
+    // blot out the first codeword of the second row
+    let mut damaged = matrix;
+    damaged
+        .setRegion(2 + 12, 2 + ROW_HEIGHT, 8, ROW_HEIGHT)
+        .unwrap();
+    let result = read(&scale(&damaged, 2), &HashMap::new()).expect("must decode");
+    assert_eq!(contents, result.getText());
+}
+
+#[test]
+fn testMultiFormat() {
+    let contents = "MicroPDF417 via MultiFormatReader";
+    let matrix = scale(&encode(contents, &HashMap::new()), 2);
+    let hints = HashMap::from([(
+        DecodeHintType::POSSIBLE_FORMATS,
+        DecodeHintValue::PossibleFormats(HashSet::from([BarcodeFormat::MICRO_PDF_417])),
+    )]);
+
+    let mut image = bit_matrix_test_case::matrix_to_binary_bitmap(&matrix);
+    let result = MultiFormatReader::default()
+        .decode_with_hints(&mut image, &hints)
+        .expect("must decode");
+    assert_eq!(contents, result.getText());
+    assert_eq!(&BarcodeFormat::MICRO_PDF_417, result.getBarcodeFormat());
+    let result = MultiUseMultiFormatReader::default()
+        .decode_with_hints(&mut image, &hints)
+        .expect("must decode");
+    assert_eq!(contents, result.getText());
+
+    // MicroPDF417 is only looked for when POSSIBLE_FORMATS names it
+    assert!(MultiFormatReader::default().decode(&mut image).is_err());
+    assert!(MultiUseMultiFormatReader::default()
+        .decode(&mut image)
+        .is_err());
+}
+
+#[test]
+fn testMacroAndCharacterSet() {
+    let mut macroPDF417 = MacroPDF417::new(2, "123456");
+    macroPDF417.setSegmentCount(Some(3));
+    macroPDF417.setLastSegment(true);
+    let hints = HashMap::from([
+        (
+            EncodeHintType::PDF417_MACRO,
+            EncodeHintValue::Pdf417Macro(macroPDF417),
+        ),
+        (
+            EncodeHintType::CHARACTER_SET,
+            EncodeHintValue::CharacterSet("UTF-8".to_owned()),
+        ),
+    ]);
+    let contents = "Grüße, €";
+    let result = read(&scale(&encode(contents, &hints), 2), &HashMap::new()).expect("decode");
+    assert_eq!(contents, result.getText());
+    let Some(RXingResultMetadataValue::Pdf417ExtraMetadata(metadata)) = result
+        .getRXingResultMetadata()
+        .get(&RXingResultMetadataType::PDF417_EXTRA_METADATA)
+    else {
+        panic!("Macro PDF417 metadata expected");
+    };
+    assert_eq!(2, metadata.getSegmentIndex());
+    assert_eq!("123456", metadata.getFileId());
+    assert_eq!(3, metadata.getSegmentCount());
+    assert!(metadata.isLastSegment());
+}
+
+#[test]
+fn testNotAlone() {
+    // the symbol shares the image with a Code 128 barcode and a blot
+    let contents = "Not alone";
+    let symbol = scale(&encode(contents, &dimensionHints(3, 10)), 3);
+    let code128 = MultiFormatWriter
+        .encode("Code 128", &BarcodeFormat::CODE_128, 0, 0)
+        .expect("must encode");
+    let mut image =
+        BitMatrix::new(symbol.getWidth() + 400, 2 * symbol.getHeight() + 60).expect("must create");
+    for y in 0..symbol.getHeight() {
+        for x in 0..symbol.getWidth() {
+            if symbol.get(x, y) {
+                image.set(x + 25, y + 40);
+            }
+        }
+    }
+    for y in 0..2 * symbol.getHeight() {
+        for x in 0..code128.getWidth() {
+            if code128.get(x, 0) {
+                image
+                    .setRegion(symbol.getWidth() + 60 + 2 * x, y + 10, 2, 1)
+                    .unwrap();
+            }
+        }
+    }
+    image.setRegion(5, 5, 60, 20).unwrap();
+
+    let hints = HashMap::from([(
+        DecodeHintType::POSSIBLE_FORMATS,
+        DecodeHintValue::PossibleFormats(HashSet::from([BarcodeFormat::MICRO_PDF_417])),
+    )]);
+    for orientation in [0, 180] {
+        let result = read(&image, &HashMap::new()).expect("must decode");
+        assert_eq!(contents, result.getText());
+        assert_eq!(
+            Some(&RXingResultMetadataValue::Orientation(orientation)),
+            result
+                .getRXingResultMetadata()
+                .get(&RXingResultMetadataType::ORIENTATION)
+        );
+        let result = MultiFormatReader::default()
+            .decode_with_hints(
+                &mut bit_matrix_test_case::matrix_to_binary_bitmap(&image),
+                &hints,
+            )
+            .expect("must decode");
+        assert_eq!(contents, result.getText());
+        image.rotate180();
+    }
+}
@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+
+use crate::{
+    common::{BitMatrix, CharacterSet, Result},
+    BarcodeFormat, EncodeHintType, EncodeHintValue, Exceptions, Writer,
+};
+
+use super::{encoder::MicroPDF417, micro_pdf_417_common::ROW_HEIGHT, PDF417Writer};
+
+/**
+ * default white space (margin) around the code
+ */
+const WHITE_SPACE: u32 = 2;
+
+/**
+ * Renders MicroPDF417 symbols. The symbol size is the smallest that holds the contents within
+ * the limits of {@link EncodeHintType#PDF417_DIMENSIONS}; the number of error correction
+ * codewords is fixed by the size, so {@link EncodeHintType#ERROR_CORRECTION} is not used.
+ */
+#[derive(Default)]
+pub struct MicroPDF417Writer;
+
+impl Writer for MicroPDF417Writer {
+    fn encode(
+        &self,
+        contents: &str,
+        format: &crate::BarcodeFormat,
+        width: i32,
+        height: i32,
+    ) -> Result<crate::common::BitMatrix> {
+        self.encode_with_hints(contents, format, width, height, &HashMap::new())
+    }
+
+    fn encode_with_hints(
+        &self,
+        contents: &str,
+        format: &crate::BarcodeFormat,
+        width: i32,
+        height: i32,
+        hints: &crate::EncodingHintDictionary,
+    ) -> Result<crate::common::BitMatrix> {
+        if format != &BarcodeFormat::MICRO_PDF_417 {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "Can only encode MICRO_PDF_417, but got {format}"
+            )));
+        }
+
+        let mut encoder = MicroPDF417::new();
+        let mut margin = WHITE_SPACE;
+        let mut autoECI = false;
+
+        if let Some(EncodeHintValue::Pdf417Compaction(compaction)) =
+            hints.get(&EncodeHintType::PDF417_COMPACTION)
+        {
+            encoder.setCompaction(compaction.try_into()?);
+        }
+        if let Some(EncodeHintValue::Pdf417Dimensions(dimensions)) =
+            hints.get(&EncodeHintType::PDF417_DIMENSIONS)
+        {
+            encoder.setDimensions(
+                dimensions.getMaxCols() as u32,
+                dimensions.getMinCols() as u32,
+                dimensions.getMaxRows() as u32,
+                dimensions.getMinRows() as u32,
+            );
+        }
+        if let Some(EncodeHintValue::Margin(m1)) = hints.get(&EncodeHintType::MARGIN) {
+            if let Ok(m) = m1.parse::<u32>() {
+                margin = m;
+            }
+        }
+        if let Some(EncodeHintValue::CharacterSet(cs)) = hints.get(&EncodeHintType::CHARACTER_SET) {
+            encoder.setEncoding(CharacterSet::get_character_set_by_name(cs));
+        }
+        if let Some(EncodeHintValue::Pdf417AutoEci(auto_eci_str)) =
+            hints.get(&EncodeHintType::PDF417_AUTO_ECI)
+        {
+            if let Ok(auto_eci_parsed) = auto_eci_str.parse::<bool>() {
+                autoECI = auto_eci_parsed;
+            }
+        }
+        if let Some(EncodeHintValue::Pdf417Macro(macroPDF417)) =
+            hints.get(&EncodeHintType::PDF417_MACRO)
+        {
+            encoder.setMacroPDF417(Some(macroPDF417.clone()));
+        }
+
+        Self::bitMatrixFromEncoder(
+            &mut encoder,
+            contents,
+            width as u32,
+            height as u32,
+            margin,
+            autoECI,
+        )
+    }
+}
+
+impl MicroPDF417Writer {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /**
+     * Takes encoder, accounts for width/height, and retrieves bit matrix
+     */
+    fn bitMatrixFromEncoder(
+        encoder: &mut MicroPDF417,
+        contents: &str,
+        width: u32,
+        height: u32,
+        margin: u32,
+        autoECI: bool,
+    ) -> Result<BitMatrix> {
+        encoder.generateBarcodeLogicWithAutoECI(contents, autoECI)?;
+
+        let aspectRatio = ROW_HEIGHT as usize;
+        let barcodeMatrix = encoder
+            .getBarcodeMatrix()
+            .as_ref()
+            .ok_or(Exceptions::ILLEGAL_STATE)?;
+        let originalScale = barcodeMatrix.getScaledMatrix(1, aspectRatio);
+
+        let scaleX = width as usize / originalScale[0].len();
+        let scaleY = height as usize / originalScale.len();
+        let scale = scaleX.min(scaleY);
+
+        if scale > 1 {
+            let scaledMatrix = barcodeMatrix.getScaledMatrix(scale, scale * aspectRatio);
+            return PDF417Writer::bitMatrixFromBitArray(&scaledMatrix, margin)
+                .ok_or(Exceptions::ILLEGAL_STATE);
+        }
+
+        PDF417Writer::bitMatrixFromBitArray(&originalScale, margin).ok_or(Exceptions::ILLEGAL_STATE)
+    }
+}
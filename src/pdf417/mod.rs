@@ -2,6 +2,7 @@ pub mod decoder;
 pub mod detector;
 pub mod encoder;
 
+pub mod micro_pdf_417_common;
 pub mod pdf_417_common;
 
 mod pdf_417_result_metadata;
@@ -12,3 +13,12 @@ pub use pdf_417_reader::*;
 
 mod pdf_417_writer;
 pub use pdf_417_writer::*;
+
+mod micro_pdf_417_reader;
+pub use micro_pdf_417_reader::*;
+
+mod micro_pdf_417_writer;
+pub use micro_pdf_417_writer::*;
+
+#[cfg(test)]
+mod micro_pdf_417_test_case;
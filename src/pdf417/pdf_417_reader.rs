@@ -17,9 +17,10 @@
 use std::collections::HashMap;
 
 use crate::{
-    common::Result, multi::MultipleBarcodeReader, BarcodeFormat, Binarizer, BinaryBitmap,
-    DecodingHintDictionary, Exceptions, Point, RXingResult, RXingResultMetadataType,
-    RXingResultMetadataValue, Reader,
+    common::{BitMatrix, DecoderRXingResult, Result},
+    multi::MultipleBarcodeReader,
+    BarcodeFormat, Binarizer, BinaryBitmap, DecodingHintDictionary, Exceptions, Point, RXingResult,
+    RXingResultMetadataType, RXingResultMetadataValue, Reader,
 };
 
 use super::{
@@ -104,51 +105,94 @@ impl PDF417Reader {
                 Self::getMaxCodewordWidth(points),
             )?;
 
-            let mut result = RXingResult::new(
-                decoderRXingResult.getText(),
-                decoderRXingResult.getRawBytes().clone(),
+            results.push(Self::buildRXingResult(
+                &decoderRXingResult,
                 points_filtered,
-                BarcodeFormat::PDF_417,
-            );
+                detectorRXingResult.getRotation(),
+            )?);
+        }
+        Ok(results)
+    }
 
-            result.putMetadata(
-                RXingResultMetadataType::ERROR_CORRECTION_LEVEL,
-                RXingResultMetadataValue::ErrorCorrectionLevel(
-                    decoderRXingResult.getECLevel().to_owned(),
-                ),
-            );
+    /**
+     * Decodes a PDF417 symbol in an already binarized image, which must be upright.
+     */
+    pub(crate) fn decodeBitMatrix(bits: &BitMatrix) -> Result<RXingResult> {
+        let points = pdf_417_detector::detect(false, bits)
+            .and_then(|points| points.into_iter().next())
+            .ok_or(Exceptions::NOT_FOUND)?;
+        let decoderRXingResult = pdf_417_scanning_decoder::decode(
+            bits,
+            points[4],
+            points[5],
+            points[6],
+            points[7],
+            Self::getMinCodewordWidth(&points),
+            Self::getMaxCodewordWidth(&points),
+        )?;
+        Self::buildRXingResult(
+            &decoderRXingResult,
+            points.iter().flatten().copied().collect(),
+            0,
+        )
+    }
 
-            if let Some(pdf417RXingResultMetadata) = decoderRXingResult.getOther() {
-                if pdf417RXingResultMetadata.is::<PDF417RXingResultMetadata>() {
-                    let data = RXingResultMetadataValue::Pdf417ExtraMetadata(
-                        pdf417RXingResultMetadata
-                            .clone()
-                            .downcast::<PDF417RXingResultMetadata>()
-                            .map_err(|_| Exceptions::ILLEGAL_STATE)?,
-                    );
-                    result.putMetadata(RXingResultMetadataType::PDF417_EXTRA_METADATA, data);
-                }
+    fn buildRXingResult(
+        decoderRXingResult: &DecoderRXingResult,
+        points: Vec<Point>,
+        rotation: u32,
+    ) -> Result<RXingResult> {
+        let mut result = RXingResult::new(
+            decoderRXingResult.getText(),
+            decoderRXingResult.getRawBytes().clone(),
+            points,
+            BarcodeFormat::PDF_417,
+        );
+
+        result.putMetadata(
+            RXingResultMetadataType::ERROR_CORRECTION_LEVEL,
+            RXingResultMetadataValue::ErrorCorrectionLevel(
+                decoderRXingResult.getECLevel().to_owned(),
+            ),
+        );
+
+        if let Some(pdf417RXingResultMetadata) = decoderRXingResult.getOther() {
+            if pdf417RXingResultMetadata.is::<PDF417RXingResultMetadata>() {
+                let data = RXingResultMetadataValue::Pdf417ExtraMetadata(
+                    pdf417RXingResultMetadata
+                        .clone()
+                        .downcast::<PDF417RXingResultMetadata>()
+                        .map_err(|_| Exceptions::ILLEGAL_STATE)?,
+                );
+                result.putMetadata(RXingResultMetadataType::PDF417_EXTRA_METADATA, data);
             }
-            // PDF417RXingResultMetadata pdf417RXingResultMetadata = (PDF417RXingResultMetadata) decoderRXingResult.getOther();
+        }
+        // PDF417RXingResultMetadata pdf417RXingResultMetadata = (PDF417RXingResultMetadata) decoderRXingResult.getOther();
 
-            // if (pdf417RXingResultMetadata != null) {
-            //   result.putMetadata(RXingResultMetadataType.PDF417_EXTRA_METADATA, pdf417RXingResultMetadata);
-            // }
+        // if (pdf417RXingResultMetadata != null) {
+        //   result.putMetadata(RXingResultMetadataType.PDF417_EXTRA_METADATA, pdf417RXingResultMetadata);
+        // }
 
+        let contentType = decoderRXingResult.getContentType();
+        if !contentType.is_empty() {
             result.putMetadata(
-                RXingResultMetadataType::ORIENTATION,
-                RXingResultMetadataValue::Orientation(detectorRXingResult.getRotation() as i32),
+                RXingResultMetadataType::CONTENT_TYPE,
+                RXingResultMetadataValue::ContentType(contentType.to_owned()),
             );
-            result.putMetadata(
-                RXingResultMetadataType::SYMBOLOGY_IDENTIFIER,
-                RXingResultMetadataValue::SymbologyIdentifier(format!(
-                    "]L{}",
-                    decoderRXingResult.getSymbologyModifier()
-                )),
-            );
-            results.push(result);
         }
-        Ok(results)
+
+        result.putMetadata(
+            RXingResultMetadataType::ORIENTATION,
+            RXingResultMetadataValue::Orientation(rotation as i32),
+        );
+        result.putMetadata(
+            RXingResultMetadataType::SYMBOLOGY_IDENTIFIER,
+            RXingResultMetadataValue::SymbologyIdentifier(format!(
+                "]L{}",
+                decoderRXingResult.getSymbologyModifier()
+            )),
+        );
+        Ok(result)
     }
 
     fn getMaxWidth(p1: &Option<Point>, p2: &Option<Point>) -> u64 {
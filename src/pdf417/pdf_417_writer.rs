@@ -17,11 +17,13 @@
 use std::collections::HashMap;
 
 use crate::{
-    common::{BitMatrix, CharacterSet, Result},
+    common::{splitIntoSegments, BitMatrix, CharacterSet, Result},
     BarcodeFormat, EncodeHintType, EncodeHintValue, Exceptions, Writer,
 };
 
-use super::encoder::PDF417;
+use super::encoder::{
+    pdf_417_high_level_encoder::MAX_MACRO_PDF417_SEGMENT_INDEX, MacroPDF417, PDF417,
+};
 
 /**
  * default white space (margin) around the code
@@ -65,6 +67,32 @@ impl Writer for PDF417Writer {
             )));
         }
 
+        let (mut encoder, errorCorrectionLevel, margin, autoECI) = Self::encoderFromHints(hints)?;
+        if let Some(EncodeHintValue::Pdf417Macro(macroPDF417)) =
+            hints.get(&EncodeHintType::PDF417_MACRO)
+        {
+            encoder.setMacroPDF417(Some(macroPDF417.clone()));
+        }
+
+        Self::bitMatrixFromEncoder(
+            &mut encoder,
+            contents,
+            errorCorrectionLevel,
+            width as u32,
+            height as u32,
+            margin,
+            autoECI,
+        )
+    }
+}
+
+impl PDF417Writer {
+    /**
+     * Creates an encoder configured by the given hints
+     *
+     * @return the encoder, error correction level, margin and whether to use automatic ECIs
+     */
+    fn encoderFromHints(hints: &crate::EncodingHintDictionary) -> Result<(PDF417, u32, u32, bool)> {
         let mut encoder = PDF417::new();
         let mut margin = WHITE_SPACE;
         let mut errorCorrectionLevel = DEFAULT_ERROR_CORRECTION_LEVEL;
@@ -119,19 +147,74 @@ impl Writer for PDF417Writer {
             }
         }
 
-        Self::bitMatrixFromEncoder(
-            &mut encoder,
+        Ok((encoder, errorCorrectionLevel, margin, autoECI))
+    }
+
+    /**
+     * Splits the contents across as few Macro PDF417 symbols as the dimension hints allow.
+     *
+     * The control block fields are taken from the {@link EncodeHintType#PDF417_MACRO} hint, if
+     * present (a file id of "000" is used otherwise). The segment index, segment count and last
+     * segment marker are set for each symbol.
+     *
+     * @return one bit matrix per segment, in segment order
+     */
+    pub fn encode_macro(
+        &self,
+        contents: &str,
+        width: i32,
+        height: i32,
+        hints: &crate::EncodingHintDictionary,
+    ) -> Result<Vec<BitMatrix>> {
+        let (mut encoder, errorCorrectionLevel, margin, autoECI) = Self::encoderFromHints(hints)?;
+        let template = if let Some(EncodeHintValue::Pdf417Macro(macroPDF417)) =
+            hints.get(&EncodeHintType::PDF417_MACRO)
+        {
+            macroPDF417.clone()
+        } else {
+            MacroPDF417::new(0, "000")
+        };
+
+        // Plan the segments with the largest possible control block, so that none of them
+        // grows once the actual segment count is known. The segment index always takes five
+        // digits.
+        let mut macroPDF417 = template.clone();
+        macroPDF417.setSegmentCount(Some(MAX_MACRO_PDF417_SEGMENT_INDEX + 1));
+        macroPDF417.setLastSegment(true);
+        encoder.setMacroPDF417(Some(macroPDF417));
+        let segments = splitIntoSegments(
             contents,
-            errorCorrectionLevel,
-            width as u32,
-            height as u32,
-            margin,
-            autoECI,
-        )
+            MAX_MACRO_PDF417_SEGMENT_INDEX as usize + 1,
+            |segment| {
+                encoder
+                    .generateBarcodeLogicWithAutoECI(segment, errorCorrectionLevel, autoECI)
+                    .is_ok()
+            },
+        )?;
+
+        let segmentCount = segments.len() as u32;
+        segments
+            .iter()
+            .enumerate()
+            .map(|(index, segment)| {
+                let mut macroPDF417 = template.clone();
+                macroPDF417.setSegmentIndex(index as u32);
+                macroPDF417.setSegmentCount(Some(segmentCount));
+                macroPDF417.setLastSegment(index as u32 + 1 == segmentCount);
+                encoder.setMacroPDF417(Some(macroPDF417));
+                Self::bitMatrixFromEncoder(
+                    &mut encoder,
+                    segment,
+                    errorCorrectionLevel,
+                    width as u32,
+                    height as u32,
+                    margin,
+                    autoECI,
+                )
+            })
+            .collect()
     }
-}
 
-impl PDF417Writer {
     /**
      * Takes encoder, accounts for width/height, and retrieves bit matrix
      */
@@ -185,7 +268,7 @@ impl PDF417Writer {
      * @param margin border around the barcode
      * @return BitMatrix of the input
      */
-    fn bitMatrixFromBitArray(input: &Vec<Vec<u8>>, margin: u32) -> Option<BitMatrix> {
+    pub(super) fn bitMatrixFromBitArray(input: &[Vec<u8>], margin: u32) -> Option<BitMatrix> {
         // Creates the bit matrix with extra space for whitespace
         let mut output = BitMatrix::new(
             input[0].len() as u32 + 2 * margin,
@@ -237,7 +320,15 @@ impl PDF417Writer {
 mod PDF417WriterTestCase {
     use std::collections::HashMap;
 
-    use crate::{pdf417::PDF417Writer, BarcodeFormat, EncodeHintType, EncodeHintValue, Writer};
+    use crate::{
+        common::{bit_matrix_test_case, BitMatrix},
+        pdf417::{
+            encoder::{Dimensions, MacroPDF417},
+            PDF417Reader, PDF417Writer,
+        },
+        BarcodeFormat, EncodeHintType, EncodeHintValue, RXingResult, RXingResultMetadataType,
+        RXingResultMetadataValue, Reader, Writer,
+    };
 
     #[test]
     fn testDataMatrixImageWriter() {
@@ -279,4 +370,117 @@ X X X X X X X X   X   X   X       X X X X X   X   X X X X   X X     X
 ";
         assert_eq!(expected, matrix.to_string());
     }
+
+    fn decode(matrix: &BitMatrix) -> RXingResult {
+        let mut image = bit_matrix_test_case::matrix_to_binary_bitmap(matrix);
+        PDF417Reader.decode(&mut image).expect("decode")
+    }
+
+    fn macroMetadata(
+        result: &RXingResult,
+    ) -> std::rc::Rc<crate::pdf417::PDF417RXingResultMetadata> {
+        match result
+            .getRXingResultMetadata()
+            .get(&RXingResultMetadataType::PDF417_EXTRA_METADATA)
+        {
+            Some(RXingResultMetadataValue::Pdf417ExtraMetadata(metadata)) => metadata.clone(),
+            _ => panic!("no PDF417 metadata"),
+        }
+    }
+
+    #[test]
+    fn testMacroPDF417Segments() {
+        let contents =
+            "Macro PDF417 splits one file across several symbols. 0123456789012345678 ".repeat(8);
+        let mut template = MacroPDF417::new(0, "017053");
+        template.setFileName(Some("report.txt".to_owned()));
+        template.setSender(Some("ZXing".to_owned()));
+        template.setTimestamp(Some(1_700_000_000));
+        template.setFileSize(Some(contents.len() as u64));
+
+        let mut hints = HashMap::new();
+        hints.insert(
+            EncodeHintType::PDF417_MACRO,
+            EncodeHintValue::Pdf417Macro(template),
+        );
+        hints.insert(
+            EncodeHintType::PDF417_DIMENSIONS,
+            EncodeHintValue::Pdf417Dimensions(Dimensions::new(2, 4, 3, 20)),
+        );
+        hints.insert(
+            EncodeHintType::MARGIN,
+            EncodeHintValue::Margin(10.to_string()),
+        );
+        let matrices = PDF417Writer
+            .encode_macro(&contents, 0, 0, &hints)
+            .expect("encode");
+        assert!(matrices.len() > 2);
+
+        // decode in reverse order and reassemble by segment index
+        let mut segments = matrices
+            .iter()
+            .rev()
+            .map(|matrix| {
+                let result = decode(matrix);
+                let metadata = macroMetadata(&result);
+                assert_eq!("017053", metadata.getFileId());
+                assert_eq!("report.txt", metadata.getFileName());
+                assert_eq!("ZXing", metadata.getSender());
+                assert_eq!(1_700_000_000, metadata.getTimestamp());
+                assert_eq!(contents.len() as i64, metadata.getFileSize());
+                assert_eq!(matrices.len() as isize, metadata.getSegmentCount());
+                assert_eq!(
+                    metadata.getSegmentIndex() + 1 == matrices.len(),
+                    metadata.isLastSegment()
+                );
+                (metadata.getSegmentIndex(), result.getText().to_owned())
+            })
+            .collect::<Vec<_>>();
+        segments.sort();
+        assert_eq!(
+            (0..matrices.len()).collect::<Vec<_>>(),
+            segments.iter().map(|(index, _)| *index).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            contents,
+            segments
+                .into_iter()
+                .map(|(_, text)| text)
+                .collect::<String>()
+        );
+    }
+
+    #[test]
+    fn testMacroPDF417Hint() {
+        let mut macroPDF417 = MacroPDF417::new(4, "123456789");
+        macroPDF417.setLastSegment(true);
+        macroPDF417.setChecksum(Some(0xBEEF));
+        let mut hints = HashMap::new();
+        hints.insert(
+            EncodeHintType::PDF417_MACRO,
+            EncodeHintValue::Pdf417Macro(macroPDF417),
+        );
+        let matrix = PDF417Writer
+            .encode_with_hints("Hello Google", &BarcodeFormat::PDF_417, 0, 0, &hints)
+            .expect("encode");
+        let result = decode(&matrix);
+        assert_eq!("Hello Google", result.getText());
+        let metadata = macroMetadata(&result);
+        assert_eq!(4, metadata.getSegmentIndex());
+        assert_eq!("123456789", metadata.getFileId());
+        assert!(metadata.isLastSegment());
+        assert_eq!(0xBEEF, metadata.getChecksum());
+        assert_eq!(-1, metadata.getSegmentCount());
+
+        // file id groups must be codeword values below 900
+        for fileId in ["", "12", "900", "12a"] {
+            hints.insert(
+                EncodeHintType::PDF417_MACRO,
+                EncodeHintValue::Pdf417Macro(MacroPDF417::new(0, fileId)),
+            );
+            assert!(PDF417Writer
+                .encode_with_hints("Hello Google", &BarcodeFormat::PDF_417, 0, 0, &hints)
+                .is_err());
+        }
+    }
 }
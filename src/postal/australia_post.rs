@@ -0,0 +1,202 @@
+use crate::{
+    common::{
+        reedsolomon::{
+            get_predefined_genericgf, PredefinedGenericGF, ReedSolomonDecoder, ReedSolomonEncoder,
+        },
+        Result,
+    },
+    Exceptions,
+};
+
+use super::FourStateBar;
+
+/// The format control codes and the number of bars of their symbols
+const FORMATS: [(&str, usize); 6] = [
+    ("11", 37), // Standard Customer Barcode
+    ("45", 37), // Reply Paid Barcode
+    ("59", 52), // Customer Barcode 2
+    ("62", 67), // Customer Barcode 3
+    ("87", 37), // Routing Barcode
+    ("92", 37), // Redirection Barcode
+];
+
+/// Bars of the start and of the stop pattern
+const START_STOP: [FourStateBar; 2] = [FourStateBar::Ascender, FourStateBar::Tracker];
+/// Bars of the format control code and of the Delivery Point Identifier
+const HEADER_BARS: usize = 20;
+/// Reed-Solomon symbols, of three bars each
+const EC_SYMBOLS: usize = 4;
+
+/// The characters of the C table, in the order of their bars in {@link #C_TABLE}
+const C_TABLE_ALPHABET: &str = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz #";
+const C_TABLE: [&str; 64] = [
+    "222", "300", "301", "302", "310", "311", "312", "320", "321", "322", "000", "001", "002",
+    "010", "011", "012", "020", "021", "022", "100", "101", "102", "110", "111", "112", "120",
+    "121", "122", "200", "201", "202", "210", "211", "212", "220", "221", "023", "030", "031",
+    "032", "033", "103", "113", "123", "130", "131", "132", "133", "203", "213", "223", "230",
+    "231", "232", "233", "303", "313", "323", "330", "331", "332", "333", "003", "013",
+];
+
+fn barValues(values: &str) -> impl Iterator<Item = FourStateBar> + '_ {
+    values
+        .bytes()
+        .map(|value| FourStateBar::fromValue(value - b'0'))
+}
+
+/**
+ * Appends digits in the N table, two bars per digit counting in base 3.
+ */
+fn encodeNumeric(digits: &str, bars: &mut Vec<FourStateBar>) {
+    for digit in digits.bytes().map(|digit| digit - b'0') {
+        bars.push(FourStateBar::fromValue(digit / 3));
+        bars.push(FourStateBar::fromValue(digit % 3));
+    }
+}
+
+fn decodeNumeric(bars: &[FourStateBar]) -> Option<String> {
+    if !bars.len().is_multiple_of(2) {
+        return None;
+    }
+    bars.chunks(2)
+        .map(|pair| {
+            let (high, low) = (pair[0].value(), pair[1].value());
+            let digit = high * 3 + low;
+            (low < 3 && digit < 10).then(|| (b'0' + digit) as char)
+        })
+        .collect()
+}
+
+/**
+ * <p>Encodes an Australia Post 4-state barcode: the format control code (FCC), the 8 digit
+ * Delivery Point Identifier (DPID) and, for FCCs 59 and 62, customer information.</p>
+ *
+ * <p>Customer information made of digits is encoded with the N table, other information with the
+ * C table. Unused bars are filled with trackers, and the data is protected by four Reed-Solomon
+ * symbols over GF(64).</p>
+ *
+ * @param contents the FCC, the DPID and the customer information, such as "1139987520"
+ */
+pub fn encode(contents: &str) -> Result<Vec<FourStateBar>> {
+    let header = contents.get(..10).unwrap_or(contents);
+    if header.len() < 10 || !header.bytes().all(|c| c.is_ascii_digit()) {
+        return Err(Exceptions::illegal_argument_with(format!(
+            "Australia Post barcodes start with a 2 digit FCC and an 8 digit DPID, but got {contents}"
+        )));
+    }
+    let (fcc, information) = (&contents[..2], &contents[10..]);
+    let (_, barCount) = FORMATS
+        .iter()
+        .find(|(code, _)| *code == fcc)
+        .ok_or_else(|| {
+            Exceptions::illegal_argument_with(format!("unknown format control code {fcc}"))
+        })?;
+    let dataBars = barCount - 2 * START_STOP.len() - 3 * EC_SYMBOLS;
+
+    let mut data = Vec::with_capacity(dataBars);
+    encodeNumeric(header, &mut data);
+    if information.bytes().all(|c| c.is_ascii_digit()) {
+        encodeNumeric(information, &mut data);
+    } else {
+        for c in information.chars() {
+            let index = C_TABLE_ALPHABET.find(c).ok_or_else(|| {
+                Exceptions::illegal_argument_with(format!(
+                    "Requested contents contain illegal character {c}"
+                ))
+            })?;
+            data.extend(barValues(C_TABLE[index]));
+        }
+    }
+    if data.len() > dataBars {
+        return Err(Exceptions::illegal_argument_with(format!(
+            "customer information {information} does not fit into FCC {fcc}"
+        )));
+    }
+    data.resize(dataBars, FourStateBar::Tracker);
+
+    let mut symbols = data
+        .chunks(3)
+        .map(|triple| {
+            triple
+                .iter()
+                .fold(0, |value, bar| value * 4 + bar.value() as i32)
+        })
+        .collect::<Vec<_>>();
+    symbols.resize(symbols.len() + EC_SYMBOLS, 0);
+    ReedSolomonEncoder::new(get_predefined_genericgf(PredefinedGenericGF::AztecData6))?
+        .encode(&mut symbols, EC_SYMBOLS)?;
+
+    let mut bars = START_STOP.to_vec();
+    bars.extend(symbolsToBars(&symbols));
+    bars.extend(START_STOP);
+    Ok(bars)
+}
+
+fn symbolsToBars(symbols: &[i32]) -> Vec<FourStateBar> {
+    symbols
+        .iter()
+        .flat_map(|symbol| [4, 2, 0].map(|shift| FourStateBar::fromValue((symbol >> shift) as u8)))
+        .collect()
+}
+
+/**
+ * Customer information is read with the N table if its bars are digits of the N table up to the
+ * trailing filler bars, and with the C table otherwise.
+ *
+ * @return the FCC, the DPID and the customer information
+ */
+pub fn decode(bars: &[FourStateBar]) -> Result<String> {
+    let barCount = bars.len();
+    if !FORMATS.iter().any(|(_, count)| *count == barCount)
+        || bars[..2] != START_STOP
+        || bars[barCount - 2..] != START_STOP
+    {
+        return Err(Exceptions::NOT_FOUND);
+    }
+
+    let mut symbols = bars[2..barCount - 2]
+        .chunks(3)
+        .map(|triple| {
+            triple
+                .iter()
+                .fold(0, |value, bar| value * 4 + bar.value() as i32)
+        })
+        .collect::<Vec<_>>();
+    ReedSolomonDecoder::new(get_predefined_genericgf(PredefinedGenericGF::AztecData6))
+        .decode(&mut symbols, EC_SYMBOLS as i32)
+        .map_err(|_| Exceptions::CHECKSUM)?;
+    let data = symbolsToBars(&symbols[..symbols.len() - EC_SYMBOLS]);
+
+    let header = decodeNumeric(&data[..HEADER_BARS]).ok_or(Exceptions::FORMAT)?;
+    if !FORMATS.contains(&(&header[..2], barCount)) {
+        return Err(Exceptions::FORMAT);
+    }
+    if !matches!(&header[..2], "59" | "62") {
+        return Ok(header);
+    }
+
+    let information = &data[HEADER_BARS..];
+    let used = information
+        .iter()
+        .rposition(|bar| *bar != FourStateBar::Tracker)
+        .map_or(0, |last| last + 1);
+    if let Some(digits) = decodeNumeric(&information[..used]) {
+        return Ok(header + &digits);
+    }
+    let characters = information
+        .get(..used.div_ceil(3) * 3)
+        .ok_or(Exceptions::FORMAT)?
+        .chunks_exact(3)
+        .map(|triple| {
+            let values = triple
+                .iter()
+                .map(|bar| (b'0' + bar.value()) as char)
+                .collect::<String>();
+            C_TABLE
+                .iter()
+                .position(|entry| *entry == values)
+                .and_then(|index| C_TABLE_ALPHABET.chars().nth(index))
+        })
+        .collect::<Option<String>>()
+        .ok_or(Exceptions::FORMAT)?;
+    Ok(header + &characters)
+}
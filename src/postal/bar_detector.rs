@@ -0,0 +1,194 @@
+use crate::{
+    common::{BitMatrix, Result},
+    Exceptions, RXingResult,
+};
+
+use super::FourStateBar;
+
+/// Fewest bars a group needs to be considered a postal barcode
+const MIN_BARS: usize = 4;
+
+/**
+ * A bar found in a row, with its horizontal extent in that row and its full vertical extent.
+ * Right and bottom are exclusive.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BarExtent {
+    pub left: u32,
+    pub right: u32,
+    pub top: u32,
+    pub bottom: u32,
+}
+
+/**
+ * Scans rows from the middle of the image outward, the way {@link OneDReader} does, and hands
+ * every group of bars found in a row to the decoder until it returns a result.
+ *
+ * <p>Height modulated barcodes are only seen completely in rows that cross the part all bars
+ * have in common: the tracker of 4-state barcodes, the short bars of 2-state ones.</p>
+ *
+ * @param image the binarized image
+ * @param tryHarder whether to look at every row instead of the middle half of the image
+ * @param decodeRow decodes a group of bars found in the given row
+ */
+pub fn detect<F>(image: &BitMatrix, tryHarder: bool, mut decodeRow: F) -> Result<RXingResult>
+where
+    F: FnMut(&[BarExtent], u32) -> Option<RXingResult>,
+{
+    let height = image.getHeight();
+    let rowStep = 1.max(height >> if tryHarder { 8 } else { 5 });
+    let maxLines = if tryHarder { height } else { 15 };
+
+    let middle = height as i64 / 2;
+    for x in 0..maxLines {
+        let rowStepsAboveOrBelow = (x as i64 + 1) / 2;
+        let isAbove = (x & 0x01) == 0;
+        let rowNumber = middle
+            + rowStep as i64
+                * if isAbove {
+                    rowStepsAboveOrBelow
+                } else {
+                    -rowStepsAboveOrBelow
+                };
+        if rowNumber < 0 || rowNumber >= height as i64 {
+            break;
+        }
+
+        for bars in findBars(image, rowNumber as u32) {
+            if let Some(result) = decodeRow(&bars, rowNumber as u32) {
+                return Ok(result);
+            }
+        }
+    }
+    Err(Exceptions::NOT_FOUND)
+}
+
+/**
+ * Finds the dark runs of a row and groups them into evenly spaced bars. A space wider than three
+ * times its neighbouring bars ends a group.
+ *
+ * @return the groups of at least {@link #MIN_BARS} bars, with their vertical extents
+ */
+pub fn findBars(image: &BitMatrix, rowNumber: u32) -> Vec<Vec<BarExtent>> {
+    let width = image.getWidth();
+    let mut runs = Vec::new();
+    let mut x = 0;
+    while x < width {
+        if image.get(x, rowNumber) {
+            let start = x;
+            while x < width && image.get(x, rowNumber) {
+                x += 1;
+            }
+            runs.push((start, x));
+        } else {
+            x += 1;
+        }
+    }
+
+    let mut groups: Vec<Vec<(u32, u32)>> = Vec::new();
+    let mut group: Vec<(u32, u32)> = Vec::new();
+    for run in runs {
+        if let Some(&(left, right)) = group.last() {
+            let gap = run.0 - right;
+            if gap > 3 * (right - left).max(run.1 - run.0) {
+                groups.push(std::mem::take(&mut group));
+            }
+        }
+        group.push(run);
+    }
+    groups.push(group);
+
+    groups
+        .into_iter()
+        .filter(|group| group.len() >= MIN_BARS)
+        .map(|group| {
+            group
+                .into_iter()
+                .map(|(left, right)| measureBar(image, rowNumber, left, right))
+                .collect()
+        })
+        .collect()
+}
+
+/**
+ * Follows the bar up and down from the row, along its center.
+ */
+fn measureBar(image: &BitMatrix, rowNumber: u32, left: u32, right: u32) -> BarExtent {
+    let x = (left + right) / 2;
+    let mut top = rowNumber;
+    while top > 0 && image.get(x, top - 1) {
+        top -= 1;
+    }
+    let mut bottom = rowNumber + 1;
+    while bottom < image.getHeight() && image.get(x, bottom) {
+        bottom += 1;
+    }
+    BarExtent {
+        left,
+        right,
+        top,
+        bottom,
+    }
+}
+
+/**
+ * Classifies bars as 4-state bars. A bar has an ascender if its top is closer to the top of the
+ * highest bars than to the top of the lowest ones, and likewise for descenders.
+ */
+pub fn classifyFourState(bars: &[BarExtent]) -> Vec<FourStateBar> {
+    let (Some(top), Some(lowestTop), Some(bottom), Some(highestBottom)) = (
+        bars.iter().map(|bar| bar.top).min(),
+        bars.iter().map(|bar| bar.top).max(),
+        bars.iter().map(|bar| bar.bottom).max(),
+        bars.iter().map(|bar| bar.bottom).min(),
+    ) else {
+        return Vec::new();
+    };
+    // if all tops are about the same, every bar has an ascender
+    let height = bottom - top;
+    let ascenderLimit = if (lowestTop - top) * 8 < height {
+        lowestTop
+    } else {
+        (top + lowestTop) / 2
+    };
+    let descenderLimit = if (bottom - highestBottom) * 8 < height {
+        highestBottom
+    } else {
+        (bottom + highestBottom).div_ceil(2)
+    };
+
+    bars.iter()
+        .map(|bar| FourStateBar::new(bar.top <= ascenderLimit, bar.bottom >= descenderLimit))
+        .collect()
+}
+
+/**
+ * Classifies bars as tall or short, by whether they are higher than halfway between the shortest
+ * and the tallest bar. Short bars stand on the same line as tall ones, so when they hang from
+ * the top instead, the symbol is upside down.
+ *
+ * @return whether each bar is tall, and whether the short bars hang from the top
+ */
+pub fn classifyTwoState(bars: &[BarExtent]) -> (Vec<bool>, bool) {
+    let heights = bars
+        .iter()
+        .map(|bar| bar.bottom - bar.top)
+        .collect::<Vec<_>>();
+    let (Some(shortest), Some(tallest)) = (heights.iter().min(), heights.iter().max()) else {
+        return (Vec::new(), false);
+    };
+    let tall = heights
+        .iter()
+        .map(|height| *height > (shortest + tallest) / 2)
+        .collect::<Vec<_>>();
+
+    let top = bars.iter().map(|bar| bar.top).min().unwrap_or_default();
+    let bottom = bars.iter().map(|bar| bar.bottom).max().unwrap_or_default();
+    let (fromTop, fromBottom) = bars.iter().zip(&tall).filter(|(_, tall)| !**tall).fold(
+        (0, 0),
+        |(fromTop, fromBottom), (bar, _)| {
+            (fromTop + bar.top - top, fromBottom + bottom - bar.bottom)
+        },
+    );
+    (tall, fromTop < fromBottom)
+}
@@ -0,0 +1,63 @@
+/**
+ * The bars of a 4-state postal barcode. Every bar has the tracker in the middle, and may extend
+ * up with an ascender and down with a descender.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FourStateBar {
+    /** Tracker with ascender and descender */
+    Full,
+    /** Tracker with ascender */
+    Ascender,
+    /** Tracker with descender */
+    Descender,
+    /** Tracker only */
+    Tracker,
+}
+
+impl FourStateBar {
+    pub fn new(ascender: bool, descender: bool) -> Self {
+        match (ascender, descender) {
+            (true, true) => FourStateBar::Full,
+            (true, false) => FourStateBar::Ascender,
+            (false, true) => FourStateBar::Descender,
+            (false, false) => FourStateBar::Tracker,
+        }
+    }
+
+    pub fn hasAscender(&self) -> bool {
+        matches!(self, FourStateBar::Full | FourStateBar::Ascender)
+    }
+
+    pub fn hasDescender(&self) -> bool {
+        matches!(self, FourStateBar::Full | FourStateBar::Descender)
+    }
+
+    /**
+     * @return the bar as seen in a symbol turned upside down
+     */
+    pub fn flipped(&self) -> Self {
+        Self::new(self.hasDescender(), self.hasAscender())
+    }
+
+    /**
+     * @return the value of the bar in the tables of the Royal Mail and Australia Post
+     *         specifications: 0 full, 1 ascender, 2 descender, 3 tracker
+     */
+    pub(crate) fn value(&self) -> u8 {
+        match self {
+            FourStateBar::Full => 0,
+            FourStateBar::Ascender => 1,
+            FourStateBar::Descender => 2,
+            FourStateBar::Tracker => 3,
+        }
+    }
+
+    pub(crate) fn fromValue(value: u8) -> Self {
+        match value & 0x03 {
+            0 => FourStateBar::Full,
+            1 => FourStateBar::Ascender,
+            2 => FourStateBar::Descender,
+            _ => FourStateBar::Tracker,
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+
+use crate::{
+    common::Result, point_f, BarcodeFormat, Binarizer, BinaryBitmap, DecodeHintType,
+    DecodeHintValue, DecodingHintDictionary, RXingResult, RXingResultMetadataType,
+    RXingResultMetadataValue, Reader,
+};
+
+use super::{australia_post, bar_detector, intelligent_mail, rm4scc, FourStateBar};
+
+/// The formats read unless others are requested. KIX has no start and stop bars or check
+/// character to tell it apart from other bars, so it is only read on request.
+const DEFAULT_FORMATS: [BarcodeFormat; 3] = [
+    BarcodeFormat::USPS_INTELLIGENT_MAIL,
+    BarcodeFormat::RM4SCC,
+    BarcodeFormat::AUSTRALIA_POST,
+];
+
+/**
+ * <p>Reads 4-state postal barcodes: USPS Intelligent Mail, Royal Mail 4-State Customer Code
+ * (RM4SCC), Australia Post and Dutch KIX.</p>
+ *
+ * <p>Rows crossing the trackers find the bars, which are followed up and down to tell whether
+ * they have an ascender and a descender. Symbols turned upside down are read as well, except
+ * for KIX codes, which have nothing to tell which way up they are.</p>
+ */
+#[derive(Default)]
+pub struct FourStateReader;
+
+impl Reader for FourStateReader {
+    fn decode<B: Binarizer>(&mut self, image: &mut BinaryBitmap<B>) -> Result<RXingResult> {
+        self.decode_with_hints(image, &HashMap::new())
+    }
+
+    fn decode_with_hints<B: Binarizer>(
+        &mut self,
+        image: &mut BinaryBitmap<B>,
+        hints: &DecodingHintDictionary,
+    ) -> Result<RXingResult> {
+        let tryHarder = matches!(
+            hints.get(&DecodeHintType::TRY_HARDER),
+            Some(DecodeHintValue::TryHarder(true))
+        );
+        let mut formats = if let Some(DecodeHintValue::PossibleFormats(formats)) =
+            hints.get(&DecodeHintType::POSSIBLE_FORMATS)
+        {
+            DEFAULT_FORMATS
+                .iter()
+                .chain([BarcodeFormat::KIX].iter())
+                .filter(|format| formats.contains(format))
+                .copied()
+                .collect::<Vec<_>>()
+        } else {
+            Vec::new()
+        };
+        if formats.is_empty() {
+            formats = DEFAULT_FORMATS.to_vec();
+        }
+
+        bar_detector::detect(image.get_black_matrix(), tryHarder, |bars, rowNumber| {
+            let states = bar_detector::classifyFourState(bars);
+            let flipped = states.iter().rev().map(FourStateBar::flipped).collect();
+            for (upsideDown, states) in [(false, states), (true, flipped)] {
+                for format in &formats {
+                    let Ok(text) = Self::decodeBars(format, &states) else {
+                        continue;
+                    };
+                    let (left, right) = (bars[0].left, bars[bars.len() - 1].right);
+                    let mut result = RXingResult::new(
+                        &text,
+                        Vec::new(),
+                        vec![
+                            point_f(left as f32, rowNumber as f32),
+                            point_f(right as f32, rowNumber as f32),
+                        ],
+                        *format,
+                    );
+                    if upsideDown {
+                        result.putMetadata(
+                            RXingResultMetadataType::ORIENTATION,
+                            RXingResultMetadataValue::Orientation(180),
+                        );
+                    }
+                    return Some(result);
+                }
+            }
+            None
+        })
+    }
+
+    fn reset(&mut self) {
+        // nothing to reset
+    }
+}
+
+impl FourStateReader {
+    fn decodeBars(format: &BarcodeFormat, bars: &[FourStateBar]) -> Result<String> {
+        match format {
+            BarcodeFormat::USPS_INTELLIGENT_MAIL => intelligent_mail::decode(bars),
+            BarcodeFormat::RM4SCC => rm4scc::decode(bars),
+            BarcodeFormat::KIX => rm4scc::decodeKIX(bars),
+            _ => australia_post::decode(bars),
+        }
+    }
+}
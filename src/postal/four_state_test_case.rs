@@ -0,0 +1,191 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    common::{bit_matrix_test_case, BitMatrix, Result},
+    BarcodeFormat, DecodeHintType, DecodeHintValue, MultiFormatReader, MultiFormatWriter,
+    MultiUseMultiFormatReader, RXingResult, RXingResultMetadataType, RXingResultMetadataValue,
+    Reader, Writer,
+};
+
+use super::{australia_post, intelligent_mail, rm4scc, FourStateBar};
+
+fn toLetters(bars: &[FourStateBar]) -> String {
+    bars.iter()
+        .map(|bar| match bar {
+            FourStateBar::Full => 'F',
+            FourStateBar::Ascender => 'A',
+            FourStateBar::Descender => 'D',
+            FourStateBar::Tracker => 'T',
+        })
+        .collect()
+}
+
+fn tryRead(image: &BitMatrix, formats: &[BarcodeFormat]) -> Result<RXingResult> {
+    let mut bitmap = bit_matrix_test_case::matrix_to_binary_bitmap(image);
+    let mut hints = HashMap::new();
+    if !formats.is_empty() {
+        hints.insert(
+            DecodeHintType::POSSIBLE_FORMATS,
+            DecodeHintValue::PossibleFormats(formats.iter().copied().collect::<HashSet<_>>()),
+        );
+    }
+    MultiFormatReader::default().decode_with_hints(&mut bitmap, &hints)
+}
+
+fn read(image: &BitMatrix, formats: &[BarcodeFormat]) -> RXingResult {
+    tryRead(image, formats).expect("must decode")
+}
+
+/**
+ * Writes the contents, reads them back, and reads them back upside down.
+ */
+fn assertRoundTrip(contents: &str, format: BarcodeFormat, formats: &[BarcodeFormat]) {
+    let mut image = MultiFormatWriter
+        .encode(contents, &format, 300, 60)
+        .expect("must encode");
+    for upsideDown in [false, true] {
+        if upsideDown {
+            image.rotate180();
+        }
+        let result = read(&image, formats);
+        assert_eq!(contents, result.getText());
+        assert_eq!(&format, result.getBarcodeFormat());
+        assert_eq!(
+            upsideDown,
+            result
+                .getRXingResultMetadata()
+                .get(&RXingResultMetadataType::ORIENTATION)
+                == Some(&RXingResultMetadataValue::Orientation(180)),
+            "{contents}"
+        );
+    }
+}
+
+#[test]
+fn testIntelligentMailSpecificationExamples() {
+    for (contents, expected) in [
+        (
+            "01234567094987654321",
+            "ATTFATTDTTADTAATTDTDTATTDAFDDFADFDFTFFFFFTATFAAAATDFFTDAADFTFDTDT",
+        ),
+        (
+            "0123456709498765432101234",
+            "DTTAFADDTTFTDTFTFDTDDADADAFADFATDDFTAAAFDTTADFAAATDFDTDFADDDTDFFT",
+        ),
+        (
+            "01234567094987654321012345678",
+            "ADFTTAFDTTTTFATTADTAAATFTFTATDAAAFDDADATATDTDTTDFDTDATADADTDFFTFA",
+        ),
+        (
+            "0123456709498765432101234567891",
+            "AADTFFDFTDADTAADAATFDTDDAAADDTDTTDAFADADDDTFFFDDTTTADFAAADFTDAADA",
+        ),
+    ] {
+        let bars = intelligent_mail::encode(contents).expect("must encode");
+        assert_eq!(expected, toLetters(&bars));
+        assert_eq!(
+            contents,
+            intelligent_mail::decode(&bars).expect("must decode")
+        );
+    }
+
+    assert!(intelligent_mail::encode("05234567094987654321").is_err());
+    assert!(intelligent_mail::encode("012345670949876543210").is_err());
+}
+
+#[test]
+fn testIntelligentMail() {
+    assertRoundTrip(
+        "0123456709498765432101234567891",
+        BarcodeFormat::USPS_INTELLIGENT_MAIL,
+        &[BarcodeFormat::USPS_INTELLIGENT_MAIL, BarcodeFormat::RM4SCC],
+    );
+    assertRoundTrip(
+        "01234567094987654321",
+        BarcodeFormat::USPS_INTELLIGENT_MAIL,
+        &[BarcodeFormat::USPS_INTELLIGENT_MAIL],
+    );
+}
+
+#[test]
+fn testRM4SCC() {
+    let bars = rm4scc::encode("SN34RD1A").expect("must encode");
+    assert_eq!(2 + 9 * 4, bars.len());
+    // the check character of the specification example
+    assert_eq!(
+        "SN34RD1AK",
+        rm4scc::decodeKIX(&bars[1..bars.len() - 1]).expect("must decode")
+    );
+    assert_eq!("SN34RD1A", rm4scc::decode(&bars).expect("must decode"));
+
+    assertRoundTrip("SN34RD1A", BarcodeFormat::RM4SCC, &[BarcodeFormat::RM4SCC]);
+    assert!(rm4scc::encode("sn34rd1a").is_err());
+}
+
+#[test]
+fn testKIX() {
+    // without start and stop bars, an upside down KIX code reads as another one
+    let image = MultiFormatWriter
+        .encode("2500GG30250", &BarcodeFormat::KIX, 300, 60)
+        .expect("must encode");
+    let result = read(&image, &[BarcodeFormat::KIX]);
+    assert_eq!("2500GG30250", result.getText());
+    assert_eq!(&BarcodeFormat::KIX, result.getBarcodeFormat());
+}
+
+#[test]
+fn testAustraliaPost() {
+    assertRoundTrip(
+        "1139987520",
+        BarcodeFormat::AUSTRALIA_POST,
+        &[BarcodeFormat::AUSTRALIA_POST],
+    );
+    assertRoundTrip(
+        "5939987520ABA 9",
+        BarcodeFormat::AUSTRALIA_POST,
+        &[BarcodeFormat::AUSTRALIA_POST],
+    );
+    assertRoundTrip(
+        "593998752012345678",
+        BarcodeFormat::AUSTRALIA_POST,
+        &[BarcodeFormat::AUSTRALIA_POST],
+    );
+    assertRoundTrip(
+        "623998752012345678901234",
+        BarcodeFormat::AUSTRALIA_POST,
+        &[BarcodeFormat::AUSTRALIA_POST],
+    );
+
+    assert!(australia_post::encode("1339987520").is_err());
+    assert!(australia_post::encode("11399875201").is_err());
+    assert!(australia_post::encode("59399875201234567890").is_err());
+}
+
+#[test]
+fn testAustraliaPostErrorCorrection() {
+    let mut bars = australia_post::encode("5939987520ABA 9").expect("must encode");
+    // two damaged Reed-Solomon symbols
+    for i in [5, 30] {
+        bars[i] = if bars[i] == FourStateBar::Full {
+            FourStateBar::Tracker
+        } else {
+            FourStateBar::Full
+        };
+    }
+    assert_eq!(
+        "5939987520ABA 9",
+        australia_post::decode(&bars).expect("must decode")
+    );
+}
+
+#[test]
+fn testNotReadByDefault() {
+    // postal formats are only looked for when POSSIBLE_FORMATS names them
+    let image = MultiFormatWriter
+        .encode("SN34RD1A", &BarcodeFormat::RM4SCC, 300, 60)
+        .expect("must encode");
+    assert!(tryRead(&image, &[]).is_err());
+    assert!(MultiUseMultiFormatReader::default()
+        .decode(&mut bit_matrix_test_case::matrix_to_binary_bitmap(&image))
+        .is_err());
+}
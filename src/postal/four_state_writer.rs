@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+
+use crate::{
+    common::{BitMatrix, Result},
+    BarcodeFormat, EncodingHintDictionary, Exceptions, Writer,
+};
+
+use super::{australia_post, intelligent_mail, rm4scc};
+
+/// Height of the ascender, the tracker and the descender, in units
+const ASCENDER_HEIGHT: u32 = 3;
+const TRACKER_HEIGHT: u32 = 2;
+const DESCENDER_HEIGHT: u32 = 3;
+/// Blank modules left and right of the symbol
+const QUIET_ZONE_MODULES: u32 = 6;
+/// Blank units above and below the symbol
+const QUIET_ZONE_UNITS: u32 = 2;
+
+/**
+ * Renders 4-state postal barcodes as a {@link BitMatrix}: USPS Intelligent Mail, Royal Mail
+ * 4-State Customer Code (RM4SCC), Australia Post and Dutch KIX.
+ *
+ * Bars are one module wide and one module apart. The height is divided into units, ascenders and
+ * descenders being three units long and the tracker two.
+ */
+#[derive(Default)]
+pub struct FourStateWriter;
+
+impl Writer for FourStateWriter {
+    fn encode(
+        &self,
+        contents: &str,
+        format: &BarcodeFormat,
+        width: i32,
+        height: i32,
+    ) -> Result<BitMatrix> {
+        self.encode_with_hints(contents, format, width, height, &HashMap::new())
+    }
+
+    fn encode_with_hints(
+        &self,
+        contents: &str,
+        format: &BarcodeFormat,
+        width: i32,
+        height: i32,
+        _hints: &EncodingHintDictionary,
+    ) -> Result<BitMatrix> {
+        if contents.is_empty() {
+            return Err(Exceptions::illegal_argument_with("Found empty contents"));
+        }
+
+        if width < 0 || height < 0 {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "Requested dimensions can't be negative: {width}x{height}"
+            )));
+        }
+
+        let bars = match format {
+            BarcodeFormat::USPS_INTELLIGENT_MAIL => intelligent_mail::encode(contents)?,
+            BarcodeFormat::RM4SCC => rm4scc::encode(contents)?,
+            BarcodeFormat::KIX => rm4scc::encodeKIX(contents)?,
+            BarcodeFormat::AUSTRALIA_POST => australia_post::encode(contents)?,
+            _ => {
+                return Err(Exceptions::illegal_argument_with(format!(
+                    "Can only encode 4-state postal barcodes, but got {format:?}"
+                )))
+            }
+        };
+
+        let extents = bars
+            .iter()
+            .map(|bar| {
+                let top = if bar.hasAscender() {
+                    0
+                } else {
+                    ASCENDER_HEIGHT
+                };
+                let bottom = ASCENDER_HEIGHT
+                    + TRACKER_HEIGHT
+                    + if bar.hasDescender() {
+                        DESCENDER_HEIGHT
+                    } else {
+                        0
+                    };
+                (top, bottom)
+            })
+            .collect::<Vec<_>>();
+        renderBars(
+            &extents,
+            ASCENDER_HEIGHT + TRACKER_HEIGHT + DESCENDER_HEIGHT,
+            width as u32,
+            height as u32,
+        )
+    }
+}
+
+/**
+ * Draws bars of one module, one module apart, scaled to fill the requested size.
+ *
+ * @param extents the top and bottom of each bar in units, bottom exclusive
+ * @param unitsHigh the height of the symbol in units
+ */
+pub(super) fn renderBars(
+    extents: &[(u32, u32)],
+    unitsHigh: u32,
+    width: u32,
+    height: u32,
+) -> Result<BitMatrix> {
+    let codeWidth = (2 * extents.len() as u32).saturating_sub(1);
+    let fullWidth = codeWidth + 2 * QUIET_ZONE_MODULES;
+    let outputWidth = width.max(fullWidth);
+    let multiple = outputWidth / fullWidth;
+    let leftPadding = (outputWidth - codeWidth * multiple) / 2;
+
+    let fullHeight = unitsHigh + 2 * QUIET_ZONE_UNITS;
+    let outputHeight = height.max(fullHeight);
+    let unit = outputHeight / fullHeight;
+    let topPadding = (outputHeight - unitsHigh * unit) / 2;
+
+    let mut output = BitMatrix::new(outputWidth, outputHeight)?;
+    for (i, (top, bottom)) in extents.iter().enumerate() {
+        output.setRegion(
+            leftPadding + 2 * i as u32 * multiple,
+            topPadding + top * unit,
+            multiple,
+            (bottom - top) * unit,
+        )?;
+    }
+    Ok(output)
+}
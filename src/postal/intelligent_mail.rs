@@ -0,0 +1,301 @@
+use once_cell::sync::Lazy;
+
+use crate::{common::Result, Exceptions};
+
+use super::FourStateBar;
+
+const BAR_COUNT: usize = 65;
+const TRACKING_CODE_LENGTH: usize = 20;
+
+/// Generator polynomial of the frame check sequence
+const CRC_POLYNOMIAL: u16 = 0x0F35;
+
+/// The character and bit of the descender and the ascender of each bar (Table 22)
+const BAR_TO_CHARACTER: [((usize, u32), (usize, u32)); BAR_COUNT] = [
+    ((7, 2), (4, 3)),
+    ((1, 10), (0, 0)),
+    ((9, 12), (2, 8)),
+    ((5, 5), (6, 11)),
+    ((8, 9), (3, 1)),
+    ((0, 1), (5, 12)),
+    ((2, 5), (1, 8)),
+    ((4, 4), (9, 11)),
+    ((6, 3), (8, 10)),
+    ((3, 9), (7, 6)),
+    ((5, 11), (1, 4)),
+    ((8, 5), (2, 12)),
+    ((9, 10), (0, 2)),
+    ((7, 1), (6, 7)),
+    ((3, 6), (4, 9)),
+    ((0, 3), (8, 6)),
+    ((6, 4), (2, 7)),
+    ((1, 1), (9, 9)),
+    ((7, 10), (5, 2)),
+    ((4, 0), (3, 8)),
+    ((6, 2), (0, 4)),
+    ((8, 11), (1, 0)),
+    ((9, 8), (3, 12)),
+    ((2, 6), (7, 7)),
+    ((5, 1), (4, 10)),
+    ((1, 12), (6, 9)),
+    ((7, 3), (8, 0)),
+    ((5, 8), (9, 7)),
+    ((4, 6), (2, 10)),
+    ((3, 4), (0, 5)),
+    ((8, 4), (5, 7)),
+    ((7, 11), (1, 9)),
+    ((6, 0), (9, 6)),
+    ((0, 6), (4, 8)),
+    ((2, 1), (3, 2)),
+    ((5, 9), (8, 12)),
+    ((4, 11), (6, 1)),
+    ((9, 5), (7, 4)),
+    ((3, 3), (1, 2)),
+    ((0, 7), (2, 0)),
+    ((1, 3), (4, 1)),
+    ((6, 10), (3, 5)),
+    ((8, 7), (9, 4)),
+    ((2, 11), (5, 6)),
+    ((0, 8), (7, 12)),
+    ((4, 2), (8, 1)),
+    ((5, 10), (3, 0)),
+    ((9, 3), (0, 9)),
+    ((6, 5), (2, 4)),
+    ((7, 8), (1, 7)),
+    ((5, 0), (4, 5)),
+    ((2, 3), (0, 10)),
+    ((6, 12), (9, 2)),
+    ((3, 11), (1, 6)),
+    ((8, 8), (7, 9)),
+    ((5, 4), (0, 11)),
+    ((1, 5), (2, 2)),
+    ((9, 1), (4, 12)),
+    ((8, 3), (6, 6)),
+    ((7, 0), (3, 7)),
+    ((4, 7), (7, 5)),
+    ((0, 12), (1, 11)),
+    ((2, 9), (9, 0)),
+    ((6, 8), (5, 3)),
+    ((3, 10), (8, 2)),
+];
+
+/// The 5 of 13 characters of codewords 0 to 1286, then the 2 of 13 characters of 1287 to 1364
+static CHARACTERS: Lazy<Vec<u16>> = Lazy::new(|| {
+    let mut characters = buildNOf13Table(5, 1287);
+    characters.append(&mut buildNOf13Table(2, 78));
+    characters
+});
+
+/// The codeword of each 13 bit value that is a character
+static CODEWORDS: Lazy<Vec<Option<u16>>> = Lazy::new(|| {
+    let mut codewords = vec![None; 1 << 13];
+    for (codeword, character) in CHARACTERS.iter().enumerate() {
+        codewords[*character as usize] = Some(codeword as u16);
+    }
+    codewords
+});
+
+/**
+ * Lists the 13 bit values with n bits set. A value and its bit reversal are next to each other
+ * from the start of the table, values that are their own reversal fill it from the end.
+ */
+fn buildNOf13Table(n: u32, length: usize) -> Vec<u16> {
+    let mut table = vec![0; length];
+    let (mut lower, mut upper) = (0, length);
+    for value in 0..(1u16 << 13) {
+        if value.count_ones() != n {
+            continue;
+        }
+        let reverse = value.reverse_bits() >> 3;
+        if reverse < value {
+            continue;
+        }
+        if reverse == value {
+            upper -= 1;
+            table[upper] = value;
+        } else {
+            table[lower] = value;
+            table[lower + 1] = reverse;
+            lower += 2;
+        }
+    }
+    table
+}
+
+/**
+ * @return the 11 bit frame check sequence of the 102 bit binary value
+ */
+fn generateFrameCheckSequence(value: u128) -> u16 {
+    let bytes = value.to_be_bytes();
+    let mut frameCheckSequence: u16 = 0x07FF;
+    // the 13 least significant bytes, skipping the 2 most significant bits
+    for (i, byte) in bytes[3..].iter().enumerate() {
+        let mut data = (*byte as u16) << 3;
+        let skip = if i == 0 { 2 } else { 0 };
+        data <<= skip;
+        for _ in skip..8 {
+            frameCheckSequence = if (frameCheckSequence ^ data) & 0x400 != 0 {
+                (frameCheckSequence << 1) ^ CRC_POLYNOMIAL
+            } else {
+                frameCheckSequence << 1
+            } & 0x07FF;
+            data <<= 1;
+        }
+    }
+    frameCheckSequence
+}
+
+/**
+ * <p>Encodes a USPS Intelligent Mail barcode (USPS-B-3200) of 65 bars, carrying a 20 digit
+ * tracking code and an optional 5, 9 or 11 digit routing code.</p>
+ *
+ * <p>The digits are converted to a binary value protected by an 11 bit CRC, split into ten
+ * codewords, which are mapped to 13 bit characters and spread over the bars.</p>
+ *
+ * @param contents 20, 25, 29 or 31 digits
+ */
+pub fn encode(contents: &str) -> Result<Vec<FourStateBar>> {
+    if !contents.bytes().all(|c| c.is_ascii_digit()) || ![20, 25, 29, 31].contains(&contents.len())
+    {
+        return Err(Exceptions::illegal_argument_with(format!(
+            "Intelligent Mail requires 20, 25, 29 or 31 digits, but got {contents}"
+        )));
+    }
+    let digits = contents
+        .bytes()
+        .map(|c| (c - b'0') as u128)
+        .collect::<Vec<_>>();
+    if digits[1] > 4 {
+        return Err(Exceptions::illegal_argument_with(
+            "the second digit of the barcode identifier must be 0 to 4",
+        ));
+    }
+
+    let (tracking, routing) = digits.split_at(TRACKING_CODE_LENGTH);
+    let routingValue = routing.iter().fold(0, |value, digit| value * 10 + digit);
+    let mut value = match routing.len() {
+        0 => 0,
+        5 => routingValue + 1,
+        9 => routingValue + 100_001,
+        _ => routingValue + 1_000_100_001,
+    };
+    value = value * 10 + tracking[0];
+    value = value * 5 + tracking[1];
+    value = tracking[2..]
+        .iter()
+        .fold(value, |value, digit| value * 10 + digit);
+
+    let frameCheckSequence = generateFrameCheckSequence(value);
+
+    let mut codewords = [0u16; 10];
+    codewords[9] = (value % 636) as u16 * 2;
+    value /= 636;
+    for codeword in codewords[1..9].iter_mut().rev() {
+        *codeword = (value % 1365) as u16;
+        value /= 1365;
+    }
+    codewords[0] = value as u16;
+    if frameCheckSequence & 0x400 != 0 {
+        codewords[0] += 659;
+    }
+
+    let characters = codewords
+        .iter()
+        .enumerate()
+        .map(|(i, codeword)| {
+            let character = CHARACTERS[*codeword as usize];
+            if frameCheckSequence >> i & 1 != 0 {
+                !character & 0x1FFF
+            } else {
+                character
+            }
+        })
+        .collect::<Vec<_>>();
+
+    Ok(BAR_TO_CHARACTER
+        .iter()
+        .map(|((descender, descenderBit), (ascender, ascenderBit))| {
+            FourStateBar::new(
+                characters[*ascender] >> ascenderBit & 1 != 0,
+                characters[*descender] >> descenderBit & 1 != 0,
+            )
+        })
+        .collect())
+}
+
+/**
+ * @return the tracking code followed by the routing code, if any
+ */
+pub fn decode(bars: &[FourStateBar]) -> Result<String> {
+    if bars.len() != BAR_COUNT {
+        return Err(Exceptions::NOT_FOUND);
+    }
+
+    let mut characters = [0u16; 10];
+    for (bar, ((descender, descenderBit), (ascender, ascenderBit))) in
+        bars.iter().zip(BAR_TO_CHARACTER.iter())
+    {
+        if bar.hasDescender() {
+            characters[*descender] |= 1 << descenderBit;
+        }
+        if bar.hasAscender() {
+            characters[*ascender] |= 1 << ascenderBit;
+        }
+    }
+
+    let mut frameCheckSequence = 0;
+    let mut codewords = [0u16; 10];
+    for (i, character) in characters.iter().enumerate() {
+        let character = match character.count_ones() {
+            2 | 5 => *character,
+            8 | 11 => {
+                frameCheckSequence |= 1 << i;
+                !character & 0x1FFF
+            }
+            _ => return Err(Exceptions::NOT_FOUND),
+        };
+        codewords[i] = CODEWORDS[character as usize].ok_or(Exceptions::NOT_FOUND)?;
+    }
+    if codewords[0] >= 659 {
+        frameCheckSequence |= 0x400;
+        codewords[0] -= 659;
+    }
+    // an odd last codeword is what a symbol read backwards looks like
+    if codewords[0] >= 659 || codewords[9] % 2 != 0 || codewords[9] >= 1272 {
+        return Err(Exceptions::NOT_FOUND);
+    }
+
+    let mut value = codewords[1..9]
+        .iter()
+        .fold(codewords[0] as u128, |value, codeword| {
+            value * 1365 + *codeword as u128
+        });
+    value = value * 636 + codewords[9] as u128 / 2;
+    if generateFrameCheckSequence(value) != frameCheckSequence {
+        return Err(Exceptions::CHECKSUM);
+    }
+
+    let mut tracking = [0u8; TRACKING_CODE_LENGTH];
+    for digit in tracking[2..].iter_mut().rev() {
+        *digit = (value % 10) as u8;
+        value /= 10;
+    }
+    tracking[1] = (value % 5) as u8;
+    value /= 5;
+    tracking[0] = (value % 10) as u8;
+    value /= 10;
+
+    let routing = match value {
+        0 => String::new(),
+        1..=100_000 => format!("{:05}", value - 1),
+        100_001..=1_000_100_000 => format!("{:09}", value - 100_001),
+        1_000_100_001..=101_000_100_000 => format!("{:011}", value - 1_000_100_001),
+        _ => return Err(Exceptions::FORMAT),
+    };
+
+    Ok(tracking
+        .iter()
+        .map(|digit| (b'0' + digit) as char)
+        .collect::<String>()
+        + &routing)
+}
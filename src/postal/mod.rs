@@ -0,0 +1,22 @@
+pub mod australia_post;
+pub mod bar_detector;
+pub mod intelligent_mail;
+pub mod postnet;
+pub mod rm4scc;
+
+mod four_state_bar;
+mod four_state_reader;
+mod four_state_writer;
+mod two_state_reader;
+mod two_state_writer;
+
+#[cfg(test)]
+mod four_state_test_case;
+#[cfg(test)]
+mod two_state_test_case;
+
+pub use four_state_bar::*;
+pub use four_state_reader::*;
+pub use four_state_writer::*;
+pub use two_state_reader::*;
+pub use two_state_writer::*;
@@ -0,0 +1,90 @@
+use crate::{common::Result, Exceptions};
+
+/// The tall bars of each digit in POSTNET, weighted 7, 4, 2, 1 and 0. PLANET uses the complement.
+const DIGITS: [u8; 10] = [
+    0b11000, 0b00011, 0b00101, 0b00110, 0b01001, 0b01010, 0b01100, 0b10001, 0b10010, 0b10100,
+];
+
+const POSTNET_LENGTHS: [usize; 3] = [5, 9, 11];
+const PLANET_LENGTHS: [usize; 2] = [11, 13];
+
+fn lengths(planet: bool) -> &'static [usize] {
+    if planet {
+        &PLANET_LENGTHS
+    } else {
+        &POSTNET_LENGTHS
+    }
+}
+
+/**
+ * The check digit brings the sum of all digits to a multiple of 10.
+ */
+fn checkDigit(digits: &[u8]) -> u8 {
+    (10 - digits.iter().map(|digit| *digit as u32).sum::<u32>() % 10) as u8 % 10
+}
+
+/**
+ * <p>Encodes a POSTNET or PLANET barcode: a tall frame bar, five bars per digit and for the check
+ * digit, and a tall frame bar. POSTNET digits have two tall bars, PLANET digits three.</p>
+ *
+ * @param contents 5, 9 or 11 digits for POSTNET, 11 or 13 digits for PLANET
+ * @param planet whether to encode PLANET instead of POSTNET
+ * @return whether each bar is tall
+ */
+pub fn encode(contents: &str, planet: bool) -> Result<Vec<bool>> {
+    if !contents.bytes().all(|c| c.is_ascii_digit()) || !lengths(planet).contains(&contents.len()) {
+        return Err(Exceptions::illegal_argument_with(format!(
+            "{} requires {:?} digits, but got {contents}",
+            if planet { "PLANET" } else { "POSTNET" },
+            lengths(planet)
+        )));
+    }
+    let mut digits = contents.bytes().map(|c| c - b'0').collect::<Vec<_>>();
+    digits.push(checkDigit(&digits));
+
+    let mut bars = vec![true];
+    for digit in digits {
+        bars.extend(
+            (0..5)
+                .rev()
+                .map(|i| (DIGITS[digit as usize] >> i & 1 != 0) != planet),
+        );
+    }
+    bars.push(true);
+    Ok(bars)
+}
+
+/**
+ * @return the digits without the check digit
+ */
+pub fn decode(bars: &[bool], planet: bool) -> Result<String> {
+    let barCount = bars.len();
+    if barCount < 2
+        || !(barCount - 2).is_multiple_of(5)
+        || !lengths(planet).contains(&((barCount - 2) / 5).saturating_sub(1))
+        || !bars[0]
+        || !bars[barCount - 1]
+    {
+        return Err(Exceptions::NOT_FOUND);
+    }
+
+    let mut digits = bars[1..barCount - 1]
+        .chunks_exact(5)
+        .map(|group| {
+            let pattern = group
+                .iter()
+                .fold(0, |pattern, tall| pattern << 1 | (*tall != planet) as u8);
+            DIGITS
+                .iter()
+                .position(|digits| *digits == pattern)
+                .map(|digit| digit as u8)
+        })
+        .collect::<Option<Vec<_>>>()
+        .ok_or(Exceptions::NOT_FOUND)?;
+
+    let check = digits.pop().ok_or(Exceptions::NOT_FOUND)?;
+    if check != checkDigit(&digits) {
+        return Err(Exceptions::CHECKSUM);
+    }
+    Ok(digits.iter().map(|digit| (b'0' + digit) as char).collect())
+}
@@ -0,0 +1,118 @@
+use crate::{common::Result, Exceptions};
+
+use super::FourStateBar;
+
+/// The characters in table order: the row gives the ascenders, the column the descenders
+const ALPHABET: &str = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// The two of four bars of a character with an ascender per row, and with a descender per column
+const PAIRS: [[usize; 2]; 6] = [[2, 3], [1, 3], [1, 2], [0, 3], [0, 2], [0, 1]];
+
+fn indicesOf(contents: &str) -> Result<Vec<usize>> {
+    contents
+        .chars()
+        .map(|c| {
+            ALPHABET.find(c).ok_or_else(|| {
+                Exceptions::illegal_argument_with(format!(
+                    "Requested contents contain illegal character {c}"
+                ))
+            })
+        })
+        .collect()
+}
+
+fn encodeIndex(index: usize) -> [FourStateBar; 4] {
+    let (ascenders, descenders) = (PAIRS[index / 6], PAIRS[index % 6]);
+    std::array::from_fn(|i| FourStateBar::new(ascenders.contains(&i), descenders.contains(&i)))
+}
+
+/**
+ * @return the index of the character in {@link #ALPHABET}
+ */
+fn decodeIndex(bars: &[FourStateBar]) -> Result<usize> {
+    let pairOf = |hasPart: fn(&FourStateBar) -> bool| {
+        let set = (0..4).filter(|i| hasPart(&bars[*i])).collect::<Vec<_>>();
+        PAIRS.iter().position(|pair| pair[..] == set[..])
+    };
+    match (
+        pairOf(FourStateBar::hasAscender),
+        pairOf(FourStateBar::hasDescender),
+    ) {
+        (Some(row), Some(column)) => Ok(row * 6 + column),
+        _ => Err(Exceptions::NOT_FOUND),
+    }
+}
+
+fn decodeCharacters(bars: &[FourStateBar]) -> Result<Vec<usize>> {
+    if !bars.len().is_multiple_of(4) {
+        return Err(Exceptions::NOT_FOUND);
+    }
+    bars.chunks(4).map(decodeIndex).collect()
+}
+
+fn toText(indices: &[usize]) -> String {
+    indices
+        .iter()
+        .map(|index| ALPHABET.as_bytes()[*index] as char)
+        .collect()
+}
+
+/**
+ * The check character sums the rows and the columns of the characters, modulo 6.
+ */
+fn checkCharacter(indices: &[usize]) -> usize {
+    let row = indices.iter().map(|index| index / 6 + 1).sum::<usize>() % 6;
+    let column = indices.iter().map(|index| index % 6 + 1).sum::<usize>() % 6;
+    (row + 5) % 6 * 6 + (column + 5) % 6
+}
+
+/**
+ * Encodes the Royal Mail 4-State Customer Code (RM4SCC): a start bar, the characters, a check
+ * character and a stop bar.
+ *
+ * @param contents digits and upper case letters
+ */
+pub fn encode(contents: &str) -> Result<Vec<FourStateBar>> {
+    let indices = indicesOf(contents)?;
+
+    let mut bars = vec![FourStateBar::Ascender];
+    for index in indices.iter().chain([checkCharacter(&indices)].iter()) {
+        bars.extend(encodeIndex(*index));
+    }
+    bars.push(FourStateBar::Full);
+    Ok(bars)
+}
+
+/**
+ * @return the characters of an RM4SCC symbol, without the check character
+ */
+pub fn decode(bars: &[FourStateBar]) -> Result<String> {
+    if bars.len() < 10
+        || bars.first() != Some(&FourStateBar::Ascender)
+        || bars.last() != Some(&FourStateBar::Full)
+    {
+        return Err(Exceptions::NOT_FOUND);
+    }
+    let mut indices = decodeCharacters(&bars[1..bars.len() - 1])?;
+    let check = indices.pop().ok_or(Exceptions::NOT_FOUND)?;
+    if check != checkCharacter(&indices) {
+        return Err(Exceptions::CHECKSUM);
+    }
+    Ok(toText(&indices))
+}
+
+/**
+ * Encodes a Dutch KIX code: the RM4SCC characters without start and stop bars or check character.
+ *
+ * @param contents digits and upper case letters
+ */
+pub fn encodeKIX(contents: &str) -> Result<Vec<FourStateBar>> {
+    Ok(indicesOf(contents)?
+        .into_iter()
+        .flat_map(encodeIndex)
+        .collect())
+}
+
+pub fn decodeKIX(bars: &[FourStateBar]) -> Result<String> {
+    Ok(toText(&decodeCharacters(bars)?))
+}
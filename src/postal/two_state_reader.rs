@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+
+use crate::{
+    common::Result, point_f, BarcodeFormat, Binarizer, BinaryBitmap, DecodeHintType,
+    DecodeHintValue, DecodingHintDictionary, RXingResult, RXingResultMetadataType,
+    RXingResultMetadataValue, Reader,
+};
+
+use super::{bar_detector, postnet};
+
+/**
+ * <p>Reads the USPS POSTNET and PLANET barcodes, made of tall and short bars.</p>
+ *
+ * <p>Rows crossing the short bars find the bars, which are followed up and down to tell whether
+ * they are tall. Symbols turned upside down are read as well.</p>
+ */
+#[derive(Default)]
+pub struct TwoStateReader;
+
+impl Reader for TwoStateReader {
+    fn decode<B: Binarizer>(&mut self, image: &mut BinaryBitmap<B>) -> Result<RXingResult> {
+        self.decode_with_hints(image, &HashMap::new())
+    }
+
+    fn decode_with_hints<B: Binarizer>(
+        &mut self,
+        image: &mut BinaryBitmap<B>,
+        hints: &DecodingHintDictionary,
+    ) -> Result<RXingResult> {
+        let tryHarder = matches!(
+            hints.get(&DecodeHintType::TRY_HARDER),
+            Some(DecodeHintValue::TryHarder(true))
+        );
+        let mut formats = vec![BarcodeFormat::POSTNET, BarcodeFormat::PLANET];
+        if let Some(DecodeHintValue::PossibleFormats(possibleFormats)) =
+            hints.get(&DecodeHintType::POSSIBLE_FORMATS)
+        {
+            if formats
+                .iter()
+                .any(|format| possibleFormats.contains(format))
+            {
+                formats.retain(|format| possibleFormats.contains(format));
+            }
+        }
+
+        bar_detector::detect(image.get_black_matrix(), tryHarder, |bars, rowNumber| {
+            let (mut tall, upsideDown) = bar_detector::classifyTwoState(bars);
+            if upsideDown {
+                tall.reverse();
+            }
+            formats.iter().find_map(|format| {
+                let text = postnet::decode(&tall, format == &BarcodeFormat::PLANET).ok()?;
+                let (left, right) = (bars[0].left, bars[bars.len() - 1].right);
+                let mut result = RXingResult::new(
+                    &text,
+                    Vec::new(),
+                    vec![
+                        point_f(left as f32, rowNumber as f32),
+                        point_f(right as f32, rowNumber as f32),
+                    ],
+                    *format,
+                );
+                if upsideDown {
+                    result.putMetadata(
+                        RXingResultMetadataType::ORIENTATION,
+                        RXingResultMetadataValue::Orientation(180),
+                    );
+                }
+                Some(result)
+            })
+        })
+    }
+
+    fn reset(&mut self) {
+        // nothing to reset
+    }
+}
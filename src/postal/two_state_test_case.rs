@@ -0,0 +1,110 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    common::bit_matrix_test_case, BarcodeFormat, DecodeHintType, DecodeHintValue, Exceptions,
+    MultiFormatReader, MultiFormatWriter, MultiUseMultiFormatReader, RXingResultMetadataType,
+    RXingResultMetadataValue, Reader, Writer,
+};
+
+use super::postnet;
+
+fn toBars(tall: &[bool]) -> String {
+    tall.iter()
+        .map(|tall| if *tall { '|' } else { '.' })
+        .collect()
+}
+
+/**
+ * Writes the contents, reads them back, and reads them back upside down.
+ */
+fn assertRoundTrip(contents: &str, format: BarcodeFormat) {
+    let mut image = MultiFormatWriter
+        .encode(contents, &format, 300, 60)
+        .expect("must encode");
+    let hints = HashMap::from([(
+        DecodeHintType::POSSIBLE_FORMATS,
+        DecodeHintValue::PossibleFormats(HashSet::from([format])),
+    )]);
+    for upsideDown in [false, true] {
+        if upsideDown {
+            image.rotate180();
+        }
+        let result = MultiFormatReader::default()
+            .decode_with_hints(
+                &mut bit_matrix_test_case::matrix_to_binary_bitmap(&image),
+                &hints,
+            )
+            .expect("must decode");
+        assert_eq!(contents, result.getText());
+        assert_eq!(&format, result.getBarcodeFormat());
+        assert_eq!(
+            upsideDown,
+            result
+                .getRXingResultMetadata()
+                .get(&RXingResultMetadataType::ORIENTATION)
+                == Some(&RXingResultMetadataValue::Orientation(180)),
+            "{contents}"
+        );
+    }
+}
+
+#[test]
+fn testPOSTNET() {
+    // ZIP+4 555551237 with check digit 2
+    let bars = postnet::encode("555551237", false).expect("must encode");
+    assert_eq!(
+        "|.|.|..|.|..|.|..|.|..|.|....||..|.|..||.|...|..|.||",
+        toBars(&bars)
+    );
+    assert_eq!(
+        "555551237",
+        postnet::decode(&bars, false).expect("must decode")
+    );
+
+    assertRoundTrip("12345", BarcodeFormat::POSTNET);
+    assertRoundTrip("555551237", BarcodeFormat::POSTNET);
+    assertRoundTrip("55555123799", BarcodeFormat::POSTNET);
+
+    assert!(postnet::encode("1234", false).is_err());
+    assert!(postnet::encode("1234A", false).is_err());
+}
+
+#[test]
+fn testPLANET() {
+    assert!(postnet::encode("4012345678", true).is_err());
+
+    let bars = postnet::encode("40123456789", true).expect("must encode");
+    // three tall bars per digit
+    assert_eq!(2 + 12 * 5, bars.len());
+    assert_eq!(2 + 12 * 3, bars.iter().filter(|tall| **tall).count());
+    assert!(postnet::decode(&bars, false).is_err());
+
+    assertRoundTrip("40123456789", BarcodeFormat::PLANET);
+    assertRoundTrip("4012345678901", BarcodeFormat::PLANET);
+}
+
+#[test]
+fn testCheckDigit() {
+    let mut bars = postnet::encode("12345", false).expect("must encode");
+    // turn the check digit 5 (01010) into 6 (01100)
+    bars[28] = true;
+    bars[29] = false;
+    assert!(matches!(
+        postnet::decode(&bars, false),
+        Err(Exceptions::ChecksumException(_))
+    ));
+}
+
+#[test]
+fn testNotReadByDefault() {
+    // postal formats are only looked for when POSSIBLE_FORMATS names them
+    let image = MultiFormatWriter
+        .encode("555551237", &BarcodeFormat::POSTNET, 300, 60)
+        .expect("must encode");
+    assert!(MultiFormatReader::default()
+        .decode(&mut bit_matrix_test_case::matrix_to_binary_bitmap(&image))
+        .is_err());
+    assert!(MultiUseMultiFormatReader::default()
+        .decode(&mut bit_matrix_test_case::matrix_to_binary_bitmap(&image))
+        .is_err());
+}
@@ -0,0 +1,72 @@
+use std::collections::HashMap;
+
+use crate::{
+    common::{BitMatrix, Result},
+    BarcodeFormat, EncodingHintDictionary, Exceptions, Writer,
+};
+
+use super::{four_state_writer::renderBars, postnet};
+
+/// Height of tall and of short bars, in units
+const TALL_HEIGHT: u32 = 5;
+const SHORT_HEIGHT: u32 = 2;
+
+/**
+ * Renders the USPS POSTNET and PLANET barcodes as a {@link BitMatrix}. Bars are one module wide
+ * and one module apart, short bars are two fifths of the height of tall ones.
+ */
+#[derive(Default)]
+pub struct TwoStateWriter;
+
+impl Writer for TwoStateWriter {
+    fn encode(
+        &self,
+        contents: &str,
+        format: &BarcodeFormat,
+        width: i32,
+        height: i32,
+    ) -> Result<BitMatrix> {
+        self.encode_with_hints(contents, format, width, height, &HashMap::new())
+    }
+
+    fn encode_with_hints(
+        &self,
+        contents: &str,
+        format: &BarcodeFormat,
+        width: i32,
+        height: i32,
+        _hints: &EncodingHintDictionary,
+    ) -> Result<BitMatrix> {
+        if contents.is_empty() {
+            return Err(Exceptions::illegal_argument_with("Found empty contents"));
+        }
+
+        if width < 0 || height < 0 {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "Requested dimensions can't be negative: {width}x{height}"
+            )));
+        }
+
+        let planet = match format {
+            BarcodeFormat::POSTNET => false,
+            BarcodeFormat::PLANET => true,
+            _ => {
+                return Err(Exceptions::illegal_argument_with(format!(
+                    "Can only encode POSTNET or PLANET, but got {format:?}"
+                )))
+            }
+        };
+
+        let extents = postnet::encode(contents, planet)?
+            .into_iter()
+            .map(|tall| {
+                if tall {
+                    (0, TALL_HEIGHT)
+                } else {
+                    (TALL_HEIGHT - SHORT_HEIGHT, TALL_HEIGHT)
+                }
+            })
+            .collect::<Vec<_>>();
+        renderBars(&extents, TALL_HEIGHT, width as u32, height as u32)
+    }
+}
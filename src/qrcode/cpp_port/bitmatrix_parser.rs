@@ -110,7 +110,53 @@ pub fn ReadFormatInformation(bitMatrix: &BitMatrix, isMicro: bool) -> Result<For
         AppendBit(&mut formatInfoBits2, getBit(bitMatrix, x, 8, None));
     }
 
-    Ok(FormatInformation::DecodeQR(
+    let formatInfo = FormatInformation::DecodeQR(formatInfoBits1 as u32, formatInfoBits2 as u32);
+    // Model 1 symbols mask their format information differently, take it if it matches better
+    let formatInfoModel1 =
+        FormatInformation::DecodeQRModel1(formatInfoBits1 as u32, formatInfoBits2 as u32);
+    if formatInfoModel1.hammingDistance < formatInfo.hammingDistance {
+        return Ok(formatInfoModel1);
+    }
+
+    Ok(formatInfo)
+}
+
+/**
+ * See ISO 23941:2022 7.9.2, the format information is stored next to the finder pattern and
+ * next to the finder sub pattern.
+ */
+pub fn ReadFormatInformationRMQR(bitMatrix: &BitMatrix) -> Result<FormatInformation> {
+    let width = bitMatrix.width();
+    let height = bitMatrix.height();
+    Version::FromDimensionsRMQR(width, height).map_err(|_| Exceptions::FORMAT)?;
+
+    let mut formatInfoBits1 = 0;
+    for y in (1..=3).rev() {
+        AppendBit(&mut formatInfoBits1, getBit(bitMatrix, 11, y, None));
+    }
+    for x in (8..=10).rev() {
+        for y in (1..=5).rev() {
+            AppendBit(&mut formatInfoBits1, getBit(bitMatrix, x, y, None));
+        }
+    }
+
+    let mut formatInfoBits2 = 0;
+    for x in 3..=5 {
+        AppendBit(
+            &mut formatInfoBits2,
+            getBit(bitMatrix, width - x, height - 6, None),
+        );
+    }
+    for x in 6..=8 {
+        for y in 2..=6 {
+            AppendBit(
+                &mut formatInfoBits2,
+                getBit(bitMatrix, width - x, height - y, None),
+            );
+        }
+    }
+
+    Ok(FormatInformation::DecodeRMQR(
         formatInfoBits1 as u32,
         formatInfoBits2 as u32,
     ))
@@ -171,6 +217,34 @@ pub fn ReadQRCodewords(
     Ok(result.iter().copied().map(|x| x as u8).collect())
 }
 
+/**
+ * Model 1 symbols place their codewords in blocks of modules, see
+ * Version::buildCodewordModulesModel1.
+ */
+pub fn ReadQRCodewordsModel1(
+    bitMatrix: &BitMatrix,
+    version: VersionRef,
+    formatInfo: &FormatInformation,
+) -> Result<Vec<u8>> {
+    let mut result = Vec::with_capacity(version.getTotalCodewords() as usize);
+    for modules in version.buildCodewordModulesModel1() {
+        let mut currentByte = 0;
+        for (x, y) in modules {
+            AppendBit(
+                &mut currentByte,
+                GetDataMaskBit(formatInfo.data_mask as u32, x, y, None)?
+                    != getBit(bitMatrix, x, y, Some(formatInfo.isMirrored)),
+            );
+        }
+        result.push(currentByte as u8);
+    }
+    if (result.len()) != version.getTotalCodewords() as usize {
+        return Err(Exceptions::FORMAT);
+    }
+
+    Ok(result)
+}
+
 pub fn ReadMQRCodewords(
     bitMatrix: &BitMatrix,
     version: VersionRef,
@@ -217,11 +291,13 @@ pub fn ReadMQRCodewords(
                     );
                     bitsRead += 1;
                     // If we've made a whole byte, save it off; save early if 2x2 data block.
-                    if bitsRead == 8
-                        || (bitsRead == 4 && hasD4mBlock && (result.len()) == d4mBlockIndex - 1)
-                    {
+                    if bitsRead == 8 {
                         result.push(std::mem::take(&mut currentByte));
                         bitsRead = 0;
+                    } else if bitsRead == 4 && hasD4mBlock && (result.len()) == d4mBlockIndex - 1 {
+                        // The 4 bit codeword is the high nibble of its byte, see ISO 18004:2015 7.7.1
+                        result.push(std::mem::take(&mut currentByte) << 4);
+                        bitsRead = 0;
                     }
                 }
             }
@@ -237,17 +313,68 @@ pub fn ReadMQRCodewords(
     Ok(result.iter().copied().map(|x| x as u8).collect())
 }
 
+pub fn ReadRMQRCodewords(
+    bitMatrix: &BitMatrix,
+    version: VersionRef,
+    formatInfo: &FormatInformation,
+) -> Result<Vec<u8>> {
+    let functionPattern = version.buildFunctionPattern()?;
+
+    let mut result = Vec::with_capacity(version.getTotalCodewords() as usize);
+    let mut currentByte = 0;
+    let mut readingUp = true;
+    let mut bitsRead = 0;
+    let width = bitMatrix.width();
+    let height = bitMatrix.height();
+    // the right edge is a timing pattern, so start one column further left
+    let mut x = width as i32 - 2;
+    while x > 0 {
+        for row in 0..height {
+            let y = if readingUp { height - 1 - row } else { row };
+            for col in 0..2 {
+                let xx = (x - col) as u32;
+                if !functionPattern.get(xx, y) {
+                    AppendBit(
+                        &mut currentByte,
+                        GetDataMaskBit(formatInfo.data_mask as u32, xx, y, None)?
+                            != getBit(bitMatrix, xx, y, None),
+                    );
+                    bitsRead += 1;
+                    if bitsRead % 8 == 0 {
+                        result.push(std::mem::take(&mut currentByte));
+                    }
+                }
+            }
+        }
+        readingUp = !readingUp; // switch directions
+
+        x -= 2;
+    }
+    // the remaining bits are remainder bits which carry no data
+    if (result.len()) != version.getTotalCodewords() as usize {
+        return Err(Exceptions::FORMAT);
+    }
+
+    Ok(result.iter().copied().map(|x| x as u8).collect())
+}
+
 pub fn ReadCodewords(
     bitMatrix: &BitMatrix,
     version: VersionRef,
     formatInfo: &FormatInformation,
 ) -> Result<Vec<u8>> {
+    if version.isRMQRCode() {
+        return ReadRMQRCodewords(bitMatrix, version, formatInfo);
+    }
+
     if !hasValidDimension(bitMatrix, version.isMicroQRCode()) {
         return Err(Exceptions::FORMAT);
     }
 
     if version.isMicroQRCode() {
         ReadMQRCodewords(bitMatrix, version, formatInfo)
+    } else if version.isModel1() {
+        ReadQRCodewordsModel1(bitMatrix, version, formatInfo)
     } else {
         ReadQRCodewords(bitMatrix, version, formatInfo)
     }
@@ -4,6 +4,8 @@
 // */
 // // SPDX-License-Identifier: Apache-2.0
 
+use std::rc::Rc;
+
 use crate::common::cpp_essentials::{DecoderResult, StructuredAppendInfo};
 use crate::common::reedsolomon::{
     get_predefined_genericgf, PredefinedGenericGF, ReedSolomonDecoder,
@@ -12,9 +14,11 @@ use crate::common::{
     AIFlag, BitMatrix, BitSource, CharacterSet, ECIStringBuilder, Eci, Result, SymbologyIdentifier,
 };
 use crate::qrcode::cpp_port::bitmatrix_parser::{
-    ReadCodewords, ReadFormatInformation, ReadVersion,
+    ReadCodewords, ReadFormatInformation, ReadFormatInformationRMQR, ReadVersion,
+};
+use crate::qrcode::decoder::{
+    DataBlock, ErrorCorrectionLevel, FormatInformation, Mode, Version, VersionRef,
 };
-use crate::qrcode::decoder::{DataBlock, ErrorCorrectionLevel, Mode, Version};
 use crate::Exceptions;
 
 /**
@@ -303,6 +307,8 @@ pub fn DecodeBitStream(
         while !IsEndOfStream(&mut bits, version)? {
             let mode: Mode = if modeBitLength == 0 {
                 Mode::NUMERIC // MicroQRCode version 1 is always NUMERIC and modeBitLength is 0
+            } else if version.isRMQRCode() {
+                Mode::CodecModeForBitsRMQR(bits.readBits(modeBitLength as usize)?)?
             } else {
                 Mode::CodecModeForBits(
                     bits.readBits(modeBitLength as usize)?,
@@ -390,16 +396,56 @@ pub fn DecodeBitStream(
         .withStructuredAppend(structuredAppend))
 }
 
+/**
+ * @return the decoded symbol, its extra telling whether it is a QR Code Model 1 symbol
+ */
 pub fn Decode(bits: &BitMatrix) -> Result<DecoderResult<bool>> {
-    let Ok(pversion) = ReadVersion(bits) else {
+    // rMQR Codes are the only non-square symbols
+    if bits.width() != bits.height() {
+        return DecodeRMQR(bits);
+    }
+
+    let isMicro = bits.height() < Version::DimensionOfVersion(1, false);
+    let Ok(formatInfo) = ReadFormatInformation(bits, isMicro) else {
+        return Err(Exceptions::format_with("Invalid format information"));
+    };
+
+    // Model 1 symbols tell themselves by their format information, they have no version information
+    let pversion = if formatInfo.isModel1() {
+        Version::FromDimensionModel1(bits.height())
+    } else {
+        ReadVersion(bits)
+    };
+    let Ok(version) = pversion else {
         return Err(Exceptions::format_with("Invalid version"));
     };
-    let version = pversion;
 
-    let Ok(formatInfo) = ReadFormatInformation(bits, version.isMicroQRCode()) else {
-        return Err(Exceptions::format_with("Invalid format information"));
+    DecodeWithFormatInformation(bits, version, formatInfo)
+}
+
+fn DecodeRMQR(bits: &BitMatrix) -> Result<DecoderResult<bool>> {
+    let Ok(version) = Version::FromDimensionsRMQR(bits.width(), bits.height()) else {
+        return Err(Exceptions::format_with("Invalid version"));
+    };
+
+    let formatInfo = match ReadFormatInformationRMQR(bits) {
+        Ok(formatInfo) if formatInfo.isValid() => formatInfo,
+        _ => return Err(Exceptions::format_with("Invalid format information")),
     };
+    if formatInfo.microVersion != version.getVersionNumber() {
+        return Err(Exceptions::format_with(
+            "Version does not match symbol size",
+        ));
+    }
 
+    DecodeWithFormatInformation(bits, version, formatInfo)
+}
+
+fn DecodeWithFormatInformation(
+    bits: &BitMatrix,
+    version: VersionRef,
+    formatInfo: FormatInformation,
+) -> Result<DecoderResult<bool>> {
     // Read codewords
     let codewords = ReadCodewords(bits, version, &formatInfo)?;
     if codewords.is_empty() {
@@ -437,7 +483,8 @@ pub fn Decode(bits: &BitMatrix) -> Result<DecoderResult<bool>> {
     // Decode the contents of that stream of bytes
     Ok(
         DecodeBitStream(&resultBytes, version, formatInfo.error_correction_level)?
-            .withIsMirrored(formatInfo.isMirrored),
+            .withIsMirrored(formatInfo.isMirrored)
+            .withExtra(Rc::new(formatInfo.isModel1())),
     )
 }
 
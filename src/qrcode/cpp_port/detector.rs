@@ -567,9 +567,12 @@ pub fn SampleQR(image: &BitMatrix, fp: &FinderPatternSet) -> Result<QRCodeDetect
         Quadrilateral::from([fp.tl.p, fp.tr.p, br.p, fp.bl.p]),
     )?;
 
-    if dimension >= Version::DimensionOfVersion(7, false) as i32 {
-        let version = ReadVersion(image, dimension as u32, mod2Pix);
-
+    let version = (dimension >= Version::DimensionOfVersion(7, false) as i32)
+        .then(|| ReadVersion(image, dimension as u32, mod2Pix));
+    // Model 1 symbols have no version information, so they are sampled like the smaller versions
+    let isModel1 =
+        matches!(version, Some(Err(_))) && Version::FromDimensionModel1(dimension as u32).is_ok();
+    if let (Some(version), false) = (version, isModel1) {
         // if the version bits are garbage -> discard the detection
         if version.is_err()
             || (version.as_ref().unwrap().getDimensionForVersion() as i32 - dimension).abs() > 8
@@ -1065,3 +1068,158 @@ pub fn SampleMQR(image: &BitMatrix, fp: ConcentricPattern) -> Result<QRCodeDetec
 
     //  SampleGrid(image, dim, dim, bestPT)
 }
+
+pub fn DetectPureRMQR(image: &BitMatrix) -> Result<QRCodeDetectorResult> {
+    type Pattern = [PatternType; 5];
+
+    let (found, left, top, width, height) = image.findBoundingBox(0, 0, 0, 0, 7);
+
+    // rMQR symbols are at least twice as wide as they are tall
+    if !found || width < 2 * height {
+        return Err(Exceptions::NOT_FOUND);
+    }
+    let right = left + width - 1;
+    let bottom = top + height - 1;
+
+    // allow corners be moved one pixel inside to accommodate for possible aliasing artifacts
+    let diagonal: Pattern = EdgeTracer::new(image, point_i(left, top), point_i(1, 1))
+        .readPatternFromBlack(1, None)
+        .ok_or(Exceptions::ILLEGAL_STATE)?;
+    let diag_hld = diagonal.to_vec().into();
+    let view = PatternView::new(&diag_hld);
+    if !(IsPattern::<E2E, 5, 7, false>(&view, &PATTERN, None, 0.0, 0.0) != 0.0) {
+        return Err(Exceptions::NOT_FOUND);
+    }
+
+    let fpWidth = diagonal.into_iter().sum::<u16>();
+    let estimatedModuleSize: f32 = (fpWidth as f32) / 7.0;
+    let dimW = (width as f32 / estimatedModuleSize).round() as u32;
+    let dimH = (height as f32 / estimatedModuleSize).round() as u32;
+
+    if Version::FromDimensionsRMQR(dimW, dimH).is_err() {
+        return Err(Exceptions::NOT_FOUND);
+    }
+
+    // the width spans more modules than the finder pattern, so it gives the more precise module size
+    let moduleSize = width as f32 / dimW as f32;
+    if !image.is_in(point_f(
+        left as f32 + moduleSize / 2.0 + (dimW - 1) as f32 * moduleSize,
+        top as f32 + moduleSize / 2.0 + (dimH - 1) as f32 * moduleSize,
+    )) {
+        return Err(Exceptions::NOT_FOUND);
+    }
+
+    // Now just read off the bits (this is a crop + subsample)
+    Ok(QRCodeDetectorResult::new(
+        image.Deflate(
+            dimW,
+            dimH,
+            top as f32 + moduleSize / 2.0,
+            left as f32 + moduleSize / 2.0,
+            moduleSize,
+        )?,
+        vec![
+            point_i(left, top),
+            point_i(right, top),
+            point_i(right, bottom),
+            point_i(left, bottom),
+        ],
+    ))
+}
+
+pub fn SampleRMQR(image: &BitMatrix, fp: ConcentricPattern) -> Result<QRCodeDetectorResult> {
+    let Some(fpQuad) = FindConcentricPatternCorners(image, fp.p, fp.size, 2) else {
+        return Err(Exceptions::NOT_FOUND);
+    };
+
+    let srcQuad = Quadrilateral::rectangle(7, 7, Some(0.5));
+
+    // the format information next to the finder pattern, most significant bit first
+    let mut formatInfoCoords = vec![point_i(11, 3), point_i(11, 2), point_i(11, 1)];
+    for x in (8..=10).rev() {
+        for y in (1..=5).rev() {
+            formatInfoCoords.push(point_i(x, y));
+        }
+    }
+
+    let mut bestFI = FormatInformation::default();
+    let mut bestPT = PerspectiveTransform::quadrilateralToQuadrilateral(
+        srcQuad,
+        fpQuad.rotated_corners(Some(0), None),
+    )?;
+    let mut bestQuad = fpQuad;
+
+    for i in 0..4 {
+        let quad = fpQuad.rotated_corners(Some(i), None);
+        let mod2Pix = PerspectiveTransform::quadrilateralToQuadrilateral(srcQuad, quad)?;
+
+        let check = |p: Point, dark: bool| {
+            let p = mod2Pix.transform_point(Point::centered(p));
+            image.is_in(p) && image.get_point(p) == dark
+        };
+
+        // check the start of the timing pattern along the top edge
+        if !check(point_i(8, 0), true)
+            || !check(point_i(9, 0), false)
+            || !check(point_i(10, 0), true)
+        {
+            continue;
+        }
+
+        let mut formatInfoBits = 0;
+        for coord in &formatInfoCoords {
+            AppendBit(
+                &mut formatInfoBits,
+                image.get_point(mod2Pix.transform_point(Point::centered(*coord))),
+            );
+        }
+
+        let fi = FormatInformation::DecodeRMQR(formatInfoBits as u32, 0);
+        if fi.hammingDistance < bestFI.hammingDistance {
+            bestFI = fi;
+            bestPT = mod2Pix;
+            bestQuad = quad;
+        }
+    }
+
+    if !bestFI.isValid() {
+        return Err(Exceptions::NOT_FOUND);
+    }
+
+    let (width, height) = Version::DimensionOfVersionRMQR(bestFI.microVersion);
+
+    // The finder pattern alone is too small to extrapolate over the full width of the symbol,
+    // so look for the finder sub pattern in the bottom right corner to anchor the far end.
+    let subPatternCenter = point_f(width as f32 - 2.5, height as f32 - 2.5);
+    let expected = bestPT.transform_point(subPatternCenter);
+    if image.is_in(expected) {
+        const SUB_PATTERN: FixedPattern<5, 5, false> = FixedPattern::new([1, 1, 1, 1, 1]);
+        let moduleSize = fp.size as f32 / 7.0;
+        if let Some(subPattern) = LocateConcentricPattern::<E2E, 5, 5>(
+            image,
+            &SUB_PATTERN.into(),
+            expected,
+            (moduleSize * 5.0) as i32,
+        ) {
+            let src =
+                Quadrilateral::new(srcQuad.0[0], srcQuad.0[1], subPatternCenter, srcQuad.0[3]);
+            let dst = Quadrilateral::new(bestQuad.0[0], bestQuad.0[1], subPattern.p, bestQuad.0[3]);
+            if let Ok(pt) = PerspectiveTransform::quadrilateralToQuadrilateral(src, dst) {
+                bestPT = pt;
+            }
+        }
+    }
+
+    let grid_sampler = DefaultGridSampler;
+    let (sample, rps) = grid_sampler.sample_grid(
+        image,
+        width,
+        height,
+        &[SamplerControl {
+            p1: point_i(width, height),
+            p0: point_i(0, 0),
+            transform: bestPT,
+        }],
+    )?;
+    Ok(QRCodeDetectorResult::new(sample, rps.to_vec()))
+}
@@ -118,17 +118,20 @@
 // } // namespace ZXing::QRCode
 
 use crate::{
-    common::{cpp_essentials::ConcentricPattern, DetectorRXingResult},
+    common::{
+        cpp_essentials::{ConcentricPattern, DecoderResult},
+        DetectorRXingResult,
+    },
     multi::MultipleBarcodeReader,
-    BarcodeFormat, DecodeHintType, DecodeHintValue, DecodingHintDictionary, Exceptions,
-    RXingResult, Reader,
+    BarcodeFormat, DecodeHintType, DecodeHintValue, DecodingHintDictionary, Exceptions, Point,
+    RXingResult, RXingResultMetadataType, RXingResultMetadataValue, Reader,
 };
 
 use super::{
     decoder::Decode,
     detector::{
-        DetectPureMQR, DetectPureQR, FindFinderPatterns, GenerateFinderPatternSets, SampleMQR,
-        SampleQR,
+        DetectPureMQR, DetectPureQR, DetectPureRMQR, FindFinderPatterns, GenerateFinderPatternSets,
+        SampleMQR, SampleQR, SampleRMQR,
     },
 };
 
@@ -179,10 +182,15 @@ impl Reader for QrReader {
             if formats.contains(&BarcodeFormat::MICRO_QR_CODE) && detectorResult.is_err() {
                 detectorResult = DetectPureMQR(binImg);
             }
+            if formats.contains(&BarcodeFormat::RECTANGULAR_MICRO_QR_CODE)
+                && detectorResult.is_err()
+            {
+                detectorResult = DetectPureRMQR(binImg);
+            }
         }
 
         if detectorResult.is_err() {
-            for decode_function in [DetectPureQR, DetectPureMQR] {
+            for decode_function in [DetectPureQR, DetectPureMQR, DetectPureRMQR] {
                 detectorResult = decode_function(binImg);
                 if detectorResult.is_ok() {
                     break;
@@ -205,15 +213,23 @@ impl Reader for QrReader {
         let decoderResult = Decode(detectorResult.getBits())?;
         let position = detectorResult.getPoints();
 
-        Ok(RXingResult::with_decoder_result(
-            decoderResult,
-            position,
-            if detectorResult.getBits().width() < 21 {
-                BarcodeFormat::MICRO_QR_CODE
+        Ok(
+            if detectorResult.getBits().width() != detectorResult.getBits().height() {
+                RXingResult::with_decoder_result(
+                    decoderResult,
+                    position,
+                    BarcodeFormat::RECTANGULAR_MICRO_QR_CODE,
+                )
+            } else if detectorResult.getBits().width() < 21 {
+                RXingResult::with_decoder_result(
+                    decoderResult,
+                    position,
+                    BarcodeFormat::MICRO_QR_CODE,
+                )
             } else {
-                BarcodeFormat::QR_CODE
+                Self::qrCodeResult(decoderResult, position)
             },
-        ))
+        )
 
         // Ok(RXingResult::new(
         //     &decoderResult.content().to_string(),
@@ -248,6 +264,20 @@ impl MultipleBarcodeReader for QrReader {
 }
 
 impl QrReader {
+    /**
+     * Builds the result of a QR Code, telling whether it is a Model 1 or a Model 2 symbol.
+     */
+    fn qrCodeResult(decoderResult: DecoderResult<bool>, position: &[Point]) -> RXingResult {
+        let model = if *decoderResult.extra() { 1 } else { 2 };
+        let mut result =
+            RXingResult::with_decoder_result(decoderResult, position, BarcodeFormat::QR_CODE);
+        result.putMetadata(
+            RXingResultMetadataType::QR_MODEL,
+            RXingResultMetadataValue::QrModel(model),
+        );
+        result
+    }
+
     fn decode_set_number_with_hints<B: crate::Binarizer>(
         &mut self,
         image: &mut crate::BinaryBitmap<B>,
@@ -276,16 +306,18 @@ impl QrReader {
         let mut usedFPs: Vec<ConcentricPattern> = Vec::new();
         let mut results: Vec<RXingResult> = Vec::new();
 
-        let (check_qr, check_mqr) = if let Some(DecodeHintValue::PossibleFormats(formats)) =
-            hints.get(&DecodeHintType::POSSIBLE_FORMATS)
-        {
-            (
-                formats.contains(&BarcodeFormat::QR_CODE),
-                formats.contains(&BarcodeFormat::MICRO_QR_CODE),
-            )
-        } else {
-            (true, true)
-        };
+        let (check_qr, check_mqr, check_rmqr) =
+            if let Some(DecodeHintValue::PossibleFormats(formats)) =
+                hints.get(&DecodeHintType::POSSIBLE_FORMATS)
+            {
+                (
+                    formats.contains(&BarcodeFormat::QR_CODE),
+                    formats.contains(&BarcodeFormat::MICRO_QR_CODE),
+                    formats.contains(&BarcodeFormat::RECTANGULAR_MICRO_QR_CODE),
+                )
+            } else {
+                (true, true, true)
+            };
 
         if check_qr {
             // if (_hints.hasFormat(BarcodeFormat::QRCode)) {
@@ -320,11 +352,7 @@ impl QrReader {
                             //     position.to_vec(),
                             //     BarcodeFormat::QR_CODE,
                             // ));
-                            results.push(RXingResult::with_decoder_result(
-                                decoderResult,
-                                position,
-                                BarcodeFormat::QR_CODE,
-                            ));
+                            results.push(Self::qrCodeResult(decoderResult, position));
                             // results.emplace_back(std::move(decoderResult), std::move(position), BarcodeFormat::QR_CODE);
 
                             if maxSymbols != 0 && (results.len() as u32) == maxSymbols {
@@ -337,7 +365,7 @@ impl QrReader {
         }
         if check_mqr && !(maxSymbols != 0 && (results.len() as u32) == maxSymbols) {
             // if (_hints.hasFormat(BarcodeFormat::MicroQRCode) && !(maxSymbols && Size(results) == maxSymbols)) {
-            for fp in allFPs {
+            for fp in allFPs.iter().copied() {
                 // for (const auto& fp : allFPs) {
                 if usedFPs.contains(&fp) {
                     continue;
@@ -350,6 +378,7 @@ impl QrReader {
                     let position = detectorResult.getPoints();
                     if let Ok(decoderResult) = decoderResult {
                         if decoderResult.isValid() {
+                            usedFPs.push(fp);
                             results.push(RXingResult::with_decoder_result(
                                 decoderResult,
                                 position,
@@ -371,6 +400,30 @@ impl QrReader {
                 }
             }
         }
+        if check_rmqr && !(maxSymbols != 0 && (results.len() as u32) == maxSymbols) {
+            for fp in allFPs {
+                if usedFPs.contains(&fp) {
+                    continue;
+                }
+
+                if let Ok(detectorResult) = SampleRMQR(binImg, fp) {
+                    let position = detectorResult.getPoints();
+                    if let Ok(decoderResult) = Decode(detectorResult.getBits()) {
+                        if decoderResult.isValid() {
+                            results.push(RXingResult::with_decoder_result(
+                                decoderResult,
+                                position,
+                                BarcodeFormat::RECTANGULAR_MICRO_QR_CODE,
+                            ));
+
+                            if maxSymbols != 0 && (results.len() as u32) == maxSymbols {
+                                break;
+                            }
+                        }
+                    }
+                }
+            }
+        }
 
         Ok(results)
     }
@@ -16,7 +16,7 @@
 
 use crate::{
     common::Result,
-    qrcode::decoder::{ErrorCorrectionLevel, Version},
+    qrcode::decoder::{ErrorCorrectionLevel, Version, RMQR_SIZES, RMQR_VERSIONS},
 };
 
 /**
@@ -88,3 +88,65 @@ fn doTestVersion(expectedVersion: u32, mask: u32) {
     assert!(version.is_ok());
     assert_eq!(expectedVersion, version.unwrap().getVersionNumber());
 }
+
+#[test]
+fn testRMQRVersions() {
+    // data codewords and blocks of every symbol at the levels M and H, ISO/IEC 23941:2022 Table 8
+    const DATA_M: [u32; 32] = [
+        6, 12, 20, 28, 44, 12, 21, 31, 42, 63, 7, 19, 31, 43, 57, 84, 12, 27, 38, 53, 73, 112, 33,
+        48, 67, 88, 127, 39, 56, 78, 100, 152,
+    ];
+    const DATA_H: [u32; 32] = [
+        3, 7, 10, 14, 24, 7, 11, 17, 22, 33, 5, 11, 15, 23, 29, 42, 7, 13, 20, 29, 35, 58, 15, 26,
+        31, 48, 69, 21, 28, 38, 56, 76,
+    ];
+    const BLOCKS_M: [u32; 32] = [
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 2, 1, 1, 1, 1, 2, 2, 1, 1, 1, 2, 2, 3, 1, 1, 2, 2, 3, 1, 2, 2,
+        3, 4,
+    ];
+    const BLOCKS_H: [u32; 32] = [
+        1, 1, 1, 1, 2, 1, 1, 2, 2, 3, 1, 1, 2, 2, 2, 3, 1, 1, 2, 2, 3, 4, 2, 2, 3, 4, 5, 2, 2, 3,
+        4, 6,
+    ];
+
+    for (i, version) in RMQR_VERSIONS.iter().enumerate() {
+        let (width, height) = RMQR_SIZES[i];
+        let functionPattern = version.buildFunctionPattern().expect("function pattern");
+        let functionModules = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .filter(|(x, y)| functionPattern.get(*x, *y))
+            .count() as u32;
+        // the modules left over are the codewords and up to 7 remainder bits
+        assert_eq!(
+            (width * height - functionModules) / 8,
+            version.getTotalCodewords(),
+            "R{height}x{width}"
+        );
+
+        for (ecLevel, data, blocks) in [
+            (ErrorCorrectionLevel::M, DATA_M[i], BLOCKS_M[i]),
+            (ErrorCorrectionLevel::H, DATA_H[i], BLOCKS_H[i]),
+        ] {
+            let ecBlocks = version.getECBlocksForLevel(ecLevel);
+            assert_eq!(
+                blocks,
+                ecBlocks.getNumBlocks(),
+                "R{height}x{width}-{ecLevel:?}"
+            );
+            assert_eq!(
+                data,
+                ecBlocks
+                    .getECBlocks()
+                    .iter()
+                    .map(|ecb| ecb.getCount() * ecb.getDataCodewords())
+                    .sum::<u32>(),
+                "R{height}x{width}-{ecLevel:?}"
+            );
+            assert_eq!(
+                version.getTotalCodewords(),
+                data + ecBlocks.getTotalECCodewords(),
+                "R{height}x{width}-{ecLevel:?}"
+            );
+        }
+    }
+}
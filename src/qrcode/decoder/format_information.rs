@@ -20,6 +20,18 @@ use super::ErrorCorrectionLevel;
 
 pub const FORMAT_INFO_MASK_QR: u32 = 0x5412;
 
+/**
+ * QR Code Model 1 symbols mask their format information with this pattern instead.
+ */
+pub const FORMAT_INFO_MASK_QR_MODEL1: u32 = 0x2825;
+
+/**
+ * See ISO 23941:2022 7.9.2, the rMQR format information is a BCH (18,6) code which is masked
+ * differently for the copies next to the finder pattern and next to the finder sub pattern.
+ */
+pub const FORMAT_INFO_POLY_RMQR: u32 = 0x1F25;
+pub const FORMAT_INFO_MASKS_RMQR: [u32; 2] = [0x1FAB2, 0x20A7B];
+
 /**
  * See ISO 18004:2006, Annex C, Table C.1
  */
@@ -73,6 +85,8 @@ pub struct FormatInformation {
     pub data_mask: u8,
     pub microVersion: u32,
     pub isMirrored: bool,
+    /// the mask applied on top of the one of the lookup table
+    pub mask: u32,
 
     pub index: u8,     // = 255;
     pub bitsIndex: u8, // = 255;
@@ -86,6 +100,7 @@ impl Default for FormatInformation {
             data_mask: Default::default(),
             microVersion: 0,
             isMirrored: false,
+            mask: 0,
             index: 255,
             bitsIndex: 255,
         }
@@ -104,6 +119,7 @@ impl FormatInformation {
             error_correction_level: errorCorrectionLevel,
             data_mask: dataMask,
             isMirrored: false,
+            mask: 0,
             index: 255,
             bitsIndex: 255,
         })
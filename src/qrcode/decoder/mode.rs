@@ -123,14 +123,18 @@ impl Mode {
     }
 
     pub const fn get_terminator_bit_length(version: &Version) -> u8 {
-        (if version.isMicroQRCode() {
+        (if version.isRMQRCode() {
+            3
+        } else if version.isMicroQRCode() {
             version.getVersionNumber() * 2 + 1
         } else {
             4
         }) as u8
     }
     pub const fn get_codec_mode_bits_length(version: &Version) -> u8 {
-        (if version.isMicroQRCode() {
+        (if version.isRMQRCode() {
+            3
+        } else if version.isMicroQRCode() {
             version.getVersionNumber() - 1
         } else {
             4
@@ -161,6 +165,50 @@ impl Mode {
         Err(Exceptions::format_with("Invalid codec mode"))
     }
 
+    /**
+     * @param bits three bits encoding an rMQR Code data mode
+     * @return Mode encoded by these bits
+     * @throws FormatError if bits do not correspond to a known mode
+     *
+     * See ISO 23941:2022 7.3 Table 3
+     */
+    pub fn CodecModeForBitsRMQR(bits: u32) -> Result<Self> {
+        const BITS_2_MODE: [Mode; 8] = [
+            Mode::TERMINATOR,
+            Mode::NUMERIC,
+            Mode::ALPHANUMERIC,
+            Mode::BYTE,
+            Mode::KANJI,
+            Mode::FNC1_FIRST_POSITION,
+            Mode::FNC1_SECOND_POSITION,
+            Mode::ECI,
+        ];
+
+        BITS_2_MODE
+            .get(bits as usize)
+            .copied()
+            .ok_or(Exceptions::format_with("Invalid codec mode"))
+    }
+
+    /**
+     * @return the three bit mode indicator of this Mode in an rMQR Code
+     */
+    pub fn getBitsRMQR(&self) -> Result<u8> {
+        match self {
+            Mode::TERMINATOR => Ok(0b000),
+            Mode::NUMERIC => Ok(0b001),
+            Mode::ALPHANUMERIC => Ok(0b010),
+            Mode::BYTE => Ok(0b011),
+            Mode::KANJI => Ok(0b100),
+            Mode::FNC1_FIRST_POSITION => Ok(0b101),
+            Mode::FNC1_SECOND_POSITION => Ok(0b110),
+            Mode::ECI => Ok(0b111),
+            _ => Err(Exceptions::illegal_argument_with(format!(
+                "{self:?} is not available in rMQR"
+            ))),
+        }
+    }
+
     /**
      * @param version version in question
      * @return number of bits used, in this QR Code symbol {@link Version}, to encode the
@@ -168,6 +216,32 @@ impl Mode {
      */
     pub fn CharacterCountBits(&self, version: &Version) -> u32 {
         let number = version.getVersionNumber() as usize;
+        if version.isRMQRCode() {
+            // See ISO 23941:2022 7.4.1 Table 3
+            const NUMERIC: [u32; 32] = [
+                4, 5, 6, 7, 7, 5, 6, 7, 7, 8, 4, 6, 7, 7, 8, 8, 5, 6, 7, 7, 8, 8, 7, 7, 8, 8, 9, 7,
+                8, 8, 8, 9,
+            ];
+            const ALPHANUMERIC: [u32; 32] = [
+                3, 5, 5, 6, 6, 5, 5, 6, 6, 7, 4, 5, 6, 6, 7, 7, 5, 6, 6, 7, 7, 8, 6, 7, 7, 7, 8, 6,
+                7, 7, 8, 8,
+            ];
+            const BYTE: [u32; 32] = [
+                3, 4, 5, 5, 6, 4, 5, 5, 6, 6, 3, 5, 5, 6, 6, 7, 4, 5, 6, 6, 7, 7, 6, 6, 7, 7, 7, 6,
+                6, 7, 7, 8,
+            ];
+            const KANJI: [u32; 32] = [
+                2, 3, 4, 5, 5, 3, 4, 5, 5, 6, 2, 4, 5, 5, 6, 6, 3, 5, 5, 6, 6, 7, 5, 5, 6, 6, 7, 5,
+                6, 6, 6, 7,
+            ];
+            return match self {
+                Mode::NUMERIC => NUMERIC[number - 1],
+                Mode::ALPHANUMERIC => ALPHANUMERIC[number - 1],
+                Mode::BYTE => BYTE[number - 1],
+                Mode::KANJI => KANJI[number - 1],
+                _ => 0,
+            };
+        }
         if version.isMicroQRCode() {
             match self {
 		 Mode::NUMERIC=>      return [3, 4, 5, 6][number - 1],
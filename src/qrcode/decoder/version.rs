@@ -29,6 +29,47 @@ pub type VersionRef = &'static Version;
 
 pub static VERSIONS: Lazy<Vec<Version>> = Lazy::new(Version::buildVersions);
 pub static MICRO_VERSIONS: Lazy<Vec<Version>> = Lazy::new(Version::build_micro_versions);
+pub static RMQR_VERSIONS: Lazy<Vec<Version>> = Lazy::new(Version::build_rmqr_versions);
+pub static MODEL1_VERSIONS: Lazy<Vec<Version>> = Lazy::new(Version::build_model1_versions);
+
+/**
+ * See ISO 23941:2022 Table 1.
+ * Element i holds the width and height in modules of rMQR version i + 1 (R7x43 to R17x139).
+ */
+pub const RMQR_SIZES: [(u32, u32); 32] = [
+    (43, 7),
+    (59, 7),
+    (77, 7),
+    (99, 7),
+    (139, 7),
+    (43, 9),
+    (59, 9),
+    (77, 9),
+    (99, 9),
+    (139, 9),
+    (27, 11),
+    (43, 11),
+    (59, 11),
+    (77, 11),
+    (99, 11),
+    (139, 11),
+    (27, 13),
+    (43, 13),
+    (59, 13),
+    (77, 13),
+    (99, 13),
+    (139, 13),
+    (43, 15),
+    (59, 15),
+    (77, 15),
+    (99, 15),
+    (139, 15),
+    (43, 17),
+    (59, 17),
+    (77, 17),
+    (99, 17),
+    (139, 17),
+];
 
 /**
  * See ISO 18004:2006 Annex D.
@@ -55,6 +96,8 @@ pub struct Version {
     ecBlocks: Vec<ECBlocks>,
     totalCodewords: u32,
     pub(crate) is_micro: bool,
+    pub(crate) is_rmqr: bool,
+    pub(crate) is_model1: bool,
 }
 impl Version {
     fn new(versionNumber: u32, alignmentPatternCenters: Vec<u32>, ecBlocks: [ECBlocks; 4]) -> Self {
@@ -73,6 +116,8 @@ impl Version {
             ecBlocks: ecBlocks.to_vec(),
             totalCodewords: total,
             is_micro: false,
+            is_rmqr: false,
+            is_model1: false,
         }
     }
 
@@ -92,6 +137,37 @@ impl Version {
             ecBlocks,
             totalCodewords: total,
             is_micro: true,
+            is_rmqr: false,
+            is_model1: false,
+        }
+    }
+
+    fn new_rmqr(
+        versionNumber: u32,
+        alignmentPatternCenters: Vec<u32>,
+        ecBlocks: [ECBlocks; 2],
+    ) -> Self {
+        let mut total = 0;
+        let ecCodewords = ecBlocks[0].getECCodewordsPerBlock();
+        for ecb in ecBlocks[0].getECBlocks() {
+            total += ecb.getCount() * (ecb.getDataCodewords() + ecCodewords);
+        }
+
+        Self {
+            versionNumber,
+            alignmentPatternCenters,
+            ecBlocks: ecBlocks.to_vec(),
+            totalCodewords: total,
+            is_micro: false,
+            is_rmqr: true,
+            is_model1: false,
+        }
+    }
+
+    fn new_model1(versionNumber: u32, ecBlocks: [ECBlocks; 4]) -> Self {
+        Self {
+            is_model1: true,
+            ..Self::new(versionNumber, Vec::default(), ecBlocks)
         }
     }
 
@@ -113,6 +189,10 @@ impl Version {
     }
 
     pub fn getECBlocksForLevel(&self, ecLevel: ErrorCorrectionLevel) -> &ECBlocks {
+        if self.is_rmqr {
+            // rMQR only knows the levels M and H
+            return &self.ecBlocks[usize::from(ecLevel == ErrorCorrectionLevel::H)];
+        }
         if ecLevel.get_ordinal() as usize >= self.ecBlocks.len() {
             return &self.ecBlocks[ecLevel.get_ordinal() as usize % self.ecBlocks.len()];
         }
@@ -170,6 +250,10 @@ impl Version {
      * See ISO 18004:2006 Annex E
      */
     pub fn buildFunctionPattern(&self) -> Result<BitMatrix> {
+        if self.is_rmqr {
+            return self.buildFunctionPatternRMQR();
+        }
+
         let dimension = self.getDimensionForVersion();
         let mut bitMatrix = BitMatrix::with_single_dimension(dimension)?;
 
@@ -199,7 +283,7 @@ impl Version {
             // Horizontal timing pattern
             bitMatrix.setRegion(9, 6, dimension - 17, 1)?;
 
-            if self.versionNumber > 6 {
+            if self.versionNumber > 6 && !self.is_model1 {
                 // Version info, top right
                 bitMatrix.setRegion(dimension - 11, 0, 3, 6)?;
                 // Version info, bottom left
@@ -216,6 +300,107 @@ impl Version {
         Ok(bitMatrix)
     }
 
+    /**
+     * See ISO 23941:2022 6.3
+     */
+    fn buildFunctionPatternRMQR(&self) -> Result<BitMatrix> {
+        let (width, height) = Self::DimensionOfVersionRMQR(self.versionNumber);
+        let mut bitMatrix = BitMatrix::new(width, height)?;
+
+        // Timing patterns along all four edges
+        bitMatrix.setRegion(0, 0, width, 1)?;
+        bitMatrix.setRegion(0, height - 1, width, 1)?;
+        bitMatrix.setRegion(0, 1, 1, height - 2)?;
+        bitMatrix.setRegion(width - 1, 1, 1, height - 2)?;
+
+        // Alignment patterns on both edges, joined by a vertical timing pattern
+        for &center in &self.alignmentPatternCenters {
+            bitMatrix.setRegion(center - 1, 1, 3, 2)?;
+            bitMatrix.setRegion(center - 1, height - 3, 3, 2)?;
+            bitMatrix.setRegion(center, 3, 1, height - 6)?;
+        }
+
+        // Finder pattern + separator, R7x* symbols have no separator below the finder
+        bitMatrix.setRegion(1, 1, 7, if height == 7 { 5 } else { 7 })?;
+        // Top left format
+        bitMatrix.setRegion(8, 1, 3, 5)?;
+        bitMatrix.setRegion(11, 1, 1, 3)?;
+
+        // Finder sub pattern
+        bitMatrix.setRegion(width - 5, height - 5, 4, 4)?;
+        // Bottom right format
+        bitMatrix.setRegion(width - 8, height - 6, 3, 5)?;
+        bitMatrix.setRegion(width - 5, height - 6, 3, 1)?;
+
+        // Corner finder patterns
+        bitMatrix.set(width - 2, 1);
+        if height > 9 {
+            bitMatrix.set(1, height - 2);
+        }
+
+        Ok(bitMatrix)
+    }
+
+    /**
+     * Lists the modules of the codewords of a Model 1 symbol, most significant bit first, in the
+     * order the codewords are placed. Codewords fill blocks of 2 x 4 modules in the two columns
+     * along the right edge and in the four columns between the left finder patterns, and blocks
+     * of 4 x 2 modules in the columns in between, each column from the bottom up. The modules
+     * left over along the right and bottom edges hold the extension patterns.
+     *
+     * See ISO 18004:2000 Annex M, the layout is the one read by zxing-cpp.
+     */
+    pub fn buildCodewordModulesModel1(&self) -> Vec<[(u32, u32); 8]> {
+        let dimension = self.getDimensionForVersion();
+        let columns = dimension / 4 + 3;
+        let mut codewords = Vec::with_capacity(self.totalCodewords as usize);
+        for j in 0..columns {
+            if j <= 1 {
+                let rows = (dimension - 8) / 4;
+                for i in 0..rows {
+                    // Leave room for the extension patterns along the right edge
+                    if j == 0 && i.is_multiple_of(2) && i > 0 && i < rows - 1 {
+                        continue;
+                    }
+                    codewords.push(Self::blockModules(
+                        dimension - 1 - 2 * j,
+                        dimension - 1 - 4 * i,
+                        2,
+                    ));
+                }
+            } else if columns - j <= 4 {
+                // Between the left finder patterns, skipping the vertical timing pattern
+                let rows = (dimension - 16) / 4;
+                let x = (columns - j - 1) * 2 + 1 + u32::from(columns - j == 4);
+                for i in 0..rows {
+                    codewords.push(Self::blockModules(x, dimension - 9 - 4 * i, 2));
+                }
+            } else {
+                let rows = dimension / 2;
+                for i in 0..rows {
+                    // Skip the top right finder pattern and leave room for the extension patterns
+                    // along the bottom edge
+                    if (j == 2 && i >= rows - 4)
+                        || (i == 0 && !j.is_multiple_of(2) && j + 1 != columns - 4)
+                    {
+                        continue;
+                    }
+                    // Skip the horizontal timing pattern
+                    let y = dimension - 1 - 2 * i - u32::from(i >= rows - 3);
+                    codewords.push(Self::blockModules(dimension - 5 - (j - 2) * 4, y, 4));
+                }
+            }
+        }
+        codewords
+    }
+
+    /**
+     * @return the modules of a block "width" modules wide whose bottom right module is at x, y
+     */
+    fn blockModules(x: u32, y: u32, width: u32) -> [(u32, u32); 8] {
+        std::array::from_fn(|bit| (x - bit as u32 % width, y - bit as u32 / width))
+    }
+
     pub fn build_micro_versions() -> Vec<Version> {
         vec![
             Version::new_micro(1, vec![ECBlocks::new(2, vec![ECB::new(1, 3)])]),
@@ -250,8 +435,405 @@ impl Version {
     }
 
     /**
-     * See ISO 18004:2006 6.5.1 Table 9
+     * See ISO 23941:2022 Table 7 and Table 8
+     */
+    pub fn build_rmqr_versions() -> Vec<Version> {
+        vec![
+            Version::new_rmqr(
+                1,
+                vec![21],
+                [
+                    ECBlocks::new(7, vec![ECB::new(1, 6)]),
+                    ECBlocks::new(10, vec![ECB::new(1, 3)]),
+                ],
+            ),
+            Version::new_rmqr(
+                2,
+                vec![19, 39],
+                [
+                    ECBlocks::new(9, vec![ECB::new(1, 12)]),
+                    ECBlocks::new(14, vec![ECB::new(1, 7)]),
+                ],
+            ),
+            Version::new_rmqr(
+                3,
+                vec![25, 51],
+                [
+                    ECBlocks::new(12, vec![ECB::new(1, 20)]),
+                    ECBlocks::new(22, vec![ECB::new(1, 10)]),
+                ],
+            ),
+            Version::new_rmqr(
+                4,
+                vec![23, 49, 75],
+                [
+                    ECBlocks::new(16, vec![ECB::new(1, 28)]),
+                    ECBlocks::new(30, vec![ECB::new(1, 14)]),
+                ],
+            ),
+            Version::new_rmqr(
+                5,
+                vec![27, 55, 83, 111],
+                [
+                    ECBlocks::new(24, vec![ECB::new(1, 44)]),
+                    ECBlocks::new(22, vec![ECB::new(2, 12)]),
+                ],
+            ),
+            Version::new_rmqr(
+                6,
+                vec![21],
+                [
+                    ECBlocks::new(9, vec![ECB::new(1, 12)]),
+                    ECBlocks::new(14, vec![ECB::new(1, 7)]),
+                ],
+            ),
+            Version::new_rmqr(
+                7,
+                vec![19, 39],
+                [
+                    ECBlocks::new(12, vec![ECB::new(1, 21)]),
+                    ECBlocks::new(22, vec![ECB::new(1, 11)]),
+                ],
+            ),
+            Version::new_rmqr(
+                8,
+                vec![25, 51],
+                [
+                    ECBlocks::new(18, vec![ECB::new(1, 31)]),
+                    ECBlocks::new(16, vec![ECB::new(1, 8), ECB::new(1, 9)]),
+                ],
+            ),
+            Version::new_rmqr(
+                9,
+                vec![23, 49, 75],
+                [
+                    ECBlocks::new(24, vec![ECB::new(1, 42)]),
+                    ECBlocks::new(22, vec![ECB::new(2, 11)]),
+                ],
+            ),
+            Version::new_rmqr(
+                10,
+                vec![27, 55, 83, 111],
+                [
+                    ECBlocks::new(18, vec![ECB::new(1, 31), ECB::new(1, 32)]),
+                    ECBlocks::new(22, vec![ECB::new(3, 11)]),
+                ],
+            ),
+            Version::new_rmqr(
+                11,
+                vec![],
+                [
+                    ECBlocks::new(8, vec![ECB::new(1, 7)]),
+                    ECBlocks::new(10, vec![ECB::new(1, 5)]),
+                ],
+            ),
+            Version::new_rmqr(
+                12,
+                vec![21],
+                [
+                    ECBlocks::new(12, vec![ECB::new(1, 19)]),
+                    ECBlocks::new(20, vec![ECB::new(1, 11)]),
+                ],
+            ),
+            Version::new_rmqr(
+                13,
+                vec![19, 39],
+                [
+                    ECBlocks::new(16, vec![ECB::new(1, 31)]),
+                    ECBlocks::new(16, vec![ECB::new(1, 7), ECB::new(1, 8)]),
+                ],
+            ),
+            Version::new_rmqr(
+                14,
+                vec![25, 51],
+                [
+                    ECBlocks::new(24, vec![ECB::new(1, 43)]),
+                    ECBlocks::new(22, vec![ECB::new(1, 11), ECB::new(1, 12)]),
+                ],
+            ),
+            Version::new_rmqr(
+                15,
+                vec![23, 49, 75],
+                [
+                    ECBlocks::new(16, vec![ECB::new(1, 28), ECB::new(1, 29)]),
+                    ECBlocks::new(30, vec![ECB::new(1, 14), ECB::new(1, 15)]),
+                ],
+            ),
+            Version::new_rmqr(
+                16,
+                vec![27, 55, 83, 111],
+                [
+                    ECBlocks::new(24, vec![ECB::new(2, 42)]),
+                    ECBlocks::new(30, vec![ECB::new(3, 14)]),
+                ],
+            ),
+            Version::new_rmqr(
+                17,
+                vec![],
+                [
+                    ECBlocks::new(9, vec![ECB::new(1, 12)]),
+                    ECBlocks::new(14, vec![ECB::new(1, 7)]),
+                ],
+            ),
+            Version::new_rmqr(
+                18,
+                vec![21],
+                [
+                    ECBlocks::new(14, vec![ECB::new(1, 27)]),
+                    ECBlocks::new(28, vec![ECB::new(1, 13)]),
+                ],
+            ),
+            Version::new_rmqr(
+                19,
+                vec![19, 39],
+                [
+                    ECBlocks::new(22, vec![ECB::new(1, 38)]),
+                    ECBlocks::new(20, vec![ECB::new(2, 10)]),
+                ],
+            ),
+            Version::new_rmqr(
+                20,
+                vec![25, 51],
+                [
+                    ECBlocks::new(16, vec![ECB::new(1, 26), ECB::new(1, 27)]),
+                    ECBlocks::new(28, vec![ECB::new(1, 14), ECB::new(1, 15)]),
+                ],
+            ),
+            Version::new_rmqr(
+                21,
+                vec![23, 49, 75],
+                [
+                    ECBlocks::new(20, vec![ECB::new(1, 36), ECB::new(1, 37)]),
+                    ECBlocks::new(26, vec![ECB::new(1, 11), ECB::new(2, 12)]),
+                ],
+            ),
+            Version::new_rmqr(
+                22,
+                vec![27, 55, 83, 111],
+                [
+                    ECBlocks::new(18, vec![ECB::new(2, 37), ECB::new(1, 38)]),
+                    ECBlocks::new(27, vec![ECB::new(2, 14), ECB::new(2, 15)]),
+                ],
+            ),
+            Version::new_rmqr(
+                23,
+                vec![21],
+                [
+                    ECBlocks::new(18, vec![ECB::new(1, 33)]),
+                    ECBlocks::new(18, vec![ECB::new(1, 7), ECB::new(1, 8)]),
+                ],
+            ),
+            Version::new_rmqr(
+                24,
+                vec![19, 39],
+                [
+                    ECBlocks::new(26, vec![ECB::new(1, 48)]),
+                    ECBlocks::new(24, vec![ECB::new(2, 13)]),
+                ],
+            ),
+            Version::new_rmqr(
+                25,
+                vec![25, 51],
+                [
+                    ECBlocks::new(18, vec![ECB::new(1, 33), ECB::new(1, 34)]),
+                    ECBlocks::new(24, vec![ECB::new(2, 10), ECB::new(1, 11)]),
+                ],
+            ),
+            Version::new_rmqr(
+                26,
+                vec![23, 49, 75],
+                [
+                    ECBlocks::new(24, vec![ECB::new(2, 44)]),
+                    ECBlocks::new(22, vec![ECB::new(4, 12)]),
+                ],
+            ),
+            Version::new_rmqr(
+                27,
+                vec![27, 55, 83, 111],
+                [
+                    ECBlocks::new(24, vec![ECB::new(2, 42), ECB::new(1, 43)]),
+                    ECBlocks::new(26, vec![ECB::new(1, 13), ECB::new(4, 14)]),
+                ],
+            ),
+            Version::new_rmqr(
+                28,
+                vec![21],
+                [
+                    ECBlocks::new(22, vec![ECB::new(1, 39)]),
+                    ECBlocks::new(20, vec![ECB::new(1, 10), ECB::new(1, 11)]),
+                ],
+            ),
+            Version::new_rmqr(
+                29,
+                vec![19, 39],
+                [
+                    ECBlocks::new(16, vec![ECB::new(2, 28)]),
+                    ECBlocks::new(30, vec![ECB::new(2, 14)]),
+                ],
+            ),
+            Version::new_rmqr(
+                30,
+                vec![25, 51],
+                [
+                    ECBlocks::new(22, vec![ECB::new(2, 39)]),
+                    ECBlocks::new(28, vec![ECB::new(1, 12), ECB::new(2, 13)]),
+                ],
+            ),
+            Version::new_rmqr(
+                31,
+                vec![23, 49, 75],
+                [
+                    ECBlocks::new(20, vec![ECB::new(2, 33), ECB::new(1, 34)]),
+                    ECBlocks::new(26, vec![ECB::new(4, 14)]),
+                ],
+            ),
+            Version::new_rmqr(
+                32,
+                vec![27, 55, 83, 111],
+                [
+                    ECBlocks::new(20, vec![ECB::new(4, 38)]),
+                    ECBlocks::new(26, vec![ECB::new(2, 12), ECB::new(4, 13)]),
+                ],
+            ),
+        ]
+    }
+
+    /**
+     * See ISO 18004:2000 Annex M, Table M.2. The error correction blocks of versions 1 to 7 are
+     * those of the standard. The ones of versions 8 to 14 still have to be checked against it,
+     * their totals follow from the placement of the codewords.
      */
+    pub fn build_model1_versions() -> Vec<Version> {
+        vec![
+            Version::new_model1(
+                1,
+                [
+                    ECBlocks::new(7, vec![ECB::new(1, 19)]),
+                    ECBlocks::new(10, vec![ECB::new(1, 16)]),
+                    ECBlocks::new(13, vec![ECB::new(1, 13)]),
+                    ECBlocks::new(17, vec![ECB::new(1, 9)]),
+                ],
+            ),
+            Version::new_model1(
+                2,
+                [
+                    ECBlocks::new(10, vec![ECB::new(1, 36)]),
+                    ECBlocks::new(16, vec![ECB::new(1, 30)]),
+                    ECBlocks::new(22, vec![ECB::new(1, 24)]),
+                    ECBlocks::new(30, vec![ECB::new(1, 16)]),
+                ],
+            ),
+            Version::new_model1(
+                3,
+                [
+                    ECBlocks::new(15, vec![ECB::new(1, 57)]),
+                    ECBlocks::new(28, vec![ECB::new(1, 44)]),
+                    ECBlocks::new(36, vec![ECB::new(1, 36)]),
+                    ECBlocks::new(48, vec![ECB::new(1, 24)]),
+                ],
+            ),
+            Version::new_model1(
+                4,
+                [
+                    ECBlocks::new(20, vec![ECB::new(1, 80)]),
+                    ECBlocks::new(40, vec![ECB::new(1, 60)]),
+                    ECBlocks::new(50, vec![ECB::new(1, 50)]),
+                    ECBlocks::new(66, vec![ECB::new(1, 34)]),
+                ],
+            ),
+            Version::new_model1(
+                5,
+                [
+                    ECBlocks::new(26, vec![ECB::new(1, 108)]),
+                    ECBlocks::new(52, vec![ECB::new(1, 82)]),
+                    ECBlocks::new(66, vec![ECB::new(1, 68)]),
+                    ECBlocks::new(44, vec![ECB::new(2, 23)]),
+                ],
+            ),
+            Version::new_model1(
+                6,
+                [
+                    ECBlocks::new(34, vec![ECB::new(1, 136)]),
+                    ECBlocks::new(63, vec![ECB::new(1, 107)]),
+                    ECBlocks::new(84, vec![ECB::new(1, 86)]),
+                    ECBlocks::new(56, vec![ECB::new(2, 29)]),
+                ],
+            ),
+            Version::new_model1(
+                7,
+                [
+                    ECBlocks::new(42, vec![ECB::new(1, 170)]),
+                    ECBlocks::new(80, vec![ECB::new(1, 132)]),
+                    ECBlocks::new(52, vec![ECB::new(2, 54)]),
+                    ECBlocks::new(70, vec![ECB::new(2, 36)]),
+                ],
+            ),
+            Version::new_model1(
+                8,
+                [
+                    ECBlocks::new(23, vec![ECB::new(2, 62), ECB::new(1, 63)]),
+                    ECBlocks::new(32, vec![ECB::new(2, 53), ECB::new(1, 54)]),
+                    ECBlocks::new(32, vec![ECB::new(4, 32)]),
+                    ECBlocks::new(42, vec![ECB::new(4, 22)]),
+                ],
+            ),
+            Version::new_model1(
+                9,
+                [
+                    ECBlocks::new(28, vec![ECB::new(3, 74)]),
+                    ECBlocks::new(39, vec![ECB::new(3, 63)]),
+                    ECBlocks::new(38, vec![ECB::new(2, 38), ECB::new(2, 39)]),
+                    ECBlocks::new(50, vec![ECB::new(2, 26), ECB::new(2, 27)]),
+                ],
+            ),
+            Version::new_model1(
+                10,
+                [
+                    ECBlocks::new(24, vec![ECB::new(2, 65), ECB::new(2, 66)]),
+                    ECBlocks::new(34, vec![ECB::new(2, 55), ECB::new(2, 56)]),
+                    ECBlocks::new(36, vec![ECB::new(2, 35), ECB::new(3, 36)]),
+                    ECBlocks::new(47, vec![ECB::new(2, 24), ECB::new(3, 25)]),
+                ],
+            ),
+            Version::new_model1(
+                11,
+                [
+                    ECBlocks::new(28, vec![ECB::new(4, 76)]),
+                    ECBlocks::new(40, vec![ECB::new(4, 64)]),
+                    ECBlocks::new(35, vec![ECB::new(4, 34), ECB::new(2, 35)]),
+                    ECBlocks::new(45, vec![ECB::new(4, 24), ECB::new(2, 25)]),
+                ],
+            ),
+            Version::new_model1(
+                12,
+                [
+                    ECBlocks::new(26, vec![ECB::new(4, 69), ECB::new(1, 70)]),
+                    ECBlocks::new(36, vec![ECB::new(4, 59), ECB::new(1, 60)]),
+                    ECBlocks::new(40, vec![ECB::new(4, 39), ECB::new(2, 40)]),
+                    ECBlocks::new(52, vec![ECB::new(4, 27), ECB::new(2, 28)]),
+                ],
+            ),
+            Version::new_model1(
+                13,
+                [
+                    ECBlocks::new(29, vec![ECB::new(3, 79), ECB::new(2, 80)]),
+                    ECBlocks::new(41, vec![ECB::new(3, 67), ECB::new(2, 68)]),
+                    ECBlocks::new(39, vec![ECB::new(4, 38), ECB::new(3, 39)]),
+                    ECBlocks::new(50, vec![ECB::new(4, 27), ECB::new(3, 28)]),
+                ],
+            ),
+            Version::new_model1(
+                14,
+                [
+                    ECBlocks::new(27, vec![ECB::new(2, 74), ECB::new(4, 75)]),
+                    ECBlocks::new(39, vec![ECB::new(2, 62), ECB::new(4, 63)]),
+                    ECBlocks::new(38, vec![ECB::new(6, 38), ECB::new(2, 39)]),
+                    ECBlocks::new(50, vec![ECB::new(6, 26), ECB::new(2, 27)]),
+                ],
+            ),
+        ]
+    }
+
     pub fn buildVersions() -> Vec<Version> {
         Vec::from([
             Version::new(
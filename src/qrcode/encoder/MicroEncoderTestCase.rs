@@ -0,0 +1,172 @@
+/**
+ * Tests {@link micro_qrcode_encoder}.
+ */
+use std::collections::HashMap;
+
+use crate::{
+    common::BitMatrix,
+    qrcode::{cpp_port::decoder::Decode, decoder::ErrorCorrectionLevel},
+    BarcodeFormat, EncodeHintType, EncodeHintValue, Exceptions, MultiFormatWriter, Writer,
+};
+
+use super::micro_qrcode_encoder;
+
+fn encode(contents: &str, ecLevel: &str, version: Option<u32>) -> BitMatrix {
+    let mut hints = HashMap::new();
+    hints.insert(
+        EncodeHintType::MARGIN,
+        EncodeHintValue::Margin("0".to_owned()),
+    );
+    hints.insert(
+        EncodeHintType::ERROR_CORRECTION,
+        EncodeHintValue::ErrorCorrection(ecLevel.to_owned()),
+    );
+    if let Some(version) = version {
+        hints.insert(
+            EncodeHintType::MICRO_QR_VERSION,
+            EncodeHintValue::MicroQrVersion(version.to_string()),
+        );
+    }
+    MultiFormatWriter
+        .encode_with_hints(contents, &BarcodeFormat::MICRO_QR_CODE, 0, 0, &hints)
+        .expect("must encode")
+}
+
+#[test]
+fn testEncodeM1() {
+    let qrCode = micro_qrcode_encoder::encode("123", ErrorCorrectionLevel::L).expect("encode");
+    assert_eq!(Some(1), qrCode.getVersion().map(|v| v.getVersionNumber()));
+    // Same symbol as in the MQRCodeM1 decoder test
+    let expected = r"XXXXXXX X X
+X     X
+X XXX X XXX
+X XXX X  XX
+X XXX X   X
+X     X XX
+XXXXXXX X
+        X
+XX     X
+ X  XXXXX X
+X  XXXXXX X
+";
+    let matrix = encode("123", "L", None);
+    let actual = matrix.toString("X", " ");
+    assert_eq!(
+        expected.lines().collect::<Vec<_>>(),
+        actual.lines().map(str::trim_end).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn testRoundTrip() {
+    for (contents, ecLevel, version) in [
+        ("12345", "L", 1),
+        ("0123456789", "L", 2),
+        ("ABC", "M", 2),
+        ("1234 ABCD", "L", 3),
+        ("E=mc2", "L", 3),
+        ("E=mc2 or not", "L", 4),
+        ("012345678901234567", "M", 3),
+        ("Micro QR Code", "M", 4),
+        ("!\"$%&/()=", "Q", 4),
+        ("12345678901234567890123456789012345", "L", 4),
+        ("HELLO WORLD", "Q", 4),
+    ] {
+        let matrix = encode(contents, ecLevel, None);
+        assert_eq!(9 + 2 * version, matrix.getWidth(), "{contents}");
+        let result = Decode(&matrix).expect("must decode");
+        assert!(result.isValid(), "{contents}");
+        assert_eq!(contents, result.text());
+        assert_eq!(ecLevel, result.ecLevel());
+    }
+}
+
+#[test]
+fn testKanji() {
+    let mut hints = HashMap::new();
+    hints.insert(
+        EncodeHintType::MARGIN,
+        EncodeHintValue::Margin("0".to_owned()),
+    );
+    hints.insert(
+        EncodeHintType::CHARACTER_SET,
+        EncodeHintValue::CharacterSet("Shift_JIS".to_owned()),
+    );
+    let qrCode = micro_qrcode_encoder::encode_with_hints("誠", ErrorCorrectionLevel::L, &hints)
+        .expect("encode");
+    assert_eq!(Some(3), qrCode.getVersion().map(|v| v.getVersionNumber()));
+    let matrix = MultiFormatWriter
+        .encode_with_hints("誠", &BarcodeFormat::MICRO_QR_CODE, 0, 0, &hints)
+        .expect("must encode");
+    assert_eq!("誠", Decode(&matrix).expect("must decode").text());
+}
+
+#[test]
+fn testVersionAndMaskHints() {
+    for version in 1..=4 {
+        let matrix = encode("123", "L", Some(version));
+        assert_eq!(9 + 2 * version, matrix.getWidth());
+        assert_eq!("123", Decode(&matrix).expect("must decode").text());
+    }
+    for maskPattern in 0..4 {
+        let mut hints = HashMap::new();
+        hints.insert(
+            EncodeHintType::QR_MASK_PATTERN,
+            EncodeHintValue::QrMaskPattern(maskPattern.to_string()),
+        );
+        let qrCode =
+            micro_qrcode_encoder::encode_with_hints("ABC123", ErrorCorrectionLevel::M, &hints)
+                .expect("encode");
+        assert_eq!(maskPattern, qrCode.getMaskPattern());
+    }
+}
+
+#[test]
+fn testTooMuchData() {
+    let mut hints = HashMap::new();
+    hints.insert(
+        EncodeHintType::MICRO_QR_VERSION,
+        EncodeHintValue::MicroQrVersion("1".to_owned()),
+    );
+    // M1 holds 5 digits and no letters
+    assert!(
+        micro_qrcode_encoder::encode_with_hints("123456", ErrorCorrectionLevel::L, &hints).is_err()
+    );
+    assert!(micro_qrcode_encoder::encode_with_hints("A", ErrorCorrectionLevel::L, &hints).is_err());
+    // M1 only has error detection
+    assert!(micro_qrcode_encoder::encode_with_hints("1", ErrorCorrectionLevel::M, &hints).is_err());
+    // No Micro QR Code holds level H or 36 digits
+    assert!(micro_qrcode_encoder::encode("1", ErrorCorrectionLevel::H).is_err());
+    assert!(micro_qrcode_encoder::encode(&"1".repeat(36), ErrorCorrectionLevel::L).is_err());
+}
+
+#[test]
+fn testUnsupportedECLevel() {
+    let mut hints = HashMap::new();
+    hints.insert(
+        EncodeHintType::MICRO_QR_VERSION,
+        EncodeHintValue::MicroQrVersion("1".to_owned()),
+    );
+    assert_eq!(
+        Err(Exceptions::illegal_argument_with(
+            "Micro QR Code M1 does not support error correction level M"
+        )),
+        micro_qrcode_encoder::encode_with_hints("1", ErrorCorrectionLevel::M, &hints).map(|_| ())
+    );
+    hints.insert(
+        EncodeHintType::MICRO_QR_VERSION,
+        EncodeHintValue::MicroQrVersion("3".to_owned()),
+    );
+    assert_eq!(
+        Err(Exceptions::illegal_argument_with(
+            "Micro QR Code M3 does not support error correction level Q"
+        )),
+        micro_qrcode_encoder::encode_with_hints("1", ErrorCorrectionLevel::Q, &hints).map(|_| ())
+    );
+    assert_eq!(
+        Err(Exceptions::illegal_argument_with(
+            "Micro QR Code does not support error correction level H"
+        )),
+        micro_qrcode_encoder::encode("1", ErrorCorrectionLevel::H).map(|_| ())
+    );
+}
@@ -0,0 +1,222 @@
+/**
+ * Tests {@link model1_encoder}.
+ */
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    common::{bit_matrix_test_case, BitMatrix},
+    qrcode::{
+        cpp_port::decoder::Decode,
+        decoder::{ErrorCorrectionLevel, Version},
+    },
+    BarcodeFormat, DecodeHintType, DecodeHintValue, EncodeHintType, EncodeHintValue,
+    MultiFormatReader, MultiFormatWriter, RXingResultMetadataType, RXingResultMetadataValue,
+    Reader, Writer,
+};
+
+fn encode(
+    contents: &str,
+    ecLevel: &str,
+    version: Option<u32>,
+    size: i32,
+    margin: u32,
+) -> crate::common::Result<BitMatrix> {
+    let mut hints = HashMap::from([
+        (
+            EncodeHintType::QR_MODEL,
+            EncodeHintValue::QrModel("1".to_owned()),
+        ),
+        (
+            EncodeHintType::ERROR_CORRECTION,
+            EncodeHintValue::ErrorCorrection(ecLevel.to_owned()),
+        ),
+        (
+            EncodeHintType::MARGIN,
+            EncodeHintValue::Margin(margin.to_string()),
+        ),
+    ]);
+    if let Some(version) = version {
+        hints.insert(
+            EncodeHintType::QR_VERSION,
+            EncodeHintValue::QrVersion(version.to_string()),
+        );
+    }
+    MultiFormatWriter.encode_with_hints(contents, &BarcodeFormat::QR_CODE, size, size, &hints)
+}
+
+fn read(matrix: &BitMatrix, hints: &crate::DecodingHintDictionary) -> crate::RXingResult {
+    let mut image = bit_matrix_test_case::matrix_to_binary_bitmap(matrix);
+    MultiFormatReader::default()
+        .decode_with_hints(&mut image, hints)
+        .expect("must decode")
+}
+
+#[test]
+fn testCodewordModules() {
+    for versionNumber in 1..=14 {
+        let version = Version::FromNumberModel1(versionNumber).expect("version");
+        assert!(version.isModel1());
+        let functionPattern = version.buildFunctionPattern().expect("function pattern");
+        let dimension = version.getDimensionForVersion();
+
+        let codewords = version.buildCodewordModulesModel1();
+        assert_eq!(
+            version.getTotalCodewords() as usize,
+            codewords.len(),
+            "{versionNumber}"
+        );
+        let modules: HashSet<(u32, u32)> = codewords.iter().flatten().copied().collect();
+        assert_eq!(codewords.len() * 8, modules.len(), "{versionNumber}");
+        for (x, y) in modules {
+            assert!(x < dimension && y < dimension, "{versionNumber}");
+            assert!(!functionPattern.get(x, y), "{versionNumber}: {x}, {y}");
+        }
+    }
+}
+
+#[test]
+fn testECBlocks() {
+    // entries of ISO 18004:2000 Table M.2: error correction codewords per block, blocks, and data
+    // codewords per block
+    for (versionNumber, ecLevel, expected) in [
+        (2, ErrorCorrectionLevel::L, (10, 1, 36)),
+        (3, ErrorCorrectionLevel::L, (15, 1, 57)),
+        (4, ErrorCorrectionLevel::H, (66, 1, 34)),
+        (5, ErrorCorrectionLevel::H, (44, 2, 23)),
+        (6, ErrorCorrectionLevel::M, (63, 1, 107)),
+        (7, ErrorCorrectionLevel::Q, (52, 2, 54)),
+    ] {
+        let version = Version::FromNumberModel1(versionNumber).expect("version");
+        let ecBlocks = version.getECBlocksForLevel(ecLevel);
+        assert_eq!(1, ecBlocks.getECBlocks().len());
+        let block = &ecBlocks.getECBlocks()[0];
+        assert_eq!(
+            expected,
+            (
+                ecBlocks.getECCodewordsPerBlock(),
+                block.getCount(),
+                block.getDataCodewords()
+            ),
+            "{versionNumber}-{ecLevel:?}"
+        );
+    }
+    for versionNumber in 1..=14 {
+        let version = Version::FromNumberModel1(versionNumber).expect("version");
+        for ecLevel in [
+            ErrorCorrectionLevel::L,
+            ErrorCorrectionLevel::M,
+            ErrorCorrectionLevel::Q,
+            ErrorCorrectionLevel::H,
+        ] {
+            let ecBlocks = version.getECBlocksForLevel(ecLevel);
+            let dataCodewords = ecBlocks
+                .getECBlocks()
+                .iter()
+                .map(|block| block.getCount() * block.getDataCodewords())
+                .sum::<u32>();
+            assert_eq!(
+                version.getTotalCodewords(),
+                dataCodewords + ecBlocks.getTotalECCodewords(),
+                "{versionNumber}-{ecLevel:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn testRoundTrip() {
+    for (contents, ecLevel, versionNumber) in [
+        ("0123456789", "L", 1),
+        ("HELLO WORLD", "H", 2),
+        ("Model 1", "M", 1),
+        ("ABCDEFGHIJKLMNOPQRSTUVWXYZ 0123456789", "Q", 3),
+        ("Lot 4711, best before 1997-03-31", "L", 2),
+    ] {
+        let matrix = encode(contents, ecLevel, None, 0, 0).expect("must encode");
+        assert_eq!(
+            Version::DimensionOfVersion(versionNumber, false),
+            matrix.getWidth(),
+            "{contents}"
+        );
+        let result = Decode(&matrix).expect("must decode");
+        assert!(result.isValid(), "{contents}");
+        assert_eq!(contents, result.text());
+        assert_eq!(ecLevel, result.ecLevel());
+        assert_eq!(versionNumber, result.versionNumber());
+        assert!(*result.extra(), "{contents}");
+    }
+}
+
+#[test]
+fn testAllVersions() {
+    for versionNumber in 1..=14 {
+        for ecLevel in ["L", "M", "Q", "H"] {
+            let contents = "1".repeat(versionNumber as usize * 3);
+            let matrix =
+                encode(&contents, ecLevel, Some(versionNumber), 0, 0).expect("must encode");
+            assert_eq!(
+                Version::DimensionOfVersion(versionNumber, false),
+                matrix.getWidth()
+            );
+            let result = Decode(&matrix).expect("must decode");
+            assert_eq!(contents, result.text(), "{versionNumber}-{ecLevel}");
+            assert!(*result.extra());
+        }
+    }
+}
+
+#[test]
+fn testReadFromImage() {
+    let hints = HashMap::from([(
+        DecodeHintType::POSSIBLE_FORMATS,
+        DecodeHintValue::PossibleFormats(HashSet::from([BarcodeFormat::QR_CODE])),
+    )]);
+    let pureHints = HashMap::from([(
+        DecodeHintType::PURE_BARCODE,
+        DecodeHintValue::PureBarcode(true),
+    )]);
+
+    // the larger versions have no version information to tell their size
+    for (contents, versionNumber) in [("Archive label 0815", 2), ("0123456789", 12)] {
+        let matrix = encode(contents, "M", Some(versionNumber), 400, 4).expect("must encode");
+        for hints in [&hints, &pureHints] {
+            let result = read(&matrix, hints);
+            assert_eq!(contents, result.getText());
+            assert_eq!(&BarcodeFormat::QR_CODE, result.getBarcodeFormat());
+            assert_eq!(
+                Some(&RXingResultMetadataValue::QrModel(1)),
+                result
+                    .getRXingResultMetadata()
+                    .get(&RXingResultMetadataType::QR_MODEL)
+            );
+        }
+    }
+
+    let matrix = MultiFormatWriter
+        .encode("Model 2", &BarcodeFormat::QR_CODE, 200, 200)
+        .expect("must encode");
+    let result = read(&matrix, &hints);
+    assert_eq!("Model 2", result.getText());
+    assert_eq!(
+        Some(&RXingResultMetadataValue::QrModel(2)),
+        result
+            .getRXingResultMetadata()
+            .get(&RXingResultMetadataType::QR_MODEL)
+    );
+}
+
+#[test]
+fn testInvalid() {
+    // Model 1 has neither ECI nor versions beyond 14
+    assert!(encode("Ünïcödé ✓", "L", None, 0, 0).is_err());
+    assert!(encode("0123456789", "L", Some(15), 0, 0).is_err());
+    assert!(encode(&"1".repeat(2000), "L", None, 0, 0).is_err());
+
+    let hints = HashMap::from([(
+        EncodeHintType::QR_MODEL,
+        EncodeHintValue::QrModel("3".to_owned()),
+    )]);
+    assert!(MultiFormatWriter
+        .encode_with_hints("0123456789", &BarcodeFormat::QR_CODE, 0, 0, &hints)
+        .is_err());
+}
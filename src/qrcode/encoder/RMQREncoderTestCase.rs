@@ -0,0 +1,217 @@
+/**
+ * Tests {@link rmqr_encoder}.
+ */
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    common::{bit_matrix_test_case, BitMatrix},
+    qrcode::{
+        cpp_port::{decoder::Decode, QrReader},
+        decoder::{ErrorCorrectionLevel, Version, RMQR_SIZES},
+    },
+    BarcodeFormat, DecodeHintType, DecodeHintValue, EncodeHintType, EncodeHintValue,
+    MultiFormatReader, MultiFormatWriter, Reader, Writer,
+};
+
+use super::rmqr_encoder;
+
+fn encode(contents: &str, ecLevel: &str, version: Option<&str>, margin: u32) -> BitMatrix {
+    let mut hints = HashMap::new();
+    hints.insert(
+        EncodeHintType::MARGIN,
+        EncodeHintValue::Margin(margin.to_string()),
+    );
+    hints.insert(
+        EncodeHintType::ERROR_CORRECTION,
+        EncodeHintValue::ErrorCorrection(ecLevel.to_owned()),
+    );
+    if let Some(version) = version {
+        hints.insert(
+            EncodeHintType::RMQR_VERSION,
+            EncodeHintValue::RmqrVersion(version.to_owned()),
+        );
+    }
+    MultiFormatWriter
+        .encode_with_hints(
+            contents,
+            &BarcodeFormat::RECTANGULAR_MICRO_QR_CODE,
+            0,
+            0,
+            &hints,
+        )
+        .expect("must encode")
+}
+
+fn read(matrix: &BitMatrix, hints: &crate::DecodingHintDictionary) -> crate::RXingResult {
+    let mut image = bit_matrix_test_case::matrix_to_binary_bitmap(matrix);
+    MultiFormatReader::default()
+        .decode_with_hints(&mut image, hints)
+        .expect("must decode")
+}
+
+#[test]
+fn testRoundTrip() {
+    for (contents, ecLevel, size) in [
+        ("123456", "M", "R11x27"),
+        ("12345", "H", "R11x27"),
+        ("HELLO WORLD", "M", "R13x27"),
+        ("rMQR Code", "M", "R13x27"),
+        ("ABCDEFGHIJKLMNOPQRSTUVWXYZ 0123456789", "M", "R13x43"),
+        ("ABCDEFGHIJKLMNOPQRSTUVWXYZ 0123456789", "H", "R13x77"),
+        ("Carton 0042 of 0100, handle with care!", "M", "R11x77"),
+    ] {
+        // the smallest symbol by area is chosen
+        let matrix = encode(contents, ecLevel, None, 0);
+        let version = rmqr_encoder::getVersionForName(size).expect("version");
+        assert_eq!(
+            Version::DimensionOfVersionRMQR(version.getVersionNumber()),
+            (matrix.getWidth(), matrix.getHeight()),
+            "{contents}"
+        );
+        let result = Decode(&matrix).expect("must decode");
+        assert!(result.isValid(), "{contents}");
+        assert_eq!(contents, result.text());
+        assert_eq!(ecLevel, result.ecLevel());
+    }
+}
+
+#[test]
+fn testAllVersionsAtCapacity() {
+    for (versionNumber, (width, height)) in (1..).zip(RMQR_SIZES) {
+        let name = format!("R{height}x{width}");
+        for ecLevel in ["M", "H"] {
+            // find the longest numeric content that still fits
+            let mut length = 1;
+            while rmqr_encoder::encode_with_hints(
+                &"7".repeat(length + 1),
+                ecLevel.parse().unwrap(),
+                &HashMap::from([(
+                    EncodeHintType::RMQR_VERSION,
+                    EncodeHintValue::RmqrVersion(versionNumber.to_string()),
+                )]),
+            )
+            .is_ok()
+            {
+                length += 1;
+            }
+            let contents = "1234567890".repeat(40)[..length].to_owned();
+            let matrix = encode(&contents, ecLevel, Some(&name), 0);
+            assert_eq!((width, height), (matrix.getWidth(), matrix.getHeight()));
+            let result = Decode(&matrix).expect("must decode");
+            assert_eq!(contents, result.text(), "{name}-{ecLevel}");
+            assert_eq!(ecLevel, result.ecLevel());
+        }
+    }
+}
+
+#[test]
+fn testReadFromImage() {
+    let contents = "ABC-1234-XYZ";
+    let matrix = encode(contents, "M", Some("R13x77"), 2);
+    let scaled = {
+        let mut scaled = BitMatrix::new(matrix.getWidth() * 4, matrix.getHeight() * 4).unwrap();
+        for y in 0..matrix.getHeight() {
+            for x in 0..matrix.getWidth() {
+                if matrix.get(x, y) {
+                    scaled.setRegion(x * 4, y * 4, 4, 4).unwrap();
+                }
+            }
+        }
+        scaled
+    };
+
+    let formats = HashSet::from([BarcodeFormat::RECTANGULAR_MICRO_QR_CODE]);
+    for pure in [false, true] {
+        let mut hints = HashMap::new();
+        hints.insert(
+            DecodeHintType::POSSIBLE_FORMATS,
+            DecodeHintValue::PossibleFormats(formats.clone()),
+        );
+        hints.insert(
+            DecodeHintType::PURE_BARCODE,
+            DecodeHintValue::PureBarcode(pure),
+        );
+        let result = read(&scaled, &hints);
+        assert_eq!(contents, result.getText());
+        assert_eq!(
+            &BarcodeFormat::RECTANGULAR_MICRO_QR_CODE,
+            result.getBarcodeFormat()
+        );
+    }
+
+    // without restricting the formats, the QR reader must find it as well
+    let mut image = bit_matrix_test_case::matrix_to_binary_bitmap(&scaled);
+    assert_eq!(
+        contents,
+        QrReader.decode(&mut image).expect("must decode").getText()
+    );
+}
+
+#[test]
+fn testVersionNames() {
+    assert_eq!(
+        Some(1),
+        rmqr_encoder::getVersionForName("R7x43").map(|v| v.getVersionNumber())
+    );
+    assert_eq!(
+        Some(12),
+        rmqr_encoder::getVersionForName("r11X43").map(|v| v.getVersionNumber())
+    );
+    assert_eq!(
+        Some(32),
+        rmqr_encoder::getVersionForName("32").map(|v| v.getVersionNumber())
+    );
+    assert!(rmqr_encoder::getVersionForName("R7x27").is_none());
+    assert!(rmqr_encoder::getVersionForName("33").is_none());
+}
+
+#[test]
+fn testKanjiAndECI() {
+    let mut hints = HashMap::new();
+    hints.insert(
+        EncodeHintType::CHARACTER_SET,
+        EncodeHintValue::CharacterSet("Shift_JIS".to_owned()),
+    );
+    let qrCode =
+        rmqr_encoder::encode_with_hints("日本", ErrorCorrectionLevel::M, &hints).expect("encode");
+    let matrix = MultiFormatWriter
+        .encode_with_hints(
+            "日本",
+            &BarcodeFormat::RECTANGULAR_MICRO_QR_CODE,
+            0,
+            0,
+            &HashMap::from([
+                (
+                    EncodeHintType::CHARACTER_SET,
+                    EncodeHintValue::CharacterSet("Shift_JIS".to_owned()),
+                ),
+                (
+                    EncodeHintType::MARGIN,
+                    EncodeHintValue::Margin("0".to_owned()),
+                ),
+            ]),
+        )
+        .expect("must encode");
+    assert_eq!(Some(crate::qrcode::decoder::Mode::KANJI), *qrCode.getMode());
+    assert_eq!("日本", Decode(&matrix).expect("must decode").text());
+
+    // content outside of ISO-8859-1 is encoded as UTF-8 with an ECI
+    let matrix = encode("Grüße, €", "M", None, 0);
+    assert_eq!("Grüße, €", Decode(&matrix).expect("must decode").text());
+}
+
+#[test]
+fn testInvalid() {
+    // rMQR only knows the error correction levels M and H
+    assert!(rmqr_encoder::encode("123", ErrorCorrectionLevel::L).is_err());
+    assert!(rmqr_encoder::encode("123", ErrorCorrectionLevel::Q).is_err());
+    // R17x139 holds at most 361 digits at level M
+    assert!(rmqr_encoder::encode(&"1".repeat(400), ErrorCorrectionLevel::M).is_err());
+    let hints = HashMap::from([(
+        EncodeHintType::RMQR_VERSION,
+        EncodeHintValue::RmqrVersion("R7x43".to_owned()),
+    )]);
+    assert!(
+        rmqr_encoder::encode_with_hints(&"1".repeat(13), ErrorCorrectionLevel::M, &hints).is_err()
+    );
+}
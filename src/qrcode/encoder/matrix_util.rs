@@ -170,7 +170,12 @@ pub fn embedTypeInfo(
 ) -> Result<()> {
     let mut typeInfoBits = BitArray::new();
     makeTypeInfoBits(ecLevel, maskPattern as u32, &mut typeInfoBits)?;
+    embedTypeInfoBits(&typeInfoBits, matrix);
+    Ok(())
+}
 
+// Embed the 15 bits of masked type information.
+pub fn embedTypeInfoBits(typeInfoBits: &BitArray, matrix: &mut ByteMatrix) {
     for (i, coordinates) in TYPE_INFO_COORDINATES
         .iter()
         .enumerate()
@@ -199,7 +204,6 @@ pub fn embedTypeInfo(
         }
         matrix.set_bool(x2, y2, bit);
     }
-    Ok(())
 }
 
 // Embed version information if need be. On success, modify the matrix and return true.
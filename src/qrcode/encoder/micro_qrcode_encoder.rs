@@ -0,0 +1,413 @@
+/**
+ * Encodes Micro QR Code symbols M1 to M4, see ISO 18004:2015 for the differences with QR Code:
+ * shorter mode indicators and terminators, a single finder pattern, 4 data masks and a 15 bit
+ * format information that also holds the version.
+ */
+use std::collections::HashMap;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{
+    common::{BitArray, CharacterSet, Result},
+    qrcode::decoder::{ErrorCorrectionLevel, Mode, Version, VersionRef},
+    EncodeHintType, EncodeHintValue, EncodingHintDictionary, Exceptions,
+};
+
+use super::{mask_util, matrix_util, qrcode_encoder, ByteMatrix, QRCode};
+
+pub const NUM_MASK_PATTERNS: i32 = 4;
+
+/// Data masks of Micro QR Code, as indices of the matching QR Code data masks
+const MASK_PATTERNS: [u32; 4] = [1, 4, 6, 7];
+
+const FORMAT_INFO_POLY: u32 = 0x537;
+const FORMAT_INFO_MASK_MICRO: u32 = 0x4445;
+
+/**
+ * @param content text to encode
+ * @param ecLevel error correction level to use, L for the error detection only of M1
+ * @return {@link QRCode} representing the encoded Micro QR code
+ * @throws WriterException if encoding can't succeed, because of for example invalid content
+ *   or configuration
+ */
+pub fn encode(content: &str, ecLevel: ErrorCorrectionLevel) -> Result<QRCode> {
+    encode_with_hints(content, ecLevel, &HashMap::new())
+}
+
+pub fn encode_with_hints(
+    content: &str,
+    ec_level: ErrorCorrectionLevel,
+    hints: &EncodingHintDictionary,
+) -> Result<QRCode> {
+    if matches!(
+        hints.get(&EncodeHintType::GS1_FORMAT),
+        Some(EncodeHintValue::Gs1Format(true))
+    ) {
+        return Err(Exceptions::writer_with(
+            "Micro QR Code does not support GS1 formatted data",
+        ));
+    }
+
+    // Micro QR Code has no ECI, so the character set only selects how bytes are encoded
+    let encoding =
+        if let Some(EncodeHintValue::CharacterSet(v)) = hints.get(&EncodeHintType::CHARACTER_SET) {
+            CharacterSet::get_character_set_by_name(v).ok_or(Exceptions::WRITER)?
+        } else {
+            qrcode_encoder::DEFAULT_BYTE_MODE_ENCODING
+        };
+
+    let mode = qrcode_encoder::chooseModeWithEncoding(content, encoding);
+
+    let mut data_bits = BitArray::new();
+    qrcode_encoder::appendBytes(content, mode, &mut data_bits, encoding)?;
+    let num_letters = if mode == Mode::BYTE {
+        data_bits.getSizeInBytes()
+    } else if mode == Mode::KANJI {
+        data_bits.get_size() / 13
+    } else {
+        content.graphemes(true).count()
+    } as u32;
+
+    if !matches!(
+        ec_level,
+        ErrorCorrectionLevel::L | ErrorCorrectionLevel::M | ErrorCorrectionLevel::Q
+    ) {
+        return Err(Exceptions::illegal_argument_with(format!(
+            "Micro QR Code does not support error correction level {ec_level}"
+        )));
+    }
+
+    let version = if let Some(EncodeHintValue::MicroQrVersion(v)) =
+        hints.get(&EncodeHintType::MICRO_QR_VERSION)
+    {
+        let versionNumber = v.parse::<u32>().unwrap_or_default();
+        let version = Version::FromNumber(versionNumber, true)
+            .map_err(|_| Exceptions::writer_with(format!("Invalid Micro QR Code version: {v}")))?;
+        checkSymbolNumber(version, ec_level)?;
+        if !willFit(num_letters, mode, &data_bits, version, ec_level) {
+            return Err(Exceptions::writer_with(
+                "Data too big for requested version",
+            ));
+        }
+        version
+    } else {
+        chooseVersion(num_letters, mode, &data_bits, ec_level)?
+    };
+
+    let mut header_and_data_bits = BitArray::new();
+    appendModeInfo(mode, version, &mut header_and_data_bits)?;
+    header_and_data_bits.appendBits(num_letters, mode.CharacterCountBits(version) as usize)?;
+    header_and_data_bits.appendBitArray(data_bits);
+
+    let final_bits = terminateAndAppendECBytes(header_and_data_bits, version, ec_level)?;
+
+    let mut qrCode = QRCode::new();
+
+    qrCode.setECLevel(ec_level);
+    qrCode.setMode(mode);
+    qrCode.setVersion(version);
+
+    let symbolNumber = checkSymbolNumber(version, ec_level)?;
+    let dimension = version.getDimensionForVersion();
+    let mut matrix = ByteMatrix::new(dimension, dimension);
+
+    // Enable manual selection of the pattern to be used via hint
+    let mut mask_pattern = -1;
+    if let Some(EncodeHintValue::QrMaskPattern(v)) = hints.get(&EncodeHintType::QR_MASK_PATTERN) {
+        mask_pattern = v
+            .parse::<i32>()
+            .ok()
+            .filter(|mask_pattern| (0..NUM_MASK_PATTERNS).contains(mask_pattern))
+            .unwrap_or(-1);
+    }
+
+    if mask_pattern == -1 {
+        mask_pattern = chooseMaskPattern(&final_bits, symbolNumber, &mut matrix)? as i32;
+    }
+    qrCode.setMaskPattern(mask_pattern);
+
+    buildMatrix(&final_bits, symbolNumber, mask_pattern as u32, &mut matrix)?;
+    qrCode.setMatrix(matrix);
+
+    Ok(qrCode)
+}
+
+/**
+ * Returns the symbol number of the version and error correction level, as stored in the format
+ * information, or None if the version does not support the error correction level.
+ * See ISO 18004:2015 Table 13.
+ */
+pub fn getSymbolNumber(version: VersionRef, ecLevel: ErrorCorrectionLevel) -> Option<u32> {
+    match (version.getVersionNumber(), ecLevel) {
+        (1, ErrorCorrectionLevel::L) => Some(0),
+        (2, ErrorCorrectionLevel::L) => Some(1),
+        (2, ErrorCorrectionLevel::M) => Some(2),
+        (3, ErrorCorrectionLevel::L) => Some(3),
+        (3, ErrorCorrectionLevel::M) => Some(4),
+        (4, ErrorCorrectionLevel::L) => Some(5),
+        (4, ErrorCorrectionLevel::M) => Some(6),
+        (4, ErrorCorrectionLevel::Q) => Some(7),
+        _ => None,
+    }
+}
+
+/**
+ * M1 only supports numeric data, M2 adds alphanumeric data, M3 and M4 support all modes.
+ */
+fn isModeSupported(mode: Mode, version: VersionRef) -> bool {
+    match mode {
+        Mode::NUMERIC => true,
+        Mode::ALPHANUMERIC => version.getVersionNumber() >= 2,
+        Mode::BYTE | Mode::KANJI => version.getVersionNumber() >= 3,
+        _ => false,
+    }
+}
+
+/**
+ * The final data codeword of M1 and M3 is only 4 bits long.
+ */
+fn getDataCapacityBits(version: VersionRef, ecLevel: ErrorCorrectionLevel) -> u32 {
+    let ecBlocks = version.getECBlocksForLevel(ecLevel);
+    let numDataBytes = version.getTotalCodewords() - ecBlocks.getTotalECCodewords();
+    numDataBytes * 8 - if hasHalfCodeword(version) { 4 } else { 0 }
+}
+
+fn hasHalfCodeword(version: VersionRef) -> bool {
+    version.getVersionNumber() % 2 == 1
+}
+
+/**
+ * @return the symbol number of the version and error correction level, or an
+ *  IllegalArgumentException naming both if the version does not support the level
+ */
+fn checkSymbolNumber(version: VersionRef, ecLevel: ErrorCorrectionLevel) -> Result<u32> {
+    getSymbolNumber(version, ecLevel).ok_or_else(|| {
+        Exceptions::illegal_argument_with(format!(
+            "Micro QR Code M{} does not support error correction level {ecLevel}",
+            version.getVersionNumber()
+        ))
+    })
+}
+
+fn willFit(
+    num_letters: u32,
+    mode: Mode,
+    data_bits: &BitArray,
+    version: VersionRef,
+    ecLevel: ErrorCorrectionLevel,
+) -> bool {
+    if getSymbolNumber(version, ecLevel).is_none() || !isModeSupported(mode, version) {
+        return false;
+    }
+    let countBits = mode.CharacterCountBits(version);
+    if num_letters >= 1 << countBits {
+        return false;
+    }
+    let bitsNeeded =
+        Mode::get_codec_mode_bits_length(version) as u32 + countBits + data_bits.get_size() as u32;
+    bitsNeeded <= getDataCapacityBits(version, ecLevel)
+}
+
+fn chooseVersion(
+    num_letters: u32,
+    mode: Mode,
+    data_bits: &BitArray,
+    ecLevel: ErrorCorrectionLevel,
+) -> Result<VersionRef> {
+    for versionNum in 1..=4 {
+        let version = Version::FromNumber(versionNum, true)?;
+        if willFit(num_letters, mode, data_bits, version, ecLevel) {
+            return Ok(version);
+        }
+    }
+    Err(Exceptions::writer_with(format!(
+        "data too big for Micro QR Code {}/{ecLevel:?}",
+        data_bits.get_size()
+    )))
+}
+
+/**
+ * Append the mode indicator, which is 0 bits long in M1 and up to 3 bits long in M4.
+ */
+fn appendModeInfo(mode: Mode, version: VersionRef, bits: &mut BitArray) -> Result<()> {
+    let modeBits = match mode {
+        Mode::NUMERIC => 0,
+        Mode::ALPHANUMERIC => 1,
+        Mode::BYTE => 2,
+        Mode::KANJI => 3,
+        _ => return Err(Exceptions::writer_with(format!("Invalid mode: {mode:?}"))),
+    };
+    bits.appendBits(modeBits, Mode::get_codec_mode_bits_length(version) as usize)
+}
+
+/**
+ * Terminates the data bits, pads them to the capacity of the symbol and appends the error
+ * correction codewords. The final 4 bit data codeword of M1 and M3 is placed as 4 bits, but
+ * counts as the high nibble of a byte in the error correction.
+ */
+fn terminateAndAppendECBytes(
+    mut bits: BitArray,
+    version: VersionRef,
+    ecLevel: ErrorCorrectionLevel,
+) -> Result<BitArray> {
+    let capacity = getDataCapacityBits(version, ecLevel) as usize;
+    if bits.get_size() > capacity {
+        return Err(Exceptions::writer_with(format!(
+            "data bits cannot fit in the Micro QR Code {} > {capacity}",
+            bits.get_size()
+        )));
+    }
+    // The terminator is 3 bits long in M1 and 2 bits longer with every version
+    let terminator =
+        (Mode::get_terminator_bit_length(version) as usize).min(capacity - bits.get_size());
+    bits.appendBits(0, terminator)?;
+    let num_bits_in_last_byte = bits.get_size() & 0x07;
+    if num_bits_in_last_byte > 0 {
+        let padding = (8 - num_bits_in_last_byte).min(capacity - bits.get_size());
+        bits.appendBits(0, padding)?;
+    }
+    let mut i = 0;
+    while bits.get_size() + 8 <= capacity {
+        bits.appendBits(if (i & 0x01) == 0 { 0xEC } else { 0x11 }, 8)?;
+        i += 1;
+    }
+    bits.appendBits(0, capacity - bits.get_size())?;
+
+    let numDataBytes = capacity.div_ceil(8);
+    let dataBytes: Vec<u8> = (0..numDataBytes)
+        .map(|byte| {
+            (0..8).fold(0, |value, bit| {
+                let position = byte * 8 + bit;
+                value << 1 | u8::from(position < capacity && bits.get(position))
+            })
+        })
+        .collect();
+    let numECBytes = version.getTotalCodewords() as usize - numDataBytes;
+    let ecBytes = qrcode_encoder::generateECBytes(&dataBytes, numECBytes)?;
+
+    for ecByte in ecBytes {
+        bits.appendBits(ecByte as u32, 8)?;
+    }
+    Ok(bits)
+}
+
+/**
+ * Choose the mask with the most dark modules on the right and bottom edges, see ISO 18004:2015
+ * 7.8.3.2.
+ */
+fn chooseMaskPattern(bits: &BitArray, symbolNumber: u32, matrix: &mut ByteMatrix) -> Result<u32> {
+    let mut max_score = 0;
+    let mut best_mask_pattern = 0;
+    for maskPattern in 0..NUM_MASK_PATTERNS as u32 {
+        let mut matrix = matrix.clone();
+        buildMatrix(bits, symbolNumber, maskPattern, &mut matrix)?;
+        let dimension = matrix.getWidth();
+        let (mut sum1, mut sum2) = (0, 0);
+        for i in 1..dimension {
+            sum1 += u32::from(matrix.get(dimension - 1, i) == 1);
+            sum2 += u32::from(matrix.get(i, dimension - 1) == 1);
+        }
+        let score = sum1.min(sum2) * 16 + sum1.max(sum2);
+        if score > max_score {
+            max_score = score;
+            best_mask_pattern = maskPattern;
+        }
+    }
+    Ok(best_mask_pattern)
+}
+
+/**
+ * Build the 2D matrix of a Micro QR Code from "dataBits" with the symbol number and mask pattern
+ * of its format information.
+ */
+pub fn buildMatrix(
+    dataBits: &BitArray,
+    symbolNumber: u32,
+    maskPattern: u32,
+    matrix: &mut ByteMatrix,
+) -> Result<()> {
+    matrix_util::clearMatrix(matrix);
+    embedBasicPatterns(matrix)?;
+    embedFormatInfo(symbolNumber, maskPattern, matrix)?;
+    embedDataBits(dataBits, maskPattern, matrix)
+}
+
+/**
+ * Embed the finder pattern, its separator and the timing patterns along the top and left edges.
+ */
+fn embedBasicPatterns(matrix: &mut ByteMatrix) -> Result<()> {
+    matrix_util::embedPositionDetectionPattern(0, 0, matrix);
+    matrix_util::embedHorizontalSeparationPattern(0, 7, matrix)?;
+    matrix_util::embedVerticalSeparationPattern(7, 0, matrix)?;
+
+    for i in 8..matrix.getWidth() {
+        let bit = (i as u8 + 1) % 2;
+        matrix.set(i, 0, bit);
+        matrix.set(0, i, bit);
+    }
+    Ok(())
+}
+
+/**
+ * Embed the format information below and right of the finder pattern, most significant bit
+ * first from the left.
+ */
+fn embedFormatInfo(symbolNumber: u32, maskPattern: u32, matrix: &mut ByteMatrix) -> Result<()> {
+    let formatInfo = symbolNumber << 2 | maskPattern;
+    let formatInfoBits = (formatInfo << 10
+        | matrix_util::calculateBCHCode(formatInfo, FORMAT_INFO_POLY)?)
+        ^ FORMAT_INFO_MASK_MICRO;
+
+    for i in 0..8 {
+        matrix.set_bool(i + 1, 8, formatInfoBits & (1 << (14 - i)) != 0);
+    }
+    for i in 0..7 {
+        matrix.set_bool(8, 7 - i, formatInfoBits & (1 << (6 - i)) != 0);
+    }
+    Ok(())
+}
+
+/**
+ * Embed "dataBits" like in QR Code, but without a vertical timing pattern to skip.
+ */
+fn embedDataBits(dataBits: &BitArray, maskPattern: u32, matrix: &mut ByteMatrix) -> Result<()> {
+    let mut bitIndex = 0;
+    let mut direction: i32 = -1;
+    // Start from the right bottom cell.
+    let mut x = matrix.getWidth() as i32 - 1;
+    let mut y = matrix.getHeight() as i32 - 1;
+    while x > 0 {
+        while y >= 0 && y < matrix.getHeight() as i32 {
+            for i in 0..2 {
+                let xx = (x - i) as u32;
+                // Skip the cell if it's not empty.
+                if !matrix_util::isEmpty(matrix.get(xx, y as u32)) {
+                    continue;
+                }
+                let mut bit = false;
+                if bitIndex < dataBits.get_size() {
+                    bit = dataBits.get(bitIndex);
+                    bitIndex += 1;
+                }
+
+                if mask_util::getDataMaskBit(MASK_PATTERNS[maskPattern as usize], xx, y as u32)? {
+                    bit = !bit;
+                }
+                matrix.set_bool(xx, y as u32, bit);
+            }
+            y += direction;
+        }
+        direction = -direction; // Reverse the direction.
+        y += direction;
+        x -= 2; // Move to the left.
+    }
+    // All bits should be consumed.
+    if bitIndex != dataBits.get_size() {
+        return Err(Exceptions::writer_with(format!(
+            "Not all bits consumed: {}/{}",
+            bitIndex,
+            dataBits.get_size()
+        )));
+    }
+    Ok(())
+}
@@ -2,9 +2,12 @@ mod block_pair;
 mod byte_matrix;
 pub mod mask_util;
 pub mod matrix_util;
+pub mod micro_qrcode_encoder;
 mod minimal_encoder;
+pub mod model1_encoder;
 mod qr_code;
 pub mod qrcode_encoder;
+pub mod rmqr_encoder;
 
 pub use block_pair::*;
 pub use byte_matrix::*;
@@ -16,8 +19,14 @@ mod EncoderTestCase;
 #[cfg(test)]
 mod MaskUtilTestCase;
 #[cfg(test)]
+mod MicroEncoderTestCase;
+#[cfg(test)]
+mod Model1EncoderTestCase;
+#[cfg(test)]
 mod QRCodeTestCase;
 #[cfg(test)]
+mod RMQREncoderTestCase;
+#[cfg(test)]
 mod bit_vector_testcase;
 #[cfg(test)]
 mod matrix_util_testcase;
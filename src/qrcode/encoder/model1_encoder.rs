@@ -0,0 +1,255 @@
+/**
+ * Encodes QR Code Model 1 symbols, the original QR Code of which Model 2 is the successor. Model 1
+ * comes in versions 1 to 14 without alignment patterns and version information, its codewords
+ * are placed in blocks of 2 x 4 or 4 x 2 modules and the format information is masked with its
+ * own pattern. The modules left over by the blocks along the right and bottom edges hold the
+ * extension patterns.
+ */
+use std::collections::HashMap;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{
+    common::{BitArray, CharacterSet, Result},
+    qrcode::decoder::{
+        ErrorCorrectionLevel, Mode, Version, VersionRef, FORMAT_INFO_MASK_QR,
+        FORMAT_INFO_MASK_QR_MODEL1,
+    },
+    EncodeHintType, EncodeHintValue, EncodingHintDictionary, Exceptions,
+};
+
+use super::{mask_util, matrix_util, qrcode_encoder, ByteMatrix, QRCode};
+
+/**
+ * @param content text to encode
+ * @param ecLevel error correction level to use
+ * @return {@link QRCode} representing the encoded Model 1 QR Code
+ * @throws WriterException if encoding can't succeed, because of for example invalid content
+ *   or configuration
+ */
+pub fn encode(content: &str, ecLevel: ErrorCorrectionLevel) -> Result<QRCode> {
+    encode_with_hints(content, ecLevel, &HashMap::new())
+}
+
+pub fn encode_with_hints(
+    content: &str,
+    ec_level: ErrorCorrectionLevel,
+    hints: &EncodingHintDictionary,
+) -> Result<QRCode> {
+    // Model 1 knows neither ECI nor FNC1, so the contents have to be in the default encoding
+    let encoding =
+        if let Some(EncodeHintValue::CharacterSet(v)) = hints.get(&EncodeHintType::CHARACTER_SET) {
+            CharacterSet::get_character_set_by_name(v).ok_or(Exceptions::WRITER)?
+        } else {
+            qrcode_encoder::DEFAULT_BYTE_MODE_ENCODING
+        };
+    if encoding != qrcode_encoder::DEFAULT_BYTE_MODE_ENCODING || encoding.encode(content).is_err() {
+        return Err(Exceptions::writer_with(format!(
+            "QR Code Model 1 can only encode {:?}",
+            qrcode_encoder::DEFAULT_BYTE_MODE_ENCODING
+        )));
+    }
+    if matches!(
+        hints.get(&EncodeHintType::GS1_FORMAT),
+        Some(EncodeHintValue::Gs1Format(true))
+    ) {
+        return Err(Exceptions::writer_with(
+            "QR Code Model 1 does not support GS1 formatted data",
+        ));
+    }
+
+    let mode = qrcode_encoder::chooseModeWithEncoding(content, encoding);
+
+    let mut data_bits = BitArray::new();
+    qrcode_encoder::appendBytes(content, mode, &mut data_bits, encoding)?;
+    let num_letters = if mode == Mode::BYTE {
+        data_bits.getSizeInBytes()
+    } else {
+        content.graphemes(true).count()
+    } as u32;
+
+    let version =
+        if let Some(EncodeHintValue::QrVersion(v)) = hints.get(&EncodeHintType::QR_VERSION) {
+            let version = v
+                .parse()
+                .ok()
+                .and_then(|number| Version::FromNumberModel1(number).ok())
+                .ok_or_else(|| {
+                    Exceptions::writer_with(format!("Invalid QR Code Model 1 version: {v}"))
+                })?;
+            if !willFit(num_letters, mode, &data_bits, version, ec_level) {
+                return Err(Exceptions::writer_with(
+                    "Data too big for requested version",
+                ));
+            }
+            version
+        } else {
+            chooseVersion(num_letters, mode, &data_bits, ec_level)?
+        };
+
+    let mut header_and_data_bits = BitArray::new();
+    qrcode_encoder::appendModeInfo(mode, &mut header_and_data_bits)?;
+    qrcode_encoder::appendLengthInfo(num_letters, version, mode, &mut header_and_data_bits)?;
+    header_and_data_bits.appendBitArray(data_bits);
+
+    let ecBlocks = version.getECBlocksForLevel(ec_level);
+    let numDataBytes = version.getTotalCodewords() - ecBlocks.getTotalECCodewords();
+    qrcode_encoder::terminateBits(numDataBytes, &mut header_and_data_bits)?;
+
+    let final_bits = qrcode_encoder::interleaveWithECBytes(
+        &header_and_data_bits,
+        version.getTotalCodewords(),
+        numDataBytes,
+        ecBlocks.getNumBlocks(),
+    )?;
+
+    let mut qrCode = QRCode::new();
+
+    qrCode.setECLevel(ec_level);
+    qrCode.setMode(mode);
+    qrCode.setVersion(version);
+
+    let dimension = version.getDimensionForVersion();
+    let mut matrix = ByteMatrix::new(dimension, dimension);
+
+    // Enable manual selection of the pattern to be used via hint
+    let hint_mask_pattern = match hints.get(&EncodeHintType::QR_MASK_PATTERN) {
+        Some(EncodeHintValue::QrMaskPattern(v)) => v
+            .parse()
+            .ok()
+            .filter(|mask_pattern| QRCode::isValidMaskPattern(*mask_pattern)),
+        _ => None,
+    };
+    let mask_pattern = if let Some(mask_pattern) = hint_mask_pattern {
+        mask_pattern
+    } else {
+        chooseMaskPattern(&final_bits, ec_level, version, &matrix)?
+    };
+    qrCode.setMaskPattern(mask_pattern);
+
+    buildMatrix(&final_bits, ec_level, version, mask_pattern, &mut matrix)?;
+    qrCode.setMatrix(matrix);
+
+    Ok(qrCode)
+}
+
+fn willFit(
+    num_letters: u32,
+    mode: Mode,
+    data_bits: &BitArray,
+    version: VersionRef,
+    ecLevel: ErrorCorrectionLevel,
+) -> bool {
+    let countBits = mode.getCharacterCountBits(version) as u32;
+    if num_letters >= 1 << countBits {
+        return false;
+    }
+    let bitsNeeded = 4 + countBits + data_bits.get_size() as u32;
+    qrcode_encoder::willFit(bitsNeeded, version, &ecLevel)
+}
+
+/**
+ * Choose the smallest version which holds the data.
+ */
+fn chooseVersion(
+    num_letters: u32,
+    mode: Mode,
+    data_bits: &BitArray,
+    ecLevel: ErrorCorrectionLevel,
+) -> Result<VersionRef> {
+    for versionNum in 1..=14 {
+        let version = Version::FromNumberModel1(versionNum)?;
+        if willFit(num_letters, mode, data_bits, version, ecLevel) {
+            return Ok(version);
+        }
+    }
+    Err(Exceptions::writer_with(format!(
+        "data too big for QR Code Model 1 {}/{ecLevel:?}",
+        data_bits.get_size()
+    )))
+}
+
+fn chooseMaskPattern(
+    bits: &BitArray,
+    ec_level: ErrorCorrectionLevel,
+    version: VersionRef,
+    matrix: &ByteMatrix,
+) -> Result<i32> {
+    let mut min_penalty = u32::MAX;
+    let mut best_mask_pattern = 0;
+    for maskPattern in 0..QRCode::NUM_MASK_PATTERNS {
+        let mut matrix = matrix.clone();
+        buildMatrix(bits, ec_level, version, maskPattern, &mut matrix)?;
+        let penalty = qrcode_encoder::calculateMaskPenalty(&matrix);
+        if penalty < min_penalty {
+            min_penalty = penalty;
+            best_mask_pattern = maskPattern;
+        }
+    }
+    Ok(best_mask_pattern)
+}
+
+/**
+ * Build the 2D matrix of a Model 1 QR Code from "dataBits" with "ecLevel", "version" and
+ * "maskPattern".
+ */
+pub fn buildMatrix(
+    dataBits: &BitArray,
+    ecLevel: ErrorCorrectionLevel,
+    version: VersionRef,
+    maskPattern: i32,
+    matrix: &mut ByteMatrix,
+) -> Result<()> {
+    matrix_util::clearMatrix(matrix);
+    // Model 1 has neither alignment patterns nor version information
+    matrix_util::embedPositionDetectionPatternsAndSeparators(matrix)?;
+    matrix_util::embedDarkDotAtLeftBottomCorner(matrix)?;
+    matrix_util::embedTimingPatterns(matrix);
+
+    let mut typeInfoBits = BitArray::new();
+    matrix_util::makeTypeInfoBits(&ecLevel, maskPattern as u32, &mut typeInfoBits)?;
+    let mut maskBits = BitArray::new();
+    maskBits.appendBits(FORMAT_INFO_MASK_QR ^ FORMAT_INFO_MASK_QR_MODEL1, 15)?;
+    typeInfoBits.xor(&maskBits)?;
+    matrix_util::embedTypeInfoBits(&typeInfoBits, matrix);
+
+    embedDataBits(dataBits, version, maskPattern, matrix)?;
+    embedExtensionPatterns(matrix);
+    Ok(())
+}
+
+fn embedDataBits(
+    dataBits: &BitArray,
+    version: VersionRef,
+    maskPattern: i32,
+    matrix: &mut ByteMatrix,
+) -> Result<()> {
+    let codewords = version.buildCodewordModulesModel1();
+    if dataBits.get_size() != codewords.len() * 8 {
+        return Err(Exceptions::writer_with(format!(
+            "Not all bits consumed: {}/{}",
+            codewords.len() * 8,
+            dataBits.get_size()
+        )));
+    }
+    for (bitIndex, (x, y)) in codewords.into_iter().flatten().enumerate() {
+        let bit = dataBits.get(bitIndex) ^ mask_util::getDataMaskBit(maskPattern as u32, x, y)?;
+        matrix.set_bool(x, y, bit);
+    }
+    Ok(())
+}
+
+/**
+ * Fill the modules left over by the codeword blocks along the right and bottom edges. They are
+ * drawn as a checkerboard, an approximation of the extension patterns of the specification which
+ * readers don't depend on.
+ */
+fn embedExtensionPatterns(matrix: &mut ByteMatrix) {
+    for y in 0..matrix.getHeight() {
+        for x in 0..matrix.getWidth() {
+            if matrix_util::isEmpty(matrix.get(x, y)) {
+                matrix.set_bool(x, y, (x + y).is_multiple_of(2));
+            }
+        }
+    }
+}
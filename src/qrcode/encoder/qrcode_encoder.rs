@@ -70,6 +70,49 @@ pub fn encode_with_hints(
     content: &str,
     ec_level: ErrorCorrectionLevel,
     hints: &EncodingHintDictionary,
+) -> Result<QRCode> {
+    encode_with_structured_append_header(content, ec_level, hints, None, None)
+}
+
+/**
+ * Encodes one symbol of a structured append sequence. The structured append header precedes
+ * all other data, {@link EncodeHintType#QR_COMPACT} is not supported.
+ *
+ * @param index position of the symbol in the sequence, 0 to 15
+ * @param count number of symbols in the sequence, 1 to 16
+ * @param parity the parity of the whole message, all its bytes XORed
+ * @param encoding the character set the parity was taken over, which byte mode uses unless
+ *  {@link EncodeHintType#CHARACTER_SET} says otherwise
+ */
+pub fn encode_structured_append(
+    content: &str,
+    ec_level: ErrorCorrectionLevel,
+    hints: &EncodingHintDictionary,
+    index: u32,
+    count: u32,
+    parity: u8,
+    encoding: CharacterSet,
+) -> Result<QRCode> {
+    if index >= count || !(1..=16).contains(&count) {
+        return Err(Exceptions::illegal_argument_with(format!(
+            "invalid structured append position {index} of {count}"
+        )));
+    }
+    encode_with_structured_append_header(
+        content,
+        ec_level,
+        hints,
+        Some((index, count, parity)),
+        Some(encoding),
+    )
+}
+
+fn encode_with_structured_append_header(
+    content: &str,
+    ec_level: ErrorCorrectionLevel,
+    hints: &EncodingHintDictionary,
+    structured_append: Option<(u32, u32, u8)>,
+    message_encoding: Option<CharacterSet>,
 ) -> Result<QRCode> {
     let version;
     let mut header_and_data_bits;
@@ -100,7 +143,7 @@ pub fn encode_with_hints(
         }
     }
 
-    if has_compaction_hint {
+    if has_compaction_hint && structured_append.is_none() {
         mode = Mode::BYTE;
 
         // dbg!("consider this a huge risk, not sure if it should be defaulting to default");
@@ -120,6 +163,12 @@ pub fn encode_with_hints(
         //Switch to default encoding
         let encoding = if let Some(encoding) = encoding {
             encoding
+        } else if let Some(encoding) =
+            message_encoding.filter(|encoding| *encoding != DEFAULT_BYTE_MODE_ENCODING)
+        {
+            // a segment of the message keeps to its encoding, even if the default would do
+            has_encoding_hint = true;
+            encoding
         } else if let Ok(_encs) = DEFAULT_BYTE_MODE_ENCODING.encode(content) {
             DEFAULT_BYTE_MODE_ENCODING
         } else {
@@ -135,6 +184,14 @@ pub fn encode_with_hints(
         // length, as well as "header" segments like an ECI segment.
         let mut header_bits = BitArray::new();
 
+        // The structured append header comes first: position, count - 1 and parity
+        if let Some((index, count, parity)) = structured_append {
+            appendModeInfo(Mode::STRUCTURED_APPEND, &mut header_bits)?;
+            header_bits.appendBits(index, 4)?;
+            header_bits.appendBits(count - 1, 4)?;
+            header_bits.appendBits(parity as u32, 8)?;
+        }
+
         // Append ECI segment if applicable
         if mode == Mode::BYTE && has_encoding_hint {
             appendECI(encoding.into(), &mut header_bits)?;
@@ -302,7 +359,7 @@ pub fn chooseMode(content: &str) -> Mode {
  * Choose the best mode by examining the content. Note that 'encoding' is used as a hint;
  * if it is Shift_JIS, and the input is only double-byte Kanji, then we return {@link Mode#KANJI}.
  */
-fn chooseModeWithEncoding(content: &str, encoding: CharacterSet) -> Mode {
+pub(super) fn chooseModeWithEncoding(content: &str, encoding: CharacterSet) -> Mode {
     if SHIFT_JIS_CHARSET == encoding && isOnlyDoubleByteKanji(content) {
         // Choose Kanji mode if all input are double-byte characters
         return Mode::KANJI;
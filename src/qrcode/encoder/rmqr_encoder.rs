@@ -0,0 +1,389 @@
+/**
+ * Encodes Rectangular Micro QR Code (rMQR) symbols, see ISO 23941:2022. rMQR shares the data
+ * modes and the Reed-Solomon code of QR Code, but comes in 32 rectangular sizes with a single
+ * finder pattern, a fixed data mask, 3 bit mode indicators and an 18 bit format information that
+ * also holds the version.
+ */
+use std::collections::HashMap;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{
+    common::{BitArray, CharacterSet, Eci, Result},
+    qrcode::decoder::{
+        ErrorCorrectionLevel, FormatInformation, Mode, Version, VersionRef, FORMAT_INFO_MASKS_RMQR,
+        RMQR_SIZES,
+    },
+    EncodeHintType, EncodeHintValue, EncodingHintDictionary, Exceptions,
+};
+
+use super::{mask_util, matrix_util, qrcode_encoder, ByteMatrix, QRCode};
+
+/// rMQR always uses the QR Code data mask 4
+pub const MASK_PATTERN: u32 = 4;
+
+/**
+ * @param content text to encode
+ * @param ecLevel error correction level to use, rMQR only supports M and H
+ * @return {@link QRCode} representing the encoded rMQR code
+ * @throws WriterException if encoding can't succeed, because of for example invalid content
+ *   or configuration
+ */
+pub fn encode(content: &str, ecLevel: ErrorCorrectionLevel) -> Result<QRCode> {
+    encode_with_hints(content, ecLevel, &HashMap::new())
+}
+
+pub fn encode_with_hints(
+    content: &str,
+    ec_level: ErrorCorrectionLevel,
+    hints: &EncodingHintDictionary,
+) -> Result<QRCode> {
+    if ec_level != ErrorCorrectionLevel::M && ec_level != ErrorCorrectionLevel::H {
+        return Err(Exceptions::writer_with(format!(
+            "rMQR Code only supports error correction level M or H, but got {ec_level:?}"
+        )));
+    }
+
+    let has_gs1_format_hint = matches!(
+        hints.get(&EncodeHintType::GS1_FORMAT),
+        Some(EncodeHintValue::Gs1Format(true))
+    );
+
+    // Determine what character encoding has been specified by the caller, if any
+    let mut has_encoding_hint = false;
+    let encoding =
+        if let Some(EncodeHintValue::CharacterSet(v)) = hints.get(&EncodeHintType::CHARACTER_SET) {
+            has_encoding_hint = true;
+            CharacterSet::get_character_set_by_name(v).ok_or(Exceptions::WRITER)?
+        } else if qrcode_encoder::DEFAULT_BYTE_MODE_ENCODING
+            .encode(content)
+            .is_ok()
+        {
+            qrcode_encoder::DEFAULT_BYTE_MODE_ENCODING
+        } else {
+            has_encoding_hint = true;
+            CharacterSet::UTF8
+        };
+
+    let mode = qrcode_encoder::chooseModeWithEncoding(content, encoding);
+
+    // This will store the header information, like the ECI and FNC1 segments.
+    let mut header_bits = BitArray::new();
+    if mode == Mode::BYTE && has_encoding_hint {
+        appendModeInfo(Mode::ECI, &mut header_bits)?;
+        // This is correct for values up to 127, which is all we need now.
+        header_bits.appendBits(Eci::from(encoding) as u32, 8)?;
+    }
+    if has_gs1_format_hint {
+        appendModeInfo(Mode::FNC1_FIRST_POSITION, &mut header_bits)?;
+    }
+
+    let mut data_bits = BitArray::new();
+    qrcode_encoder::appendBytes(content, mode, &mut data_bits, encoding)?;
+    let num_letters = if mode == Mode::BYTE {
+        data_bits.getSizeInBytes()
+    } else if mode == Mode::KANJI {
+        data_bits.get_size() / 13
+    } else {
+        content.graphemes(true).count()
+    } as u32;
+
+    let version = if let Some(EncodeHintValue::RmqrVersion(v)) =
+        hints.get(&EncodeHintType::RMQR_VERSION)
+    {
+        let version = getVersionForName(v)
+            .ok_or_else(|| Exceptions::writer_with(format!("Invalid rMQR Code version: {v}")))?;
+        if !willFit(
+            num_letters,
+            mode,
+            &header_bits,
+            &data_bits,
+            version,
+            ec_level,
+        ) {
+            return Err(Exceptions::writer_with(
+                "Data too big for requested version",
+            ));
+        }
+        version
+    } else {
+        chooseVersion(num_letters, mode, &header_bits, &data_bits, ec_level)?
+    };
+
+    let mut header_and_data_bits = header_bits;
+    appendModeInfo(mode, &mut header_and_data_bits)?;
+    header_and_data_bits.appendBits(num_letters, mode.CharacterCountBits(version) as usize)?;
+    header_and_data_bits.appendBitArray(data_bits);
+
+    let ecBlocks = version.getECBlocksForLevel(ec_level);
+    let numDataBytes = version.getTotalCodewords() - ecBlocks.getTotalECCodewords();
+    terminateBits(numDataBytes, &mut header_and_data_bits)?;
+
+    let final_bits = qrcode_encoder::interleaveWithECBytes(
+        &header_and_data_bits,
+        version.getTotalCodewords(),
+        numDataBytes,
+        ecBlocks.getNumBlocks(),
+    )?;
+
+    let mut qrCode = QRCode::new();
+
+    qrCode.setECLevel(ec_level);
+    qrCode.setMode(mode);
+    qrCode.setVersion(version);
+    qrCode.setMaskPattern(MASK_PATTERN as i32);
+
+    let (width, height) = Version::DimensionOfVersionRMQR(version.getVersionNumber());
+    let mut matrix = ByteMatrix::new(width, height);
+    buildMatrix(&final_bits, ec_level, version, &mut matrix)?;
+    qrCode.setMatrix(matrix);
+
+    Ok(qrCode)
+}
+
+/**
+ * Looks up an rMQR version either by its number, 1 to 32, or by its size name such as "R7x43".
+ */
+pub fn getVersionForName(name: &str) -> Option<VersionRef> {
+    if let Ok(versionNumber) = name.parse::<u32>() {
+        return Version::FromNumberRMQR(versionNumber).ok();
+    }
+    let (height, width) = name
+        .strip_prefix(['R', 'r'])?
+        .split_once(['x', 'X'])
+        .and_then(|(h, w)| Some((h.parse::<u32>().ok()?, w.parse::<u32>().ok()?)))?;
+    Version::FromDimensionsRMQR(width, height).ok()
+}
+
+fn willFit(
+    num_letters: u32,
+    mode: Mode,
+    header_bits: &BitArray,
+    data_bits: &BitArray,
+    version: VersionRef,
+    ecLevel: ErrorCorrectionLevel,
+) -> bool {
+    let countBits = mode.CharacterCountBits(version);
+    if num_letters >= 1 << countBits {
+        return false;
+    }
+    let ecBlocks = version.getECBlocksForLevel(ecLevel);
+    let numDataBytes = version.getTotalCodewords() - ecBlocks.getTotalECCodewords();
+    let bitsNeeded = header_bits.get_size() as u32
+        + Mode::get_codec_mode_bits_length(version) as u32
+        + countBits
+        + data_bits.get_size() as u32;
+    bitsNeeded <= numDataBytes * 8
+}
+
+/**
+ * Choose the version with the smallest area which holds the data. On a tie the lower version,
+ * which is the less tall one, wins.
+ */
+fn chooseVersion(
+    num_letters: u32,
+    mode: Mode,
+    header_bits: &BitArray,
+    data_bits: &BitArray,
+    ecLevel: ErrorCorrectionLevel,
+) -> Result<VersionRef> {
+    let mut best: Option<VersionRef> = None;
+    for (versionNum, (width, height)) in (1..).zip(RMQR_SIZES) {
+        let version = Version::FromNumberRMQR(versionNum)?;
+        let smaller = best.is_none_or(|best| {
+            let (bestWidth, bestHeight) = Version::DimensionOfVersionRMQR(best.getVersionNumber());
+            width * height < bestWidth * bestHeight
+        });
+        if smaller && willFit(num_letters, mode, header_bits, data_bits, version, ecLevel) {
+            best = Some(version);
+        }
+    }
+    best.ok_or_else(|| {
+        Exceptions::writer_with(format!(
+            "data too big for rMQR Code {}/{ecLevel:?}",
+            data_bits.get_size()
+        ))
+    })
+}
+
+fn appendModeInfo(mode: Mode, bits: &mut BitArray) -> Result<()> {
+    bits.appendBits(mode.getBitsRMQR()? as u32, 3)
+}
+
+/**
+ * Terminate bits with the 3 bit terminator and pad them to "num_data_bytes" bytes.
+ */
+fn terminateBits(num_data_bytes: u32, bits: &mut BitArray) -> Result<()> {
+    let capacity = num_data_bytes as usize * 8;
+    if bits.get_size() > capacity {
+        return Err(Exceptions::writer_with(format!(
+            "data bits cannot fit in the rMQR Code {} > {capacity}",
+            bits.get_size()
+        )));
+    }
+    bits.appendBits(0, 3.min(capacity - bits.get_size()))?;
+    let num_bits_in_last_byte = bits.get_size() & 0x07;
+    if num_bits_in_last_byte > 0 {
+        bits.appendBits(0, 8 - num_bits_in_last_byte)?;
+    }
+    let mut i = 0;
+    while bits.get_size() < capacity {
+        bits.appendBits(if (i & 0x01) == 0 { 0xEC } else { 0x11 }, 8)?;
+        i += 1;
+    }
+    Ok(())
+}
+
+/**
+ * Build the 2D matrix of an rMQR Code from "dataBits" with the error correction level and
+ * version of its format information.
+ */
+pub fn buildMatrix(
+    dataBits: &BitArray,
+    ecLevel: ErrorCorrectionLevel,
+    version: VersionRef,
+    matrix: &mut ByteMatrix,
+) -> Result<()> {
+    matrix_util::clearMatrix(matrix);
+    embedBasicPatterns(version, matrix)?;
+    embedFormatInfo(ecLevel, version, matrix)?;
+    embedDataBits(dataBits, matrix)
+}
+
+/**
+ * Embed the timing patterns along the edges, the finder pattern, the finder sub pattern, the
+ * alignment patterns with their vertical timing patterns and the corner finder patterns.
+ */
+fn embedBasicPatterns(version: VersionRef, matrix: &mut ByteMatrix) -> Result<()> {
+    let width = matrix.getWidth();
+    let height = matrix.getHeight();
+
+    for x in 0..width {
+        matrix.set_bool(x, 0, x % 2 == 0);
+        matrix.set_bool(x, height - 1, x % 2 == 0);
+    }
+    for y in 0..height {
+        matrix.set_bool(0, y, y % 2 == 0);
+        matrix.set_bool(width - 1, y, y % 2 == 0);
+    }
+
+    for &center in version.getAlignmentPatternCenters() {
+        for y in 3..height - 3 {
+            matrix.set_bool(center, y, y % 2 == 0);
+        }
+        for centerY in [1, height - 2] {
+            for y in centerY - 1..=centerY + 1 {
+                for x in center - 1..=center + 1 {
+                    matrix.set_bool(x, y, x != center || y != centerY);
+                }
+            }
+        }
+    }
+
+    matrix_util::embedPositionDetectionPattern(0, 0, matrix);
+    for y in 0..8.min(height - 1) {
+        matrix.set(7, y, 0);
+    }
+    if height > 7 {
+        for x in 0..8 {
+            matrix.set(x, 7, 0);
+        }
+    }
+
+    matrix_util::embedPositionAdjustmentPattern(width - 5, height - 5, matrix);
+
+    matrix.set(width - 2, 0, 1);
+    matrix.set(width - 2, 1, 1);
+    matrix.set(width - 1, 1, 1);
+    if height > 9 {
+        matrix.set(1, height - 1, 1);
+        matrix.set(1, height - 2, 1);
+        matrix.set(0, height - 2, 1);
+    }
+    Ok(())
+}
+
+/**
+ * Embed both copies of the format information, most significant bit first. See ISO 23941:2022
+ * 7.9.2.
+ */
+fn embedFormatInfo(
+    ecLevel: ErrorCorrectionLevel,
+    version: VersionRef,
+    matrix: &mut ByteMatrix,
+) -> Result<()> {
+    let width = matrix.getWidth();
+    let height = matrix.getHeight();
+    let formatInfo =
+        u32::from(ecLevel == ErrorCorrectionLevel::H) << 5 | (version.getVersionNumber() - 1);
+    let formatInfoBits = FormatInformation::EncodeRMQR(formatInfo);
+
+    let mut leftCoordinates = vec![(11, 3), (11, 2), (11, 1)];
+    let mut rightCoordinates = vec![
+        (width - 3, height - 6),
+        (width - 4, height - 6),
+        (width - 5, height - 6),
+    ];
+    for i in 0..3 {
+        for j in 0..5 {
+            leftCoordinates.push((10 - i, 5 - j));
+            rightCoordinates.push((width - 6 - i, height - 2 - j));
+        }
+    }
+
+    for (coordinates, mask) in [leftCoordinates, rightCoordinates]
+        .iter()
+        .zip(FORMAT_INFO_MASKS_RMQR)
+    {
+        let bits = formatInfoBits ^ mask;
+        for (i, &(x, y)) in coordinates.iter().enumerate() {
+            matrix.set_bool(x, y, bits & (1 << (17 - i)) != 0);
+        }
+    }
+    Ok(())
+}
+
+/**
+ * Embed "dataBits" like in QR Code, starting left of the timing pattern on the right edge.
+ * Modules left over after the last codeword are remainder bits and stay light before masking.
+ */
+fn embedDataBits(dataBits: &BitArray, matrix: &mut ByteMatrix) -> Result<()> {
+    let mut bitIndex = 0;
+    let mut direction: i32 = -1;
+    let mut x = matrix.getWidth() as i32 - 2;
+    let mut y = matrix.getHeight() as i32 - 1;
+    while x > 0 {
+        while y >= 0 && y < matrix.getHeight() as i32 {
+            for i in 0..2 {
+                let xx = (x - i) as u32;
+                // Skip the cell if it's not empty.
+                if !matrix_util::isEmpty(matrix.get(xx, y as u32)) {
+                    continue;
+                }
+                let mut bit = false;
+                if bitIndex < dataBits.get_size() {
+                    bit = dataBits.get(bitIndex);
+                    bitIndex += 1;
+                }
+
+                if mask_util::getDataMaskBit(MASK_PATTERN, xx, y as u32)? {
+                    bit = !bit;
+                }
+                matrix.set_bool(xx, y as u32, bit);
+            }
+            y += direction;
+        }
+        direction = -direction; // Reverse the direction.
+        y += direction;
+        x -= 2; // Move to the left.
+    }
+    // All bits should be consumed.
+    if bitIndex != dataBits.get_size() {
+        return Err(Exceptions::writer_with(format!(
+            "Not all bits consumed: {}/{}",
+            bitIndex,
+            dataBits.get_size()
+        )));
+    }
+    Ok(())
+}
@@ -17,20 +17,30 @@
 use std::collections::HashMap;
 
 use crate::{
-    common::{BitMatrix, Result},
+    common::{splitIntoSegments, BitMatrix, CharacterSet, Result},
     BarcodeFormat, EncodeHintType, EncodeHintValue, Exceptions, Writer,
 };
 
 use super::{
     decoder::ErrorCorrectionLevel,
-    encoder::{qrcode_encoder, QRCode},
+    encoder::{micro_qrcode_encoder, model1_encoder, qrcode_encoder, rmqr_encoder, QRCode},
 };
 
 const QUIET_ZONE_SIZE: i32 = 4;
+const MICRO_QUIET_ZONE_SIZE: i32 = 2;
 
 /**
  * This object renders a QR Code as a BitMatrix 2D array of greyscale values.
  *
+ * Micro QR Codes are rendered when the format is {@link BarcodeFormat#MICRO_QR_CODE}, the
+ * version can be set with {@link EncodeHintType#MICRO_QR_VERSION}.
+ *
+ * rMQR Codes are rendered when the format is {@link BarcodeFormat#RECTANGULAR_MICRO_QR_CODE},
+ * the size can be set with {@link EncodeHintType#RMQR_VERSION}. They default to error correction
+ * level M, the lowest level rMQR supports.
+ *
+ * QR Code Model 1 symbols are rendered when {@link EncodeHintType#QR_MODEL} is 1.
+ *
  * @author dswitkin@google.com (Daniel Switkin)
  */
 #[derive(Default)]
@@ -62,9 +72,11 @@ impl Writer for QRCodeWriter {
             return Err(Exceptions::illegal_argument_with("found empty contents"));
         }
 
-        if format != &BarcodeFormat::QR_CODE {
+        let isMicro = format == &BarcodeFormat::MICRO_QR_CODE;
+        let isRMQR = format == &BarcodeFormat::RECTANGULAR_MICRO_QR_CODE;
+        if format != &BarcodeFormat::QR_CODE && !isMicro && !isRMQR {
             return Err(Exceptions::illegal_argument_with(format!(
-                "can only encode QR_CODE, but got {format:?}"
+                "can only encode QR_CODE, MICRO_QR_CODE or RECTANGULAR_MICRO_QR_CODE, but got {format:?}"
             )));
             // throw new IllegalArgumentException("Can only encode QR_CODE, but got " + format);
         }
@@ -75,10 +87,27 @@ impl Writer for QRCodeWriter {
             )));
         }
 
+        let isModel1 = match hints.get(&EncodeHintType::QR_MODEL) {
+            Some(EncodeHintValue::QrModel(model)) if format == &BarcodeFormat::QR_CODE => {
+                match model.as_str() {
+                    "1" => true,
+                    "2" => false,
+                    _ => {
+                        return Err(Exceptions::illegal_argument_with(format!(
+                            "QR Code model must be 1 or 2, but got {model}"
+                        )))
+                    }
+                }
+            }
+            _ => false,
+        };
+
         let errorCorrectionLevel = if let Some(EncodeHintValue::ErrorCorrection(ec_level)) =
             hints.get(&EncodeHintType::ERROR_CORRECTION)
         {
             ec_level.parse()?
+        } else if isRMQR {
+            ErrorCorrectionLevel::M
         } else {
             ErrorCorrectionLevel::L
         };
@@ -88,17 +117,119 @@ impl Writer for QRCodeWriter {
                 margin
                     .parse::<i32>()
                     .map_err(|e| Exceptions::parse_with(format!("could not parse {margin}: {e}")))?
+            } else if isMicro || isRMQR {
+                MICRO_QUIET_ZONE_SIZE
             } else {
                 QUIET_ZONE_SIZE
             };
 
-        let code = qrcode_encoder::encode_with_hints(contents, errorCorrectionLevel, hints)?;
+        let code = if isMicro {
+            micro_qrcode_encoder::encode_with_hints(contents, errorCorrectionLevel, hints)?
+        } else if isRMQR {
+            rmqr_encoder::encode_with_hints(contents, errorCorrectionLevel, hints)?
+        } else if isModel1 {
+            model1_encoder::encode_with_hints(contents, errorCorrectionLevel, hints)?
+        } else {
+            qrcode_encoder::encode_with_hints(contents, errorCorrectionLevel, hints)?
+        };
 
         Self::renderRXingResult(&code, width, height, quietZone)
     }
 }
 
 impl QRCodeWriter {
+    /**
+     * Splits the contents across as few QR Codes as the hints allow, up to 16 or
+     * {@link EncodeHintType#STRUCTURED_APPEND_MAX_SYMBOLS}. The size of the symbols can be
+     * limited with {@link EncodeHintType#QR_VERSION}.
+     *
+     * Each symbol carries a structured append header with its position, the number of symbols
+     * and the parity of the whole message. Contents that fit into a single symbol are encoded
+     * without header.
+     *
+     * @return one bit matrix per symbol, in sequence order
+     */
+    pub fn encode_structured_append(
+        &self,
+        contents: &str,
+        width: i32,
+        height: i32,
+        hints: &crate::EncodingHintDictionary,
+    ) -> Result<Vec<BitMatrix>> {
+        if let Ok(single) =
+            self.encode_with_hints(contents, &BarcodeFormat::QR_CODE, width, height, hints)
+        {
+            return Ok(vec![single]);
+        }
+
+        let errorCorrectionLevel = if let Some(EncodeHintValue::ErrorCorrection(ec_level)) =
+            hints.get(&EncodeHintType::ERROR_CORRECTION)
+        {
+            ec_level.parse()?
+        } else {
+            ErrorCorrectionLevel::L
+        };
+        let quietZone =
+            if let Some(EncodeHintValue::Margin(margin)) = hints.get(&EncodeHintType::MARGIN) {
+                margin
+                    .parse::<i32>()
+                    .map_err(|e| Exceptions::parse_with(format!("could not parse {margin}: {e}")))?
+            } else {
+                QUIET_ZONE_SIZE
+            };
+        let maxSymbols = if let Some(EncodeHintValue::StructuredAppendMaxSymbols(max)) =
+            hints.get(&EncodeHintType::STRUCTURED_APPEND_MAX_SYMBOLS)
+        {
+            (*max).min(16)
+        } else {
+            16
+        };
+
+        // The parity is taken over the bytes of the message in the encoding of the symbols
+        let encoding = if let Some(EncodeHintValue::CharacterSet(name)) =
+            hints.get(&EncodeHintType::CHARACTER_SET)
+        {
+            CharacterSet::get_character_set_by_name(name).ok_or(Exceptions::WRITER)?
+        } else if CharacterSet::ISO8859_1.encode(contents).is_ok() {
+            CharacterSet::ISO8859_1
+        } else {
+            CharacterSet::UTF8
+        };
+        let parity = encoding
+            .encode(contents)?
+            .iter()
+            .fold(0, |parity, byte| parity ^ byte);
+
+        let segments = splitIntoSegments(contents, maxSymbols as usize, |segment| {
+            qrcode_encoder::encode_structured_append(
+                segment,
+                errorCorrectionLevel,
+                hints,
+                0,
+                16,
+                parity,
+                encoding,
+            )
+            .is_ok()
+        })?;
+        segments
+            .iter()
+            .enumerate()
+            .map(|(index, segment)| {
+                let code = qrcode_encoder::encode_structured_append(
+                    segment,
+                    errorCorrectionLevel,
+                    hints,
+                    index as u32,
+                    segments.len() as u32,
+                    parity,
+                    encoding,
+                )?;
+                Self::renderRXingResult(&code, width, height, quietZone)
+            })
+            .collect()
+    }
+
     // Note that the input matrix uses 0 == white, 1 == black, while the output matrix uses
     // 0 == black, 255 == white (i.e. an 8 bit greyscale bitmap).
     fn renderRXingResult(
@@ -126,6 +126,20 @@ impl RXingResult {
             RXingResultMetadataType::STRUCTURED_APPEND_SEQUENCE,
             RXingResultMetadataValue::StructuredAppendSequence(res.structuredAppend().index),
         );
+        if res.structuredAppend().index >= 0 {
+            meta_data.insert(
+                RXingResultMetadataType::STRUCTURED_APPEND_COUNT,
+                RXingResultMetadataValue::StructuredAppendCount(
+                    res.structuredAppend().count.max(0),
+                ),
+            );
+            if !res.structuredAppend().id.is_empty() {
+                meta_data.insert(
+                    RXingResultMetadataType::STRUCTURED_APPEND_ID,
+                    RXingResultMetadataValue::StructuredAppendId(res.structuredAppend().id.clone()),
+                );
+            }
+        }
         meta_data.insert(
             RXingResultMetadataType::SYMBOLOGY_IDENTIFIER,
             RXingResultMetadataValue::SymbologyIdentifier(res.symbologyIdentifier()),
@@ -102,6 +102,19 @@ pub enum RXingResultMetadataType {
      */
     STRUCTURED_APPEND_PARITY,
 
+    /**
+     * If the code format supports structured append and the current scanned code is part of one then the
+     * number of symbols in the sequence is given with it, 0 if the symbol doesn't tell.
+     * The sequence number is then the 0-based position of the symbol in the sequence.
+     */
+    STRUCTURED_APPEND_COUNT,
+
+    /**
+     * If the code format supports structured append and the current scanned code is part of one then the
+     * file identification shared by all symbols of the sequence is given with it, if there is one.
+     */
+    STRUCTURED_APPEND_ID,
+
     /**
      * Barcode Symbology Identifier.
      * Note: According to the GS1 specification the identifier may have to replace a leading FNC1/GS character
@@ -114,6 +127,30 @@ pub enum RXingResultMetadataType {
     CONTENT_TYPE,
 
     IS_INVERTED,
+
+    /**
+     * The value 0 to 255 of an Aztec Rune as an {@link Integer}. The text of the result holds the
+     * same value as three decimal digits.
+     */
+    AZTEC_RUNE_VALUE,
+
+    /**
+     * Set if the result is the linear component of a GS1 Composite symbol, whose linkage flag
+     * tells there is a 2D component printed above it.
+     */
+    IS_GS1_COMPOSITE,
+
+    /**
+     * The element strings of the 2D component of a GS1 Composite symbol, as a {@link String},
+     * such as "(10)4567". The text of the result is that of the linear component.
+     */
+    GS1_COMPOSITE_COMPONENT,
+
+    /**
+     * The model of a QR Code, 1 for the original QR Code and 2 for the current one, as an
+     * {@link Integer}.
+     */
+    QR_MODEL,
 }
 
 impl From<String> for RXingResultMetadataType {
@@ -138,12 +175,24 @@ impl From<String> for RXingResultMetadataType {
             "STRUCTURED_APPEND_PARITY" | "STRUCTUREDAPPENDPARITY" => {
                 RXingResultMetadataType::STRUCTURED_APPEND_PARITY
             }
+            "STRUCTURED_APPEND_COUNT" | "STRUCTUREDAPPENDCOUNT" => {
+                RXingResultMetadataType::STRUCTURED_APPEND_COUNT
+            }
+            "STRUCTURED_APPEND_ID" | "STRUCTUREDAPPENDID" => {
+                RXingResultMetadataType::STRUCTURED_APPEND_ID
+            }
             "SYMBOLOGY_IDENTIFIER" | "SYMBOLOGYIDENTIFIER" => {
                 RXingResultMetadataType::SYMBOLOGY_IDENTIFIER
             }
             "IS_MIRRORED" | "ISMIRRORED" => RXingResultMetadataType::IS_MIRRORED,
             "CONTENT_TYPE" | "CONTENTTYPE" => RXingResultMetadataType::CONTENT_TYPE,
             "ISINVERTED" => RXingResultMetadataType::IS_INVERTED,
+            "AZTEC_RUNE_VALUE" | "AZTECRUNEVALUE" => RXingResultMetadataType::AZTEC_RUNE_VALUE,
+            "IS_GS1_COMPOSITE" | "ISGS1COMPOSITE" => RXingResultMetadataType::IS_GS1_COMPOSITE,
+            "GS1_COMPOSITE_COMPONENT" | "GS1COMPOSITECOMPONENT" => {
+                RXingResultMetadataType::GS1_COMPOSITE_COMPONENT
+            }
+            "QR_MODEL" | "QRMODEL" => RXingResultMetadataType::QR_MODEL,
             _ => RXingResultMetadataType::OTHER,
         }
     }
@@ -222,6 +271,19 @@ pub enum RXingResultMetadataValue {
      */
     StructuredAppendParity(i32),
 
+    /**
+     * If the code format supports structured append and the current scanned code is part of one then the
+     * number of symbols in the sequence is given with it, 0 if the symbol doesn't tell.
+     * The sequence number is then the 0-based position of the symbol in the sequence.
+     */
+    StructuredAppendCount(i32),
+
+    /**
+     * If the code format supports structured append and the current scanned code is part of one then the
+     * file identification shared by all symbols of the sequence is given with it, if there is one.
+     */
+    StructuredAppendId(String),
+
     /**
      * Barcode Symbology Identifier.
      * Note: According to the GS1 specification the identifier may have to replace a leading FNC1/GS character
@@ -234,4 +296,28 @@ pub enum RXingResultMetadataValue {
     ContentType(String),
 
     IsInverted(bool),
+
+    /**
+     * The value 0 to 255 of an Aztec Rune as an {@link Integer}. The text of the result holds the
+     * same value as three decimal digits.
+     */
+    AztecRuneValue(i32),
+
+    /**
+     * Set if the result is the linear component of a GS1 Composite symbol, whose linkage flag
+     * tells there is a 2D component printed above it.
+     */
+    IsGs1Composite(bool),
+
+    /**
+     * The element strings of the 2D component of a GS1 Composite symbol, as a {@link String},
+     * such as "(10)4567". The text of the result is that of the linear component.
+     */
+    Gs1CompositeComponent(String),
+
+    /**
+     * The model of a QR Code, 1 for the original QR Code and 2 for the current one, as an
+     * {@link Integer}.
+     */
+    QrModel(i32),
 }
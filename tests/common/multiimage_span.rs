@@ -391,6 +391,14 @@ impl<T: MultipleBarcodeReader + Reader> MultiImageSpanAbstractBlackBoxTestCase<T
                             v.parse().unwrap_or_default(),
                         )
                     }
+                    RXingResultMetadataType::STRUCTURED_APPEND_COUNT => {
+                        RXingResultMetadataValue::StructuredAppendCount(
+                            v.parse().unwrap_or_default(),
+                        )
+                    }
+                    RXingResultMetadataType::STRUCTURED_APPEND_ID => {
+                        RXingResultMetadataValue::StructuredAppendId(v)
+                    }
                     RXingResultMetadataType::SYMBOLOGY_IDENTIFIER => {
                         RXingResultMetadataValue::SymbologyIdentifier(v)
                     }
@@ -403,6 +411,18 @@ impl<T: MultipleBarcodeReader + Reader> MultiImageSpanAbstractBlackBoxTestCase<T
                     RXingResultMetadataType::IS_INVERTED => {
                         RXingResultMetadataValue::IsInverted(v.parse().unwrap())
                     }
+                    RXingResultMetadataType::AZTEC_RUNE_VALUE => {
+                        RXingResultMetadataValue::AztecRuneValue(v.parse().unwrap_or_default())
+                    }
+                    RXingResultMetadataType::IS_GS1_COMPOSITE => {
+                        RXingResultMetadataValue::IsGs1Composite(v.parse().unwrap())
+                    }
+                    RXingResultMetadataType::GS1_COMPOSITE_COMPONENT => {
+                        RXingResultMetadataValue::Gs1CompositeComponent(v)
+                    }
+                    RXingResultMetadataType::QR_MODEL => {
+                        RXingResultMetadataValue::QrModel(v.parse().unwrap_or_default())
+                    }
                 };
                 expected_metadata.insert(new_k, new_v);
             }